@@ -1,41 +1,76 @@
 //! HTTP client for communicating with the Dioxus inspector bridge.
 
-use anyhow::Result;
-use serde::{Deserialize, Serialize};
-
-#[derive(Clone)]
-pub struct BridgeClient {
-    client: reqwest::Client,
-    base_url: String,
-}
-
-#[derive(Debug, Serialize)]
-pub struct EvalRequest {
-    pub script: String,
-}
+mod accessibility;
+mod client;
+mod diagnostics;
+mod dom;
+mod eval;
+mod interaction;
+mod layout;
+mod media;
+mod monitoring;
+mod network;
+mod registry;
+mod sse;
+mod storage;
+mod viewport;
+
+pub use accessibility::{
+    AccessibilityApi, AriaResponse, ColorContrastResult, ColorPair, ColorsResponse,
+    ContrastAuditResponse, ContrastIssue, RoleQueryResponse, TabOrderResponse,
+    ValidateColorsResponse,
+};
+pub use client::{BridgeClient, RetryConfig};
+pub use diagnostics::{
+    CoverageResponse, CustomDiagnoseResponse, DiagnosticsApi, ImageStatusResponse, MeasureRequest,
+    MeasureResponse, MissingFontsResponse, PerfDiagnoseResponse,
+};
+pub use dom::{
+    AncestorResponse, ComponentNode, CreateElementRequest, DataAttributesResponse, DomApi,
+    DomDiffResponse, DomNode, DomPageResponse, DomSearchRequest, DomSearchResponse, ListResponse,
+    QueryAllResponse, SlotResponse, TableResponse,
+};
+pub use eval::{
+    EvalApi, HistoryResponse, NamedScriptsResponse, ParallelEvalResponse, SandboxEvalResponse,
+    TraceResponse,
+};
+pub use interaction::{InputStateResponse, InteractionApi};
+pub use layout::{
+    BoxEdges, ComputedLayoutResponse, IntersectionResponse, LayoutApi, LayoutInspectResponse,
+    ParentChainResponse, ScrollContainersResponse, StackingResponse,
+};
+pub use media::{AnimationsResponse, MediaApi, MediaResponse, SvgResponse};
+pub use monitoring::{
+    BridgeConfigResponse, EventLogResponse, EventWatchRequest, JsErrorsResponse, MonitoringApi,
+    MutationEntry, MutationSummaryResponse, SignalsResponse,
+};
+pub use network::{GeoMockRequest, NetworkApi, NetworkCondition, NetworkRequest, NetworkResponse};
+pub use registry::{parse_bridges, BridgeRegistry};
+pub use storage::{IndexedDbResponse, StorageApi, StorageEvent};
+pub use viewport::{
+    BreakpointSimResponse, BreakpointStep, DarkModeResponse, MediaQueryAllResponse,
+    ResponsiveTestResponse, ScreenshotFormat, ViewportApi, ViewportResponse, ViewportSize,
+    WindowStateResponse, ZoomResponse,
+};
+
+use std::time::Duration;
 
-#[derive(Debug, Serialize)]
-pub struct QueryRequest {
-    pub selector: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub property: Option<String>,
-}
-
-#[derive(Debug, Serialize)]
-pub struct InspectRequest {
-    pub selector: String,
-}
+use anyhow::Result;
+use async_trait::async_trait;
+use base64::Engine;
+use futures_util::stream::{self, BoxStream, StreamExt};
+use reqwest::Method;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
 
-#[derive(Debug, Serialize)]
-pub struct ScreenshotRequest {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub path: Option<String>,
-}
+use sse::{decode_eval_event, decode_signal_event, signal_watch_path, SseEvent};
 
 #[derive(Debug, Serialize)]
-pub struct ResizeRequest {
-    pub width: u32,
-    pub height: u32,
+pub struct StreamEvalRequest {
+    pub setup_script: String,
+    pub poll_script: String,
+    pub interval_ms: u64,
+    pub max_events: u32,
 }
 
 #[derive(Debug, Deserialize)]
@@ -45,200 +80,144 @@ pub struct StatusResponse {
 }
 
 #[derive(Debug, Deserialize)]
+pub struct PingResponse {
+    pub server_time_us: u64,
+}
+
+/// Outcome of [`BridgeClientTrait::ping`].
+#[derive(Debug)]
+pub struct PingResult {
+    /// Bridge clock, in microseconds since the Unix epoch.
+    pub server_time_us: u64,
+    /// Time from sending the request to reading the response.
+    pub round_trip_ms: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EvalResponse {
     pub success: bool,
     pub result: Option<String>,
     pub error: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct ScreenshotResponse {
-    pub success: bool,
-    pub path: Option<String>,
-    pub error: Option<String>,
+/// A ZIP archive from `GET /snapshot`.
+#[derive(Debug)]
+pub struct SnapshotArchive {
+    /// The `Snapshot-Id` response header.
+    pub snapshot_id: String,
+    pub zip: Vec<u8>,
 }
 
+/// How clients without a network connection return a [`SnapshotArchive`].
 #[derive(Debug, Deserialize)]
-pub struct ResizeResponse {
-    pub success: bool,
-    pub width: u32,
-    pub height: u32,
-    pub error: Option<String>,
+struct EncodedSnapshotArchive {
+    snapshot_id: String,
+    zip_base64: String,
 }
 
-impl BridgeClient {
-    pub fn new(base_url: &str) -> Self {
-        Self {
-            client: reqwest::Client::new(),
-            base_url: base_url.to_string(),
-        }
-    }
-
-    pub async fn status(&self) -> Result<StatusResponse> {
-        let resp = self
-            .client
-            .get(format!("{}/status", self.base_url))
-            .send()
-            .await?
-            .json()
-            .await?;
-        Ok(resp)
-    }
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SignalChange {
+    pub name: String,
+    pub old_value: Option<Value>,
+    pub new_value: Option<Value>,
+}
 
-    pub async fn eval(&self, script: &str) -> Result<EvalResponse> {
-        let resp = self
-            .client
-            .post(format!("{}/eval", self.base_url))
-            .json(&EvalRequest {
-                script: script.to_string(),
-            })
-            .send()
-            .await?
-            .json()
-            .await?;
-        Ok(resp)
+/// The bridge operations used by the MCP tools.
+///
+/// Implementors only provide [`request`](Self::request); the typed endpoint
+/// methods are built on top of it, here and in the per-domain extension
+/// traits such as [`DomApi`] that every implementor gets, so a test double only
+/// has to answer raw JSON requests.
+#[async_trait]
+pub trait BridgeClientTrait: Send + Sync {
+    /// Send a request to a bridge path (including any query string) and return the JSON body.
+    async fn request(&self, method: Method, path: &str, body: Option<Value>) -> Result<Value>;
+
+    /// Like [`request`](Self::request), retrying connection failures as `retry` says.
+    ///
+    /// Clients without a network connection to retry just send once.
+    async fn request_with_retry(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<Value>,
+        _retry: RetryConfig,
+    ) -> Result<Value> {
+        self.request(method, path, body).await
     }
 
-    pub async fn query(&self, selector: &str, property: Option<&str>) -> Result<EvalResponse> {
-        let resp = self
-            .client
-            .post(format!("{}/query", self.base_url))
-            .json(&QueryRequest {
-                selector: selector.to_string(),
-                property: property.map(String::from),
-            })
-            .send()
-            .await?
-            .json()
-            .await?;
-        Ok(resp)
+    /// Listen to a server-sent event stream for `duration` and return the
+    /// events received.
+    ///
+    /// Clients without a network connection answer with a JSON array of
+    /// events from [`request`](Self::request) instead.
+    async fn stream_events(&self, path: &str, _duration: Duration) -> Result<Vec<SseEvent>> {
+        decode(self.request(Method::GET, path, None).await?)
     }
 
-    pub async fn dom(
+    /// Results of `/eval/stream` polls as the bridge sends them.
+    ///
+    /// Clients without a network connection answer with a JSON array of
+    /// events from [`request`](Self::request) instead.
+    async fn eval_stream(
         &self,
-        depth: Option<u32>,
-        max_nodes: Option<u32>,
-        selector: Option<&str>,
-    ) -> Result<EvalResponse> {
-        let mut url = format!("{}/dom", self.base_url);
-        let mut params = vec![];
-        if let Some(d) = depth {
-            params.push(format!("depth={}", d));
-        }
-        if let Some(m) = max_nodes {
-            params.push(format!("max_nodes={}", m));
-        }
-        if let Some(s) = selector {
-            params.push(format!("selector={}", urlencoding::encode(s)));
-        }
-        if !params.is_empty() {
-            url = format!("{}?{}", url, params.join("&"));
-        }
-
-        let resp = self.client.get(&url).send().await?.json().await?;
-        Ok(resp)
+        req: StreamEvalRequest,
+    ) -> Result<BoxStream<'static, Result<EvalResponse>>> {
+        let body = serde_json::to_value(req)?;
+        let events: Vec<SseEvent> = decode(
+            self.request(Method::POST, "/eval/stream", Some(body))
+                .await?,
+        )?;
+        Ok(stream::iter(events.into_iter().map(decode_eval_event)).boxed())
     }
 
-    pub async fn inspect(&self, selector: &str) -> Result<EvalResponse> {
-        let resp = self
-            .client
-            .post(format!("{}/inspect", self.base_url))
-            .json(&InspectRequest {
-                selector: selector.to_string(),
-            })
-            .send()
-            .await?
-            .json()
-            .await?;
-        Ok(resp)
+    /// Changes to the signals in `names`, or to every signal when `names` is
+    /// empty, as `/signals/watch` reports them.
+    ///
+    /// Clients without a network connection answer with a JSON array of
+    /// events from [`request`](Self::request) instead.
+    async fn watch_signals(
+        &self,
+        names: &[String],
+        interval_ms: Option<u64>,
+    ) -> Result<BoxStream<'static, Result<SignalChange>>> {
+        let path = signal_watch_path(names, interval_ms);
+        let events: Vec<SseEvent> = decode(self.request(Method::GET, &path, None).await?)?;
+        Ok(stream::iter(events.into_iter().map(decode_signal_event)).boxed())
     }
 
-    pub async fn diagnose(&self) -> Result<EvalResponse> {
-        let resp = self
-            .client
-            .get(format!("{}/diagnose", self.base_url))
-            .send()
-            .await?
-            .json()
-            .await?;
-        Ok(resp)
+    /// Capture a `/snapshot` archive, with a screenshot if `screenshot` is set.
+    ///
+    /// Clients without a network connection answer with
+    /// `{"snapshot_id", "zip_base64"}` JSON from [`request`](Self::request)
+    /// instead.
+    async fn capture_snapshot(&self, screenshot: bool) -> Result<SnapshotArchive> {
+        let path = format!("/snapshot?screenshot={}", screenshot);
+        let encoded: EncodedSnapshotArchive =
+            decode(self.request(Method::GET, &path, None).await?)?;
+        Ok(SnapshotArchive {
+            snapshot_id: encoded.snapshot_id,
+            zip: base64::engine::general_purpose::STANDARD.decode(encoded.zip_base64)?,
+        })
     }
 
-    pub async fn screenshot(&self, path: Option<&str>) -> Result<ScreenshotResponse> {
-        let resp = self
-            .client
-            .post(format!("{}/screenshot", self.base_url))
-            .json(&ScreenshotRequest {
-                path: path.map(String::from),
-            })
-            .send()
-            .await?
-            .json()
-            .await?;
-        Ok(resp)
+    async fn status(&self) -> Result<StatusResponse> {
+        decode(
+            self.request_with_retry(Method::GET, "/status", None, RetryConfig::WAIT_FOR_APP)
+                .await?,
+        )
     }
 
-    pub async fn resize(&self, width: u32, height: u32) -> Result<ResizeResponse> {
-        let resp = self
-            .client
-            .post(format!("{}/resize", self.base_url))
-            .json(&ResizeRequest { width, height })
-            .send()
-            .await?
-            .json()
-            .await?;
-        Ok(resp)
+    async fn ping(&self) -> Result<PingResult> {
+        let started = std::time::Instant::now();
+        let resp: PingResponse = decode(self.request(Method::GET, "/ping", None).await?)?;
+        Ok(PingResult {
+            server_time_us: resp.server_time_us,
+            round_trip_ms: started.elapsed().as_secs_f64() * 1000.0,
+        })
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_bridge_client_new() {
-        let client = BridgeClient::new("http://localhost:9999");
-        assert_eq!(client.base_url, "http://localhost:9999");
-    }
-
-    #[test]
-    fn test_eval_request_serialize() {
-        let req = EvalRequest {
-            script: "return 1".to_string(),
-        };
-        let json = serde_json::to_string(&req).unwrap();
-        assert!(json.contains("return 1"));
-    }
-
-    #[test]
-    fn test_query_request_serialize() {
-        let req = QueryRequest {
-            selector: ".btn".to_string(),
-            property: Some("text".to_string()),
-        };
-        let json = serde_json::to_string(&req).unwrap();
-        assert!(json.contains(".btn"));
-        assert!(json.contains("text"));
-    }
-
-    #[test]
-    fn test_query_request_without_property() {
-        let req = QueryRequest {
-            selector: "#id".to_string(),
-            property: None,
-        };
-        let json = serde_json::to_string(&req).unwrap();
-        assert!(!json.contains("property"));
-    }
-
-    #[test]
-    fn test_resize_request_serialize() {
-        let req = ResizeRequest {
-            width: 800,
-            height: 600,
-        };
-        let json = serde_json::to_string(&req).unwrap();
-        assert!(json.contains("800"));
-        assert!(json.contains("600"));
-    }
+fn decode<T: DeserializeOwned>(value: Value) -> Result<T> {
+    Ok(serde_json::from_value(value)?)
 }
@@ -0,0 +1,177 @@
+//! Bridge requests for accessibility checks.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use super::{decode, BridgeClientTrait};
+
+#[derive(Debug, Serialize)]
+pub struct ColorPair {
+    pub foreground: String,
+    pub background: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ValidateColorsRequest {
+    pub pairs: Vec<ColorPair>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AriaRequest {
+    pub selector: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AriaResponse {
+    pub current_attributes: HashMap<String, String>,
+    pub applied: u32,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ColorsResponse {
+    pub colors: Vec<ColorEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ColorEntry {
+    pub css_value: String,
+    pub hex: String,
+    pub usage_count: u32,
+    pub element_examples: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ContrastAuditResponse {
+    pub failures: Vec<ContrastIssue>,
+    pub warnings: Vec<ContrastIssue>,
+    pub pass_count: u32,
+}
+
+/// A failure or warning from `/contrast-audit`, which share a shape.
+#[derive(Debug, Deserialize)]
+pub struct ContrastIssue {
+    pub selector_path: String,
+    pub text: String,
+    pub foreground: String,
+    pub background: String,
+    pub ratio: f64,
+    pub required_ratio: f64,
+    pub level: String,
+    pub text_size: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ColorContrastResult {
+    pub foreground: String,
+    pub background: String,
+    pub ratio: f64,
+    pub aa_normal: bool,
+    pub aa_large: bool,
+    pub aaa_normal: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ValidateColorsResponse {
+    pub results: Vec<ColorContrastResult>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TabOrderEntry {
+    pub index: usize,
+    pub selector_path: String,
+    pub tag: String,
+    pub text: String,
+    pub tabindex: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TabOrderResponse {
+    pub elements: Vec<TabOrderEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RoleQueryResponse {
+    pub elements: Vec<RoleElement>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RoleElement {
+    pub selector_path: String,
+    pub accessible_name: String,
+    pub accessible_description: String,
+    pub states: HashMap<String, String>,
+}
+
+/// ARIA attributes, tab order, roles and color contrast.
+#[async_trait]
+pub trait AccessibilityApi: BridgeClientTrait {
+    async fn validate_colors(&self, pairs: Vec<ColorPair>) -> Result<ValidateColorsResponse> {
+        let body = serde_json::to_value(ValidateColorsRequest { pairs })?;
+        decode(
+            self.request(Method::POST, "/validate-colors", Some(body))
+                .await?,
+        )
+    }
+
+    async fn tab_order(&self) -> Result<TabOrderResponse> {
+        decode(self.request(Method::GET, "/tab-order", None).await?)
+    }
+
+    async fn get_aria(&self, selector: &str) -> Result<AriaResponse> {
+        let body = serde_json::to_value(AriaRequest {
+            selector: selector.to_string(),
+            attributes: None,
+        })?;
+        decode(self.request(Method::POST, "/aria", Some(body)).await?)
+    }
+
+    async fn set_aria(
+        &self,
+        selector: &str,
+        attributes: HashMap<String, String>,
+    ) -> Result<AriaResponse> {
+        let body = serde_json::to_value(AriaRequest {
+            selector: selector.to_string(),
+            attributes: Some(attributes),
+        })?;
+        decode(self.request(Method::POST, "/aria", Some(body)).await?)
+    }
+
+    async fn query_by_role(&self, role: &str, name: Option<&str>) -> Result<RoleQueryResponse> {
+        let mut path = format!("/role?role={}", urlencoding::encode(role));
+        if let Some(name) = name {
+            path.push_str(&format!("&name={}", urlencoding::encode(name)));
+        }
+        decode(self.request(Method::GET, &path, None).await?)
+    }
+
+    async fn colors(&self) -> Result<ColorsResponse> {
+        decode(self.request(Method::GET, "/colors", None).await?)
+    }
+
+    async fn contrast_audit(&self) -> Result<ContrastAuditResponse> {
+        decode(self.request(Method::GET, "/contrast-audit", None).await?)
+    }
+}
+
+impl<T: BridgeClientTrait + ?Sized> AccessibilityApi for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_colors_response_deserialize() {
+        let json = r##"{"results": [{"foreground": "#000", "background": "#fff", "ratio": 21.0, "aa_normal": true, "aa_large": true, "aaa_normal": true}]}"##;
+        let resp: ValidateColorsResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(resp.results.len(), 1);
+        assert!(resp.results[0].aaa_normal);
+    }
+}
@@ -0,0 +1,547 @@
+//! HTTP client for a running bridge, over TCP or a Unix socket.
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use futures_util::stream::{self, BoxStream, StreamExt};
+use reqwest::Method;
+use serde_json::Value;
+
+use super::sse::{decode_eval_event, decode_signal_event, signal_watch_path, SseEvent, SseParser};
+use super::{
+    decode, BridgeClientTrait, EvalResponse, SignalChange, SnapshotArchive, StreamEvalRequest,
+};
+use crate::discovery::{discover_bridge_url, lock_file_path, NoBridgeFound, AUTO_URL};
+
+#[derive(Clone)]
+pub struct BridgeClient {
+    /// `None` until the bridge is found through `lock_file`.
+    endpoint: Arc<Mutex<Option<Endpoint>>>,
+    lock_file: Option<PathBuf>,
+    danger_accept_invalid_certs: bool,
+    auth_token: Option<String>,
+    retry: RetryConfig,
+}
+
+/// The HTTP client for one bridge URL.
+#[derive(Clone)]
+struct Endpoint {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl Endpoint {
+    fn connect(base_url: &str, danger_accept_invalid_certs: bool) -> Self {
+        let builder =
+            reqwest::Client::builder().danger_accept_invalid_certs(danger_accept_invalid_certs);
+        // Every request goes to the socket; the host is only sent as `Host:`
+        #[cfg(unix)]
+        let (builder, base_url) = match unix_socket_path(base_url) {
+            Some(path) => (builder.unix_socket(path), "http://localhost"),
+            None => (builder, base_url),
+        };
+        let client = builder.build().expect("failed to build HTTP client");
+        Self {
+            client,
+            base_url: base_url.to_string(),
+        }
+    }
+}
+
+/// How [`BridgeClient`] retries requests that fail to connect or time out.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryConfig {
+    /// Total tries, including the first. `1` disables retrying.
+    pub max_attempts: u32,
+    /// Wait before the first retry.
+    pub initial_delay_ms: u64,
+    /// Each following wait is the previous one times this.
+    pub backoff_factor: f64,
+}
+
+impl RetryConfig {
+    /// Single attempt, no retries.
+    pub const NONE: RetryConfig = RetryConfig {
+        max_attempts: 1,
+        initial_delay_ms: 0,
+        backoff_factor: 1.0,
+    };
+
+    /// Used by `status`, to wait for an app that is still starting (about 15s in total).
+    pub const WAIT_FOR_APP: RetryConfig = RetryConfig {
+        max_attempts: 10,
+        initial_delay_ms: 200,
+        backoff_factor: 1.5,
+    };
+}
+
+/// The socket path of a `unix://` or `http+unix://` bridge URL.
+#[cfg(unix)]
+fn unix_socket_path(url: &str) -> Option<String> {
+    if let Some(path) = url.strip_prefix("unix://") {
+        return Some(path.trim_end_matches('/').to_string()).filter(|p| !p.is_empty());
+    }
+    // The path is percent-encoded into the host, e.g. `http+unix://%2Ftmp%2Fa.sock`
+    let host = url.strip_prefix("http+unix://")?.split('/').next()?;
+    urlencoding::decode(host)
+        .ok()
+        .map(|path| path.into_owned())
+        .filter(|p| !p.is_empty())
+}
+
+impl BridgeClient {
+    pub fn new(base_url: &str) -> Self {
+        Self::with_options(base_url, false)
+    }
+
+    /// Create a client, optionally skipping certificate checks so an HTTPS
+    /// bridge with a self-signed certificate can be used in development.
+    ///
+    /// `unix:///path/to.sock` and `http+unix://%2Fpath%2Fto.sock` URLs
+    /// connect to a bridge listening on a Unix socket, and
+    /// [`AUTO_URL`] to the one in the lock file.
+    pub fn with_options(base_url: &str, danger_accept_invalid_certs: bool) -> Self {
+        if base_url == AUTO_URL {
+            return Self::discovering(lock_file_path(), danger_accept_invalid_certs);
+        }
+        let endpoint = Endpoint::connect(base_url, danger_accept_invalid_certs);
+        Self {
+            endpoint: Arc::new(Mutex::new(Some(endpoint))),
+            lock_file: None,
+            danger_accept_invalid_certs,
+            auth_token: None,
+            retry: RetryConfig::NONE,
+        }
+    }
+
+    /// Create a client for the bridge named in `lock_file`.
+    ///
+    /// The file is read on the first request, not here, so the app may
+    /// start after this client, and read again after the bridge stops
+    /// answering, so a restarted app is picked up.
+    fn discovering(lock_file: PathBuf, danger_accept_invalid_certs: bool) -> Self {
+        Self {
+            endpoint: Arc::new(Mutex::new(None)),
+            lock_file: Some(lock_file),
+            danger_accept_invalid_certs,
+            auth_token: None,
+            retry: RetryConfig::NONE,
+        }
+    }
+
+    /// Retry requests that fail to connect or time out, e.g. while the app starts.
+    pub fn with_retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    async fn send(&self, method: Method, path: &str, body: Option<&Value>) -> Result<Value> {
+        let mut req = self.build_request(method, path)?;
+        if let Some(body) = body {
+            req = req.json(body);
+        }
+        let resp = self.execute(req).await?;
+        Ok(resp.json().await?)
+    }
+
+    fn build_request(&self, method: Method, path: &str) -> Result<reqwest::RequestBuilder> {
+        let endpoint = self.endpoint()?;
+        let req = endpoint
+            .client
+            .request(method, format!("{}{}", endpoint.base_url, path));
+        Ok(match &self.auth_token {
+            Some(token) => req.bearer_auth(token),
+            None => req,
+        })
+    }
+
+    /// Send `req`, forgetting a discovered bridge that can't be reached.
+    async fn execute(&self, req: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        match req.send().await {
+            Ok(resp) => check_status(resp).await,
+            Err(e) => {
+                if e.is_connect() && self.lock_file.is_some() {
+                    *self.lock_endpoint() = None;
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    /// The endpoint to send to, reading the lock file if there is none yet.
+    fn endpoint(&self) -> Result<Endpoint> {
+        let mut endpoint = self.lock_endpoint();
+        if let Some(found) = endpoint.as_ref() {
+            return Ok(found.clone());
+        }
+        let lock_file = self
+            .lock_file
+            .as_ref()
+            .ok_or_else(|| anyhow!("Bridge client has no URL"))?;
+        let url = discover_bridge_url(lock_file)?;
+        let found = Endpoint::connect(&url, self.danger_accept_invalid_certs);
+        Ok(endpoint.insert(found).clone())
+    }
+
+    fn lock_endpoint(&self) -> std::sync::MutexGuard<'_, Option<Endpoint>> {
+        self.endpoint.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// The URL requests go to.
+    #[cfg(test)]
+    pub(super) fn base_url(&self) -> String {
+        self.endpoint().unwrap().base_url
+    }
+
+    /// Send `Authorization: Bearer {token}`, for bridges started with an auth token.
+    pub fn with_auth_token(mut self, token: impl Into<String>) -> Self {
+        self.auth_token = Some(token.into());
+        self
+    }
+}
+
+#[async_trait]
+impl BridgeClientTrait for BridgeClient {
+    async fn stream_events(&self, path: &str, duration: Duration) -> Result<Vec<SseEvent>> {
+        let mut resp = self.execute(self.build_request(Method::GET, path)?).await?;
+        let mut parser = SseParser::default();
+        let deadline = tokio::time::Instant::now() + duration;
+        // Stop at the deadline or when the bridge ends the stream
+        while let Ok(chunk) = tokio::time::timeout_at(deadline, resp.chunk()).await {
+            match chunk? {
+                Some(bytes) => parser.push(&bytes),
+                None => break,
+            }
+        }
+        Ok(parser.events)
+    }
+
+    async fn eval_stream(
+        &self,
+        req: StreamEvalRequest,
+    ) -> Result<BoxStream<'static, Result<EvalResponse>>> {
+        let req = self.build_request(Method::POST, "/eval/stream")?.json(&req);
+        let events = sse_events(self.execute(req).await?);
+        Ok(events
+            .map(|event| event.and_then(decode_eval_event))
+            .boxed())
+    }
+
+    async fn watch_signals(
+        &self,
+        names: &[String],
+        interval_ms: Option<u64>,
+    ) -> Result<BoxStream<'static, Result<SignalChange>>> {
+        let req = self.build_request(Method::GET, &signal_watch_path(names, interval_ms))?;
+        let events = sse_events(self.execute(req).await?);
+        Ok(events
+            .map(|event| event.and_then(decode_signal_event))
+            .boxed())
+    }
+
+    async fn capture_snapshot(&self, screenshot: bool) -> Result<SnapshotArchive> {
+        let path = format!("/snapshot?screenshot={}", screenshot);
+        let resp = self
+            .execute(self.build_request(Method::GET, &path)?)
+            .await?;
+        let snapshot_id = resp
+            .headers()
+            .get("snapshot-id")
+            .and_then(|v| v.to_str().ok())
+            .ok_or_else(|| anyhow!("Bridge sent no Snapshot-Id header"))?
+            .to_string();
+        Ok(SnapshotArchive {
+            snapshot_id,
+            zip: resp.bytes().await?.to_vec(),
+        })
+    }
+
+    async fn request(&self, method: Method, path: &str, body: Option<Value>) -> Result<Value> {
+        self.request_with_retry(method, path, body, self.retry)
+            .await
+    }
+
+    async fn request_with_retry(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<Value>,
+        retry: RetryConfig,
+    ) -> Result<Value> {
+        // An explicit retry config can only add attempts to the client's own
+        let retry = if retry.max_attempts > self.retry.max_attempts {
+            retry
+        } else {
+            self.retry
+        };
+        let mut delay = Duration::from_millis(retry.initial_delay_ms);
+        let mut attempt = 1;
+        loop {
+            match self.send(method.clone(), path, body.as_ref()).await {
+                Err(e) if attempt < retry.max_attempts && is_transient(&e) => {
+                    tracing::debug!(
+                        "Bridge request {} failed (attempt {}/{}), retrying in {:?}: {}",
+                        path,
+                        attempt,
+                        retry.max_attempts,
+                        delay,
+                        e
+                    );
+                    tokio::time::sleep(delay).await;
+                    delay = delay.mul_f64(retry.backoff_factor);
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Yield the server-sent events of `resp` as they arrive.
+fn sse_events(resp: reqwest::Response) -> BoxStream<'static, Result<SseEvent>> {
+    let reader = (resp, SseParser::default(), false);
+    let events = stream::unfold(reader, |(mut resp, mut parser, mut ended)| async move {
+        loop {
+            if !parser.events.is_empty() {
+                let event = parser.events.remove(0);
+                return Some((Ok(event), (resp, parser, ended)));
+            }
+            if ended {
+                return None;
+            }
+            match resp.chunk().await {
+                Ok(Some(bytes)) => parser.push(&bytes),
+                Ok(None) => ended = true,
+                Err(e) => return Some((Err(e.into()), (resp, parser, true))),
+            }
+        }
+    });
+    events.boxed()
+}
+
+async fn check_status(resp: reqwest::Response) -> Result<reqwest::Response> {
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        return Err(anyhow!("Bridge returned {}: {}", status, body));
+    }
+    Ok(resp)
+}
+
+/// Whether a request failed before reaching the bridge, so trying again may work.
+fn is_transient(e: &anyhow::Error) -> bool {
+    e.is::<NoBridgeFound>()
+        || e.downcast_ref::<reqwest::Error>()
+            .is_some_and(|e| e.is_connect() || e.is_timeout())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bridge_client_new() {
+        let client = BridgeClient::new("http://localhost:9999");
+        assert_eq!(client.base_url(), "http://localhost:9999");
+    }
+
+    /// A free local port with nothing listening on it.
+    fn closed_port() -> u16 {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.local_addr().unwrap().port()
+    }
+
+    #[tokio::test]
+    async fn test_request_retries_until_bridge_starts() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let port = closed_port();
+        let client =
+            BridgeClient::new(&format!("http://127.0.0.1:{}", port)).with_retry(RetryConfig {
+                max_attempts: 20,
+                initial_delay_ms: 20,
+                backoff_factor: 1.0,
+            });
+
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            let listener = tokio::net::TcpListener::bind(("127.0.0.1", port))
+                .await
+                .unwrap();
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0; 1024];
+            let _ = socket.read(&mut buf).await;
+            let body = r#"{"status":"ok","app":"late"}"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            let _ = socket.write_all(response.as_bytes()).await;
+        });
+
+        let status = client.status().await.unwrap();
+        assert_eq!(status.app, "late");
+    }
+
+    #[tokio::test]
+    async fn test_discovers_bridge_on_first_request() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let lock_file =
+            std::env::temp_dir().join(format!("dioxus-mcp-client-{}.lock", std::process::id()));
+        let _ = std::fs::remove_file(&lock_file);
+        let client = BridgeClient::discovering(lock_file.clone(), false);
+        // No app yet, which is worth retrying
+        let err = client
+            .request(Method::GET, "/status", None)
+            .await
+            .unwrap_err();
+        assert!(is_transient(&err));
+
+        let listener = tokio::net::TcpListener::bind(("127.0.0.1", 0))
+            .await
+            .unwrap();
+        let url = format!("http://127.0.0.1:{}", listener.local_addr().unwrap().port());
+        let lock = serde_json::json!({"url": url, "app_name": "late", "pid": 42});
+        std::fs::write(&lock_file, lock.to_string()).unwrap();
+
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0; 1024];
+            let _ = socket.read(&mut buf).await;
+            let body = r#"{"status":"ok","app":"late"}"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            let _ = socket.write_all(response.as_bytes()).await;
+        });
+
+        let status = client.status().await.unwrap();
+        assert_eq!(status.app, "late");
+        assert_eq!(client.base_url(), url);
+        let _ = std::fs::remove_file(lock_file);
+    }
+
+    #[tokio::test]
+    async fn test_eval_stream_yields_results_as_they_arrive() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind(("127.0.0.1", 0))
+            .await
+            .unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let client = BridgeClient::new(&format!("http://127.0.0.1:{}", port));
+
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0; 1024];
+            let _ = socket.read(&mut buf).await;
+            let head =
+                "HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\nconnection: close\r\n\r\n";
+            let _ = socket.write_all(head.as_bytes()).await;
+            let _ = socket
+                .write_all(
+                    b"event: result\ndata: {\"success\":true,\"result\":\"50\",\"error\":null}\n\n",
+                )
+                .await;
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            let _ = socket
+                .write_all(b": keep-alive\n\nevent: result\ndata: {\"success\":false,\"result\":null,\"error\":\"boom\"}\n\n")
+                .await;
+        });
+
+        let req = StreamEvalRequest {
+            setup_script: String::new(),
+            poll_script: "return window.__progress".to_string(),
+            interval_ms: 100,
+            max_events: 10,
+        };
+        let results: Vec<EvalResponse> = client
+            .eval_stream(req)
+            .await
+            .unwrap()
+            .map(|r| r.unwrap())
+            .collect()
+            .await;
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].result.as_deref(), Some("50"));
+        assert_eq!(results[1].error.as_deref(), Some("boom"));
+    }
+
+    #[tokio::test]
+    async fn test_request_without_retry_fails_fast() {
+        let client = BridgeClient::new(&format!("http://127.0.0.1:{}", closed_port()));
+        let err = client
+            .request(Method::GET, "/eval", None)
+            .await
+            .unwrap_err();
+        assert!(is_transient(&err));
+    }
+
+    #[test]
+    fn test_is_transient_ignores_bridge_errors() {
+        assert!(!is_transient(&anyhow!("Bridge returned 500: boom")));
+    }
+
+    #[test]
+    fn test_bridge_client_with_auth_token() {
+        let client = BridgeClient::new("http://localhost:9999").with_auth_token("secret");
+        assert_eq!(client.auth_token.as_deref(), Some("secret"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_unix_socket_path() {
+        assert_eq!(
+            unix_socket_path("unix:///tmp/dioxus-inspector.sock").as_deref(),
+            Some("/tmp/dioxus-inspector.sock")
+        );
+        assert_eq!(
+            unix_socket_path("http+unix://%2Ftmp%2Fdioxus-inspector.sock/").as_deref(),
+            Some("/tmp/dioxus-inspector.sock")
+        );
+        assert_eq!(unix_socket_path("unix://"), None);
+        assert_eq!(unix_socket_path("http://127.0.0.1:9999"), None);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_request_over_unix_socket() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let path = std::env::temp_dir().join(format!("dioxus-mcp-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let listener = tokio::net::UnixListener::bind(&path).unwrap();
+        let client = BridgeClient::new(&format!("unix://{}", path.display()));
+        assert_eq!(client.base_url(), "http://localhost");
+
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0; 1024];
+            let _ = socket.read(&mut buf).await;
+            let body = r#"{"status":"ok","app":"socket"}"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            let _ = socket.write_all(response.as_bytes()).await;
+        });
+
+        let status = client.status().await.unwrap();
+        assert_eq!(status.app, "socket");
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_bridge_client_with_options() {
+        let client = BridgeClient::with_options("https://localhost:9999", true);
+        assert_eq!(client.base_url(), "https://localhost:9999");
+    }
+}
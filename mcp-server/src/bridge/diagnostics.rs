@@ -0,0 +1,170 @@
+//! Bridge requests for diagnostics and coverage.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use super::{decode, BridgeClientTrait, EvalResponse};
+
+#[derive(Debug, Serialize)]
+pub struct MeasureRequest {
+    pub label: String,
+    pub script: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repetitions: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CustomCheckRequest {
+    pub name: String,
+    pub script: String,
+    pub severity: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MissingFontsResponse {
+    pub missing: Vec<String>,
+    pub loading: Vec<String>,
+    pub loaded: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ImageStatusResponse {
+    pub images: Vec<ImageStatus>,
+    pub broken_count: u32,
+    pub missing_alt_count: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ImageStatus {
+    pub src: String,
+    pub complete: bool,
+    pub natural_width: u32,
+    pub alt: Option<String>,
+}
+
+impl ImageStatus {
+    pub fn is_broken(&self) -> bool {
+        self.complete && self.natural_width == 0
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoverageStartResponse {
+    pub started: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoverageScript {
+    pub url: String,
+    pub covered_ranges: Vec<[u32; 2]>,
+    pub total_bytes: u32,
+    pub covered_bytes: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoverageResponse {
+    pub scripts: Vec<CoverageScript>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RegisterCheckResponse {
+    pub success: bool,
+    pub checks: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckResult {
+    pub name: String,
+    pub severity: String,
+    pub passed: bool,
+    pub response: EvalResponse,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CustomDiagnoseResponse {
+    pub checks: Vec<CheckResult>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PerfDiagnoseResponse {
+    pub issues: Vec<PerfIssue>,
+    pub score: u8,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MeasureResponse {
+    pub label: String,
+    pub mean_ms: f64,
+    pub min_ms: f64,
+    pub max_ms: f64,
+    pub stddev_ms: f64,
+    pub samples: Vec<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PerfIssue {
+    pub kind: String,
+    pub severity: String,
+    pub message: String,
+}
+
+/// Health checks, performance, missing fonts, broken images and coverage.
+#[async_trait]
+pub trait DiagnosticsApi: BridgeClientTrait {
+    async fn diagnose(&self) -> Result<EvalResponse> {
+        decode(self.request(Method::GET, "/diagnose", None).await?)
+    }
+
+    async fn register_check(
+        &self,
+        name: &str,
+        script: &str,
+        severity: &str,
+    ) -> Result<RegisterCheckResponse> {
+        let body = serde_json::to_value(CustomCheckRequest {
+            name: name.to_string(),
+            script: script.to_string(),
+            severity: severity.to_string(),
+        })?;
+        decode(
+            self.request(Method::POST, "/diagnose/register", Some(body))
+                .await?,
+        )
+    }
+
+    async fn custom_checks(&self) -> Result<CustomDiagnoseResponse> {
+        decode(self.request(Method::GET, "/diagnose/custom", None).await?)
+    }
+
+    async fn diagnose_performance(&self) -> Result<PerfDiagnoseResponse> {
+        decode(
+            self.request(Method::GET, "/diagnose/performance", None)
+                .await?,
+        )
+    }
+
+    async fn measure(&self, req: MeasureRequest) -> Result<MeasureResponse> {
+        let body = serde_json::to_value(req)?;
+        decode(self.request(Method::POST, "/measure", Some(body)).await?)
+    }
+
+    async fn missing_fonts(&self) -> Result<MissingFontsResponse> {
+        decode(self.request(Method::GET, "/fonts/missing", None).await?)
+    }
+
+    async fn image_status(&self) -> Result<ImageStatusResponse> {
+        decode(self.request(Method::GET, "/image-status", None).await?)
+    }
+
+    async fn coverage_start(&self) -> Result<CoverageStartResponse> {
+        decode(self.request(Method::POST, "/coverage/start", None).await?)
+    }
+
+    async fn coverage_report(&self) -> Result<CoverageResponse> {
+        decode(self.request(Method::GET, "/coverage/report", None).await?)
+    }
+}
+
+impl<T: BridgeClientTrait + ?Sized> DiagnosticsApi for T {}
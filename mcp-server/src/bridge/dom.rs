@@ -0,0 +1,443 @@
+//! Bridge requests for the DOM tree and element queries.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{decode, BridgeClientTrait, EvalResponse};
+
+#[derive(Debug, Serialize)]
+pub struct QueryRequest {
+    pub selector: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub property: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct QueryAllRequest {
+    pub selector: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub property: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DataAttributesRequest {
+    pub selector: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ComputedStylesRequest {
+    pub selector: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DomDiffRequest {
+    pub before_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateElementRequest {
+    pub parent_selector: String,
+    pub tag: String,
+    pub attributes: HashMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TableRequest {
+    pub selector: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ListRequest {
+    pub selector: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AncestorRequest {
+    pub selectors: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DomSearchRequest {
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub case_sensitive: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exact: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector_filter: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SlotInspectRequest {
+    pub host_selector: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QueryAllResponse {
+    pub elements: Vec<EvalResponse>,
+    pub total_count: u32,
+    pub truncated: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DataAttributesResponse {
+    pub attributes: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ComputedStylesResponse {
+    pub styles: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DomSnapshotResponse {
+    pub snapshot_id: String,
+    pub node_count: usize,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DomDiffResponse {
+    pub after_id: String,
+    pub changes: Vec<DomChange>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DomChange {
+    pub path: String,
+    pub kind: String,
+    pub field: Option<String>,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateElementResponse {
+    pub success: bool,
+    pub selector: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RemoveElementResponse {
+    pub removed: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TableResponse {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+    pub row_count: u32,
+    pub merged_cells: Vec<MergedCell>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MergedCell {
+    pub row: u32,
+    pub column: u32,
+    pub rowspan: u32,
+    pub colspan: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DomPageResponse {
+    pub nodes: Vec<DomNode>,
+    pub next_cursor: Option<String>,
+    pub total_nodes: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DomNode {
+    pub index: u32,
+    pub depth: u32,
+    pub tag: Option<String>,
+    pub id: Option<String>,
+    pub class: Option<String>,
+    pub text: Option<String>,
+    pub in_shadow_root: bool,
+    pub truncated: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AncestorResponse {
+    pub ancestor_selector: String,
+    pub ancestor_tag: String,
+    pub depth: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DomSearchResponse {
+    pub matches: Vec<SearchMatch>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SearchMatch {
+    pub selector_path: String,
+    pub tag: String,
+    pub text_snippet: String,
+    pub match_count: usize,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListResponse {
+    pub items: Vec<ListItem>,
+    pub list_type: String,
+    pub ordered: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListItem {
+    pub tag: String,
+    pub text: String,
+    pub href: Option<String>,
+    pub data_attributes: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SlotResponse {
+    pub slots: Vec<SlotInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SlotInfo {
+    pub name: String,
+    pub assigned_elements: Vec<String>,
+    pub fallback_content: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ComponentNode {
+    pub name: Option<String>,
+    pub id: String,
+    pub children: Vec<ComponentNode>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ComponentTreeResponse {
+    pub root: ComponentNode,
+}
+
+/// DOM tree reads, snapshots and element queries.
+#[async_trait]
+pub trait DomApi: BridgeClientTrait {
+    async fn query(&self, selector: &str, property: Option<&str>) -> Result<EvalResponse> {
+        let body = serde_json::to_value(QueryRequest {
+            selector: selector.to_string(),
+            property: property.map(String::from),
+        })?;
+        decode(self.request(Method::POST, "/query", Some(body)).await?)
+    }
+
+    async fn query_all(
+        &self,
+        selector: &str,
+        property: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<QueryAllResponse> {
+        let body = serde_json::to_value(QueryAllRequest {
+            selector: selector.to_string(),
+            property: property.map(String::from),
+            limit,
+        })?;
+        decode(self.request(Method::POST, "/query/all", Some(body)).await?)
+    }
+
+    async fn data_attributes(&self, selector: &str) -> Result<DataAttributesResponse> {
+        let body = serde_json::to_value(DataAttributesRequest {
+            selector: selector.to_string(),
+        })?;
+        decode(
+            self.request(Method::POST, "/data-attributes", Some(body))
+                .await?,
+        )
+    }
+
+    async fn computed_styles(
+        &self,
+        selector: &str,
+        properties: Option<Vec<String>>,
+    ) -> Result<ComputedStylesResponse> {
+        let body = serde_json::to_value(ComputedStylesRequest {
+            selector: selector.to_string(),
+            properties,
+        })?;
+        decode(
+            self.request(Method::POST, "/computed-styles", Some(body))
+                .await?,
+        )
+    }
+
+    async fn component_tree(&self) -> Result<ComponentTreeResponse> {
+        decode(self.request(Method::GET, "/tree", None).await?)
+    }
+
+    async fn dom_snapshot(&self) -> Result<DomSnapshotResponse> {
+        decode(self.request(Method::GET, "/dom/snapshot", None).await?)
+    }
+
+    async fn dom_diff(&self, before_id: &str, after_id: Option<&str>) -> Result<DomDiffResponse> {
+        let body = serde_json::to_value(DomDiffRequest {
+            before_id: before_id.to_string(),
+            after_id: after_id.map(String::from),
+        })?;
+        decode(self.request(Method::POST, "/dom/diff", Some(body)).await?)
+    }
+
+    async fn dom(
+        &self,
+        depth: Option<u32>,
+        max_nodes: Option<u32>,
+        selector: Option<&str>,
+        include_shadow: bool,
+    ) -> Result<EvalResponse> {
+        let params = dom_params(depth, max_nodes, selector, include_shadow);
+        decode(
+            self.request(Method::GET, &with_query("/dom", params), None)
+                .await?,
+        )
+    }
+
+    /// One page of the flattened DOM tree, starting at `cursor` or the root.
+    async fn dom_page(
+        &self,
+        depth: Option<u32>,
+        max_nodes: Option<u32>,
+        selector: Option<&str>,
+        include_shadow: bool,
+        cursor: Option<&str>,
+        page_size: u32,
+    ) -> Result<DomPageResponse> {
+        let mut params = dom_params(depth, max_nodes, selector, include_shadow);
+        params.push(format!("page_size={}", page_size));
+        if let Some(c) = cursor {
+            params.push(format!("cursor={}", urlencoding::encode(c)));
+        }
+        decode(
+            self.request(Method::GET, &with_query("/dom", params), None)
+                .await?,
+        )
+    }
+
+    async fn create_element(&self, req: CreateElementRequest) -> Result<CreateElementResponse> {
+        let body = serde_json::to_value(req)?;
+        decode(
+            self.request(Method::POST, "/element/create", Some(body))
+                .await?,
+        )
+    }
+
+    async fn remove_element(&self, selector: &str) -> Result<RemoveElementResponse> {
+        let path = format!("/element/remove?selector={}", urlencoding::encode(selector));
+        decode(self.request(Method::DELETE, &path, None).await?)
+    }
+
+    async fn extract_table(&self, selector: &str) -> Result<TableResponse> {
+        let body = serde_json::to_value(TableRequest {
+            selector: selector.to_string(),
+        })?;
+        decode(self.request(Method::POST, "/table", Some(body)).await?)
+    }
+
+    async fn extract_list(&self, selector: &str) -> Result<ListResponse> {
+        let body = serde_json::to_value(ListRequest {
+            selector: selector.to_string(),
+        })?;
+        decode(self.request(Method::POST, "/list", Some(body)).await?)
+    }
+
+    async fn common_ancestor(&self, selectors: Vec<String>) -> Result<AncestorResponse> {
+        let body = serde_json::to_value(AncestorRequest { selectors })?;
+        decode(
+            self.request(Method::POST, "/dom/ancestors", Some(body))
+                .await?,
+        )
+    }
+
+    async fn dom_search(&self, req: DomSearchRequest) -> Result<DomSearchResponse> {
+        let body = serde_json::to_value(req)?;
+        decode(
+            self.request(Method::POST, "/dom/search", Some(body))
+                .await?,
+        )
+    }
+
+    async fn inspect_slots(&self, host_selector: &str) -> Result<SlotResponse> {
+        let body = serde_json::to_value(SlotInspectRequest {
+            host_selector: host_selector.to_string(),
+        })?;
+        decode(self.request(Method::POST, "/slot", Some(body)).await?)
+    }
+}
+
+impl<T: BridgeClientTrait + ?Sized> DomApi for T {}
+
+/// Query parameters shared by [`DomApi::dom`] and [`DomApi::dom_page`].
+fn dom_params(
+    depth: Option<u32>,
+    max_nodes: Option<u32>,
+    selector: Option<&str>,
+    include_shadow: bool,
+) -> Vec<String> {
+    let mut params = vec![];
+    if let Some(d) = depth {
+        params.push(format!("depth={}", d));
+    }
+    if let Some(m) = max_nodes {
+        params.push(format!("max_nodes={}", m));
+    }
+    if let Some(s) = selector {
+        params.push(format!("selector={}", urlencoding::encode(s)));
+    }
+    if include_shadow {
+        params.push("include_shadow=true".to_string());
+    }
+    params
+}
+
+fn with_query(path: &str, params: Vec<String>) -> String {
+    if params.is_empty() {
+        path.to_string()
+    } else {
+        format!("{}?{}", path, params.join("&"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_request_serialize() {
+        let req = QueryRequest {
+            selector: ".btn".to_string(),
+            property: Some("text".to_string()),
+        };
+        let json = serde_json::to_string(&req).unwrap();
+        assert!(json.contains(".btn"));
+        assert!(json.contains("text"));
+    }
+
+    #[test]
+    fn test_query_request_without_property() {
+        let req = QueryRequest {
+            selector: "#id".to_string(),
+            property: None,
+        };
+        let json = serde_json::to_string(&req).unwrap();
+        assert!(!json.contains("property"));
+    }
+}
@@ -0,0 +1,257 @@
+//! Bridge requests for JavaScript evaluation.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use super::{decode, BridgeClientTrait, EvalResponse};
+
+#[derive(Debug, Serialize)]
+pub struct EvalRequest {
+    pub script: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ModuleEvalRequest {
+    pub code: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AsyncEvalRequest {
+    pub script: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ParallelEvalRequest {
+    pub scripts: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NamedScriptRequest {
+    pub script: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NamedEvalRequest {
+    pub params: HashMap<String, String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SandboxEvalRequest {
+    pub script: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_output_bytes: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ConditionalEvalRequest {
+    pub condition_selector: String,
+    pub script: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub else_script: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SandboxEvalResponse {
+    #[serde(flatten)]
+    pub eval: EvalResponse,
+    pub truncated: bool,
+    pub cpu_ms: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NamedScriptsResponse {
+    pub scripts: Vec<NamedScript>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NamedScript {
+    pub name: String,
+    pub script: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ParallelEvalResponse {
+    pub results: Vec<EvalResult>,
+    pub total_ms: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EvalResult {
+    pub script_index: usize,
+    #[serde(flatten)]
+    pub eval: EvalResponse,
+    pub duration_ms: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HistoryEntry {
+    pub script: String,
+    pub response: EvalResponse,
+    pub timestamp_ms: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HistoryResponse {
+    pub entries: Vec<HistoryEntry>,
+    pub capacity: usize,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TraceEntry {
+    pub timestamp_ms: u64,
+    pub script_hash: u64,
+    pub success: bool,
+    pub duration_ms: f64,
+    pub result_preview: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TraceResponse {
+    pub entries: Vec<TraceEntry>,
+}
+
+/// JavaScript evaluation, named scripts, eval history and traces.
+#[async_trait]
+pub trait EvalApi: BridgeClientTrait {
+    async fn eval(&self, script: &str) -> Result<EvalResponse> {
+        let body = serde_json::to_value(EvalRequest {
+            script: script.to_string(),
+        })?;
+        decode(self.request(Method::POST, "/eval", Some(body)).await?)
+    }
+
+    async fn eval_if(
+        &self,
+        selector: &str,
+        script: &str,
+        else_script: Option<&str>,
+    ) -> Result<EvalResponse> {
+        let body = serde_json::to_value(ConditionalEvalRequest {
+            condition_selector: selector.to_string(),
+            script: script.to_string(),
+            else_script: else_script.map(String::from),
+        })?;
+        decode(
+            self.request(Method::POST, "/eval/conditional", Some(body))
+                .await?,
+        )
+    }
+
+    async fn eval_async(&self, script: &str, timeout_ms: Option<u64>) -> Result<EvalResponse> {
+        let body = serde_json::to_value(AsyncEvalRequest {
+            script: script.to_string(),
+            timeout_ms,
+        })?;
+        decode(
+            self.request(Method::POST, "/eval/async", Some(body))
+                .await?,
+        )
+    }
+
+    async fn eval_sandboxed(
+        &self,
+        script: &str,
+        max_output_bytes: Option<usize>,
+        timeout_ms: Option<u64>,
+    ) -> Result<SandboxEvalResponse> {
+        let body = serde_json::to_value(SandboxEvalRequest {
+            script: script.to_string(),
+            max_output_bytes,
+            timeout_ms,
+        })?;
+        decode(
+            self.request(Method::POST, "/eval/sandbox", Some(body))
+                .await?,
+        )
+    }
+
+    async fn eval_parallel(
+        &self,
+        scripts: Vec<String>,
+        timeout_ms: Option<u64>,
+    ) -> Result<ParallelEvalResponse> {
+        let body = serde_json::to_value(ParallelEvalRequest {
+            scripts,
+            timeout_ms,
+        })?;
+        decode(
+            self.request(Method::POST, "/eval/parallel", Some(body))
+                .await?,
+        )
+    }
+
+    async fn named_scripts(&self) -> Result<NamedScriptsResponse> {
+        decode(self.request(Method::GET, "/eval/named", None).await?)
+    }
+
+    async fn store_named_script(&self, name: &str, script: &str) -> Result<NamedScriptsResponse> {
+        let body = serde_json::to_value(NamedScriptRequest {
+            script: script.to_string(),
+        })?;
+        let path = format!("/eval/named/{}", urlencoding::encode(name));
+        decode(self.request(Method::PUT, &path, Some(body)).await?)
+    }
+
+    async fn run_named_script(
+        &self,
+        name: &str,
+        params: HashMap<String, String>,
+    ) -> Result<EvalResponse> {
+        let body = serde_json::to_value(NamedEvalRequest { params })?;
+        let path = format!("/eval/named/{}", urlencoding::encode(name));
+        decode(self.request(Method::POST, &path, Some(body)).await?)
+    }
+
+    async fn remove_named_script(&self, name: &str) -> Result<NamedScriptsResponse> {
+        let path = format!("/eval/named/{}", urlencoding::encode(name));
+        decode(self.request(Method::DELETE, &path, None).await?)
+    }
+
+    async fn eval_module(&self, code: &str) -> Result<EvalResponse> {
+        let body = serde_json::to_value(ModuleEvalRequest {
+            code: code.to_string(),
+        })?;
+        decode(
+            self.request(Method::POST, "/eval/module", Some(body))
+                .await?,
+        )
+    }
+
+    async fn history(&self, last: Option<usize>) -> Result<HistoryResponse> {
+        let path = match last {
+            Some(n) => format!("/history?last={}", n),
+            None => "/history".to_string(),
+        };
+        decode(self.request(Method::GET, &path, None).await?)
+    }
+
+    async fn trace(&self) -> Result<TraceResponse> {
+        decode(self.request(Method::GET, "/trace", None).await?)
+    }
+}
+
+impl<T: BridgeClientTrait + ?Sized> EvalApi for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eval_request_serialize() {
+        let req = EvalRequest {
+            script: "return 1".to_string(),
+        };
+        let json = serde_json::to_string(&req).unwrap();
+        assert!(json.contains("return 1"));
+    }
+}
@@ -0,0 +1,213 @@
+//! Bridge requests that act on elements or wait for them.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{decode, BridgeClientTrait, EvalResponse};
+
+#[derive(Debug, Serialize)]
+pub struct ScrollIntoViewRequest {
+    pub selector: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub behavior: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DragRequest {
+    pub from_selector: String,
+    pub to_selector: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TouchRequest {
+    pub selector: String,
+    pub action: String,
+    pub touches: Vec<TouchPoint>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TouchPoint {
+    pub x: f64,
+    pub y: f64,
+    pub id: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WatchRequest {
+    pub selector: String,
+    pub condition: Value,
+    pub timeout_ms: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FormFillRequest {
+    pub form_selector: String,
+    pub fields: HashMap<String, String>,
+    pub submit: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SelectRequest {
+    pub selector: String,
+    pub start: u32,
+    pub end: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WatchResponse {
+    pub matched: bool,
+    pub elapsed_ms: u64,
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FormFillResponse {
+    pub success: bool,
+    pub fields_filled: u32,
+    pub submitted: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct InputStateResponse {
+    pub inputs: Vec<InputState>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct InputState {
+    pub name: String,
+    pub id: String,
+    #[serde(rename = "type")]
+    pub input_type: String,
+    pub value: String,
+    pub checked: Option<bool>,
+    pub validity_valid: bool,
+    pub validation_message: String,
+    pub required: bool,
+    pub disabled: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SelectResponse {
+    pub success: bool,
+    pub selected_text: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Scrolling, dragging, touch, text selection and forms.
+#[async_trait]
+pub trait InteractionApi: BridgeClientTrait {
+    async fn scroll_into_view(
+        &self,
+        selector: &str,
+        behavior: Option<&str>,
+        block: Option<&str>,
+        inline: Option<&str>,
+    ) -> Result<EvalResponse> {
+        let body = serde_json::to_value(ScrollIntoViewRequest {
+            selector: selector.to_string(),
+            behavior: behavior.map(String::from),
+            block: block.map(String::from),
+            inline: inline.map(String::from),
+        })?;
+        decode(
+            self.request(Method::POST, "/scroll-into-view", Some(body))
+                .await?,
+        )
+    }
+
+    async fn drag(&self, from_selector: &str, to_selector: &str) -> Result<EvalResponse> {
+        let body = serde_json::to_value(DragRequest {
+            from_selector: from_selector.to_string(),
+            to_selector: to_selector.to_string(),
+        })?;
+        decode(self.request(Method::POST, "/drag", Some(body)).await?)
+    }
+
+    async fn touch(
+        &self,
+        selector: &str,
+        action: &str,
+        touches: Vec<TouchPoint>,
+    ) -> Result<EvalResponse> {
+        let body = serde_json::to_value(TouchRequest {
+            selector: selector.to_string(),
+            action: action.to_string(),
+            touches,
+        })?;
+        decode(self.request(Method::POST, "/touch", Some(body)).await?)
+    }
+
+    async fn select_text(&self, selector: &str, start: u32, end: u32) -> Result<SelectResponse> {
+        let body = serde_json::to_value(SelectRequest {
+            selector: selector.to_string(),
+            start,
+            end,
+        })?;
+        decode(self.request(Method::POST, "/select", Some(body)).await?)
+    }
+
+    async fn input_state(&self, form_selector: Option<&str>) -> Result<InputStateResponse> {
+        let path = match form_selector {
+            Some(s) => format!("/input-state?form_selector={}", urlencoding::encode(s)),
+            None => "/input-state".to_string(),
+        };
+        decode(self.request(Method::GET, &path, None).await?)
+    }
+
+    async fn form_fill(
+        &self,
+        form_selector: &str,
+        fields: HashMap<String, String>,
+        submit: bool,
+    ) -> Result<FormFillResponse> {
+        let body = serde_json::to_value(FormFillRequest {
+            form_selector: form_selector.to_string(),
+            fields,
+            submit,
+        })?;
+        decode(self.request(Method::POST, "/form", Some(body)).await?)
+    }
+
+    /// `condition` is the bridge's tagged form, e.g. `{"type": "visible"}`.
+    async fn watch(
+        &self,
+        selector: &str,
+        condition: Value,
+        timeout_ms: u64,
+    ) -> Result<WatchResponse> {
+        let body = serde_json::to_value(WatchRequest {
+            selector: selector.to_string(),
+            condition,
+            timeout_ms,
+        })?;
+        decode(self.request(Method::POST, "/watch", Some(body)).await?)
+    }
+}
+
+impl<T: BridgeClientTrait + ?Sized> InteractionApi for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drag_request_serialize() {
+        let req = DragRequest {
+            from_selector: ".card".to_string(),
+            to_selector: ".column".to_string(),
+        };
+        let json = serde_json::to_string(&req).unwrap();
+        assert!(json.contains("\"from_selector\":\".card\""));
+        assert!(json.contains("\"to_selector\":\".column\""));
+    }
+}
@@ -0,0 +1,256 @@
+//! Bridge requests for layout inspection.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use super::{decode, BridgeClientTrait, EvalResponse};
+
+#[derive(Debug, Serialize)]
+pub struct LayoutInspectRequest {
+    pub selector: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StackingInspectRequest {
+    pub selector: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ParentChainRequest {
+    pub selector: String,
+    pub depth: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct InspectRequest {
+    pub selector: String,
+    pub include_listeners: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LayoutInspectResponse {
+    pub display: String,
+    pub flex_direction: Option<String>,
+    pub flex_wrap: Option<String>,
+    pub grid_template_columns: Option<String>,
+    pub grid_template_rows: Option<String>,
+    pub children: Vec<LayoutChildInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ComputedLayoutResponse {
+    pub display: String,
+    pub box_model: BoxModel,
+    pub flex: Option<FlexProperties>,
+    pub grid: Option<GridProperties>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BoxModel {
+    pub box_sizing: String,
+    pub content_width: f64,
+    pub content_height: f64,
+    pub padding_width: f64,
+    pub padding_height: f64,
+    pub border_width: f64,
+    pub border_height: f64,
+    pub margin_width: f64,
+    pub margin_height: f64,
+    pub padding: BoxEdges,
+    pub border: BoxEdges,
+    pub margin: BoxEdges,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BoxEdges {
+    pub top: f64,
+    pub right: f64,
+    pub bottom: f64,
+    pub left: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FlexProperties {
+    pub is_container: bool,
+    pub is_item: bool,
+    pub direction: String,
+    pub wrap: String,
+    pub justify_content: String,
+    pub align_items: String,
+    pub align_content: String,
+    pub row_gap: String,
+    pub column_gap: String,
+    pub grow: f64,
+    pub shrink: f64,
+    pub basis: String,
+    pub align_self: String,
+    pub order: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GridProperties {
+    pub is_container: bool,
+    pub is_item: bool,
+    pub template_columns: String,
+    pub template_rows: String,
+    pub template_areas: String,
+    pub auto_flow: String,
+    pub auto_columns: String,
+    pub auto_rows: String,
+    pub row_gap: String,
+    pub column_gap: String,
+    pub justify_items: String,
+    pub align_items: String,
+    pub column: String,
+    pub row: String,
+    pub justify_self: String,
+    pub align_self: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StackingResponse {
+    pub contexts: Vec<StackingContext>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StackingContext {
+    pub selector_path: String,
+    pub z_index: String,
+    pub creates_context_reason: Vec<String>,
+    pub bounding_rect: BoundingRect,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IntersectionResponse {
+    pub elements: Vec<IntersectionEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IntersectionEntry {
+    pub selector_path: String,
+    pub in_viewport: bool,
+    pub intersection_ratio: f64,
+    pub bounding_rect: BoundingRect,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BoundingRect {
+    pub top: f64,
+    pub left: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ParentChainResponse {
+    pub chain: Vec<ParentNode>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ParentNode {
+    pub tag: String,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+    pub display: String,
+    pub overflow: String,
+    pub visibility: String,
+    pub z_index: String,
+    pub position: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LayoutChildInfo {
+    pub selector_path: String,
+    pub flex_basis: String,
+    pub flex_grow: String,
+    pub flex_shrink: String,
+    pub grid_column: String,
+    pub grid_row: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ScrollContainersResponse {
+    pub containers: Vec<ScrollContainer>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ScrollContainer {
+    pub selector_path: String,
+    pub scroll_x: f64,
+    pub scroll_y: f64,
+    pub scroll_width: f64,
+    pub scroll_height: f64,
+    pub client_width: f64,
+    pub client_height: f64,
+    pub is_at_bottom: bool,
+}
+
+/// Layout inspection: box model, flexbox and grid, stacking and visibility.
+#[async_trait]
+pub trait LayoutApi: BridgeClientTrait {
+    async fn parent_chain(
+        &self,
+        selector: &str,
+        depth: Option<u32>,
+    ) -> Result<ParentChainResponse> {
+        let body = serde_json::to_value(ParentChainRequest {
+            selector: selector.to_string(),
+            depth,
+        })?;
+        decode(
+            self.request(Method::POST, "/inspect/parent", Some(body))
+                .await?,
+        )
+    }
+
+    async fn stacking_contexts(&self, selector: &str) -> Result<StackingResponse> {
+        let body = serde_json::to_value(StackingInspectRequest {
+            selector: selector.to_string(),
+        })?;
+        decode(
+            self.request(Method::POST, "/inspect/stacking", Some(body))
+                .await?,
+        )
+    }
+
+    async fn inspect_layout(&self, selector: &str) -> Result<LayoutInspectResponse> {
+        let body = serde_json::to_value(LayoutInspectRequest {
+            selector: selector.to_string(),
+        })?;
+        decode(
+            self.request(Method::POST, "/inspect/layout", Some(body))
+                .await?,
+        )
+    }
+
+    async fn computed_layout(&self, selector: &str) -> Result<ComputedLayoutResponse> {
+        let body = serde_json::to_value(LayoutInspectRequest {
+            selector: selector.to_string(),
+        })?;
+        decode(
+            self.request(Method::POST, "/computed-layout", Some(body))
+                .await?,
+        )
+    }
+
+    async fn inspect(&self, selector: &str, include_listeners: bool) -> Result<EvalResponse> {
+        let body = serde_json::to_value(InspectRequest {
+            selector: selector.to_string(),
+            include_listeners,
+        })?;
+        decode(self.request(Method::POST, "/inspect", Some(body)).await?)
+    }
+
+    async fn scroll_containers(&self) -> Result<ScrollContainersResponse> {
+        decode(self.request(Method::GET, "/scroll/all", None).await?)
+    }
+
+    async fn check_intersection(&self, selector: &str) -> Result<IntersectionResponse> {
+        let path = format!("/intersection?selector={}", urlencoding::encode(selector));
+        decode(self.request(Method::GET, &path, None).await?)
+    }
+}
+
+impl<T: BridgeClientTrait + ?Sized> LayoutApi for T {}
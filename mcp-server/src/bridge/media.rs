@@ -0,0 +1,240 @@
+//! Bridge requests for animations, media, canvases, SVGs and styles.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use super::{decode, BridgeClientTrait};
+
+#[derive(Debug, Serialize)]
+pub struct SvgAnimateRequest {
+    pub selector: String,
+    pub attribute: String,
+    pub from: String,
+    pub to: String,
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AnimationControlRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub action: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MediaControlRequest {
+    pub selector: String,
+    pub action: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CanvasRequest {
+    pub selector: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub save_path: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SvgResponse {
+    pub elements: Vec<SvgElement>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SvgElement {
+    pub selector_path: String,
+    pub tag: String,
+    pub view_box: Option<String>,
+    pub width: Option<String>,
+    pub height: Option<String>,
+    pub preserve_aspect_ratio: Option<String>,
+    pub transform: Option<String>,
+    pub d: Option<String>,
+    pub cx: Option<String>,
+    pub cy: Option<String>,
+    pub r: Option<String>,
+    pub points: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SvgAnimateResponse {
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct InjectCssRequest {
+    pub id: String,
+    pub css: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RemoveCssRequest {
+    pub id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct InjectCssResponse {
+    pub success: bool,
+    pub style_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AnimationEntry {
+    pub id: Option<String>,
+    pub state: String,
+    pub current_time: f64,
+    pub effect_target: String,
+    pub duration_ms: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AnimationsResponse {
+    pub animations: Vec<AnimationEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CanvasResponse {
+    pub data_url: String,
+    pub width: u32,
+    pub height: u32,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MediaTimeRange {
+    pub start: f64,
+    pub end: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MediaElementState {
+    pub element: String,
+    pub src: Option<String>,
+    pub current_time: f64,
+    pub duration: Option<f64>,
+    pub paused: bool,
+    pub muted: bool,
+    pub volume: f64,
+    pub buffered: Vec<MediaTimeRange>,
+    pub ready_state: u8,
+    pub network_state: u8,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MediaResponse {
+    pub media: Vec<MediaElementState>,
+}
+
+/// Animations, media elements, canvases, SVGs and injected CSS.
+#[async_trait]
+pub trait MediaApi: BridgeClientTrait {
+    async fn animations(&self) -> Result<AnimationsResponse> {
+        decode(self.request(Method::GET, "/animations", None).await?)
+    }
+
+    async fn control_animations(
+        &self,
+        id: Option<&str>,
+        action: &str,
+    ) -> Result<AnimationsResponse> {
+        let body = serde_json::to_value(AnimationControlRequest {
+            id: id.map(String::from),
+            action: action.to_string(),
+        })?;
+        decode(
+            self.request(Method::POST, "/animations", Some(body))
+                .await?,
+        )
+    }
+
+    async fn canvas_capture(
+        &self,
+        selector: &str,
+        format: Option<&str>,
+        save_path: Option<&str>,
+    ) -> Result<CanvasResponse> {
+        let body = serde_json::to_value(CanvasRequest {
+            selector: selector.to_string(),
+            format: format.map(String::from),
+            save_path: save_path.map(String::from),
+        })?;
+        decode(self.request(Method::POST, "/canvas", Some(body)).await?)
+    }
+
+    async fn media_state(&self, selector: Option<&str>) -> Result<MediaResponse> {
+        let path = match selector {
+            Some(s) => format!("/media?selector={}", urlencoding::encode(s)),
+            None => "/media".to_string(),
+        };
+        decode(self.request(Method::GET, &path, None).await?)
+    }
+
+    async fn media_control(
+        &self,
+        selector: &str,
+        action: &str,
+        value: Option<f64>,
+    ) -> Result<MediaResponse> {
+        let body = serde_json::to_value(MediaControlRequest {
+            selector: selector.to_string(),
+            action: action.to_string(),
+            value,
+        })?;
+        decode(
+            self.request(Method::POST, "/media/control", Some(body))
+                .await?,
+        )
+    }
+
+    async fn svg_elements(&self) -> Result<SvgResponse> {
+        decode(self.request(Method::GET, "/svg", None).await?)
+    }
+
+    async fn animate_svg(
+        &self,
+        selector: &str,
+        attribute: &str,
+        from: &str,
+        to: &str,
+        duration_ms: u64,
+    ) -> Result<SvgAnimateResponse> {
+        let body = serde_json::to_value(SvgAnimateRequest {
+            selector: selector.to_string(),
+            attribute: attribute.to_string(),
+            from: from.to_string(),
+            to: to.to_string(),
+            duration_ms,
+        })?;
+        decode(
+            self.request(Method::POST, "/svg/animate", Some(body))
+                .await?,
+        )
+    }
+
+    async fn inject_css(&self, id: &str, css: &str) -> Result<InjectCssResponse> {
+        let body = serde_json::to_value(InjectCssRequest {
+            id: id.to_string(),
+            css: css.to_string(),
+        })?;
+        decode(
+            self.request(Method::POST, "/inject-css", Some(body))
+                .await?,
+        )
+    }
+
+    async fn remove_css(&self, id: &str) -> Result<InjectCssResponse> {
+        let body = serde_json::to_value(RemoveCssRequest { id: id.to_string() })?;
+        decode(
+            self.request(Method::POST, "/remove-css", Some(body))
+                .await?,
+        )
+    }
+}
+
+impl<T: BridgeClientTrait + ?Sized> MediaApi for T {}
@@ -0,0 +1,168 @@
+//! Bridge requests for runtime monitoring and bridge settings.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{decode, BridgeClientTrait};
+
+#[derive(Debug, Serialize)]
+pub struct EventWatchRequest {
+    pub selector: String,
+    pub events: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_events: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MutationLogStartRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_entries: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EvalTimeoutRequest {
+    pub timeout_ms: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SignalsResponse {
+    pub signals: HashMap<String, Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JsError {
+    pub message: String,
+    pub source: Option<String>,
+    pub line: Option<u32>,
+    pub col: Option<u32>,
+    pub stack: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JsErrorsResponse {
+    pub errors: Vec<JsError>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MutationEntry {
+    pub kind: String,
+    pub target_selector: String,
+    pub added_nodes: u32,
+    pub removed_nodes: u32,
+    pub attribute_name: Option<String>,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MutationSummaryResponse {
+    pub added_nodes: u32,
+    pub removed_nodes: u32,
+    pub attribute_changes: u32,
+    pub text_changes: u32,
+    pub entries: Vec<MutationEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BridgeConfigResponse {
+    pub app_name: String,
+    pub default_eval_timeout_ms: Option<u64>,
+    pub backpressure: String,
+    pub backpressure_queue_max: Option<usize>,
+    pub max_history: usize,
+    pub auth_required: bool,
+    pub rate_limit: Option<u32>,
+    pub startup_scripts: usize,
+    pub log_eval_scripts: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EventWatchResponse {
+    pub matched_elements: u32,
+    pub watched_selectors: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DomEvent {
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub target_selector: String,
+    pub timestamp_ms: u64,
+    pub detail: Value,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EventLogResponse {
+    pub events: Vec<DomEvent>,
+}
+
+/// JS errors, DOM events, mutations, signals and bridge settings.
+#[async_trait]
+pub trait MonitoringApi: BridgeClientTrait {
+    async fn js_errors(&self, clear: bool) -> Result<JsErrorsResponse> {
+        let path = if clear {
+            "/errors"
+        } else {
+            "/errors?clear=false"
+        };
+        decode(self.request(Method::GET, path, None).await?)
+    }
+
+    async fn watch_events(&self, req: EventWatchRequest) -> Result<EventWatchResponse> {
+        let body = serde_json::to_value(req)?;
+        decode(
+            self.request(Method::POST, "/event-log/watch", Some(body))
+                .await?,
+        )
+    }
+
+    async fn event_log(&self, clear: bool) -> Result<EventLogResponse> {
+        let path = if clear {
+            "/event-log"
+        } else {
+            "/event-log?clear=false"
+        };
+        decode(self.request(Method::GET, path, None).await?)
+    }
+
+    async fn start_mutation_log(&self, max_entries: Option<u32>) -> Result<()> {
+        let body = serde_json::to_value(MutationLogStartRequest { max_entries })?;
+        self.request(Method::POST, "/mutation-log/start", Some(body))
+            .await?;
+        Ok(())
+    }
+
+    async fn mutation_summary(&self) -> Result<MutationSummaryResponse> {
+        decode(self.request(Method::GET, "/mutation-log", None).await?)
+    }
+
+    async fn stop_mutation_log(&self) -> Result<()> {
+        self.request(Method::DELETE, "/mutation-log", None).await?;
+        Ok(())
+    }
+
+    async fn bridge_config(&self) -> Result<BridgeConfigResponse> {
+        decode(self.request(Method::GET, "/config", None).await?)
+    }
+
+    /// Set how long the bridge waits for evals without their own timeout;
+    /// `0` waits indefinitely.
+    async fn set_default_timeout(&self, timeout_ms: u64) -> Result<BridgeConfigResponse> {
+        let body = serde_json::to_value(EvalTimeoutRequest { timeout_ms })?;
+        decode(
+            self.request(Method::PUT, "/config/eval-timeout", Some(body))
+                .await?,
+        )
+    }
+
+    async fn signals(&self) -> Result<SignalsResponse> {
+        decode(self.request(Method::GET, "/signals", None).await?)
+    }
+}
+
+impl<T: BridgeClientTrait + ?Sized> MonitoringApi for T {}
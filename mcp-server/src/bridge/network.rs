@@ -0,0 +1,82 @@
+//! Bridge requests for network inspection and simulation.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use super::{decode, BridgeClientTrait};
+
+#[derive(Debug, Serialize)]
+pub struct GeoMockRequest {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub accuracy: f64,
+    pub error_code: Option<u16>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NetworkCondition {
+    Offline,
+    #[serde(rename = "slow_3g")]
+    Slow3G {
+        latency_ms: u64,
+        bandwidth_kbps: u64,
+    },
+    Normal,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NetworkSimRequest {
+    pub condition: NetworkCondition,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NetworkRequest {
+    pub url: String,
+    pub method: String,
+    pub status: Option<u16>,
+    pub duration_ms: f64,
+    pub request_body: Option<String>,
+    pub response_preview: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NetworkResponse {
+    pub requests: Vec<NetworkRequest>,
+}
+
+/// The request log, network simulation and geolocation mocking.
+#[async_trait]
+pub trait NetworkApi: BridgeClientTrait {
+    async fn network(&self) -> Result<NetworkResponse> {
+        decode(self.request(Method::GET, "/network", None).await?)
+    }
+
+    async fn mock_geolocation(&self, mock: GeoMockRequest) -> Result<()> {
+        let body = serde_json::to_value(mock)?;
+        self.request(Method::POST, "/geo/mock", Some(body)).await?;
+        Ok(())
+    }
+
+    async fn clear_geolocation_mock(&self) -> Result<()> {
+        self.request(Method::DELETE, "/geo/mock", None).await?;
+        Ok(())
+    }
+
+    async fn simulate_network(&self, condition: NetworkCondition) -> Result<()> {
+        let body = serde_json::to_value(NetworkSimRequest { condition })?;
+        self.request(Method::POST, "/simulate-network", Some(body))
+            .await?;
+        Ok(())
+    }
+
+    async fn clear_network_simulation(&self) -> Result<()> {
+        self.request(Method::DELETE, "/simulate-network", None)
+            .await?;
+        Ok(())
+    }
+}
+
+impl<T: BridgeClientTrait + ?Sized> NetworkApi for T {}
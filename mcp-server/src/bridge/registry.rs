@@ -0,0 +1,169 @@
+//! Bridges of a multi-window app.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+
+use super::BridgeClient;
+
+/// Window name of the only bridge when `DIOXUS_BRIDGES` isn't set.
+pub const DEFAULT_WINDOW: &str = "default";
+
+/// Bridges of a multi-window app, one per window, keyed by window name.
+pub struct BridgeRegistry {
+    bridges: HashMap<String, BridgeClient>,
+    /// Window used when a tool call doesn't name one: the first configured.
+    default_window: String,
+}
+
+impl BridgeRegistry {
+    /// A registry with a single bridge, named [`DEFAULT_WINDOW`].
+    pub fn single(client: BridgeClient) -> Self {
+        Self {
+            bridges: HashMap::from([(DEFAULT_WINDOW.to_string(), client)]),
+            default_window: DEFAULT_WINDOW.to_string(),
+        }
+    }
+
+    /// A registry of named bridges; the first one is the default.
+    pub fn new(windows: Vec<(String, BridgeClient)>) -> Result<Self> {
+        let default_window = windows
+            .first()
+            .map(|(name, _)| name.clone())
+            .ok_or_else(|| anyhow!("No bridges configured"))?;
+        let mut bridges = HashMap::new();
+        for (name, client) in windows {
+            if bridges.insert(name.clone(), client).is_some() {
+                return Err(anyhow!("Window '{}' is configured twice", name));
+            }
+        }
+        Ok(Self {
+            bridges,
+            default_window,
+        })
+    }
+
+    /// The bridge of `window`, or of the default window for `None`.
+    pub fn get(&self, window: Option<&str>) -> Result<&BridgeClient> {
+        let name = window.unwrap_or(&self.default_window);
+        self.bridges.get(name).ok_or_else(|| {
+            anyhow!(
+                "Unknown window '{}' (available: {})",
+                name,
+                self.windows().join(", ")
+            )
+        })
+    }
+
+    pub fn default_bridge(&self) -> &BridgeClient {
+        &self.bridges[&self.default_window]
+    }
+
+    /// Window names, sorted.
+    pub fn windows(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.bridges.keys().map(|n| n.as_str()).collect();
+        names.sort_unstable();
+        names
+    }
+
+    pub fn default_window(&self) -> &str {
+        &self.default_window
+    }
+}
+
+/// Parse `DIOXUS_BRIDGES`, e.g. `main=9999,settings=10000`, into window names
+/// and bridge URLs. A bare port means `http://127.0.0.1:{port}`; anything
+/// else is used as the URL.
+pub fn parse_bridges(spec: &str) -> Result<Vec<(String, String)>> {
+    spec.split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (name, target) = entry
+                .split_once('=')
+                .map(|(name, target)| (name.trim(), target.trim()))
+                .filter(|(name, target)| !name.is_empty() && !target.is_empty())
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Invalid DIOXUS_BRIDGES entry '{}', expected name=port",
+                        entry
+                    )
+                })?;
+            let url = match target.parse::<u16>() {
+                Ok(port) => format!("http://127.0.0.1:{}", port),
+                Err(_) => target.to_string(),
+            };
+            Ok((name.to_string(), url))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bridges() {
+        let bridges =
+            parse_bridges("main=9999, settings = 10000,remote=https://10.0.0.2:9999,").unwrap();
+        assert_eq!(
+            bridges,
+            vec![
+                ("main".to_string(), "http://127.0.0.1:9999".to_string()),
+                ("settings".to_string(), "http://127.0.0.1:10000".to_string()),
+                ("remote".to_string(), "https://10.0.0.2:9999".to_string()),
+            ]
+        );
+        assert!(parse_bridges("main").is_err());
+        assert!(parse_bridges("=9999").is_err());
+    }
+
+    #[test]
+    fn test_registry_routes_by_window() {
+        let registry = BridgeRegistry::new(vec![
+            (
+                "main".to_string(),
+                BridgeClient::new("http://127.0.0.1:9999"),
+            ),
+            (
+                "settings".to_string(),
+                BridgeClient::new("http://127.0.0.1:10000"),
+            ),
+        ])
+        .unwrap();
+
+        assert_eq!(registry.default_window(), "main");
+        assert_eq!(registry.windows(), vec!["main", "settings"]);
+        assert_eq!(
+            registry.get(None).unwrap().base_url(),
+            "http://127.0.0.1:9999"
+        );
+        assert_eq!(
+            registry.get(Some("settings")).unwrap().base_url(),
+            "http://127.0.0.1:10000"
+        );
+        let err = registry.get(Some("about")).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Unknown window 'about' (available: main, settings)"
+        );
+    }
+
+    #[test]
+    fn test_registry_rejects_duplicate_windows() {
+        let err = BridgeRegistry::new(vec![
+            (
+                "main".to_string(),
+                BridgeClient::new("http://127.0.0.1:9999"),
+            ),
+            (
+                "main".to_string(),
+                BridgeClient::new("http://127.0.0.1:10000"),
+            ),
+        ])
+        .err()
+        .unwrap();
+        assert_eq!(err.to_string(), "Window 'main' is configured twice");
+        assert!(BridgeRegistry::new(vec![]).is_err());
+    }
+}
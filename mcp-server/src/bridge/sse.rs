@@ -0,0 +1,135 @@
+//! Parsing and decoding of the bridge's server-sent event streams.
+
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+
+use super::{EvalResponse, SignalChange};
+
+/// One server-sent event from a streaming bridge endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct SseEvent {
+    pub event: String,
+    pub data: String,
+}
+
+/// Splits a server-sent event stream into events as bytes arrive.
+#[derive(Default)]
+pub(super) struct SseParser {
+    buffer: Vec<u8>,
+    pub(super) events: Vec<SseEvent>,
+}
+
+impl SseParser {
+    pub(super) fn push(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+        // Only whole events are decoded, so UTF-8 split across chunks survives
+        while let Some(end) = self.buffer.windows(2).position(|w| w == b"\n\n") {
+            let block: Vec<u8> = self.buffer.drain(..end + 2).collect();
+            let block = String::from_utf8_lossy(&block);
+            let mut event = "message".to_string();
+            let mut data: Vec<&str> = Vec::new();
+            for line in block.lines() {
+                // Lines starting with `:` are comments, e.g. keep-alives
+                if let Some(value) = line.strip_prefix("event:") {
+                    event = value.trim_start().to_string();
+                } else if let Some(value) = line.strip_prefix("data:") {
+                    data.push(value.strip_prefix(' ').unwrap_or(value));
+                }
+            }
+            if !data.is_empty() {
+                self.events.push(SseEvent {
+                    event,
+                    data: data.join("\n"),
+                });
+            }
+        }
+    }
+}
+
+/// Decode a `result` event of `/eval/stream`.
+pub(super) fn decode_eval_event(event: SseEvent) -> Result<EvalResponse> {
+    serde_json::from_str(&event.data)
+        .map_err(|e| anyhow!("Invalid {} event from eval stream: {}", event.event, e))
+}
+
+/// Decode an event of `/signals/watch`.
+pub(super) fn decode_signal_event(event: SseEvent) -> Result<SignalChange> {
+    match event.event.as_str() {
+        "error" => Err(anyhow!("Signal watch failed: {}", event.data)),
+        _ => serde_json::from_str(&event.data)
+            .map_err(|e| anyhow!("Invalid {} event from signal watch: {}", event.event, e)),
+    }
+}
+
+/// `/signals/watch` for `names`, or for every signal when `names` is empty.
+pub(super) fn signal_watch_path(names: &[String], interval_ms: Option<u64>) -> String {
+    let mut params = Vec::new();
+    if !names.is_empty() {
+        params.push(format!("names={}", urlencoding::encode(&names.join(","))));
+    }
+    if let Some(interval_ms) = interval_ms {
+        params.push(format!("interval_ms={}", interval_ms));
+    }
+    if params.is_empty() {
+        "/signals/watch".to_string()
+    } else {
+        format!("/signals/watch?{}", params.join("&"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sse_parser() {
+        let mut parser = SseParser::default();
+        parser.push(b"event: storage\ndata: {\"key\":\"th");
+        assert!(parser.events.is_empty());
+        // A multi-byte character split across chunks
+        parser.push(&[0xc3]);
+        parser.push(&[0xa9]);
+        parser.push(b"\"}\n\n:\n\ndata: a\ndata: b\n\n");
+        assert_eq!(
+            parser.events,
+            vec![
+                SseEvent {
+                    event: "storage".to_string(),
+                    data: "{\"key\":\"th\u{e9}\"}".to_string(),
+                },
+                SseEvent {
+                    event: "message".to_string(),
+                    data: "a\nb".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_signal_watch_path() {
+        assert_eq!(signal_watch_path(&[], None), "/signals/watch");
+        let names = vec!["counter".to_string(), "message".to_string()];
+        assert_eq!(
+            signal_watch_path(&names, Some(250)),
+            "/signals/watch?names=counter%2Cmessage&interval_ms=250"
+        );
+    }
+
+    #[test]
+    fn test_decode_signal_event() {
+        let change = decode_signal_event(SseEvent {
+            event: "signal".to_string(),
+            data: r#"{"name":"counter","old_value":1,"new_value":2}"#.to_string(),
+        })
+        .unwrap();
+        assert_eq!(change.name, "counter");
+        assert_eq!(change.new_value, Some(serde_json::json!(2)));
+
+        let err = decode_signal_event(SseEvent {
+            event: "error".to_string(),
+            data: "Eval timeout".to_string(),
+        })
+        .unwrap_err();
+        assert_eq!(err.to_string(), "Signal watch failed: Eval timeout");
+    }
+}
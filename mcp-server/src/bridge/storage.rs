@@ -0,0 +1,89 @@
+//! Bridge requests for the clipboard and browser storage.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{decode, BridgeClientTrait};
+
+#[derive(Debug, Serialize)]
+pub struct SetClipboardRequest {
+    pub text: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct IndexedDbRequest {
+    pub database: String,
+    pub store: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ClipboardResponse {
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StorageEvent {
+    pub key: Option<String>,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub url: String,
+    pub timestamp_ms: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IndexedDbResponse {
+    pub records: Vec<Value>,
+    pub error: Option<String>,
+}
+
+/// The clipboard, IndexedDB and web storage.
+#[async_trait]
+pub trait StorageApi: BridgeClientTrait {
+    async fn clipboard_read(&self) -> Result<ClipboardResponse> {
+        decode(self.request(Method::GET, "/clipboard", None).await?)
+    }
+
+    async fn clipboard_write(&self, text: &str) -> Result<ClipboardResponse> {
+        let body = serde_json::to_value(SetClipboardRequest {
+            text: text.to_string(),
+        })?;
+        decode(self.request(Method::POST, "/clipboard", Some(body)).await?)
+    }
+
+    async fn indexeddb_read(
+        &self,
+        database: &str,
+        store: &str,
+        key: Option<&str>,
+    ) -> Result<IndexedDbResponse> {
+        let body = serde_json::to_value(IndexedDbRequest {
+            database: database.to_string(),
+            store: store.to_string(),
+            key: key.map(String::from),
+        })?;
+        decode(self.request(Method::POST, "/indexeddb", Some(body)).await?)
+    }
+
+    /// `localStorage` changes made during the next `duration_ms`.
+    async fn watch_storage(&self, duration_ms: u64) -> Result<Vec<StorageEvent>> {
+        let events = self
+            .stream_events("/storage/watch", Duration::from_millis(duration_ms))
+            .await?;
+        events
+            .into_iter()
+            .map(|event| match event.event.as_str() {
+                "error" => Err(anyhow!("Storage watch failed: {}", event.data)),
+                _ => Ok(serde_json::from_str(&event.data)?),
+            })
+            .collect()
+    }
+}
+
+impl<T: BridgeClientTrait + ?Sized> StorageApi for T {}
@@ -0,0 +1,382 @@
+//! Bridge requests for the window, viewport and page display.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{decode, BridgeClientTrait};
+
+#[derive(Debug, Serialize)]
+pub struct ScreenshotRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<ScreenshotFormat>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum ScreenshotFormat {
+    Png,
+    Jpeg { quality: u8 },
+    WebP,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ResizeRequest {
+    pub width: u32,
+    pub height: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anchor: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MoveRequest {
+    pub x: i32,
+    pub y: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monitor: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReloadRequest {
+    pub soft: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SetDarkModeRequest {
+    pub dark: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ZoomRequest {
+    pub level: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SetViewportRequest {
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewportSize {
+    pub name: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ResponsiveTestRequest {
+    pub sizes: Vec<ViewportSize>,
+    pub screenshot: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BreakpointStep {
+    pub name: String,
+    pub width: u32,
+    pub height: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delay_ms: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BreakpointSimRequest {
+    pub breakpoints: Vec<BreakpointStep>,
+    pub screenshot: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ScreenshotResponse {
+    pub success: bool,
+    pub path: Option<String>,
+    pub mime_type: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResizeResponse {
+    pub success: bool,
+    pub width: u32,
+    pub height: u32,
+    pub anchor: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MoveResponse {
+    pub success: bool,
+    pub x: i32,
+    pub y: i32,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HotReloadResponse {
+    pub triggered: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReloadResponse {
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DarkModeResponse {
+    pub system_dark: bool,
+    pub document_dark: bool,
+    pub data_theme: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ZoomResponse {
+    pub success: bool,
+    pub level: f64,
+    pub device_pixel_ratio: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ViewportResponse {
+    pub inner_width: u32,
+    pub inner_height: u32,
+    pub outer_width: u32,
+    pub outer_height: u32,
+    pub device_pixel_ratio: f64,
+    pub orientation: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WindowStateResponse {
+    pub title: String,
+    pub url: String,
+    pub ready_state: String,
+    pub visibility_state: String,
+    pub has_focus: bool,
+    pub scroll_x: f64,
+    pub scroll_y: f64,
+    pub inner_width: u32,
+    pub inner_height: u32,
+    pub outer_width: u32,
+    pub outer_height: u32,
+    pub screen_x: i32,
+    pub screen_y: i32,
+    pub device_pixel_ratio: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResponsiveResult {
+    pub name: String,
+    pub size: String,
+    pub screenshot_path: Option<String>,
+    pub dom: Option<Value>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BreakpointResult {
+    pub name: String,
+    pub size: String,
+    pub viewport: Option<ViewportResponse>,
+    pub dom_changed: bool,
+    pub screenshot_path: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BreakpointSimResponse {
+    pub results: Vec<BreakpointResult>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResponsiveTestResponse {
+    pub results: Vec<ResponsiveResult>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MediaQueryResponse {
+    pub matches: bool,
+    pub query: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MediaQueryAllResponse {
+    pub matches: HashMap<String, bool>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TranslateRequest {
+    pub replacements: HashMap<String, String>,
+    pub selector: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TranslateResponse {
+    pub replaced_count: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TranslateRestoreResponse {
+    pub restored_count: u32,
+}
+
+/// Screenshots, window size and position, reloads, and how the page adapts.
+#[async_trait]
+pub trait ViewportApi: BridgeClientTrait {
+    async fn screenshot(&self, path: Option<&str>) -> Result<ScreenshotResponse> {
+        self.screenshot_with_format(path, None).await
+    }
+
+    async fn screenshot_with_format(
+        &self,
+        path: Option<&str>,
+        format: Option<ScreenshotFormat>,
+    ) -> Result<ScreenshotResponse> {
+        let body = serde_json::to_value(ScreenshotRequest {
+            path: path.map(String::from),
+            format,
+        })?;
+        decode(
+            self.request(Method::POST, "/screenshot", Some(body))
+                .await?,
+        )
+    }
+
+    async fn resize(
+        &self,
+        width: u32,
+        height: u32,
+        anchor: Option<&str>,
+    ) -> Result<ResizeResponse> {
+        let body = serde_json::to_value(ResizeRequest {
+            width,
+            height,
+            anchor: anchor.map(String::from),
+        })?;
+        decode(self.request(Method::POST, "/resize", Some(body)).await?)
+    }
+
+    async fn move_window(&self, x: i32, y: i32, monitor: Option<u32>) -> Result<MoveResponse> {
+        let body = serde_json::to_value(MoveRequest { x, y, monitor })?;
+        decode(self.request(Method::POST, "/move", Some(body)).await?)
+    }
+
+    async fn hot_reload(&self) -> Result<HotReloadResponse> {
+        decode(self.request(Method::POST, "/reload/hot", None).await?)
+    }
+
+    async fn reload(&self, soft: bool) -> Result<ReloadResponse> {
+        let body = serde_json::to_value(ReloadRequest { soft })?;
+        decode(self.request(Method::POST, "/reload", Some(body)).await?)
+    }
+
+    async fn get_dark_mode(&self) -> Result<DarkModeResponse> {
+        decode(self.request(Method::GET, "/dark-mode", None).await?)
+    }
+
+    async fn set_dark_mode(&self, dark: bool) -> Result<DarkModeResponse> {
+        let body = serde_json::to_value(SetDarkModeRequest { dark })?;
+        decode(self.request(Method::POST, "/dark-mode", Some(body)).await?)
+    }
+
+    async fn get_zoom(&self) -> Result<ZoomResponse> {
+        decode(self.request(Method::GET, "/zoom", None).await?)
+    }
+
+    async fn set_zoom(&self, level: f64) -> Result<ZoomResponse> {
+        let body = serde_json::to_value(ZoomRequest { level })?;
+        decode(self.request(Method::POST, "/zoom", Some(body)).await?)
+    }
+
+    async fn get_viewport(&self) -> Result<ViewportResponse> {
+        decode(self.request(Method::GET, "/viewport", None).await?)
+    }
+
+    async fn window_state(&self) -> Result<WindowStateResponse> {
+        decode(self.request(Method::GET, "/window/state", None).await?)
+    }
+
+    async fn set_viewport(&self, width: u32, height: u32) -> Result<ViewportResponse> {
+        let body = serde_json::to_value(SetViewportRequest { width, height })?;
+        decode(self.request(Method::POST, "/viewport", Some(body)).await?)
+    }
+
+    async fn media_query(&self, query: &str) -> Result<MediaQueryResponse> {
+        let path = format!("/media-query?query={}", urlencoding::encode(query));
+        decode(self.request(Method::GET, &path, None).await?)
+    }
+
+    async fn media_query_all(&self) -> Result<MediaQueryAllResponse> {
+        decode(self.request(Method::GET, "/media-query/all", None).await?)
+    }
+
+    async fn translate(
+        &self,
+        replacements: HashMap<String, String>,
+        selector: Option<&str>,
+    ) -> Result<TranslateResponse> {
+        let body = serde_json::to_value(TranslateRequest {
+            replacements,
+            selector: selector.map(String::from),
+        })?;
+        decode(self.request(Method::POST, "/translate", Some(body)).await?)
+    }
+
+    async fn restore_translation(&self) -> Result<TranslateRestoreResponse> {
+        decode(
+            self.request(Method::POST, "/translate/restore", None)
+                .await?,
+        )
+    }
+
+    async fn responsive_test(
+        &self,
+        sizes: Vec<ViewportSize>,
+        screenshot: bool,
+    ) -> Result<ResponsiveTestResponse> {
+        let body = serde_json::to_value(ResponsiveTestRequest { sizes, screenshot })?;
+        decode(
+            self.request(Method::POST, "/responsive", Some(body))
+                .await?,
+        )
+    }
+
+    async fn simulate_breakpoints(
+        &self,
+        breakpoints: Vec<BreakpointStep>,
+        screenshot: bool,
+    ) -> Result<BreakpointSimResponse> {
+        let body = serde_json::to_value(BreakpointSimRequest {
+            breakpoints,
+            screenshot,
+        })?;
+        decode(
+            self.request(Method::POST, "/breakpoints/simulate", Some(body))
+                .await?,
+        )
+    }
+}
+
+impl<T: BridgeClientTrait + ?Sized> ViewportApi for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resize_request_serialize() {
+        let req = ResizeRequest {
+            width: 800,
+            height: 600,
+            anchor: None,
+        };
+        let json = serde_json::to_string(&req).unwrap();
+        assert!(json.contains("800"));
+        assert!(json.contains("600"));
+    }
+}
@@ -0,0 +1,90 @@
+//! Find a bridge through the lock file it writes on startup.
+//!
+//! Bridges write `{temp_dir}/dioxus-inspector.lock` with their URL once they
+//! have bound a port (see `dioxus_inspector::lock_file_path`), which is the
+//! only way to reach one started on port 0.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+/// `DIOXUS_BRIDGE_URL` value that asks for discovery through the lock file.
+pub const AUTO_URL: &str = "http://auto";
+
+#[derive(Debug, Deserialize)]
+struct BridgeLock {
+    url: String,
+    app_name: String,
+    pid: u32,
+}
+
+pub fn lock_file_path() -> PathBuf {
+    std::env::temp_dir().join("dioxus-inspector.lock")
+}
+
+/// The lock file can't be read, most likely because the app hasn't started.
+#[derive(Debug)]
+pub struct NoBridgeFound {
+    path: PathBuf,
+    source: std::io::Error,
+}
+
+impl std::fmt::Display for NoBridgeFound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "No running bridge found ({}: {}); start the app first",
+            self.path.display(),
+            self.source
+        )
+    }
+}
+
+impl std::error::Error for NoBridgeFound {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Read the URL of the most recently started bridge from `path`.
+pub fn discover_bridge_url(path: &Path) -> Result<String> {
+    let json = std::fs::read_to_string(path).map_err(|source| NoBridgeFound {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let lock: BridgeLock = serde_json::from_str(&json)
+        .with_context(|| format!("Invalid bridge lock file {}", path.display()))?;
+    tracing::info!(
+        "Discovered bridge for '{}' (pid {}) at {}",
+        lock.app_name,
+        lock.pid,
+        lock.url
+    );
+    Ok(lock.url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discover_bridge_url() {
+        let path =
+            std::env::temp_dir().join(format!("dioxus-mcp-discovery-{}.lock", std::process::id()));
+        std::fs::write(
+            &path,
+            r#"{"url": "http://127.0.0.1:49152", "app_name": "demo", "pid": 42}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            discover_bridge_url(&path).unwrap(),
+            "http://127.0.0.1:49152"
+        );
+
+        let _ = std::fs::remove_file(&path);
+        let err = discover_bridge_url(&path).unwrap_err();
+        assert!(err.to_string().starts_with("No running bridge found"));
+    }
+}
@@ -1,7 +1,8 @@
 //! Dioxus MCP Server
 //!
 //! Connects to a running Dioxus app's inspector bridge and exposes
-//! MCP tools for DOM inspection and interaction.
+//! MCP tools for DOM inspection and interaction, plus the current DOM as a
+//! readable resource.
 //!
 //! Configure in Claude Code:
 //! ```json
@@ -14,6 +15,17 @@
 //!   }
 //! }
 //! ```
+//!
+//! Set `DIOXUS_BRIDGE_URL` to `http://auto` to connect to the most recently
+//! started bridge instead, e.g. one listening on a port picked by the OS. It
+//! is looked up on the first tool call, so the app may start after this
+//! server.
+//! For a bridge listening on a Unix socket, use
+//! `unix:///tmp/dioxus-inspector.sock`.
+//!
+//! Multi-window apps with one bridge per window can list them all in
+//! `DIOXUS_BRIDGES`, e.g. `main=9999,settings=10000`; tools then take an
+//! optional `window` argument, defaulting to the first one.
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
@@ -21,9 +33,13 @@ use serde_json::{json, Value};
 use std::io::{BufRead, Write};
 
 mod bridge;
+mod discovery;
+#[cfg(test)]
+mod mock_bridge;
+mod resources;
 mod tools;
 
-use bridge::BridgeClient;
+use bridge::{BridgeClient, BridgeRegistry, RetryConfig};
 
 #[derive(Debug, Deserialize)]
 struct JsonRpcRequest {
@@ -77,7 +93,7 @@ impl JsonRpcResponse {
 fn server_info() -> Value {
     json!({
         "protocolVersion": "2024-11-05",
-        "capabilities": { "tools": {} },
+        "capabilities": { "tools": {}, "resources": {} },
         "serverInfo": {
             "name": "dioxus-mcp",
             "version": env!("CARGO_PKG_VERSION")
@@ -86,103 +102,24 @@ fn server_info() -> Value {
 }
 
 fn tools_list() -> Value {
-    json!({
-        "tools": [
-            tool_def("status", "Check if the Dioxus app is running", json!({})),
-            tool_def_optional(
-                "get_dom",
-                "Get simplified DOM tree (limited to prevent token overflow)",
-                json!({
-                    "depth": { "type": "number", "description": "Max depth to traverse (default: 10)" },
-                    "max_nodes": { "type": "number", "description": "Max nodes to return (default: 500)" },
-                    "selector": { "type": "string", "description": "CSS selector for root element (default: body)" }
-                }),
-                vec![]
-            ),
-            tool_def("query_text", "Get element text by CSS selector", json!({
-                "selector": { "type": "string", "description": "CSS selector" }
-            })),
-            tool_def("query_html", "Get element innerHTML by CSS selector", json!({
-                "selector": { "type": "string", "description": "CSS selector" }
-            })),
-            tool_def("query_all", "List all elements matching a selector", json!({
-                "selector": { "type": "string", "description": "CSS selector" }
-            })),
-            tool_def("click", "Click an element by CSS selector", json!({
-                "selector": { "type": "string", "description": "CSS selector" }
-            })),
-            tool_def("type_text", "Type text into an input", json!({
-                "selector": { "type": "string", "description": "CSS selector" },
-                "text": { "type": "string", "description": "Text to type" }
-            })),
-            tool_def("eval", "Execute JavaScript in the webview", json!({
-                "script": { "type": "string", "description": "JavaScript code" }
-            })),
-            tool_def("inspect", "Analyze element visibility", json!({
-                "selector": { "type": "string", "description": "CSS selector" }
-            })),
-            tool_def("diagnose", "Quick UI health check", json!({})),
-            tool_def("screenshot", "Capture window screenshot", json!({
-                "path": { "type": "string", "description": "Output path (optional)" }
-            })),
-            tool_def("resize", "Resize the window", json!({
-                "width": { "type": "number", "description": "Window width in pixels" },
-                "height": { "type": "number", "description": "Window height in pixels" }
-            })),
-            tool_def_optional(
-                "dom_to_rsx",
-                "Convert DOM HTML to Dioxus RSX code using dx translate",
-                json!({
-                    "selector": { "type": "string", "description": "CSS selector to get HTML from" },
-                    "html": { "type": "string", "description": "Raw HTML string to convert" }
-                }),
-                vec![]  // Neither required - one or the other
-            ),
-            tool_def("doctor", "Run dx doctor to diagnose Dioxus installation and tools", json!({})),
-            tool_def_optional(
-                "check",
-                "Run dx check to find RSX and component issues in the project",
-                json!({
-                    "path": { "type": "string", "description": "Project path (defaults to current directory)" }
-                }),
-                vec![]
-            )
-        ]
-    })
-}
-
-fn tool_def(name: &str, description: &str, properties: Value) -> Value {
-    let required: Vec<&str> = properties
-        .as_object()
-        .map(|obj| obj.keys().map(|k| k.as_str()).collect())
-        .unwrap_or_default();
-
-    json!({
-        "name": name,
-        "description": description,
-        "inputSchema": {
-            "type": "object",
-            "properties": properties,
-            "required": required
-        }
-    })
+    let mut list = json!({ "tools": tools::definitions() });
+    add_window_arg(&mut list);
+    list
 }
 
-fn tool_def_optional(
-    name: &str,
-    description: &str,
-    properties: Value,
-    required: Vec<&str>,
-) -> Value {
-    json!({
-        "name": name,
-        "description": description,
-        "inputSchema": {
-            "type": "object",
-            "properties": properties,
-            "required": required
+/// Give every bridge tool an optional `window` argument.
+fn add_window_arg(list: &mut Value) {
+    let Some(tools) = list["tools"].as_array_mut() else {
+        return;
+    };
+    for tool in tools.iter_mut().filter(|t| t["name"] != "list_windows") {
+        if let Some(properties) = tool["inputSchema"]["properties"].as_object_mut() {
+            properties.insert(
+                "window".to_string(),
+                json!({ "type": "string", "description": "Window name from DIOXUS_BRIDGES (default: the first one)" }),
+            );
         }
-    })
+    }
 }
 
 #[tokio::main]
@@ -195,12 +132,44 @@ async fn main() -> Result<()> {
         .with_writer(std::io::stderr)
         .init();
 
-    let bridge_url =
-        std::env::var("DIOXUS_BRIDGE_URL").unwrap_or_else(|_| "http://127.0.0.1:9999".to_string());
-
-    tracing::info!("Dioxus MCP server starting, bridge: {}", bridge_url);
+    // For HTTPS bridges with self-signed certificates
+    let accept_invalid_certs =
+        std::env::var("DIOXUS_BRIDGE_INSECURE").is_ok_and(|v| matches!(v.as_str(), "1" | "true"));
+    let token = std::env::var("DIOXUS_BRIDGE_TOKEN").ok();
+    let connect = |url: &str| {
+        // Ride out short gaps, e.g. the app starting just after this server
+        let bridge =
+            BridgeClient::with_options(url, accept_invalid_certs).with_retry(RetryConfig {
+                max_attempts: 3,
+                initial_delay_ms: 100,
+                backoff_factor: 2.0,
+            });
+        match &token {
+            Some(token) => bridge.with_auth_token(token.clone()),
+            None => bridge,
+        }
+    };
 
-    let bridge = BridgeClient::new(&bridge_url);
+    let registry = match std::env::var("DIOXUS_BRIDGES") {
+        Ok(spec) => {
+            let windows = bridge::parse_bridges(&spec)?;
+            for (name, url) in &windows {
+                tracing::info!("Window '{}': bridge {}", name, url);
+            }
+            BridgeRegistry::new(
+                windows
+                    .into_iter()
+                    .map(|(name, url)| (name, connect(&url)))
+                    .collect(),
+            )?
+        }
+        Err(_) => {
+            let bridge_url = std::env::var("DIOXUS_BRIDGE_URL")
+                .unwrap_or_else(|_| "http://127.0.0.1:9999".to_string());
+            tracing::info!("Dioxus MCP server starting, bridge: {}", bridge_url);
+            BridgeRegistry::single(connect(&bridge_url))
+        }
+    };
     let stdin = std::io::stdin();
     let mut stdout = std::io::stdout();
 
@@ -227,7 +196,7 @@ async fn main() -> Result<()> {
             }
         };
 
-        let response = handle_request(&bridge, request).await;
+        let response = handle_request(&registry, request).await;
         let response_json = serde_json::to_string(&response)?;
 
         tracing::debug!("Sending: {}", response_json);
@@ -238,7 +207,7 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
-async fn handle_request(bridge: &BridgeClient, request: JsonRpcRequest) -> JsonRpcResponse {
+async fn handle_request(registry: &BridgeRegistry, request: JsonRpcRequest) -> JsonRpcResponse {
     let id = request.id.clone().unwrap_or(Value::Null);
 
     match request.method.as_str() {
@@ -250,7 +219,7 @@ async fn handle_request(bridge: &BridgeClient, request: JsonRpcRequest) -> JsonR
             let tool_name = params.get("name").and_then(|v| v.as_str()).unwrap_or("");
             let arguments = params.get("arguments").cloned().unwrap_or(json!({}));
 
-            match tools::call_tool(bridge, tool_name, arguments).await {
+            match tools::call_window_tool(registry, tool_name, arguments).await {
                 Ok(result) => JsonRpcResponse::success(
                     id,
                     json!({ "content": [{ "type": "text", "text": result }] }),
@@ -258,6 +227,16 @@ async fn handle_request(bridge: &BridgeClient, request: JsonRpcRequest) -> JsonR
                 Err(e) => JsonRpcResponse::error(id, -32000, e.to_string()),
             }
         }
+        "resources/list" => JsonRpcResponse::success(id, resources::resources_list()),
+        "resources/read" => {
+            let params = request.params.unwrap_or(json!({}));
+            let uri = params.get("uri").and_then(|v| v.as_str()).unwrap_or("");
+
+            match resources::read_resource(registry.default_bridge(), uri).await {
+                Ok(result) => JsonRpcResponse::success(id, result),
+                Err(e) => JsonRpcResponse::error(id, -32002, e.to_string()),
+            }
+        }
         _ => JsonRpcResponse::error(id, -32601, format!("Method not found: {}", request.method)),
     }
 }
@@ -270,6 +249,7 @@ mod tests {
     fn test_server_info() {
         let info = server_info();
         assert_eq!(info["serverInfo"]["name"], "dioxus-mcp");
+        assert!(info["capabilities"]["resources"].is_object());
     }
 
     #[test]
@@ -288,6 +268,24 @@ mod tests {
         assert!(tools.iter().any(|t| t["name"] == "check"));
     }
 
+    #[test]
+    fn test_tools_list_window_arg() {
+        let list = tools_list();
+        let tools = list["tools"].as_array().unwrap();
+        let tool = |name: &str| tools.iter().find(|t| t["name"] == name).unwrap();
+
+        let status = &tool("status")["inputSchema"];
+        assert!(status["properties"]["window"].is_object());
+        assert!(!status["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|r| r == "window"));
+        assert!(tool("list_windows")["inputSchema"]["properties"]
+            .get("window")
+            .is_none());
+    }
+
     #[test]
     fn test_json_rpc_response_success() {
         let resp = JsonRpcResponse::success(json!(1), json!({"ok": true}));
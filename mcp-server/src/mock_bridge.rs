@@ -0,0 +1,101 @@
+//! In-memory bridge client for testing MCP tools without a running app.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use reqwest::Method;
+use serde_json::Value;
+
+use crate::bridge::{BridgeClientTrait, EvalResponse};
+
+/// A request received by [`MockBridgeClient`].
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: Method,
+    pub path: String,
+    pub body: Option<Value>,
+}
+
+/// Answers bridge requests from a table of canned responses.
+///
+/// Each request is matched against the `script` field of its body, or against
+/// its path when there is no script (e.g. `/query`, `/dom?depth=2`). The
+/// longest key that is a prefix of that text wins.
+pub struct MockBridgeClient {
+    responses: HashMap<String, Value>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+impl MockBridgeClient {
+    pub fn new(responses: HashMap<String, EvalResponse>) -> Self {
+        Self {
+            responses: responses
+                .into_iter()
+                .map(|(key, resp)| (key, serde_json::to_value(resp).unwrap()))
+                .collect(),
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Add a raw JSON response for endpoints that don't return an `EvalResponse`.
+    pub fn with_json(mut self, key: &str, body: Value) -> Self {
+        self.responses.insert(key.to_string(), body);
+        self
+    }
+
+    /// All requests received so far, in order.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl BridgeClientTrait for MockBridgeClient {
+    async fn request(&self, method: Method, path: &str, body: Option<Value>) -> Result<Value> {
+        let text = body
+            .as_ref()
+            .and_then(|b| b.get("script"))
+            .and_then(|s| s.as_str())
+            .unwrap_or(path)
+            .to_string();
+        self.requests.lock().unwrap().push(RecordedRequest {
+            method,
+            path: path.to_string(),
+            body,
+        });
+
+        self.responses
+            .iter()
+            .filter(|(key, _)| text.starts_with(key.as_str()))
+            .max_by_key(|(key, _)| key.len())
+            .map(|(_, resp)| resp.clone())
+            .ok_or_else(|| anyhow!("No canned response for '{}'", text))
+    }
+}
+
+/// A successful eval response with a raw result.
+pub fn ok(result: &str) -> EvalResponse {
+    EvalResponse {
+        success: true,
+        result: Some(result.to_string()),
+        error: None,
+    }
+}
+
+/// A successful eval response carrying `value` the way the bridge returns
+/// script output: `JSON.stringify` in the webview, then JSON-encoded again.
+pub fn ok_json(value: &Value) -> EvalResponse {
+    let inner = value.to_string();
+    ok(&serde_json::to_string(&inner).unwrap())
+}
+
+/// A failed eval response.
+pub fn err(message: &str) -> EvalResponse {
+    EvalResponse {
+        success: false,
+        result: None,
+        error: Some(message.to_string()),
+    }
+}
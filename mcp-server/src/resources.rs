@@ -0,0 +1,68 @@
+//! MCP resources: documents that clients can read alongside tool calls.
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+
+use crate::bridge::{BridgeClientTrait, DomApi};
+use crate::tools::extract_json_pretty;
+
+const DOM_URI: &str = "dioxus://dom";
+
+pub fn resources_list() -> Value {
+    json!({
+        "resources": [{
+            "uri": DOM_URI,
+            "name": "DOM snapshot",
+            "description": "Current DOM tree of the running Dioxus app",
+            "mimeType": "application/json"
+        }]
+    })
+}
+
+pub async fn read_resource(bridge: &dyn BridgeClientTrait, uri: &str) -> Result<Value> {
+    let text = match uri {
+        DOM_URI => {
+            let resp = bridge.dom(None, None, None, false).await?;
+            extract_json_pretty(resp)?
+        }
+        _ => return Err(anyhow!("Unknown resource: {}", uri)),
+    };
+
+    Ok(json!({
+        "contents": [{ "uri": uri, "mimeType": "application/json", "text": text }]
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_bridge::{ok_json, MockBridgeClient};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_resources_list_contains_dom() {
+        let list = resources_list();
+        assert_eq!(list["resources"][0]["uri"], "dioxus://dom");
+        assert_eq!(list["resources"][0]["mimeType"], "application/json");
+    }
+
+    #[tokio::test]
+    async fn test_read_dom_resource() {
+        let bridge = MockBridgeClient::new(HashMap::from([(
+            "/dom".to_string(),
+            ok_json(&json!({"tag": "body"})),
+        )]));
+        let result = read_resource(&bridge, "dioxus://dom").await.unwrap();
+        let content = &result["contents"][0];
+        assert_eq!(content["uri"], "dioxus://dom");
+        let dom: Value = serde_json::from_str(content["text"].as_str().unwrap()).unwrap();
+        assert_eq!(dom["tag"], "body");
+    }
+
+    #[tokio::test]
+    async fn test_read_unknown_resource() {
+        let bridge = MockBridgeClient::new(HashMap::new());
+        let err = read_resource(&bridge, "dioxus://nope").await.unwrap_err();
+        assert!(err.to_string().contains("Unknown resource"));
+    }
+}
@@ -1,73 +1,206 @@
-//! MCP tool implementations.
+//! MCP tool implementations, grouped by domain in submodules.
+
+mod accessibility;
+mod assertions;
+mod diagnostics;
+mod dom;
+mod dx;
+mod eval;
+mod history;
+mod interaction;
+mod layout;
+mod media;
+mod monitoring;
+mod network;
+mod query;
+mod responsive;
+mod storage;
+mod viewport;
+
+use std::collections::HashMap;
 
 use anyhow::{anyhow, Result};
-use serde_json::Value;
+use serde_json::{json, Value};
 
-use crate::bridge::BridgeClient;
+use crate::bridge::{BridgeClientTrait, BridgeConfigResponse, BridgeRegistry, MonitoringApi};
 
-pub async fn call_tool(bridge: &BridgeClient, name: &str, args: Value) -> Result<String> {
+/// Run a tool on the bridge of the window named by its optional `window`
+/// argument, or the default window.
+pub async fn call_window_tool(
+    registry: &BridgeRegistry,
+    name: &str,
+    args: Value,
+) -> Result<String> {
+    if name == "list_windows" {
+        return Ok(list_windows(registry));
+    }
+    let window = args.get("window").and_then(|v| v.as_str());
+    call_tool(registry.get(window)?, name, args).await
+}
+
+fn list_windows(registry: &BridgeRegistry) -> String {
+    registry
+        .windows()
+        .into_iter()
+        .map(|name| {
+            if name == registry.default_window() {
+                format!("{} (default)", name)
+            } else {
+                name.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub async fn call_tool(bridge: &dyn BridgeClientTrait, name: &str, args: Value) -> Result<String> {
     match name {
         "status" => status(bridge).await,
-        "get_dom" => {
-            let depth = args.get("depth").and_then(|v| v.as_u64()).map(|v| v as u32);
-            let max_nodes = args
-                .get("max_nodes")
-                .and_then(|v| v.as_u64())
-                .map(|v| v as u32);
-            let selector = args.get("selector").and_then(|v| v.as_str());
-            get_dom(bridge, depth, max_nodes, selector).await
-        }
-        "query_text" => {
-            let selector = get_string_arg(&args, "selector")?;
-            query_text(bridge, &selector).await
-        }
-        "query_html" => {
-            let selector = get_string_arg(&args, "selector")?;
-            query_html(bridge, &selector).await
+        "ping" => {
+            let resp = bridge.ping().await?;
+            Ok(format!(
+                "Pong in {:.1}ms (bridge time: {}us)",
+                resp.round_trip_ms, resp.server_time_us
+            ))
         }
-        "query_all" => {
-            let selector = get_string_arg(&args, "selector")?;
-            query_all(bridge, &selector).await
+        "configure_bridge" => {
+            let config = match args.get("default_timeout_ms").and_then(|v| v.as_u64()) {
+                Some(timeout_ms) => bridge.set_default_timeout(timeout_ms).await?,
+                None => bridge.bridge_config().await?,
+            };
+            Ok(format_bridge_config(&config))
         }
-        "click" => {
-            let selector = get_string_arg(&args, "selector")?;
-            click(bridge, &selector).await
-        }
-        "type_text" => {
-            let selector = get_string_arg(&args, "selector")?;
-            let text = get_string_arg(&args, "text")?;
-            type_text(bridge, &selector, &text).await
-        }
-        "eval" => {
-            let script = get_string_arg(&args, "script")?;
-            eval(bridge, &script).await
-        }
-        "inspect" => {
-            let selector = get_string_arg(&args, "selector")?;
-            inspect(bridge, &selector).await
-        }
-        "diagnose" => diagnose(bridge).await,
-        "screenshot" => {
-            let path = args.get("path").and_then(|v| v.as_str());
-            screenshot(bridge, path).await
-        }
-        "resize" => {
-            let width = get_u32_arg(&args, "width")?;
-            let height = get_u32_arg(&args, "height")?;
-            resize(bridge, width, height).await
-        }
-        "dom_to_rsx" => {
-            let selector = args.get("selector").and_then(|v| v.as_str());
-            let html = args.get("html").and_then(|v| v.as_str());
-            dom_to_rsx(bridge, selector, html).await
+        _ => call_domain_tool(bridge, name, &args).await,
+    }
+}
+
+/// Offer the tool to each domain module in turn.
+async fn call_domain_tool(
+    bridge: &dyn BridgeClientTrait,
+    name: &str,
+    args: &Value,
+) -> Result<String> {
+    if let Some(text) = dom::call(bridge, name, args).await? {
+        return Ok(text);
+    }
+    if let Some(text) = query::call(bridge, name, args).await? {
+        return Ok(text);
+    }
+    if let Some(text) = layout::call(bridge, name, args).await? {
+        return Ok(text);
+    }
+    if let Some(text) = interaction::call(bridge, name, args).await? {
+        return Ok(text);
+    }
+    if let Some(text) = assertions::call(bridge, name, args).await? {
+        return Ok(text);
+    }
+    if let Some(text) = eval::call(bridge, name, args).await? {
+        return Ok(text);
+    }
+    if let Some(text) = history::call(bridge, name, args).await? {
+        return Ok(text);
+    }
+    if let Some(text) = diagnostics::call(bridge, name, args).await? {
+        return Ok(text);
+    }
+    if let Some(text) = monitoring::call(bridge, name, args).await? {
+        return Ok(text);
+    }
+    if let Some(text) = viewport::call(bridge, name, args).await? {
+        return Ok(text);
+    }
+    if let Some(text) = responsive::call(bridge, name, args).await? {
+        return Ok(text);
+    }
+    if let Some(text) = accessibility::call(bridge, name, args).await? {
+        return Ok(text);
+    }
+    if let Some(text) = network::call(bridge, name, args).await? {
+        return Ok(text);
+    }
+    if let Some(text) = storage::call(bridge, name, args).await? {
+        return Ok(text);
+    }
+    if let Some(text) = media::call(bridge, name, args).await? {
+        return Ok(text);
+    }
+    if let Some(text) = dx::call(bridge, name, args).await? {
+        return Ok(text);
+    }
+    Err(anyhow!("Unknown tool: {}", name))
+}
+
+/// Every tool's name, description and input schema, for `tools/list`.
+pub fn definitions() -> Vec<Value> {
+    let mut tools = vec![
+        tool_def("list_windows", "List the windows configured in DIOXUS_BRIDGES, which tools take as their window argument", json!({})),
+        tool_def("status", "Check if the Dioxus app is running", json!({})),
+        tool_def("ping", "Measure bridge round-trip latency without running any JavaScript", json!({})),
+        tool_def_optional(
+            "configure_bridge",
+            "Show the bridge's settings, optionally changing how long evals wait for the app before failing with a timeout. Evals with their own timeout_ms keep theirs",
+            json!({
+                "default_timeout_ms": { "type": "integer", "description": "Milliseconds an eval may take; 0 waits indefinitely (default: leave unchanged)" }
+            }),
+            vec![]
+        ),
+    ];
+    for module_tools in [
+        dom::definitions(),
+        query::definitions(),
+        layout::definitions(),
+        interaction::definitions(),
+        assertions::definitions(),
+        eval::definitions(),
+        history::definitions(),
+        diagnostics::definitions(),
+        monitoring::definitions(),
+        viewport::definitions(),
+        responsive::definitions(),
+        accessibility::definitions(),
+        network::definitions(),
+        storage::definitions(),
+        media::definitions(),
+        dx::definitions(),
+    ] {
+        tools.extend(module_tools);
+    }
+    tools
+}
+
+fn tool_def(name: &str, description: &str, properties: Value) -> Value {
+    let required: Vec<&str> = properties
+        .as_object()
+        .map(|obj| obj.keys().map(|k| k.as_str()).collect())
+        .unwrap_or_default();
+
+    json!({
+        "name": name,
+        "description": description,
+        "inputSchema": {
+            "type": "object",
+            "properties": properties,
+            "required": required
         }
-        "doctor" => doctor().await,
-        "check" => {
-            let path = args.get("path").and_then(|v| v.as_str());
-            check(path).await
+    })
+}
+
+fn tool_def_optional(
+    name: &str,
+    description: &str,
+    properties: Value,
+    required: Vec<&str>,
+) -> Value {
+    json!({
+        "name": name,
+        "description": description,
+        "inputSchema": {
+            "type": "object",
+            "properties": properties,
+            "required": required
         }
-        _ => Err(anyhow!("Unknown tool: {}", name)),
-    }
+    })
 }
 
 fn get_string_arg(args: &Value, key: &str) -> Result<String> {
@@ -84,223 +217,94 @@ fn get_u32_arg(args: &Value, key: &str) -> Result<u32> {
         .ok_or_else(|| anyhow!("Missing '{}' argument", key))
 }
 
-async fn status(bridge: &BridgeClient) -> Result<String> {
-    match bridge.status().await {
-        Ok(resp) => Ok(format!("Connected: {} ({})", resp.app, resp.status)),
-        Err(e) => Ok(format!(
-            "Bridge not available: {}. Start app with inspector enabled.",
-            e
-        )),
-    }
-}
-
-async fn get_dom(
-    bridge: &BridgeClient,
-    depth: Option<u32>,
-    max_nodes: Option<u32>,
-    selector: Option<&str>,
-) -> Result<String> {
-    let resp = bridge.dom(depth, max_nodes, selector).await?;
-    extract_json_pretty(resp)
-}
-
-async fn query_text(bridge: &BridgeClient, selector: &str) -> Result<String> {
-    let resp = bridge.query(selector, Some("text")).await?;
-    extract_result(resp)
-}
-
-async fn query_html(bridge: &BridgeClient, selector: &str) -> Result<String> {
-    let resp = bridge.query(selector, Some("html")).await?;
-    extract_result(resp)
-}
-
-async fn query_all(bridge: &BridgeClient, selector: &str) -> Result<String> {
-    let script = format!(
-        r#"return (() => {{
-            const els = document.querySelectorAll({});
-            return JSON.stringify(Array.from(els).map((el, i) => ({{
-                index: i,
-                tag: el.tagName.toLowerCase(),
-                id: el.id || null,
-                class: el.className || null,
-                text: el.textContent?.trim().substring(0, 100) || null
-            }})));
-        }})()"#,
-        serde_json::to_string(selector)?
-    );
-    let resp = bridge.eval(&script).await?;
-    extract_json_pretty(resp)
-}
-
-async fn click(bridge: &BridgeClient, selector: &str) -> Result<String> {
-    let script = format!(
-        r#"return (() => {{
-            const el = document.querySelector({});
-            if (el) {{ el.click(); return 'clicked'; }}
-            return 'element not found';
-        }})()"#,
-        serde_json::to_string(selector)?
-    );
-    let resp = bridge.eval(&script).await?;
-    extract_result(resp)
-}
-
-async fn type_text(bridge: &BridgeClient, selector: &str, text: &str) -> Result<String> {
-    let script = format!(
-        r#"return (() => {{
-            const el = document.querySelector({});
-            if (el) {{
-                el.value = {};
-                el.dispatchEvent(new Event('input', {{ bubbles: true }}));
-                return 'typed';
-            }}
-            return 'element not found';
-        }})()"#,
-        serde_json::to_string(selector)?,
-        serde_json::to_string(text)?
-    );
-    let resp = bridge.eval(&script).await?;
-    extract_result(resp)
-}
-
-async fn eval(bridge: &BridgeClient, script: &str) -> Result<String> {
-    // Wrap user script in IIFE with eval() to capture expression results
-    // This allows both simple expressions (1+1) and complex scripts to work
-    let wrapped = format!(
-        r#"return (() => {{
-            const __result__ = eval({});
-            return __result__;
-        }})()"#,
-        serde_json::to_string(script)?
-    );
-    let resp = bridge.eval(&wrapped).await?;
-    extract_result(resp)
-}
-
-async fn inspect(bridge: &BridgeClient, selector: &str) -> Result<String> {
-    let resp = bridge.inspect(selector).await?;
-    extract_json_pretty(resp)
+fn get_i32_arg(args: &Value, key: &str) -> Result<i32> {
+    args.get(key)
+        .and_then(|v| v.as_i64())
+        .and_then(|v| i32::try_from(v).ok())
+        .ok_or_else(|| anyhow!("Missing '{}' argument", key))
 }
 
-async fn diagnose(bridge: &BridgeClient) -> Result<String> {
-    let resp = bridge.diagnose().await?;
-    extract_json_pretty(resp)
+/// An optional array of strings, e.g. `"properties": ["display", "color"]`.
+fn get_string_list_arg(args: &Value, key: &str) -> Result<Option<Vec<String>>> {
+    let Some(value) = args.get(key) else {
+        return Ok(None);
+    };
+    value
+        .as_array()
+        .and_then(|items| {
+            items
+                .iter()
+                .map(|item| item.as_str().map(String::from))
+                .collect::<Option<Vec<_>>>()
+        })
+        .map(Some)
+        .ok_or_else(|| anyhow!("'{}' must be an array of strings", key))
 }
 
-async fn screenshot(bridge: &BridgeClient, path: Option<&str>) -> Result<String> {
-    let resp = bridge.screenshot(path).await?;
-    if resp.success {
-        Ok(format!(
-            "Screenshot saved: {}",
-            resp.path.unwrap_or_default()
-        ))
-    } else {
-        Err(anyhow!(resp
-            .error
-            .unwrap_or_else(|| "Unknown error".to_string())))
-    }
+/// An object of field values, e.g. `"fields": {"email": "a@b.c", "age": 30}`.
+///
+/// Numbers and booleans are passed on as their text, the way a form holds them.
+fn get_string_map_arg(args: &Value, key: &str) -> Result<HashMap<String, String>> {
+    let object = args
+        .get(key)
+        .and_then(|v| v.as_object())
+        .ok_or_else(|| anyhow!("Missing '{}' argument", key))?;
+    object
+        .iter()
+        .map(|(name, value)| {
+            let text = match value {
+                Value::String(s) => s.clone(),
+                Value::Number(_) | Value::Bool(_) => value.to_string(),
+                _ => return Err(anyhow!("'{}.{}' must be a string", key, name)),
+            };
+            Ok((name.clone(), text))
+        })
+        .collect()
 }
 
-async fn resize(bridge: &BridgeClient, width: u32, height: u32) -> Result<String> {
-    let resp = bridge.resize(width, height).await?;
-    if resp.success {
-        Ok(format!("Window resized to {}x{}", resp.width, resp.height))
-    } else {
-        Err(anyhow!(resp
-            .error
-            .unwrap_or_else(|| "Unknown error".to_string())))
+async fn status(bridge: &dyn BridgeClientTrait) -> Result<String> {
+    match bridge.status().await {
+        Ok(resp) => Ok(format!("Connected: {} ({})", resp.app, resp.status)),
+        Err(e) => Ok(format!(
+            "Bridge not available: {}. Start app with inspector enabled.",
+            e
+        )),
     }
 }
 
-async fn dom_to_rsx(
-    bridge: &BridgeClient,
-    selector: Option<&str>,
-    html: Option<&str>,
-) -> Result<String> {
-    // Get HTML from selector or use provided HTML
-    let html_content = match (selector, html) {
-        (Some(sel), _) => {
-            let resp = bridge.query(sel, Some("html")).await?;
-            let json_str = extract_result(resp)?;
-            // The result is JSON-encoded, so we need to unescape it
-            serde_json::from_str::<String>(&json_str)
-                .unwrap_or_else(|_| json_str.trim_matches('"').to_string())
-        }
-        (None, Some(h)) => h.to_string(),
-        (None, None) => return Err(anyhow!("Either 'selector' or 'html' argument is required")),
+/// Totals, then the last `limit` entries, one per line.
+fn format_bridge_config(config: &BridgeConfigResponse) -> String {
+    let timeout = config
+        .default_eval_timeout_ms
+        .map_or_else(|| "none".to_string(), |ms| format!("{}ms", ms));
+    let backpressure = match config.backpressure_queue_max {
+        Some(max) => format!("{} (max {})", config.backpressure, max),
+        None => config.backpressure.clone(),
     };
-
-    // Shell out to dx translate
-    translate_html_to_rsx(&html_content).await
+    let rate_limit = config
+        .rate_limit
+        .map_or_else(|| "none".to_string(), |n| format!("{}/s", n));
+    [
+        format!("App: {}", config.app_name),
+        format!("Default eval timeout: {}", timeout),
+        format!("Backpressure: {}", backpressure),
+        format!("History size: {}", config.max_history),
+        format!("Auth required: {}", config.auth_required),
+        format!("Rate limit: {}", rate_limit),
+        format!("Startup scripts: {}", config.startup_scripts),
+        format!("Eval script logging: {}", config.log_eval_scripts),
+    ]
+    .join("\n")
 }
 
-async fn translate_html_to_rsx(html: &str) -> Result<String> {
-    use std::process::Stdio;
-    use tokio::process::Command;
-
-    let output = Command::new("dx")
-        .args(["translate", "--raw", html])
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .output()
-        .await
-        .map_err(|e| anyhow!("Failed to run 'dx translate': {}. Is dx CLI installed?", e))?;
-
-    if output.status.success() {
-        let rsx = String::from_utf8_lossy(&output.stdout).trim().to_string();
-        if rsx.is_empty() {
-            Ok("// No RSX generated (empty input?)".to_string())
-        } else {
-            Ok(rsx)
-        }
+/// Collapse whitespace and cut `text` to at most `max` characters.
+fn summarize(text: &str, max: usize) -> String {
+    let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.chars().count() <= max {
+        collapsed
     } else {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        Err(anyhow!("dx translate failed: {}", stderr.trim()))
-    }
-}
-
-async fn doctor() -> Result<String> {
-    run_dx_command(&["doctor"]).await
-}
-
-async fn check(path: Option<&str>) -> Result<String> {
-    let mut args = vec!["check"];
-    if let Some(p) = path {
-        args.push("-p");
-        args.push(p);
-    }
-    run_dx_command(&args).await
-}
-
-async fn run_dx_command(args: &[&str]) -> Result<String> {
-    use std::process::Stdio;
-    use tokio::process::Command;
-
-    let output = Command::new("dx")
-        .args(args)
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .output()
-        .await
-        .map_err(|e| {
-            anyhow!(
-                "Failed to run 'dx {}': {}. Is dx CLI installed?",
-                args[0],
-                e
-            )
-        })?;
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let stderr = String::from_utf8_lossy(&output.stderr);
-
-    // Combine stdout and stderr - dx outputs diagnostics to both
-    let combined = format!("{}{}", stdout, stderr);
-    let trimmed = combined.trim();
-
-    if trimmed.is_empty() {
-        Ok(format!("dx {} completed (no output)", args[0]))
-    } else {
-        Ok(trimmed.to_string())
+        let cut: String = collapsed.chars().take(max).collect();
+        format!("{}...", cut)
     }
 }
 
@@ -315,7 +319,7 @@ fn extract_result(resp: crate::bridge::EvalResponse) -> Result<String> {
 }
 
 /// Extract and pretty-print JSON from double-encoded response
-fn extract_json_pretty(resp: crate::bridge::EvalResponse) -> Result<String> {
+pub(crate) fn extract_json_pretty(resp: crate::bridge::EvalResponse) -> Result<String> {
     let json_str = extract_result(resp)?;
     let inner: String =
         serde_json::from_str(&json_str).map_err(|e| anyhow!("Failed to unescape: {}", e))?;
@@ -326,7 +330,26 @@ fn extract_json_pretty(resp: crate::bridge::EvalResponse) -> Result<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use serde_json::json;
+    use crate::bridge::EvalResponse;
+    use crate::mock_bridge::MockBridgeClient;
+    use reqwest::Method;
+
+    pub(super) fn mock(responses: &[(&str, EvalResponse)]) -> MockBridgeClient {
+        MockBridgeClient::new(
+            responses
+                .iter()
+                .map(|(key, resp)| (key.to_string(), resp.clone()))
+                .collect(),
+        )
+    }
+
+    pub(super) fn sent_script(bridge: &MockBridgeClient) -> String {
+        let requests = bridge.requests();
+        requests[0].body.as_ref().unwrap()["script"]
+            .as_str()
+            .unwrap()
+            .to_string()
+    }
 
     #[test]
     fn test_get_string_arg_success() {
@@ -384,4 +407,118 @@ mod tests {
         assert_eq!(args.get("selector").and_then(|v| v.as_str()), Some(".btn"));
         assert_eq!(args.get("html").and_then(|v| v.as_str()), None);
     }
+
+    #[tokio::test]
+    async fn test_status_connected() {
+        let bridge = mock(&[]).with_json("/status", json!({"status": "ok", "app": "demo"}));
+        let result = call_tool(&bridge, "status", json!({})).await.unwrap();
+        assert_eq!(result, "Connected: demo (ok)");
+        assert_eq!(bridge.requests()[0].method, Method::GET);
+    }
+
+    #[tokio::test]
+    async fn test_ping() {
+        let bridge = mock(&[]).with_json(
+            "/ping",
+            json!({"pong": true, "server_time_us": 1_700_000_000_000_000u64}),
+        );
+        let result = call_tool(&bridge, "ping", json!({})).await.unwrap();
+        assert!(result.starts_with("Pong in "), "{}", result);
+        assert!(result.ends_with("(bridge time: 1700000000000000us)"));
+    }
+
+    #[tokio::test]
+    async fn test_status_unavailable() {
+        let bridge = mock(&[]);
+        let result = call_tool(&bridge, "status", json!({})).await.unwrap();
+        assert!(result.starts_with("Bridge not available"));
+    }
+
+    #[tokio::test]
+    async fn test_configure_bridge() {
+        let bridge = mock(&[]).with_json(
+            "/config",
+            json!({
+                "app_name": "my-app",
+                "default_eval_timeout_ms": 5000,
+                "backpressure": "queue",
+                "backpressure_queue_max": 8,
+                "max_history": 100,
+                "auth_required": false,
+                "rate_limit": null,
+                "startup_scripts": 1,
+                "log_eval_scripts": false
+            }),
+        );
+        let result = call_tool(
+            &bridge,
+            "configure_bridge",
+            json!({"default_timeout_ms": 5000}),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            result,
+            "App: my-app\n\
+             Default eval timeout: 5000ms\n\
+             Backpressure: queue (max 8)\n\
+             History size: 100\n\
+             Auth required: false\n\
+             Rate limit: none\n\
+             Startup scripts: 1\n\
+             Eval script logging: false"
+        );
+
+        let requests = bridge.requests();
+        assert_eq!(requests[0].method, Method::PUT);
+        assert_eq!(requests[0].path, "/config/eval-timeout");
+        assert_eq!(
+            requests[0].body.clone().unwrap(),
+            json!({"timeout_ms": 5000})
+        );
+
+        call_tool(&bridge, "configure_bridge", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(bridge.requests()[1].method, Method::GET);
+    }
+
+    #[test]
+    fn test_summarize() {
+        assert_eq!(summarize("a\n  b", 10), "a b");
+        assert_eq!(summarize("abcdef", 3), "abc...");
+    }
+
+    #[tokio::test]
+    async fn test_list_windows() {
+        use crate::bridge::BridgeClient;
+
+        let registry = BridgeRegistry::new(vec![
+            (
+                "settings".to_string(),
+                BridgeClient::new("http://127.0.0.1:10000"),
+            ),
+            (
+                "main".to_string(),
+                BridgeClient::new("http://127.0.0.1:9999"),
+            ),
+        ])
+        .unwrap();
+        let result = call_window_tool(&registry, "list_windows", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(result, "main\nsettings (default)");
+
+        let err = call_window_tool(&registry, "status", json!({"window": "about"}))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().starts_with("Unknown window 'about'"));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_tool() {
+        let bridge = mock(&[]);
+        let result = call_tool(&bridge, "nope", json!({})).await;
+        assert_eq!(result.unwrap_err().to_string(), "Unknown tool: nope");
+    }
 }
@@ -0,0 +1,424 @@
+//! Accessibility tools: ARIA attributes, tab order, roles and color contrast.
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+
+use super::{get_string_arg, get_string_map_arg, tool_def, tool_def_optional};
+use crate::bridge::{
+    AccessibilityApi, AriaResponse, BridgeClientTrait, ColorPair, ColorsResponse,
+    ContrastAuditResponse, ContrastIssue, RoleQueryResponse, TabOrderResponse,
+    ValidateColorsResponse,
+};
+
+pub(super) async fn call(
+    bridge: &dyn BridgeClientTrait,
+    name: &str,
+    args: &Value,
+) -> Result<Option<String>> {
+    let text = match name {
+        "get_aria" => {
+            let selector = get_string_arg(args, "selector")?;
+            format_aria(bridge.get_aria(&selector).await?)
+        }
+        "set_aria" => {
+            let selector = get_string_arg(args, "selector")?;
+            let attributes = get_string_map_arg(args, "attributes")?;
+            format_aria(bridge.set_aria(&selector, attributes).await?)
+        }
+        "validate_colors" => {
+            let pairs = get_color_pairs(args)?;
+            validate_colors(bridge, pairs).await
+        }
+        "get_tab_order" => get_tab_order(bridge).await,
+        "query_by_role" => {
+            let role = get_string_arg(args, "role")?;
+            let name = args.get("name").and_then(|v| v.as_str());
+            let resp = bridge.query_by_role(&role, name).await?;
+            Ok(format_role_query(&role, name, &resp))
+        }
+        "get_color_palette" => Ok(format_colors(&bridge.colors().await?)),
+        "contrast_audit" => Ok(format_contrast_audit(&bridge.contrast_audit().await?)),
+        _ => return Ok(None),
+    };
+    text.map(Some)
+}
+
+pub(super) fn definitions() -> Vec<Value> {
+    vec![
+        tool_def("validate_colors", "Check WCAG contrast ratios (AA/AAA) for foreground/background color pairs", json!({
+            "pairs": {
+                "type": "array",
+                "description": "Color pairs to check, as CSS color strings",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "foreground": { "type": "string", "description": "Text color" },
+                        "background": { "type": "string", "description": "Background color" }
+                    },
+                    "required": ["foreground", "background"]
+                }
+            }
+        })),
+        tool_def("get_tab_order", "List focusable elements in keyboard tab order, for accessibility testing", json!({})),
+        tool_def_optional(
+            "query_by_role",
+            "Find elements by ARIA role and accessible name, a more stable way to locate controls than CSS selectors. Also matches implicit roles, e.g. <button> for button",
+            json!({
+                "role": { "type": "string", "description": "ARIA role, e.g. button, link, heading, checkbox, dialog" },
+                "name": { "type": "string", "description": "Only elements whose accessible name contains this, ignoring case" }
+            }),
+            vec!["role"]
+        ),
+        tool_def("get_color_palette", "List every distinct color the page uses for text, backgrounds, borders and SVG fill/stroke, as hex with usage counts and example elements", json!({})),
+        tool_def("contrast_audit", "Check the WCAG contrast of all visible text against its background, listing text that fails AA and text that only misses AAA", json!({})),
+        tool_def("get_aria", "Read the role and aria-* attributes of an element", json!({
+            "selector": { "type": "string", "description": "CSS selector" }
+        })),
+        tool_def("set_aria", "Set ARIA attributes on an element and return its ARIA attributes afterwards", json!({
+            "selector": { "type": "string", "description": "CSS selector" },
+            "attributes": { "type": "object", "description": "Attribute names to values; the aria- prefix is optional, e.g. {\"expanded\": \"true\"}" }
+        })),
+    ]
+}
+
+fn get_color_pairs(args: &Value) -> Result<Vec<ColorPair>> {
+    let pairs = args
+        .get("pairs")
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| anyhow!("Missing 'pairs' argument"))?;
+    pairs
+        .iter()
+        .map(|pair| -> Result<ColorPair> {
+            Ok(ColorPair {
+                foreground: get_string_arg(pair, "foreground")?,
+                background: get_string_arg(pair, "background")?,
+            })
+        })
+        .collect()
+}
+
+async fn validate_colors(bridge: &dyn BridgeClientTrait, pairs: Vec<ColorPair>) -> Result<String> {
+    let resp = bridge.validate_colors(pairs).await?;
+    Ok(format_contrast_results(&resp))
+}
+
+fn format_contrast_results(resp: &ValidateColorsResponse) -> String {
+    let verdict = |pass: bool| if pass { "pass" } else { "fail" };
+    resp.results
+        .iter()
+        .map(|r| {
+            format!(
+                "{} on {}: {:.2}:1 (AA normal: {}, AA large: {}, AAA normal: {})",
+                r.foreground,
+                r.background,
+                r.ratio,
+                verdict(r.aa_normal),
+                verdict(r.aa_large),
+                verdict(r.aaa_normal)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+async fn get_tab_order(bridge: &dyn BridgeClientTrait) -> Result<String> {
+    let resp = bridge.tab_order().await?;
+    Ok(format_tab_order(&resp))
+}
+
+fn format_tab_order(resp: &TabOrderResponse) -> String {
+    if resp.elements.is_empty() {
+        return "No focusable elements".to_string();
+    }
+    resp.elements
+        .iter()
+        .map(|el| {
+            let mut line = format!("{}. <{}> {}", el.index + 1, el.tag, el.selector_path);
+            if el.tabindex > 0 {
+                line.push_str(&format!(" [tabindex={}]", el.tabindex));
+            }
+            if !el.text.is_empty() {
+                line.push_str(&format!(" \"{}\"", el.text));
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn format_role_query(role: &str, name: Option<&str>, resp: &RoleQueryResponse) -> String {
+    let what = match name {
+        Some(name) => format!("role '{}' named like \"{}\"", role, name),
+        None => format!("role '{}'", role),
+    };
+    if resp.elements.is_empty() {
+        return format!("No elements with {}", what);
+    }
+    let mut lines = vec![format!("{} element(s) with {}:", resp.elements.len(), what)];
+    for el in &resp.elements {
+        let mut line = format!("- {} \"{}\"", el.selector_path, el.accessible_name);
+        if !el.accessible_description.is_empty() {
+            line.push_str(&format!(" ({})", el.accessible_description));
+        }
+        if !el.states.is_empty() {
+            // Sorted so the output doesn't depend on map order
+            let mut states: Vec<_> = el
+                .states
+                .iter()
+                .map(|(state, value)| format!("{}={}", state, value))
+                .collect();
+            states.sort();
+            line.push_str(&format!(" [{}]", states.join(", ")));
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+fn format_aria(resp: AriaResponse) -> Result<String> {
+    if let Some(error) = resp.error {
+        return Err(anyhow!("ARIA request failed: {}", error));
+    }
+    let mut attributes: Vec<_> = resp.current_attributes.into_iter().collect();
+    attributes.sort();
+    let mut lines = Vec::with_capacity(attributes.len() + 1);
+    if resp.applied > 0 {
+        lines.push(format!("Set {} attribute(s)", resp.applied));
+    }
+    if attributes.is_empty() {
+        lines.push("No ARIA attributes".to_string());
+    }
+    lines.extend(
+        attributes
+            .into_iter()
+            .map(|(name, value)| format!("{}=\"{}\"", name, value)),
+    );
+    Ok(lines.join("\n"))
+}
+
+fn format_colors(resp: &ColorsResponse) -> String {
+    if resp.colors.is_empty() {
+        return "No colors found".to_string();
+    }
+    let mut lines = vec![format!("{} color(s):", resp.colors.len())];
+    for color in &resp.colors {
+        let mut line = format!("{} used {}x", color.hex, color.usage_count);
+        if !color.css_value.eq_ignore_ascii_case(&color.hex) {
+            line.push_str(&format!(" as {}", color.css_value));
+        }
+        if !color.element_examples.is_empty() {
+            line.push_str(&format!(", e.g. {}", color.element_examples.join(", ")));
+        }
+        lines.push(format!("- {}", line));
+    }
+    lines.join("\n")
+}
+
+fn format_contrast_audit(resp: &ContrastAuditResponse) -> String {
+    let mut lines = vec![format!(
+        "{} failure(s), {} warning(s), {} passing",
+        resp.failures.len(),
+        resp.warnings.len(),
+        resp.pass_count
+    )];
+    let issue = |issue: &ContrastIssue| {
+        format!(
+            "- {} \"{}\": {} on {} is {:.2}:1, {} {} text needs {}:1",
+            issue.selector_path,
+            issue.text,
+            issue.foreground,
+            issue.background,
+            issue.ratio,
+            issue.level,
+            issue.text_size,
+            issue.required_ratio
+        )
+    };
+    if !resp.failures.is_empty() {
+        lines.push("Failures:".to_string());
+        lines.extend(resp.failures.iter().map(issue));
+    }
+    if !resp.warnings.is_empty() {
+        lines.push("Warnings:".to_string());
+        lines.extend(resp.warnings.iter().map(issue));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::call_tool;
+    use crate::tools::tests::mock;
+
+    #[test]
+    fn test_get_color_pairs() {
+        let args = json!({"pairs": [{"foreground": "#000", "background": "#fff"}]});
+        let pairs = get_color_pairs(&args).unwrap();
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0].foreground, "#000");
+        assert!(get_color_pairs(&json!({"pairs": [{"foreground": "#000"}]})).is_err());
+    }
+
+    #[test]
+    fn test_format_contrast_results() {
+        let resp = ValidateColorsResponse {
+            results: vec![crate::bridge::ColorContrastResult {
+                foreground: "#767676".to_string(),
+                background: "#fff".to_string(),
+                ratio: 4.54,
+                aa_normal: true,
+                aa_large: true,
+                aaa_normal: false,
+            }],
+        };
+        assert_eq!(
+            format_contrast_results(&resp),
+            "#767676 on #fff: 4.54:1 (AA normal: pass, AA large: pass, AAA normal: fail)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validate_colors_tool() {
+        let bridge = mock(&[]).with_json(
+            "/validate-colors",
+            json!({"results": [{"foreground": "#000", "background": "#fff", "ratio": 21.0,
+                "aa_normal": true, "aa_large": true, "aaa_normal": true}]}),
+        );
+        let args = json!({"pairs": [{"foreground": "#000", "background": "#fff"}]});
+        let result = call_tool(&bridge, "validate_colors", args).await.unwrap();
+        assert!(result.starts_with("#000 on #fff: 21.00:1"));
+    }
+
+    #[tokio::test]
+    async fn test_get_aria() {
+        let bridge = mock(&[]).with_json(
+            "/aria",
+            json!({"current_attributes": {"role": "menu", "aria-expanded": "false"}, "applied": 0, "error": null}),
+        );
+        let result = call_tool(&bridge, "get_aria", json!({"selector": "#menu"}))
+            .await
+            .unwrap();
+        assert_eq!(result, "aria-expanded=\"false\"\nrole=\"menu\"");
+        assert_eq!(
+            bridge.requests()[0].body.clone().unwrap(),
+            json!({"selector": "#menu"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_aria() {
+        let bridge = mock(&[]).with_json(
+            "/aria",
+            json!({"current_attributes": {"aria-expanded": "true"}, "applied": 1, "error": null}),
+        );
+        let args = json!({"selector": "#menu", "attributes": {"expanded": true}});
+        let result = call_tool(&bridge, "set_aria", args).await.unwrap();
+        assert_eq!(result, "Set 1 attribute(s)\naria-expanded=\"true\"");
+        assert_eq!(
+            bridge.requests()[0].body.clone().unwrap(),
+            json!({"selector": "#menu", "attributes": {"expanded": "true"}})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_aria_error() {
+        let bridge = mock(&[]).with_json(
+            "/aria",
+            json!({"current_attributes": {}, "applied": 0, "error": "Element not found"}),
+        );
+        let err = call_tool(&bridge, "get_aria", json!({"selector": "#nope"}))
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "ARIA request failed: Element not found");
+    }
+
+    #[tokio::test]
+    async fn test_get_tab_order() {
+        let bridge = mock(&[]).with_json(
+            "/tab-order",
+            json!({"elements": [
+                {"index": 0, "selector_path": "input#email", "tag": "input", "text": "Email", "tabindex": 2},
+                {"index": 1, "selector_path": "main > a", "tag": "a", "text": "", "tabindex": 0}
+            ]}),
+        );
+        let result = call_tool(&bridge, "get_tab_order", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "1. <input> input#email [tabindex=2] \"Email\"\n2. <a> main > a"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_query_by_role() {
+        let bridge = mock(&[]).with_json(
+            "/role?role=button&name=Save%20draft",
+            json!({"elements": [
+                {"selector_path": "form#post > button:nth-of-type(2)", "accessible_name": "Save draft",
+                 "accessible_description": "Saved every minute", "states": {"pressed": "false", "disabled": "true"}}
+            ]}),
+        );
+        let args = json!({"role": "button", "name": "Save draft"});
+        let result = call_tool(&bridge, "query_by_role", args).await.unwrap();
+        assert_eq!(
+            result,
+            "1 element(s) with role 'button' named like \"Save draft\":\n\
+             - form#post > button:nth-of-type(2) \"Save draft\" (Saved every minute) [disabled=true, pressed=false]"
+        );
+
+        let bridge = mock(&[]).with_json("/role?role=dialog", json!({"elements": []}));
+        let result = call_tool(&bridge, "query_by_role", json!({"role": "dialog"}))
+            .await
+            .unwrap();
+        assert_eq!(result, "No elements with role 'dialog'");
+    }
+
+    #[tokio::test]
+    async fn test_get_color_palette() {
+        let bridge = mock(&[]).with_json(
+            "/colors",
+            json!({"colors": [
+                {"css_value": "rgb(37, 99, 235)", "hex": "#2563eb", "usage_count": 4, "element_examples": ["button#save", "a"]},
+                {"css_value": "#000000", "hex": "#000000", "usage_count": 1, "element_examples": []}
+            ]}),
+        );
+        let result = call_tool(&bridge, "get_color_palette", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "2 color(s):\n\
+             - #2563eb used 4x as rgb(37, 99, 235), e.g. button#save, a\n\
+             - #000000 used 1x"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_contrast_audit() {
+        let issue = |text: &str, ratio: f64, level: &str, required: f64| {
+            json!({"selector_path": "p", "text": text, "foreground": "#777", "background": "#fff",
+                   "ratio": ratio, "required_ratio": required, "level": level, "text_size": "normal"})
+        };
+        let bridge = mock(&[]).with_json(
+            "/contrast-audit",
+            json!({
+                "failures": [issue("Fine print", 2.96, "AA", 4.5)],
+                "warnings": [issue("Caption", 4.54, "AAA", 7.0)],
+                "pass_count": 12
+            }),
+        );
+        let result = call_tool(&bridge, "contrast_audit", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "1 failure(s), 1 warning(s), 12 passing\n\
+             Failures:\n\
+             - p \"Fine print\": #777 on #fff is 2.96:1, AA normal text needs 4.5:1\n\
+             Warnings:\n\
+             - p \"Caption\": #777 on #fff is 4.54:1, AAA normal text needs 7:1"
+        );
+    }
+}
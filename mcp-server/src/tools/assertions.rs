@@ -0,0 +1,258 @@
+//! Tools that wait for or assert on the state of the page.
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+
+use super::layout::inspect;
+use super::query::query_text;
+use super::{get_string_arg, summarize, tool_def, tool_def_optional};
+use crate::bridge::{BridgeClientTrait, InteractionApi};
+
+pub(super) async fn call(
+    bridge: &dyn BridgeClientTrait,
+    name: &str,
+    args: &Value,
+) -> Result<Option<String>> {
+    let text = match name {
+        "wait_for" => wait_for(bridge, args).await,
+        "assert_text" => {
+            let selector = get_string_arg(args, "selector")?;
+            let expected = get_string_arg(args, "expected")?;
+            assert_text(bridge, &selector, &expected).await
+        }
+        "assert_visible" => {
+            let selector = get_string_arg(args, "selector")?;
+            assert_visible(bridge, &selector).await
+        }
+        _ => return Ok(None),
+    };
+    text.map(Some)
+}
+
+pub(super) fn definitions() -> Vec<Value> {
+    vec![
+        tool_def("assert_text", "Assert that an element's trimmed text equals the expected value; fails with the actual text otherwise", json!({
+            "selector": { "type": "string", "description": "CSS selector" },
+            "expected": { "type": "string", "description": "Expected text content" }
+        })),
+        tool_def("assert_visible", "Assert that an element is visible (in viewport, displayed, non-zero size); fails with the reason otherwise", json!({
+            "selector": { "type": "string", "description": "CSS selector" }
+        })),
+        tool_def_optional(
+            "wait_for",
+            "Wait until an element exists, is visible, is hidden or has the given text, like Playwright's waitForSelector",
+            json!({
+                "selector": { "type": "string", "description": "CSS selector" },
+                "condition": { "type": "string", "enum": ["exists", "visible", "hidden", "text_equals"], "description": "Condition to wait for (default: visible)" },
+                "text": { "type": "string", "description": "Expected trimmed text content, for text_equals" },
+                "timeout_ms": { "type": "integer", "description": "Give up after this many milliseconds (default: 5000, max: 300000)" }
+            }),
+            vec!["selector"]
+        ),
+    ]
+}
+
+async fn assert_text(
+    bridge: &dyn BridgeClientTrait,
+    selector: &str,
+    expected: &str,
+) -> Result<String> {
+    let raw = query_text(bridge, selector).await?;
+    check_text(&raw, expected)
+}
+
+/// Compare a `query_text` result (JSON-encoded by the bridge) against the expected text.
+fn check_text(raw: &str, expected: &str) -> Result<String> {
+    if raw == "null" {
+        return Err(anyhow!("expected '{}' but element was not found", expected));
+    }
+    let text = serde_json::from_str::<String>(raw).unwrap_or_else(|_| raw.to_string());
+    let actual = text.trim();
+    if actual == expected.trim() {
+        Ok(format!("Assertion passed: text is '{}'", actual))
+    } else {
+        Err(anyhow!("expected '{}' but got '{}'", expected, actual))
+    }
+}
+
+async fn assert_visible(bridge: &dyn BridgeClientTrait, selector: &str) -> Result<String> {
+    let report = inspect(bridge, selector, false).await?;
+    check_visible(&report, selector)
+}
+
+/// Check the `visible` flag of an `inspect` report.
+fn check_visible(report: &str, selector: &str) -> Result<String> {
+    let parsed: Value =
+        serde_json::from_str(report).map_err(|e| anyhow!("Invalid inspect report: {}", e))?;
+    if parsed.get("found").and_then(|v| v.as_bool()) == Some(false) {
+        return Err(anyhow!(
+            "expected '{}' to be visible but it was not found",
+            selector
+        ));
+    }
+    if parsed.get("visible").and_then(|v| v.as_bool()) == Some(true) {
+        Ok(format!("Assertion passed: '{}' is visible", selector))
+    } else {
+        let summary = parsed
+            .get("summary")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown reason");
+        Err(anyhow!(
+            "expected '{}' to be visible but it is not: {}",
+            selector,
+            summary
+        ))
+    }
+}
+
+/// Default `timeout_ms` for `wait_for`, the same as the bridge's.
+const DEFAULT_WAIT_TIMEOUT_MS: u64 = 5_000;
+
+async fn wait_for(bridge: &dyn BridgeClientTrait, args: &Value) -> Result<String> {
+    let selector = get_string_arg(args, "selector")?;
+    let condition = args
+        .get("condition")
+        .and_then(|v| v.as_str())
+        .unwrap_or("visible");
+    let condition = match condition {
+        "exists" | "visible" | "hidden" => serde_json::json!({ "type": condition }),
+        "text_equals" => {
+            serde_json::json!({ "type": condition, "value": get_string_arg(args, "text")? })
+        }
+        other => {
+            return Err(anyhow!(
+                "Unknown condition '{}', expected exists, visible, hidden or text_equals",
+                other
+            ))
+        }
+    };
+    let timeout_ms = args
+        .get("timeout_ms")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(DEFAULT_WAIT_TIMEOUT_MS);
+
+    let resp = bridge.watch(&selector, condition, timeout_ms).await?;
+    if !resp.matched {
+        return Err(anyhow!(
+            "Timed out after {}ms waiting for '{}'",
+            resp.elapsed_ms,
+            selector
+        ));
+    }
+    Ok(match resp.value {
+        Some(text) if !text.is_empty() => format!(
+            "Matched after {}ms: {}",
+            resp.elapsed_ms,
+            summarize(&text, 200)
+        ),
+        _ => format!("Matched after {}ms", resp.elapsed_ms),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_bridge::{ok, ok_json};
+    use crate::tools::call_tool;
+    use crate::tools::tests::mock;
+
+    #[test]
+    fn test_check_text_match() {
+        let result = check_text("\"  Click me \"", "Click me").unwrap();
+        assert!(result.contains("Click me"));
+    }
+
+    #[test]
+    fn test_check_text_mismatch() {
+        let err = check_text("\"Cancel\"", "Submit").unwrap_err();
+        assert_eq!(err.to_string(), "expected 'Submit' but got 'Cancel'");
+    }
+
+    #[test]
+    fn test_check_text_not_found() {
+        assert!(check_text("null", "Submit").is_err());
+    }
+
+    #[test]
+    fn test_check_visible() {
+        let report = r#"{"found": true, "visible": true, "summary": "Element is visible"}"#;
+        assert!(check_visible(report, ".modal").is_ok());
+    }
+
+    #[test]
+    fn test_check_visible_hidden() {
+        let report = r#"{"found": true, "visible": false, "summary": "Element has display: none"}"#;
+        let err = check_visible(report, ".modal").unwrap_err();
+        assert!(err.to_string().contains("display: none"));
+    }
+
+    #[test]
+    fn test_check_visible_not_found() {
+        let report = r#"{"found": false, "error": "Element not found"}"#;
+        assert!(check_visible(report, ".modal").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_assert_text() {
+        let bridge = mock(&[("/query", ok("\"Submit\""))]);
+        let args = json!({"selector": "button", "expected": "Submit"});
+        assert!(call_tool(&bridge, "assert_text", args).await.is_ok());
+
+        let args = json!({"selector": "button", "expected": "Cancel"});
+        let err = call_tool(&bridge, "assert_text", args).await.unwrap_err();
+        assert_eq!(err.to_string(), "expected 'Cancel' but got 'Submit'");
+    }
+
+    #[tokio::test]
+    async fn test_assert_visible_hidden() {
+        let report = json!({"found": true, "visible": false, "summary": "Element has zero size"});
+        let bridge = mock(&[("/inspect", ok_json(&report))]);
+        let args = json!({"selector": ".modal"});
+        let err = call_tool(&bridge, "assert_visible", args)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("zero size"));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for() {
+        let bridge = mock(&[]).with_json(
+            "/watch",
+            json!({"matched": true, "elapsed_ms": 300, "value": "Saved"}),
+        );
+        let args = json!({"selector": "#toast", "condition": "text_equals", "text": "Saved"});
+        let result = call_tool(&bridge, "wait_for", args).await.unwrap();
+        assert_eq!(result, "Matched after 300ms: Saved");
+        assert_eq!(
+            bridge.requests()[0].body.clone().unwrap(),
+            json!({"selector": "#toast", "condition": {"type": "text_equals", "value": "Saved"}, "timeout_ms": 5000})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_timeout() {
+        let bridge = mock(&[]).with_json(
+            "/watch",
+            json!({"matched": false, "elapsed_ms": 1000, "value": null}),
+        );
+        let args = json!({"selector": "#toast", "timeout_ms": 1000});
+        let err = call_tool(&bridge, "wait_for", args).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Timed out after 1000ms waiting for '#toast'"
+        );
+        assert_eq!(
+            bridge.requests()[0].body.clone().unwrap()["condition"],
+            json!({"type": "visible"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_unknown_condition() {
+        let bridge = mock(&[]);
+        let args = json!({"selector": "#toast", "condition": "focused"});
+        let err = call_tool(&bridge, "wait_for", args).await.unwrap_err();
+        assert!(err.to_string().starts_with("Unknown condition 'focused'"));
+        assert!(bridge.requests().is_empty());
+    }
+}
@@ -0,0 +1,521 @@
+//! Diagnostic tools: health checks, performance, fonts, images and coverage.
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+
+use super::{extract_json_pretty, get_string_arg, tool_def, tool_def_optional};
+use crate::bridge::{
+    BridgeClientTrait, CoverageResponse, CustomDiagnoseResponse, DiagnosticsApi,
+    ImageStatusResponse, MeasureRequest, MeasureResponse, MediaApi, MissingFontsResponse,
+    PerfDiagnoseResponse,
+};
+
+pub(super) async fn call(
+    bridge: &dyn BridgeClientTrait,
+    name: &str,
+    args: &Value,
+) -> Result<Option<String>> {
+    let text = match name {
+        "diagnose" => diagnose(bridge).await,
+        "register_health_check" => {
+            let name = get_string_arg(args, "name")?;
+            let script = get_string_arg(args, "script")?;
+            let severity = args
+                .get("severity")
+                .and_then(|v| v.as_str())
+                .unwrap_or("warning");
+            register_health_check(bridge, &name, &script, severity).await
+        }
+        "run_health_checks" => run_health_checks(bridge).await,
+        "diagnose_performance" => Ok(format_perf_diagnosis(&bridge.diagnose_performance().await?)),
+        "measure_performance" => {
+            let req = MeasureRequest {
+                label: get_string_arg(args, "label")?,
+                script: get_string_arg(args, "script")?,
+                repetitions: args
+                    .get("repetitions")
+                    .and_then(|v| v.as_u64())
+                    .map(|v| v as u32),
+            };
+            Ok(format_measure(&bridge.measure(req).await?))
+        }
+        "check_missing_fonts" => Ok(format_missing_fonts(&bridge.missing_fonts().await?)),
+        "inspect_images" => inspect_images(bridge).await,
+        "start_coverage" => {
+            if !bridge.coverage_start().await?.started {
+                return Err(anyhow!("Coverage collection did not start"));
+            }
+            Ok("Coverage collection started".to_string())
+        }
+        "get_coverage" => format_coverage(bridge.coverage_report().await?),
+        _ => return Ok(None),
+    };
+    text.map(Some)
+}
+
+pub(super) fn definitions() -> Vec<Value> {
+    vec![
+        tool_def("diagnose", "Quick UI health check", json!({})),
+        tool_def_optional(
+            "register_health_check",
+            "Register a custom health check script that should return true when the UI is healthy",
+            json!({
+                "name": { "type": "string", "description": "Unique check name (re-registering replaces it)" },
+                "script": { "type": "string", "description": "JavaScript returning true when healthy, or a failure message" },
+                "severity": { "type": "string", "enum": ["error", "warning", "info"], "description": "Severity (default: warning)" }
+            }),
+            vec!["name", "script"]
+        ),
+        tool_def("run_health_checks", "Run all registered custom health checks", json!({})),
+        tool_def("diagnose_performance", "Check for rendering performance issues: long tasks, layout thrashing, large DOM trees, animations without will-change and document.write calls, with a 0-100 score", json!({})),
+        tool_def_optional(
+            "measure_performance",
+            "Time a script over repeated runs with performance.mark/measure, e.g. a click that re-renders a list, and report mean, min, max and standard deviation in milliseconds",
+            json!({
+                "label": { "type": "string", "description": "Name for the measurement, e.g. \"add-row\"" },
+                "script": { "type": "string", "description": "Function body to time; runs synchronously, so awaited work isn't counted" },
+                "repetitions": { "type": "integer", "description": "How many times to run the script, 1-1000 (default: 10)" }
+            }),
+            vec!["label", "script"],
+        ),
+        tool_def("check_missing_fonts", "List the font families the page uses that failed to load or aren't installed, plus those still loading and loaded", json!({})),
+        tool_def("inspect_images", "List broken images and images missing alt text, and outline the broken ones with a red border", json!({})),
+        tool_def_optional(
+            "start_coverage",
+            "Start collecting JS code coverage; the app must expose window.__coverage_start and window.__coverage_report backed by the V8 coverage API",
+            json!({}),
+            vec![]
+        ),
+        tool_def_optional(
+            "get_coverage",
+            "Report executed bytes per script since start_coverage",
+            json!({}),
+            vec![]
+        ),
+    ]
+}
+
+async fn diagnose(bridge: &dyn BridgeClientTrait) -> Result<String> {
+    let resp = bridge.diagnose().await?;
+    extract_json_pretty(resp)
+}
+
+async fn register_health_check(
+    bridge: &dyn BridgeClientTrait,
+    name: &str,
+    script: &str,
+    severity: &str,
+) -> Result<String> {
+    let resp = bridge.register_check(name, script, severity).await?;
+    if !resp.success {
+        return Err(anyhow!("Failed to register check '{}'", name));
+    }
+    Ok(format!(
+        "Registered '{}' ({} checks: {})",
+        name,
+        resp.checks.len(),
+        resp.checks.join(", ")
+    ))
+}
+
+async fn run_health_checks(bridge: &dyn BridgeClientTrait) -> Result<String> {
+    let resp = bridge.custom_checks().await?;
+    Ok(format_check_results(&resp))
+}
+
+fn format_check_results(resp: &CustomDiagnoseResponse) -> String {
+    if resp.checks.is_empty() {
+        return "No health checks registered".to_string();
+    }
+    resp.checks
+        .iter()
+        .map(|check| {
+            let mut line = format!(
+                "[{}] {} ({})",
+                if check.passed { "PASS" } else { "FAIL" },
+                check.name,
+                check.severity
+            );
+            if !check.passed {
+                let detail = check
+                    .response
+                    .error
+                    .as_deref()
+                    .or(check.response.result.as_deref())
+                    .unwrap_or("no result");
+                line.push_str(&format!(": {}", detail));
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn format_missing_fonts(resp: &MissingFontsResponse) -> String {
+    let mut lines = Vec::new();
+    if resp.missing.is_empty() {
+        lines.push("No missing fonts".to_string());
+    } else {
+        lines.push(format!(
+            "Missing (falling back): {}",
+            resp.missing.join(", ")
+        ));
+    }
+    if !resp.loading.is_empty() {
+        lines.push(format!("Loading: {}", resp.loading.join(", ")));
+    }
+    if !resp.loaded.is_empty() {
+        lines.push(format!("Loaded: {}", resp.loaded.join(", ")));
+    }
+    lines.join("\n")
+}
+
+fn format_perf_diagnosis(resp: &PerfDiagnoseResponse) -> String {
+    let mut lines = vec![format!("Performance score: {}/100", resp.score)];
+    if resp.issues.is_empty() {
+        lines.push("No performance issues found".to_string());
+    }
+    for issue in &resp.issues {
+        lines.push(format!(
+            "[{}] {}: {}",
+            issue.severity.to_uppercase(),
+            issue.kind,
+            issue.message
+        ));
+    }
+    lines.join("\n")
+}
+
+fn format_measure(resp: &MeasureResponse) -> String {
+    format!(
+        "{}: mean {:.3}ms, min {:.3}ms, max {:.3}ms, stddev {:.3}ms over {} run(s)",
+        resp.label,
+        resp.mean_ms,
+        resp.min_ms,
+        resp.max_ms,
+        resp.stddev_ms,
+        resp.samples.len()
+    )
+}
+
+/// Id of the style `inspect_images` outlines broken images with.
+const BROKEN_IMAGES_STYLE_ID: &str = "broken-images";
+
+async fn inspect_images(bridge: &dyn BridgeClientTrait) -> Result<String> {
+    let resp = bridge.image_status().await?;
+    let mut output = format_image_status(&resp);
+    match broken_images_css(&resp) {
+        Some(css) => {
+            bridge.inject_css(BROKEN_IMAGES_STYLE_ID, &css).await?;
+            output.push_str("\nBroken images are highlighted with a red border");
+        }
+        // Clear the highlight from an earlier call once the images load
+        None => {
+            bridge.remove_css(BROKEN_IMAGES_STYLE_ID).await?;
+        }
+    }
+    Ok(output)
+}
+
+/// A red border rule matching the broken images by their `src` attribute.
+fn broken_images_css(resp: &ImageStatusResponse) -> Option<String> {
+    let selectors: Vec<String> = resp
+        .images
+        .iter()
+        .filter(|image| image.is_broken())
+        .map(|image| {
+            if image.src.is_empty() {
+                "img:not([src])".to_string()
+            } else {
+                let escaped = image
+                    .src
+                    .replace('\\', "\\\\")
+                    .replace('"', "\\\"")
+                    .replace('\n', "\\a ");
+                format!("img[src=\"{}\"]", escaped)
+            }
+        })
+        .collect();
+    if selectors.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "{} {{ border: 3px solid red !important; }}",
+        selectors.join(", ")
+    ))
+}
+
+fn format_image_status(resp: &ImageStatusResponse) -> String {
+    if resp.images.is_empty() {
+        return "No images on the page".to_string();
+    }
+    let mut lines = vec![format!(
+        "{} image(s), {} broken, {} missing alt",
+        resp.images.len(),
+        resp.broken_count,
+        resp.missing_alt_count
+    )];
+    for image in &resp.images {
+        let mut problems = Vec::new();
+        if image.is_broken() {
+            problems.push("broken");
+        }
+        if image.alt.is_none() {
+            problems.push("no alt");
+        }
+        if !problems.is_empty() {
+            let src = if image.src.is_empty() {
+                "(no src)"
+            } else {
+                &image.src
+            };
+            lines.push(format!("- {}: {}", src, problems.join(", ")));
+        }
+    }
+    lines.join("\n")
+}
+
+fn format_coverage(resp: CoverageResponse) -> Result<String> {
+    if resp.scripts.is_empty() {
+        return Ok("No script coverage reported".to_string());
+    }
+    fn percent(covered: u32, total: u32) -> f64 {
+        if total == 0 {
+            0.0
+        } else {
+            covered as f64 * 100.0 / total as f64
+        }
+    }
+    let covered: u32 = resp.scripts.iter().map(|s| s.covered_bytes).sum();
+    let total: u32 = resp.scripts.iter().map(|s| s.total_bytes).sum();
+    let mut lines = vec![format!(
+        "{} script(s), {}/{} bytes covered ({:.1}%)",
+        resp.scripts.len(),
+        covered,
+        total,
+        percent(covered, total)
+    )];
+    for script in &resp.scripts {
+        let url = if script.url.is_empty() {
+            "(inline)"
+        } else {
+            &script.url
+        };
+        lines.push(format!(
+            "{}: {}/{} bytes ({:.1}%) in {} range(s)",
+            url,
+            script.covered_bytes,
+            script.total_bytes,
+            percent(script.covered_bytes, script.total_bytes),
+            script.covered_ranges.len()
+        ));
+    }
+    Ok(lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_bridge::ok_json;
+    use crate::tools::call_tool;
+    use crate::tools::tests::mock;
+
+    #[tokio::test]
+    async fn test_diagnose() {
+        let bridge = mock(&[("/diagnose", ok_json(&json!({"issues": []})))]);
+        let result = call_tool(&bridge, "diagnose", json!({})).await.unwrap();
+        assert!(result.contains("\"issues\": []"));
+    }
+
+    #[tokio::test]
+    async fn test_register_health_check_defaults_to_warning() {
+        let bridge = mock(&[]).with_json(
+            "/diagnose/register",
+            json!({"success": true, "checks": ["badge"]}),
+        );
+        let args = json!({"name": "badge", "script": "return true"});
+        let result = call_tool(&bridge, "register_health_check", args)
+            .await
+            .unwrap();
+        assert_eq!(result, "Registered 'badge' (1 checks: badge)");
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(body["severity"], "warning");
+    }
+
+    #[tokio::test]
+    async fn test_run_health_checks() {
+        let bridge = mock(&[]).with_json(
+            "/diagnose/custom",
+            json!({"checks": [
+                {"name": "header", "severity": "error", "passed": true,
+                    "response": {"success": true, "result": "true"}},
+                {"name": "badge", "severity": "warning", "passed": false,
+                    "response": {"success": false, "error": "TypeError"}}
+            ]}),
+        );
+        let result = call_tool(&bridge, "run_health_checks", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "[PASS] header (error)\n[FAIL] badge (warning): TypeError"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_diagnose_performance() {
+        let bridge = mock(&[]).with_json(
+            "/diagnose/performance",
+            json!({"score": 65, "issues": [
+                {"kind": "document_write", "severity": "error", "message": "document.write was called 1 time(s)"},
+                {"kind": "dom_size", "severity": "warning", "message": "2400 elements, nested up to 12 deep"}
+            ]}),
+        );
+        let result = call_tool(&bridge, "diagnose_performance", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "Performance score: 65/100\n\
+             [ERROR] document_write: document.write was called 1 time(s)\n\
+             [WARNING] dom_size: 2400 elements, nested up to 12 deep"
+        );
+
+        let bridge =
+            mock(&[]).with_json("/diagnose/performance", json!({"score": 100, "issues": []}));
+        let result = call_tool(&bridge, "diagnose_performance", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "Performance score: 100/100\nNo performance issues found"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_measure_performance() {
+        let bridge = mock(&[]).with_json(
+            "document.querySelector('#add')",
+            json!({
+                "label": "add-row",
+                "mean_ms": 4.0,
+                "min_ms": 2.0,
+                "max_ms": 6.0,
+                "stddev_ms": 1.4142,
+                "samples": [2.0, 4.0, 4.0, 6.0]
+            }),
+        );
+        let args = json!({
+            "label": "add-row",
+            "script": "document.querySelector('#add').click()",
+            "repetitions": 4
+        });
+        let result = call_tool(&bridge, "measure_performance", args)
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "add-row: mean 4.000ms, min 2.000ms, max 6.000ms, stddev 1.414ms over 4 run(s)"
+        );
+        let request = &bridge.requests()[0];
+        assert_eq!(request.path, "/measure");
+        assert_eq!(request.body.as_ref().unwrap()["repetitions"], 4);
+    }
+
+    #[tokio::test]
+    async fn test_check_missing_fonts() {
+        let bridge = mock(&[]).with_json(
+            "/fonts/missing",
+            json!({"missing": ["Brand Display", "Icons"], "loading": [], "loaded": ["Inter"]}),
+        );
+        let result = call_tool(&bridge, "check_missing_fonts", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "Missing (falling back): Brand Display, Icons\nLoaded: Inter"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_inspect_images_highlights_broken_images() {
+        let bridge = mock(&[])
+            .with_json(
+                "/image-status",
+                json!({
+                    "images": [
+                        {"src": "/logo.png", "complete": true, "natural_width": 120, "natural_height": 40,
+                         "current_src": "http://localhost/logo.png", "alt": "Logo", "loading": "auto"},
+                        {"src": "/a \"b\".png", "complete": true, "natural_width": 0, "natural_height": 0,
+                         "current_src": "http://localhost/a%20%22b%22.png", "alt": null, "loading": "auto"},
+                        {"src": "/hero.jpg", "complete": false, "natural_width": 0, "natural_height": 0,
+                         "current_src": "", "alt": null, "loading": "lazy"}
+                    ],
+                    "broken_count": 1,
+                    "missing_alt_count": 2
+                }),
+            )
+            .with_json(
+                "/inject-css",
+                json!({"success": true, "style_id": "dioxus-inspector-broken-images"}),
+            );
+        let result = call_tool(&bridge, "inspect_images", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "3 image(s), 1 broken, 2 missing alt\n- /a \"b\".png: broken, no alt\n\
+             - /hero.jpg: no alt\nBroken images are highlighted with a red border"
+        );
+        assert_eq!(
+            bridge.requests()[1].body.clone().unwrap(),
+            json!({
+                "id": "broken-images",
+                "css": "img[src=\"/a \\\"b\\\".png\"] { border: 3px solid red !important; }"
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_inspect_images_clears_highlight() {
+        let bridge = mock(&[])
+            .with_json(
+                "/image-status",
+                json!({"images": [], "broken_count": 0, "missing_alt_count": 0}),
+            )
+            .with_json(
+                "/remove-css",
+                json!({"success": false, "style_id": "dioxus-inspector-broken-images"}),
+            );
+        let result = call_tool(&bridge, "inspect_images", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(result, "No images on the page");
+        assert_eq!(bridge.requests()[1].path, "/remove-css");
+    }
+
+    #[tokio::test]
+    async fn test_get_coverage() {
+        let bridge = mock(&[]).with_json(
+            "/coverage/report",
+            json!({"scripts": [
+                {"url": "http://localhost/app.js", "covered_ranges": [[0, 30], [50, 80]], "total_bytes": 100, "covered_bytes": 60},
+                {"url": "", "covered_ranges": [], "total_bytes": 20, "covered_bytes": 0}
+            ]}),
+        );
+        let result = call_tool(&bridge, "get_coverage", json!({})).await.unwrap();
+        assert!(result.starts_with("2 script(s), 60/120 bytes covered (50.0%)"));
+        assert!(result.contains("http://localhost/app.js: 60/100 bytes (60.0%) in 2 range(s)"));
+        assert!(result.contains("(inline): 0/20 bytes (0.0%)"));
+    }
+
+    #[tokio::test]
+    async fn test_start_coverage() {
+        let bridge = mock(&[]).with_json("/coverage/start", json!({"started": true}));
+        let result = call_tool(&bridge, "start_coverage", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(result, "Coverage collection started");
+        assert_eq!(bridge.requests()[0].path, "/coverage/start");
+    }
+}
@@ -0,0 +1,510 @@
+//! DOM tree tools: reading the tree, snapshots and diffs, and adding or removing elements.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+
+use super::{
+    extract_json_pretty, get_string_arg, get_string_list_arg, get_string_map_arg, tool_def,
+    tool_def_optional,
+};
+use crate::bridge::{
+    AncestorResponse, BridgeClientTrait, ComponentNode, CreateElementRequest, DomApi,
+    DomDiffResponse, DomNode, DomPageResponse,
+};
+
+pub(super) async fn call(
+    bridge: &dyn BridgeClientTrait,
+    name: &str,
+    args: &Value,
+) -> Result<Option<String>> {
+    let text = match name {
+        "get_dom" | "shadow_dom" => {
+            let depth = args.get("depth").and_then(|v| v.as_u64()).map(|v| v as u32);
+            let max_nodes = args
+                .get("max_nodes")
+                .and_then(|v| v.as_u64())
+                .map(|v| v as u32);
+            let selector = args.get("selector").and_then(|v| v.as_str());
+            let include_shadow = name == "shadow_dom";
+            get_dom(bridge, depth, max_nodes, selector, include_shadow).await
+        }
+        "get_dom_paged" => {
+            let depth = args.get("depth").and_then(|v| v.as_u64()).map(|v| v as u32);
+            let max_nodes = args
+                .get("max_nodes")
+                .and_then(|v| v.as_u64())
+                .map(|v| v as u32);
+            let selector = args.get("selector").and_then(|v| v.as_str());
+            let include_shadow = args
+                .get("include_shadow")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            let cursor = args.get("cursor").and_then(|v| v.as_str());
+            let page_size = args
+                .get("page_size")
+                .and_then(|v| v.as_u64())
+                .map_or(DEFAULT_DOM_PAGE_SIZE, |v| v as u32);
+            let resp = bridge
+                .dom_page(
+                    depth,
+                    max_nodes,
+                    selector,
+                    include_shadow,
+                    cursor,
+                    page_size,
+                )
+                .await?;
+            Ok(format_dom_page(&resp))
+        }
+        "get_component_tree" => {
+            let resp = bridge.component_tree().await?;
+            Ok(format_component_tree(&resp.root))
+        }
+        "dom_snapshot" => {
+            let resp = bridge.dom_snapshot().await?;
+            Ok(format!(
+                "Snapshot {} ({} nodes)",
+                resp.snapshot_id, resp.node_count
+            ))
+        }
+        "dom_diff" => {
+            let before_id = get_string_arg(args, "before_id")?;
+            let after_id = args.get("after_id").and_then(|v| v.as_str());
+            let resp = bridge.dom_diff(&before_id, after_id).await?;
+            Ok(format_dom_diff(&resp))
+        }
+        "find_common_ancestor" => {
+            let selectors = get_string_list_arg(args, "selectors")?
+                .ok_or_else(|| anyhow!("Missing 'selectors' argument"))?;
+            Ok(format_common_ancestor(
+                &bridge.common_ancestor(selectors).await?,
+            ))
+        }
+        "create_element" => create_element(bridge, args).await,
+        "remove_element" => {
+            let selector = get_string_arg(args, "selector")?;
+            let resp = bridge.remove_element(&selector).await?;
+            Ok(format!(
+                "Removed {} element(s) matching {}",
+                resp.removed, selector
+            ))
+        }
+        _ => return Ok(None),
+    };
+    text.map(Some)
+}
+
+pub(super) fn definitions() -> Vec<Value> {
+    vec![
+        tool_def_optional(
+            "get_dom",
+            "Get simplified DOM tree (limited to prevent token overflow)",
+            json!({
+                "depth": { "type": "number", "description": "Max depth to traverse (default: 10)" },
+                "max_nodes": { "type": "number", "description": "Max nodes to return (default: 500)" },
+                "selector": { "type": "string", "description": "CSS selector for root element (default: body)" }
+            }),
+            vec![]
+        ),
+        tool_def_optional(
+            "shadow_dom",
+            "Get simplified DOM tree including open shadow roots (listed under each host's shadow_root)",
+            json!({
+                "depth": { "type": "number", "description": "Max depth to traverse (default: 10)" },
+                "max_nodes": { "type": "number", "description": "Max nodes to return (default: 500)" },
+                "selector": { "type": "string", "description": "CSS selector for root element (default: body)" }
+            }),
+            vec![]
+        ),
+        tool_def_optional(
+            "get_dom_paged",
+            "Read a large DOM tree a page at a time, as a flat list of nodes indented by depth; pass the returned cursor to get the next page",
+            json!({
+                "cursor": { "type": "string", "description": "Cursor from the previous page (default: start at the root)" },
+                "page_size": { "type": "number", "description": "Nodes per page (default: 200)" },
+                "depth": { "type": "number", "description": "Max depth to traverse (default: 10)" },
+                "max_nodes": { "type": "number", "description": "Max nodes in the whole tree (default: 50000)" },
+                "selector": { "type": "string", "description": "CSS selector for root element (default: body)" },
+                "include_shadow": { "type": "boolean", "description": "Include open shadow roots (default: false)" }
+            }),
+            vec![]
+        ),
+        tool_def("get_component_tree", "Show the Dioxus component tree, rebuilt from data-dioxus-id elements (debug builds; names come from data-component attributes)", json!({})),
+        tool_def("dom_snapshot", "Store the current DOM in the bridge and return a snapshot id for dom_diff", json!({})),
+        tool_def_optional(
+            "dom_diff",
+            "Show what changed in the DOM between two snapshots, or between a snapshot and now",
+            json!({
+                "before_id": { "type": "string", "description": "Snapshot id from dom_snapshot" },
+                "after_id": { "type": "string", "description": "Later snapshot id (default: snapshot the DOM now)" }
+            }),
+            vec!["before_id"],
+        ),
+        tool_def(
+            "find_common_ancestor",
+            "Find the nearest element containing every element the selectors match, e.g. the shared container behind a z-index or layout problem. Fails naming any selector that matches nothing",
+            json!({
+                "selectors": { "type": "array", "items": { "type": "string" }, "description": "CSS selectors; every element each matches is included" }
+            }),
+        ),
+        tool_def_optional(
+            "create_element",
+            "Insert a new element, e.g. a test fixture, into or next to an existing one; returns a selector for it",
+            json!({
+                "parent_selector": { "type": "string", "description": "CSS selector of the element to insert into or next to" },
+                "tag": { "type": "string", "description": "Tag name, e.g. div" },
+                "attributes": { "type": "object", "additionalProperties": { "type": "string" }, "description": "Attributes to set; a data-test-id is generated when there's no id" },
+                "text": { "type": "string", "description": "Text content" },
+                "position": { "type": "string", "enum": ["append", "prepend", "before", "after"], "description": "Where to insert relative to the parent (default: append)" }
+            }),
+            vec!["parent_selector", "tag"]
+        ),
+        tool_def("remove_element", "Remove every element matching a CSS selector, e.g. fixtures added with create_element", json!({
+            "selector": { "type": "string", "description": "CSS selector" }
+        })),
+    ]
+}
+
+async fn get_dom(
+    bridge: &dyn BridgeClientTrait,
+    depth: Option<u32>,
+    max_nodes: Option<u32>,
+    selector: Option<&str>,
+    include_shadow: bool,
+) -> Result<String> {
+    let resp = bridge
+        .dom(depth, max_nodes, selector, include_shadow)
+        .await?;
+    extract_json_pretty(resp)
+}
+
+/// Nodes per `get_dom_paged` page when the caller doesn't say.
+const DEFAULT_DOM_PAGE_SIZE: u32 = 200;
+
+/// One node per line, indented by depth, then the cursor for the next page.
+fn format_dom_page(resp: &DomPageResponse) -> String {
+    let mut lines = match (resp.nodes.first(), resp.nodes.last()) {
+        (Some(first), Some(last)) => vec![format!(
+            "Nodes {}-{} of {}:",
+            first.index, last.index, resp.total_nodes
+        )],
+        _ => vec![format!(
+            "No nodes on this page ({} in total)",
+            resp.total_nodes
+        )],
+    };
+    for node in &resp.nodes {
+        lines.push(format!(
+            "{}[{}] {}",
+            "  ".repeat(node.depth as usize),
+            node.index,
+            dom_node_label(node)
+        ));
+    }
+    if let Some(cursor) = &resp.next_cursor {
+        lines.push(format!("More nodes remain; next cursor: {}", cursor));
+    }
+    lines.join("\n")
+}
+
+fn dom_node_label(node: &DomNode) -> String {
+    let mut label = match (&node.tag, &node.text) {
+        (Some(tag), _) => {
+            let mut label = format!("<{}>", tag);
+            if let Some(id) = &node.id {
+                label.push_str(&format!(" #{}", id));
+            }
+            if let Some(class) = &node.class {
+                label.push_str(&format!(
+                    " .{}",
+                    class.split_whitespace().collect::<Vec<_>>().join(".")
+                ));
+            }
+            label
+        }
+        (None, text) => format!("{:?}", text.as_deref().unwrap_or("")),
+    };
+    if let Some(reason) = &node.truncated {
+        label.push_str(&format!(" (truncated: {})", reason));
+    }
+    if node.in_shadow_root {
+        label.push_str(" (shadow)");
+    }
+    label
+}
+
+fn format_dom_diff(resp: &DomDiffResponse) -> String {
+    if resp.changes.is_empty() {
+        return format!("No changes (after: {})", resp.after_id);
+    }
+    let compact =
+        |value: &Option<Value>| value.as_ref().map_or("null".to_string(), Value::to_string);
+    let mut lines = vec![format!(
+        "{} changes (after: {})",
+        resp.changes.len(),
+        resp.after_id
+    )];
+    for change in &resp.changes {
+        lines.push(match change.kind.as_str() {
+            "added" => format!("+ {} {}", change.path, compact(&change.after)),
+            "removed" => format!("- {} {}", change.path, compact(&change.before)),
+            _ => format!(
+                "~ {} {}: {} -> {}",
+                change.path,
+                change.field.as_deref().unwrap_or("node"),
+                compact(&change.before),
+                compact(&change.after)
+            ),
+        });
+    }
+    lines.join("\n")
+}
+
+fn format_common_ancestor(resp: &AncestorResponse) -> String {
+    format!(
+        "Common ancestor: {} <{}> at depth {}",
+        resp.ancestor_selector, resp.ancestor_tag, resp.depth
+    )
+}
+
+async fn create_element(bridge: &dyn BridgeClientTrait, args: &Value) -> Result<String> {
+    let attributes = if args.get("attributes").is_some() {
+        get_string_map_arg(args, "attributes")?
+    } else {
+        HashMap::new()
+    };
+    let req = CreateElementRequest {
+        parent_selector: get_string_arg(args, "parent_selector")?,
+        tag: get_string_arg(args, "tag")?,
+        attributes,
+        text: args.get("text").and_then(|v| v.as_str()).map(String::from),
+        position: args
+            .get("position")
+            .and_then(|v| v.as_str())
+            .map(String::from),
+    };
+    let tag = req.tag.clone();
+    let resp = bridge.create_element(req).await?;
+    if !resp.success {
+        return Err(anyhow!(resp
+            .error
+            .unwrap_or_else(|| "Unknown error".to_string())));
+    }
+    Ok(format!(
+        "Created <{}>, select it with {}",
+        tag,
+        resp.selector.unwrap_or_default()
+    ))
+}
+
+fn format_component_tree(root: &ComponentNode) -> String {
+    if root.children.is_empty() {
+        return "No data-dioxus-id elements found (the app may be a release build)".to_string();
+    }
+    fn walk(node: &ComponentNode, depth: usize, lines: &mut Vec<String>) {
+        lines.push(format!(
+            "{}{} [{}]",
+            "  ".repeat(depth),
+            node.name.as_deref().unwrap_or("(unnamed)"),
+            node.id
+        ));
+        for child in &node.children {
+            walk(child, depth + 1, lines);
+        }
+    }
+    let mut lines = Vec::new();
+    for child in &root.children {
+        walk(child, 0, &mut lines);
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_bridge::ok_json;
+    use crate::tools::call_tool;
+    use crate::tools::tests::mock;
+    use reqwest::Method;
+
+    #[tokio::test]
+    async fn test_get_dom_passes_params() {
+        let bridge = mock(&[("/dom", ok_json(&json!({"tag": "div"})))]);
+        let result = call_tool(&bridge, "get_dom", json!({"depth": 2, "selector": "#app"}))
+            .await
+            .unwrap();
+        assert!(result.contains("\"tag\": \"div\""));
+        assert_eq!(bridge.requests()[0].path, "/dom?depth=2&selector=%23app");
+    }
+
+    #[tokio::test]
+    async fn test_get_dom_paged() {
+        let bridge = mock(&[]).with_json(
+            "/dom",
+            json!({
+                "nodes": [
+                    {"index": 2, "parent": 1, "depth": 2, "tag": "slot", "id": null, "class": null, "text": null, "in_shadow_root": true, "truncated": null},
+                    {"index": 3, "parent": 1, "depth": 2, "tag": null, "id": null, "class": null, "text": "Hi", "in_shadow_root": false, "truncated": null},
+                    {"index": 4, "parent": 0, "depth": 1, "tag": "p", "id": "intro", "class": "lead wide", "text": null, "in_shadow_root": false, "truncated": null}
+                ],
+                "next_cursor": "NQ",
+                "total_nodes": 9,
+                "has_more": true
+            }),
+        );
+        let args = json!({"cursor": "Mg", "page_size": 3, "include_shadow": true});
+        let result = call_tool(&bridge, "get_dom_paged", args).await.unwrap();
+        assert_eq!(
+            result,
+            "Nodes 2-4 of 9:\n    \
+             [2] <slot> (shadow)\n    \
+             [3] \"Hi\"\n  \
+             [4] <p> #intro .lead.wide\n\
+             More nodes remain; next cursor: NQ"
+        );
+        assert_eq!(
+            bridge.requests()[0].path,
+            "/dom?include_shadow=true&page_size=3&cursor=Mg"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_shadow_dom() {
+        let bridge = mock(&[("/dom", ok_json(&json!({"tag": "my-widget"})))]);
+        let result = call_tool(&bridge, "shadow_dom", json!({"max_nodes": 50}))
+            .await
+            .unwrap();
+        assert!(result.contains("\"tag\": \"my-widget\""));
+        assert_eq!(
+            bridge.requests()[0].path,
+            "/dom?max_nodes=50&include_shadow=true"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_component_tree() {
+        let bridge = mock(&[]).with_json(
+            "/tree",
+            json!({"root": {"name": null, "id": "root", "children": [
+                {"name": "App", "id": "1", "children": [
+                    {"name": null, "id": "4", "children": []}
+                ]}
+            ]}}),
+        );
+        let result = call_tool(&bridge, "get_component_tree", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(result, "App [1]\n  (unnamed) [4]");
+    }
+
+    #[tokio::test]
+    async fn test_get_component_tree_empty() {
+        let bridge = mock(&[]).with_json(
+            "/tree",
+            json!({"root": {"name": null, "id": "root", "children": []}}),
+        );
+        let result = call_tool(&bridge, "get_component_tree", json!({}))
+            .await
+            .unwrap();
+        assert!(result.starts_with("No data-dioxus-id elements"));
+    }
+
+    #[tokio::test]
+    async fn test_dom_snapshot() {
+        let bridge = mock(&[]).with_json(
+            "/dom/snapshot",
+            json!({"snapshot_id": "67e55044-10b1-426f-9247-bb680e5fe0c8", "node_count": 12}),
+        );
+        let result = call_tool(&bridge, "dom_snapshot", json!({})).await.unwrap();
+        assert_eq!(
+            result,
+            "Snapshot 67e55044-10b1-426f-9247-bb680e5fe0c8 (12 nodes)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dom_diff() {
+        let bridge = mock(&[]).with_json(
+            "/dom/diff",
+            json!({
+                "before_id": "a",
+                "after_id": "b",
+                "changes": [
+                    {"path": "ul > li#new[1]", "kind": "added", "after": {"tag": "li", "id": "new"}},
+                    {"path": "h1 > #text[0]", "kind": "changed", "field": "text", "before": "Hi", "after": "Bye"}
+                ]
+            }),
+        );
+        let result = call_tool(&bridge, "dom_diff", json!({"before_id": "a"}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "2 changes (after: b)\n\
+             + ul > li#new[1] {\"id\":\"new\",\"tag\":\"li\"}\n\
+             ~ h1 > #text[0] text: \"Hi\" -> \"Bye\""
+        );
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(body, json!({"before_id": "a"}));
+    }
+
+    #[tokio::test]
+    async fn test_find_common_ancestor() {
+        let bridge = mock(&[]).with_json(
+            "/dom/ancestors",
+            json!({"ancestor_selector": "main > div.toolbar", "ancestor_tag": "div", "depth": 3}),
+        );
+        let args = json!({"selectors": [".tooltip", "#save"]});
+        let result = call_tool(&bridge, "find_common_ancestor", args)
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "Common ancestor: main > div.toolbar <div> at depth 3"
+        );
+        assert_eq!(
+            bridge.requests()[0].body.clone().unwrap(),
+            json!({"selectors": [".tooltip", "#save"]})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_element() {
+        let bridge = mock(&[]).with_json(
+            "/element/create",
+            json!({"success": true, "test_id": "dioxus-inspector-1", "selector": "[data-test-id=\"dioxus-inspector-1\"]"}),
+        );
+        let args = json!({"parent_selector": "main", "tag": "div", "text": "Saved", "position": "prepend"});
+        let result = call_tool(&bridge, "create_element", args).await.unwrap();
+        assert_eq!(
+            result,
+            "Created <div>, select it with [data-test-id=\"dioxus-inspector-1\"]"
+        );
+        assert_eq!(
+            bridge.requests()[0].body.clone().unwrap(),
+            json!({"parent_selector": "main", "tag": "div", "attributes": {}, "text": "Saved", "position": "prepend"})
+        );
+
+        let bridge = mock(&[]).with_json(
+            "/element/create",
+            json!({"success": false, "error": "Element not found: main"}),
+        );
+        let args = json!({"parent_selector": "main", "tag": "div"});
+        let err = call_tool(&bridge, "create_element", args)
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Element not found: main");
+    }
+
+    #[tokio::test]
+    async fn test_remove_element() {
+        let bridge = mock(&[]).with_json("/element/remove", json!({"removed": 2}));
+        let args = json!({"selector": ".toast"});
+        let result = call_tool(&bridge, "remove_element", args).await.unwrap();
+        assert_eq!(result, "Removed 2 element(s) matching .toast");
+        assert_eq!(bridge.requests()[0].method, Method::DELETE);
+        assert_eq!(bridge.requests()[0].path, "/element/remove?selector=.toast");
+    }
+}
@@ -0,0 +1,162 @@
+//! Tools that run the `dx` CLI.
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+
+use super::{extract_result, tool_def, tool_def_optional};
+use crate::bridge::{BridgeClientTrait, DomApi};
+
+pub(super) async fn call(
+    bridge: &dyn BridgeClientTrait,
+    name: &str,
+    args: &Value,
+) -> Result<Option<String>> {
+    let text = match name {
+        "dom_to_rsx" => {
+            let selector = args.get("selector").and_then(|v| v.as_str());
+            let html = args.get("html").and_then(|v| v.as_str());
+            dom_to_rsx(bridge, selector, html).await
+        }
+        "doctor" => doctor().await,
+        "check" => {
+            let path = args.get("path").and_then(|v| v.as_str());
+            check(path).await
+        }
+        _ => return Ok(None),
+    };
+    text.map(Some)
+}
+
+pub(super) fn definitions() -> Vec<Value> {
+    vec![
+        tool_def_optional(
+            "dom_to_rsx",
+            "Convert DOM HTML to Dioxus RSX code using dx translate",
+            json!({
+                "selector": { "type": "string", "description": "CSS selector to get HTML from" },
+                "html": { "type": "string", "description": "Raw HTML string to convert" }
+            }),
+            vec![], // Neither required - one or the other
+        ),
+        tool_def(
+            "doctor",
+            "Run dx doctor to diagnose Dioxus installation and tools",
+            json!({}),
+        ),
+        tool_def_optional(
+            "check",
+            "Run dx check to find RSX and component issues in the project",
+            json!({
+                "path": { "type": "string", "description": "Project path (defaults to current directory)" }
+            }),
+            vec![],
+        ),
+    ]
+}
+
+async fn dom_to_rsx(
+    bridge: &dyn BridgeClientTrait,
+    selector: Option<&str>,
+    html: Option<&str>,
+) -> Result<String> {
+    // Get HTML from selector or use provided HTML
+    let html_content = match (selector, html) {
+        (Some(sel), _) => {
+            let resp = bridge.query(sel, Some("html")).await?;
+            let json_str = extract_result(resp)?;
+            // The result is JSON-encoded, so we need to unescape it
+            serde_json::from_str::<String>(&json_str)
+                .unwrap_or_else(|_| json_str.trim_matches('"').to_string())
+        }
+        (None, Some(h)) => h.to_string(),
+        (None, None) => return Err(anyhow!("Either 'selector' or 'html' argument is required")),
+    };
+
+    // Shell out to dx translate
+    translate_html_to_rsx(&html_content).await
+}
+
+async fn translate_html_to_rsx(html: &str) -> Result<String> {
+    use std::process::Stdio;
+    use tokio::process::Command;
+
+    let output = Command::new("dx")
+        .args(["translate", "--raw", html])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .map_err(|e| anyhow!("Failed to run 'dx translate': {}. Is dx CLI installed?", e))?;
+
+    if output.status.success() {
+        let rsx = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if rsx.is_empty() {
+            Ok("// No RSX generated (empty input?)".to_string())
+        } else {
+            Ok(rsx)
+        }
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(anyhow!("dx translate failed: {}", stderr.trim()))
+    }
+}
+
+async fn doctor() -> Result<String> {
+    run_dx_command(&["doctor"]).await
+}
+
+async fn check(path: Option<&str>) -> Result<String> {
+    let mut args = vec!["check"];
+    if let Some(p) = path {
+        args.push("-p");
+        args.push(p);
+    }
+    run_dx_command(&args).await
+}
+
+async fn run_dx_command(args: &[&str]) -> Result<String> {
+    use std::process::Stdio;
+    use tokio::process::Command;
+
+    let output = Command::new("dx")
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .map_err(|e| {
+            anyhow!(
+                "Failed to run 'dx {}': {}. Is dx CLI installed?",
+                args[0],
+                e
+            )
+        })?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    // Combine stdout and stderr - dx outputs diagnostics to both
+    let combined = format!("{}{}", stdout, stderr);
+    let trimmed = combined.trim();
+
+    if trimmed.is_empty() {
+        Ok(format!("dx {} completed (no output)", args[0]))
+    } else {
+        Ok(trimmed.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::call_tool;
+    use crate::tools::tests::mock;
+
+    #[tokio::test]
+    async fn test_dom_to_rsx_requires_input() {
+        let bridge = mock(&[]);
+        let result = call_tool(&bridge, "dom_to_rsx", json!({})).await;
+        assert!(result.is_err());
+        assert!(bridge.requests().is_empty());
+    }
+}
@@ -0,0 +1,402 @@
+//! JavaScript evaluation tools.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use futures_util::StreamExt;
+use serde_json::{json, Value};
+
+use super::{extract_result, get_string_arg, get_string_list_arg, tool_def, tool_def_optional};
+use crate::bridge::{
+    BridgeClientTrait, EvalApi, ParallelEvalResponse, SandboxEvalResponse, StreamEvalRequest,
+};
+
+pub(super) async fn call(
+    bridge: &dyn BridgeClientTrait,
+    name: &str,
+    args: &Value,
+) -> Result<Option<String>> {
+    let text = match name {
+        "eval" => {
+            let script = get_string_arg(args, "script")?;
+            eval(bridge, &script).await
+        }
+        "eval_async" => {
+            let script = get_string_arg(args, "script")?;
+            let timeout_ms = args.get("timeout_ms").and_then(|v| v.as_u64());
+            let resp = bridge.eval_async(&script, timeout_ms).await?;
+            extract_result(resp)
+        }
+        "eval_sandboxed" => {
+            let script = get_string_arg(args, "script")?;
+            let max_output_bytes = args
+                .get("max_output_bytes")
+                .and_then(|v| v.as_u64())
+                .map(|n| n as usize);
+            let timeout_ms = args.get("timeout_ms").and_then(|v| v.as_u64());
+            let resp = bridge
+                .eval_sandboxed(&script, max_output_bytes, timeout_ms)
+                .await?;
+            format_sandbox_eval(resp)
+        }
+        "eval_parallel" => {
+            let scripts = get_string_list_arg(args, "scripts")?
+                .ok_or_else(|| anyhow!("Missing 'scripts' argument"))?;
+            let timeout_ms = args.get("timeout_ms").and_then(|v| v.as_u64());
+            Ok(format_parallel_eval(
+                &bridge.eval_parallel(scripts, timeout_ms).await?,
+            ))
+        }
+        "stream_eval" => stream_eval(bridge, args).await,
+        "eval_module" => {
+            let code = get_string_arg(args, "code")?;
+            let resp = bridge.eval_module(&code).await?;
+            extract_result(resp)
+        }
+        "eval_if_exists" => {
+            let selector = get_string_arg(args, "selector")?;
+            let script = get_string_arg(args, "script")?;
+            let else_script = args.get("else_script").and_then(|v| v.as_str());
+            eval_if_exists(bridge, &selector, &script, else_script).await
+        }
+        _ => return Ok(None),
+    };
+    text.map(Some)
+}
+
+pub(super) fn definitions() -> Vec<Value> {
+    vec![
+        tool_def("eval", "Execute JavaScript in the webview", json!({
+            "script": { "type": "string", "description": "JavaScript code" }
+        })),
+        tool_def_optional(
+            "eval_async",
+            "Execute JavaScript that may use await (fetch, IndexedDB, ...); returns the resolved value",
+            json!({
+                "script": { "type": "string", "description": "Async function body, e.g. \"return (await fetch('/api')).status\"" },
+                "timeout_ms": { "type": "integer", "description": "Fail if the promise hasn't settled after this many milliseconds" }
+            }),
+            vec!["script"],
+        ),
+        tool_def_optional(
+            "eval_sandboxed",
+            "Execute untrusted or possibly runaway JavaScript in a Web Worker, which is terminated on timeout so it can't hang the app. The script has no DOM access; long results are truncated",
+            json!({
+                "script": { "type": "string", "description": "Async function body, e.g. \"return heavyComputation()\"" },
+                "max_output_bytes": { "type": "integer", "description": "Truncate the JSON result beyond this many bytes (default: 65536)" },
+                "timeout_ms": { "type": "integer", "description": "Terminate the worker after this many milliseconds (default: 5000)" }
+            }),
+            vec!["script"],
+        ),
+        tool_def_optional(
+            "eval_parallel",
+            "Run several independent scripts at once, e.g. one per selector to check, with each script's result and timing. Scripts fail independently, including on timeout",
+            json!({
+                "scripts": { "type": "array", "items": { "type": "string" }, "description": "Function bodies, e.g. \"return document.title\"" },
+                "timeout_ms": { "type": "integer", "description": "Report a script as failed if it hasn't answered after this many milliseconds (default: no limit)" }
+            }),
+            vec!["scripts"],
+        ),
+        tool_def_optional(
+            "stream_eval",
+            "Run a setup script, then poll another script and collect each result, e.g. to follow the progress of a long-running task. Polling stops once the poll script returns null",
+            json!({
+                "setup_script": { "type": "string", "description": "Function body run once before polling, e.g. to start the task" },
+                "poll_script": { "type": "string", "description": "Function body run on every poll; return null to stop" },
+                "interval_ms": { "type": "integer", "description": "Delay between polls in milliseconds (default: 500)" },
+                "max_events": { "type": "integer", "description": "Most results to collect (default: 20)" }
+            }),
+            vec!["poll_script"],
+        ),
+        tool_def("eval_module", "Execute JavaScript as an ES module (supports import and top-level await); returns the default export", json!({
+            "code": { "type": "string", "description": "Module source, e.g. \"import { x } from '/assets/app.js'; export default x;\"" }
+        })),
+        tool_def_optional(
+            "eval_if_exists",
+            "Execute JavaScript only if a CSS selector matches, in a single round-trip",
+            json!({
+                "selector": { "type": "string", "description": "CSS selector that must match" },
+                "script": { "type": "string", "description": "JavaScript to run when it matches" },
+                "else_script": { "type": "string", "description": "JavaScript to run otherwise (default: return null)" }
+            }),
+            vec!["selector", "script"],
+        ),
+    ]
+}
+
+async fn eval(bridge: &dyn BridgeClientTrait, script: &str) -> Result<String> {
+    let resp = bridge.eval(&wrap_script(script)?).await?;
+    extract_result(resp)
+}
+
+async fn eval_if_exists(
+    bridge: &dyn BridgeClientTrait,
+    selector: &str,
+    script: &str,
+    else_script: Option<&str>,
+) -> Result<String> {
+    let else_script = else_script.map(wrap_script).transpose()?;
+    let resp = bridge
+        .eval_if(selector, &wrap_script(script)?, else_script.as_deref())
+        .await?;
+    extract_result(resp)
+}
+
+/// Wrap a user script in an IIFE with eval() to capture expression results.
+///
+/// This allows both simple expressions (1+1) and complex scripts to work.
+fn wrap_script(script: &str) -> Result<String> {
+    Ok(format!(
+        r#"return (() => {{
+            const __result__ = eval({});
+            return __result__;
+        }})()"#,
+        serde_json::to_string(script)?
+    ))
+}
+
+/// Default `interval_ms` of `stream_eval`.
+const DEFAULT_STREAM_INTERVAL_MS: u64 = 500;
+
+/// Default `max_events` of `stream_eval`.
+const DEFAULT_STREAM_MAX_EVENTS: u32 = 20;
+
+/// Longest `stream_eval` collects results, so a tool call can't hang the client.
+const MAX_STREAM_EVAL_MS: u64 = 60_000;
+
+async fn stream_eval(bridge: &dyn BridgeClientTrait, args: &Value) -> Result<String> {
+    let req = StreamEvalRequest {
+        setup_script: args
+            .get("setup_script")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        poll_script: get_string_arg(args, "poll_script")?,
+        interval_ms: args
+            .get("interval_ms")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(DEFAULT_STREAM_INTERVAL_MS),
+        max_events: args
+            .get("max_events")
+            .and_then(|v| v.as_u64())
+            .map_or(DEFAULT_STREAM_MAX_EVENTS, |v| v as u32),
+    };
+    let deadline = tokio::time::Instant::now() + Duration::from_millis(MAX_STREAM_EVAL_MS);
+    let mut results = bridge.eval_stream(req).await?;
+    let mut lines = Vec::new();
+    let mut timed_out = false;
+    loop {
+        match tokio::time::timeout_at(deadline, results.next()).await {
+            Ok(Some(resp)) => {
+                let resp = resp?;
+                let line = if resp.success {
+                    resp.result.unwrap_or_else(|| "null".to_string())
+                } else {
+                    format!(
+                        "error: {}",
+                        resp.error.unwrap_or_else(|| "Unknown error".to_string())
+                    )
+                };
+                lines.push(format!("[{}] {}", lines.len() + 1, line));
+            }
+            Ok(None) => break,
+            Err(_) => {
+                timed_out = true;
+                break;
+            }
+        }
+    }
+    let mut output = format!("{} result(s)", lines.len());
+    if timed_out {
+        output.push_str(&format!(" (stopped after {}ms)", MAX_STREAM_EVAL_MS));
+    }
+    if !lines.is_empty() {
+        output.push_str(":\n");
+        output.push_str(&lines.join("\n"));
+    }
+    Ok(output)
+}
+
+/// The result of `/eval/sandbox`, noting truncation and run time.
+fn format_sandbox_eval(resp: SandboxEvalResponse) -> Result<String> {
+    let mut out = extract_result(resp.eval)?;
+    if resp.truncated {
+        out.push_str("\n(truncated)");
+    }
+    if let Some(cpu_ms) = resp.cpu_ms {
+        out.push_str(&format!("\nRan for {:.1}ms", cpu_ms));
+    }
+    Ok(out)
+}
+
+/// One line per script from `/eval/parallel`, with its result or error.
+fn format_parallel_eval(resp: &ParallelEvalResponse) -> String {
+    let failed = resp.results.iter().filter(|r| !r.eval.success).count();
+    let mut lines = vec![format!(
+        "{} script(s) in {:.1}ms, {} failed:",
+        resp.results.len(),
+        resp.total_ms,
+        failed
+    )];
+    for r in &resp.results {
+        let outcome = if r.eval.success {
+            r.eval.result.clone().unwrap_or_else(|| "null".to_string())
+        } else {
+            format!(
+                "error: {}",
+                r.eval.error.as_deref().unwrap_or("Unknown error")
+            )
+        };
+        lines.push(format!(
+            "[{}] {:.1}ms {}",
+            r.script_index, r.duration_ms, outcome
+        ));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_bridge::{err, ok};
+    use crate::tools::call_tool;
+    use crate::tools::tests::{mock, sent_script};
+
+    #[tokio::test]
+    async fn test_eval_module() {
+        let bridge = mock(&[("/eval/module", ok("42"))]);
+        let args = json!({"code": "export default 6 * 7;"});
+        let result = call_tool(&bridge, "eval_module", args).await.unwrap();
+        assert_eq!(result, "42");
+
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(body, json!({"code": "export default 6 * 7;"}));
+    }
+
+    #[tokio::test]
+    async fn test_eval_async() {
+        let bridge = mock(&[("return (await", ok("200"))]);
+        let args = json!({"script": "return (await fetch('/api')).status;", "timeout_ms": 5000});
+        let result = call_tool(&bridge, "eval_async", args).await.unwrap();
+        assert_eq!(result, "200");
+
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(
+            body,
+            json!({"script": "return (await fetch('/api')).status;", "timeout_ms": 5000})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_eval_sandboxed() {
+        let bridge = mock(&[]).with_json(
+            "/eval/sandbox",
+            json!({"success": true, "result": "[1,2,", "truncated": true, "cpu_ms": 2.54}),
+        );
+        let args = json!({"script": "return [1, 2, 3];", "max_output_bytes": 5});
+        let result = call_tool(&bridge, "eval_sandboxed", args).await.unwrap();
+        assert_eq!(result, "[1,2,\n(truncated)\nRan for 2.5ms");
+
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(
+            body,
+            json!({"script": "return [1, 2, 3];", "max_output_bytes": 5})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_eval_sandboxed_timeout() {
+        let bridge = mock(&[]).with_json(
+            "/eval/sandbox",
+            json!({"success": false, "error": "Script timed out after 100ms", "truncated": false, "cpu_ms": 100.0}),
+        );
+        let args = json!({"script": "while (true) {}", "timeout_ms": 100});
+        let err = call_tool(&bridge, "eval_sandboxed", args)
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Script timed out after 100ms");
+    }
+
+    #[tokio::test]
+    async fn test_eval_if_exists() {
+        // The mock matches on the body's `script`, which here is the wrapped then-branch
+        let bridge = mock(&[("return", ok("\"loading\""))]);
+        let args = json!({"selector": "#spinner", "script": "'loading'", "else_script": "'ready'"});
+        let result = call_tool(&bridge, "eval_if_exists", args).await.unwrap();
+        assert_eq!(result, "\"loading\"");
+
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(body["condition_selector"], "#spinner");
+        assert!(body["script"]
+            .as_str()
+            .unwrap()
+            .contains("eval(\"'loading'\")"));
+        assert!(body["else_script"]
+            .as_str()
+            .unwrap()
+            .contains("eval(\"'ready'\")"));
+    }
+
+    #[tokio::test]
+    async fn test_eval_error() {
+        let bridge = mock(&[("return", err("ReferenceError: x is not defined"))]);
+        let result = call_tool(&bridge, "eval", json!({"script": "x"})).await;
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "ReferenceError: x is not defined"
+        );
+        assert!(sent_script(&bridge).contains("eval(\"x\")"));
+    }
+
+    #[tokio::test]
+    async fn test_stream_eval() {
+        let event = |data: Value| json!({"event": "result", "data": data.to_string()});
+        let bridge = mock(&[]).with_json(
+            "/eval/stream",
+            json!([
+                event(json!({"success": true, "result": "50", "error": null})),
+                event(json!({"success": false, "result": null, "error": "boom"}))
+            ]),
+        );
+        let args =
+            json!({"setup_script": "startImport()", "poll_script": "return window.__progress"});
+        let result = call_tool(&bridge, "stream_eval", args).await.unwrap();
+        assert_eq!(result, "2 result(s):\n[1] 50\n[2] error: boom");
+        assert_eq!(
+            bridge.requests()[0].body.clone().unwrap(),
+            json!({
+                "setup_script": "startImport()",
+                "poll_script": "return window.__progress",
+                "interval_ms": 500,
+                "max_events": 20
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_eval_parallel() {
+        let bridge = mock(&[]).with_json(
+            "/eval/parallel",
+            json!({
+                "results": [
+                    {"script_index": 0, "success": true, "result": "\"Home\"", "duration_ms": 3.2},
+                    {"script_index": 1, "success": false, "error": "Script timed out after 100ms", "duration_ms": 100.4}
+                ],
+                "total_ms": 100.5
+            }),
+        );
+        let args =
+            json!({"scripts": ["return document.title", "while (true) {}"], "timeout_ms": 100});
+        let result = call_tool(&bridge, "eval_parallel", args).await.unwrap();
+        assert_eq!(
+            result,
+            "2 script(s) in 100.5ms, 1 failed:\n\
+             [0] 3.2ms \"Home\"\n\
+             [1] 100.4ms error: Script timed out after 100ms"
+        );
+        assert_eq!(
+            bridge.requests()[0].body.clone().unwrap(),
+            json!({"scripts": ["return document.title", "while (true) {}"], "timeout_ms": 100})
+        );
+    }
+}
@@ -0,0 +1,269 @@
+//! Eval history, traces and named scripts.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use serde_json::{json, Value};
+
+use super::{
+    extract_result, get_string_arg, get_string_map_arg, summarize, tool_def, tool_def_optional,
+};
+use crate::bridge::{
+    BridgeClientTrait, EvalApi, HistoryResponse, NamedScriptsResponse, TraceResponse,
+};
+
+pub(super) async fn call(
+    bridge: &dyn BridgeClientTrait,
+    name: &str,
+    args: &Value,
+) -> Result<Option<String>> {
+    let text = match name {
+        "list_named_scripts" => Ok(format_named_scripts(&bridge.named_scripts().await?)),
+        "save_named_script" => {
+            let name = get_string_arg(args, "name")?;
+            let script = get_string_arg(args, "script")?;
+            let resp = bridge.store_named_script(&name, &script).await?;
+            Ok(format!("Saved '{}'\n{}", name, format_named_scripts(&resp)))
+        }
+        "run_named_script" => {
+            let name = get_string_arg(args, "name")?;
+            let params = if args.get("params").is_some() {
+                get_string_map_arg(args, "params")?
+            } else {
+                HashMap::new()
+            };
+            extract_result(bridge.run_named_script(&name, params).await?)
+        }
+        "delete_named_script" => {
+            let name = get_string_arg(args, "name")?;
+            let resp = bridge.remove_named_script(&name).await?;
+            Ok(format!(
+                "Deleted '{}'\n{}",
+                name,
+                format_named_scripts(&resp)
+            ))
+        }
+        "get_history" => {
+            let last = args
+                .get("last")
+                .and_then(|v| v.as_u64())
+                .map(|v| v as usize);
+            get_history(bridge, last).await
+        }
+        "get_trace" => {
+            let resp = bridge.trace().await?;
+            Ok(format_trace(&resp))
+        }
+        _ => return Ok(None),
+    };
+    text.map(Some)
+}
+
+pub(super) fn definitions() -> Vec<Value> {
+    vec![
+        tool_def("list_named_scripts", "List script templates saved with save_named_script", json!({})),
+        tool_def("save_named_script", "Save a reusable script template under a name, replacing any with that name. {{param}} placeholders are filled in by run_named_script", json!({
+            "name": { "type": "string", "description": "Name to run the script by" },
+            "script": { "type": "string", "description": "Function body, e.g. \"return document.querySelector({{selector}}).outerHTML\"" }
+        })),
+        tool_def_optional(
+            "run_named_script",
+            "Run a saved script template, filling in its {{param}} placeholders",
+            json!({
+                "name": { "type": "string", "description": "Name the script was saved under" },
+                "params": { "type": "object", "additionalProperties": { "type": "string" }, "description": "Placeholder values, e.g. {\"selector\": \"#app\"}; each is inserted as a string literal" }
+            }),
+            vec!["name"],
+        ),
+        tool_def("delete_named_script", "Delete a saved script template", json!({
+            "name": { "type": "string", "description": "Name the script was saved under" }
+        })),
+        tool_def_optional(
+            "get_history",
+            "List recent evals sent through the bridge with their results, to replay what happened",
+            json!({
+                "last": { "type": "number", "description": "Only show the most recent N evals" }
+            }),
+            vec![],
+        ),
+        tool_def("get_trace", "List timing and outcome of the last 500 evals, to find slow or failing scripts", json!({})),
+    ]
+}
+
+async fn get_history(bridge: &dyn BridgeClientTrait, last: Option<usize>) -> Result<String> {
+    let resp = bridge.history(last).await?;
+    Ok(format_history(&resp))
+}
+
+fn format_history(resp: &HistoryResponse) -> String {
+    if resp.entries.is_empty() {
+        return "No evals recorded".to_string();
+    }
+
+    let mut lines = vec![format!(
+        "{} eval(s), oldest first (bridge keeps up to {}):",
+        resp.entries.len(),
+        resp.capacity
+    )];
+    for entry in &resp.entries {
+        let secs = entry.timestamp_ms / 1000;
+        let (status, output) = if entry.response.success {
+            ("ok ", entry.response.result.as_deref().unwrap_or("null"))
+        } else {
+            (
+                "err",
+                entry.response.error.as_deref().unwrap_or("unknown error"),
+            )
+        };
+        lines.push(format!(
+            "[{:02}:{:02}:{:02}] {} {} => {}",
+            secs / 3600 % 24,
+            secs / 60 % 60,
+            secs % 60,
+            status,
+            summarize(&entry.script, 80),
+            summarize(output, 80)
+        ));
+    }
+    lines.join("\n")
+}
+
+fn format_trace(resp: &TraceResponse) -> String {
+    if resp.entries.is_empty() {
+        return "No evals traced".to_string();
+    }
+
+    let failed = resp.entries.iter().filter(|e| !e.success).count();
+    let mut lines = vec![format!(
+        "{} eval(s), {} failed, oldest first:",
+        resp.entries.len(),
+        failed
+    )];
+    for entry in &resp.entries {
+        let secs = entry.timestamp_ms / 1000;
+        lines.push(format!(
+            "[{:02}:{:02}:{:02}] {} {:>8.1}ms script {:016x} => {}",
+            secs / 3600 % 24,
+            secs / 60 % 60,
+            secs % 60,
+            if entry.success { "ok " } else { "err" },
+            entry.duration_ms,
+            entry.script_hash,
+            summarize(&entry.result_preview, 80)
+        ));
+    }
+    lines.join("\n")
+}
+
+fn format_named_scripts(resp: &NamedScriptsResponse) -> String {
+    if resp.scripts.is_empty() {
+        return "No named scripts".to_string();
+    }
+    let mut lines = vec![format!("{} named script(s):", resp.scripts.len())];
+    for script in &resp.scripts {
+        lines.push(format!(
+            "  {}: {}",
+            script.name,
+            summarize(&script.script, 80)
+        ));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::call_tool;
+    use crate::tools::tests::mock;
+    use reqwest::Method;
+
+    #[tokio::test]
+    async fn test_get_history() {
+        let bridge = mock(&[]).with_json(
+            "/history",
+            json!({
+                "entries": [
+                    {"script": "return (() => {\n  return 1;\n})()",
+                     "response": {"success": true, "result": "1"}, "timestamp_ms": 3_723_000},
+                    {"script": "document.foo()",
+                     "response": {"success": false, "error": "TypeError"}, "timestamp_ms": 3_724_500}
+                ],
+                "capacity": 100
+            }),
+        );
+        let result = call_tool(&bridge, "get_history", json!({"last": 2}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "2 eval(s), oldest first (bridge keeps up to 100):\n\
+             [01:02:03] ok  return (() => { return 1; })() => 1\n\
+             [01:02:04] err document.foo() => TypeError"
+        );
+        assert_eq!(bridge.requests()[0].path, "/history?last=2");
+    }
+
+    #[tokio::test]
+    async fn test_get_trace() {
+        let bridge = mock(&[]).with_json(
+            "/trace",
+            json!({"entries": [
+                {"timestamp_ms": 3_723_000, "script_hash": 255, "success": true,
+                 "duration_ms": 4.31, "result_preview": "1"},
+                {"timestamp_ms": 3_724_500, "script_hash": 4096, "success": false,
+                 "duration_ms": 120.0, "result_preview": "TypeError: x is undefined"}
+            ]}),
+        );
+        let result = call_tool(&bridge, "get_trace", json!({})).await.unwrap();
+        assert_eq!(
+            result,
+            "2 eval(s), 1 failed, oldest first:\n\
+             [01:02:03] ok       4.3ms script 00000000000000ff => 1\n\
+             [01:02:04] err    120.0ms script 0000000000001000 => TypeError: x is undefined"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_named_script() {
+        let bridge = mock(&[]).with_json(
+            "/eval/named/outer%20html",
+            json!({"success": true, "result": "\"app\""}),
+        );
+        let args = json!({"name": "outer html", "params": {"selector": "#app"}});
+        let result = call_tool(&bridge, "run_named_script", args).await.unwrap();
+        assert_eq!(result, "\"app\"");
+        let request = &bridge.requests()[0];
+        assert_eq!(request.method, Method::POST);
+        assert_eq!(
+            request.body.clone().unwrap(),
+            json!({"params": {"selector": "#app"}})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_named_script_rejects_non_string_params() {
+        let bridge = mock(&[]);
+        let args = json!({"name": "outer", "params": {"depth": 2}});
+        let err = call_tool(&bridge, "run_named_script", args)
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "'params' must be an object of strings");
+    }
+
+    #[tokio::test]
+    async fn test_delete_named_script() {
+        let bridge = mock(&[]).with_json(
+            "/eval/named/outer",
+            json!({"scripts": [{"name": "title", "script": "return document.title"}]}),
+        );
+        let args = json!({"name": "outer"});
+        let result = call_tool(&bridge, "delete_named_script", args)
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "Deleted 'outer'\n1 named script(s):\n  title: return document.title"
+        );
+        assert_eq!(bridge.requests()[0].method, Method::DELETE);
+    }
+}
@@ -0,0 +1,484 @@
+//! Tools that act on elements: clicking, typing, dragging, touch and forms.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+
+use super::{
+    extract_json_pretty, extract_result, get_string_arg, get_string_map_arg, get_u32_arg, tool_def,
+    tool_def_optional,
+};
+use crate::bridge::{BridgeClientTrait, EvalApi, InputStateResponse, InteractionApi};
+
+pub(super) async fn call(
+    bridge: &dyn BridgeClientTrait,
+    name: &str,
+    args: &Value,
+) -> Result<Option<String>> {
+    let text = match name {
+        "click" => {
+            let selector = get_string_arg(args, "selector")?;
+            click(bridge, &selector).await
+        }
+        "toggle" => {
+            let selector = get_string_arg(args, "selector")?;
+            toggle(bridge, &selector).await
+        }
+        "type_text" => {
+            let selector = get_string_arg(args, "selector")?;
+            let text = get_string_arg(args, "text")?;
+            type_text(bridge, &selector, &text).await
+        }
+        "scroll_into_view" => {
+            let selector = get_string_arg(args, "selector")?;
+            let behavior = args.get("behavior").and_then(|v| v.as_str());
+            let block = args.get("block").and_then(|v| v.as_str());
+            let inline = args.get("inline").and_then(|v| v.as_str());
+            let resp = bridge
+                .scroll_into_view(&selector, behavior, block, inline)
+                .await?;
+            extract_json_pretty(resp)
+        }
+        "drag" => {
+            let from_selector = get_string_arg(args, "from_selector")?;
+            let to_selector = get_string_arg(args, "to_selector")?;
+            drag(bridge, &from_selector, &to_selector).await
+        }
+        "simulate_touch" => {
+            let selector = get_string_arg(args, "selector")?;
+            let action = get_string_arg(args, "action")?;
+            let touches = serde_json::from_value(args.get("touches").cloned().unwrap_or_default())
+                .map_err(|e| anyhow!("Invalid touches: {}", e))?;
+            let resp = bridge.touch(&selector, &action, touches).await?;
+            extract_json_pretty(resp)
+        }
+        "inspect_form" => {
+            let form_selector = args.get("form_selector").and_then(|v| v.as_str());
+            Ok(format_input_state(
+                &bridge.input_state(form_selector).await?,
+            ))
+        }
+        "fill_form" => {
+            let form_selector = get_string_arg(args, "form_selector")?;
+            let fields = get_string_map_arg(args, "fields")?;
+            let submit = args
+                .get("submit")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            fill_form(bridge, &form_selector, fields, submit).await
+        }
+        "select_text" => {
+            let selector = get_string_arg(args, "selector")?;
+            let start = get_u32_arg(args, "start")?;
+            let end = get_u32_arg(args, "end")?;
+            select_text(bridge, &selector, start, end).await
+        }
+        _ => return Ok(None),
+    };
+    text.map(Some)
+}
+
+pub(super) fn definitions() -> Vec<Value> {
+    vec![
+        tool_def("click", "Click an element by CSS selector", json!({
+            "selector": { "type": "string", "description": "CSS selector" }
+        })),
+        tool_def(
+            "toggle",
+            "Click a checkbox or switch and return its new state: checked/unchecked, or on/off for role=\"switch\". Works with Dioxus Primitives checkbox and switch components",
+            json!({
+                "selector": { "type": "string", "description": "CSS selector" }
+            }),
+        ),
+        tool_def("type_text", "Type text into an input", json!({
+            "selector": { "type": "string", "description": "CSS selector" },
+            "text": { "type": "string", "description": "Text to type" }
+        })),
+        tool_def_optional(
+            "scroll_into_view",
+            "Scroll an element into the visible viewport, e.g. before taking a screenshot of it",
+            json!({
+                "selector": { "type": "string", "description": "CSS selector" },
+                "behavior": { "type": "string", "enum": ["auto", "instant", "smooth"], "description": "Scroll animation (default: auto)" },
+                "block": { "type": "string", "enum": ["start", "center", "end", "nearest"], "description": "Vertical alignment (default: start)" },
+                "inline": { "type": "string", "enum": ["start", "center", "end", "nearest"], "description": "Horizontal alignment (default: nearest)" }
+            }),
+            vec!["selector"],
+        ),
+        tool_def("drag", "Simulate an HTML5 drag-and-drop from one element onto another", json!({
+            "from_selector": { "type": "string", "description": "CSS selector of the element to drag" },
+            "to_selector": { "type": "string", "description": "CSS selector of the drop target" }
+        })),
+        tool_def("simulate_touch", "Dispatch a touch event on an element; send start, move and end with the same touch ids to simulate a swipe", json!({
+            "selector": { "type": "string", "description": "CSS selector of the element receiving the event" },
+            "action": { "type": "string", "enum": ["start", "move", "end", "cancel"], "description": "Dispatch touchstart, touchmove, touchend or touchcancel" },
+            "touches": {
+                "type": "array",
+                "description": "Touch points in viewport coordinates",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "x": { "type": "number" },
+                        "y": { "type": "number" },
+                        "id": { "type": "integer", "description": "Identifier tying a touch's start, move and end together" }
+                    },
+                    "required": ["x", "y", "id"]
+                }
+            }
+        })),
+        tool_def("select_text", "Select a range of text inside an element (inputs, textareas or any element's text content)", json!({
+            "selector": { "type": "string", "description": "CSS selector" },
+            "start": { "type": "number", "description": "Start character offset" },
+            "end": { "type": "number", "description": "End character offset (exclusive)" }
+        })),
+        tool_def_optional(
+            "inspect_form",
+            "Read the value and validation state (validity, validationMessage, required, disabled) of every input, select and textarea in one call",
+            json!({
+                "form_selector": { "type": "string", "description": "CSS selector of the form (default: the whole document)" }
+            }),
+            vec![]
+        ),
+        tool_def_optional(
+            "fill_form",
+            "Fill form fields by name (firing input and change events) and optionally submit the form",
+            json!({
+                "form_selector": { "type": "string", "description": "CSS selector of the form" },
+                "fields": { "type": "object", "additionalProperties": { "type": "string" }, "description": "Values keyed by field name; checkboxes take \"true\", radios the option value" },
+                "submit": { "type": "boolean", "description": "Submit after filling (default: false)" }
+            }),
+            vec!["form_selector", "fields"]
+        ),
+    ]
+}
+
+async fn click(bridge: &dyn BridgeClientTrait, selector: &str) -> Result<String> {
+    let script = format!(
+        r#"return (() => {{
+            const el = document.querySelector({});
+            if (el) {{ el.click(); return 'clicked'; }}
+            return 'element not found';
+        }})()"#,
+        serde_json::to_string(selector)?
+    );
+    let resp = bridge.eval(&script).await?;
+    extract_result(resp)
+}
+
+/// Click a checkbox or switch, then read back its state.
+///
+/// The state is read in a separate eval so that components which re-render
+/// on click (e.g. Dioxus Primitives, where `data-state` follows a signal) have
+/// been patched by the time it runs.
+async fn toggle(bridge: &dyn BridgeClientTrait, selector: &str) -> Result<String> {
+    if click(bridge, selector).await? != "clicked" {
+        return Err(anyhow!("Element not found: {}", selector));
+    }
+
+    let script = format!(
+        r#"const el = document.querySelector({});
+        if (!el) return 'element not found';
+        const state = el.getAttribute('data-state');
+        const aria = el.getAttribute('aria-checked');
+        if (state === 'indeterminate' || aria === 'mixed') return 'indeterminate';
+        const checked = state !== null ? state === 'checked' || state === 'on'
+            : aria !== null ? aria === 'true'
+            : !!el.checked;
+        if (el.getAttribute('role') === 'switch') return checked ? 'on' : 'off';
+        return checked ? 'checked' : 'unchecked';"#,
+        serde_json::to_string(selector)?
+    );
+    let resp = bridge.eval(&script).await?;
+    extract_result(resp)
+}
+
+async fn type_text(bridge: &dyn BridgeClientTrait, selector: &str, text: &str) -> Result<String> {
+    let script = format!(
+        r#"return (() => {{
+            const el = document.querySelector({});
+            if (el) {{
+                el.value = {};
+                el.dispatchEvent(new Event('input', {{ bubbles: true }}));
+                return 'typed';
+            }}
+            return 'element not found';
+        }})()"#,
+        serde_json::to_string(selector)?,
+        serde_json::to_string(text)?
+    );
+    let resp = bridge.eval(&script).await?;
+    extract_result(resp)
+}
+
+async fn drag(
+    bridge: &dyn BridgeClientTrait,
+    from_selector: &str,
+    to_selector: &str,
+) -> Result<String> {
+    let resp = bridge.drag(from_selector, to_selector).await?;
+    extract_json_pretty(resp)
+}
+
+async fn select_text(
+    bridge: &dyn BridgeClientTrait,
+    selector: &str,
+    start: u32,
+    end: u32,
+) -> Result<String> {
+    let resp = bridge.select_text(selector, start, end).await?;
+    if resp.success {
+        Ok(format!(
+            "Selected: '{}'",
+            resp.selected_text.unwrap_or_default()
+        ))
+    } else {
+        Err(anyhow!(resp
+            .error
+            .unwrap_or_else(|| "Unknown error".to_string())))
+    }
+}
+
+fn format_input_state(resp: &InputStateResponse) -> String {
+    if resp.inputs.is_empty() {
+        return "No form controls found".to_string();
+    }
+    let invalid = resp.inputs.iter().filter(|i| !i.validity_valid).count();
+    let mut lines = vec![format!(
+        "{} control(s), {} invalid:",
+        resp.inputs.len(),
+        invalid
+    )];
+    for input in &resp.inputs {
+        let label = [&input.name, &input.id]
+            .into_iter()
+            .find(|s| !s.is_empty())
+            .map_or("(unnamed)", |s| s.as_str());
+        let mut line = format!("- {} [{}] = {:?}", label, input.input_type, input.value);
+        if let Some(checked) = input.checked {
+            line.push_str(if checked { ", checked" } else { ", unchecked" });
+        }
+        if input.required {
+            line.push_str(", required");
+        }
+        if input.disabled {
+            line.push_str(", disabled");
+        }
+        if !input.validity_valid {
+            line.push_str(&format!(", invalid: {}", input.validation_message));
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+async fn fill_form(
+    bridge: &dyn BridgeClientTrait,
+    form_selector: &str,
+    fields: HashMap<String, String>,
+    submit: bool,
+) -> Result<String> {
+    let resp = bridge.form_fill(form_selector, fields, submit).await?;
+    if !resp.success {
+        return Err(anyhow!(resp
+            .error
+            .unwrap_or_else(|| "Unknown error".to_string())));
+    }
+    let mut message = format!("Filled {} field(s)", resp.fields_filled);
+    if resp.submitted {
+        message.push_str(" and submitted the form");
+    }
+    Ok(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_bridge::{ok, ok_json};
+    use crate::tools::call_tool;
+    use crate::tools::tests::{mock, sent_script};
+
+    #[tokio::test]
+    async fn test_click() {
+        let bridge = mock(&[("return", ok("clicked"))]);
+        let result = call_tool(&bridge, "click", json!({"selector": ".btn"}))
+            .await
+            .unwrap();
+        assert_eq!(result, "clicked");
+        assert!(sent_script(&bridge).contains("querySelector(\".btn\")"));
+    }
+
+    #[tokio::test]
+    async fn test_toggle_checkbox() {
+        let bridge = mock(&[("return", ok("clicked")), ("const el", ok("checked"))]);
+        let result = call_tool(&bridge, "toggle", json!({"selector": "#terms"}))
+            .await
+            .unwrap();
+        assert_eq!(result, "checked");
+
+        let requests = bridge.requests();
+        assert_eq!(requests.len(), 2);
+        let state_script = requests[1].body.as_ref().unwrap()["script"]
+            .as_str()
+            .unwrap();
+        assert!(state_script.contains("querySelector(\"#terms\")"));
+        assert!(state_script.contains("data-state"));
+    }
+
+    #[tokio::test]
+    async fn test_toggle_switch() {
+        let bridge = mock(&[("return", ok("clicked")), ("const el", ok("on"))]);
+        let result = call_tool(&bridge, "toggle", json!({"selector": "[role=switch]"}))
+            .await
+            .unwrap();
+        assert_eq!(result, "on");
+    }
+
+    #[tokio::test]
+    async fn test_toggle_not_found() {
+        let bridge = mock(&[("return", ok("element not found"))]);
+        let err = call_tool(&bridge, "toggle", json!({"selector": "#missing"}))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("#missing"));
+        assert_eq!(bridge.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_type_text_escapes_text() {
+        let bridge = mock(&[("return", ok("typed"))]);
+        let args = json!({"selector": "input", "text": "say \"hi\""});
+        let result = call_tool(&bridge, "type_text", args).await.unwrap();
+        assert_eq!(result, "typed");
+        assert!(sent_script(&bridge).contains(r#"el.value = "say \"hi\"";"#));
+    }
+
+    #[tokio::test]
+    async fn test_scroll_into_view() {
+        let bridge = mock(&[("/scroll-into-view", ok_json(&json!({"success": true})))]);
+        let args = json!({"selector": "#pricing", "block": "center"});
+        call_tool(&bridge, "scroll_into_view", args).await.unwrap();
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(body, json!({"selector": "#pricing", "block": "center"}));
+    }
+
+    #[tokio::test]
+    async fn test_drag() {
+        let bridge = mock(&[("/drag", ok_json(&json!({"success": true})))]);
+        let args = json!({"from_selector": ".card", "to_selector": ".column"});
+        call_tool(&bridge, "drag", args).await.unwrap();
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(
+            body,
+            json!({"from_selector": ".card", "to_selector": ".column"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_simulate_touch() {
+        let bridge = mock(&[("/touch", ok_json(&json!({"success": true})))]);
+        let args = json!({"selector": ".carousel", "action": "start", "touches": [{"x": 100, "y": 40.5, "id": 0}]});
+        call_tool(&bridge, "simulate_touch", args).await.unwrap();
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(
+            body,
+            json!({"selector": ".carousel", "action": "start", "touches": [{"x": 100.0, "y": 40.5, "id": 0}]})
+        );
+
+        let args = json!({"selector": ".carousel", "action": "move", "touches": [{"x": 1}]});
+        let err = call_tool(&bridge, "simulate_touch", args)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().starts_with("Invalid touches"));
+    }
+
+    #[tokio::test]
+    async fn test_fill_form() {
+        let bridge = mock(&[]).with_json(
+            "/form",
+            json!({"success": true, "fields_filled": 2, "submitted": true, "error": null}),
+        );
+        let args = json!({"form_selector": "#signup", "fields": {"email": "a@b.c", "age": 30}, "submit": true});
+        let result = call_tool(&bridge, "fill_form", args).await.unwrap();
+        assert_eq!(result, "Filled 2 field(s) and submitted the form");
+
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(
+            body,
+            json!({"form_selector": "#signup", "fields": {"email": "a@b.c", "age": "30"}, "submit": true})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fill_form_missing_field() {
+        let bridge = mock(&[]).with_json(
+            "/form",
+            json!({"success": false, "fields_filled": 0, "submitted": false, "error": "No field named: nope"}),
+        );
+        let args = json!({"form_selector": "form", "fields": {"nope": "x"}});
+        let err = call_tool(&bridge, "fill_form", args).await.unwrap_err();
+        assert_eq!(err.to_string(), "No field named: nope");
+    }
+
+    #[tokio::test]
+    async fn test_inspect_form() {
+        let bridge = mock(&[]).with_json(
+            "/input-state",
+            json!({"inputs": [
+                {"name": "email", "id": "", "type": "email", "value": "a@", "checked": null,
+                 "validity_valid": false, "validation_message": "Please enter an email address.",
+                 "required": true, "disabled": false},
+                {"name": "", "id": "terms", "type": "checkbox", "value": "on", "checked": true,
+                 "validity_valid": true, "validation_message": "", "required": false,
+                 "disabled": false}
+            ]}),
+        );
+        let args = json!({"form_selector": "#signup"});
+        let result = call_tool(&bridge, "inspect_form", args).await.unwrap();
+        assert_eq!(
+            result,
+            "2 control(s), 1 invalid:\n\
+             - email [email] = \"a@\", required, invalid: Please enter an email address.\n\
+             - terms [checkbox] = \"on\", checked"
+        );
+        assert_eq!(
+            bridge.requests()[0].path,
+            "/input-state?form_selector=%23signup"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_text() {
+        let bridge = mock(&[]).with_json(
+            "/select",
+            json!({"success": true, "selected_text": "Hello"}),
+        );
+        let args = json!({"selector": "#bio", "start": 0, "end": 5});
+        let result = call_tool(&bridge, "select_text", args).await.unwrap();
+        assert_eq!(result, "Selected: 'Hello'");
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(body, json!({"selector": "#bio", "start": 0, "end": 5}));
+    }
+
+    #[tokio::test]
+    async fn test_select_text_not_found() {
+        let bridge = mock(&[]).with_json(
+            "/select",
+            json!({"success": false, "error": "Element not found: #bio"}),
+        );
+        let args = json!({"selector": "#bio", "start": 0, "end": 5});
+        let err = call_tool(&bridge, "select_text", args).await.unwrap_err();
+        assert_eq!(err.to_string(), "Element not found: #bio");
+    }
+
+    #[tokio::test]
+    async fn test_missing_argument_sends_nothing() {
+        let bridge = mock(&[]);
+        let result = call_tool(&bridge, "click", json!({})).await;
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Missing 'selector' argument"
+        );
+        assert!(bridge.requests().is_empty());
+    }
+}
@@ -0,0 +1,530 @@
+//! Layout tools: flexbox and grid, box model, stacking contexts and scrolling.
+
+use anyhow::Result;
+use serde_json::{json, Value};
+
+use super::{extract_json_pretty, get_string_arg, tool_def, tool_def_optional};
+use crate::bridge::{
+    BoxEdges, BridgeClientTrait, ComputedLayoutResponse, IntersectionResponse, LayoutApi,
+    LayoutInspectResponse, ParentChainResponse, ScrollContainersResponse, StackingResponse,
+};
+
+pub(super) async fn call(
+    bridge: &dyn BridgeClientTrait,
+    name: &str,
+    args: &Value,
+) -> Result<Option<String>> {
+    let text = match name {
+        "inspect_layout" => {
+            let selector = get_string_arg(args, "selector")?;
+            let resp = bridge.inspect_layout(&selector).await?;
+            Ok(format_layout(&selector, &resp))
+        }
+        "get_box_model" => {
+            let selector = get_string_arg(args, "selector")?;
+            let resp = bridge.computed_layout(&selector).await?;
+            Ok(format_computed_layout(&selector, &resp))
+        }
+        "get_parent_chain" => {
+            let selector = get_string_arg(args, "selector")?;
+            let depth = args.get("depth").and_then(|v| v.as_u64()).map(|v| v as u32);
+            let resp = bridge.parent_chain(&selector, depth).await?;
+            Ok(format_parent_chain(&selector, &resp))
+        }
+        "get_stacking_contexts" => {
+            let selector = get_string_arg(args, "selector")?;
+            let resp = bridge.stacking_contexts(&selector).await?;
+            Ok(format_stacking_contexts(&selector, &resp))
+        }
+        "inspect" => {
+            let selector = get_string_arg(args, "selector")?;
+            let include_listeners = args
+                .get("include_listeners")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            inspect(bridge, &selector, include_listeners).await
+        }
+        "check_intersection" => {
+            let selector = get_string_arg(args, "selector")?;
+            let resp = bridge.check_intersection(&selector).await?;
+            Ok(format_intersection(&selector, &resp))
+        }
+        "list_scroll_containers" => {
+            Ok(format_scroll_containers(&bridge.scroll_containers().await?))
+        }
+        _ => return Ok(None),
+    };
+    text.map(Some)
+}
+
+pub(super) fn definitions() -> Vec<Value> {
+    vec![
+        tool_def("inspect_layout", "Show resolved flexbox/grid properties of a container and its direct children", json!({
+            "selector": { "type": "string", "description": "CSS selector of the container" }
+        })),
+        tool_def("get_box_model", "Show an element's resolved box model (content, padding, border and margin sizes) and its flex or grid values as a container or item", json!({
+            "selector": { "type": "string", "description": "CSS selector of the element" }
+        })),
+        tool_def_optional(
+            "get_parent_chain",
+            "List an element's ancestors with display, overflow, visibility, z-index and position, to find what clips or hides it",
+            json!({
+                "selector": { "type": "string", "description": "CSS selector" },
+                "depth": { "type": "integer", "description": "Max ancestors to return (default: 10)" }
+            }),
+            vec!["selector"]
+        ),
+        tool_def("get_stacking_contexts", "List the elements in a subtree that create a stacking context, with their z-index and the styles responsible, to debug z-index issues", json!({
+            "selector": { "type": "string", "description": "CSS selector of the subtree root, e.g. body" }
+        })),
+        tool_def_optional(
+            "inspect",
+            "Analyze element visibility, optionally listing attached event listeners",
+            json!({
+                "selector": { "type": "string", "description": "CSS selector" },
+                "include_listeners": { "type": "boolean", "description": "Include event listener types (default: false)" }
+            }),
+            vec!["selector"]
+        ),
+        tool_def("check_intersection", "Check which elements matching a selector are inside the viewport and how much of each is visible, e.g. to debug lazy loading", json!({
+            "selector": { "type": "string", "description": "CSS selector; every match is checked" }
+        })),
+        tool_def("list_scroll_containers", "List every scrollable element (including the page itself) with its scroll position, content and visible size, and whether it's scrolled to the bottom", json!({})),
+    ]
+}
+
+fn format_layout(selector: &str, resp: &LayoutInspectResponse) -> String {
+    let mut lines = vec![format!("{}: display: {}", selector, resp.display)];
+    let container = [
+        ("flex-direction", &resp.flex_direction),
+        ("flex-wrap", &resp.flex_wrap),
+        ("grid-template-columns", &resp.grid_template_columns),
+        ("grid-template-rows", &resp.grid_template_rows),
+    ];
+    for (name, value) in container {
+        if let Some(value) = value {
+            lines.push(format!("  {}: {}", name, value));
+        }
+    }
+
+    let is_grid = resp.display.contains("grid");
+    for child in &resp.children {
+        let item = if is_grid {
+            format!(
+                "grid-column: {}; grid-row: {}",
+                child.grid_column, child.grid_row
+            )
+        } else {
+            format!(
+                "flex: {} {} {}",
+                child.flex_grow, child.flex_shrink, child.flex_basis
+            )
+        };
+        lines.push(format!("- {} ({})", child.selector_path, item));
+    }
+    lines.join("\n")
+}
+
+/// The boxes from the outside in, then whichever flex and grid roles apply.
+fn format_computed_layout(selector: &str, resp: &ComputedLayoutResponse) -> String {
+    let b = &resp.box_model;
+    let edges = |e: &BoxEdges| format!("{} {} {} {}", e.top, e.right, e.bottom, e.left);
+    let mut lines = vec![
+        format!(
+            "{}: display: {}, box-sizing: {}",
+            selector, resp.display, b.box_sizing
+        ),
+        format!(
+            "  margin  {} x {} ({})",
+            b.margin_width,
+            b.margin_height,
+            edges(&b.margin)
+        ),
+        format!(
+            "  border  {} x {} ({})",
+            b.border_width,
+            b.border_height,
+            edges(&b.border)
+        ),
+        format!(
+            "  padding {} x {} ({})",
+            b.padding_width,
+            b.padding_height,
+            edges(&b.padding)
+        ),
+        format!("  content {} x {}", b.content_width, b.content_height),
+    ];
+    if let Some(flex) = &resp.flex {
+        if flex.is_container {
+            lines.push(format!(
+                "  flex container: {} {}, justify-content: {}, align-items: {}, align-content: {}, gap: {} {}",
+                flex.direction,
+                flex.wrap,
+                flex.justify_content,
+                flex.align_items,
+                flex.align_content,
+                flex.row_gap,
+                flex.column_gap
+            ));
+        }
+        if flex.is_item {
+            lines.push(format!(
+                "  flex item: {} {} {}, align-self: {}, order: {}",
+                flex.grow, flex.shrink, flex.basis, flex.align_self, flex.order
+            ));
+        }
+    }
+    if let Some(grid) = &resp.grid {
+        if grid.is_container {
+            lines.push(format!(
+                "  grid container: columns: {}, rows: {}, areas: {}, auto-flow: {}, auto-columns: {}, auto-rows: {}, gap: {} {}, justify-items: {}, align-items: {}",
+                grid.template_columns,
+                grid.template_rows,
+                grid.template_areas,
+                grid.auto_flow,
+                grid.auto_columns,
+                grid.auto_rows,
+                grid.row_gap,
+                grid.column_gap,
+                grid.justify_items,
+                grid.align_items
+            ));
+        }
+        if grid.is_item {
+            lines.push(format!(
+                "  grid item: column: {}, row: {}, justify-self: {}, align-self: {}",
+                grid.column, grid.row, grid.justify_self, grid.align_self
+            ));
+        }
+    }
+    lines.join("\n")
+}
+
+fn format_parent_chain(selector: &str, resp: &ParentChainResponse) -> String {
+    if resp.chain.is_empty() {
+        return format!("{} has no parent elements", selector);
+    }
+    let mut lines = vec![format!("Ancestors of {}, nearest first:", selector)];
+    for node in &resp.chain {
+        let mut name = node.tag.clone();
+        if let Some(id) = &node.id {
+            name.push('#');
+            name.push_str(id);
+        }
+        for class in &node.classes {
+            name.push('.');
+            name.push_str(class);
+        }
+        let mut line = format!(
+            "- {} (display: {}; overflow: {}; visibility: {}; z-index: {}; position: {})",
+            name, node.display, node.overflow, node.visibility, node.z_index, node.position
+        );
+        // Call out the ancestors that can clip or hide their descendants
+        if node.display == "none" || node.visibility != "visible" {
+            line.push_str(" <- hides descendants");
+        } else if node.overflow.split(' ').any(|o| o != "visible") {
+            line.push_str(" <- clips overflow");
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+fn format_intersection(selector: &str, resp: &IntersectionResponse) -> String {
+    if resp.elements.is_empty() {
+        return format!("No elements match {}", selector);
+    }
+    let visible = resp.elements.iter().filter(|e| e.in_viewport).count();
+    let mut lines = vec![format!(
+        "{} of {} elements matching {} are in the viewport:",
+        visible,
+        resp.elements.len(),
+        selector
+    )];
+    for entry in &resp.elements {
+        let rect = &entry.bounding_rect;
+        let status = if entry.in_viewport {
+            format!("{}% visible", (entry.intersection_ratio * 100.0).round())
+        } else {
+            "outside the viewport".to_string()
+        };
+        lines.push(format!(
+            "- {}: {} (at {},{} {}x{})",
+            entry.selector_path,
+            status,
+            rect.left.round(),
+            rect.top.round(),
+            rect.width.round(),
+            rect.height.round()
+        ));
+    }
+    lines.join("\n")
+}
+
+fn format_stacking_contexts(selector: &str, resp: &StackingResponse) -> String {
+    if resp.contexts.is_empty() {
+        return format!("Nothing in {} creates a stacking context", selector);
+    }
+    let mut lines = vec![format!("Stacking contexts in {}, in DOM order:", selector)];
+    for ctx in &resp.contexts {
+        let rect = &ctx.bounding_rect;
+        lines.push(format!(
+            "- {} (z-index: {}) at {},{} {}x{}: {}",
+            ctx.selector_path,
+            ctx.z_index,
+            rect.left.round(),
+            rect.top.round(),
+            rect.width.round(),
+            rect.height.round(),
+            ctx.creates_context_reason.join(", ")
+        ));
+    }
+    lines.join("\n")
+}
+
+pub(super) async fn inspect(
+    bridge: &dyn BridgeClientTrait,
+    selector: &str,
+    include_listeners: bool,
+) -> Result<String> {
+    let resp = bridge.inspect(selector, include_listeners).await?;
+    extract_json_pretty(resp)
+}
+
+fn format_scroll_containers(resp: &ScrollContainersResponse) -> String {
+    if resp.containers.is_empty() {
+        return "No scrollable elements".to_string();
+    }
+    let mut lines = vec![format!("{} scrollable element(s):", resp.containers.len())];
+    for c in &resp.containers {
+        let mut line = format!(
+            "- {}: scrolled to ({}, {}), content {}x{} in {}x{}",
+            c.selector_path,
+            c.scroll_x,
+            c.scroll_y,
+            c.scroll_width,
+            c.scroll_height,
+            c.client_width,
+            c.client_height
+        );
+        if c.is_at_bottom {
+            line.push_str(", at bottom");
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_bridge::ok_json;
+    use crate::tools::call_tool;
+    use crate::tools::tests::mock;
+
+    #[tokio::test]
+    async fn test_get_parent_chain() {
+        let node = |tag: &str, classes: Value, overflow: &str, visibility: &str| {
+            json!({"tag": tag, "id": null, "classes": classes, "display": "block", "overflow": overflow,
+                   "visibility": visibility, "z_index": "auto", "position": "static"})
+        };
+        let bridge = mock(&[]).with_json(
+            "/inspect/parent",
+            json!({"chain": [
+                node("div", json!(["menu", "open"]), "visible", "visible"),
+                node("section", json!([]), "hidden", "visible"),
+                node("main", json!([]), "visible", "hidden")
+            ]}),
+        );
+        let result = call_tool(
+            &bridge,
+            "get_parent_chain",
+            json!({"selector": ".item", "depth": 3}),
+        )
+        .await
+        .unwrap();
+        assert_eq!(bridge.requests()[0].body.as_ref().unwrap()["depth"], 3);
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines[0], "Ancestors of .item, nearest first:");
+        assert_eq!(
+            lines[1],
+            "- div.menu.open (display: block; overflow: visible; visibility: visible; z-index: auto; position: static)"
+        );
+        assert!(lines[2].ends_with("<- clips overflow"));
+        assert!(lines[3].ends_with("<- hides descendants"));
+    }
+
+    #[tokio::test]
+    async fn test_get_stacking_contexts() {
+        let bridge = mock(&[]).with_json(
+            "/inspect/stacking",
+            json!({"contexts": [{
+                "selector_path": "div#modal",
+                "z_index": "100",
+                "creates_context_reason": ["position: fixed", "opacity: 0.95"],
+                "bounding_rect": {"top": 20.4, "left": 10, "width": 300, "height": 200}
+            }]}),
+        );
+        let result = call_tool(
+            &bridge,
+            "get_stacking_contexts",
+            json!({"selector": "body"}),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            bridge.requests()[0].body.as_ref().unwrap()["selector"],
+            "body"
+        );
+        assert_eq!(
+            result,
+            "Stacking contexts in body, in DOM order:\n- div#modal (z-index: 100) at 10,20 300x200: position: fixed, opacity: 0.95"
+        );
+
+        let bridge = mock(&[]).with_json("/inspect/stacking", json!({"contexts": []}));
+        let result = call_tool(
+            &bridge,
+            "get_stacking_contexts",
+            json!({"selector": "main"}),
+        )
+        .await
+        .unwrap();
+        assert_eq!(result, "Nothing in main creates a stacking context");
+    }
+
+    #[tokio::test]
+    async fn test_inspect_layout() {
+        let child = |path: &str| json!({"selector_path": path, "flex_basis": "auto", "flex_grow": "1", "flex_shrink": "0", "grid_column": "auto", "grid_row": "auto"});
+        let bridge = mock(&[]).with_json(
+            "/inspect/layout",
+            json!({
+                "display": "flex",
+                "flex_direction": "column",
+                "flex_wrap": "nowrap",
+                "grid_template_columns": null,
+                "grid_template_rows": null,
+                "children": [child("nav > a:nth-of-type(1)"), child("nav > a:nth-of-type(2)")]
+            }),
+        );
+        let result = call_tool(&bridge, "inspect_layout", json!({"selector": "nav"}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "nav: display: flex\n  flex-direction: column\n  flex-wrap: nowrap\n\
+             - nav > a:nth-of-type(1) (flex: 1 0 auto)\n- nav > a:nth-of-type(2) (flex: 1 0 auto)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_box_model() {
+        let edges = |n: f64| json!({"top": n, "right": n, "bottom": n, "left": n});
+        let bridge = mock(&[]).with_json(
+            "/computed-layout",
+            json!({
+                "display": "block",
+                "box_model": {
+                    "box_sizing": "border-box",
+                    "content_width": 98, "content_height": 18.5,
+                    "padding_width": 118, "padding_height": 38.5,
+                    "border_width": 120, "border_height": 40.5,
+                    "margin_width": 136, "margin_height": 56.5,
+                    "padding": edges(10.0), "border": edges(1.0), "margin": edges(8.0)
+                },
+                "flex": {
+                    "is_container": false, "is_item": true,
+                    "direction": "row", "wrap": "nowrap",
+                    "justify_content": "normal", "align_items": "normal",
+                    "align_content": "normal", "row_gap": "normal", "column_gap": "normal",
+                    "grow": 1, "shrink": 0, "basis": "120px", "align_self": "center", "order": 2
+                },
+                "grid": null
+            }),
+        );
+        let result = call_tool(&bridge, "get_box_model", json!({"selector": ".card"}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            ".card: display: block, box-sizing: border-box\n  \
+             margin  136 x 56.5 (8 8 8 8)\n  \
+             border  120 x 40.5 (1 1 1 1)\n  \
+             padding 118 x 38.5 (10 10 10 10)\n  \
+             content 98 x 18.5\n  \
+             flex item: 1 0 120px, align-self: center, order: 2"
+        );
+        assert_eq!(
+            bridge.requests()[0].body.clone().unwrap(),
+            json!({"selector": ".card"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_inspect_include_listeners() {
+        let bridge = mock(&[("/inspect", ok_json(&json!({"found": true})))]);
+        let args = json!({"selector": ".btn", "include_listeners": true});
+        call_tool(&bridge, "inspect", args).await.unwrap();
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(body["include_listeners"], true);
+    }
+
+    #[tokio::test]
+    async fn test_check_intersection() {
+        let entry = |path: &str, in_viewport: bool, ratio: f64, top: f64| {
+            json!({"selector_path": path, "in_viewport": in_viewport, "intersection_ratio": ratio,
+                   "bounding_rect": {"top": top, "left": 0, "width": 200, "height": 100}})
+        };
+        let bridge = mock(&[]).with_json(
+            "/intersection?selector=li.card",
+            json!({"elements": [
+                entry("li.card:nth-of-type(1)", true, 1.0, 0.0),
+                entry("li.card:nth-of-type(2)", true, 0.25, 775.0),
+                entry("li.card:nth-of-type(3)", false, 0.0, 1200.0)
+            ]}),
+        );
+        let result = call_tool(
+            &bridge,
+            "check_intersection",
+            json!({"selector": "li.card"}),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            result,
+            "2 of 3 elements matching li.card are in the viewport:\n\
+             - li.card:nth-of-type(1): 100% visible (at 0,0 200x100)\n\
+             - li.card:nth-of-type(2): 25% visible (at 0,775 200x100)\n\
+             - li.card:nth-of-type(3): outside the viewport (at 0,1200 200x100)"
+        );
+
+        let bridge = mock(&[]).with_json("/intersection?selector=.none", json!({"elements": []}));
+        let result = call_tool(&bridge, "check_intersection", json!({"selector": ".none"}))
+            .await
+            .unwrap();
+        assert_eq!(result, "No elements match .none");
+    }
+
+    #[tokio::test]
+    async fn test_list_scroll_containers() {
+        let bridge = mock(&[]).with_json(
+            "/scroll/all",
+            json!({"containers": [
+                {"selector_path": "html", "scroll_x": 0.0, "scroll_y": 0.0, "scroll_width": 1280.0,
+                 "scroll_height": 2400.0, "client_width": 1280.0, "client_height": 800.0,
+                 "is_at_bottom": false},
+                {"selector_path": "ul#feed", "scroll_x": 0.0, "scroll_y": 700.0, "scroll_width": 320.0,
+                 "scroll_height": 1000.0, "client_width": 320.0, "client_height": 300.0,
+                 "is_at_bottom": true}
+            ]}),
+        );
+        let result = call_tool(&bridge, "list_scroll_containers", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "2 scrollable element(s):\n\
+             - html: scrolled to (0, 0), content 1280x2400 in 1280x800\n\
+             - ul#feed: scrolled to (0, 700), content 320x1000 in 320x300, at bottom"
+        );
+    }
+}
@@ -0,0 +1,423 @@
+//! Tools for animations, media elements, canvases, SVGs and injected CSS.
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+
+use super::{get_string_arg, get_u32_arg, tool_def, tool_def_optional};
+use crate::bridge::{AnimationsResponse, BridgeClientTrait, MediaApi, MediaResponse, SvgResponse};
+
+pub(super) async fn call(
+    bridge: &dyn BridgeClientTrait,
+    name: &str,
+    args: &Value,
+) -> Result<Option<String>> {
+    let text = match name {
+        "get_animations" => get_animations(bridge).await,
+        "control_animation" => {
+            let id = args.get("id").and_then(|v| v.as_str());
+            let action = get_string_arg(args, "action")?;
+            control_animation(bridge, id, &action).await
+        }
+        "capture_canvas" => {
+            let selector = get_string_arg(args, "selector")?;
+            let format = args.get("format").and_then(|v| v.as_str());
+            let save_path = args.get("save_path").and_then(|v| v.as_str());
+            capture_canvas(bridge, &selector, format, save_path).await
+        }
+        "inject_css" => {
+            let id = get_string_arg(args, "id")?;
+            let css = get_string_arg(args, "css")?;
+            let resp = bridge.inject_css(&id, &css).await?;
+            Ok(format!("Injected <style id=\"{}\">", resp.style_id))
+        }
+        "remove_css" => {
+            let id = get_string_arg(args, "id")?;
+            let resp = bridge.remove_css(&id).await?;
+            if !resp.success {
+                return Err(anyhow!("No injected style with id '{}'", id));
+            }
+            Ok(format!("Removed <style id=\"{}\">", resp.style_id))
+        }
+        "get_svg" => Ok(format_svg(&bridge.svg_elements().await?)),
+        "animate_svg" => {
+            let selector = get_string_arg(args, "selector")?;
+            let attribute = get_string_arg(args, "attribute")?;
+            let from = get_string_arg(args, "from")?;
+            let to = get_string_arg(args, "to")?;
+            let duration_ms = get_u32_arg(args, "duration_ms")?;
+            let resp = bridge
+                .animate_svg(&selector, &attribute, &from, &to, duration_ms.into())
+                .await?;
+            if !resp.success {
+                return Err(anyhow!(resp
+                    .error
+                    .unwrap_or_else(|| "Unknown error".to_string())));
+            }
+            Ok(format!(
+                "Animating {} of {} from {} to {} over {}ms",
+                attribute, selector, from, to, duration_ms
+            ))
+        }
+        "get_media_state" => {
+            let selector = args.get("selector").and_then(|v| v.as_str());
+            let resp = bridge.media_state(selector).await?;
+            Ok(format_media(&resp))
+        }
+        "control_media" => {
+            let selector = get_string_arg(args, "selector")?;
+            let action = get_string_arg(args, "action")?;
+            let value = args.get("value").and_then(|v| v.as_f64());
+            let resp = bridge.media_control(&selector, &action, value).await?;
+            Ok(format!(
+                "Applied '{}' to {}\n{}",
+                action,
+                selector,
+                format_media(&resp)
+            ))
+        }
+        _ => return Ok(None),
+    };
+    text.map(Some)
+}
+
+pub(super) fn definitions() -> Vec<Value> {
+    vec![
+        tool_def("get_animations", "List CSS and Web Animations API animations with their play state and timing", json!({})),
+        tool_def_optional(
+            "control_animation",
+            "Pause, play or cancel an animation by id, or all animations",
+            json!({
+                "id": { "type": "string", "description": "Animation id or CSS animation name (default: all animations)" },
+                "action": { "type": "string", "enum": ["pause", "play", "cancel"], "description": "Action to apply" }
+            }),
+            vec!["action"]
+        ),
+        tool_def_optional(
+            "capture_canvas",
+            "Capture a <canvas> element as a data URL, or save it to a file",
+            json!({
+                "selector": { "type": "string", "description": "CSS selector of the canvas" },
+                "format": { "type": "string", "description": "Image MIME type, e.g. image/jpeg (default: image/png)" },
+                "save_path": { "type": "string", "description": "Write the decoded image to this path instead of returning the data URL" }
+            }),
+            vec!["selector"]
+        ),
+        tool_def("inject_css", "Add CSS rules to the page for quick layout experiments; injecting again with the same id replaces them", json!({
+            "id": { "type": "string", "description": "Name of the style (letters, digits, - and _)" },
+            "css": { "type": "string", "description": "CSS rules, e.g. .sidebar { width: 320px; }" }
+        })),
+        tool_def("remove_css", "Remove CSS added with inject_css", json!({
+            "id": { "type": "string", "description": "Name the style was injected with" }
+        })),
+        tool_def("get_svg", "List every <svg> and its children with viewBox, width, height, preserveAspectRatio, transform, path d, circle cx/cy/r and polygon points", json!({})),
+        tool_def("animate_svg", "Animate an attribute of an SVG element from one value to another by adding an <animate> element", json!({
+            "selector": { "type": "string", "description": "CSS selector of the SVG element" },
+            "attribute": { "type": "string", "description": "Attribute to animate, e.g. r or fill" },
+            "from": { "type": "string", "description": "Start value" },
+            "to": { "type": "string", "description": "End value, kept after the animation ends" },
+            "duration_ms": { "type": "number", "description": "Duration in milliseconds" }
+        })),
+        tool_def_optional(
+            "get_media_state",
+            "Report playback state of audio/video elements: position, duration, paused, muted, volume, buffered ranges, readyState and networkState",
+            json!({
+                "selector": { "type": "string", "description": "CSS selector (default: all audio and video elements)" }
+            }),
+            vec![]
+        ),
+        tool_def_optional(
+            "control_media",
+            "Play, pause, seek, mute or set the volume of audio/video elements",
+            json!({
+                "selector": { "type": "string", "description": "CSS selector of the media elements" },
+                "action": { "type": "string", "enum": ["play", "pause", "seek", "mute", "volume"], "description": "Action to apply" },
+                "value": { "type": "number", "description": "Position in seconds for seek, 0-1 for volume, 0 to unmute for mute" }
+            }),
+            vec!["selector", "action"]
+        ),
+    ]
+}
+
+async fn get_animations(bridge: &dyn BridgeClientTrait) -> Result<String> {
+    let resp = bridge.animations().await?;
+    Ok(format_animations(&resp))
+}
+
+async fn control_animation(
+    bridge: &dyn BridgeClientTrait,
+    id: Option<&str>,
+    action: &str,
+) -> Result<String> {
+    let resp = bridge.control_animations(id, action).await?;
+    Ok(format!(
+        "Applied '{}' to {}\n{}",
+        action,
+        id.unwrap_or("all animations"),
+        format_animations(&resp)
+    ))
+}
+
+async fn capture_canvas(
+    bridge: &dyn BridgeClientTrait,
+    selector: &str,
+    format: Option<&str>,
+    save_path: Option<&str>,
+) -> Result<String> {
+    let resp = bridge.canvas_capture(selector, format, save_path).await?;
+    if let Some(error) = resp.error {
+        return Err(anyhow!("Canvas capture failed: {}", error));
+    }
+    // Data URLs are large; return just the size when the image went to disk
+    match save_path {
+        Some(path) => Ok(format!(
+            "Saved {}x{} canvas to {}",
+            resp.width, resp.height, path
+        )),
+        None => Ok(format!(
+            "{}x{} canvas\n{}",
+            resp.width, resp.height, resp.data_url
+        )),
+    }
+}
+
+fn format_svg(resp: &SvgResponse) -> String {
+    if resp.elements.is_empty() {
+        return "No SVG elements found".to_string();
+    }
+    let mut lines = vec![format!("{} SVG element(s):", resp.elements.len())];
+    for el in &resp.elements {
+        let attributes = [
+            ("viewBox", &el.view_box),
+            ("width", &el.width),
+            ("height", &el.height),
+            ("preserveAspectRatio", &el.preserve_aspect_ratio),
+            ("transform", &el.transform),
+            ("d", &el.d),
+            ("cx", &el.cx),
+            ("cy", &el.cy),
+            ("r", &el.r),
+            ("points", &el.points),
+        ];
+        let mut line = format!("- {} <{}>", el.selector_path, el.tag);
+        for (name, value) in attributes {
+            if let Some(value) = value {
+                line.push_str(&format!(" {}=\"{}\"", name, value));
+            }
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+fn format_media(resp: &MediaResponse) -> String {
+    if resp.media.is_empty() {
+        return "No media elements".to_string();
+    }
+    resp.media
+        .iter()
+        .map(|m| {
+            let duration = m
+                .duration
+                .map_or_else(|| "?".to_string(), |d| format!("{:.1}", d));
+            let buffered = m
+                .buffered
+                .iter()
+                .map(|r| format!("{:.1}-{:.1}", r.start, r.end))
+                .collect::<Vec<_>>();
+            format!(
+                "{} [{}{}] {:.1}/{}s volume {:.0}% buffered {} (ready_state {}, network_state {}) {}",
+                m.element,
+                if m.paused { "paused" } else { "playing" },
+                if m.muted { ", muted" } else { "" },
+                m.current_time,
+                duration,
+                m.volume * 100.0,
+                if buffered.is_empty() { "none".to_string() } else { buffered.join(", ") },
+                m.ready_state,
+                m.network_state,
+                m.src.as_deref().unwrap_or("(no source)")
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn format_animations(resp: &AnimationsResponse) -> String {
+    if resp.animations.is_empty() {
+        return "No animations".to_string();
+    }
+    resp.animations
+        .iter()
+        .map(|a| {
+            format!(
+                "{} [{}] {} ({:.0}/{:.0} ms)",
+                a.id.as_deref().unwrap_or("(anonymous)"),
+                a.state,
+                a.effect_target,
+                a.current_time,
+                a.duration_ms
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::call_tool;
+    use crate::tools::tests::mock;
+
+    #[tokio::test]
+    async fn test_get_animations() {
+        let bridge = mock(&[]).with_json(
+            "/animations",
+            json!({"animations": [{"id": null, "state": "paused", "current_time": 250.0,
+                "effect_target": "div.spinner", "duration_ms": 1000.0}]}),
+        );
+        let result = call_tool(&bridge, "get_animations", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(result, "(anonymous) [paused] div.spinner (250/1000 ms)");
+    }
+
+    #[tokio::test]
+    async fn test_capture_canvas() {
+        let bridge = mock(&[]).with_json(
+            "/canvas",
+            json!({"data_url": "data:image/png;base64,AA==", "width": 300, "height": 150, "error": null}),
+        );
+        let result = call_tool(&bridge, "capture_canvas", json!({"selector": "canvas"}))
+            .await
+            .unwrap();
+        assert_eq!(result, "300x150 canvas\ndata:image/png;base64,AA==");
+
+        let args = json!({"selector": "canvas", "save_path": "/tmp/chart.png"});
+        let result = call_tool(&bridge, "capture_canvas", args).await.unwrap();
+        assert_eq!(result, "Saved 300x150 canvas to /tmp/chart.png");
+        let body = bridge.requests()[1].body.clone().unwrap();
+        assert_eq!(
+            body,
+            json!({"selector": "canvas", "save_path": "/tmp/chart.png"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_capture_canvas_error() {
+        let bridge = mock(&[]).with_json(
+            "/canvas",
+            json!({"data_url": "", "width": 0, "height": 0, "error": "Element not found"}),
+        );
+        let err = call_tool(&bridge, "capture_canvas", json!({"selector": "#nope"}))
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Canvas capture failed: Element not found");
+    }
+
+    #[tokio::test]
+    async fn test_inject_and_remove_css() {
+        let bridge = mock(&[]).with_json(
+            "/inject-css",
+            json!({"success": true, "style_id": "dioxus-inspector-wide"}),
+        );
+        let args = json!({"id": "wide", "css": "main { width: 100% }"});
+        let result = call_tool(&bridge, "inject_css", args).await.unwrap();
+        assert_eq!(result, "Injected <style id=\"dioxus-inspector-wide\">");
+        assert_eq!(
+            bridge.requests()[0].body.clone().unwrap(),
+            json!({"id": "wide", "css": "main { width: 100% }"})
+        );
+
+        let bridge = mock(&[]).with_json(
+            "/remove-css",
+            json!({"success": false, "style_id": "dioxus-inspector-gone"}),
+        );
+        let err = call_tool(&bridge, "remove_css", json!({"id": "gone"}))
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "No injected style with id 'gone'");
+    }
+
+    #[tokio::test]
+    async fn test_get_svg() {
+        let bridge = mock(&[]).with_json(
+            "/svg",
+            json!({"elements": [
+                {"selector_path": "svg#chart", "tag": "svg", "view_box": "0 0 100 100",
+                 "width": "200", "height": null, "preserve_aspect_ratio": null, "transform": null,
+                 "d": null, "cx": null, "cy": null, "r": null, "points": null},
+                {"selector_path": "svg#chart > circle", "tag": "circle", "view_box": null,
+                 "width": null, "height": null, "preserve_aspect_ratio": null, "transform": null,
+                 "d": null, "cx": "50", "cy": "50", "r": "10", "points": null}
+            ]}),
+        );
+        let result = call_tool(&bridge, "get_svg", json!({})).await.unwrap();
+        assert_eq!(
+            result,
+            "2 SVG element(s):\n\
+             - svg#chart <svg> viewBox=\"0 0 100 100\" width=\"200\"\n\
+             - svg#chart > circle <circle> cx=\"50\" cy=\"50\" r=\"10\""
+        );
+    }
+
+    #[tokio::test]
+    async fn test_animate_svg() {
+        let bridge = mock(&[]).with_json("/svg/animate", json!({"success": true}));
+        let args = json!({
+            "selector": "circle#dot", "attribute": "r", "from": "5", "to": "20", "duration_ms": 500
+        });
+        let result = call_tool(&bridge, "animate_svg", args.clone())
+            .await
+            .unwrap();
+        assert_eq!(result, "Animating r of circle#dot from 5 to 20 over 500ms");
+        assert_eq!(bridge.requests()[0].body.clone().unwrap(), args);
+
+        let bridge = mock(&[]).with_json(
+            "/svg/animate",
+            json!({"success": false, "error": "Not an SVG element: <div>"}),
+        );
+        let err = call_tool(&bridge, "animate_svg", args).await.unwrap_err();
+        assert_eq!(err.to_string(), "Not an SVG element: <div>");
+    }
+
+    #[tokio::test]
+    async fn test_get_media_state() {
+        let bridge = mock(&[]).with_json(
+            "/media",
+            json!({"media": [{"element": "video#player", "src": "movie.mp4", "current_time": 3.5,
+                "duration": 60.0, "paused": false, "muted": true, "volume": 0.5,
+                "buffered": [{"start": 0.0, "end": 10.0}], "ready_state": 4, "network_state": 2}]}),
+        );
+        let result = call_tool(&bridge, "get_media_state", json!({"selector": "video"}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "video#player [playing, muted] 3.5/60.0s volume 50% buffered 0.0-10.0 (ready_state 4, network_state 2) movie.mp4"
+        );
+        assert_eq!(bridge.requests()[0].path, "/media?selector=video");
+    }
+
+    #[tokio::test]
+    async fn test_control_media() {
+        let bridge = mock(&[]).with_json("/media/control", json!({"media": []}));
+        let args = json!({"selector": "audio", "action": "seek", "value": 12});
+        let result = call_tool(&bridge, "control_media", args).await.unwrap();
+        assert_eq!(result, "Applied 'seek' to audio\nNo media elements");
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(
+            body,
+            json!({"selector": "audio", "action": "seek", "value": 12.0})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_control_animation() {
+        let bridge = mock(&[]).with_json("/animations", json!({"animations": []}));
+        let args = json!({"id": "spin", "action": "cancel"});
+        let result = call_tool(&bridge, "control_animation", args).await.unwrap();
+        assert_eq!(result, "Applied 'cancel' to spin\nNo animations");
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(body, json!({"id": "spin", "action": "cancel"}));
+    }
+}
@@ -0,0 +1,483 @@
+//! Tools that watch the app: JS errors, events, DOM mutations and signals.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use futures_util::StreamExt;
+use serde_json::{json, Value};
+
+use super::{get_string_arg, get_string_list_arg, tool_def, tool_def_optional};
+use crate::bridge::{
+    BridgeClientTrait, EventLogResponse, EventWatchRequest, JsErrorsResponse, MonitoringApi,
+    MutationEntry, MutationSummaryResponse, SignalChange, SignalsResponse,
+};
+
+pub(super) async fn call(
+    bridge: &dyn BridgeClientTrait,
+    name: &str,
+    args: &Value,
+) -> Result<Option<String>> {
+    let text = match name {
+        "get_js_errors" => {
+            let clear = args.get("clear").and_then(|v| v.as_bool()).unwrap_or(true);
+            let resp = bridge.js_errors(clear).await?;
+            Ok(format_js_errors(&resp))
+        }
+        "watch_events" => {
+            let req = EventWatchRequest {
+                selector: get_string_arg(args, "selector")?,
+                events: get_string_list_arg(args, "events")?
+                    .ok_or_else(|| anyhow!("Missing 'events' argument"))?,
+                max_events: args
+                    .get("max_events")
+                    .and_then(|v| v.as_u64())
+                    .map(|v| v as u32),
+            };
+            let events = req.events.join(", ");
+            let resp = bridge.watch_events(req).await?;
+            Ok(format!(
+                "Recording {} ({} matching element(s))\nWatched selectors: {}",
+                events,
+                resp.matched_elements,
+                resp.watched_selectors.join(", ")
+            ))
+        }
+        "get_event_log" => {
+            let clear = args.get("clear").and_then(|v| v.as_bool()).unwrap_or(true);
+            Ok(format_event_log(&bridge.event_log(clear).await?))
+        }
+        "start_mutation_log" => {
+            let max_entries = args
+                .get("max_entries")
+                .and_then(|v| v.as_u64())
+                .map(|v| v as u32);
+            bridge.start_mutation_log(max_entries).await?;
+            Ok("Recording DOM mutations".to_string())
+        }
+        "get_mutation_summary" => {
+            let limit = args
+                .get("limit")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(DEFAULT_MUTATION_ENTRY_LIMIT as u64) as usize;
+            Ok(format_mutation_summary(
+                &bridge.mutation_summary().await?,
+                limit,
+            ))
+        }
+        "stop_mutation_log" => {
+            bridge.stop_mutation_log().await?;
+            Ok("Stopped recording DOM mutations".to_string())
+        }
+        "watch_signals" => watch_signals(bridge, args).await,
+        "get_signals" => Ok(format_signals(bridge.signals().await?)),
+        _ => return Ok(None),
+    };
+    text.map(Some)
+}
+
+pub(super) fn definitions() -> Vec<Value> {
+    vec![
+        tool_def_optional(
+            "get_js_errors",
+            "List uncaught JavaScript errors and unhandled promise rejections since the last call",
+            json!({
+                "clear": { "type": "boolean", "description": "Empty the error buffer after reading (default: true)" }
+            }),
+            vec![]
+        ),
+        tool_def_optional(
+            "watch_events",
+            "Start recording DOM events such as click, input and change on elements matching a selector and their descendants, including elements rendered later. Read them with get_event_log",
+            json!({
+                "selector": { "type": "string", "description": "CSS selector of the elements to watch" },
+                "events": { "type": "array", "items": { "type": "string" }, "description": "Event types to record, e.g. [\"click\", \"input\", \"change\"]" },
+                "max_events": { "type": "integer", "description": "Most events kept for this selector; older ones are dropped (default: 200)" }
+            }),
+            vec!["selector", "events"],
+        ),
+        tool_def_optional(
+            "get_event_log",
+            "List the DOM events recorded by watch_events since the last call, with target, timing and event details",
+            json!({
+                "clear": { "type": "boolean", "description": "Empty the event log after reading (default: true)" }
+            }),
+            vec![]
+        ),
+        tool_def_optional(
+            "start_mutation_log",
+            "Start recording DOM mutations under <body> (added and removed nodes, attribute and text changes), e.g. before an interaction, to see what it changed. Starting again clears the log",
+            json!({
+                "max_entries": { "type": "integer", "description": "Most mutation entries kept; totals still count older ones (default: 500)" }
+            }),
+            vec![]
+        ),
+        tool_def_optional(
+            "get_mutation_summary",
+            "Summarise the DOM mutations recorded since start_mutation_log: node, attribute and text change totals plus the most recent entries",
+            json!({
+                "limit": { "type": "integer", "description": "Most recent entries to list (default: 20)" }
+            }),
+            vec![]
+        ),
+        tool_def("stop_mutation_log", "Stop recording DOM mutations and discard the log", json!({})),
+        tool_def_optional(
+            "watch_signals",
+            "Watch signals published with use_signal_bridge for a while and list each change as it happens",
+            json!({
+                "names": { "type": "array", "items": { "type": "string" }, "description": "Signals to watch (default: all)" },
+                "interval_ms": { "type": "integer", "description": "How often the bridge reads the signals (default: 500)" },
+                "duration_ms": { "type": "integer", "description": "How long to listen (default: 5000, max: 60000)" }
+            }),
+            vec![]
+        ),
+        tool_def("get_signals", "Read the signal values the app publishes with use_signal_bridge", json!({})),
+    ]
+}
+
+fn format_js_errors(resp: &JsErrorsResponse) -> String {
+    if resp.errors.is_empty() {
+        return "No JavaScript errors".to_string();
+    }
+    let mut lines = Vec::new();
+    for error in &resp.errors {
+        let location = match (&error.source, error.line, error.col) {
+            (Some(source), Some(line), Some(col)) => format!(" at {}:{}:{}", source, line, col),
+            (Some(source), Some(line), None) => format!(" at {}:{}", source, line),
+            (Some(source), None, _) => format!(" at {}", source),
+            (None, ..) => String::new(),
+        };
+        lines.push(format!("{}{}", error.message, location));
+        if let Some(stack) = &error.stack {
+            lines.extend(stack.lines().map(|l| format!("  {}", l.trim())));
+        }
+    }
+    lines.join("\n")
+}
+
+/// One line per event, timed from the first, with its detail as compact JSON.
+fn format_event_log(resp: &EventLogResponse) -> String {
+    let Some(first) = resp.events.first() else {
+        return "No events recorded".to_string();
+    };
+    resp.events
+        .iter()
+        .map(|event| {
+            let mut line = format!(
+                "+{}ms {} on {}",
+                event.timestamp_ms.saturating_sub(first.timestamp_ms),
+                event.event_type,
+                event.target_selector
+            );
+            if event.detail.as_object().is_some_and(|d| !d.is_empty()) {
+                line.push_str(&format!(" {}", event.detail));
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Entries listed by `get_mutation_summary` when no `limit` is given.
+const DEFAULT_MUTATION_ENTRY_LIMIT: usize = 20;
+
+fn format_mutation_summary(resp: &MutationSummaryResponse, limit: usize) -> String {
+    let mut lines = vec![format!(
+        "+{} / -{} node(s), {} attribute change(s), {} text change(s)",
+        resp.added_nodes, resp.removed_nodes, resp.attribute_changes, resp.text_changes
+    )];
+    let shown = &resp.entries[resp.entries.len().saturating_sub(limit)..];
+    if shown.len() < resp.entries.len() {
+        lines.push(format!(
+            "Last {} of {} entries:",
+            shown.len(),
+            resp.entries.len()
+        ));
+    }
+    lines.extend(shown.iter().map(mutation_entry_line));
+    lines.join("\n")
+}
+
+fn mutation_entry_line(entry: &MutationEntry) -> String {
+    let quoted = |value: &Option<String>| {
+        value
+            .as_ref()
+            .map_or_else(|| "none".to_string(), |v| format!("{:?}", v))
+    };
+    let change = if entry.kind == "child_list" {
+        format!("+{} -{}", entry.added_nodes, entry.removed_nodes)
+    } else {
+        let attribute = entry
+            .attribute_name
+            .as_ref()
+            .map_or_else(String::new, |name| format!("{} ", name));
+        format!(
+            "{}{} -> {}",
+            attribute,
+            quoted(&entry.old_value),
+            quoted(&entry.new_value)
+        )
+    };
+    format!("{} {} {}", entry.kind, entry.target_selector, change)
+}
+
+/// How long `watch_signals` listens by default.
+const DEFAULT_SIGNAL_WATCH_MS: u64 = 5_000;
+
+/// Longest `watch_signals` may listen, so a tool call can't hang the client.
+const MAX_SIGNAL_WATCH_MS: u64 = 60_000;
+
+/// Collect signal changes for `duration_ms`, logging each as it arrives.
+async fn watch_signals(bridge: &dyn BridgeClientTrait, args: &Value) -> Result<String> {
+    let duration_ms = args
+        .get("duration_ms")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(DEFAULT_SIGNAL_WATCH_MS);
+    if duration_ms > MAX_SIGNAL_WATCH_MS {
+        return Err(anyhow!(
+            "duration_ms must be at most {}",
+            MAX_SIGNAL_WATCH_MS
+        ));
+    }
+    let names: Vec<String> = args
+        .get("names")
+        .and_then(|v| v.as_array())
+        .map(|names| {
+            names
+                .iter()
+                .filter_map(|n| n.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+    let interval_ms = args.get("interval_ms").and_then(|v| v.as_u64());
+
+    let deadline = tokio::time::Instant::now() + Duration::from_millis(duration_ms);
+    let mut changes = bridge.watch_signals(&names, interval_ms).await?;
+    let mut lines = Vec::new();
+    // Stop at the deadline or when the bridge ends the stream
+    while let Ok(Some(change)) = tokio::time::timeout_at(deadline, changes.next()).await {
+        let line = format_signal_change(&change?);
+        tracing::info!("Signal changed: {}", line);
+        lines.push(line);
+    }
+    if lines.is_empty() {
+        return Ok(format!("No signal changes in {}ms", duration_ms));
+    }
+    Ok(format!(
+        "{} signal change(s) in {}ms:\n{}",
+        lines.len(),
+        duration_ms,
+        lines.join("\n")
+    ))
+}
+
+fn format_signal_change(change: &SignalChange) -> String {
+    let show = |value: &Option<Value>| match value {
+        Some(value) => value.to_string(),
+        None => "(none)".to_string(),
+    };
+    format!(
+        "{}: {} -> {}",
+        change.name,
+        show(&change.old_value),
+        show(&change.new_value)
+    )
+}
+
+fn format_signals(resp: SignalsResponse) -> String {
+    if resp.signals.is_empty() {
+        return "No signals published (call use_signal_bridge in the app)".to_string();
+    }
+    let mut signals: Vec<(String, Value)> = resp.signals.into_iter().collect();
+    signals.sort_by(|a, b| a.0.cmp(&b.0));
+    signals
+        .into_iter()
+        .map(|(name, value)| format!("{} = {}", name, value))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::call_tool;
+    use crate::tools::tests::mock;
+    use reqwest::Method;
+
+    #[tokio::test]
+    async fn test_get_js_errors() {
+        let bridge = mock(&[]).with_json(
+            "/errors",
+            json!({"errors": [
+                {"message": "Uncaught TypeError: x is null", "source": "app.js", "line": 12,
+                 "col": 5, "stack": "TypeError: x is null\n    at render (app.js:12:5)",
+                 "timestamp_ms": 0},
+                {"message": "Unhandled rejection: nope", "source": null, "line": null,
+                 "col": null, "stack": null, "timestamp_ms": 0}
+            ]}),
+        );
+        let result = call_tool(&bridge, "get_js_errors", json!({"clear": false}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "Uncaught TypeError: x is null at app.js:12:5\n  \
+             TypeError: x is null\n  \
+             at render (app.js:12:5)\n\
+             Unhandled rejection: nope"
+        );
+        assert_eq!(bridge.requests()[0].path, "/errors?clear=false");
+    }
+
+    #[tokio::test]
+    async fn test_watch_events() {
+        let bridge = mock(&[]).with_json(
+            "/event-log/watch",
+            json!({"matched_elements": 1, "watched_selectors": ["#signup"]}),
+        );
+        let args = json!({"selector": "#signup", "events": ["click", "input"]});
+        let result = call_tool(&bridge, "watch_events", args).await.unwrap();
+        assert_eq!(
+            result,
+            "Recording click, input (1 matching element(s))\nWatched selectors: #signup"
+        );
+        assert_eq!(
+            bridge.requests()[0].body.clone().unwrap(),
+            json!({"selector": "#signup", "events": ["click", "input"]})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_event_log() {
+        let bridge = mock(&[]).with_json(
+            "/event-log",
+            json!({"events": [
+                {"type": "input", "target_selector": "form#signup > input", "timestamp_ms": 1000,
+                 "detail": {"input_type": "insertText", "value": "a"}},
+                {"type": "click", "target_selector": "form#signup > button", "timestamp_ms": 1250,
+                 "detail": {}}
+            ]}),
+        );
+        let result = call_tool(&bridge, "get_event_log", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "+0ms input on form#signup > input {\"input_type\":\"insertText\",\"value\":\"a\"}\n\
+             +250ms click on form#signup > button"
+        );
+        assert_eq!(bridge.requests()[0].path, "/event-log");
+
+        let bridge = mock(&[]).with_json("/event-log", json!({"events": []}));
+        let result = call_tool(&bridge, "get_event_log", json!({"clear": false}))
+            .await
+            .unwrap();
+        assert_eq!(result, "No events recorded");
+        assert_eq!(bridge.requests()[0].path, "/event-log?clear=false");
+    }
+
+    #[tokio::test]
+    async fn test_get_mutation_summary() {
+        let bridge = mock(&[]).with_json(
+            "/mutation-log",
+            json!({
+                "added_nodes": 3,
+                "removed_nodes": 1,
+                "attribute_changes": 1,
+                "text_changes": 1,
+                "entries": [
+                    {"kind": "child_list", "target_selector": "ul#items", "timestamp_ms": 0,
+                     "added_nodes": 3, "removed_nodes": 1, "attribute_name": null,
+                     "old_value": null, "new_value": null},
+                    {"kind": "attributes", "target_selector": "ul#items > li", "timestamp_ms": 0,
+                     "added_nodes": 0, "removed_nodes": 0, "attribute_name": "class",
+                     "old_value": null, "new_value": "active"},
+                    {"kind": "character_data", "target_selector": "span#count", "timestamp_ms": 0,
+                     "added_nodes": 0, "removed_nodes": 0, "attribute_name": null,
+                     "old_value": "2", "new_value": "3"}
+                ]
+            }),
+        );
+        let result = call_tool(&bridge, "get_mutation_summary", json!({"limit": 2}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "+3 / -1 node(s), 1 attribute change(s), 1 text change(s)\n\
+             Last 2 of 3 entries:\n\
+             attributes ul#items > li class none -> \"active\"\n\
+             character_data span#count \"2\" -> \"3\""
+        );
+    }
+
+    #[tokio::test]
+    async fn test_start_and_stop_mutation_log() {
+        let bridge = mock(&[])
+            .with_json("/mutation-log/start", json!({"observing": true}))
+            .with_json("/mutation-log", json!({"observing": false}));
+        let result = call_tool(&bridge, "start_mutation_log", json!({"max_entries": 100}))
+            .await
+            .unwrap();
+        assert_eq!(result, "Recording DOM mutations");
+        let result = call_tool(&bridge, "stop_mutation_log", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(result, "Stopped recording DOM mutations");
+
+        let requests = bridge.requests();
+        assert_eq!(
+            requests[0].body.clone().unwrap(),
+            json!({"max_entries": 100})
+        );
+        assert_eq!(requests[1].method, Method::DELETE);
+    }
+
+    #[tokio::test]
+    async fn test_watch_signals() {
+        let event = |data: Value| json!({"event": "signal", "data": data.to_string()});
+        let bridge = mock(&[]).with_json(
+            "/signals/watch",
+            json!([
+                event(json!({"name": "counter", "old_value": 1, "new_value": 2})),
+                event(json!({"name": "message", "old_value": "hi", "new_value": null}))
+            ]),
+        );
+        let args = json!({"names": ["counter", "message"], "duration_ms": 100});
+        let result = call_tool(&bridge, "watch_signals", args).await.unwrap();
+        assert_eq!(
+            result,
+            "2 signal change(s) in 100ms:\n\
+             counter: 1 -> 2\n\
+             message: \"hi\" -> (none)"
+        );
+        assert_eq!(
+            bridge.requests()[0].path,
+            "/signals/watch?names=counter%2Cmessage"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_watch_signals_error_event() {
+        let bridge = mock(&[]).with_json(
+            "/signals/watch",
+            json!([{"event": "error", "data": "Eval timeout"}]),
+        );
+        let err = call_tool(&bridge, "watch_signals", json!({}))
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Signal watch failed: Eval timeout");
+    }
+
+    #[tokio::test]
+    async fn test_get_signals() {
+        let bridge = mock(&[]).with_json(
+            "/signals",
+            json!({"signals": {"user": {"name": "ada"}, "count": 3}}),
+        );
+        let result = call_tool(&bridge, "get_signals", json!({})).await.unwrap();
+        assert_eq!(result, "count = 3\nuser = {\"name\":\"ada\"}");
+
+        let bridge = mock(&[]).with_json("/signals", json!({"signals": {}}));
+        let result = call_tool(&bridge, "get_signals", json!({})).await.unwrap();
+        assert!(result.starts_with("No signals published"));
+    }
+}
@@ -0,0 +1,268 @@
+//! Network tools: the request log, throttling and geolocation mocking.
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+
+use super::{get_string_arg, tool_def, tool_def_optional};
+use crate::bridge::{
+    BridgeClientTrait, GeoMockRequest, NetworkApi, NetworkCondition, NetworkResponse,
+};
+
+pub(super) async fn call(
+    bridge: &dyn BridgeClientTrait,
+    name: &str,
+    args: &Value,
+) -> Result<Option<String>> {
+    let text = match name {
+        "get_network" => get_network(bridge).await,
+        "mock_geolocation" => mock_geolocation(bridge, args).await,
+        "simulate_network" => simulate_network(bridge, args).await,
+        "restore_network" => {
+            bridge.clear_network_simulation().await?;
+            Ok("Restored the real fetch".to_string())
+        }
+        _ => return Ok(None),
+    };
+    text.map(Some)
+}
+
+pub(super) fn definitions() -> Vec<Value> {
+    vec![
+        tool_def("get_network", "List fetch/XHR requests made by the app since the bridge started (method, status, URL, timing, body previews)", json!({})),
+        tool_def_optional(
+            "mock_geolocation",
+            "Make navigator.geolocation report a fixed position or error, or restore the real API with restore: true",
+            json!({
+                "latitude": { "type": "number", "description": "Latitude in degrees" },
+                "longitude": { "type": "number", "description": "Longitude in degrees" },
+                "accuracy": { "type": "number", "description": "Accuracy radius in meters (default: 10)" },
+                "error_code": { "type": "integer", "enum": [1, 2, 3], "description": "Fail with this error instead: 1 permission denied, 2 position unavailable, 3 timeout" },
+                "restore": { "type": "boolean", "description": "Remove the mock instead (default: false)" }
+            }),
+            vec![]
+        ),
+        tool_def_optional(
+            "simulate_network",
+            "Make fetch requests fail as if offline or slow them down, to test loading and offline states. Only fetch is affected, not XHR or WebSockets",
+            json!({
+                "condition": { "type": "string", "enum": ["offline", "slow_3g", "normal"], "description": "Network condition; normal restores the real fetch" },
+                "latency_ms": { "type": "integer", "description": "Delay before each request for slow_3g (default: 2000)" },
+                "bandwidth_kbps": { "type": "integer", "description": "Download speed for slow_3g in kilobits per second (default: 400)" }
+            }),
+            vec!["condition"]
+        ),
+        tool_def("restore_network", "Undo simulate_network and restore the real fetch", json!({})),
+    ]
+}
+
+async fn get_network(bridge: &dyn BridgeClientTrait) -> Result<String> {
+    let resp = bridge.network().await?;
+    Ok(format_network(&resp))
+}
+
+fn format_network(resp: &NetworkResponse) -> String {
+    if resp.requests.is_empty() {
+        return "No requests captured".to_string();
+    }
+    let mut lines = Vec::new();
+    for req in &resp.requests {
+        let status = req
+            .status
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| "failed".to_string());
+        lines.push(format!(
+            "{} {} {} ({:.1} ms)",
+            req.method, status, req.url, req.duration_ms
+        ));
+        if let Some(body) = &req.request_body {
+            lines.push(format!("  request: {}", body));
+        }
+        if let Some(preview) = &req.response_preview {
+            lines.push(format!("  response: {}", preview));
+        }
+    }
+    lines.join("\n")
+}
+
+/// Mock `navigator.geolocation`, or restore it with `restore: true`.
+async fn mock_geolocation(bridge: &dyn BridgeClientTrait, args: &Value) -> Result<String> {
+    if args.get("restore").and_then(|v| v.as_bool()) == Some(true) {
+        bridge.clear_geolocation_mock().await?;
+        return Ok("Restored the real Geolocation API".to_string());
+    }
+    let coordinate = |key: &str| {
+        args.get(key)
+            .and_then(|v| v.as_f64())
+            .ok_or_else(|| anyhow!("Missing '{}' argument", key))
+    };
+    let error_code = args
+        .get("error_code")
+        .and_then(|v| v.as_u64())
+        .map(|v| v as u16);
+    let mock = GeoMockRequest {
+        latitude: coordinate("latitude")?,
+        longitude: coordinate("longitude")?,
+        accuracy: args
+            .get("accuracy")
+            .and_then(|v| v.as_f64())
+            .unwrap_or(10.0),
+        error_code,
+    };
+    let summary = match error_code {
+        Some(code) => format!("Geolocation now fails with error code {}", code),
+        None => format!(
+            "Geolocation now reports {}, {} (accuracy {}m)",
+            mock.latitude, mock.longitude, mock.accuracy
+        ),
+    };
+    bridge.mock_geolocation(mock).await?;
+    Ok(summary)
+}
+
+/// Default `slow_3g` latency, matching the "Slow 3G" preset of Chrome DevTools.
+const SLOW_3G_LATENCY_MS: u64 = 2_000;
+
+/// Default `slow_3g` bandwidth, matching the same preset.
+const SLOW_3G_BANDWIDTH_KBPS: u64 = 400;
+
+async fn simulate_network(bridge: &dyn BridgeClientTrait, args: &Value) -> Result<String> {
+    let condition = match get_string_arg(args, "condition")?.as_str() {
+        "offline" => NetworkCondition::Offline,
+        "slow_3g" => NetworkCondition::Slow3G {
+            latency_ms: args
+                .get("latency_ms")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(SLOW_3G_LATENCY_MS),
+            bandwidth_kbps: args
+                .get("bandwidth_kbps")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(SLOW_3G_BANDWIDTH_KBPS),
+        },
+        "normal" => NetworkCondition::Normal,
+        other => {
+            return Err(anyhow!(
+                "Unknown condition '{}', expected offline, slow_3g or normal",
+                other
+            ))
+        }
+    };
+    bridge.simulate_network(condition).await?;
+    Ok(match condition {
+        NetworkCondition::Offline => {
+            "fetch now fails as if offline; XHR and WebSockets are unaffected".to_string()
+        }
+        NetworkCondition::Slow3G {
+            latency_ms,
+            bandwidth_kbps,
+        } => format!(
+            "fetch now waits {}ms per request and downloads at {} kbps; XHR and WebSockets are unaffected",
+            latency_ms, bandwidth_kbps
+        ),
+        NetworkCondition::Normal => "Restored the real fetch".to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::call_tool;
+    use crate::tools::tests::mock;
+    use reqwest::Method;
+
+    #[test]
+    fn test_format_network() {
+        let resp = NetworkResponse {
+            requests: vec![crate::bridge::NetworkRequest {
+                url: "/api/items".to_string(),
+                method: "GET".to_string(),
+                status: None,
+                duration_ms: 12.34,
+                request_body: None,
+                response_preview: Some("TypeError: Failed to fetch".to_string()),
+            }],
+        };
+        assert_eq!(
+            format_network(&resp),
+            "GET failed /api/items (12.3 ms)\n  response: TypeError: Failed to fetch"
+        );
+    }
+
+    #[test]
+    fn test_format_network_empty() {
+        let resp = NetworkResponse { requests: vec![] };
+        assert_eq!(format_network(&resp), "No requests captured");
+    }
+
+    #[tokio::test]
+    async fn test_get_network_tool() {
+        let bridge = mock(&[]).with_json(
+            "/network",
+            json!({"requests": [{"url": "/api", "method": "POST", "status": 201,
+                "duration_ms": 3.0, "request_body": "{}", "response_preview": null}]}),
+        );
+        let result = call_tool(&bridge, "get_network", json!({})).await.unwrap();
+        assert_eq!(result, "POST 201 /api (3.0 ms)\n  request: {}");
+    }
+
+    #[tokio::test]
+    async fn test_simulate_network() {
+        let bridge = mock(&[]).with_json("/simulate-network", json!({"simulated": true}));
+        let args = json!({"condition": "slow_3g", "latency_ms": 500});
+        let result = call_tool(&bridge, "simulate_network", args).await.unwrap();
+        assert_eq!(
+            result,
+            "fetch now waits 500ms per request and downloads at 400 kbps; XHR and WebSockets are unaffected"
+        );
+        assert_eq!(
+            bridge.requests()[0].body.clone().unwrap(),
+            json!({"condition": {"type": "slow_3g", "latency_ms": 500, "bandwidth_kbps": 400}})
+        );
+
+        let err = call_tool(&bridge, "simulate_network", json!({"condition": "5g"}))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown condition '5g', expected offline, slow_3g or normal"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_restore_network() {
+        let bridge = mock(&[]).with_json("/simulate-network", json!({"simulated": false}));
+        let result = call_tool(&bridge, "restore_network", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(result, "Restored the real fetch");
+        assert_eq!(bridge.requests()[0].method, Method::DELETE);
+    }
+
+    #[tokio::test]
+    async fn test_mock_geolocation() {
+        let bridge = mock(&[]).with_json("/geo/mock", json!({"mocked": true}));
+        let result = call_tool(
+            &bridge,
+            "mock_geolocation",
+            json!({"latitude": 52.52, "longitude": 13.405}),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            result,
+            "Geolocation now reports 52.52, 13.405 (accuracy 10m)"
+        );
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(body["accuracy"], 10.0);
+        assert!(body["error_code"].is_null());
+    }
+
+    #[tokio::test]
+    async fn test_mock_geolocation_restore() {
+        let bridge = mock(&[]).with_json("/geo/mock", json!({"mocked": false}));
+        let result = call_tool(&bridge, "mock_geolocation", json!({"restore": true}))
+            .await
+            .unwrap();
+        assert_eq!(result, "Restored the real Geolocation API");
+        assert_eq!(bridge.requests()[0].method, Method::DELETE);
+    }
+}
@@ -0,0 +1,542 @@
+//! Tools that find elements by CSS selector or text and extract their content.
+
+use anyhow::Result;
+use serde_json::{json, Value};
+
+use super::{
+    extract_json_pretty, extract_result, get_string_arg, get_string_list_arg, tool_def,
+    tool_def_optional,
+};
+use crate::bridge::{
+    BridgeClientTrait, DataAttributesResponse, DomApi, DomSearchRequest, DomSearchResponse,
+    EvalApi, ListResponse, QueryAllResponse, SlotResponse, TableResponse,
+};
+
+pub(super) async fn call(
+    bridge: &dyn BridgeClientTrait,
+    name: &str,
+    args: &Value,
+) -> Result<Option<String>> {
+    let text = match name {
+        "query_text" => {
+            let selector = get_string_arg(args, "selector")?;
+            query_text(bridge, &selector).await
+        }
+        "query_html" => {
+            let selector = get_string_arg(args, "selector")?;
+            query_html(bridge, &selector).await
+        }
+        "query_all" => {
+            let selector = get_string_arg(args, "selector")?;
+            query_all(bridge, &selector).await
+        }
+        "query_values" => {
+            let selector = get_string_arg(args, "selector")?;
+            let property = args.get("property").and_then(|v| v.as_str());
+            let limit = args.get("limit").and_then(|v| v.as_u64()).map(|v| v as u32);
+            query_values(bridge, &selector, property, limit).await
+        }
+        "get_data_attributes" => {
+            let selector = get_string_arg(args, "selector")?;
+            get_data_attributes(bridge, &selector).await
+        }
+        "get_computed_styles" => {
+            let selector = get_string_arg(args, "selector")?;
+            let properties = get_string_list_arg(args, "properties")?;
+            get_computed_styles(bridge, &selector, properties).await
+        }
+        "extract_table" => {
+            let selector = get_string_arg(args, "selector")?;
+            Ok(format_table(&bridge.extract_table(&selector).await?))
+        }
+        "extract_list" => {
+            let selector = get_string_arg(args, "selector")?;
+            Ok(format_list(&bridge.extract_list(&selector).await?))
+        }
+        "inspect_slots" => {
+            let host_selector = get_string_arg(args, "host_selector")?;
+            Ok(format_slots(&bridge.inspect_slots(&host_selector).await?))
+        }
+        "search_dom" => {
+            let req = DomSearchRequest {
+                text: get_string_arg(args, "text")?,
+                case_sensitive: args.get("case_sensitive").and_then(|v| v.as_bool()),
+                exact: args.get("exact").and_then(|v| v.as_bool()),
+                selector_filter: args
+                    .get("selector_filter")
+                    .and_then(|v| v.as_str())
+                    .map(String::from),
+            };
+            Ok(format_dom_search(&bridge.dom_search(req).await?))
+        }
+        _ => return Ok(None),
+    };
+    text.map(Some)
+}
+
+pub(super) fn definitions() -> Vec<Value> {
+    vec![
+        tool_def("query_text", "Get element text by CSS selector", json!({
+            "selector": { "type": "string", "description": "CSS selector" }
+        })),
+        tool_def("query_html", "Get element innerHTML by CSS selector", json!({
+            "selector": { "type": "string", "description": "CSS selector" }
+        })),
+        tool_def("query_all", "List all elements matching a selector", json!({
+            "selector": { "type": "string", "description": "CSS selector" }
+        })),
+        tool_def_optional(
+            "query_values",
+            "Read a property from every element matching a selector",
+            json!({
+                "selector": { "type": "string", "description": "CSS selector" },
+                "property": { "type": "string", "description": "text (default), html, outerHTML, value, or an attribute name" },
+                "limit": { "type": "number", "description": "Max elements to return (default: 100)" }
+            }),
+            vec!["selector"],
+        ),
+        tool_def("get_data_attributes", "List the data-* attributes of an element, e.g. data-state and data-open on Dioxus Primitives components", json!({
+            "selector": { "type": "string", "description": "CSS selector" }
+        })),
+        tool_def_optional(
+            "get_computed_styles",
+            "Get computed CSS properties of an element (all of them unless properties is given)",
+            json!({
+                "selector": { "type": "string", "description": "CSS selector" },
+                "properties": {
+                    "type": "array",
+                    "description": "Property names to return, e.g. [\"display\", \"margin-top\"]",
+                    "items": { "type": "string" }
+                }
+            }),
+            vec!["selector"]
+        ),
+        tool_def("extract_table", "Read the header and cell text of an HTML table as a Markdown table, noting merged (colspan/rowspan) cells", json!({
+            "selector": { "type": "string", "description": "CSS selector of the table" }
+        })),
+        tool_def_optional(
+            "search_dom",
+            "Find elements by their text content, which CSS selectors can't do, with a selector path, snippet and match count for each",
+            json!({
+                "text": { "type": "string", "description": "Text to look for" },
+                "case_sensitive": { "type": "boolean", "description": "Match case exactly (default: false)" },
+                "exact": { "type": "boolean", "description": "Match only text that equals the search text once trimmed (default: false)" },
+                "selector_filter": { "type": "string", "description": "CSS selector; only search inside matching elements" }
+            }),
+            vec!["text"],
+        ),
+        tool_def("inspect_slots", "List the slots in a web component's open shadow root with the elements assigned to each, or the fallback content shown when empty", json!({
+            "host_selector": { "type": "string", "description": "CSS selector of the custom element" }
+        })),
+        tool_def("extract_list", "Read the items of a list (ul, ol, menu, dl, or ARIA list/listbox/menu) with their text, link and data-* attributes, e.g. navigation menus or options", json!({
+            "selector": { "type": "string", "description": "CSS selector of the list" }
+        })),
+    ]
+}
+
+pub(super) async fn query_text(bridge: &dyn BridgeClientTrait, selector: &str) -> Result<String> {
+    let resp = bridge.query(selector, Some("text")).await?;
+    extract_result(resp)
+}
+
+async fn query_html(bridge: &dyn BridgeClientTrait, selector: &str) -> Result<String> {
+    let resp = bridge.query(selector, Some("html")).await?;
+    extract_result(resp)
+}
+
+async fn query_all(bridge: &dyn BridgeClientTrait, selector: &str) -> Result<String> {
+    let script = format!(
+        r#"return (() => {{
+            const els = document.querySelectorAll({});
+            return JSON.stringify(Array.from(els).map((el, i) => ({{
+                index: i,
+                tag: el.tagName.toLowerCase(),
+                id: el.id || null,
+                class: el.className || null,
+                text: el.textContent?.trim().substring(0, 100) || null
+            }})));
+        }})()"#,
+        serde_json::to_string(selector)?
+    );
+    let resp = bridge.eval(&script).await?;
+    extract_json_pretty(resp)
+}
+
+async fn query_values(
+    bridge: &dyn BridgeClientTrait,
+    selector: &str,
+    property: Option<&str>,
+    limit: Option<u32>,
+) -> Result<String> {
+    let resp = bridge.query_all(selector, property, limit).await?;
+    Ok(format_query_all(selector, &resp))
+}
+
+fn format_query_all(selector: &str, resp: &QueryAllResponse) -> String {
+    if resp.total_count == 0 {
+        return format!("No elements match '{}'", selector);
+    }
+
+    let mut lines = vec![format!(
+        "{} element(s) match '{}'",
+        resp.total_count, selector
+    )];
+    for (i, element) in resp.elements.iter().enumerate() {
+        let value = element.result.as_deref().unwrap_or("(null)");
+        lines.push(format!("[{}] {}", i, value));
+    }
+    if resp.truncated {
+        lines.push(format!(
+            "... {} more not shown",
+            resp.total_count as usize - resp.elements.len()
+        ));
+    }
+    lines.join("\n")
+}
+
+async fn get_data_attributes(bridge: &dyn BridgeClientTrait, selector: &str) -> Result<String> {
+    let resp = bridge.data_attributes(selector).await?;
+    Ok(format_data_attributes(selector, &resp))
+}
+
+fn format_data_attributes(selector: &str, resp: &DataAttributesResponse) -> String {
+    if resp.attributes.is_empty() {
+        return format!("No data-* attributes on '{}'", selector);
+    }
+    let mut attributes: Vec<_> = resp.attributes.iter().collect();
+    attributes.sort();
+    attributes
+        .into_iter()
+        .map(|(name, value)| format!("{}=\"{}\"", name, value))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+async fn get_computed_styles(
+    bridge: &dyn BridgeClientTrait,
+    selector: &str,
+    properties: Option<Vec<String>>,
+) -> Result<String> {
+    let resp = bridge.computed_styles(selector, properties).await?;
+    if resp.styles.is_empty() {
+        return Ok(format!("No computed styles for '{}'", selector));
+    }
+    let mut styles: Vec<_> = resp.styles.iter().collect();
+    styles.sort();
+    Ok(styles
+        .into_iter()
+        .map(|(name, value)| format!("{}: {};", name, value))
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+fn format_slots(resp: &SlotResponse) -> String {
+    if resp.slots.is_empty() {
+        return "No slots in the shadow root".to_string();
+    }
+    let mut lines = Vec::new();
+    for slot in &resp.slots {
+        let name = if slot.name.is_empty() {
+            "(default)"
+        } else {
+            slot.name.as_str()
+        };
+        if slot.assigned_elements.is_empty() {
+            let fallback = if slot.fallback_content.is_empty() {
+                "nothing"
+            } else {
+                slot.fallback_content.as_str()
+            };
+            lines.push(format!("- {}: empty, showing {}", name, fallback));
+        } else {
+            lines.push(format!("- {}: {}", name, slot.assigned_elements.join(", ")));
+        }
+    }
+    lines.join("\n")
+}
+
+fn format_dom_search(resp: &DomSearchResponse) -> String {
+    if resp.matches.is_empty() {
+        return "No matching text".to_string();
+    }
+    let mut lines = vec![format!("{} matching element(s):", resp.matches.len())];
+    for m in &resp.matches {
+        lines.push(format!(
+            "  {} <{}> x{}: {}",
+            m.selector_path, m.tag, m.match_count, m.text_snippet
+        ));
+    }
+    lines.join("\n")
+}
+
+fn format_list(resp: &ListResponse) -> String {
+    let mut lines = vec![format!(
+        "<{}> with {} item(s):",
+        resp.list_type,
+        resp.items.len()
+    )];
+    for (i, item) in resp.items.iter().enumerate() {
+        let marker = if resp.ordered {
+            format!("{}.", i + 1)
+        } else {
+            "-".to_string()
+        };
+        let mut line = format!("{} {}", marker, item.text);
+        if item.tag != "li" {
+            line.push_str(&format!(" <{}>", item.tag));
+        }
+        if let Some(href) = &item.href {
+            line.push_str(&format!(" ({})", href));
+        }
+        let mut data: Vec<String> = item
+            .data_attributes
+            .iter()
+            .map(|(name, value)| format!("{}=\"{}\"", name, value))
+            .collect();
+        if !data.is_empty() {
+            data.sort();
+            line.push_str(&format!(" [{}]", data.join(" ")));
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+/// Render a table as Markdown, noting merged cells below it.
+fn format_table(resp: &TableResponse) -> String {
+    let columns = resp
+        .rows
+        .iter()
+        .map(Vec::len)
+        .chain([resp.headers.len()])
+        .max()
+        .unwrap_or(0);
+    if columns == 0 {
+        return "Empty table".to_string();
+    }
+    let row = |cells: &[String]| {
+        let cells: Vec<String> = (0..columns)
+            .map(|i| cells.get(i).map_or("", String::as_str).replace('|', "\\|"))
+            .collect();
+        format!("| {} |", cells.join(" | "))
+    };
+
+    let mut lines = vec![format!("{} row(s)", resp.row_count)];
+    lines.push(row(&resp.headers));
+    lines.push(format!("|{}", " --- |".repeat(columns)));
+    lines.extend(resp.rows.iter().map(|cells| row(cells)));
+    for cell in &resp.merged_cells {
+        lines.push(format!(
+            "Merged: row {}, column {} spans {} row(s) x {} column(s)",
+            cell.row, cell.column, cell.rowspan, cell.colspan
+        ));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_bridge::{ok, ok_json};
+    use crate::tools::call_tool;
+    use crate::tools::tests::{mock, sent_script};
+
+    #[tokio::test]
+    async fn test_query_text() {
+        let bridge = mock(&[("/query", ok("\"Hello\""))]);
+        let result = call_tool(&bridge, "query_text", json!({"selector": "h1"}))
+            .await
+            .unwrap();
+        assert_eq!(result, "\"Hello\"");
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(body, json!({"selector": "h1", "property": "text"}));
+    }
+
+    #[tokio::test]
+    async fn test_query_all() {
+        let bridge = mock(&[("return", ok_json(&json!([{"index": 0, "tag": "li"}])))]);
+        let result = call_tool(&bridge, "query_all", json!({"selector": "li"}))
+            .await
+            .unwrap();
+        assert!(result.contains("\"tag\": \"li\""));
+        assert!(sent_script(&bridge).contains("querySelectorAll(\"li\")"));
+    }
+
+    #[tokio::test]
+    async fn test_query_values() {
+        let bridge = mock(&[]).with_json(
+            "/query/all",
+            json!({
+                "elements": [
+                    {"success": true, "result": "1"},
+                    {"success": true, "result": null}
+                ],
+                "total_count": 3,
+                "truncated": true
+            }),
+        );
+        let args = json!({"selector": "li", "property": "data-id", "limit": 2});
+        let result = call_tool(&bridge, "query_values", args).await.unwrap();
+        assert_eq!(
+            result,
+            "3 element(s) match 'li'\n[0] 1\n[1] (null)\n... 1 more not shown"
+        );
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(
+            body,
+            json!({"selector": "li", "property": "data-id", "limit": 2})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_query_values_no_match() {
+        let bridge = mock(&[]).with_json(
+            "/query/all",
+            json!({"elements": [], "total_count": 0, "truncated": false}),
+        );
+        let result = call_tool(&bridge, "query_values", json!({"selector": ".nope"}))
+            .await
+            .unwrap();
+        assert_eq!(result, "No elements match '.nope'");
+    }
+
+    #[tokio::test]
+    async fn test_get_data_attributes() {
+        let bridge = mock(&[]).with_json(
+            "/data-attributes",
+            json!({"attributes": {"data-state": "checked", "data-disabled": ""}}),
+        );
+        let result = call_tool(
+            &bridge,
+            "get_data_attributes",
+            json!({"selector": "#terms"}),
+        )
+        .await
+        .unwrap();
+        assert_eq!(result, "data-disabled=\"\"\ndata-state=\"checked\"");
+    }
+
+    #[tokio::test]
+    async fn test_get_computed_styles() {
+        let bridge = mock(&[]).with_json(
+            "/computed-styles",
+            json!({"styles": {"display": "flex", "color": "rgb(0, 0, 0)"}}),
+        );
+        let result = call_tool(
+            &bridge,
+            "get_computed_styles",
+            json!({"selector": ".card", "properties": ["display", "color"]}),
+        )
+        .await
+        .unwrap();
+        assert_eq!(result, "color: rgb(0, 0, 0);\ndisplay: flex;");
+
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(body["properties"], json!(["display", "color"]));
+    }
+
+    #[tokio::test]
+    async fn test_get_computed_styles_rejects_non_string_properties() {
+        let bridge = mock(&[]);
+        let result = call_tool(
+            &bridge,
+            "get_computed_styles",
+            json!({"selector": ".card", "properties": [1]}),
+        )
+        .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_search_dom() {
+        let bridge = mock(&[]).with_json(
+            "/dom/search",
+            json!({"matches": [
+                {"selector_path": "header > button", "tag": "button", "text_snippet": "Sign in", "match_count": 1},
+                {"selector_path": "main > p", "tag": "p", "text_snippet": "...please sign in to continue", "match_count": 2}
+            ]}),
+        );
+        let args = json!({"text": "sign in", "selector_filter": "body"});
+        let result = call_tool(&bridge, "search_dom", args).await.unwrap();
+        assert_eq!(
+            result,
+            "2 matching element(s):\n  \
+             header > button <button> x1: Sign in\n  \
+             main > p <p> x2: ...please sign in to continue"
+        );
+        assert_eq!(
+            bridge.requests()[0].body.clone().unwrap(),
+            json!({"text": "sign in", "selector_filter": "body"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_inspect_slots() {
+        let bridge = mock(&[]).with_json(
+            "/slot",
+            json!({"slots": [
+                {"name": "title", "assigned_elements": ["my-card > h2"], "fallback_content": ""},
+                {"name": "", "assigned_elements": [], "fallback_content": "<p>Empty</p>"}
+            ]}),
+        );
+        let args = json!({"host_selector": "my-card"});
+        let result = call_tool(&bridge, "inspect_slots", args).await.unwrap();
+        assert_eq!(
+            result,
+            "- title: my-card > h2\n\
+             - (default): empty, showing <p>Empty</p>"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_extract_list() {
+        let bridge = mock(&[]).with_json(
+            "/list",
+            json!({
+                "items": [
+                    {"tag": "li", "text": "Home", "href": "http://localhost/",
+                     "data_attributes": {"data-active": "true"}},
+                    {"tag": "li", "text": "Settings", "href": null, "data_attributes": {}}
+                ],
+                "list_type": "ol",
+                "ordered": true
+            }),
+        );
+        let args = json!({"selector": "nav ol"});
+        let result = call_tool(&bridge, "extract_list", args).await.unwrap();
+        assert_eq!(
+            result,
+            "<ol> with 2 item(s):\n\
+             1. Home (http://localhost/) [data-active=\"true\"]\n\
+             2. Settings"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_extract_table() {
+        let bridge = mock(&[]).with_json(
+            "/table",
+            json!({
+                "headers": ["Item", "Qty"],
+                "rows": [["Apples", "3"], ["Total", "Total"]],
+                "row_count": 2,
+                "merged_cells": [{"row": 1, "column": 0, "rowspan": 1, "colspan": 2}]
+            }),
+        );
+        let args = json!({"selector": "table.orders"});
+        let result = call_tool(&bridge, "extract_table", args).await.unwrap();
+        assert_eq!(
+            result,
+            "2 row(s)\n\
+             | Item | Qty |\n\
+             | --- | --- |\n\
+             | Apples | 3 |\n\
+             | Total | Total |\n\
+             Merged: row 1, column 0 spans 1 row(s) x 2 column(s)"
+        );
+        assert_eq!(
+            bridge.requests()[0].body.clone().unwrap(),
+            json!({"selector": "table.orders"})
+        );
+    }
+}
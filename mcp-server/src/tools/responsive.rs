@@ -0,0 +1,511 @@
+//! Tools for checking how the page adapts: sizes, breakpoints, dark mode, zoom, media queries and translated text.
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+
+use super::viewport::screenshot;
+use super::{get_string_map_arg, tool_def, tool_def_optional};
+use crate::bridge::{
+    BreakpointSimResponse, BreakpointStep, BridgeClientTrait, DarkModeResponse,
+    MediaQueryAllResponse, ResponsiveTestResponse, ViewportApi, ViewportSize, ZoomResponse,
+};
+
+pub(super) async fn call(
+    bridge: &dyn BridgeClientTrait,
+    name: &str,
+    args: &Value,
+) -> Result<Option<String>> {
+    let text = match name {
+        "get_dark_mode" => Ok(format_dark_mode(&bridge.get_dark_mode().await?)),
+        "set_dark_mode" => {
+            let dark = args
+                .get("dark")
+                .and_then(|v| v.as_bool())
+                .ok_or_else(|| anyhow!("Missing 'dark' argument"))?;
+            Ok(format_dark_mode(&bridge.set_dark_mode(dark).await?))
+        }
+        "get_zoom" => get_zoom(bridge).await,
+        "set_zoom" => {
+            let level = args
+                .get("level")
+                .and_then(|v| v.as_f64())
+                .ok_or_else(|| anyhow!("Missing 'level' argument"))?;
+            set_zoom(bridge, level).await
+        }
+        "responsive_test" => {
+            let sizes = match args.get("sizes") {
+                Some(sizes) => serde_json::from_value(sizes.clone())
+                    .map_err(|e| anyhow!("Invalid sizes: {}", e))?,
+                None => default_responsive_sizes(),
+            };
+            let screenshot = args
+                .get("screenshot")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            let resp = bridge.responsive_test(sizes, screenshot).await?;
+            Ok(format_responsive(&resp))
+        }
+        "simulate_breakpoints" => {
+            let breakpoints = match args.get("breakpoints") {
+                Some(breakpoints) => serde_json::from_value(breakpoints.clone())
+                    .map_err(|e| anyhow!("Invalid breakpoints: {}", e))?,
+                None => default_breakpoints(),
+            };
+            let screenshot = args
+                .get("screenshot")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            let resp = bridge.simulate_breakpoints(breakpoints, screenshot).await?;
+            Ok(format_breakpoints(&resp))
+        }
+        "check_media_query" => match args.get("query").and_then(|v| v.as_str()) {
+            Some(query) => {
+                let resp = bridge.media_query(query).await?;
+                let verdict = if resp.matches {
+                    "matches"
+                } else {
+                    "does not match"
+                };
+                Ok(format!("{} {}", resp.query, verdict))
+            }
+            None => Ok(format_media_queries(bridge.media_query_all().await?)),
+        },
+        "simulate_translation" => simulate_translation(bridge, args).await,
+        _ => return Ok(None),
+    };
+    text.map(Some)
+}
+
+pub(super) fn definitions() -> Vec<Value> {
+    vec![
+        tool_def("get_dark_mode", "Read the system prefers-color-scheme and the document's data-theme attribute", json!({})),
+        tool_def("set_dark_mode", "Switch the document's data-theme attribute between dark and light", json!({
+            "dark": { "type": "boolean", "description": "true for data-theme=\"dark\", false for \"light\"" }
+        })),
+        tool_def("get_zoom", "Read the document zoom level and device pixel ratio", json!({})),
+        tool_def("set_zoom", "Set the CSS zoom level of the document body", json!({
+            "level": { "type": "number", "description": "Zoom factor (1.0 = 100%)" }
+        })),
+        tool_def_optional(
+            "responsive_test",
+            "Resize the window to several sizes in turn, reporting the DOM size and optional screenshot at each (the app must handle /resize)",
+            json!({
+                "sizes": {
+                    "type": "array",
+                    "description": "Sizes to try (default: mobile 375x812, tablet 768x1024, desktop 1440x900)",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "name": { "type": "string" },
+                            "width": { "type": "integer" },
+                            "height": { "type": "integer" }
+                        },
+                        "required": ["name", "width", "height"]
+                    }
+                },
+                "screenshot": { "type": "boolean", "description": "Capture a screenshot at each size (macOS only, default: false)" }
+            }),
+            vec![]
+        ),
+        tool_def_optional(
+            "simulate_breakpoints",
+            "Step the window through CSS breakpoints, waiting at each, and report the viewport, whether the DOM changed from the previous step, and optional screenshots (the app must handle /resize)",
+            json!({
+                "breakpoints": {
+                    "type": "array",
+                    "description": "Steps in order (default: sm 640, md 768, lg 1024, xl 1280, all 900 high)",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "name": { "type": "string" },
+                            "width": { "type": "integer" },
+                            "height": { "type": "integer" },
+                            "delay_ms": { "type": "integer", "description": "Wait after resizing (default: 200)" }
+                        },
+                        "required": ["name", "width", "height"]
+                    }
+                },
+                "screenshot": { "type": "boolean", "description": "Capture a screenshot at each step (macOS only, default: false)" }
+            }),
+            vec![]
+        ),
+        tool_def_optional(
+            "check_media_query",
+            "Check whether a CSS media query matches the webview, or which common max-width breakpoints (640/768/1024/1280px) match",
+            json!({
+                "query": { "type": "string", "description": "Media query, e.g. (prefers-reduced-motion: reduce); omit to check the common breakpoints" }
+            }),
+            vec![]
+        ),
+        tool_def_optional(
+            "simulate_translation",
+            "Replace text in the page from a dictionary to check layouts with translated (e.g. longer German) strings, or put the original text back with restore: true",
+            json!({
+                "replacements": { "type": "object", "description": "Text to find mapped to its replacement, e.g. {\"Save\": \"Speichern\"}", "additionalProperties": { "type": "string" } },
+                "selector": { "type": "string", "description": "CSS selector of the element to translate (default: body)" },
+                "restore": { "type": "boolean", "description": "Restore the original text instead (default: false)" }
+            }),
+            vec![]
+        ),
+    ]
+}
+
+async fn get_zoom(bridge: &dyn BridgeClientTrait) -> Result<String> {
+    let resp = bridge.get_zoom().await?;
+    Ok(format_zoom(&resp))
+}
+
+async fn set_zoom(bridge: &dyn BridgeClientTrait, level: f64) -> Result<String> {
+    let resp = bridge.set_zoom(level).await?;
+    Ok(format_zoom(&resp))
+}
+
+/// Common phone, tablet and laptop sizes.
+fn default_responsive_sizes() -> Vec<ViewportSize> {
+    [
+        ("mobile", 375, 812),
+        ("tablet", 768, 1024),
+        ("desktop", 1440, 900),
+    ]
+    .into_iter()
+    .map(|(name, width, height)| ViewportSize {
+        name: name.to_string(),
+        width,
+        height,
+    })
+    .collect()
+}
+
+/// Tailwind's `sm`, `md`, `lg` and `xl` breakpoints.
+fn default_breakpoints() -> Vec<BreakpointStep> {
+    [("sm", 640), ("md", 768), ("lg", 1024), ("xl", 1280)]
+        .into_iter()
+        .map(|(name, width)| BreakpointStep {
+            name: name.to_string(),
+            width,
+            height: 900,
+            delay_ms: None,
+        })
+        .collect()
+}
+
+fn format_breakpoints(resp: &BreakpointSimResponse) -> String {
+    resp.results
+        .iter()
+        .enumerate()
+        .map(|(i, result)| {
+            let mut parts = Vec::new();
+            if let Some(viewport) = &result.viewport {
+                parts.push(format!(
+                    "viewport {}x{}",
+                    viewport.inner_width, viewport.inner_height
+                ));
+            }
+            if i > 0 && result.error.is_none() {
+                let dom = if result.dom_changed {
+                    "DOM changed"
+                } else {
+                    "DOM unchanged"
+                };
+                parts.push(dom.to_string());
+            }
+            if let Some(path) = &result.screenshot_path {
+                parts.push(format!("screenshot {}", path));
+            }
+            if let Some(error) = &result.error {
+                parts.push(format!("error: {}", error));
+            }
+            format!("{} ({}): {}", result.name, result.size, parts.join(", "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn format_responsive(resp: &ResponsiveTestResponse) -> String {
+    resp.results
+        .iter()
+        .map(|result| {
+            let mut parts = Vec::new();
+            if let Some(path) = &result.screenshot_path {
+                parts.push(format!("screenshot {}", path));
+            }
+            if let Some(root) = result.dom.as_ref().and_then(|dom| dom.get("root")) {
+                parts.push(format!("{} DOM nodes", count_dom_nodes(root)));
+            }
+            if let Some(error) = &result.error {
+                parts.push(format!("error: {}", error));
+            }
+            format!("{} ({}): {}", result.name, result.size, parts.join(", "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn count_dom_nodes(node: &Value) -> usize {
+    1 + node
+        .get("children")
+        .and_then(|c| c.as_array())
+        .map_or(0, |children| children.iter().map(count_dom_nodes).sum())
+}
+
+fn format_dark_mode(resp: &DarkModeResponse) -> String {
+    format!(
+        "System prefers: {}\nDocument theme: {} (data-theme: {})",
+        if resp.system_dark { "dark" } else { "light" },
+        if resp.document_dark { "dark" } else { "light" },
+        resp.data_theme.as_deref().unwrap_or("unset")
+    )
+}
+
+fn format_zoom(resp: &ZoomResponse) -> String {
+    if resp.success {
+        format!(
+            "Zoom: {} (devicePixelRatio: {})",
+            resp.level, resp.device_pixel_ratio
+        )
+    } else {
+        "Zoom unavailable".to_string()
+    }
+}
+
+fn format_media_queries(resp: MediaQueryAllResponse) -> String {
+    let mut matches: Vec<(String, bool)> = resp.matches.into_iter().collect();
+    // Breakpoints are all `(max-width: Npx)`, so sort by width rather than text
+    matches.sort_by_key(|(query, _)| {
+        let width: String = query.chars().filter(|c| c.is_ascii_digit()).collect();
+        (width.parse::<u32>().unwrap_or(u32::MAX), query.clone())
+    });
+    matches
+        .into_iter()
+        .map(|(query, matched)| {
+            let verdict = if matched { "matches" } else { "does not match" };
+            format!("{} {}", query, verdict)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+async fn simulate_translation(bridge: &dyn BridgeClientTrait, args: &Value) -> Result<String> {
+    if args.get("restore").and_then(|v| v.as_bool()) == Some(true) {
+        let resp = bridge.restore_translation().await?;
+        return Ok(format!(
+            "Restored the original text of {} node(s)",
+            resp.restored_count
+        ));
+    }
+    let replacements = get_string_map_arg(args, "replacements")?;
+    let selector = args.get("selector").and_then(|v| v.as_str());
+    let resp = bridge.translate(replacements, selector).await?;
+    Ok(format!(
+        "Replaced {} occurrence(s) in {}; call again with restore: true to undo",
+        resp.replaced_count,
+        selector.unwrap_or("body")
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::call_tool;
+    use crate::tools::tests::mock;
+    use reqwest::Method;
+
+    #[tokio::test]
+    async fn test_set_zoom() {
+        let bridge = mock(&[]).with_json(
+            "/zoom",
+            json!({"success": true, "level": 1.5, "device_pixel_ratio": 2.0}),
+        );
+        let result = call_tool(&bridge, "set_zoom", json!({"level": 1.5}))
+            .await
+            .unwrap();
+        assert_eq!(result, "Zoom: 1.5 (devicePixelRatio: 2)");
+        assert_eq!(
+            bridge.requests()[0].body.clone().unwrap(),
+            json!({"level": 1.5})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_responsive_test() {
+        let bridge = mock(&[]).with_json(
+            "/responsive",
+            json!({"results": [
+                {"name": "mobile", "size": "375x812", "screenshot_path": "/tmp/dioxus-responsive-mobile.png",
+                    "dom": {"root": {"tag": "body", "children": [{"tag": "div"}]}}, "error": null},
+                {"name": "tiny", "size": "1x1", "screenshot_path": null, "dom": null, "error": "unsupported"}
+            ]}),
+        );
+        let args =
+            json!({"sizes": [{"name": "mobile", "width": 375, "height": 812}], "screenshot": true});
+        let result = call_tool(&bridge, "responsive_test", args).await.unwrap();
+        assert_eq!(
+            result,
+            "mobile (375x812): screenshot /tmp/dioxus-responsive-mobile.png, 2 DOM nodes\n\
+             tiny (1x1): error: unsupported"
+        );
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(
+            body,
+            json!({"sizes": [{"name": "mobile", "width": 375, "height": 812}], "screenshot": true})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_simulate_breakpoints() {
+        let viewport = |width: u32| {
+            json!({"inner_width": width, "inner_height": 900, "outer_width": width, "outer_height": 928,
+                   "device_pixel_ratio": 2.0, "orientation": "landscape"})
+        };
+        let bridge = mock(&[]).with_json(
+            "/breakpoints/simulate",
+            json!({"results": [
+                {"name": "sm", "size": "640x900", "viewport": viewport(640), "dom_changed": false,
+                 "screenshot_path": null, "error": null},
+                {"name": "md", "size": "768x900", "viewport": viewport(768), "dom_changed": true,
+                 "screenshot_path": null, "error": null},
+                {"name": "lg", "size": "1024x900", "viewport": null, "dom_changed": false,
+                 "screenshot_path": null, "error": "unsupported"}
+            ]}),
+        );
+        let args =
+            json!({"breakpoints": [{"name": "sm", "width": 640, "height": 900, "delay_ms": 300}]});
+        let result = call_tool(&bridge, "simulate_breakpoints", args)
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "sm (640x900): viewport 640x900\n\
+             md (768x900): viewport 768x900, DOM changed\n\
+             lg (1024x900): error: unsupported"
+        );
+        assert_eq!(
+            bridge.requests()[0].body.clone().unwrap(),
+            json!({"breakpoints": [{"name": "sm", "width": 640, "height": 900, "delay_ms": 300}], "screenshot": false})
+        );
+
+        let bridge = mock(&[]).with_json("/breakpoints/simulate", json!({"results": []}));
+        call_tool(&bridge, "simulate_breakpoints", json!({}))
+            .await
+            .unwrap();
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(body["breakpoints"].as_array().unwrap().len(), 4);
+        assert!(body["breakpoints"][0].get("delay_ms").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_responsive_test_default_sizes() {
+        let bridge = mock(&[]).with_json("/responsive", json!({"results": []}));
+        call_tool(&bridge, "responsive_test", json!({}))
+            .await
+            .unwrap();
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(body["sizes"].as_array().unwrap().len(), 3);
+        assert_eq!(body["screenshot"], false);
+    }
+
+    #[tokio::test]
+    async fn test_get_dark_mode() {
+        let bridge = mock(&[]).with_json(
+            "/dark-mode",
+            json!({"system_dark": true, "document_dark": false, "data_theme": null}),
+        );
+        let result = call_tool(&bridge, "get_dark_mode", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "System prefers: dark\nDocument theme: light (data-theme: unset)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_dark_mode() {
+        let bridge = mock(&[]).with_json(
+            "/dark-mode",
+            json!({"system_dark": false, "document_dark": true, "data_theme": "dark"}),
+        );
+        let result = call_tool(&bridge, "set_dark_mode", json!({"dark": true}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "System prefers: light\nDocument theme: dark (data-theme: dark)"
+        );
+        assert_eq!(
+            bridge.requests()[0].body.clone().unwrap(),
+            json!({"dark": true})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_zoom() {
+        let bridge = mock(&[]).with_json(
+            "/zoom",
+            json!({"success": true, "level": 1.0, "device_pixel_ratio": 1.0}),
+        );
+        let result = call_tool(&bridge, "get_zoom", json!({})).await.unwrap();
+        assert_eq!(result, "Zoom: 1 (devicePixelRatio: 1)");
+        assert_eq!(bridge.requests()[0].method, Method::GET);
+    }
+
+    #[tokio::test]
+    async fn test_check_media_query() {
+        let bridge = mock(&[]).with_json(
+            "/media-query?query=%28max-width%3A%20768px%29",
+            json!({"matches": true, "query": "(max-width: 768px)"}),
+        );
+        let result = call_tool(
+            &bridge,
+            "check_media_query",
+            json!({"query": "(max-width: 768px)"}),
+        )
+        .await
+        .unwrap();
+        assert_eq!(result, "(max-width: 768px) matches");
+    }
+
+    #[tokio::test]
+    async fn test_check_media_query_breakpoints() {
+        let bridge = mock(&[]).with_json(
+            "/media-query/all",
+            json!({"matches": {
+                "(max-width: 1280px)": true,
+                "(max-width: 640px)": false,
+                "(max-width: 1024px)": true,
+                "(max-width: 768px)": false
+            }}),
+        );
+        let result = call_tool(&bridge, "check_media_query", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "(max-width: 640px) does not match\n(max-width: 768px) does not match\n\
+             (max-width: 1024px) matches\n(max-width: 1280px) matches"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_simulate_translation() {
+        let bridge = mock(&[]).with_json("/translate", json!({"replaced_count": 4}));
+        let args = json!({"replacements": {"Save": "Speichern"}, "selector": "#toolbar"});
+        let result = call_tool(&bridge, "simulate_translation", args)
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "Replaced 4 occurrence(s) in #toolbar; call again with restore: true to undo"
+        );
+        assert_eq!(
+            bridge.requests()[0].body.clone().unwrap(),
+            json!({"replacements": {"Save": "Speichern"}, "selector": "#toolbar"})
+        );
+
+        let bridge = mock(&[]).with_json("/translate/restore", json!({"restored_count": 2}));
+        let result = call_tool(&bridge, "simulate_translation", json!({"restore": true}))
+            .await
+            .unwrap();
+        assert_eq!(result, "Restored the original text of 2 node(s)");
+    }
+}
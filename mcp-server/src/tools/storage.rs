@@ -0,0 +1,225 @@
+//! Clipboard, IndexedDB and web storage tools.
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+
+use super::{get_string_arg, tool_def, tool_def_optional};
+use crate::bridge::{BridgeClientTrait, IndexedDbResponse, StorageApi, StorageEvent};
+
+pub(super) async fn call(
+    bridge: &dyn BridgeClientTrait,
+    name: &str,
+    args: &Value,
+) -> Result<Option<String>> {
+    let text = match name {
+        "get_clipboard" => Ok(bridge.clipboard_read().await?.text),
+        "set_clipboard" => {
+            let text = get_string_arg(args, "text")?;
+            let resp = bridge.clipboard_write(&text).await?;
+            Ok(format!(
+                "Clipboard set ({} chars)",
+                resp.text.chars().count()
+            ))
+        }
+        "watch_storage" => {
+            let duration_ms = args
+                .get("duration_ms")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(DEFAULT_STORAGE_WATCH_MS);
+            if duration_ms > MAX_STORAGE_WATCH_MS {
+                return Err(anyhow!(
+                    "duration_ms must be at most {}",
+                    MAX_STORAGE_WATCH_MS
+                ));
+            }
+            let events = bridge.watch_storage(duration_ms).await?;
+            Ok(format_storage_events(duration_ms, &events))
+        }
+        "read_indexeddb" => {
+            let database = get_string_arg(args, "database")?;
+            let store = get_string_arg(args, "store")?;
+            let key = args.get("key").and_then(|v| v.as_str());
+            let resp = bridge.indexeddb_read(&database, &store, key).await?;
+            format_indexeddb(&database, &store, resp)
+        }
+        _ => return Ok(None),
+    };
+    text.map(Some)
+}
+
+pub(super) fn definitions() -> Vec<Value> {
+    vec![
+        tool_def("get_clipboard", "Read the clipboard text (the app window must have focus)", json!({})),
+        tool_def("set_clipboard", "Write text to the clipboard (the app window must have focus)", json!({
+            "text": { "type": "string", "description": "Text to copy" }
+        })),
+        tool_def_optional(
+            "read_indexeddb",
+            "Read records from an IndexedDB object store, optionally a single record by key",
+            json!({
+                "database": { "type": "string", "description": "Database name" },
+                "store": { "type": "string", "description": "Object store name" },
+                "key": { "type": "string", "description": "Record key (numeric strings also match number keys); omit to read the whole store" }
+            }),
+            vec!["database", "store"]
+        ),
+        tool_def_optional(
+            "watch_storage",
+            "Record localStorage changes (from this window's setItem/removeItem/clear and storage events from other windows) for a while, then list them",
+            json!({
+                "duration_ms": { "type": "integer", "description": "How long to listen (default: 5000, max: 60000)" }
+            }),
+            vec![]
+        ),
+    ]
+}
+
+fn format_indexeddb(database: &str, store: &str, resp: IndexedDbResponse) -> Result<String> {
+    if let Some(error) = resp.error {
+        return Err(anyhow!("IndexedDB read failed: {}", error));
+    }
+    if resp.records.is_empty() {
+        return Ok(format!("No records in {}/{}", database, store));
+    }
+    Ok(format!(
+        "{} record(s) in {}/{}\n{}",
+        resp.records.len(),
+        database,
+        store,
+        serde_json::to_string_pretty(&resp.records)?
+    ))
+}
+
+/// How long `watch_storage` listens by default.
+const DEFAULT_STORAGE_WATCH_MS: u64 = 5_000;
+
+/// Longest `watch_storage` may listen, so a tool call can't hang the client.
+const MAX_STORAGE_WATCH_MS: u64 = 60_000;
+
+fn format_storage_events(duration_ms: u64, events: &[StorageEvent]) -> String {
+    if events.is_empty() {
+        return format!("No localStorage changes in {}ms", duration_ms);
+    }
+    let show = |value: &Option<String>| match value {
+        Some(value) => format!("{:?}", value),
+        None => "(none)".to_string(),
+    };
+    let mut lines = vec![format!(
+        "{} localStorage change(s) in {}ms:",
+        events.len(),
+        duration_ms
+    )];
+    for event in events {
+        let change = match &event.key {
+            Some(key) => format!(
+                "{}: {} -> {}",
+                key,
+                show(&event.old_value),
+                show(&event.new_value)
+            ),
+            None => "cleared".to_string(),
+        };
+        lines.push(format!(
+            "[{}] {} ({})",
+            event.timestamp_ms, change, event.url
+        ));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::call_tool;
+    use crate::tools::tests::mock;
+    use reqwest::Method;
+
+    #[tokio::test]
+    async fn test_get_clipboard() {
+        let bridge = mock(&[]).with_json("/clipboard", json!({"text": "copied"}));
+        let result = call_tool(&bridge, "get_clipboard", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(result, "copied");
+        assert_eq!(bridge.requests()[0].method, Method::GET);
+    }
+
+    #[tokio::test]
+    async fn test_set_clipboard() {
+        let bridge = mock(&[]).with_json("/clipboard", json!({"text": "héllo"}));
+        let result = call_tool(&bridge, "set_clipboard", json!({"text": "héllo"}))
+            .await
+            .unwrap();
+        assert_eq!(result, "Clipboard set (5 chars)");
+        let request = &bridge.requests()[0];
+        assert_eq!(request.method, Method::POST);
+        assert_eq!(request.body, Some(json!({"text": "héllo"})));
+    }
+
+    #[tokio::test]
+    async fn test_watch_storage() {
+        let event = |data: Value| json!({"event": "storage", "data": data.to_string()});
+        let bridge = mock(&[]).with_json(
+            "/storage/watch",
+            json!([
+                event(json!({"key": "theme", "old_value": null, "new_value": "dark", "url": "app://index.html", "timestamp_ms": 10})),
+                event(json!({"key": null, "old_value": null, "new_value": null, "url": "app://index.html", "timestamp_ms": 20}))
+            ]),
+        );
+        let result = call_tool(&bridge, "watch_storage", json!({"duration_ms": 100}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "2 localStorage change(s) in 100ms:\n\
+             [10] theme: (none) -> \"dark\" (app://index.html)\n\
+             [20] cleared (app://index.html)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_watch_storage_error_event() {
+        let bridge = mock(&[]).with_json(
+            "/storage/watch",
+            json!([{"event": "error", "data": "Eval timeout"}]),
+        );
+        let err = call_tool(&bridge, "watch_storage", json!({}))
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Storage watch failed: Eval timeout");
+    }
+
+    #[tokio::test]
+    async fn test_read_indexeddb() {
+        let bridge = mock(&[]).with_json(
+            "/indexeddb",
+            json!({"records": [{"id": 1, "done": false}], "error": null}),
+        );
+        let args = json!({"database": "app", "store": "todos", "key": "1"});
+        let result = call_tool(&bridge, "read_indexeddb", args).await.unwrap();
+        assert!(result.starts_with("1 record(s) in app/todos\n"));
+        assert!(result.contains("\"done\": false"));
+
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(
+            body,
+            json!({"database": "app", "store": "todos", "key": "1"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_read_indexeddb_error() {
+        let bridge = mock(&[]).with_json(
+            "/indexeddb",
+            json!({"records": [], "error": "Database not found: nope"}),
+        );
+        let args = json!({"database": "nope", "store": "todos"});
+        let err = call_tool(&bridge, "read_indexeddb", args)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "IndexedDB read failed: Database not found: nope"
+        );
+    }
+}
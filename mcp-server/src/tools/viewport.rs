@@ -0,0 +1,498 @@
+//! Window and viewport tools: screenshots, sizing, reloads and snapshots.
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+
+use super::{get_i32_arg, get_u32_arg, tool_def, tool_def_optional};
+use crate::bridge::{
+    BridgeClientTrait, ScreenshotFormat, ViewportApi, ViewportResponse, WindowStateResponse,
+};
+
+pub(super) async fn call(
+    bridge: &dyn BridgeClientTrait,
+    name: &str,
+    args: &Value,
+) -> Result<Option<String>> {
+    let text = match name {
+        "screenshot" => {
+            let path = args.get("path").and_then(|v| v.as_str());
+            let format = get_screenshot_format(args)?;
+            screenshot(bridge, path, format).await
+        }
+        "resize" => {
+            let width = get_u32_arg(args, "width")?;
+            let height = get_u32_arg(args, "height")?;
+            let anchor = args.get("anchor").and_then(|v| v.as_str());
+            resize(bridge, width, height, anchor).await
+        }
+        "move_window" => {
+            let x = get_i32_arg(args, "x")?;
+            let y = get_i32_arg(args, "y")?;
+            let monitor = args
+                .get("monitor")
+                .and_then(|v| v.as_u64())
+                .map(|v| v as u32);
+            move_window(bridge, x, y, monitor).await
+        }
+        "reload" => {
+            let soft = args.get("soft").and_then(|v| v.as_bool()).unwrap_or(false);
+            reload(bridge, soft).await
+        }
+        "hot_reload" => {
+            let resp = bridge.hot_reload().await?;
+            Ok(if resp.triggered {
+                "Hot reload triggered".to_string()
+            } else {
+                "Hot reload not triggered: no open Dioxus dev server WebSocket (is the app running under dx serve?)".to_string()
+            })
+        }
+        "get_viewport" => get_viewport(bridge).await,
+        "get_window_state" => Ok(format_window_state(&bridge.window_state().await?)),
+        "set_viewport" => {
+            let width = get_u32_arg(args, "width")?;
+            let height = get_u32_arg(args, "height")?;
+            set_viewport(bridge, width, height).await
+        }
+        "capture_snapshot" => {
+            let include_screenshot = args
+                .get("screenshot")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            let path = args.get("path").and_then(|v| v.as_str());
+            capture_snapshot(bridge, include_screenshot, path).await
+        }
+        _ => return Ok(None),
+    };
+    text.map(Some)
+}
+
+pub(super) fn definitions() -> Vec<Value> {
+    vec![
+        tool_def_optional(
+            "screenshot",
+            "Capture window screenshot",
+            json!({
+                "path": { "type": "string", "description": "Output path (optional)" },
+                "format": { "type": "string", "enum": ["png", "jpeg", "webp"], "description": "Image format (default: png)" },
+                "quality": { "type": "number", "description": "JPEG quality 1-100 (default: 85)" }
+            }),
+            vec![]
+        ),
+        tool_def_optional(
+            "resize",
+            "Resize the window",
+            json!({
+                "width": { "type": "number", "description": "Window width in pixels" },
+                "height": { "type": "number", "description": "Window height in pixels" },
+                "anchor": {
+                    "type": "string",
+                    "enum": ["top-left", "top-right", "bottom-left", "bottom-right", "center"],
+                    "description": "Point of the window that stays in place (default: app-defined, usually top-left)"
+                }
+            }),
+            vec!["width", "height"],
+        ),
+        tool_def_optional(
+            "move_window",
+            "Move the window (requires app handling), e.g. onto another monitor",
+            json!({
+                "x": { "type": "number", "description": "X position of the top-left corner in pixels" },
+                "y": { "type": "number", "description": "Y position of the top-left corner in pixels" },
+                "monitor": { "type": "number", "description": "Monitor index the position is relative to" }
+            }),
+            vec!["x", "y"],
+        ),
+        tool_def_optional(
+            "reload",
+            "Reload the page in the webview",
+            json!({
+                "soft": { "type": "boolean", "description": "Re-navigate to the current URL instead of location.reload() (default: false)" }
+            }),
+            vec![]
+        ),
+        tool_def("hot_reload", "Ask the Dioxus dev server (dx serve) to hot-reload the app over its WebSocket", json!({})),
+        tool_def("get_viewport", "Read viewport size, window size, device pixel ratio and orientation", json!({})),
+        tool_def("get_window_state", "Read the page title, URL, ready state, visibility, focus, scroll position, viewport and window geometry in one call", json!({})),
+        tool_def("set_viewport", "Resize the viewport to test responsive breakpoints", json!({
+            "width": { "type": "number", "description": "Width in pixels" },
+            "height": { "type": "number", "description": "Height in pixels" }
+        })),
+        tool_def_optional(
+            "capture_snapshot",
+            "Save the DOM, CSS variables, JS errors, network log, performance diagnosis and optionally a screenshot as one ZIP archive, e.g. to attach to a bug report",
+            json!({
+                "screenshot": { "type": "boolean", "description": "Include a screenshot of the window (default: false)" },
+                "path": { "type": "string", "description": "Where to save the archive (default: /tmp/dioxus-snapshot-{id}.zip)" }
+            }),
+            vec![],
+        ),
+    ]
+}
+
+fn get_screenshot_format(args: &Value) -> Result<Option<ScreenshotFormat>> {
+    let Some(format) = args.get("format").and_then(|v| v.as_str()) else {
+        return Ok(None);
+    };
+    match format {
+        "png" => Ok(Some(ScreenshotFormat::Png)),
+        "webp" => Ok(Some(ScreenshotFormat::WebP)),
+        "jpeg" | "jpg" => {
+            let quality = args
+                .get("quality")
+                .and_then(|v| v.as_u64())
+                .map(|q| q.clamp(1, 100) as u8)
+                .unwrap_or(85);
+            Ok(Some(ScreenshotFormat::Jpeg { quality }))
+        }
+        other => Err(anyhow!(
+            "Unknown format '{}', expected png, jpeg or webp",
+            other
+        )),
+    }
+}
+
+pub(super) async fn screenshot(
+    bridge: &dyn BridgeClientTrait,
+    path: Option<&str>,
+    format: Option<ScreenshotFormat>,
+) -> Result<String> {
+    let resp = bridge.screenshot_with_format(path, format).await?;
+    if resp.success {
+        let saved = format!("Screenshot saved: {}", resp.path.unwrap_or_default());
+        Ok(match resp.mime_type {
+            Some(mime) => format!("{} ({})", saved, mime),
+            None => saved,
+        })
+    } else {
+        Err(anyhow!(resp
+            .error
+            .unwrap_or_else(|| "Unknown error".to_string())))
+    }
+}
+
+/// Save a `/snapshot` archive, by default to `/tmp/dioxus-snapshot-{id}.zip`.
+async fn capture_snapshot(
+    bridge: &dyn BridgeClientTrait,
+    include_screenshot: bool,
+    path: Option<&str>,
+) -> Result<String> {
+    let archive = bridge.capture_snapshot(include_screenshot).await?;
+    let path = path
+        .map(String::from)
+        .unwrap_or_else(|| format!("/tmp/dioxus-snapshot-{}.zip", archive.snapshot_id));
+    std::fs::write(&path, &archive.zip)
+        .map_err(|e| anyhow!("Failed to save snapshot to {}: {}", path, e))?;
+    Ok(format!(
+        "Snapshot {} saved: {} ({} bytes)",
+        archive.snapshot_id,
+        path,
+        archive.zip.len()
+    ))
+}
+
+async fn resize(
+    bridge: &dyn BridgeClientTrait,
+    width: u32,
+    height: u32,
+    anchor: Option<&str>,
+) -> Result<String> {
+    let resp = bridge.resize(width, height, anchor).await?;
+    if resp.success {
+        let mut message = format!("Window resized to {}x{}", resp.width, resp.height);
+        if let Some(anchor) = resp.anchor {
+            message.push_str(&format!(" from {}", anchor));
+        }
+        Ok(message)
+    } else {
+        Err(anyhow!(resp
+            .error
+            .unwrap_or_else(|| "Unknown error".to_string())))
+    }
+}
+
+async fn move_window(
+    bridge: &dyn BridgeClientTrait,
+    x: i32,
+    y: i32,
+    monitor: Option<u32>,
+) -> Result<String> {
+    let resp = bridge.move_window(x, y, monitor).await?;
+    if resp.success {
+        Ok(format!("Window moved to {},{}", resp.x, resp.y))
+    } else {
+        Err(anyhow!(resp
+            .error
+            .unwrap_or_else(|| "Unknown error".to_string())))
+    }
+}
+
+async fn reload(bridge: &dyn BridgeClientTrait, soft: bool) -> Result<String> {
+    let resp = bridge.reload(soft).await?;
+    if resp.success {
+        Ok(if soft {
+            "Page reloaded (soft)".to_string()
+        } else {
+            "Page reloaded".to_string()
+        })
+    } else {
+        Err(anyhow!(resp
+            .error
+            .unwrap_or_else(|| "Unknown error".to_string())))
+    }
+}
+
+async fn get_viewport(bridge: &dyn BridgeClientTrait) -> Result<String> {
+    let resp = bridge.get_viewport().await?;
+    Ok(format_viewport(&resp))
+}
+
+async fn set_viewport(bridge: &dyn BridgeClientTrait, width: u32, height: u32) -> Result<String> {
+    let resp = bridge.set_viewport(width, height).await?;
+    Ok(format_viewport(&resp))
+}
+
+fn format_viewport(resp: &ViewportResponse) -> String {
+    format!(
+        "Viewport: {}x{} {} (window: {}x{}, devicePixelRatio: {})",
+        resp.inner_width,
+        resp.inner_height,
+        resp.orientation,
+        resp.outer_width,
+        resp.outer_height,
+        resp.device_pixel_ratio
+    )
+}
+
+fn format_window_state(resp: &WindowStateResponse) -> String {
+    [
+        format!("Title: {}", resp.title),
+        format!("URL: {}", resp.url),
+        format!(
+            "Document: {}, {}, {}",
+            resp.ready_state,
+            resp.visibility_state,
+            if resp.has_focus {
+                "focused"
+            } else {
+                "not focused"
+            }
+        ),
+        format!("Scroll: {}, {}", resp.scroll_x, resp.scroll_y),
+        format!(
+            "Viewport: {}x{} (window: {}x{} at {}, {}, devicePixelRatio: {})",
+            resp.inner_width,
+            resp.inner_height,
+            resp.outer_width,
+            resp.outer_height,
+            resp.screen_x,
+            resp.screen_y,
+            resp.device_pixel_ratio
+        ),
+    ]
+    .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::call_tool;
+    use crate::tools::tests::mock;
+    use reqwest::Method;
+
+    #[tokio::test]
+    async fn test_capture_snapshot() {
+        let bridge = mock(&[]).with_json(
+            "/snapshot",
+            json!({"snapshot_id": "5f0c", "zip_base64": "UEsFBg=="}),
+        );
+        let path =
+            std::env::temp_dir().join(format!("dioxus-snapshot-test-{}.zip", std::process::id()));
+        let args = json!({"screenshot": true, "path": path.to_str().unwrap()});
+        let result = call_tool(&bridge, "capture_snapshot", args).await.unwrap();
+        assert_eq!(
+            result,
+            format!("Snapshot 5f0c saved: {} (4 bytes)", path.display())
+        );
+        assert_eq!(std::fs::read(&path).unwrap(), b"PK\x05\x06");
+        assert_eq!(bridge.requests()[0].path, "/snapshot?screenshot=true");
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[tokio::test]
+    async fn test_screenshot() {
+        let bridge = mock(&[]).with_json(
+            "/screenshot",
+            json!({"success": true, "path": "/tmp/shot.png"}),
+        );
+        let result = call_tool(&bridge, "screenshot", json!({"path": "/tmp/shot.png"}))
+            .await
+            .unwrap();
+        assert_eq!(result, "Screenshot saved: /tmp/shot.png");
+    }
+
+    #[tokio::test]
+    async fn test_screenshot_jpeg() {
+        let bridge = mock(&[]).with_json(
+            "/screenshot",
+            json!({"success": true, "path": "/tmp/shot.jpg", "mime_type": "image/jpeg"}),
+        );
+        let args = json!({"path": "/tmp/shot.jpg", "format": "jpeg", "quality": 70});
+        let result = call_tool(&bridge, "screenshot", args).await.unwrap();
+        assert_eq!(result, "Screenshot saved: /tmp/shot.jpg (image/jpeg)");
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(body["format"], json!({"type": "jpeg", "quality": 70}));
+    }
+
+    #[test]
+    fn test_get_screenshot_format() {
+        assert!(get_screenshot_format(&json!({})).unwrap().is_none());
+        assert!(matches!(
+            get_screenshot_format(&json!({"format": "webp"})).unwrap(),
+            Some(ScreenshotFormat::WebP)
+        ));
+        assert!(matches!(
+            get_screenshot_format(&json!({"format": "jpg"})).unwrap(),
+            Some(ScreenshotFormat::Jpeg { quality: 85 })
+        ));
+        assert!(get_screenshot_format(&json!({"format": "gif"})).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_screenshot_failure() {
+        let bridge = mock(&[]).with_json(
+            "/screenshot",
+            json!({"success": false, "error": "Window not found"}),
+        );
+        let result = call_tool(&bridge, "screenshot", json!({})).await;
+        assert_eq!(result.unwrap_err().to_string(), "Window not found");
+    }
+
+    #[tokio::test]
+    async fn test_resize() {
+        let bridge = mock(&[]).with_json(
+            "/resize",
+            json!({"success": true, "width": 800, "height": 600}),
+        );
+        let result = call_tool(&bridge, "resize", json!({"width": 800, "height": 600}))
+            .await
+            .unwrap();
+        assert_eq!(result, "Window resized to 800x600");
+        assert_eq!(bridge.requests()[0].method, Method::POST);
+    }
+
+    #[tokio::test]
+    async fn test_resize_with_anchor() {
+        let bridge = mock(&[]).with_json(
+            "/resize",
+            json!({"success": true, "width": 800, "height": 600, "anchor": "center"}),
+        );
+        let args = json!({"width": 800, "height": 600, "anchor": "center"});
+        let result = call_tool(&bridge, "resize", args).await.unwrap();
+        assert_eq!(result, "Window resized to 800x600 from center");
+        assert_eq!(
+            bridge.requests()[0].body.clone().unwrap(),
+            json!({"width": 800, "height": 600, "anchor": "center"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_move_window() {
+        let bridge = mock(&[]).with_json("/move", json!({"success": true, "x": -1920, "y": 0}));
+        let args = json!({"x": -1920, "y": 0, "monitor": 1});
+        let result = call_tool(&bridge, "move_window", args).await.unwrap();
+        assert_eq!(result, "Window moved to -1920,0");
+        assert_eq!(
+            bridge.requests()[0].body.clone().unwrap(),
+            json!({"x": -1920, "y": 0, "monitor": 1})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reload() {
+        let bridge = mock(&[]).with_json("/reload", json!({"success": true}));
+        let result = call_tool(&bridge, "reload", json!({"soft": true}))
+            .await
+            .unwrap();
+        assert_eq!(result, "Page reloaded (soft)");
+        let body = bridge.requests()[0].body.clone().unwrap();
+        assert_eq!(body, json!({"soft": true}));
+    }
+
+    #[tokio::test]
+    async fn test_get_window_state() {
+        let bridge = mock(&[]).with_json(
+            "/window/state",
+            json!({
+                "title": "Home",
+                "url": "http://localhost/",
+                "ready_state": "complete",
+                "visibility_state": "visible",
+                "has_focus": false,
+                "scroll_x": 0.0,
+                "scroll_y": 120.5,
+                "inner_width": 375,
+                "inner_height": 812,
+                "outer_width": 375,
+                "outer_height": 840,
+                "screen_x": -1440,
+                "screen_y": 0,
+                "device_pixel_ratio": 3.0
+            }),
+        );
+        let result = call_tool(&bridge, "get_window_state", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "Title: Home\n\
+             URL: http://localhost/\n\
+             Document: complete, visible, not focused\n\
+             Scroll: 0, 120.5\n\
+             Viewport: 375x812 (window: 375x840 at -1440, 0, devicePixelRatio: 3)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_viewport() {
+        let bridge = mock(&[]).with_json(
+            "/viewport",
+            json!({"inner_width": 375, "inner_height": 812, "outer_width": 375,
+                   "outer_height": 840, "device_pixel_ratio": 3.0, "orientation": "portrait"}),
+        );
+        let result = call_tool(
+            &bridge,
+            "set_viewport",
+            json!({"width": 375, "height": 812}),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            result,
+            "Viewport: 375x812 portrait (window: 375x840, devicePixelRatio: 3)"
+        );
+        let request = &bridge.requests()[0];
+        assert_eq!(request.method, Method::POST);
+        assert_eq!(
+            request.body.clone().unwrap(),
+            json!({"width": 375, "height": 812})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hot_reload() {
+        let bridge = mock(&[]).with_json(
+            "/reload/hot",
+            json!({"triggered": true, "ws_connected": true}),
+        );
+        let result = call_tool(&bridge, "hot_reload", json!({})).await.unwrap();
+        assert_eq!(result, "Hot reload triggered");
+        assert_eq!(bridge.requests()[0].method, Method::POST);
+
+        let bridge = mock(&[]).with_json(
+            "/reload/hot",
+            json!({"triggered": false, "ws_connected": false}),
+        );
+        let result = call_tool(&bridge, "hot_reload", json!({})).await.unwrap();
+        assert!(result.starts_with("Hot reload not triggered"));
+    }
+}
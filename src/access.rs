@@ -0,0 +1,167 @@
+//! Optional bearer-token auth and rate limiting for bridge requests.
+
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use axum::{
+    extract::{Request, State},
+    http::{header, HeaderMap, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::BridgeState;
+
+/// Allows up to a fixed number of requests per one-second window.
+#[derive(Debug)]
+pub struct RateLimiter {
+    max_per_sec: u32,
+    /// Start of the current window and requests counted in it.
+    window: Mutex<(Instant, u32)>,
+}
+
+impl RateLimiter {
+    pub fn new(max_per_sec: u32) -> Self {
+        Self {
+            max_per_sec,
+            window: Mutex::new((Instant::now(), 0)),
+        }
+    }
+
+    /// Requests allowed per second.
+    pub fn max_per_sec(&self) -> u32 {
+        self.max_per_sec
+    }
+
+    /// Count a request, returning `false` if the window is already full.
+    pub fn try_acquire(&self) -> bool {
+        self.try_acquire_at(Instant::now())
+    }
+
+    fn try_acquire_at(&self, now: Instant) -> bool {
+        let mut window = self.window.lock().unwrap_or_else(PoisonError::into_inner);
+        if now.duration_since(window.0) >= Duration::from_secs(1) {
+            *window = (now, 0);
+        }
+        if window.1 >= self.max_per_sec {
+            return false;
+        }
+        window.1 += 1;
+        true
+    }
+}
+
+/// Middleware that rejects requests without the configured bearer token
+/// (401) and requests over the rate limit (429).
+pub(crate) async fn guard(
+    State(state): State<Arc<BridgeState>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    if let Some(token) = &state.auth_token {
+        if !has_bearer_token(req.headers(), token) {
+            return (StatusCode::UNAUTHORIZED, "Missing or invalid bearer token").into_response();
+        }
+    }
+    if let Some(limiter) = &state.rate_limiter {
+        if !limiter.try_acquire() {
+            return (StatusCode::TOO_MANY_REQUESTS, "Rate limit exceeded").into_response();
+        }
+    }
+    next.run(req).await
+}
+
+fn has_bearer_token(headers: &HeaderMap, token: &str) -> bool {
+    headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|given| constant_time_eq(given.as_bytes(), token.as_bytes()))
+}
+
+/// Compare without stopping at the first differing byte, so response timing
+/// doesn't reveal how much of a guessed token is right.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, middleware, routing::get, Router};
+    use tokio::sync::mpsc;
+    use tower::ServiceExt;
+
+    fn state_with(auth_token: Option<&str>, rate_limiter: Option<RateLimiter>) -> Arc<BridgeState> {
+        let (eval_tx, _eval_rx) = mpsc::channel(1);
+        Arc::new(BridgeState {
+            auth_token: auth_token.map(String::from),
+            rate_limiter,
+            ..BridgeState::new("test-app".to_string(), eval_tx)
+        })
+    }
+
+    fn app(state: Arc<BridgeState>) -> Router {
+        Router::new()
+            .route("/ping", get(|| async { "pong" }))
+            .layer(middleware::from_fn_with_state(state.clone(), guard))
+            .with_state(state)
+    }
+
+    #[test]
+    fn test_rate_limiter_resets_each_second() {
+        let limiter = RateLimiter::new(2);
+        let start = Instant::now();
+        assert!(limiter.try_acquire_at(start));
+        assert!(limiter.try_acquire_at(start));
+        assert!(!limiter.try_acquire_at(start + Duration::from_millis(500)));
+        assert!(limiter.try_acquire_at(start + Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"secret", b"secret"));
+        assert!(!constant_time_eq(b"secret", b"secreT"));
+        assert!(!constant_time_eq(b"secret", b"secret2"));
+        assert!(!constant_time_eq(b"", b"secret"));
+    }
+
+    #[tokio::test]
+    async fn test_guard_requires_token() {
+        let state = state_with(Some("secret"), None);
+
+        let response = app(state.clone())
+            .oneshot(Request::get("/ping").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 401);
+
+        let response = app(state)
+            .oneshot(
+                Request::get("/ping")
+                    .header("authorization", "Bearer secret")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn test_guard_rate_limits() {
+        let state = state_with(None, Some(RateLimiter::new(1)));
+
+        let first = app(state.clone())
+            .oneshot(Request::get("/ping").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(first.status(), 200);
+
+        let second = app(state)
+            .oneshot(Request::get("/ping").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(second.status(), 429);
+    }
+}
@@ -0,0 +1,446 @@
+//! Configuration for [`start_bridge_with_config`](crate::start_bridge_with_config).
+
+use std::net::{IpAddr, Ipv4Addr};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use tracing::Level;
+
+use crate::history::DEFAULT_MAX_HISTORY;
+
+/// Options for starting the inspector bridge.
+///
+/// [`start_bridge`](crate::start_bridge) uses the defaults, overridden by the
+/// environment variables below; build a config to change them in code.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let config = BridgeConfig::new(9999, "my-app").with_session_dir("/tmp/inspector-sessions");
+/// let mut eval_rx = start_bridge_with_config(config);
+/// ```
+///
+/// # Environment Variables
+///
+/// [`from_env`](Self::from_env) reads:
+///
+/// | Variable | Overrides |
+/// |----------|-----------|
+/// | `DIOXUS_INSPECTOR_PORT` | [`transport`](Self::transport), as [`Transport::Tcp`] |
+/// | `DIOXUS_INSPECTOR_SOCKET` | [`transport`](Self::transport), as [`Transport::Unix`] (Unix only; wins over the port) |
+/// | `DIOXUS_INSPECTOR_ADDR` | [`addr`](Self::addr), e.g. `0.0.0.0` |
+/// | `DIOXUS_INSPECTOR_AUTH_TOKEN` | [`auth_token`](Self::auth_token) |
+/// | `DIOXUS_INSPECTOR_MAX_HISTORY` | [`max_history`](Self::max_history) |
+/// | `DIOXUS_INSPECTOR_RATE_LIMIT` | [`rate_limit`](Self::rate_limit), in requests per second |
+/// | `DIOXUS_INSPECTOR_LOG_LEVEL` | [`log_level`](Self::log_level), e.g. `warn` |
+/// | `DIOXUS_INSPECTOR_DISABLED` | Set to `1` to turn the bridge off ([`enabled`](Self::enabled)) |
+///
+/// Values that don't parse are ignored with a warning.
+#[derive(Debug, Clone)]
+pub struct BridgeConfig {
+    /// Where to listen: a TCP port, or a Unix socket.
+    pub transport: Transport,
+    /// Address to listen on with [`Transport::Tcp`]. Defaults to
+    /// `127.0.0.1`, so only local clients can connect; on any other address,
+    /// set [`auth_token`](Self::auth_token) too, since clients can run
+    /// JavaScript in the app.
+    pub addr: IpAddr,
+    /// When set, requests must send `Authorization: Bearer {token}`.
+    pub auth_token: Option<String>,
+    /// Maximum requests per second; more get `429 Too Many Requests`.
+    pub rate_limit: Option<u32>,
+    /// When `false`, starting the bridge does nothing and the handle never
+    /// yields evals.
+    pub enabled: bool,
+    /// The application name, reported by `/status`.
+    pub app_name: String,
+    /// Directory where `/session/save` writes sessions.
+    ///
+    /// Defaults to `~/.config/dioxus-inspector/sessions/`.
+    pub session_dir: Option<PathBuf>,
+    /// Directory where `/snapshot` stores archives for `/snapshot/{id}`,
+    /// keeping the last 20.
+    ///
+    /// Defaults to `{temp_dir}/dioxus-inspector-snapshots/`.
+    pub snapshot_dir: Option<PathBuf>,
+    /// Where the bridge advertises its URL for `DIOXUS_BRIDGE_URL=http://auto`.
+    ///
+    /// Defaults to [`lock_file_path`](crate::lock_file_path).
+    pub lock_file: Option<PathBuf>,
+    /// Number of evals kept for `/history` and saved sessions.
+    ///
+    /// Defaults to [`DEFAULT_MAX_HISTORY`].
+    pub max_history: usize,
+    /// Scripts run once, in order, before the bridge's first eval, e.g. to
+    /// mock `fetch` or install monitoring. Failures are logged and skipped.
+    pub startup_scripts: Vec<String>,
+    /// What to do with evals when the app falls behind.
+    ///
+    /// Defaults to [`BackpressureStrategy::Block`].
+    pub backpressure: BackpressureStrategy,
+    /// Most verbose level the bridge logs at; its events above this level
+    /// are dropped before they reach the app's subscriber.
+    ///
+    /// Defaults to [`Level::TRACE`], leaving all filtering to the subscriber.
+    pub log_level: Level,
+    /// Log every script sent to the app at TRACE level, for debugging the
+    /// bridge itself.
+    pub log_eval_scripts: bool,
+    /// Serve HTTPS with this certificate instead of plain HTTP.
+    #[cfg(feature = "tls")]
+    pub tls: Option<TlsConfig>,
+}
+
+/// How clients connect to the bridge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Transport {
+    /// Listen on `{addr}:{port}`; port `0` lets the OS pick one.
+    Tcp { port: u16 },
+    /// Listen on a Unix domain socket at `path`.
+    ///
+    /// A stale socket left at `path` is replaced, and the new one is only
+    /// accessible to the current user. The socket file is removed when the
+    /// bridge stops.
+    #[cfg(unix)]
+    Unix { path: PathBuf },
+}
+
+/// How the bridge handles evals once the app's eval channel is full.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BackpressureStrategy {
+    /// Wait for the app to make room.
+    #[default]
+    Block,
+    /// Answer immediately with a "rate limited" error.
+    Drop,
+    /// Hold up to `max` extra evals in an overflow buffer, then drop.
+    Queue { max: usize },
+}
+
+/// PEM certificate and private key for serving the bridge over HTTPS.
+#[cfg(feature = "tls")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TlsConfig {
+    /// Path to the PEM certificate chain.
+    pub cert_pem: PathBuf,
+    /// Path to the PEM private key.
+    pub key_pem: PathBuf,
+}
+
+impl BridgeConfig {
+    /// Create a config listening on TCP `port`, with default options.
+    pub fn new(port: u16, app_name: impl Into<String>) -> Self {
+        Self {
+            transport: Transport::Tcp { port },
+            addr: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            auth_token: None,
+            rate_limit: None,
+            enabled: true,
+            app_name: app_name.into(),
+            session_dir: None,
+            snapshot_dir: None,
+            lock_file: None,
+            max_history: DEFAULT_MAX_HISTORY,
+            startup_scripts: Vec::new(),
+            backpressure: BackpressureStrategy::Block,
+            log_level: Level::TRACE,
+            log_eval_scripts: false,
+            #[cfg(feature = "tls")]
+            tls: None,
+        }
+    }
+
+    /// Create a config with default options, overridden by the
+    /// `DIOXUS_INSPECTOR_*` environment variables.
+    pub fn from_env(port: u16, app_name: impl Into<String>) -> Self {
+        Self::new(port, app_name).with_env(|name| std::env::var(name).ok())
+    }
+
+    fn with_env(mut self, var: impl Fn(&str) -> Option<String>) -> Self {
+        if let Some(port) = parse_var(&var, "DIOXUS_INSPECTOR_PORT") {
+            self.transport = Transport::Tcp { port };
+        }
+        #[cfg(unix)]
+        if let Some(path) = var("DIOXUS_INSPECTOR_SOCKET").filter(|p| !p.is_empty()) {
+            self.transport = Transport::Unix { path: path.into() };
+        }
+        if let Some(addr) = parse_var(&var, "DIOXUS_INSPECTOR_ADDR") {
+            self.addr = addr;
+        }
+        if let Some(token) = var("DIOXUS_INSPECTOR_AUTH_TOKEN").filter(|t| !t.is_empty()) {
+            self.auth_token = Some(token);
+        }
+        if let Some(max_history) = parse_var(&var, "DIOXUS_INSPECTOR_MAX_HISTORY") {
+            self.max_history = max_history;
+        }
+        if let Some(rate_limit) = parse_var(&var, "DIOXUS_INSPECTOR_RATE_LIMIT") {
+            self.rate_limit = Some(rate_limit);
+        }
+        if let Some(log_level) = parse_var(&var, "DIOXUS_INSPECTOR_LOG_LEVEL") {
+            self.log_level = log_level;
+        }
+        if var("DIOXUS_INSPECTOR_DISABLED").is_some_and(|v| v == "1") {
+            self.enabled = false;
+        }
+        self
+    }
+
+    /// Listen on a Unix socket at `path` instead of a TCP port.
+    #[cfg(unix)]
+    pub fn with_unix_socket(mut self, path: impl Into<PathBuf>) -> Self {
+        self.transport = Transport::Unix { path: path.into() };
+        self
+    }
+
+    /// Listen on `addr` instead of `127.0.0.1`.
+    pub fn with_addr(mut self, addr: IpAddr) -> Self {
+        self.addr = addr;
+        self
+    }
+
+    /// Require `Authorization: Bearer {token}` on every request.
+    pub fn with_auth_token(mut self, token: impl Into<String>) -> Self {
+        self.auth_token = Some(token.into());
+        self
+    }
+
+    /// Allow at most `per_sec` requests per second.
+    pub fn with_rate_limit(mut self, per_sec: u32) -> Self {
+        self.rate_limit = Some(per_sec);
+        self
+    }
+
+    /// Store sessions in `dir` instead of the default directory.
+    pub fn with_session_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.session_dir = Some(dir.into());
+        self
+    }
+
+    /// Store snapshot archives in `dir` instead of the temp directory.
+    pub fn with_snapshot_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.snapshot_dir = Some(dir.into());
+        self
+    }
+
+    /// Advertise the bridge in `path` instead of the shared lock file, e.g.
+    /// so tests don't replace a running app's.
+    pub fn with_lock_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.lock_file = Some(path.into());
+        self
+    }
+
+    /// Keep the last `max_history` evals instead of the default 100.
+    pub fn with_max_history(mut self, max_history: usize) -> Self {
+        self.max_history = max_history;
+        self
+    }
+
+    /// Run `script` before the first eval, after any added earlier.
+    pub fn with_startup_script(mut self, script: impl Into<String>) -> Self {
+        self.startup_scripts.push(script.into());
+        self
+    }
+
+    /// Handle a full eval channel with `strategy` instead of blocking.
+    pub fn with_backpressure(mut self, strategy: BackpressureStrategy) -> Self {
+        self.backpressure = strategy;
+        self
+    }
+
+    /// Drop the bridge's log events more verbose than `level`.
+    pub fn with_log_level(mut self, level: Level) -> Self {
+        self.log_level = level;
+        self
+    }
+
+    /// Log each script sent to the app at TRACE level.
+    pub fn with_eval_script_logging(mut self, enabled: bool) -> Self {
+        self.log_eval_scripts = enabled;
+        self
+    }
+
+    /// Serve HTTPS using the PEM certificate and key at these paths.
+    #[cfg(feature = "tls")]
+    pub fn with_tls(mut self, cert_pem: impl Into<PathBuf>, key_pem: impl Into<PathBuf>) -> Self {
+        self.tls = Some(TlsConfig {
+            cert_pem: cert_pem.into(),
+            key_pem: key_pem.into(),
+        });
+        self
+    }
+
+    /// Whether clients on other machines could reach the bridge without a
+    /// token, and so run JavaScript in the app.
+    pub(crate) fn is_exposed_without_auth(&self) -> bool {
+        matches!(self.transport, Transport::Tcp { .. })
+            && !self.addr.is_loopback()
+            && self.auth_token.is_none()
+    }
+}
+
+fn parse_var<T: FromStr>(var: impl Fn(&str) -> Option<String>, name: &str) -> Option<T> {
+    let value = var(name)?;
+    match value.parse() {
+        Ok(parsed) => Some(parsed),
+        Err(_) => {
+            tracing::warn!("Ignoring {}={:?}: invalid value", name, value);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bridge_config_defaults() {
+        let config = BridgeConfig::new(9999, "my-app");
+        assert_eq!(config.transport, Transport::Tcp { port: 9999 });
+        assert_eq!(config.app_name, "my-app");
+        assert!(config.session_dir.is_none());
+        assert!(config.snapshot_dir.is_none());
+        assert_eq!(config.max_history, DEFAULT_MAX_HISTORY);
+        assert_eq!(config.backpressure, BackpressureStrategy::Block);
+        assert!(config.startup_scripts.is_empty());
+        assert_eq!(config.log_level, Level::TRACE);
+        assert!(!config.log_eval_scripts);
+        assert_eq!(config.addr, IpAddr::V4(Ipv4Addr::LOCALHOST));
+        assert!(config.auth_token.is_none());
+        assert!(config.rate_limit.is_none());
+        assert!(config.enabled);
+    }
+
+    #[test]
+    fn test_bridge_config_with_session_dir() {
+        let config = BridgeConfig::new(9999, "my-app").with_session_dir("/tmp/sessions");
+        assert_eq!(config.session_dir, Some(PathBuf::from("/tmp/sessions")));
+    }
+
+    #[test]
+    fn test_bridge_config_with_snapshot_dir() {
+        let config = BridgeConfig::new(9999, "my-app").with_snapshot_dir("/tmp/snapshots");
+        assert_eq!(config.snapshot_dir, Some(PathBuf::from("/tmp/snapshots")));
+    }
+
+    #[test]
+    fn test_bridge_config_with_startup_scripts() {
+        let config = BridgeConfig::new(9999, "my-app")
+            .with_startup_script("window.a = 1")
+            .with_startup_script("window.b = 2");
+        assert_eq!(config.startup_scripts, vec!["window.a = 1", "window.b = 2"]);
+    }
+
+    #[test]
+    fn test_bridge_config_with_max_history() {
+        let config = BridgeConfig::new(9999, "my-app").with_max_history(10);
+        assert_eq!(config.max_history, 10);
+    }
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: std::collections::HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_bridge_config_with_env() {
+        let config = BridgeConfig::new(9999, "my-app").with_env(env(&[
+            ("DIOXUS_INSPECTOR_PORT", "4000"),
+            ("DIOXUS_INSPECTOR_ADDR", "0.0.0.0"),
+            ("DIOXUS_INSPECTOR_AUTH_TOKEN", "secret"),
+            ("DIOXUS_INSPECTOR_MAX_HISTORY", "20"),
+            ("DIOXUS_INSPECTOR_RATE_LIMIT", "50"),
+            ("DIOXUS_INSPECTOR_LOG_LEVEL", "warn"),
+        ]));
+        assert_eq!(config.transport, Transport::Tcp { port: 4000 });
+        assert_eq!(config.addr, IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+        assert_eq!(config.auth_token.as_deref(), Some("secret"));
+        assert_eq!(config.max_history, 20);
+        assert_eq!(config.rate_limit, Some(50));
+        assert_eq!(config.log_level, Level::WARN);
+        assert!(config.enabled);
+    }
+
+    #[test]
+    fn test_bridge_config_with_env_ignores_invalid_values() {
+        let config = BridgeConfig::new(9999, "my-app").with_env(env(&[
+            ("DIOXUS_INSPECTOR_PORT", "not-a-port"),
+            ("DIOXUS_INSPECTOR_ADDR", "localhost:80"),
+        ]));
+        assert_eq!(config.transport, Transport::Tcp { port: 9999 });
+        assert_eq!(config.addr, IpAddr::V4(Ipv4Addr::LOCALHOST));
+    }
+
+    #[test]
+    fn test_bridge_config_is_exposed_without_auth() {
+        let config = BridgeConfig::new(9999, "my-app");
+        assert!(!config.is_exposed_without_auth());
+
+        let config = config.with_addr(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+        assert!(config.is_exposed_without_auth());
+
+        let config = config.with_auth_token("secret");
+        assert!(!config.is_exposed_without_auth());
+    }
+
+    #[test]
+    fn test_bridge_config_with_env_disabled() {
+        let config =
+            BridgeConfig::new(9999, "my-app").with_env(env(&[("DIOXUS_INSPECTOR_DISABLED", "1")]));
+        assert!(!config.enabled);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_bridge_config_with_unix_socket() {
+        let config = BridgeConfig::new(9999, "my-app").with_unix_socket("/tmp/inspector.sock");
+        assert_eq!(
+            config.transport,
+            Transport::Unix {
+                path: PathBuf::from("/tmp/inspector.sock")
+            }
+        );
+
+        let config = BridgeConfig::new(9999, "my-app").with_env(env(&[
+            ("DIOXUS_INSPECTOR_PORT", "4000"),
+            ("DIOXUS_INSPECTOR_SOCKET", "/tmp/env.sock"),
+        ]));
+        assert_eq!(
+            config.transport,
+            Transport::Unix {
+                path: PathBuf::from("/tmp/env.sock")
+            }
+        );
+    }
+
+    #[test]
+    fn test_bridge_config_with_lock_file() {
+        let config = BridgeConfig::new(9999, "my-app");
+        assert_eq!(config.lock_file, None);
+        let config = config.with_lock_file("/tmp/test.lock");
+        assert_eq!(config.lock_file, Some(PathBuf::from("/tmp/test.lock")));
+    }
+
+    #[test]
+    fn test_bridge_config_with_backpressure() {
+        let config = BridgeConfig::new(9999, "my-app")
+            .with_backpressure(BackpressureStrategy::Queue { max: 8 });
+        assert_eq!(config.backpressure, BackpressureStrategy::Queue { max: 8 });
+    }
+
+    #[cfg(feature = "tls")]
+    #[test]
+    fn test_bridge_config_with_tls() {
+        let config = BridgeConfig::new(9999, "my-app").with_tls("cert.pem", "key.pem");
+        assert_eq!(
+            config.tls,
+            Some(TlsConfig {
+                cert_pem: PathBuf::from("cert.pem"),
+                key_pem: PathBuf::from("key.pem"),
+            })
+        );
+    }
+}
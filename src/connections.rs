@@ -0,0 +1,212 @@
+//! Connection and request counters for the bridge's HTTP server.
+//!
+//! Accepted sockets are wrapped in [`Counted`], which holds a
+//! [`ConnectionGuard`] for as long as the connection is open, so
+//! `active_connections` drops back as soon as a client disconnects.
+
+use std::io;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::Response,
+};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+use crate::BridgeState;
+
+/// Counts one open connection until dropped.
+#[derive(Debug)]
+pub(crate) struct ConnectionGuard {
+    active: Arc<AtomicU32>,
+}
+
+impl ConnectionGuard {
+    pub(crate) fn new(active: Arc<AtomicU32>) -> Self {
+        active.fetch_add(1, Ordering::Relaxed);
+        Self { active }
+    }
+}
+
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        self.active.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// A connection that counts as active while it's alive.
+pub(crate) struct Counted<S> {
+    inner: S,
+    _guard: ConnectionGuard,
+}
+
+impl<S> Counted<S> {
+    pub(crate) fn new(inner: S, active: Arc<AtomicU32>) -> Self {
+        Self {
+            inner,
+            _guard: ConnectionGuard::new(active),
+        }
+    }
+}
+
+impl<S: AsyncRead + Unpin> AsyncRead for Counted<S> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_read(cx, buf)
+    }
+}
+
+impl<S: AsyncWrite + Unpin> AsyncWrite for Counted<S> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
+
+/// Listener for `axum::serve` that counts open connections, over TCP or a
+/// Unix socket.
+pub(crate) struct CountingListener<L> {
+    inner: L,
+    active: Arc<AtomicU32>,
+}
+
+impl<L> CountingListener<L> {
+    pub(crate) fn new(inner: L, active: Arc<AtomicU32>) -> Self {
+        Self { inner, active }
+    }
+}
+
+impl<L: axum::serve::Listener> axum::serve::Listener for CountingListener<L> {
+    type Io = Counted<L::Io>;
+    type Addr = L::Addr;
+
+    async fn accept(&mut self) -> (Self::Io, Self::Addr) {
+        let (stream, addr) = self.inner.accept().await;
+        (Counted::new(stream, self.active.clone()), addr)
+    }
+
+    fn local_addr(&self) -> io::Result<Self::Addr> {
+        self.inner.local_addr()
+    }
+}
+
+/// Acceptor for `axum_server` that counts open connections, run before TLS.
+#[cfg(feature = "tls")]
+#[derive(Clone)]
+pub(crate) struct CountingAcceptor {
+    active: Arc<AtomicU32>,
+}
+
+#[cfg(feature = "tls")]
+impl CountingAcceptor {
+    pub(crate) fn new(active: Arc<AtomicU32>) -> Self {
+        Self { active }
+    }
+}
+
+#[cfg(feature = "tls")]
+impl<I, S> axum_server::accept::Accept<I, S> for CountingAcceptor
+where
+    I: AsyncRead + AsyncWrite + Unpin,
+{
+    type Stream = Counted<I>;
+    type Service = S;
+    type Future = std::future::Ready<io::Result<(Self::Stream, Self::Service)>>;
+
+    fn accept(&self, stream: I, service: S) -> Self::Future {
+        std::future::ready(Ok((Counted::new(stream, self.active.clone()), service)))
+    }
+}
+
+/// Middleware counting every request, including ones rejected by the guard.
+pub(crate) async fn count_requests(
+    State(state): State<Arc<BridgeState>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    state.total_requests.fetch_add(1, Ordering::Relaxed);
+    next.run(req).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, middleware, routing::get, Router};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::sync::mpsc;
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_connection_guard_counts_while_alive() {
+        let active = Arc::new(AtomicU32::new(0));
+        let first = ConnectionGuard::new(active.clone());
+        let second = ConnectionGuard::new(active.clone());
+        assert_eq!(active.load(Ordering::Relaxed), 2);
+        assert_eq!(Arc::strong_count(&active), 3);
+
+        drop(first);
+        assert_eq!(active.load(Ordering::Relaxed), 1);
+        drop(second);
+        assert_eq!(active.load(Ordering::Relaxed), 0);
+        assert_eq!(Arc::strong_count(&active), 1);
+    }
+
+    #[tokio::test]
+    async fn test_counted_stream_forwards_io() {
+        let active = Arc::new(AtomicU32::new(0));
+        let (client, server) = tokio::io::duplex(64);
+        let mut counted = Counted::new(server, active.clone());
+        let mut client = client;
+
+        client.write_all(b"ping").await.unwrap();
+        let mut buf = [0u8; 4];
+        counted.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"ping");
+        counted.write_all(b"pong").await.unwrap();
+        client.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"pong");
+
+        assert_eq!(active.load(Ordering::Relaxed), 1);
+        drop(counted);
+        assert_eq!(active.load(Ordering::Relaxed), 0);
+    }
+
+    #[tokio::test]
+    async fn test_count_requests() {
+        let (eval_tx, _eval_rx) = mpsc::channel(1);
+        let state = Arc::new(BridgeState::new("test-app".to_string(), eval_tx));
+        let app = Router::new()
+            .route("/ping", get(|| async { "pong" }))
+            .layer(middleware::from_fn_with_state(
+                state.clone(),
+                count_requests,
+            ))
+            .with_state(state.clone());
+
+        for _ in 0..3 {
+            app.clone()
+                .oneshot(Request::get("/ping").body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+        }
+        assert_eq!(state.total_requests.load(Ordering::Relaxed), 3);
+    }
+}
@@ -0,0 +1,448 @@
+//! WCAG color contrast calculations.
+//!
+//! Parses CSS color strings (hex, `rgb()`, `hsl()`, named colors) and computes
+//! relative luminance and contrast ratios as defined by WCAG 2.x.
+
+/// Minimum ratio for normal text at level AA.
+pub const AA_NORMAL: f64 = 4.5;
+/// Minimum ratio for large text (18pt, or 14pt bold) at level AA.
+pub const AA_LARGE: f64 = 3.0;
+/// Minimum ratio for normal text at level AAA.
+pub const AAA_NORMAL: f64 = 7.0;
+/// Minimum ratio for large text at level AAA.
+pub const AAA_LARGE: f64 = 4.5;
+
+/// An sRGB color with alpha.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rgba {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    /// Opacity in `0.0..=1.0`.
+    pub a: f64,
+}
+
+impl Rgba {
+    const WHITE: Rgba = Rgba::rgb(255, 255, 255);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b, a: 1.0 }
+    }
+
+    /// Composite this color over an opaque background.
+    fn over(self, bg: Rgba) -> Rgba {
+        let mix = |top: u8, bottom: u8| {
+            (f64::from(top) * self.a + f64::from(bottom) * (1.0 - self.a)).round() as u8
+        };
+        Rgba::rgb(mix(self.r, bg.r), mix(self.g, bg.g), mix(self.b, bg.b))
+    }
+}
+
+/// Parse a CSS color string.
+///
+/// Supports `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`, `rgb()`/`rgba()`,
+/// `hsl()`/`hsla()` (comma or space separated), `transparent`, and the
+/// CSS named colors. Returns `None` for anything else.
+pub fn parse_color(input: &str) -> Option<Rgba> {
+    let color = input.trim().to_ascii_lowercase();
+
+    if let Some(hex) = color.strip_prefix('#') {
+        return parse_hex(hex);
+    }
+    if let Some(args) = function_args(&color, &["rgb", "rgba"]) {
+        return parse_rgb_args(&args);
+    }
+    if let Some(args) = function_args(&color, &["hsl", "hsla"]) {
+        return parse_hsl_args(&args);
+    }
+    if color == "transparent" {
+        return Some(Rgba {
+            r: 0,
+            g: 0,
+            b: 0,
+            a: 0.0,
+        });
+    }
+
+    NAMED_COLORS
+        .iter()
+        .find(|(name, _)| *name == color)
+        .map(|&(_, hex)| Rgba::rgb((hex >> 16) as u8, (hex >> 8) as u8, hex as u8))
+}
+
+/// Relative luminance of an opaque color, per WCAG 2.x.
+pub fn relative_luminance(color: Rgba) -> f64 {
+    let linear = |channel: u8| {
+        let c = f64::from(channel) / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * linear(color.r) + 0.7152 * linear(color.g) + 0.0722 * linear(color.b)
+}
+
+/// Contrast ratio between a foreground and background color, from 1.0 to 21.0.
+///
+/// Translucent backgrounds are composited over white, and translucent
+/// foregrounds over the resulting background.
+pub fn contrast_ratio(foreground: Rgba, background: Rgba) -> f64 {
+    let bg = if background.a < 1.0 {
+        background.over(Rgba::WHITE)
+    } else {
+        background
+    };
+    let fg = if foreground.a < 1.0 {
+        foreground.over(bg)
+    } else {
+        foreground
+    };
+
+    let l1 = relative_luminance(fg);
+    let l2 = relative_luminance(bg);
+    let (lighter, darker) = if l1 > l2 { (l1, l2) } else { (l2, l1) };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+fn parse_hex(hex: &str) -> Option<Rgba> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let short = |i: usize| u8::from_str_radix(&hex[i..=i].repeat(2), 16).ok();
+    let long = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    let alpha = |a: u8| f64::from(a) / 255.0;
+
+    match hex.len() {
+        3 => Some(Rgba::rgb(short(0)?, short(1)?, short(2)?)),
+        4 => Some(Rgba {
+            a: alpha(short(3)?),
+            ..Rgba::rgb(short(0)?, short(1)?, short(2)?)
+        }),
+        6 => Some(Rgba::rgb(long(0)?, long(2)?, long(4)?)),
+        8 => Some(Rgba {
+            a: alpha(long(6)?),
+            ..Rgba::rgb(long(0)?, long(2)?, long(4)?)
+        }),
+        _ => None,
+    }
+}
+
+/// Split `name(a, b, c)` / `name(a b c / d)` into its arguments.
+fn function_args(color: &str, names: &[&str]) -> Option<Vec<String>> {
+    let open = color.find('(')?;
+    if !names.contains(&color[..open].trim()) {
+        return None;
+    }
+    let inner = color[open + 1..].strip_suffix(')')?;
+    let args: Vec<String> = inner
+        .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .map(String::from)
+        .collect();
+    (args.len() == 3 || args.len() == 4).then_some(args)
+}
+
+fn parse_rgb_args(args: &[String]) -> Option<Rgba> {
+    let channel = |arg: &str| -> Option<u8> {
+        let value = match arg.strip_suffix('%') {
+            Some(pct) => pct.parse::<f64>().ok()? * 2.55,
+            None => arg.parse::<f64>().ok()?,
+        };
+        Some(value.clamp(0.0, 255.0).round() as u8)
+    };
+    Some(Rgba {
+        a: parse_alpha(args.get(3).map(String::as_str))?,
+        ..Rgba::rgb(channel(&args[0])?, channel(&args[1])?, channel(&args[2])?)
+    })
+}
+
+fn parse_hsl_args(args: &[String]) -> Option<Rgba> {
+    let hue = args[0].trim_end_matches("deg").parse::<f64>().ok()?;
+    let percent = |arg: &str| -> Option<f64> {
+        let value = arg.trim_end_matches('%').parse::<f64>().ok()?;
+        Some((value / 100.0).clamp(0.0, 1.0))
+    };
+    let (r, g, b) = hsl_to_rgb(hue, percent(&args[1])?, percent(&args[2])?);
+    Some(Rgba {
+        a: parse_alpha(args.get(3).map(String::as_str))?,
+        ..Rgba::rgb(r, g, b)
+    })
+}
+
+fn parse_alpha(arg: Option<&str>) -> Option<f64> {
+    let Some(arg) = arg else {
+        return Some(1.0);
+    };
+    let value = match arg.strip_suffix('%') {
+        Some(pct) => pct.parse::<f64>().ok()? / 100.0,
+        None => arg.parse::<f64>().ok()?,
+    };
+    Some(value.clamp(0.0, 1.0))
+}
+
+fn hsl_to_rgb(hue: f64, saturation: f64, lightness: f64) -> (u8, u8, u8) {
+    let to_u8 = |v: f64| (v * 255.0).round() as u8;
+    if saturation <= 0.0 {
+        let gray = to_u8(lightness);
+        return (gray, gray, gray);
+    }
+
+    let h = hue.rem_euclid(360.0) / 360.0;
+    let q = if lightness < 0.5 {
+        lightness * (1.0 + saturation)
+    } else {
+        lightness + saturation - lightness * saturation
+    };
+    let p = 2.0 * lightness - q;
+    let channel = |t: f64| {
+        let t = t.rem_euclid(1.0);
+        if t < 1.0 / 6.0 {
+            p + (q - p) * 6.0 * t
+        } else if t < 0.5 {
+            q
+        } else if t < 2.0 / 3.0 {
+            p + (q - p) * (2.0 / 3.0 - t) * 6.0
+        } else {
+            p
+        }
+    };
+
+    (
+        to_u8(channel(h + 1.0 / 3.0)),
+        to_u8(channel(h)),
+        to_u8(channel(h - 1.0 / 3.0)),
+    )
+}
+
+/// CSS named colors (CSS Color Module Level 4).
+const NAMED_COLORS: &[(&str, u32)] = &[
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ratio(fg: &str, bg: &str) -> f64 {
+        contrast_ratio(parse_color(fg).unwrap(), parse_color(bg).unwrap())
+    }
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!(parse_color("#fff"), Some(Rgba::rgb(255, 255, 255)));
+        assert_eq!(parse_color("#1e293b"), Some(Rgba::rgb(30, 41, 59)));
+        assert_eq!(parse_color("#FF000080").map(|c| c.r), Some(255));
+        assert!(parse_color("#12345").is_none());
+        assert!(parse_color("#ggg").is_none());
+    }
+
+    #[test]
+    fn test_parse_rgb() {
+        assert_eq!(parse_color("rgb(255, 0, 0)"), Some(Rgba::rgb(255, 0, 0)));
+        assert_eq!(parse_color("rgb(0 128 0)"), Some(Rgba::rgb(0, 128, 0)));
+        assert_eq!(parse_color("rgba(0, 0, 0, 0.5)").map(|c| c.a), Some(0.5));
+        assert_eq!(parse_color("rgb(0 0 0 / 25%)").map(|c| c.a), Some(0.25));
+        assert_eq!(parse_color("rgb(100%, 0%, 0%)"), Some(Rgba::rgb(255, 0, 0)));
+    }
+
+    #[test]
+    fn test_parse_hsl() {
+        assert_eq!(parse_color("hsl(0, 100%, 50%)"), Some(Rgba::rgb(255, 0, 0)));
+        assert_eq!(
+            parse_color("hsl(120deg 100% 25%)"),
+            Some(Rgba::rgb(0, 128, 0))
+        );
+        assert_eq!(
+            parse_color("hsl(0, 0%, 100%)"),
+            Some(Rgba::rgb(255, 255, 255))
+        );
+    }
+
+    #[test]
+    fn test_parse_named() {
+        assert_eq!(parse_color("White"), Some(Rgba::rgb(255, 255, 255)));
+        assert_eq!(parse_color("rebeccapurple"), Some(Rgba::rgb(102, 51, 153)));
+        assert_eq!(parse_color("transparent").map(|c| c.a), Some(0.0));
+        assert!(parse_color("notacolor").is_none());
+    }
+
+    #[test]
+    fn test_relative_luminance_extremes() {
+        assert!(relative_luminance(Rgba::rgb(0, 0, 0)).abs() < 1e-9);
+        assert!((relative_luminance(Rgba::rgb(255, 255, 255)) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_contrast_ratio_black_on_white() {
+        assert!((ratio("#000", "#fff") - 21.0).abs() < 1e-9);
+        assert!((ratio("#fff", "#000") - 21.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_contrast_ratio_same_color() {
+        assert!((ratio("#777", "#777") - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_contrast_ratio_known_value() {
+        // #767676 on white is the classic "just passes AA" gray (4.54:1)
+        let r = ratio("#767676", "#ffffff");
+        assert!(r >= AA_NORMAL && r < 4.6);
+    }
+
+    #[test]
+    fn test_contrast_ratio_translucent_foreground() {
+        // 50% black over white composites to mid gray
+        let r = ratio("rgba(0, 0, 0, 0.5)", "#fff");
+        let expected = ratio("#808080", "#fff");
+        assert!((r - expected).abs() < 0.05);
+    }
+}
@@ -0,0 +1,310 @@
+//! Deduplication of identical read-only evals sent in quick succession.
+//!
+//! MCP clients often call the same tool twice in a row, e.g. two `get_dom`
+//! calls. The bridge answers a repeated read-only script from
+//! [`BridgeState::recent_results`](crate::BridgeState::recent_results) for
+//! [`DEDUP_TTL`] instead of running it again, and any other script clears the
+//! cache, since it may have changed what the reads saw.
+//!
+//! Whether a script is read-only is a heuristic. Scripts that only look
+//! read-only by accident are merely not cached, so the checks lean towards
+//! treating scripts as mutating.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::time::Duration;
+
+/// How long a read-only script's result is reused.
+pub const DEDUP_TTL: Duration = Duration::from_millis(500);
+
+/// Objects whose properties belong to the page rather than to the script.
+const GLOBALS: &[&str] = &[
+    "window",
+    "document",
+    "globalThis",
+    "self",
+    "localStorage",
+    "sessionStorage",
+    "location",
+    "history",
+    "navigator",
+];
+
+/// Properties whose assignment changes an element or the page.
+const DOM_PROPERTIES: &[&str] = &[
+    "value",
+    "checked",
+    "selected",
+    "disabled",
+    "hidden",
+    "textContent",
+    "innerText",
+    "innerHTML",
+    "outerHTML",
+    "style",
+    "src",
+    "href",
+    "title",
+    "scrollTop",
+    "scrollLeft",
+    "currentTime",
+    "volume",
+    "muted",
+    "playbackRate",
+    "zoom",
+    // Truncates an array, e.g. emptying a tracker's log after reading it
+    "length",
+];
+
+/// Methods that change the page, matched as `.name(`.
+const MUTATING_METHODS: &[&str] = &[
+    "click",
+    "dispatchEvent",
+    "focus",
+    "blur",
+    "submit",
+    "requestSubmit",
+    "reset",
+    "select",
+    "setSelectionRange",
+    "setAttribute",
+    "removeAttribute",
+    "toggleAttribute",
+    "appendChild",
+    "removeChild",
+    "insertBefore",
+    "replaceChild",
+    "replaceWith",
+    "append",
+    "prepend",
+    "remove",
+    "insertAdjacentHTML",
+    "insertAdjacentElement",
+    "insertAdjacentText",
+    "setProperty",
+    "removeProperty",
+    "toggle",
+    "setItem",
+    "removeItem",
+    "clear",
+    "scrollIntoView",
+    "scrollTo",
+    "scrollBy",
+    "resizeTo",
+    "resizeBy",
+    "moveTo",
+    "moveBy",
+    "play",
+    "pause",
+    "pushState",
+    "replaceState",
+    "reload",
+    "assign",
+    "defineProperty",
+    "showModal",
+    "close",
+    "execCommand",
+    "writeText",
+    "send",
+    "splice",
+    "mark",
+    "measure",
+    "disconnect",
+];
+
+/// Prefix of the resize and move commands the app intercepts, which act on
+/// the window even though they look like plain reads.
+const COMMAND_PREFIX: &str = "__DIOXUS_INSPECTOR_";
+
+/// Key for [`BridgeState::recent_results`](crate::BridgeState::recent_results).
+pub fn script_hash(script: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    script.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Whether `script` only reads the page, so running it twice in a row gives
+/// the same result.
+///
+/// That means it starts with `return`, isn't a window command such as
+/// `__DIOXUS_INSPECTOR_RESIZE__`, calls none of the methods in
+/// [`MUTATING_METHODS`], and assigns no property of a page global or element
+/// (`window.x = 1`, `el.value = "a"`, `el.style.color = "red"`). Assigning
+/// to variables and the script's own objects (`node.id = el.id`) is fine.
+pub fn is_read_only(script: &str) -> bool {
+    if !script.trim_start().starts_with("return") || script.contains(COMMAND_PREFIX) {
+        return false;
+    }
+    if MUTATING_METHODS
+        .iter()
+        .any(|method| script.contains(&format!(".{}(", method)))
+    {
+        return false;
+    }
+    !assignment_targets(script)
+        .iter()
+        .any(|target| is_page_property(target))
+}
+
+/// Left-hand sides of the assignments and increments in `script`, as dotted
+/// chains with computed properties reduced to `[]`, e.g. `el.style.color`.
+fn assignment_targets(script: &str) -> Vec<String> {
+    let bytes = script.as_bytes();
+    let mut targets = Vec::new();
+    for i in 0..bytes.len() {
+        match bytes[i] {
+            b'=' => {
+                let next = bytes.get(i + 1).copied();
+                let prev = i.checked_sub(1).map(|p| bytes[p]);
+                // `==`, `===`, `!=`, `<=`, `>=` and `=>` don't assign
+                if matches!(next, Some(b'=' | b'>'))
+                    || matches!(prev, Some(b'=' | b'!' | b'<' | b'>'))
+                {
+                    continue;
+                }
+                // Step over compound operators such as `+=` and `??=`
+                let mut end = i;
+                while end > 0 && b"+-*/%&|^?".contains(&bytes[end - 1]) {
+                    end -= 1;
+                }
+                targets.push(chain_before(bytes, end));
+            }
+            b'+' | b'-' if bytes.get(i + 1) == Some(&bytes[i]) => {
+                targets.push(chain_before(bytes, i));
+                targets.push(chain_after(bytes, i + 2));
+            }
+            _ => {}
+        }
+    }
+    targets.retain(|t| !t.is_empty());
+    targets
+}
+
+fn is_chain_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'_' | b'$' | b'.')
+}
+
+/// The property chain ending just before `end`, skipping whitespace.
+fn chain_before(bytes: &[u8], mut end: usize) -> String {
+    while end > 0 && bytes[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+    let mut parts = Vec::new();
+    let mut pos = end;
+    while pos > 0 {
+        let b = bytes[pos - 1];
+        if b == b']' {
+            // Skip back over the computed property to its `[`
+            let mut depth = 0;
+            while pos > 0 {
+                pos -= 1;
+                match bytes[pos] {
+                    b']' => depth += 1,
+                    b'[' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            parts.push(".[]".to_string());
+        } else if is_chain_byte(b) {
+            let start = (0..pos)
+                .rev()
+                .find(|&p| !is_chain_byte(bytes[p]))
+                .map_or(0, |p| p + 1);
+            parts.push(String::from_utf8_lossy(&bytes[start..pos]).into_owned());
+            pos = start;
+        } else {
+            break;
+        }
+    }
+    parts.reverse();
+    parts.concat()
+}
+
+/// The property chain starting at `start`, e.g. the target of `++window.x`.
+fn chain_after(bytes: &[u8], start: usize) -> String {
+    let end = (start..bytes.len())
+        .find(|&p| !is_chain_byte(bytes[p]))
+        .unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[start..end]).into_owned()
+}
+
+fn is_page_property(target: &str) -> bool {
+    let segments: Vec<&str> = target.split('.').filter(|s| !s.is_empty()).collect();
+    match segments.split_first() {
+        Some((root, properties)) if !properties.is_empty() => {
+            GLOBALS.contains(root) || properties.iter().any(|p| DOM_PROPERTIES.contains(p))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_only_scripts() {
+        assert!(is_read_only("return document.title;"));
+        assert!(is_read_only(include_str!("scripts/dom.js")));
+        assert!(is_read_only(
+            "return (() => { let n = 0; for (const el of document.querySelectorAll('a')) { n++; } return n; })()"
+        ));
+        assert!(is_read_only(
+            "return (() => { const node = {}; node.id = el.id; return node.id === 'a' && x >= 1; })()"
+        ));
+    }
+
+    #[test]
+    fn test_mutating_scripts() {
+        assert!(!is_read_only("document.title = 'x'"));
+        assert!(!is_read_only(
+            "return document.querySelector('button').click();"
+        ));
+        assert!(!is_read_only(
+            "return (() => { window.__flag__ = true; })()"
+        ));
+        assert!(!is_read_only("return (() => { el.value = 'a'; })()"));
+        assert!(!is_read_only(
+            "return (() => { el.style.color = 'red'; })()"
+        ));
+        assert!(!is_read_only(
+            "return (() => { localStorage['k'] = 'v'; })()"
+        ));
+        assert!(!is_read_only("return (() => { window.counter += 1; })()"));
+        assert!(!is_read_only("return ++window.counter;"));
+        assert!(!is_read_only(
+            "return '__DIOXUS_INSPECTOR_RESIZE__800x600__'"
+        ));
+        assert!(!is_read_only(include_str!("scripts/track_errors.js")));
+        assert!(!is_read_only(
+            "return (() => { window.__dioxus_ws.send('{}'); })()"
+        ));
+        assert!(!is_read_only("return performance.mark('a').startTime;"));
+        assert!(!is_read_only("return (() => { observer.disconnect(); })()"));
+        assert!(!is_read_only(
+            "return (() => { const log = window.__log__; const copy = log.slice(); log.length = 0; return copy; })()"
+        ));
+        assert!(!is_read_only(
+            "return (() => { window.__log__.splice(0, 1); })()"
+        ));
+    }
+
+    #[test]
+    fn test_assignment_targets() {
+        assert_eq!(
+            assignment_targets("a.b[c].d = 1; x ??= 2; if (y === z) {} f(() => 1);"),
+            vec!["a.b.[].d", "x"]
+        );
+    }
+
+    #[test]
+    fn test_script_hash_is_stable() {
+        assert_eq!(script_hash("return 1"), script_hash("return 1"));
+        assert_ne!(script_hash("return 1"), script_hash("return 2"));
+    }
+}
@@ -0,0 +1,105 @@
+//! Lock file advertising the most recently started bridge.
+//!
+//! Each bridge writes its URL to [`lock_file_path`] once it has bound its
+//! port, and removes the file again when its [`BridgeHandle`](crate::BridgeHandle)
+//! is dropped. The MCP server reads it when `DIOXUS_BRIDGE_URL=http://auto`,
+//! which is how it finds bridges started on port 0.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// File name of the lock file inside the system temp directory.
+pub const LOCK_FILE_NAME: &str = "dioxus-inspector.lock";
+
+/// Where bridges advertise themselves: `{temp_dir}/dioxus-inspector.lock`.
+pub fn lock_file_path() -> PathBuf {
+    std::env::temp_dir().join(LOCK_FILE_NAME)
+}
+
+/// Contents of the lock file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BridgeLock {
+    /// Base URL of the bridge, e.g. `http://127.0.0.1:49152`.
+    pub url: String,
+    /// App name reported by `/status`.
+    pub app_name: String,
+    /// Process ID of the app.
+    pub pid: u32,
+}
+
+/// Removes the lock file on drop, unless a newer bridge has replaced it.
+pub(crate) struct LockGuard {
+    path: PathBuf,
+    url: String,
+}
+
+impl LockGuard {
+    pub(crate) fn write(path: &Path, lock: &BridgeLock) -> std::io::Result<Self> {
+        let json = serde_json::to_string(lock)?;
+        std::fs::write(path, json)?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            url: lock.url.clone(),
+        })
+    }
+}
+
+impl Drop for LockGuard {
+    fn drop(&mut self) {
+        let current = std::fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|json| serde_json::from_str::<BridgeLock>(&json).ok());
+        if current.is_some_and(|lock| lock.url == self.url) {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lock(port: u16) -> BridgeLock {
+        BridgeLock {
+            url: format!("http://127.0.0.1:{}", port),
+            app_name: "test-app".to_string(),
+            pid: 1,
+        }
+    }
+
+    fn temp_lock_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "dioxus-inspector-{}-{}.lock",
+            name,
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn test_lock_guard_removes_file_on_drop() {
+        let path = temp_lock_path("drop");
+        let guard = LockGuard::write(&path, &lock(4000)).unwrap();
+
+        let json = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            serde_json::from_str::<BridgeLock>(&json).unwrap(),
+            lock(4000)
+        );
+
+        drop(guard);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_lock_guard_keeps_newer_lock() {
+        let path = temp_lock_path("newer");
+        let older = LockGuard::write(&path, &lock(4000)).unwrap();
+        let newer = LockGuard::write(&path, &lock(4001)).unwrap();
+
+        drop(older);
+        assert!(path.exists());
+        drop(newer);
+        assert!(!path.exists());
+    }
+}
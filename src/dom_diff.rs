@@ -0,0 +1,200 @@
+//! Structural diffs between DOM snapshots.
+//!
+//! Snapshots are the trees produced by the `/dom` script. Children are lined
+//! up by their tag, id and class (or as text nodes) using a longest common
+//! subsequence, so inserting one element reports a single addition instead
+//! of a change to every sibling after it.
+
+use std::time::Instant;
+
+use serde_json::Value;
+
+use crate::types::{DomChange, DomChangeKind};
+
+/// Maximum snapshots kept by the bridge; older ones are evicted first.
+pub const MAX_DOM_SNAPSHOTS: usize = 32;
+
+/// Node fields compared between snapshots.
+const FIELDS: &[&str] = &["tag", "id", "class", "text"];
+
+/// A DOM tree captured by `/dom/snapshot`.
+#[derive(Debug, Clone)]
+pub struct DomSnapshot {
+    /// Root node, as serialized by the DOM script.
+    pub root: Value,
+    /// When the snapshot was taken, for evicting the oldest.
+    pub taken_at: Instant,
+}
+
+/// Every difference between `before` and `after`, in document order.
+pub fn diff(before: &Value, after: &Value) -> Vec<DomChange> {
+    let mut changes = Vec::new();
+    diff_node(before, after, &label(after), &mut changes);
+    changes
+}
+
+/// Number of nodes in a serialized tree.
+pub fn count_nodes(node: &Value) -> usize {
+    1 + children(node).iter().map(count_nodes).sum::<usize>()
+}
+
+fn diff_node(before: &Value, after: &Value, path: &str, changes: &mut Vec<DomChange>) {
+    for field in FIELDS {
+        let (old, new) = (before.get(field), after.get(field));
+        if old != new {
+            changes.push(DomChange {
+                path: path.to_string(),
+                kind: DomChangeKind::Changed,
+                field: Some(field.to_string()),
+                before: old.cloned(),
+                after: new.cloned(),
+            });
+        }
+    }
+    diff_children(children(before), children(after), path, changes);
+}
+
+fn diff_children(before: &[Value], after: &[Value], path: &str, changes: &mut Vec<DomChange>) {
+    let before_keys: Vec<String> = before.iter().map(key).collect();
+    let after_keys: Vec<String> = after.iter().map(key).collect();
+
+    // lcs[i][j] = length of the common subsequence of before[i..] and after[j..]
+    let mut lcs = vec![vec![0usize; after.len() + 1]; before.len() + 1];
+    for i in (0..before.len()).rev() {
+        for j in (0..after.len()).rev() {
+            lcs[i][j] = if before_keys[i] == after_keys[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < before.len() || j < after.len() {
+        if i < before.len() && j < after.len() && before_keys[i] == after_keys[j] {
+            diff_node(
+                &before[i],
+                &after[j],
+                &child_path(path, &after[j], j),
+                changes,
+            );
+            i += 1;
+            j += 1;
+        } else if j < after.len() && (i == before.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            changes.push(DomChange {
+                path: child_path(path, &after[j], j),
+                kind: DomChangeKind::Added,
+                field: None,
+                before: None,
+                after: Some(after[j].clone()),
+            });
+            j += 1;
+        } else {
+            changes.push(DomChange {
+                path: child_path(path, &before[i], i),
+                kind: DomChangeKind::Removed,
+                field: None,
+                before: Some(before[i].clone()),
+                after: None,
+            });
+            i += 1;
+        }
+    }
+}
+
+fn children(node: &Value) -> &[Value] {
+    node.get("children")
+        .and_then(Value::as_array)
+        .map_or(&[], Vec::as_slice)
+}
+
+/// Identity used to line up children: elements by tag, id and class, text
+/// nodes all alike so a text edit shows up as a change.
+fn key(node: &Value) -> String {
+    match node.get("tag").and_then(Value::as_str) {
+        Some(tag) => format!(
+            "{}#{}.{}",
+            tag,
+            node.get("id").and_then(Value::as_str).unwrap_or(""),
+            node.get("class").and_then(Value::as_str).unwrap_or("")
+        ),
+        None => "#text".to_string(),
+    }
+}
+
+fn label(node: &Value) -> String {
+    match node.get("tag").and_then(Value::as_str) {
+        Some(tag) => match node.get("id").and_then(Value::as_str) {
+            Some(id) => format!("{}#{}", tag, id),
+            None => tag.to_string(),
+        },
+        None => "#text".to_string(),
+    }
+}
+
+fn child_path(parent: &str, child: &Value, index: usize) -> String {
+    format!("{} > {}[{}]", parent, label(child), index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn kinds(changes: &[DomChange]) -> Vec<(DomChangeKind, &str)> {
+        changes.iter().map(|c| (c.kind, c.path.as_str())).collect()
+    }
+
+    #[test]
+    fn test_diff_identical() {
+        let tree = json!({"tag": "body", "children": [{"tag": "p", "children": [{"text": "Hi"}]}]});
+        assert!(diff(&tree, &tree).is_empty());
+    }
+
+    #[test]
+    fn test_diff_inserted_child() {
+        let before =
+            json!({"tag": "ul", "children": [{"tag": "li", "id": "a"}, {"tag": "li", "id": "c"}]});
+        let after = json!({"tag": "ul", "children": [
+            {"tag": "li", "id": "a"}, {"tag": "li", "id": "b"}, {"tag": "li", "id": "c"}
+        ]});
+        let changes = diff(&before, &after);
+        assert_eq!(kinds(&changes), [(DomChangeKind::Added, "ul > li#b[1]")]);
+        assert_eq!(changes[0].after, Some(json!({"tag": "li", "id": "b"})));
+    }
+
+    #[test]
+    fn test_diff_removed_child() {
+        let before = json!({"tag": "div", "children": [{"tag": "span"}, {"tag": "button"}]});
+        let after = json!({"tag": "div", "children": [{"tag": "button"}]});
+        assert_eq!(
+            kinds(&diff(&before, &after)),
+            [(DomChangeKind::Removed, "div > span[0]")]
+        );
+    }
+
+    #[test]
+    fn test_diff_changed_text_and_class() {
+        let before =
+            json!({"tag": "div", "id": "app", "class": "open", "children": [{"text": "1 item"}]});
+        let after = json!({"tag": "div", "id": "app", "class": "closed", "children": [{"text": "2 items"}]});
+        let changes = diff(&before, &after);
+        assert_eq!(
+            kinds(&changes),
+            [
+                (DomChangeKind::Changed, "div#app"),
+                (DomChangeKind::Changed, "div#app > #text[0]")
+            ]
+        );
+        assert_eq!(changes[0].field.as_deref(), Some("class"));
+        assert_eq!(changes[1].before, Some(json!("1 item")));
+        assert_eq!(changes[1].after, Some(json!("2 items")));
+    }
+
+    #[test]
+    fn test_count_nodes() {
+        let tree = json!({"tag": "body", "children": [{"tag": "p", "children": [{"text": "Hi"}]}, {"tag": "br"}]});
+        assert_eq!(count_nodes(&tree), 4);
+    }
+}
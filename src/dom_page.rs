@@ -0,0 +1,158 @@
+//! Flattening DOM trees into pages for `GET /dom`.
+//!
+//! The tree produced by the `/dom` script is listed in document order, each
+//! node pointing at its parent by index, and cut into pages. Cursors are the
+//! base64-encoded index of a page's first node; each page evaluates the DOM
+//! afresh, so pages can overlap or skip nodes if the page changes in between.
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use serde_json::Value;
+
+use crate::types::{DomNode, DomPageResponse};
+
+/// Page size when `page_size` is omitted.
+pub const DEFAULT_DOM_PAGE_SIZE: u32 = 200;
+/// Largest `page_size` allowed.
+pub const MAX_DOM_PAGE_SIZE: u32 = 5_000;
+/// `max_nodes` used for paged requests that don't set one.
+pub const DEFAULT_PAGED_MAX_NODES: u32 = 50_000;
+
+/// `root` and its descendants in document order, shadow roots before the
+/// light DOM as the `/dom` script lists them.
+pub fn flatten(root: &Value) -> Vec<DomNode> {
+    let mut nodes = Vec::new();
+    push_node(root, None, 0, false, &mut nodes);
+    nodes
+}
+
+fn push_node(
+    node: &Value,
+    parent: Option<u32>,
+    depth: u32,
+    in_shadow_root: bool,
+    nodes: &mut Vec<DomNode>,
+) {
+    let index = nodes.len() as u32;
+    let field = |name: &str| node.get(name).and_then(Value::as_str).map(String::from);
+    nodes.push(DomNode {
+        index,
+        parent,
+        depth,
+        tag: field("tag"),
+        id: field("id"),
+        class: field("class"),
+        text: field("text"),
+        in_shadow_root,
+        truncated: field("truncated"),
+    });
+
+    for (key, shadow) in [("shadow_root", true), ("children", false)] {
+        for child in node
+            .get(key)
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            push_node(child, Some(index), depth + 1, shadow, nodes);
+        }
+    }
+}
+
+/// The page of `nodes` starting at `offset`.
+pub fn page(mut nodes: Vec<DomNode>, offset: u32, page_size: u32) -> DomPageResponse {
+    let total_nodes = nodes.len() as u32;
+    let start = offset.min(total_nodes) as usize;
+    let end = offset.saturating_add(page_size).min(total_nodes);
+    let has_more = end < total_nodes;
+    nodes.truncate(end as usize);
+    DomPageResponse {
+        nodes: nodes.split_off(start),
+        next_cursor: has_more.then(|| encode_cursor(end)),
+        total_nodes,
+        has_more,
+    }
+}
+
+pub fn encode_cursor(offset: u32) -> String {
+    URL_SAFE_NO_PAD.encode(offset.to_string())
+}
+
+pub fn decode_cursor(cursor: &str) -> Result<u32, String> {
+    URL_SAFE_NO_PAD
+        .decode(cursor)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .and_then(|offset| offset.parse().ok())
+        .ok_or_else(|| format!("Invalid cursor '{}'", cursor))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn tree() -> Value {
+        json!({
+            "tag": "body",
+            "children": [
+                {"tag": "my-widget", "shadow_root": [{"tag": "slot"}], "children": [{"text": "Hi"}]},
+                {"tag": "p", "id": "intro", "class": "lead"},
+                {"tag": "...", "truncated": "max_nodes", "remaining": 3}
+            ]
+        })
+    }
+
+    #[test]
+    fn test_flatten() {
+        let nodes = flatten(&tree());
+        let summary: Vec<_> = nodes
+            .iter()
+            .map(|n| (n.parent, n.depth, n.tag.as_deref(), n.in_shadow_root))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (None, 0, Some("body"), false),
+                (Some(0), 1, Some("my-widget"), false),
+                (Some(1), 2, Some("slot"), true),
+                (Some(1), 2, None, false),
+                (Some(0), 1, Some("p"), false),
+                (Some(0), 1, Some("..."), false),
+            ]
+        );
+        assert_eq!(nodes[3].text.as_deref(), Some("Hi"));
+        assert_eq!(nodes[4].id.as_deref(), Some("intro"));
+        assert_eq!(nodes[4].class.as_deref(), Some("lead"));
+        assert_eq!(nodes[5].truncated.as_deref(), Some("max_nodes"));
+    }
+
+    #[test]
+    fn test_page_walks_all_nodes() {
+        let first = page(flatten(&tree()), 0, 4);
+        assert_eq!(first.total_nodes, 6);
+        assert!(first.has_more);
+        assert_eq!(first.nodes.len(), 4);
+
+        let offset = decode_cursor(first.next_cursor.as_deref().unwrap()).unwrap();
+        assert_eq!(offset, 4);
+        let second = page(flatten(&tree()), offset, 4);
+        assert!(!second.has_more);
+        assert_eq!(second.next_cursor, None);
+        let indexes: Vec<_> = second.nodes.iter().map(|n| n.index).collect();
+        assert_eq!(indexes, vec![4, 5]);
+    }
+
+    #[test]
+    fn test_page_past_end_is_empty() {
+        let resp = page(flatten(&tree()), 10, 4);
+        assert!(resp.nodes.is_empty());
+        assert!(!resp.has_more);
+    }
+
+    #[test]
+    fn test_decode_cursor() {
+        assert_eq!(decode_cursor(&encode_cursor(1200)), Ok(1200));
+        assert!(decode_cursor("not a cursor").is_err());
+        assert!(decode_cursor(&URL_SAFE_NO_PAD.encode("-1")).is_err());
+    }
+}
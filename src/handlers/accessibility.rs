@@ -0,0 +1,551 @@
+//! Accessibility check handlers.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::Json,
+};
+use serde_json::Value;
+
+use super::eval_json;
+use crate::contrast::{self, AAA_LARGE, AAA_NORMAL, AA_LARGE, AA_NORMAL};
+use crate::template::ScriptTemplate;
+use crate::types::{
+    AriaRequest, AriaResponse, ColorContrastResult, ContrastAuditRequest, ContrastAuditResponse,
+    ContrastFailure, ContrastSample, ContrastWarning, RoleQuery, RoleQueryResponse,
+    TabOrderResponse, TextSize, ValidateColorsRequest, ValidateColorsResponse, WcagLevel,
+};
+use crate::BridgeState;
+
+/// POST /validate-colors - Check WCAG contrast ratios for color pairs.
+///
+/// Computed in Rust, so it works without a running webview. Returns
+/// `400 Bad Request` naming the first color that cannot be parsed.
+pub async fn validate_colors(
+    Json(req): Json<ValidateColorsRequest>,
+) -> Result<Json<ValidateColorsResponse>, (StatusCode, String)> {
+    let results = req
+        .pairs
+        .into_iter()
+        .map(|pair| check_contrast(pair.foreground, pair.background))
+        .collect::<Result<Vec<_>, String>>()
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+    Ok(Json(ValidateColorsResponse { results }))
+}
+
+fn check_contrast(foreground: String, background: String) -> Result<ColorContrastResult, String> {
+    let parse = |color: &str| {
+        contrast::parse_color(color).ok_or_else(|| format!("Invalid CSS color: '{}'", color))
+    };
+    let ratio = contrast::contrast_ratio(parse(&foreground)?, parse(&background)?);
+
+    Ok(ColorContrastResult {
+        foreground,
+        background,
+        ratio: (ratio * 100.0).round() / 100.0,
+        aa_normal: ratio >= AA_NORMAL,
+        aa_large: ratio >= AA_LARGE,
+        aaa_normal: ratio >= AAA_NORMAL,
+    })
+}
+
+/// Large text is at least 18pt, or 14pt when bold.
+const LARGE_TEXT_PX: f64 = 24.0;
+const LARGE_BOLD_TEXT_PX: f64 = 18.66;
+const BOLD_WEIGHT: u32 = 700;
+
+/// GET /contrast-audit - Check the contrast of all visible text against its
+/// background.
+///
+/// The page only reports each text element's computed colors and font; the
+/// ratios are worked out here with the same formula as `/validate-colors`.
+/// Text over a background image, and colors that can't be parsed such as
+/// `color(display-p3 ...)`, are left out.
+pub async fn contrast_audit(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<ContrastAuditResponse>, (StatusCode, String)> {
+    let script =
+        ScriptTemplate::new(include_str!("../scripts/contrast_audit.js")).render(HashMap::new());
+    let request: ContrastAuditRequest = eval_json(&state, script).await?;
+    Ok(Json(audit_contrast(request.pairs)))
+}
+
+fn audit_contrast(samples: Vec<ContrastSample>) -> ContrastAuditResponse {
+    let mut failures = Vec::new();
+    let mut warnings = Vec::new();
+    let mut pass_count = 0;
+    for sample in samples {
+        let (Some(fg), Some(bg)) = (
+            contrast::parse_color(&sample.foreground),
+            contrast::parse_color(&sample.background),
+        ) else {
+            continue;
+        };
+        let ratio = contrast::contrast_ratio(fg, bg);
+        let text_size = text_size(sample.font_size_px, sample.font_weight);
+        let (aa, aaa) = match text_size {
+            TextSize::Normal => (AA_NORMAL, AAA_NORMAL),
+            TextSize::Large => (AA_LARGE, AAA_LARGE),
+        };
+        let rounded = (ratio * 100.0).round() / 100.0;
+        if ratio < aa {
+            failures.push(ContrastFailure {
+                selector_path: sample.selector_path,
+                text: sample.text,
+                foreground: sample.foreground,
+                background: sample.background,
+                ratio: rounded,
+                required_ratio: aa,
+                level: WcagLevel::Aa,
+                text_size,
+            });
+        } else if ratio < aaa {
+            warnings.push(ContrastWarning {
+                selector_path: sample.selector_path,
+                text: sample.text,
+                foreground: sample.foreground,
+                background: sample.background,
+                ratio: rounded,
+                required_ratio: aaa,
+                level: WcagLevel::Aaa,
+                text_size,
+            });
+        } else {
+            pass_count += 1;
+        }
+    }
+
+    failures.sort_by(|a, b| a.ratio.total_cmp(&b.ratio));
+    warnings.sort_by(|a, b| a.ratio.total_cmp(&b.ratio));
+    ContrastAuditResponse {
+        failures,
+        warnings,
+        pass_count,
+    }
+}
+
+fn text_size(font_size_px: f64, font_weight: u32) -> TextSize {
+    if font_size_px >= LARGE_TEXT_PX
+        || (font_size_px >= LARGE_BOLD_TEXT_PX && font_weight >= BOLD_WEIGHT)
+    {
+        TextSize::Large
+    } else {
+        TextSize::Normal
+    }
+}
+
+/// GET /tab-order - List focusable elements in keyboard tab order.
+///
+/// Elements with a positive `tabindex` come first, in ascending order, then
+/// the rest in document order. Disabled, `inert` and unrendered elements are
+/// skipped, as are those with a negative `tabindex`.
+pub async fn tab_order(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<TabOrderResponse>, (StatusCode, String)> {
+    let script =
+        ScriptTemplate::new(include_str!("../scripts/tab_order.js")).render(HashMap::new());
+    let response = eval_json(&state, script).await?;
+    Ok(Json(response))
+}
+
+/// POST /aria - Read the `role` and `aria-*` attributes of an element, after
+/// setting any given in `attributes`.
+///
+/// Names without an `aria-` prefix get one, except `role`. Responds with 400
+/// for names that aren't valid attribute names; a missing element is
+/// reported in `error`.
+pub async fn aria(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<AriaRequest>,
+) -> Result<Json<AriaResponse>, (StatusCode, String)> {
+    let attributes = req
+        .attributes
+        .map(|attributes| {
+            attributes
+                .into_iter()
+                .map(|(name, value)| Ok((normalize_aria_name(&name)?, value)))
+                .collect::<Result<HashMap<_, _>, String>>()
+        })
+        .transpose()
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+    let script =
+        ScriptTemplate::new(include_str!("../scripts/aria.js")).render_json(HashMap::from([
+            ("selector", Value::from(req.selector.as_str())),
+            ("attributes", serde_json::json!(attributes)),
+        ]));
+    let response = eval_json(&state, script).await?;
+    Ok(Json(response))
+}
+
+/// GET /role - List elements with an ARIA role, optionally filtered by
+/// accessible name.
+///
+/// Matches `[role=...]` as well as elements with the role implicitly, such
+/// as `<button>` for `button` or `<h2>` for `heading`, unless their `role`
+/// attribute says otherwise. `name` matches any part of the accessible name,
+/// ignoring case.
+pub async fn query_by_role(
+    State(state): State<Arc<BridgeState>>,
+    Query(query): Query<RoleQuery>,
+) -> Result<Json<RoleQueryResponse>, (StatusCode, String)> {
+    let role = normalize_role(&query.role).map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+    let script =
+        ScriptTemplate::new(include_str!("../scripts/role_query.js")).render_json(HashMap::from([
+            ("role", Value::from(role)),
+            ("name", serde_json::json!(query.name)),
+        ]));
+    let response = eval_json(&state, script).await?;
+    Ok(Json(response))
+}
+
+/// Roles are single lowercase words, e.g. `button` or `menuitemcheckbox`.
+fn normalize_role(role: &str) -> Result<String, String> {
+    let role = role.trim().to_ascii_lowercase();
+    if role.is_empty() || !role.chars().all(|c| c.is_ascii_lowercase()) {
+        return Err(format!("Invalid ARIA role: '{}'", role));
+    }
+    Ok(role)
+}
+
+/// `label` -> `aria-label`; `aria-label` and `role` stay as they are.
+fn normalize_aria_name(name: &str) -> Result<String, String> {
+    let name = name.trim().to_ascii_lowercase();
+    let suffix = name.strip_prefix("aria-").unwrap_or(&name);
+    if suffix.is_empty() || !suffix.chars().all(|c| c.is_ascii_lowercase() || c == '-') {
+        return Err(format!("Invalid ARIA attribute name: '{}'", name));
+    }
+    if name == "role" || name.starts_with("aria-") {
+        Ok(name)
+    } else {
+        Ok(format!("aria-{}", name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{
+        body::Body,
+        http::Request,
+        routing::{get, post},
+        Router,
+    };
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_check_contrast_passes() {
+        let result = check_contrast("#000".to_string(), "#fff".to_string()).unwrap();
+        assert!((result.ratio - 21.0).abs() < f64::EPSILON);
+        assert!(result.aa_normal && result.aa_large && result.aaa_normal);
+    }
+
+    #[test]
+    fn test_check_contrast_large_text_only() {
+        // #949494 on white is ~3.03:1
+        let result = check_contrast("#949494".to_string(), "white".to_string()).unwrap();
+        assert!(!result.aa_normal);
+        assert!(result.aa_large);
+        assert!(!result.aaa_normal);
+    }
+
+    #[test]
+    fn test_check_contrast_invalid_color() {
+        let err = check_contrast("not-a-color".to_string(), "#fff".to_string()).unwrap_err();
+        assert!(err.contains("not-a-color"));
+    }
+
+    #[test]
+    fn test_normalize_aria_name() {
+        assert_eq!(normalize_aria_name("label").unwrap(), "aria-label");
+        assert_eq!(
+            normalize_aria_name("aria-expanded").unwrap(),
+            "aria-expanded"
+        );
+        assert_eq!(normalize_aria_name(" ARIA-Hidden ").unwrap(), "aria-hidden");
+        assert_eq!(normalize_aria_name("role").unwrap(), "role");
+        assert!(normalize_aria_name("aria-").is_err());
+        assert!(normalize_aria_name("on\"click").is_err());
+    }
+
+    #[test]
+    fn test_normalize_role() {
+        assert_eq!(normalize_role(" Button ").unwrap(), "button");
+        assert!(normalize_role("").is_err());
+        assert!(normalize_role("button\"]").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_query_by_role_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/role", get(query_by_role))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains(r#"const role = "button";"#));
+                assert!(cmd.script.contains(r#"const name = "Submit";"#));
+                let payload = r#"{"elements": [{"selector_path": "form#signup > button", "accessible_name": "Submit form", "accessible_description": "", "states": {"disabled": "true"}}]}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::get("/role?role=button&name=Submit")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["elements"][0]["accessible_name"], "Submit form");
+        assert_eq!(json["elements"][0]["states"]["disabled"], "true");
+    }
+
+    #[tokio::test]
+    async fn test_query_by_role_without_name() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/role", get(query_by_role))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("const name = null;"));
+                let encoded = serde_json::to_string(r#"{"elements": []}"#).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::get("/role?role=dialog")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn test_aria_handler_sets_attributes() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new().route("/aria", post(aria)).with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd
+                    .script
+                    .contains(r##"const attributes = {"aria-expanded":"true"};"##));
+                let payload = r#"{"current_attributes": {"aria-expanded": "true", "role": "menu"}, "applied": 1, "error": null}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/aria")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r##"{"selector": "#menu", "attributes": {"expanded": "true"}}"##,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["applied"], 1);
+        assert_eq!(json["current_attributes"]["role"], "menu");
+    }
+
+    #[tokio::test]
+    async fn test_aria_handler_rejects_invalid_name() {
+        let (state, _rx) = create_test_state();
+        let app = Router::new().route("/aria", post(aria)).with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::post("/aria")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r##"{"selector": "#menu", "attributes": {"x y": "1"}}"##,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 400);
+    }
+
+    fn sample(foreground: &str, background: &str, font_size_px: f64) -> ContrastSample {
+        ContrastSample {
+            selector_path: "p".to_string(),
+            text: "Hello".to_string(),
+            foreground: foreground.to_string(),
+            background: background.to_string(),
+            font_size_px,
+            font_weight: 400,
+        }
+    }
+
+    #[test]
+    fn test_text_size() {
+        assert_eq!(text_size(16.0, 700), TextSize::Normal);
+        assert_eq!(text_size(18.66, 700), TextSize::Large);
+        assert_eq!(text_size(18.66, 400), TextSize::Normal);
+        assert_eq!(text_size(24.0, 400), TextSize::Large);
+    }
+
+    #[test]
+    fn test_audit_contrast() {
+        let response = audit_contrast(vec![
+            // 4.54:1 passes AA but not AAA
+            sample("#767676", "#fff", 16.0),
+            // 2.96:1 fails AA for normal text
+            sample("rgb(150, 150, 150)", "rgb(255, 255, 255)", 16.0),
+            // 3.84:1 passes AA for large text
+            sample("rgb(130, 130, 130)", "rgb(255, 255, 255)", 32.0),
+            sample("#000", "#fff", 16.0),
+            sample("color(display-p3 1 0 0)", "#fff", 16.0),
+        ]);
+
+        assert_eq!(response.pass_count, 1);
+        assert_eq!(response.failures.len(), 1);
+        assert_eq!(response.failures[0].ratio, 2.96);
+        assert_eq!(response.failures[0].required_ratio, AA_NORMAL);
+        assert_eq!(response.failures[0].level, WcagLevel::Aa);
+
+        assert_eq!(response.warnings.len(), 2);
+        assert_eq!(response.warnings[0].ratio, 3.84);
+        assert_eq!(response.warnings[0].text_size, TextSize::Large);
+        assert_eq!(response.warnings[0].required_ratio, AAA_LARGE);
+        assert_eq!(response.warnings[1].ratio, 4.54);
+        assert_eq!(response.warnings[1].level, WcagLevel::Aaa);
+    }
+
+    #[tokio::test]
+    async fn test_contrast_audit_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/contrast-audit", get(contrast_audit))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                let payload = serde_json::json!({"pairs": [{
+                    "selector_path": "p#faint", "text": "Fine print",
+                    "foreground": "rgb(200, 200, 200)", "background": "rgb(255, 255, 255)",
+                    "font_size_px": 12.0, "font_weight": 400
+                }]});
+                let encoded = serde_json::to_string(&payload.to_string()).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(Request::get("/contrast-audit").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["failures"][0]["selector_path"], "p#faint");
+        assert_eq!(json["failures"][0]["level"], "AA");
+        assert_eq!(json["failures"][0]["text_size"], "normal");
+        assert_eq!(json["pass_count"], 0);
+    }
+
+    #[tokio::test]
+    async fn test_validate_colors_handler() {
+        let app = Router::new().route("/validate-colors", post(validate_colors));
+
+        let response = app
+            .oneshot(
+                Request::post("/validate-colors")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r##"{"pairs": [{"foreground": "#767676", "background": "#fff"}]}"##,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["results"][0]["ratio"], 4.54);
+        assert_eq!(json["results"][0]["aa_normal"], true);
+        assert_eq!(json["results"][0]["aaa_normal"], false);
+    }
+
+    #[tokio::test]
+    async fn test_validate_colors_handler_invalid_color() {
+        let app = Router::new().route("/validate-colors", post(validate_colors));
+
+        let response = app
+            .oneshot(
+                Request::post("/validate-colors")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r##"{"pairs": [{"foreground": "bogus", "background": "#fff"}]}"##,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn test_tab_order_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/tab-order", get(tab_order))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("tabIndex"));
+                let payload = r#"{"elements": [
+                    {"index": 0, "selector_path": "form#login > input", "tag": "input", "text": "Email", "tabindex": 1},
+                    {"index": 1, "selector_path": "form#login > button", "tag": "button", "text": "Sign in", "tabindex": 0}
+                ]}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(Request::get("/tab-order").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["elements"][0]["tabindex"], 1);
+        assert_eq!(json["elements"][1]["selector_path"], "form#login > button");
+    }
+}
@@ -0,0 +1,133 @@
+//! Handler for finding the common ancestor of elements.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+use serde::Deserialize;
+
+use super::eval_json;
+use crate::template::ScriptTemplate;
+use crate::types::{AncestorRequest, AncestorResponse};
+use crate::BridgeState;
+
+/// Result of the common ancestor script; `ancestor` is absent when a
+/// selector matched nothing.
+#[derive(Deserialize)]
+struct AncestorResult {
+    found: bool,
+    #[serde(default)]
+    unmatched: Vec<String>,
+    #[serde(flatten)]
+    ancestor: Option<AncestorResponse>,
+}
+
+/// POST /dom/ancestors - The nearest element containing every element the
+/// selectors match, e.g. to see which container's stacking context or
+/// layout two elements share.
+///
+/// Responds with 404 naming the selectors that match no element.
+pub async fn common_ancestor(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<AncestorRequest>,
+) -> Result<Json<AncestorResponse>, (StatusCode, String)> {
+    if req.selectors.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "selectors must not be empty".to_string(),
+        ));
+    }
+    let result: AncestorResult = eval_json(&state, build_ancestor_script(&req.selectors)).await?;
+    match result.ancestor {
+        Some(ancestor) if result.found => Ok(Json(ancestor)),
+        _ => Err((
+            StatusCode::NOT_FOUND,
+            format!("No elements match: {}", result.unmatched.join(", ")),
+        )),
+    }
+}
+
+fn build_ancestor_script(selectors: &[String]) -> String {
+    ScriptTemplate::new(include_str!("../scripts/common_ancestor.js"))
+        .render_json(HashMap::from([("selectors", serde_json::json!(selectors))]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::post, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    async fn ancestors(body: &str, payload: serde_json::Value) -> (u16, serde_json::Value, String) {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/dom/ancestors", post(common_ancestor))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                let encoded = serde_json::to_string(&payload.to_string()).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/dom/ancestors")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status().as_u16();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let text = String::from_utf8_lossy(&body).to_string();
+        let json = serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null);
+        (status, json, text)
+    }
+
+    #[test]
+    fn test_build_ancestor_script() {
+        let script = build_ancestor_script(&[".tooltip".to_string(), "a[title=\"x\"]".to_string()]);
+        assert!(script.contains(r#"const selectors = [".tooltip","a[title=\"x\"]"];"#));
+    }
+
+    #[tokio::test]
+    async fn test_common_ancestor_handler() {
+        let (status, json, _) = ancestors(
+            r##"{"selectors": [".tooltip", "#save"]}"##,
+            serde_json::json!({
+                "found": true,
+                "ancestor_selector": "main > div.toolbar",
+                "ancestor_tag": "div",
+                "depth": 3
+            }),
+        )
+        .await;
+        assert_eq!(status, 200);
+        assert_eq!(json["ancestor_selector"], "main > div.toolbar");
+        assert_eq!(json["ancestor_tag"], "div");
+        assert_eq!(json["depth"], 3);
+    }
+
+    #[tokio::test]
+    async fn test_common_ancestor_unmatched_selector() {
+        let (status, _, text) = ancestors(
+            r##"{"selectors": [".tooltip", "#missing"]}"##,
+            serde_json::json!({"found": false, "unmatched": ["#missing"]}),
+        )
+        .await;
+        assert_eq!(status, 404);
+        assert_eq!(text, "No elements match: #missing");
+    }
+
+    #[tokio::test]
+    async fn test_common_ancestor_rejects_empty_selectors() {
+        let (status, _, _) = ancestors(r#"{"selectors": []}"#, serde_json::json!({})).await;
+        assert_eq!(status, 400);
+    }
+}
@@ -0,0 +1,164 @@
+//! Handlers for inspecting and controlling Web Animations.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+use serde::Deserialize;
+
+use super::eval_json;
+use crate::template::ScriptTemplate;
+use crate::types::{AnimationControlRequest, AnimationEntry, AnimationsResponse};
+use crate::BridgeState;
+
+const ACTIONS: &[&str] = &["pause", "play", "cancel"];
+
+/// Result of the animations script when an action was applied.
+#[derive(Deserialize)]
+struct ControlResult {
+    matched: usize,
+    animations: Vec<AnimationEntry>,
+}
+
+/// GET /animations - List CSS and Web Animations API animations.
+pub async fn animations(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<AnimationsResponse>, (StatusCode, String)> {
+    let response = eval_json(&state, build_animations_script(None, None)).await?;
+    Ok(Json(response))
+}
+
+/// POST /animations - Pause, play or cancel animations.
+///
+/// Returns the animations left after the action. Responds with 404 when an
+/// `id` was given but no animation matched it.
+pub async fn control_animations(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<AnimationControlRequest>,
+) -> Result<Json<AnimationsResponse>, (StatusCode, String)> {
+    if !ACTIONS.contains(&req.action.as_str()) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!(
+                "Unknown action '{}', expected one of: {}",
+                req.action,
+                ACTIONS.join(", ")
+            ),
+        ));
+    }
+
+    let script = build_animations_script(req.id.as_deref(), Some(&req.action));
+    let result: ControlResult = eval_json(&state, script).await?;
+    if let (Some(id), 0) = (&req.id, result.matched) {
+        return Err((
+            StatusCode::NOT_FOUND,
+            format!("No animation with id '{}'", id),
+        ));
+    }
+
+    Ok(Json(AnimationsResponse {
+        animations: result.animations,
+    }))
+}
+
+fn build_animations_script(id: Option<&str>, action: Option<&str>) -> String {
+    ScriptTemplate::new(include_str!("../scripts/animations.js")).render_json(HashMap::from([
+        ("id", serde_json::json!(id)),
+        ("action", serde_json::json!(action)),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn control_request(body: &'static str) -> Request<Body> {
+        Request::post("/animations")
+            .header("content-type", "application/json")
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_build_animations_script() {
+        let script = build_animations_script(None, None);
+        assert!(script.contains("const id = null;"));
+        assert!(script.contains("const action = null;"));
+
+        let script = build_animations_script(Some("spin"), Some("pause"));
+        assert!(script.contains("const id = \"spin\";"));
+        assert!(script.contains("const action = \"pause\";"));
+    }
+
+    #[tokio::test]
+    async fn test_animations_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/animations", get(animations))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("getAnimations"));
+                let payload = r#"{"matched": 0, "animations": [{"id": "spin", "state": "running", "current_time": 250, "effect_target": "div.spinner", "duration_ms": 1000}]}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(Request::get("/animations").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["animations"][0]["id"], "spin");
+        assert_eq!(json["animations"][0]["state"], "running");
+    }
+
+    #[tokio::test]
+    async fn test_control_animations_unknown_id() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/animations", get(animations).post(control_animations))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                let payload = r#"{"matched": 0, "animations": []}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(control_request(r#"{"id": "nope", "action": "pause"}"#))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 404);
+    }
+
+    #[tokio::test]
+    async fn test_control_animations_rejects_unknown_action() {
+        let (state, _rx) = create_test_state();
+        let app = Router::new()
+            .route("/animations", get(animations).post(control_animations))
+            .with_state(state);
+
+        let response = app
+            .oneshot(control_request(r#"{"action": "reverse"}"#))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 400);
+    }
+}
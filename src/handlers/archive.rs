@@ -0,0 +1,382 @@
+//! Handlers that bundle the page's state into ZIP archives for bug reports.
+
+use std::collections::HashMap;
+use std::io::{Cursor, Write};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderName, StatusCode},
+    response::{IntoResponse, Json, Response},
+};
+use serde::Serialize;
+use serde_json::Value;
+use uuid::Uuid;
+use zip::write::SimpleFileOptions;
+
+use super::dom::{build_dom_script, DomQuery};
+use super::{diagnose_performance, eval_json, js_errors, network};
+use crate::history::now_ms;
+use crate::screenshot::capture_screenshot_bytes;
+use crate::types::{JsErrorsQuery, ScreenshotFormat, SnapshotArchiveQuery, SnapshotManifest};
+use crate::BridgeState;
+
+/// Response header carrying the archive's id.
+const SNAPSHOT_ID_HEADER: &str = "snapshot-id";
+
+/// Archives kept for `GET /snapshot/{id}`; older ones are deleted.
+const MAX_STORED_SNAPSHOTS: usize = 20;
+
+/// A file for the archive, or why it couldn't be captured.
+type Part = (&'static str, Result<Vec<u8>, String>);
+
+/// GET /snapshot - Capture the DOM, CSS variables, JS errors, network log
+/// and performance diagnosis into one ZIP archive.
+///
+/// The parts are captured concurrently, each as a JSON file, and
+/// `?screenshot=true` adds `screenshot.png`. Parts that fail are listed in
+/// `manifest.json` instead of failing the capture. The bridge doesn't record
+/// console output, so `errors.json` is the closest thing to a console log.
+///
+/// The archive is also stored for `GET /snapshot/{id}`; its id is sent in
+/// the `Snapshot-Id` header. Only the last [`MAX_STORED_SNAPSHOTS`] archives
+/// are kept.
+pub async fn capture_snapshot(
+    State(state): State<Arc<BridgeState>>,
+    Query(query): Query<SnapshotArchiveQuery>,
+) -> Result<Response, (StatusCode, String)> {
+    let snapshot_id = Uuid::new_v4();
+    let captured_at_ms = now_ms();
+
+    let (dom, css_variables, errors, network_log, performance, screenshot) = futures_util::join!(
+        eval_json::<Value>(&state, build_dom_script(&DomQuery::default())),
+        eval_json::<Value>(
+            &state,
+            include_str!("../scripts/css_variables.js").to_string()
+        ),
+        // Leave the errors for the next `/errors` read
+        js_errors(
+            State(state.clone()),
+            Query(JsErrorsQuery { clear: Some(false) })
+        ),
+        network(State(state.clone())),
+        diagnose_performance(State(state.clone())),
+        capture_png(&state.app_name, query.screenshot),
+    );
+
+    let mut parts: Vec<Part> = vec![
+        ("dom.json", json_part(dom)),
+        ("css-variables.json", json_part(css_variables)),
+        ("errors.json", json_part(errors.map(|Json(r)| r))),
+        ("network.json", json_part(network_log.map(|Json(r)| r))),
+        ("performance.json", json_part(performance.map(|Json(r)| r))),
+    ];
+    if let Some(png) = screenshot {
+        parts.push(("screenshot.png", png));
+    }
+    let manifest = SnapshotManifest {
+        snapshot_id,
+        app_name: state.app_name.clone(),
+        captured_at_ms,
+        files: Vec::new(),
+        errors: HashMap::new(),
+    };
+    let zip = build_archive(manifest, parts).map_err(internal_error)?;
+
+    let dir = snapshots_dir(&state);
+    let zip =
+        tokio::task::spawn_blocking(move || store_archive(&dir, snapshot_id, &zip).map(|()| zip))
+            .await
+            .map_err(internal_error)?
+            .map_err(internal_error)?;
+
+    Ok(archive_response(snapshot_id, zip))
+}
+
+/// GET /snapshot/{id} - Return an archive stored by `GET /snapshot`.
+pub async fn load_snapshot(
+    State(state): State<Arc<BridgeState>>,
+    Path(id): Path<Uuid>,
+) -> Result<Response, (StatusCode, String)> {
+    let path = snapshots_dir(&state).join(archive_name(id));
+    let read = tokio::task::spawn_blocking(move || std::fs::read(path))
+        .await
+        .map_err(internal_error)?;
+    match read {
+        Ok(zip) => Ok(archive_response(id, zip)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            Err((StatusCode::NOT_FOUND, format!("No snapshot with id {}", id)))
+        }
+        Err(e) => Err(internal_error(e)),
+    }
+}
+
+/// Capture the window off the async runtime, since it blocks. `None` when
+/// no screenshot was asked for.
+async fn capture_png(app_name: &str, wanted: bool) -> Option<Result<Vec<u8>, String>> {
+    if !wanted {
+        return None;
+    }
+    let app_name = app_name.to_string();
+    let capture = tokio::task::spawn_blocking(move || {
+        capture_screenshot_bytes(&app_name, ScreenshotFormat::Png)
+    });
+    Some(capture.await.unwrap_or_else(|e| Err(e.to_string())))
+}
+
+fn json_part(result: Result<impl Serialize, (StatusCode, String)>) -> Result<Vec<u8>, String> {
+    let value = result.map_err(|(_, message)| message)?;
+    serde_json::to_vec_pretty(&value).map_err(|e| e.to_string())
+}
+
+/// Write the captured parts and a manifest listing them.
+fn build_archive(
+    mut manifest: SnapshotManifest,
+    parts: Vec<Part>,
+) -> zip::result::ZipResult<Vec<u8>> {
+    let options = SimpleFileOptions::default();
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, part) in parts {
+        match part {
+            Ok(bytes) => {
+                writer.start_file(name, options)?;
+                writer.write_all(&bytes)?;
+                manifest.files.push(name.to_string());
+            }
+            Err(error) => {
+                manifest.errors.insert(name.to_string(), error);
+            }
+        }
+    }
+    writer.start_file("manifest.json", options)?;
+    writer.write_all(&serde_json::to_vec_pretty(&manifest).unwrap_or_default())?;
+    Ok(writer.finish()?.into_inner())
+}
+
+fn archive_response(id: Uuid, zip: Vec<u8>) -> Response {
+    (
+        [
+            (header::CONTENT_TYPE, "application/zip".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"snapshot-{}.zip\"", id),
+            ),
+            (HeaderName::from_static(SNAPSHOT_ID_HEADER), id.to_string()),
+        ],
+        zip,
+    )
+        .into_response()
+}
+
+fn snapshots_dir(state: &BridgeState) -> PathBuf {
+    state
+        .snapshot_dir
+        .clone()
+        .unwrap_or_else(|| std::env::temp_dir().join("dioxus-inspector-snapshots"))
+}
+
+fn archive_name(id: Uuid) -> String {
+    format!("{}.zip", id)
+}
+
+/// Write the archive into `dir` and delete the oldest beyond
+/// [`MAX_STORED_SNAPSHOTS`]. Blocks, so run it off the async runtime.
+fn store_archive(dir: &std::path::Path, id: Uuid, zip: &[u8]) -> std::io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    let path = dir.join(archive_name(id));
+    std::fs::write(&path, zip)?;
+    prune_archives(dir, &path, MAX_STORED_SNAPSHOTS)
+}
+
+/// Delete all but the `keep` newest archives in `dir`, never `current`.
+fn prune_archives(
+    dir: &std::path::Path,
+    current: &std::path::Path,
+    keep: usize,
+) -> std::io::Result<()> {
+    let mut older: Vec<(SystemTime, PathBuf)> = std::fs::read_dir(dir)?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path != current && path.extension().is_some_and(|ext| ext == "zip"))
+        .filter_map(|path| Some((path.metadata().ok()?.modified().ok()?, path)))
+        .collect();
+    older.sort_by(|a, b| b.0.cmp(&a.0));
+    for (_, path) in older.into_iter().skip(keep.saturating_sub(1)) {
+        // Another capture may have removed it already
+        let _ = std::fs::remove_file(path);
+    }
+    Ok(())
+}
+
+fn internal_error(e: impl std::fmt::Display) -> (StatusCode, String) {
+    (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{EvalCommand, EvalResponse};
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use http_body_util::BodyExt;
+    use std::io::Read;
+    use tokio::sync::mpsc;
+    use tower::ServiceExt;
+
+    fn snapshot_state(name: &str) -> (Arc<BridgeState>, mpsc::Receiver<EvalCommand>, PathBuf) {
+        let dir = std::env::temp_dir().join(format!(
+            "dioxus-inspector-snapshots-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        let (eval_tx, eval_rx) = mpsc::channel(32);
+        let state = Arc::new(BridgeState {
+            snapshot_dir: Some(dir.clone()),
+            ..BridgeState::new("test-app".to_string(), eval_tx)
+        });
+        (state, eval_rx, dir)
+    }
+
+    fn app(state: Arc<BridgeState>) -> Router {
+        Router::new()
+            .route("/snapshot", get(capture_snapshot))
+            .route("/snapshot/{id}", get(load_snapshot))
+            .with_state(state)
+    }
+
+    /// Answer every eval with an object that satisfies all the parts.
+    fn respond_to_evals(mut rx: mpsc::Receiver<EvalCommand>) {
+        tokio::spawn(async move {
+            while let Some(cmd) = rx.recv().await {
+                let payload = serde_json::json!({
+                    "tag": "body",
+                    "variables": { "--accent": "#ff0000" },
+                    "errors": [],
+                    "requests": [],
+                    "issues": []
+                });
+                let _ = cmd
+                    .response_tx
+                    .send(EvalResponse::success(payload.to_string()));
+            }
+        });
+    }
+
+    fn read_file(zip: &[u8], name: &str) -> String {
+        let mut archive = zip::ZipArchive::new(Cursor::new(zip)).unwrap();
+        let mut file = archive.by_name(name).unwrap();
+        let mut contents = String::new();
+        file.read_to_string(&mut contents).unwrap();
+        contents
+    }
+
+    #[tokio::test]
+    async fn test_capture_and_load_snapshot() {
+        let (state, rx, dir) = snapshot_state("roundtrip");
+        respond_to_evals(rx);
+
+        let response = app(state.clone())
+            .oneshot(Request::get("/snapshot").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/zip");
+        let id = response.headers()[SNAPSHOT_ID_HEADER]
+            .to_str()
+            .unwrap()
+            .to_string();
+        let zip = response.into_body().collect().await.unwrap().to_bytes();
+
+        let manifest: SnapshotManifest =
+            serde_json::from_str(&read_file(&zip, "manifest.json")).unwrap();
+        assert_eq!(manifest.snapshot_id.to_string(), id);
+        assert_eq!(
+            manifest.files,
+            vec![
+                "dom.json",
+                "css-variables.json",
+                "errors.json",
+                "network.json",
+                "performance.json"
+            ]
+        );
+        assert!(manifest.errors.is_empty());
+        assert!(read_file(&zip, "css-variables.json").contains("--accent"));
+        assert!(dir.join(format!("{}.zip", id)).exists());
+
+        let response = app(state)
+            .oneshot(
+                Request::get(format!("/snapshot/{}", id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let stored = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(stored, zip);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    #[tokio::test]
+    async fn test_capture_snapshot_lists_failed_screenshot() {
+        let (state, rx, dir) = snapshot_state("screenshot");
+        respond_to_evals(rx);
+
+        let response = app(state)
+            .oneshot(
+                Request::get("/snapshot?screenshot=true")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let zip = response.into_body().collect().await.unwrap().to_bytes();
+
+        let manifest: SnapshotManifest =
+            serde_json::from_str(&read_file(&zip, "manifest.json")).unwrap();
+        assert!(!manifest.files.contains(&"screenshot.png".to_string()));
+        assert!(manifest.errors["screenshot.png"].contains("only supported on macOS"));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_prune_archives_keeps_newest() {
+        let (_state, _rx, dir) = snapshot_state("prune");
+        std::fs::create_dir_all(&dir).unwrap();
+        let start = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_000_000);
+        let paths: Vec<PathBuf> = (0..4)
+            .map(|i| {
+                let path = dir.join(format!("{}.zip", i));
+                let file = std::fs::File::create(&path).unwrap();
+                file.set_modified(start + std::time::Duration::from_secs(i))
+                    .unwrap();
+                path
+            })
+            .collect();
+
+        // The current archive survives even when it isn't the newest
+        prune_archives(&dir, &paths[0], 2).unwrap();
+        let exists: Vec<bool> = paths.iter().map(|p| p.exists()).collect();
+        assert_eq!(exists, vec![true, false, false, true]);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[tokio::test]
+    async fn test_load_unknown_snapshot() {
+        let (state, _rx, _dir) = snapshot_state("unknown");
+        let response = app(state)
+            .oneshot(
+                Request::get(format!("/snapshot/{}", Uuid::new_v4()))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 404);
+    }
+}
@@ -0,0 +1,112 @@
+//! Handlers for reading and changing bridge settings at runtime.
+
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use axum::{extract::State, response::Json};
+
+use crate::types::{BridgeConfigResponse, EvalTimeoutRequest};
+use crate::{BackpressureStrategy, BridgeState};
+
+/// GET /config - The bridge's current settings.
+pub async fn bridge_config(State(state): State<Arc<BridgeState>>) -> Json<BridgeConfigResponse> {
+    Json(current_config(&state))
+}
+
+/// PUT /config/eval-timeout - Change how long the bridge waits for an eval.
+///
+/// Applies to every eval without a timeout of its own; `/eval/async`,
+/// `/eval/parallel` and `/eval/sandbox` requests that set `timeout_ms` use
+/// theirs instead. An eval that runs out of time answers with a "timed out"
+/// error, though its script may keep running in the page.
+pub async fn set_eval_timeout(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<EvalTimeoutRequest>,
+) -> Json<BridgeConfigResponse> {
+    state
+        .default_eval_timeout_ms
+        .store(req.timeout_ms, Ordering::Relaxed);
+    Json(current_config(&state))
+}
+
+fn current_config(state: &BridgeState) -> BridgeConfigResponse {
+    let (backpressure, backpressure_queue_max) = match state.backpressure {
+        BackpressureStrategy::Block => ("block", None),
+        BackpressureStrategy::Drop => ("drop", None),
+        BackpressureStrategy::Queue { max } => ("queue", Some(max)),
+    };
+    let timeout_ms = state.default_eval_timeout_ms.load(Ordering::Relaxed);
+    BridgeConfigResponse {
+        app_name: state.app_name.clone(),
+        default_eval_timeout_ms: (timeout_ms > 0).then_some(timeout_ms),
+        backpressure: backpressure.to_string(),
+        backpressure_queue_max,
+        max_history: state.history.capacity(),
+        auth_required: state.auth_token.is_some(),
+        rate_limit: state.rate_limiter.as_ref().map(|l| l.max_per_sec()),
+        startup_scripts: state.startup_scripts.len(),
+        log_eval_scripts: state.log_eval_scripts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    async fn send(
+        state: Arc<BridgeState>,
+        method: &str,
+        uri: &str,
+        body: &str,
+    ) -> serde_json::Value {
+        let app = Router::new()
+            .route("/config", get(bridge_config))
+            .route("/config/eval-timeout", axum::routing::put(set_eval_timeout))
+            .with_state(state);
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status().as_u16(), 200);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_bridge_config_defaults() {
+        let (state, _rx) = create_test_state();
+        let json = send(state, "GET", "/config", "").await;
+        assert_eq!(json["app_name"], "test-app");
+        assert_eq!(json["default_eval_timeout_ms"], serde_json::Value::Null);
+        assert_eq!(json["backpressure"], "block");
+        assert_eq!(json["auth_required"], false);
+    }
+
+    #[tokio::test]
+    async fn test_set_eval_timeout() {
+        let (state, _rx) = create_test_state();
+        let json = send(
+            state.clone(),
+            "PUT",
+            "/config/eval-timeout",
+            r#"{"timeout_ms": 5000}"#,
+        )
+        .await;
+        assert_eq!(json["default_eval_timeout_ms"], 5000);
+        assert_eq!(state.default_eval_timeout_ms.load(Ordering::Relaxed), 5000);
+
+        let json = send(state, "PUT", "/config/eval-timeout", r#"{"timeout_ms": 0}"#).await;
+        assert_eq!(json["default_eval_timeout_ms"], serde_json::Value::Null);
+    }
+}
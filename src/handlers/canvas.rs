@@ -0,0 +1,145 @@
+//! Handler for capturing `<canvas>` content.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+use super::eval_json;
+use crate::template::ScriptTemplate;
+use crate::types::{CanvasRequest, CanvasResponse};
+use crate::BridgeState;
+
+const DEFAULT_FORMAT: &str = "image/png";
+
+/// POST /canvas - Capture a canvas element with `toDataURL`.
+///
+/// Problems in the page (no such element, not a canvas, tainted canvas) and
+/// failures writing `save_path` are reported in `error` rather than as an
+/// HTTP error, so the dimensions still come back when they're known.
+pub async fn capture_canvas(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<CanvasRequest>,
+) -> Result<Json<CanvasResponse>, (StatusCode, String)> {
+    let format = req.format.as_deref().unwrap_or(DEFAULT_FORMAT);
+    if !format.starts_with("image/") {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Invalid format '{}', expected an image MIME type", format),
+        ));
+    }
+
+    let script = ScriptTemplate::new(include_str!("../scripts/canvas.js")).render(HashMap::from([
+        ("selector", req.selector.as_str()),
+        ("format", format),
+    ]));
+    let mut response: CanvasResponse = eval_json(&state, script).await?;
+
+    if let (Some(path), None) = (&req.save_path, &response.error) {
+        if let Err(e) = save_data_url(&response.data_url, path) {
+            response.error = Some(e);
+        }
+    }
+    Ok(Json(response))
+}
+
+fn save_data_url(data_url: &str, path: &str) -> Result<(), String> {
+    let bytes = decode_data_url(data_url)?;
+    std::fs::write(path, bytes).map_err(|e| format!("Failed to save canvas: {}", e))
+}
+
+/// Decode a base64 `data:` URL into its raw bytes.
+fn decode_data_url(data_url: &str) -> Result<Vec<u8>, String> {
+    let (header, data) = data_url
+        .strip_prefix("data:")
+        .and_then(|rest| rest.split_once(','))
+        .ok_or_else(|| "Malformed data URL".to_string())?;
+    if !header.ends_with(";base64") {
+        return Err("Data URL is not base64-encoded".to_string());
+    }
+    STANDARD
+        .decode(data)
+        .map_err(|e| format!("Invalid base64 in data URL: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::post, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn canvas_request(body: serde_json::Value) -> Request<Body> {
+        Request::post("/canvas")
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_decode_data_url() {
+        assert_eq!(
+            decode_data_url("data:image/png;base64,aGVsbG8=").unwrap(),
+            b"hello"
+        );
+        assert!(decode_data_url("data:,hello").is_err());
+        assert!(decode_data_url("image/png;base64,aGVsbG8=").is_err());
+        assert!(decode_data_url("data:image/png;base64,!!").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_capture_canvas_saves_file() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/canvas", post(capture_canvas))
+            .with_state(state);
+        let path = std::env::temp_dir().join(format!("dioxus-canvas-{}.png", std::process::id()));
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains(r#"toDataURL("image/png")"#));
+                let payload = r#"{"data_url": "data:image/png;base64,aGVsbG8=", "width": 300, "height": 150, "error": null}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(canvas_request(serde_json::json!({
+                "selector": "canvas#chart",
+                "save_path": path.display().to_string(),
+            })))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["width"], 300);
+        assert!(json["error"].is_null());
+        assert_eq!(std::fs::read(&path).unwrap(), b"hello");
+
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[tokio::test]
+    async fn test_capture_canvas_rejects_non_image_format() {
+        let (state, _rx) = create_test_state();
+        let app = Router::new()
+            .route("/canvas", post(capture_canvas))
+            .with_state(state);
+
+        let response = app
+            .oneshot(canvas_request(serde_json::json!({
+                "selector": "canvas",
+                "format": "text/html",
+            })))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 400);
+    }
+}
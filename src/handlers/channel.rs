@@ -0,0 +1,244 @@
+//! Handing scripts to the app over the eval channel, under the configured
+//! backpressure strategy and timeout.
+
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use axum::http::StatusCode;
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::oneshot;
+
+use crate::types::{EvalCommand, EvalResponse};
+use crate::{BackpressureStrategy, BridgeState};
+
+/// Hand a script to the app and wait for its answer.
+///
+/// Gives up after `timeout_ms`, or [`BridgeState::default_eval_timeout_ms`]
+/// when that's `None`, answering with a "timed out" error; `0` waits
+/// indefinitely.
+pub(super) async fn dispatch_eval(
+    state: &BridgeState,
+    script: String,
+    timeout_ms: Option<u64>,
+) -> Result<EvalResponse, StatusCode> {
+    let timeout_ms =
+        timeout_ms.unwrap_or_else(|| state.default_eval_timeout_ms.load(Ordering::Relaxed));
+    if timeout_ms == 0 {
+        return deliver_eval(state, script).await;
+    }
+    tokio::time::timeout(
+        Duration::from_millis(timeout_ms),
+        deliver_eval(state, script),
+    )
+    .await
+    .unwrap_or_else(|_| {
+        Ok(EvalResponse::error(format!(
+            "Script timed out after {}ms",
+            timeout_ms
+        )))
+    })
+}
+
+async fn deliver_eval(state: &BridgeState, script: String) -> Result<EvalResponse, StatusCode> {
+    let (response_tx, response_rx) = oneshot::channel();
+
+    let cmd = EvalCommand {
+        script,
+        response_tx,
+    };
+
+    match state.backpressure {
+        BackpressureStrategy::Block => state
+            .eval_tx
+            .send(cmd)
+            .await
+            .map_err(|_| StatusCode::SERVICE_UNAVAILABLE)?,
+        BackpressureStrategy::Drop => match state.eval_tx.try_send(cmd) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => return Ok(rate_limited(state)),
+            Err(TrySendError::Closed(_)) => return Err(StatusCode::SERVICE_UNAVAILABLE),
+        },
+        BackpressureStrategy::Queue { max } => {
+            if let Some(response) = enqueue_eval(state, cmd, max).await? {
+                return Ok(response);
+            }
+        }
+    }
+
+    response_rx
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+/// Send `cmd` to the app, or park it in the overflow buffer while the channel
+/// is full. Returns a response only when the eval was rejected.
+async fn enqueue_eval(
+    state: &BridgeState,
+    cmd: EvalCommand,
+    max: usize,
+) -> Result<Option<EvalResponse>, StatusCode> {
+    let mut overflow = state.eval_overflow.lock().await;
+
+    // Only skip the buffer when it's empty, so evals keep their order
+    let cmd = if overflow.is_empty() {
+        match state.eval_tx.try_send(cmd) {
+            Ok(()) => return Ok(None),
+            Err(TrySendError::Full(cmd)) => cmd,
+            Err(TrySendError::Closed(_)) => return Err(StatusCode::SERVICE_UNAVAILABLE),
+        }
+    } else {
+        cmd
+    };
+    if overflow.len() >= max {
+        return Ok(Some(rate_limited(state)));
+    }
+    overflow.push_back(cmd);
+    state.eval_overflow_depth.fetch_add(1, Ordering::Relaxed);
+    drop(overflow);
+
+    drain_overflow(state).await?;
+    Ok(None)
+}
+
+/// Move buffered evals into the channel as the app makes room.
+///
+/// Every request that buffers an eval drains, so the buffer empties even if
+/// some of those requests are cancelled while waiting.
+async fn drain_overflow(state: &BridgeState) -> Result<(), StatusCode> {
+    while !state.eval_overflow.lock().await.is_empty() {
+        let permit = state
+            .eval_tx
+            .reserve()
+            .await
+            .map_err(|_| StatusCode::SERVICE_UNAVAILABLE)?;
+        // Another request may have drained it while this one waited
+        if let Some(cmd) = state.eval_overflow.lock().await.pop_front() {
+            state.eval_overflow_depth.fetch_sub(1, Ordering::Relaxed);
+            permit.send(cmd);
+        }
+    }
+    Ok(())
+}
+
+fn rate_limited(state: &BridgeState) -> EvalResponse {
+    state.eval_dropped_total.fetch_add(1, Ordering::Relaxed);
+    EvalResponse::error("rate limited")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use std::sync::Arc;
+    use tokio::sync::mpsc;
+
+    fn backpressure_state(
+        strategy: BackpressureStrategy,
+    ) -> (Arc<BridgeState>, mpsc::Receiver<EvalCommand>) {
+        let (eval_tx, eval_rx) = mpsc::channel(1);
+        let state = Arc::new(BridgeState {
+            backpressure: strategy,
+            ..BridgeState::new("test-app".to_string(), eval_tx)
+        });
+        (state, eval_rx)
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_eval_drop_when_full() {
+        let (state, mut rx) = backpressure_state(BackpressureStrategy::Drop);
+        let (response_tx, _response_rx) = oneshot::channel();
+        state
+            .eval_tx
+            .try_send(EvalCommand {
+                script: "busy".to_string(),
+                response_tx,
+            })
+            .unwrap();
+
+        let response = dispatch_eval(&state, "return 1".to_string(), None)
+            .await
+            .unwrap();
+        assert!(!response.success);
+        assert_eq!(response.error.as_deref(), Some("rate limited"));
+        assert_eq!(state.eval_dropped_total.load(Ordering::Relaxed), 1);
+        assert_eq!(rx.recv().await.unwrap().script, "busy");
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_eval_queue_overflow() {
+        let (state, mut rx) = backpressure_state(BackpressureStrategy::Queue { max: 1 });
+
+        // The first eval fills the channel, the second waits in the buffer
+        let spawn_eval = |script: &'static str| {
+            let state = state.clone();
+            tokio::spawn(async move { dispatch_eval(&state, script.to_string(), None).await })
+        };
+        let first = spawn_eval("first");
+        while state.eval_tx.capacity() > 0 {
+            tokio::task::yield_now().await;
+        }
+        let second = spawn_eval("second");
+        while state.eval_overflow.lock().await.is_empty() {
+            tokio::task::yield_now().await;
+        }
+
+        assert_eq!(state.eval_overflow_depth.load(Ordering::Relaxed), 1);
+
+        let third = dispatch_eval(&state, "third".to_string(), None)
+            .await
+            .unwrap();
+        assert_eq!(third.error.as_deref(), Some("rate limited"));
+        assert_eq!(state.eval_dropped_total.load(Ordering::Relaxed), 1);
+
+        for expected in ["first", "second"] {
+            let cmd = rx.recv().await.unwrap();
+            assert_eq!(cmd.script, expected);
+            let _ = cmd.response_tx.send(EvalResponse::success(expected));
+        }
+        assert_eq!(
+            first.await.unwrap().unwrap().result.as_deref(),
+            Some("first")
+        );
+        assert_eq!(
+            second.await.unwrap().unwrap().result.as_deref(),
+            Some("second")
+        );
+        assert!(state.eval_overflow.lock().await.is_empty());
+        assert_eq!(state.eval_overflow_depth.load(Ordering::Relaxed), 0);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_eval_default_timeout() {
+        let (state, mut rx) = create_test_state();
+        state.default_eval_timeout_ms.store(20, Ordering::Relaxed);
+
+        let response = dispatch_eval(&state, "return 1".to_string(), None)
+            .await
+            .unwrap();
+        assert!(!response.success);
+        assert_eq!(
+            response.error.as_deref(),
+            Some("Script timed out after 20ms")
+        );
+        // The app got the eval, it just never answered
+        assert_eq!(rx.recv().await.unwrap().script, "return 1");
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_eval_own_timeout_overrides_default() {
+        let (state, mut rx) = create_test_state();
+        state.default_eval_timeout_ms.store(1, Ordering::Relaxed);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                let _ = cmd.response_tx.send(EvalResponse::success("1"));
+            }
+        });
+
+        let response = dispatch_eval(&state, "return 1".to_string(), Some(5000))
+            .await
+            .unwrap();
+        assert_eq!(response.result.as_deref(), Some("1"));
+    }
+}
@@ -0,0 +1,115 @@
+//! Handlers for reading and writing the system clipboard.
+//!
+//! Both go through `navigator.clipboard` in the webview, which browsers only
+//! allow while the document has focus. If the app window is in the
+//! background, the eval fails with a `NotAllowedError` and the handlers
+//! respond with 502.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+
+use super::eval_json;
+use crate::template::ScriptTemplate;
+use crate::types::{ClipboardResponse, SetClipboardRequest};
+use crate::BridgeState;
+
+/// GET /clipboard - Read the clipboard text.
+pub async fn get_clipboard(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<ClipboardResponse>, (StatusCode, String)> {
+    let response = eval_json(&state, build_clipboard_script(None)).await?;
+    Ok(Json(response))
+}
+
+/// POST /clipboard - Write text to the clipboard, returning what it now holds.
+pub async fn set_clipboard(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<SetClipboardRequest>,
+) -> Result<Json<ClipboardResponse>, (StatusCode, String)> {
+    let response = eval_json(&state, build_clipboard_script(Some(&req.text))).await?;
+    Ok(Json(response))
+}
+
+fn build_clipboard_script(text: Option<&str>) -> String {
+    ScriptTemplate::new(include_str!("../scripts/clipboard.js"))
+        .render_json(HashMap::from([("text", serde_json::json!(text))]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_build_clipboard_script() {
+        let script = build_clipboard_script(None);
+        assert!(script.contains("const text = null;"));
+
+        let script = build_clipboard_script(Some("a \"quote\""));
+        assert!(script.contains(r#"const text = "a \"quote\"";"#));
+        assert!(script.contains("navigator.clipboard.writeText(text)"));
+    }
+
+    #[tokio::test]
+    async fn test_set_clipboard_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/clipboard", get(get_clipboard).post(set_clipboard))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("\"hello\""));
+                let encoded = serde_json::to_string(r#"{"text": "hello"}"#).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/clipboard")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"text": "hello"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["text"], "hello");
+    }
+
+    #[tokio::test]
+    async fn test_get_clipboard_without_focus() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/clipboard", get(get_clipboard))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                let _ = cmd.response_tx.send(EvalResponse::error(
+                    "NotAllowedError: Document is not focused.",
+                ));
+            }
+        });
+
+        let response = app
+            .oneshot(Request::get("/clipboard").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 502);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert!(String::from_utf8_lossy(&body).contains("not focused"));
+    }
+}
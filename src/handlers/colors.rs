@@ -0,0 +1,170 @@
+//! Handler for listing the colors used in the document.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::eval_json;
+use crate::contrast::{self, Rgba};
+use crate::template::ScriptTemplate;
+use crate::types::{ColorEntry, ColorsResponse};
+use crate::BridgeState;
+
+/// Selector paths kept per color.
+const MAX_COLOR_EXAMPLES: usize = 3;
+
+/// How often the page used one computed color value.
+#[derive(Deserialize)]
+struct ColorUsage {
+    value: String,
+    count: u32,
+    examples: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct ColorUsages {
+    values: Vec<ColorUsage>,
+}
+
+/// GET /colors - List every distinct color the page paints with.
+///
+/// Values are normalized to hex, so `rgb(255, 0, 0)` and `red` count as one
+/// color. Fully transparent values, and ones that can't be parsed such as
+/// `color(display-p3 ...)`, are left out.
+pub async fn colors(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<ColorsResponse>, (StatusCode, String)> {
+    let script = ScriptTemplate::new(include_str!("../scripts/colors.js")).render_json(
+        HashMap::from([("max_examples", Value::from(MAX_COLOR_EXAMPLES))]),
+    );
+    let usages: ColorUsages = eval_json(&state, script).await?;
+    Ok(Json(ColorsResponse {
+        colors: merge_colors(usages.values),
+    }))
+}
+
+/// Group usages by hex value, most used first.
+fn merge_colors(usages: Vec<ColorUsage>) -> Vec<ColorEntry> {
+    // Per hex value: the entry and how often its `css_value` was used
+    let mut by_hex: HashMap<String, (ColorEntry, u32)> = HashMap::new();
+    for usage in usages {
+        let Some(color) = contrast::parse_color(&usage.value).filter(|c| c.a > 0.0) else {
+            continue;
+        };
+        let hex = to_hex(color);
+        let (entry, top_count) = by_hex.entry(hex.clone()).or_insert_with(|| {
+            let entry = ColorEntry {
+                css_value: usage.value.clone(),
+                hex,
+                usage_count: 0,
+                element_examples: Vec::new(),
+            };
+            (entry, 0)
+        });
+        entry.usage_count += usage.count;
+        if usage.count > *top_count {
+            entry.css_value = usage.value;
+            *top_count = usage.count;
+        }
+        for example in usage.examples {
+            if entry.element_examples.len() < MAX_COLOR_EXAMPLES
+                && !entry.element_examples.contains(&example)
+            {
+                entry.element_examples.push(example);
+            }
+        }
+    }
+
+    let mut colors: Vec<ColorEntry> = by_hex.into_values().map(|(entry, _)| entry).collect();
+    colors.sort_by(|a, b| b.usage_count.cmp(&a.usage_count).then(a.hex.cmp(&b.hex)));
+    colors
+}
+
+fn to_hex(color: Rgba) -> String {
+    let hex = format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b);
+    if color.a < 1.0 {
+        format!("{}{:02x}", hex, (color.a * 255.0).round() as u8)
+    } else {
+        hex
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn usage(value: &str, count: u32, examples: &[&str]) -> ColorUsage {
+        ColorUsage {
+            value: value.to_string(),
+            count,
+            examples: examples.iter().map(|e| e.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_to_hex() {
+        assert_eq!(to_hex(Rgba::rgb(37, 99, 235)), "#2563eb");
+        let translucent = Rgba {
+            r: 0,
+            g: 0,
+            b: 0,
+            a: 0.5,
+        };
+        assert_eq!(to_hex(translucent), "#00000080");
+    }
+
+    #[test]
+    fn test_merge_colors() {
+        let colors = merge_colors(vec![
+            usage("rgb(255, 0, 0)", 2, &["p", "span"]),
+            usage("red", 3, &["a", "p"]),
+            usage("rgb(0, 0, 0)", 1, &["body"]),
+            usage("rgba(0, 0, 0, 0)", 40, &["div"]),
+            usage("color(display-p3 1 0 0)", 1, &["h1"]),
+        ]);
+
+        assert_eq!(colors.len(), 2);
+        assert_eq!(colors[0].hex, "#ff0000");
+        assert_eq!(colors[0].css_value, "red");
+        assert_eq!(colors[0].usage_count, 5);
+        assert_eq!(colors[0].element_examples, vec!["p", "span", "a"]);
+        assert_eq!(colors[1].hex, "#000000");
+    }
+
+    #[tokio::test]
+    async fn test_colors_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/colors", get(colors))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("const maxExamples = 3;"));
+                let payload = r#"{"values": [{"value": "rgb(37, 99, 235)", "count": 4, "examples": ["button#save"]}]}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(Request::get("/colors").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["colors"][0]["hex"], "#2563eb");
+        assert_eq!(json["colors"][0]["usage_count"], 4);
+        assert_eq!(json["colors"][0]["element_examples"][0], "button#save");
+    }
+}
@@ -0,0 +1,277 @@
+//! Handlers for collecting JavaScript code coverage.
+//!
+//! The V8 coverage API (`Profiler.takePreciseCoverage`) belongs to the
+//! DevTools protocol and isn't reachable from page scripts, so the app, or a
+//! test harness attached to the webview, provides it through two hooks:
+//!
+//! - `window.__coverage_start()` starts precise coverage (may be async).
+//! - `window.__coverage_report()` returns the `takePreciseCoverage` result,
+//!   either `{ result: [...] }` or just the array (may be async).
+
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+use serde::Deserialize;
+
+use super::eval_json;
+use crate::types::{CoverageResponse, CoverageScript, CoverageStartResponse};
+use crate::BridgeState;
+
+const START_SCRIPT: &str = r#"return (async () => {
+    if (typeof window.__coverage_start !== "function") return JSON.stringify({ missing: true });
+    await window.__coverage_start();
+    return JSON.stringify({ missing: false });
+})()"#;
+
+const REPORT_SCRIPT: &str = r#"return (async () => {
+    if (typeof window.__coverage_report !== "function") return JSON.stringify({ missing: true });
+    const coverage = await window.__coverage_report();
+    return JSON.stringify({ missing: false, result: Array.isArray(coverage) ? coverage : coverage.result });
+})()"#;
+
+/// What the hook scripts return.
+#[derive(Deserialize)]
+struct HookResult {
+    missing: bool,
+    #[serde(default)]
+    result: Vec<V8ScriptCoverage>,
+}
+
+/// A `ScriptCoverage` from the DevTools protocol.
+#[derive(Deserialize)]
+struct V8ScriptCoverage {
+    url: String,
+    functions: Vec<V8FunctionCoverage>,
+}
+
+#[derive(Deserialize)]
+struct V8FunctionCoverage {
+    ranges: Vec<V8Range>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct V8Range {
+    start_offset: u32,
+    end_offset: u32,
+    count: u32,
+}
+
+/// POST /coverage/start - Start collecting coverage through `window.__coverage_start`.
+///
+/// Responds with 501 when the app doesn't define the hook.
+pub async fn coverage_start(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<CoverageStartResponse>, (StatusCode, String)> {
+    let hook: HookResult = eval_json(&state, START_SCRIPT.to_string()).await?;
+    if hook.missing {
+        return Err(missing_hook("__coverage_start"));
+    }
+    Ok(Json(CoverageStartResponse { started: true }))
+}
+
+/// GET /coverage/report - Executed ranges per script, from `window.__coverage_report`.
+///
+/// Responds with 501 when the app doesn't define the hook.
+pub async fn coverage_report(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<CoverageResponse>, (StatusCode, String)> {
+    let hook: HookResult = eval_json(&state, REPORT_SCRIPT.to_string()).await?;
+    if hook.missing {
+        return Err(missing_hook("__coverage_report"));
+    }
+    let scripts = hook.result.iter().map(summarize_script).collect();
+    Ok(Json(CoverageResponse { scripts }))
+}
+
+fn missing_hook(name: &str) -> (StatusCode, String) {
+    (
+        StatusCode::NOT_IMPLEMENTED,
+        format!(
+            "window.{} is not defined; the app must expose the V8 coverage API",
+            name
+        ),
+    )
+}
+
+fn summarize_script(script: &V8ScriptCoverage) -> CoverageScript {
+    let ranges: Vec<V8Range> = script
+        .functions
+        .iter()
+        .flat_map(|f| f.ranges.iter().copied())
+        .collect();
+    let covered_ranges = covered_ranges(&ranges);
+    CoverageScript {
+        url: script.url.clone(),
+        covered_bytes: covered_ranges.iter().map(|[start, end]| end - start).sum(),
+        // The first function is the whole script, so the widest range is its size
+        total_bytes: ranges.iter().map(|r| r.end_offset).max().unwrap_or(0),
+        covered_ranges,
+    }
+}
+
+/// Flatten nested V8 block ranges into the executed byte ranges.
+///
+/// V8 ranges nest, and each byte's count comes from the innermost range
+/// containing it, so a block that never ran punches a hole in its function.
+fn covered_ranges(ranges: &[V8Range]) -> Vec<[u32; 2]> {
+    let mut sorted: Vec<V8Range> = ranges
+        .iter()
+        .copied()
+        .filter(|r| r.start_offset < r.end_offset)
+        .collect();
+    // Outer ranges before the ranges nested inside them
+    sorted.sort_by(|a, b| {
+        a.start_offset
+            .cmp(&b.start_offset)
+            .then(b.end_offset.cmp(&a.end_offset))
+    });
+
+    let mut points: Vec<u32> = sorted
+        .iter()
+        .flat_map(|r| [r.start_offset, r.end_offset])
+        .collect();
+    points.sort_unstable();
+    points.dedup();
+
+    let mut covered: Vec<[u32; 2]> = Vec::new();
+    let mut stack: Vec<V8Range> = Vec::new();
+    let mut next = sorted.iter().peekable();
+    for window in points.windows(2) {
+        let (start, end) = (window[0], window[1]);
+        while stack.last().is_some_and(|r| r.end_offset <= start) {
+            stack.pop();
+        }
+        while let Some(range) = next.next_if(|r| r.start_offset == start) {
+            stack.push(*range);
+        }
+        if !stack.last().is_some_and(|r| r.count > 0) {
+            continue;
+        }
+        match covered.last_mut() {
+            Some(last) if last[1] == start => last[1] = end,
+            _ => covered.push([start, end]),
+        }
+    }
+    covered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{
+        body::Body,
+        http::Request,
+        routing::{get, post},
+        Router,
+    };
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn range(start_offset: u32, end_offset: u32, count: u32) -> V8Range {
+        V8Range {
+            start_offset,
+            end_offset,
+            count,
+        }
+    }
+
+    #[test]
+    fn test_covered_ranges_punches_holes_for_unexecuted_blocks() {
+        // A script where one function ran, except for one branch, and another never ran
+        let ranges = [
+            range(0, 100, 1),
+            range(10, 40, 2),
+            range(20, 30, 0),
+            range(60, 80, 0),
+        ];
+        assert_eq!(covered_ranges(&ranges), vec![[0, 20], [30, 60], [80, 100]]);
+    }
+
+    #[test]
+    fn test_covered_ranges_uncovered_script() {
+        assert!(covered_ranges(&[range(0, 50, 0)]).is_empty());
+        assert!(covered_ranges(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_summarize_script() {
+        let script = V8ScriptCoverage {
+            url: "http://localhost/app.js".to_string(),
+            functions: vec![
+                V8FunctionCoverage {
+                    ranges: vec![range(0, 100, 1)],
+                },
+                V8FunctionCoverage {
+                    ranges: vec![range(50, 90, 0)],
+                },
+            ],
+        };
+        let summary = summarize_script(&script);
+        assert_eq!(summary.covered_ranges, vec![[0, 50], [90, 100]]);
+        assert_eq!(summary.covered_bytes, 60);
+        assert_eq!(summary.total_bytes, 100);
+    }
+
+    #[tokio::test]
+    async fn test_coverage_report_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/coverage/report", get(coverage_report))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("__coverage_report"));
+                let payload = r#"{"missing": false, "result": [{"scriptId": "1", "url": "app.js",
+                    "functions": [{"functionName": "", "isBlockCoverage": true,
+                    "ranges": [{"startOffset": 0, "endOffset": 10, "count": 1}]}]}]}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::get("/coverage/report")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["scripts"][0]["url"], "app.js");
+        assert_eq!(json["scripts"][0]["covered_ranges"][0][1], 10);
+        assert_eq!(json["scripts"][0]["covered_bytes"], 10);
+    }
+
+    #[tokio::test]
+    async fn test_coverage_start_without_hook() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/coverage/start", post(coverage_start))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                let encoded = serde_json::to_string(r#"{"missing": true}"#).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/coverage/start")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_IMPLEMENTED);
+    }
+}
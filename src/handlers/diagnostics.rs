@@ -0,0 +1,286 @@
+//! Handlers for user-registered health checks and the performance diagnosis.
+
+use std::sync::{Arc, PoisonError};
+
+use axum::{extract::State, http::StatusCode, response::Json};
+use futures_util::future::join_all;
+
+use serde::Deserialize;
+
+use super::{eval_json, send_eval};
+use crate::types::{
+    CheckResult, CustomCheck, CustomCheckRequest, CustomDiagnoseResponse, EvalResponse,
+    PerfDiagnoseResponse, PerfIssue, RegisterCheckResponse,
+};
+use crate::BridgeState;
+
+const SEVERITIES: &[&str] = &["error", "warning", "info"];
+
+/// POST /diagnose/register - Register (or replace) a custom health check.
+pub async fn register_check(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<CustomCheckRequest>,
+) -> Result<Json<RegisterCheckResponse>, (StatusCode, String)> {
+    if req.name.trim().is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Check name must not be empty".to_string(),
+        ));
+    }
+    if !SEVERITIES.contains(&req.severity.as_str()) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!(
+                "Unknown severity '{}', expected one of: {}",
+                req.severity,
+                SEVERITIES.join(", ")
+            ),
+        ));
+    }
+
+    let mut checks = state
+        .custom_checks
+        .write()
+        .unwrap_or_else(PoisonError::into_inner);
+    let check = CustomCheck {
+        name: req.name,
+        script: req.script,
+        severity: req.severity,
+    };
+    match checks.iter_mut().find(|c| c.name == check.name) {
+        Some(existing) => *existing = check,
+        None => checks.push(check),
+    }
+
+    Ok(Json(RegisterCheckResponse {
+        success: true,
+        checks: checks.iter().map(|c| c.name.clone()).collect(),
+    }))
+}
+
+/// GET /diagnose/custom - Run all registered checks concurrently.
+pub async fn run_custom_checks(
+    State(state): State<Arc<BridgeState>>,
+) -> Json<CustomDiagnoseResponse> {
+    let checks = state
+        .custom_checks
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone();
+
+    let responses = join_all(checks.iter().map(|check| {
+        let state = &state;
+        async move {
+            send_eval(state, check.script.clone())
+                .await
+                .unwrap_or_else(|status| EvalResponse::error(status.to_string()))
+        }
+    }))
+    .await;
+
+    let checks = checks
+        .into_iter()
+        .zip(responses)
+        .map(|(check, response)| CheckResult {
+            passed: response.success && response.result.as_deref() == Some("true"),
+            name: check.name,
+            severity: check.severity,
+            response,
+        })
+        .collect();
+
+    Json(CustomDiagnoseResponse { checks })
+}
+
+/// Issues found by the performance script, before scoring.
+#[derive(Deserialize)]
+struct PerfIssues {
+    issues: Vec<PerfIssue>,
+}
+
+/// GET /diagnose/performance - Check for long tasks, layout thrashing, large
+/// DOM trees, animations without `will-change` and `document.write` calls.
+///
+/// Long tasks, layout thrashing and `document.write` are recorded by the
+/// performance tracker installed with the other setup scripts.
+pub async fn diagnose_performance(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<PerfDiagnoseResponse>, (StatusCode, String)> {
+    let script = include_str!("../scripts/perf_diagnose.js").to_string();
+    let PerfIssues { issues } = eval_json(&state, script).await?;
+    Ok(Json(PerfDiagnoseResponse {
+        score: perf_score(&issues),
+        issues,
+    }))
+}
+
+fn perf_score(issues: &[PerfIssue]) -> u8 {
+    let penalty: u32 = issues
+        .iter()
+        .map(|issue| match issue.severity.as_str() {
+            "error" => 25,
+            "warning" => 10,
+            _ => 0,
+        })
+        .sum();
+    100u32.saturating_sub(penalty) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn app(state: Arc<BridgeState>) -> Router {
+        Router::new()
+            .route("/diagnose/register", axum::routing::post(register_check))
+            .route("/diagnose/custom", get(run_custom_checks))
+            .route("/diagnose/performance", get(diagnose_performance))
+            .with_state(state)
+    }
+
+    fn register_request(body: &'static str) -> Request<Body> {
+        Request::post("/diagnose/register")
+            .header("content-type", "application/json")
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_register_replaces_existing_check() {
+        let (state, _rx) = create_test_state();
+
+        for body in [
+            r#"{"name": "badge", "script": "return false", "severity": "info"}"#,
+            r#"{"name": "badge", "script": "return true", "severity": "error"}"#,
+        ] {
+            let response = app(state.clone())
+                .oneshot(register_request(body))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), 200);
+        }
+
+        let checks = state.custom_checks.read().unwrap();
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].script, "return true");
+        assert_eq!(checks[0].severity, "error");
+    }
+
+    #[tokio::test]
+    async fn test_register_rejects_unknown_severity() {
+        let (state, _rx) = create_test_state();
+        let response = app(state)
+            .oneshot(register_request(
+                r#"{"name": "badge", "script": "return true", "severity": "fatal"}"#,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn test_run_custom_checks() {
+        let (state, mut rx) = create_test_state();
+        state.custom_checks.write().unwrap().extend([
+            CustomCheck {
+                name: "header".to_string(),
+                script: "return !!document.querySelector('header')".to_string(),
+                severity: "error".to_string(),
+            },
+            CustomCheck {
+                name: "badge".to_string(),
+                script: "return 'badge missing'".to_string(),
+                severity: "warning".to_string(),
+            },
+        ]);
+
+        tokio::spawn(async move {
+            while let Some(cmd) = rx.recv().await {
+                let result = if cmd.script.contains("header") {
+                    "true"
+                } else {
+                    "\"badge missing\""
+                };
+                let _ = cmd.response_tx.send(EvalResponse::success(result));
+            }
+        });
+
+        let response = app(state)
+            .oneshot(
+                Request::get("/diagnose/custom")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["checks"][0]["name"], "header");
+        assert_eq!(json["checks"][0]["passed"], true);
+        assert_eq!(json["checks"][1]["name"], "badge");
+        assert_eq!(json["checks"][1]["passed"], false);
+        assert_eq!(json["checks"][1]["response"]["result"], "\"badge missing\"");
+    }
+
+    fn issue(severity: &str) -> PerfIssue {
+        PerfIssue {
+            kind: "dom_size".to_string(),
+            severity: severity.to_string(),
+            message: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_perf_score() {
+        assert_eq!(perf_score(&[]), 100);
+        assert_eq!(
+            perf_score(&[issue("error"), issue("warning"), issue("info")]),
+            65
+        );
+        assert_eq!(
+            perf_score(&[
+                issue("error"),
+                issue("error"),
+                issue("error"),
+                issue("error"),
+                issue("warning")
+            ]),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_diagnose_performance() {
+        let (state, mut rx) = create_test_state();
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("__dioxusInspectorPerf__"));
+                let payload = r#"{"issues": [{"kind": "layout_thrashing", "severity": "warning", "message": "3 forced layouts"}]}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app(state)
+            .oneshot(
+                Request::get("/diagnose/performance")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["score"], 90);
+        assert_eq!(json["issues"][0]["kind"], "layout_thrashing");
+    }
+}
@@ -0,0 +1,410 @@
+//! Sending scripts to the app, through the dedup cache and after the
+//! startup scripts.
+
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, PoisonError};
+use std::time::Instant;
+
+use axum::http::StatusCode;
+use serde::de::DeserializeOwned;
+
+use super::channel::dispatch_eval;
+use crate::dedup;
+use crate::types::EvalResponse;
+use crate::BridgeState;
+
+/// Scripts installed in the webview when the bridge starts.
+///
+/// A page reload drops them, so [`reload`](super::reload) and
+/// [`hot_reload`](super::hot_reload) run them again on the new page; each one
+/// is idempotent, so a second run on the same page is harmless.
+const SETUP_SCRIPTS: &[(&str, &str)] = &[
+    (
+        "listener tracker",
+        include_str!("../scripts/track_listeners.js"),
+    ),
+    (
+        "network tracker",
+        include_str!("../scripts/track_network.js"),
+    ),
+    ("error tracker", include_str!("../scripts/track_errors.js")),
+    (
+        "performance tracker",
+        include_str!("../scripts/track_performance.js"),
+    ),
+];
+
+/// Run the [`SETUP_SCRIPTS`] through the eval channel.
+///
+/// The scripts are queued until the app starts polling the receiver.
+/// Failures are logged and don't stop the remaining scripts.
+pub async fn run_setup_scripts(state: Arc<BridgeState>) {
+    for (name, script) in SETUP_SCRIPTS {
+        match send_eval(&state, script.to_string()).await {
+            Ok(resp) if resp.success => tracing::debug!("Installed {}", name),
+            Ok(resp) => tracing::warn!("Failed to install {}: {:?}", name, resp.error),
+            Err(status) => tracing::warn!("Failed to install {}: {}", name, status),
+        }
+    }
+}
+
+/// Send a script and decode its JSON result into `T`.
+///
+/// Script errors and malformed results map to `502 Bad Gateway` with the
+/// error message as the body.
+pub(super) async fn eval_json<T: DeserializeOwned>(
+    state: &BridgeState,
+    script: String,
+) -> Result<T, (StatusCode, String)> {
+    let response = send_eval(state, script)
+        .await
+        .map_err(|status| (status, status.to_string()))?;
+    decode_result(response).map_err(|e| (StatusCode::BAD_GATEWAY, e))
+}
+
+/// Like [`eval_json`], bypassing the dedup cache as [`send_eval_fresh`] does.
+pub(super) async fn eval_json_fresh<T: DeserializeOwned>(
+    state: &BridgeState,
+    script: String,
+) -> Result<T, (StatusCode, String)> {
+    let response = send_eval_fresh(state, script)
+        .await
+        .map_err(|status| (status, status.to_string()))?;
+    decode_result(response).map_err(|e| (StatusCode::BAD_GATEWAY, e))
+}
+
+/// Decode the JSON payload of a successful eval.
+///
+/// Scripts return `JSON.stringify(...)`, which the app passes back as a
+/// JSON-encoded string, so the payload is usually double-encoded.
+pub(super) fn decode_result<T: DeserializeOwned>(response: EvalResponse) -> Result<T, String> {
+    if !response.success {
+        return Err(response
+            .error
+            .unwrap_or_else(|| "Unknown error".to_string()));
+    }
+    let raw = response.result.unwrap_or_default();
+    let json = match serde_json::from_str::<String>(&raw) {
+        Ok(inner) => inner,
+        Err(_) => raw,
+    };
+    serde_json::from_str(&json).map_err(|e| format!("Invalid script result: {}", e))
+}
+
+/// Send a script to the app, reusing the result of an identical read-only
+/// script sent in the last [`DEDUP_TTL`](crate::dedup::DEDUP_TTL).
+pub(super) async fn send_eval(
+    state: &BridgeState,
+    script: String,
+) -> Result<EvalResponse, StatusCode> {
+    send_eval_within(state, script, None).await
+}
+
+/// Like [`send_eval`], waiting `timeout_ms` for the app when set instead of
+/// [`BridgeState::default_eval_timeout_ms`].
+pub(super) async fn send_eval_within(
+    state: &BridgeState,
+    script: String,
+    timeout_ms: Option<u64>,
+) -> Result<EvalResponse, StatusCode> {
+    if !dedup::is_read_only(&script) {
+        // Whatever it changed may make the cached reads stale
+        lock_recent_results(state).clear();
+        return send_eval_fresh_within(state, script, timeout_ms).await;
+    }
+
+    let hash = dedup::script_hash(&script);
+    if let Some((at, response)) = lock_recent_results(state).get(&hash) {
+        if at.elapsed() < dedup::DEDUP_TTL {
+            return Ok(response.clone());
+        }
+    }
+    let result = send_eval_fresh_within(state, script, timeout_ms).await;
+    if let Ok(response) = &result {
+        if response.success {
+            let mut recent = lock_recent_results(state);
+            recent.retain(|_, (at, _)| at.elapsed() < dedup::DEDUP_TTL);
+            recent.insert(hash, (Instant::now(), response.clone()));
+        }
+    }
+    result
+}
+
+fn lock_recent_results(
+    state: &BridgeState,
+) -> std::sync::MutexGuard<'_, HashMap<u64, (Instant, EvalResponse)>> {
+    state
+        .recent_results
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Send a script to the app, bypassing the dedup cache. For polling loops,
+/// which expect the page to change between identical scripts.
+pub(super) async fn send_eval_fresh(
+    state: &BridgeState,
+    script: String,
+) -> Result<EvalResponse, StatusCode> {
+    send_eval_fresh_within(state, script, None).await
+}
+
+async fn send_eval_fresh_within(
+    state: &BridgeState,
+    script: String,
+    timeout_ms: Option<u64>,
+) -> Result<EvalResponse, StatusCode> {
+    run_startup_scripts(state).await;
+    if state.log_eval_scripts {
+        tracing::trace!("Eval: {}", script);
+    }
+    state.eval_total.fetch_add(1, Ordering::Relaxed);
+    let started = Instant::now();
+    let result = dispatch_eval(state, script.clone(), timeout_ms).await;
+    if !matches!(&result, Ok(response) if response.success) {
+        state.eval_errors_total.fetch_add(1, Ordering::Relaxed);
+    }
+    let recorded = match &result {
+        Ok(response) => response.clone(),
+        Err(status) => EvalResponse::error(status.to_string()),
+    };
+    state.trace.push(&script, &recorded, started.elapsed());
+    state.history.push(script, recorded);
+    result
+}
+
+/// Run [`BridgeState::startup_scripts`] in order, before the first eval only.
+///
+/// Evals that arrive while the scripts run wait for them to finish. A failing
+/// script is logged and the rest still run, so one broken setup script
+/// doesn't block every request.
+async fn run_startup_scripts(state: &BridgeState) {
+    if state.startup_scripts.is_empty() {
+        return;
+    }
+    state
+        .startup_ran
+        .get_or_init(|| dispatch_startup_scripts(state))
+        .await;
+}
+
+async fn dispatch_startup_scripts(state: &BridgeState) {
+    for (i, script) in state.startup_scripts.iter().enumerate() {
+        match dispatch_eval(state, script.clone(), None).await {
+            Ok(response) if response.success => {}
+            Ok(response) => tracing::warn!(
+                "Startup script {} failed: {}",
+                i + 1,
+                response
+                    .error
+                    .unwrap_or_else(|| "Unknown error".to_string())
+            ),
+            Err(status) => tracing::warn!("Startup script {} failed: {}", i + 1, status),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use std::time::Duration;
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn test_startup_scripts_run_once_before_first_eval() {
+        let (eval_tx, mut rx) = mpsc::channel(32);
+        let state = Arc::new(BridgeState {
+            startup_scripts: vec!["window.__a = 1".to_string(), "broken(".to_string()],
+            ..BridgeState::new("test-app".to_string(), eval_tx)
+        });
+
+        let responder = tokio::spawn(async move {
+            let mut scripts = Vec::new();
+            while let Some(cmd) = rx.recv().await {
+                let response = if cmd.script == "broken(" {
+                    EvalResponse::error("SyntaxError")
+                } else {
+                    EvalResponse::success("1")
+                };
+                scripts.push(cmd.script);
+                let _ = cmd.response_tx.send(response);
+            }
+            scripts
+        });
+
+        // The failing startup script doesn't fail the eval
+        let first = send_eval_fresh(&state, "return 1".to_string())
+            .await
+            .unwrap();
+        assert!(first.success);
+        send_eval_fresh(&state, "return 2".to_string())
+            .await
+            .unwrap();
+        drop(state);
+
+        assert_eq!(
+            responder.await.unwrap(),
+            vec!["window.__a = 1", "broken(", "return 1", "return 2"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_startup_scripts_finish_before_concurrent_evals() {
+        let (eval_tx, mut rx) = mpsc::channel(32);
+        let state = Arc::new(BridgeState {
+            startup_scripts: vec!["window.__a = 1".to_string()],
+            ..BridgeState::new("test-app".to_string(), eval_tx)
+        });
+
+        let responder = tokio::spawn(async move {
+            let startup = rx.recv().await.unwrap();
+            assert_eq!(startup.script, "window.__a = 1");
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            // No eval may reach the app before the startup script answered
+            assert!(rx.try_recv().is_err());
+            let _ = startup.response_tx.send(EvalResponse::success("1"));
+
+            let mut scripts = Vec::new();
+            for _ in 0..2 {
+                let cmd = rx.recv().await.unwrap();
+                scripts.push(cmd.script.clone());
+                let _ = cmd.response_tx.send(EvalResponse::success("1"));
+            }
+            scripts.sort();
+            scripts
+        });
+
+        let (first, second) = tokio::join!(
+            send_eval_fresh(&state, "return 1".to_string()),
+            send_eval_fresh(&state, "return 2".to_string()),
+        );
+        assert!(first.unwrap().success);
+        assert!(second.unwrap().success);
+        assert_eq!(responder.await.unwrap(), vec!["return 1", "return 2"]);
+    }
+
+    // decode_result tests
+    #[test]
+    fn test_decode_result_double_encoded() {
+        let resp = EvalResponse::success(r#""{\"a\":1}""#);
+        let value: serde_json::Value = decode_result(resp).unwrap();
+        assert_eq!(value["a"], 1);
+    }
+
+    #[test]
+    fn test_decode_result_plain_json() {
+        let resp = EvalResponse::success(r#"{"a":1}"#);
+        let value: serde_json::Value = decode_result(resp).unwrap();
+        assert_eq!(value["a"], 1);
+    }
+
+    #[test]
+    fn test_decode_result_error() {
+        let resp = EvalResponse::error("ReferenceError");
+        let err = decode_result::<serde_json::Value>(resp).unwrap_err();
+        assert_eq!(err, "ReferenceError");
+    }
+
+    #[test]
+    fn test_decode_result_invalid() {
+        let resp = EvalResponse::success("not json");
+        assert!(decode_result::<serde_json::Value>(resp).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_run_setup_scripts() {
+        let (state, mut rx) = create_test_state();
+
+        let responder = tokio::spawn(async move {
+            let mut scripts = Vec::new();
+            while let Some(cmd) = rx.recv().await {
+                scripts.push(cmd.script);
+                let _ = cmd.response_tx.send(EvalResponse::success("installed"));
+                if scripts.len() == SETUP_SCRIPTS.len() {
+                    break;
+                }
+            }
+            scripts
+        });
+
+        run_setup_scripts(state).await;
+        let scripts = responder.await.unwrap();
+        assert!(scripts[0].contains("__dioxusInspectorListeners__"));
+    }
+
+    #[tokio::test]
+    async fn test_send_eval_counts_evals_and_errors() {
+        let (state, mut rx) = create_test_state();
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                let _ = cmd.response_tx.send(EvalResponse::success("1"));
+            }
+            if let Some(cmd) = rx.recv().await {
+                let _ = cmd.response_tx.send(EvalResponse::error("boom"));
+            }
+            // Dropping the receiver fails the third eval
+        });
+
+        for _ in 0..3 {
+            let _ = send_eval_fresh(&state, "return 1".to_string()).await;
+        }
+        assert_eq!(state.eval_total.load(Ordering::Relaxed), 3);
+        assert_eq!(state.eval_errors_total.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn test_send_eval_reuses_recent_read_only_result() {
+        let (state, mut rx) = create_test_state();
+
+        let responder = tokio::spawn(async move {
+            let mut scripts = Vec::new();
+            while let Some(cmd) = rx.recv().await {
+                scripts.push(cmd.script);
+                let _ = cmd
+                    .response_tx
+                    .send(EvalResponse::success(scripts.len().to_string()));
+            }
+            scripts
+        });
+
+        let read = "return document.title;".to_string();
+        let first = send_eval(&state, read.clone()).await.unwrap();
+        let second = send_eval(&state, read.clone()).await.unwrap();
+        assert_eq!(first.result, second.result);
+
+        // Mutating scripts always run and invalidate the cached read
+        let click = "return document.querySelector('button').click();".to_string();
+        send_eval(&state, click.clone()).await.unwrap();
+        send_eval(&state, click.clone()).await.unwrap();
+        let third = send_eval(&state, read.clone()).await.unwrap();
+        assert_eq!(third.result.as_deref(), Some("4"));
+
+        drop(state);
+        let scripts = responder.await.unwrap();
+        assert_eq!(scripts, vec![read.clone(), click.clone(), click, read]);
+    }
+
+    #[tokio::test]
+    async fn test_send_eval_ignores_expired_result() {
+        let (state, mut rx) = create_test_state();
+        let script = "return document.title;".to_string();
+        let expired = Instant::now()
+            .checked_sub(crate::dedup::DEDUP_TTL * 2)
+            .unwrap();
+        state.recent_results.lock().unwrap().insert(
+            crate::dedup::script_hash(&script),
+            (expired, EvalResponse::success("stale")),
+        );
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                let _ = cmd.response_tx.send(EvalResponse::success("fresh"));
+            }
+        });
+
+        let response = send_eval(&state, script).await.unwrap();
+        assert_eq!(response.result.as_deref(), Some("fresh"));
+    }
+}
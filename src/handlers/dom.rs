@@ -0,0 +1,265 @@
+//! Handler for reading the DOM tree.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Json, Response},
+};
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{eval_json, send_eval};
+use crate::dom_page::{self, DEFAULT_DOM_PAGE_SIZE, DEFAULT_PAGED_MAX_NODES, MAX_DOM_PAGE_SIZE};
+use crate::template::ScriptTemplate;
+use crate::types::DomPageResponse;
+use crate::BridgeState;
+
+/// Query parameters for DOM endpoint.
+#[derive(Debug, Default, Deserialize)]
+pub struct DomQuery {
+    pub depth: Option<u32>,
+    pub max_nodes: Option<u32>,
+    pub selector: Option<String>,
+    /// Descend into open shadow roots, listed under each host's `shadow_root`.
+    pub include_shadow: Option<bool>,
+    /// Where to continue a paged read, from the previous page's `next_cursor`.
+    pub cursor: Option<String>,
+    /// Nodes per page; setting this or `cursor` returns a flat, paged list.
+    pub page_size: Option<u32>,
+}
+
+/// GET /dom - Get simplified DOM tree.
+///
+/// With `cursor` or `page_size`, the tree is flattened and returned a page
+/// at a time as a [`DomPageResponse`], with `max_nodes` defaulting to
+/// [`DEFAULT_PAGED_MAX_NODES`] instead of 500.
+pub async fn dom(
+    State(state): State<Arc<BridgeState>>,
+    Query(query): Query<DomQuery>,
+) -> Result<Response, (StatusCode, String)> {
+    if query.cursor.is_some() || query.page_size.is_some() {
+        return Ok(Json(dom_page(&state, query).await?).into_response());
+    }
+    let script = build_dom_script(&query);
+    let response = send_eval(&state, script)
+        .await
+        .map_err(|status| (status, status.to_string()))?;
+    Ok(Json(response).into_response())
+}
+
+async fn dom_page(
+    state: &BridgeState,
+    query: DomQuery,
+) -> Result<DomPageResponse, (StatusCode, String)> {
+    let bad_request = |e: String| (StatusCode::BAD_REQUEST, e);
+    let offset = match &query.cursor {
+        Some(cursor) => dom_page::decode_cursor(cursor).map_err(bad_request)?,
+        None => 0,
+    };
+    let page_size = query.page_size.unwrap_or(DEFAULT_DOM_PAGE_SIZE);
+    if page_size == 0 || page_size > MAX_DOM_PAGE_SIZE {
+        return Err(bad_request(format!(
+            "page_size must be between 1 and {}",
+            MAX_DOM_PAGE_SIZE
+        )));
+    }
+
+    let query = DomQuery {
+        max_nodes: Some(query.max_nodes.unwrap_or(DEFAULT_PAGED_MAX_NODES)),
+        ..query
+    };
+    let result: Value = eval_json(state, build_dom_script(&query)).await?;
+    if let Some(error) = result.get("error").and_then(Value::as_str) {
+        return Err((StatusCode::BAD_GATEWAY, error.to_string()));
+    }
+    let root = result
+        .get("root")
+        .filter(|root| !root.is_null())
+        .ok_or_else(|| {
+            (
+                StatusCode::BAD_GATEWAY,
+                "DOM script returned no root".to_string(),
+            )
+        })?;
+    Ok(dom_page::page(dom_page::flatten(root), offset, page_size))
+}
+
+pub(super) fn build_dom_script(query: &DomQuery) -> String {
+    ScriptTemplate::new(include_str!("../scripts/dom.js")).render_json(HashMap::from([
+        ("max_depth", Value::from(query.depth.unwrap_or(10))),
+        ("max_nodes", Value::from(query.max_nodes.unwrap_or(500))),
+        ("selector", serde_json::json!(query.selector)),
+        (
+            "include_shadow",
+            Value::from(query.include_shadow.unwrap_or(false)),
+        ),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_build_dom_script_include_shadow() {
+        let query = DomQuery {
+            include_shadow: Some(true),
+            ..DomQuery::default()
+        };
+        assert!(build_dom_script(&query).contains("INCLUDE_SHADOW = true"));
+    }
+
+    // DomQuery tests
+    #[test]
+    fn test_dom_query_default() {
+        let query = DomQuery::default();
+        assert!(query.depth.is_none());
+        assert!(query.max_nodes.is_none());
+        assert!(query.selector.is_none());
+    }
+
+    #[test]
+    fn test_dom_query_deserialize_empty() {
+        let json = "{}";
+        let query: DomQuery = serde_json::from_str(json).unwrap();
+        assert!(query.depth.is_none());
+        assert!(query.max_nodes.is_none());
+        assert!(query.selector.is_none());
+    }
+
+    #[test]
+    fn test_dom_query_deserialize_full() {
+        let json = r#"{"depth": 5, "max_nodes": 100, "selector": ".container"}"#;
+        let query: DomQuery = serde_json::from_str(json).unwrap();
+        assert_eq!(query.depth, Some(5));
+        assert_eq!(query.max_nodes, Some(100));
+        assert_eq!(query.selector, Some(".container".to_string()));
+    }
+
+    #[test]
+    fn test_dom_query_deserialize_partial() {
+        let json = r#"{"depth": 3}"#;
+        let query: DomQuery = serde_json::from_str(json).unwrap();
+        assert_eq!(query.depth, Some(3));
+        assert!(query.max_nodes.is_none());
+        assert!(query.selector.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_dom_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new().route("/dom", get(dom)).with_state(state);
+
+        // Spawn responder
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                // Verify script has default values
+                assert!(cmd.script.contains("MAX_DEPTH = 10"));
+                assert!(cmd.script.contains("MAX_NODES = 500"));
+                assert!(cmd.script.contains("INCLUDE_SHADOW = false"));
+                let _ = cmd
+                    .response_tx
+                    .send(EvalResponse::success(r#"{"tag":"body"}"#));
+            }
+        });
+
+        let response = app
+            .oneshot(Request::get("/dom").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn test_dom_handler_with_params() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new().route("/dom", get(dom)).with_state(state);
+
+        // Spawn responder
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                // Verify script has custom values
+                assert!(cmd.script.contains("MAX_DEPTH = 5"));
+                assert!(cmd.script.contains("MAX_NODES = 100"));
+                assert!(cmd.script.contains("\".container\""));
+                let _ = cmd
+                    .response_tx
+                    .send(EvalResponse::success(r#"{"tag":"div"}"#));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::get("/dom?depth=5&max_nodes=100&selector=.container")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn test_dom_handler_paged() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new().route("/dom", get(dom)).with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("MAX_NODES = 50000"));
+                let payload = serde_json::json!({
+                    "root": {"tag": "body", "children": [{"tag": "h1", "children": [{"text": "Hi"}]}, {"tag": "p"}]},
+                    "stats": {"nodeCount": 4, "maxNodes": 50000, "maxDepth": 10, "truncated": false}
+                });
+                let encoded = serde_json::to_string(&payload.to_string()).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let cursor = dom_page::encode_cursor(1);
+        let response = app
+            .oneshot(
+                Request::get(format!("/dom?page_size=2&cursor={}", cursor))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["total_nodes"], 4);
+        assert_eq!(json["has_more"], true);
+        assert_eq!(json["next_cursor"], dom_page::encode_cursor(3));
+        assert_eq!(json["nodes"][0]["tag"], "h1");
+        assert_eq!(json["nodes"][1]["text"], "Hi");
+        assert_eq!(json["nodes"][1]["parent"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_dom_handler_rejects_bad_cursor() {
+        let (state, _rx) = create_test_state();
+        let app = Router::new().route("/dom", get(dom)).with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::get("/dom?cursor=%21%21")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 400);
+    }
+}
@@ -0,0 +1,280 @@
+//! Handlers for adding and removing DOM elements, e.g. test fixtures.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::Json,
+};
+use uuid::Uuid;
+
+use super::eval_json;
+use crate::template::ScriptTemplate;
+use crate::types::{
+    CreateElementRequest, CreateElementResponse, RemoveElementQuery, RemoveElementResponse,
+};
+use crate::BridgeState;
+
+const POSITIONS: &[&str] = &["append", "prepend", "before", "after"];
+const TEST_ID_ATTRIBUTE: &str = "data-test-id";
+
+/// POST /element/create - Insert a new element into, or next to, an
+/// existing one.
+///
+/// Elements created without an `id` get a generated `data-test-id`, so the
+/// response always has a `selector` for finding or removing the element.
+/// Invalid tag names, attribute names and positions are rejected with 400; a
+/// missing parent is reported in `error`.
+pub async fn create_element(
+    State(state): State<Arc<BridgeState>>,
+    Json(mut req): Json<CreateElementRequest>,
+) -> Result<Json<CreateElementResponse>, (StatusCode, String)> {
+    validate_create_request(&req).map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+    let id = req.attributes.get("id").cloned();
+    let (selector, test_id) = match &id {
+        Some(id) => (attribute_selector("id", id), None),
+        None => {
+            let test_id = req
+                .attributes
+                .entry(TEST_ID_ATTRIBUTE.to_string())
+                .or_insert_with(|| format!("dioxus-inspector-{}", Uuid::new_v4().simple()))
+                .clone();
+            (
+                attribute_selector(TEST_ID_ATTRIBUTE, &test_id),
+                Some(test_id),
+            )
+        }
+    };
+
+    let mut response: CreateElementResponse = eval_json(&state, build_create_script(&req)).await?;
+    if response.success {
+        response.id = id;
+        response.test_id = test_id;
+        response.selector = Some(selector);
+    }
+    Ok(Json(response))
+}
+
+/// DELETE /element/remove - Remove every element matching `selector`.
+pub async fn remove_element(
+    State(state): State<Arc<BridgeState>>,
+    Query(query): Query<RemoveElementQuery>,
+) -> Result<Json<RemoveElementResponse>, (StatusCode, String)> {
+    let script = ScriptTemplate::new(include_str!("../scripts/remove_element.js"))
+        .render(HashMap::from([("selector", query.selector.as_str())]));
+    let response = eval_json(&state, script).await?;
+    Ok(Json(response))
+}
+
+fn validate_create_request(req: &CreateElementRequest) -> Result<(), String> {
+    let tag_ok = req.tag.starts_with(|c: char| c.is_ascii_alphabetic())
+        && req
+            .tag
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-');
+    if !tag_ok {
+        return Err(format!("Invalid tag name: '{}'", req.tag));
+    }
+    if let Some(name) = req.attributes.keys().find(|name| !is_attribute_name(name)) {
+        return Err(format!("Invalid attribute name: '{}'", name));
+    }
+    if let Some(position) = req.position.as_deref() {
+        if !POSITIONS.contains(&position) {
+            return Err(format!(
+                "Invalid position '{}', expected one of: {}",
+                position,
+                POSITIONS.join(", ")
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Names `setAttribute` accepts, per the HTML attribute name syntax.
+fn is_attribute_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.chars().any(|c| {
+            c.is_whitespace() || c.is_control() || matches!(c, '"' | '\'' | '>' | '/' | '=')
+        })
+}
+
+fn build_create_script(req: &CreateElementRequest) -> String {
+    let spec = serde_json::json!({
+        "parent_selector": req.parent_selector,
+        "tag": req.tag,
+        "attributes": req.attributes,
+        "text": req.text,
+        "position": req.position.as_deref().unwrap_or("append"),
+    });
+    ScriptTemplate::new(include_str!("../scripts/create_element.js"))
+        .render_json(HashMap::from([("spec", spec)]))
+}
+
+/// `[name="value"]`, with `value` escaped as a CSS string.
+fn attribute_selector(name: &str, value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' | '\\' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            // Newlines can't appear in CSS strings unescaped
+            '\n' => escaped.push_str("\\a "),
+            _ => escaped.push(c),
+        }
+    }
+    format!("[{}=\"{}\"]", name, escaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{
+        body::Body,
+        http::Request,
+        routing::{delete, post},
+        Router,
+    };
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn create_request(tag: &str, attributes: &[(&str, &str)]) -> CreateElementRequest {
+        CreateElementRequest {
+            parent_selector: "main".to_string(),
+            tag: tag.to_string(),
+            attributes: attributes
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            text: None,
+            position: None,
+        }
+    }
+
+    async fn post_create(body: serde_json::Value) -> (u16, serde_json::Value, String) {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/element/create", post(create_element))
+            .with_state(state);
+
+        let script = tokio::spawn(async move {
+            let cmd = rx.recv().await?;
+            let encoded = serde_json::to_string(r#"{"success": true}"#).unwrap();
+            let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            Some(cmd.script)
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/element/create")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status().as_u16();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json = serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null);
+        (status, json, script.await.unwrap().unwrap_or_default())
+    }
+
+    #[test]
+    fn test_validate_create_request() {
+        assert!(validate_create_request(&create_request("my-widget", &[("class", "a")])).is_ok());
+        assert!(validate_create_request(&create_request("1div", &[])).is_err());
+        assert!(validate_create_request(&create_request("div><script", &[])).is_err());
+        assert!(validate_create_request(&create_request("div", &[("on click", "x")])).is_err());
+
+        let mut req = create_request("div", &[]);
+        req.position = Some("inside".to_string());
+        assert!(validate_create_request(&req).is_err());
+    }
+
+    #[test]
+    fn test_attribute_selector() {
+        assert_eq!(attribute_selector("id", "main"), r#"[id="main"]"#);
+        assert_eq!(attribute_selector("id", r#"a"b\c"#), r#"[id="a\"b\\c"]"#);
+    }
+
+    #[test]
+    fn test_build_create_script() {
+        let mut req = create_request("div", &[("class", "toast")]);
+        req.text = Some("{{selector}}".to_string());
+        let script = build_create_script(&req);
+        assert!(script.contains(r#""attributes":{"class":"toast"}"#));
+        assert!(script.contains(r#""position":"append""#));
+        assert!(script.contains(r#""text":"{{selector}}""#));
+    }
+
+    #[tokio::test]
+    async fn test_create_element_with_id() {
+        let (status, json, _) = post_create(serde_json::json!({
+            "parent_selector": "main", "tag": "div", "attributes": {"id": "fixture"}
+        }))
+        .await;
+        assert_eq!(status, 200);
+        assert_eq!(json["id"], "fixture");
+        assert_eq!(json["selector"], r#"[id="fixture"]"#);
+        assert!(json.get("test_id").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_create_element_generates_test_id() {
+        let (status, json, script) = post_create(serde_json::json!({
+            "parent_selector": "main", "tag": "div", "position": "before"
+        }))
+        .await;
+        assert_eq!(status, 200);
+        let test_id = json["test_id"].as_str().unwrap();
+        assert!(test_id.starts_with("dioxus-inspector-"));
+        assert_eq!(json["selector"], format!(r#"[data-test-id="{}"]"#, test_id));
+        assert!(script.contains(&format!(r#""data-test-id":"{}""#, test_id)));
+        assert!(script.contains(r#""position":"before""#));
+    }
+
+    #[tokio::test]
+    async fn test_create_element_rejects_invalid_tag() {
+        let (status, _, script) = post_create(serde_json::json!({
+            "parent_selector": "main", "tag": "<img>"
+        }))
+        .await;
+        assert_eq!(status, 400);
+        assert!(script.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_remove_element() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/element/remove", delete(remove_element))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains(r#"querySelectorAll(".toast")"#));
+                let encoded = serde_json::to_string(r#"{"removed": 2}"#).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::delete("/element/remove?selector=.toast")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["removed"], 2);
+    }
+}
@@ -0,0 +1,280 @@
+//! Handlers that run scripts in the webview.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+
+use super::{send_eval, send_eval_within};
+use crate::template::ScriptTemplate;
+use crate::types::{
+    AsyncEvalRequest, ConditionalEvalRequest, EvalRequest, EvalResponse, ModuleEvalRequest,
+};
+use crate::BridgeState;
+
+/// POST /eval - Execute JavaScript.
+pub async fn eval(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<EvalRequest>,
+) -> Result<Json<EvalResponse>, StatusCode> {
+    let response = send_eval(&state, req.script).await?;
+    Ok(Json(response))
+}
+
+/// POST /eval/conditional - Execute JavaScript only if a selector matches.
+pub async fn eval_conditional(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<ConditionalEvalRequest>,
+) -> Result<Json<EvalResponse>, StatusCode> {
+    let script = build_conditional_script(
+        &req.condition_selector,
+        &req.script,
+        req.else_script.as_deref(),
+    );
+    let response = send_eval(&state, script).await?;
+    Ok(Json(response))
+}
+
+fn build_conditional_script(selector: &str, script: &str, else_script: Option<&str>) -> String {
+    let selector_json = serde_json::to_string(selector).unwrap_or_else(|_| "\"\"".to_string());
+    format!(
+        "if (document.querySelector({})) {{\n{}\n}} else {{\n{}\n}}",
+        selector_json,
+        script,
+        else_script.unwrap_or("return null;")
+    )
+}
+
+/// POST /eval/module - Execute JavaScript as an ES module.
+///
+/// The code is imported from a blob URL, which is revoked afterwards. The
+/// result is the module's default export.
+pub async fn eval_module(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<ModuleEvalRequest>,
+) -> Result<Json<EvalResponse>, StatusCode> {
+    let response = send_eval(&state, build_module_script(&req.code)).await?;
+    Ok(Json(response))
+}
+
+fn build_module_script(code: &str) -> String {
+    ScriptTemplate::new(include_str!("../scripts/eval_module.js"))
+        .render(HashMap::from([("code", code)]))
+}
+
+/// POST /eval/async - Execute JavaScript that may `await`, returning what it resolves to.
+pub async fn eval_async(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<AsyncEvalRequest>,
+) -> Result<Json<EvalResponse>, StatusCode> {
+    let script = build_async_script(&req.script, req.timeout_ms);
+    let response = send_eval_within(&state, script, req.timeout_ms).await?;
+    Ok(Json(response))
+}
+
+/// Wrap `script` in an async function; `document::eval` awaits the returned promise.
+pub(super) fn build_async_script(script: &str, timeout_ms: Option<u64>) -> String {
+    let body = format!("(async () => {{\n{}\n}})()", script);
+    match timeout_ms {
+        None => format!("return {};", body),
+        Some(ms) => format!(
+            "return Promise.race([\n{},\nnew Promise((_, reject) => setTimeout(() => reject(new Error(\"Script timed out after {}ms\")), {})),\n]);",
+            body, ms, ms
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use axum::{body::Body, http::Request, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_build_conditional_script() {
+        let script = build_conditional_script("#spinner", "return 1;", Some("return 2;"));
+        assert_eq!(
+            script,
+            "if (document.querySelector(\"#spinner\")) {\nreturn 1;\n} else {\nreturn 2;\n}"
+        );
+
+        let script = build_conditional_script("#spinner", "return 1;", None);
+        assert!(script.ends_with("} else {\nreturn null;\n}"));
+    }
+
+    #[test]
+    fn test_build_module_script() {
+        let script = build_module_script("export default `a\"b`;");
+        assert!(script.contains(r#"new Blob(["export default `a\"b`;"]"#));
+        assert!(script.contains("await import(url)"));
+        assert!(script.contains("URL.revokeObjectURL(url)"));
+    }
+
+    #[tokio::test]
+    async fn test_eval_module_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/eval/module", axum::routing::post(eval_module))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("export default 42;"));
+                let _ = cmd.response_tx.send(EvalResponse::success("42"));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/eval/module")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"code": "export default 42;"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["result"], "42");
+    }
+
+    #[test]
+    fn test_build_async_script() {
+        let script = build_async_script("return await fetch('/a').then(r => r.status);", None);
+        assert!(script.starts_with("return (async () => {\n"));
+        assert!(script.contains("await fetch('/a')"));
+        assert!(!script.contains("setTimeout"));
+
+        let script = build_async_script("return 1;", Some(250));
+        assert!(script.starts_with("return Promise.race(["));
+        assert!(script.contains("Script timed out after 250ms\")), 250)"));
+    }
+
+    #[tokio::test]
+    async fn test_eval_async_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/eval/async", axum::routing::post(eval_async))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd
+                    .script
+                    .contains("(async () => {\nreturn await 42;\n})()"));
+                let _ = cmd.response_tx.send(EvalResponse::success("42"));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/eval/async")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"script": "return await 42;"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["result"], "42");
+    }
+
+    #[tokio::test]
+    async fn test_eval_handler_success() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/eval", axum::routing::post(eval))
+            .with_state(state);
+
+        // Spawn responder
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                let _ = cmd.response_tx.send(EvalResponse::success("42"));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/eval")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"script": "return 42"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(json["success"], true);
+        assert_eq!(json["result"], "42");
+    }
+
+    #[tokio::test]
+    async fn test_eval_handler_error() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/eval", axum::routing::post(eval))
+            .with_state(state);
+
+        // Spawn responder with error
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                let _ = cmd.response_tx.send(EvalResponse::error("Script failed"));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/eval")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"script": "invalid"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(json["success"], false);
+        assert_eq!(json["error"], "Script failed");
+    }
+
+    #[tokio::test]
+    async fn test_eval_handler_channel_closed() {
+        let (state, rx) = create_test_state();
+        // Drop receiver to simulate closed channel
+        drop(rx);
+
+        let app = Router::new()
+            .route("/eval", axum::routing::post(eval))
+            .with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::post("/eval")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"script": "return 42"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        // Should return 503 Service Unavailable when channel is closed
+        assert_eq!(response.status(), 503);
+    }
+}
@@ -0,0 +1,228 @@
+//! Handlers for recording DOM events on watched elements.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::Json,
+};
+
+use super::{eval_json, eval_json_fresh};
+use crate::template::ScriptTemplate;
+use crate::types::{EventLogQuery, EventLogResponse, EventWatchRequest, EventWatchResponse};
+use crate::BridgeState;
+
+/// POST /event-log/watch - Start recording events of the given types on
+/// elements matching a selector.
+///
+/// Responds with 400 when no event types or a `max_events` of 0 are given.
+pub async fn watch_events(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<EventWatchRequest>,
+) -> Result<Json<EventWatchResponse>, (StatusCode, String)> {
+    if req.events.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "At least one event type is required".to_string(),
+        ));
+    }
+    if req.max_events == 0 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "max_events must be at least 1".to_string(),
+        ));
+    }
+    let response = eval_json(&state, build_watch_events_script(&req)).await?;
+    Ok(Json(response))
+}
+
+/// GET /event-log - List the events recorded since the last read.
+///
+/// Reading empties the log unless `?clear=false` is given, so the read
+/// bypasses the dedup cache, like `/errors`.
+pub async fn event_log(
+    State(state): State<Arc<BridgeState>>,
+    Query(query): Query<EventLogQuery>,
+) -> Result<Json<EventLogResponse>, (StatusCode, String)> {
+    let script = build_read_events_script(query.clear.unwrap_or(true));
+    let response = eval_json_fresh(&state, script).await?;
+    Ok(Json(response))
+}
+
+fn build_watch_events_script(req: &EventWatchRequest) -> String {
+    let spec = serde_json::json!({
+        "selector": req.selector,
+        "events": req.events,
+        "max_events": req.max_events,
+    });
+    ScriptTemplate::new(include_str!("../scripts/event_watch.js"))
+        .render_json(HashMap::from([("spec", spec)]))
+}
+
+fn build_read_events_script(clear: bool) -> String {
+    format!(
+        r#"return (() => {{
+    const log = window.__dioxusInspectorEvents__;
+    if (!log) return JSON.stringify({{ events: [] }});
+    const events = log.events.slice();
+    if ({}) log.events.length = 0;
+    return JSON.stringify({{ events }});
+}})()"#,
+        clear
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{
+        body::Body,
+        http::Request,
+        routing::{get, post},
+        Router,
+    };
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn app(state: Arc<BridgeState>) -> Router {
+        Router::new()
+            .route("/event-log", get(event_log))
+            .route("/event-log/watch", post(watch_events))
+            .with_state(state)
+    }
+
+    #[test]
+    fn test_build_watch_events_script() {
+        let script = build_watch_events_script(&EventWatchRequest {
+            selector: "input[name=\"email\"]".to_string(),
+            events: vec!["input".to_string(), "change".to_string()],
+            max_events: 50,
+        });
+        assert!(script.contains(
+            r#"const spec = {"events":["input","change"],"max_events":50,"selector":"input[name=\"email\"]"};"#
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_watch_events_handler() {
+        let (state, mut rx) = create_test_state();
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains(r##""selector":"#signup""##));
+                let payload = serde_json::json!({
+                    "matched_elements": 1,
+                    "watched_selectors": ["#signup"]
+                });
+                let encoded = serde_json::to_string(&payload.to_string()).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app(state)
+            .oneshot(
+                Request::post("/event-log/watch")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r##"{"selector": "#signup", "events": ["click"]}"##,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["matched_elements"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_watch_events_rejects_empty_events() {
+        let (state, _rx) = create_test_state();
+        let response = app(state)
+            .oneshot(
+                Request::post("/event-log/watch")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r##"{"selector": "#signup", "events": []}"##))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn test_event_log_handler() {
+        let (state, mut rx) = create_test_state();
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("if (false)"));
+                let payload = serde_json::json!({"events": [{
+                    "type": "click",
+                    "target_selector": "form#signup > button",
+                    "timestamp_ms": 1700000000000u64,
+                    "detail": {"x": 10, "y": 20, "button": 0}
+                }]});
+                let encoded = serde_json::to_string(&payload.to_string()).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app(state)
+            .oneshot(
+                Request::get("/event-log?clear=false")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["events"][0]["type"], "click");
+        assert_eq!(json["events"][0]["detail"]["y"], 20);
+    }
+
+    #[tokio::test]
+    async fn test_event_log_repeated_read_is_not_cached() {
+        let (state, mut rx) = create_test_state();
+
+        tokio::spawn(async move {
+            let payloads = [
+                serde_json::json!({"events": [{
+                    "type": "click",
+                    "target_selector": "button",
+                    "timestamp_ms": 1u64,
+                    "detail": {}
+                }]}),
+                serde_json::json!({"events": []}),
+            ];
+            for payload in payloads {
+                if let Some(cmd) = rx.recv().await {
+                    let encoded = serde_json::to_string(&payload.to_string()).unwrap();
+                    let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+                }
+            }
+        });
+
+        let app = app(state);
+        let mut counts = Vec::new();
+        for _ in 0..2 {
+            let response = app
+                .clone()
+                .oneshot(Request::get("/event-log").body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), 200);
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            counts.push(json["events"].as_array().unwrap().len());
+        }
+        assert_eq!(counts, [1, 0]);
+    }
+}
@@ -0,0 +1,198 @@
+//! Handlers for checking which fonts the page is missing.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+use serde::Deserialize;
+
+use super::eval_json;
+use crate::types::MissingFontsResponse;
+use crate::BridgeState;
+
+/// Generic families, CSS-wide keywords and vendor aliases of `system-ui`,
+/// all lowercase. These always resolve to some font.
+const GENERIC_FAMILIES: &[&str] = &[
+    "serif",
+    "sans-serif",
+    "monospace",
+    "cursive",
+    "fantasy",
+    "system-ui",
+    "ui-serif",
+    "ui-sans-serif",
+    "ui-monospace",
+    "ui-rounded",
+    "math",
+    "emoji",
+    "fangsong",
+    "-apple-system",
+    "blinkmacsystemfont",
+    "inherit",
+    "initial",
+    "unset",
+    "revert",
+];
+
+/// What the fonts script found on the page.
+#[derive(Debug, Deserialize)]
+struct FontUsage {
+    /// Computed `font-family` lists in use.
+    used: Vec<String>,
+    /// `document.fonts` entries.
+    faces: Vec<FontFaceInfo>,
+    /// Families that render differently from the generic fallbacks.
+    installed: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FontFaceInfo {
+    family: String,
+    /// `unloaded`, `loading`, `loaded` or `error`.
+    status: String,
+}
+
+/// GET /fonts/missing - Compare the families used in computed styles against
+/// the fonts that are loaded or installed.
+pub async fn missing_fonts(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<MissingFontsResponse>, (StatusCode, String)> {
+    let usage: FontUsage =
+        eval_json(&state, include_str!("../scripts/fonts.js").to_string()).await?;
+    Ok(Json(classify_fonts(&usage)))
+}
+
+/// Family name without quotes, e.g. `"Open Sans"` becomes `Open Sans`.
+fn unquote(family: &str) -> &str {
+    let family = family.trim();
+    family
+        .strip_prefix('"')
+        .and_then(|f| f.strip_suffix('"'))
+        .or_else(|| family.strip_prefix('\'').and_then(|f| f.strip_suffix('\'')))
+        .unwrap_or(family)
+}
+
+fn classify_fonts(usage: &FontUsage) -> MissingFontsResponse {
+    // Family names match case-insensitively; keep the first spelling seen
+    let mut used: BTreeMap<String, String> = BTreeMap::new();
+    for list in &usage.used {
+        for family in list.split(',').map(unquote) {
+            let key = family.to_lowercase();
+            if family.is_empty() || GENERIC_FAMILIES.contains(&key.as_str()) {
+                continue;
+            }
+            used.entry(key).or_insert_with(|| family.to_string());
+        }
+    }
+
+    // A family can have several faces (weights, styles); it's usable once
+    // any of them has loaded, and still loading while any is pending
+    let mut faces: HashMap<String, Vec<&str>> = HashMap::new();
+    for face in &usage.faces {
+        faces
+            .entry(unquote(&face.family).to_lowercase())
+            .or_default()
+            .push(face.status.as_str());
+    }
+    let installed: HashSet<String> = usage
+        .installed
+        .iter()
+        .map(|f| unquote(f).to_lowercase())
+        .collect();
+
+    let mut response = MissingFontsResponse {
+        missing: Vec::new(),
+        loading: Vec::new(),
+        loaded: Vec::new(),
+    };
+    for (key, family) in used {
+        let list = match faces.get(&key) {
+            Some(statuses) if statuses.contains(&"loaded") => &mut response.loaded,
+            Some(statuses) if statuses.iter().any(|s| *s == "loading" || *s == "unloaded") => {
+                &mut response.loading
+            }
+            // No face loaded, but a local copy still renders
+            _ if installed.contains(&key) => &mut response.loaded,
+            _ => &mut response.missing,
+        };
+        list.push(family);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn face(family: &str, status: &str) -> FontFaceInfo {
+        FontFaceInfo {
+            family: family.to_string(),
+            status: status.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_unquote() {
+        assert_eq!(unquote(" \"Open Sans\""), "Open Sans");
+        assert_eq!(unquote("'Inter'"), "Inter");
+        assert_eq!(unquote("Arial"), "Arial");
+    }
+
+    #[test]
+    fn test_classify_fonts() {
+        let usage = FontUsage {
+            used: vec![
+                "\"Inter\", -apple-system, BlinkMacSystemFont, sans-serif".to_string(),
+                "\"Fira Code\", ui-monospace, monospace".to_string(),
+                "Brand Display, serif".to_string(),
+                "inter, Arial".to_string(),
+                "Icons".to_string(),
+            ],
+            faces: vec![
+                face("Inter", "loaded"),
+                face("Inter", "error"),
+                face("\"Fira Code\"", "loading"),
+                face("Icons", "error"),
+                face("Unused", "unloaded"),
+            ],
+            installed: vec!["Arial".to_string()],
+        };
+        let response = classify_fonts(&usage);
+        assert_eq!(response.loaded, vec!["Arial", "Inter"]);
+        assert_eq!(response.loading, vec!["Fira Code"]);
+        assert_eq!(response.missing, vec!["Brand Display", "Icons"]);
+    }
+
+    #[tokio::test]
+    async fn test_missing_fonts_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/fonts/missing", get(missing_fonts))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("document.fonts"));
+                let payload = r#"{"used": ["Roboto, sans-serif"], "faces": [{"family": "Roboto", "status": "error"}], "installed": []}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(Request::get("/fonts/missing").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["missing"], serde_json::json!(["Roboto"]));
+        assert_eq!(json["loaded"], serde_json::json!([]));
+    }
+}
@@ -0,0 +1,154 @@
+//! Handlers for mocking the Geolocation API.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+use serde_json::Value;
+
+use super::eval_json;
+use crate::template::ScriptTemplate;
+use crate::types::{GeoMockRequest, GeoMockResponse};
+use crate::BridgeState;
+
+/// POST /geo/mock - Make `navigator.geolocation` report a fixed position or error.
+///
+/// `getCurrentPosition` and `watchPosition` call back right away (on the next
+/// task, like the real API) without asking for permission. Mocking again
+/// while watches are active sends them the new position.
+pub async fn mock_geolocation(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<GeoMockRequest>,
+) -> Result<Json<GeoMockResponse>, (StatusCode, String)> {
+    validate_geo_mock(&req).map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+    let mock = serde_json::to_value(&req).unwrap_or(Value::Null);
+    let response = eval_json(&state, build_geolocation_script(mock)).await?;
+    Ok(Json(response))
+}
+
+/// DELETE /geo/mock - Restore the real Geolocation API.
+pub async fn clear_geolocation_mock(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<GeoMockResponse>, (StatusCode, String)> {
+    let response = eval_json(&state, build_geolocation_script(Value::Null)).await?;
+    Ok(Json(response))
+}
+
+fn validate_geo_mock(req: &GeoMockRequest) -> Result<(), String> {
+    if !(-90.0..=90.0).contains(&req.latitude) {
+        return Err(format!(
+            "latitude must be between -90 and 90, got {}",
+            req.latitude
+        ));
+    }
+    if !(-180.0..=180.0).contains(&req.longitude) {
+        return Err(format!(
+            "longitude must be between -180 and 180, got {}",
+            req.longitude
+        ));
+    }
+    if !req.accuracy.is_finite() || req.accuracy < 0.0 {
+        return Err(format!("accuracy must be at least 0, got {}", req.accuracy));
+    }
+    match req.error_code {
+        None | Some(1..=3) => Ok(()),
+        Some(code) => Err(format!(
+            "error_code must be 1 (permission denied), 2 (position unavailable) or 3 (timeout), got {}",
+            code
+        )),
+    }
+}
+
+fn build_geolocation_script(mock: Value) -> String {
+    ScriptTemplate::new(include_str!("../scripts/geolocation.js"))
+        .render_json(HashMap::from([("mock", mock)]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::post, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn app() -> (
+        Router,
+        tokio::sync::mpsc::Receiver<crate::types::EvalCommand>,
+    ) {
+        let (state, rx) = create_test_state();
+        let app = Router::new()
+            .route(
+                "/geo/mock",
+                post(mock_geolocation).delete(clear_geolocation_mock),
+            )
+            .with_state(state);
+        (app, rx)
+    }
+
+    #[test]
+    fn test_validate_geo_mock() {
+        let req = |latitude, longitude, error_code| GeoMockRequest {
+            latitude,
+            longitude,
+            accuracy: 10.0,
+            error_code,
+        };
+        assert!(validate_geo_mock(&req(52.5, 13.4, None)).is_ok());
+        assert!(validate_geo_mock(&req(0.0, 0.0, Some(1))).is_ok());
+        assert!(validate_geo_mock(&req(91.0, 0.0, None)).is_err());
+        assert!(validate_geo_mock(&req(0.0, f64::NAN, None)).is_err());
+        assert!(validate_geo_mock(&req(0.0, 0.0, Some(4))).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_mock_geolocation_handler() {
+        let (app, mut rx) = app();
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains(r#"const mock = {"accuracy":10.0,"error_code":null,"latitude":52.52,"longitude":13.405};"#));
+                let encoded = serde_json::to_string(r#"{"mocked": true}"#).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/geo/mock")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::json!({ "latitude": 52.52, "longitude": 13.405, "accuracy": 10.0 })
+                            .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["mocked"], true);
+    }
+
+    #[tokio::test]
+    async fn test_clear_geolocation_mock_handler() {
+        let (app, mut rx) = app();
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("const mock = null;"));
+                let encoded = serde_json::to_string(r#"{"mocked": false}"#).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(Request::delete("/geo/mock").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+    }
+}
@@ -0,0 +1,133 @@
+//! Handlers for reading and clearing the eval history and trace.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    response::Json,
+};
+
+use crate::types::{ClearHistoryResponse, HistoryQuery, HistoryResponse, TraceResponse};
+use crate::BridgeState;
+
+/// GET /history - Evals sent through the bridge, oldest first.
+///
+/// `?last=N` returns only the most recent `N` entries.
+pub async fn history(
+    State(state): State<Arc<BridgeState>>,
+    Query(query): Query<HistoryQuery>,
+) -> Json<HistoryResponse> {
+    let entries = match query.last {
+        Some(n) => state.history.last(n),
+        None => state.history.entries(),
+    };
+    Json(HistoryResponse {
+        entries,
+        capacity: state.history.capacity(),
+    })
+}
+
+/// DELETE /history - Forget all recorded evals.
+pub async fn clear_history(State(state): State<Arc<BridgeState>>) -> Json<ClearHistoryResponse> {
+    Json(ClearHistoryResponse {
+        cleared: state.history.clear(),
+    })
+}
+
+/// GET /trace - Timing and outcome of recent evals, oldest first.
+pub async fn trace(State(state): State<Arc<BridgeState>>) -> Json<TraceResponse> {
+    Json(TraceResponse {
+        entries: state.trace.entries(),
+    })
+}
+
+/// DELETE /trace - Forget all traced evals.
+pub async fn clear_trace(State(state): State<Arc<BridgeState>>) -> Json<ClearHistoryResponse> {
+    Json(ClearHistoryResponse {
+        cleared: state.trace.clear(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn app(state: Arc<BridgeState>) -> Router {
+        Router::new()
+            .route("/history", get(history).delete(clear_history))
+            .route("/trace", get(trace).delete(clear_trace))
+            .with_state(state)
+    }
+
+    async fn body_json(response: axum::response::Response) -> serde_json::Value {
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_history_last_and_clear() {
+        let (state, _rx) = create_test_state();
+        for i in 0..3 {
+            state.history.push(
+                format!("return {}", i),
+                EvalResponse::success(i.to_string()),
+            );
+        }
+
+        let response = app(state.clone())
+            .oneshot(Request::get("/history").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let json = body_json(response).await;
+        assert_eq!(json["entries"].as_array().unwrap().len(), 3);
+        assert_eq!(json["capacity"], 100);
+
+        let response = app(state.clone())
+            .oneshot(Request::get("/history?last=2").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let json = body_json(response).await;
+        assert_eq!(json["entries"][0]["script"], "return 1");
+        assert_eq!(json["entries"][1]["script"], "return 2");
+
+        let response = app(state.clone())
+            .oneshot(Request::delete("/history").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(body_json(response).await["cleared"], 3);
+        assert!(state.history.entries().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_trace_and_clear() {
+        let (state, _rx) = create_test_state();
+        state.trace.push(
+            "return 1",
+            &EvalResponse::error("boom"),
+            std::time::Duration::from_millis(2),
+        );
+
+        let response = app(state.clone())
+            .oneshot(Request::get("/trace").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let json = body_json(response).await;
+        assert_eq!(json["entries"][0]["success"], false);
+        assert_eq!(json["entries"][0]["result_preview"], "boom");
+        assert_eq!(json["entries"][0]["duration_ms"], 2.0);
+
+        let response = app(state.clone())
+            .oneshot(Request::delete("/trace").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(body_json(response).await["cleared"], 1);
+        assert!(state.trace.entries().is_empty());
+    }
+}
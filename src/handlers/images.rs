@@ -0,0 +1,74 @@
+//! Handlers for checking the page's images.
+
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+
+use super::eval_json;
+use crate::types::{ImageStatus, ImageStatusResponse};
+use crate::BridgeState;
+
+/// GET /image-status - Load state and `alt` text of every `<img>`, with
+/// counts of broken images and images missing `alt`.
+pub async fn image_status(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<ImageStatusResponse>, (StatusCode, String)> {
+    let images: Vec<ImageStatus> = eval_json(
+        &state,
+        include_str!("../scripts/image_status.js").to_string(),
+    )
+    .await?;
+    let broken_count = images.iter().filter(|i| i.is_broken()).count() as u32;
+    let missing_alt_count = images.iter().filter(|i| i.alt.is_none()).count() as u32;
+    Ok(Json(ImageStatusResponse {
+        images,
+        broken_count,
+        missing_alt_count,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_image_status_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/image-status", get(image_status))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("document.images"));
+                let payload = serde_json::json!([
+                    {"src": "/logo.png", "complete": true, "natural_width": 120, "natural_height": 40,
+                     "current_src": "http://localhost/logo.png", "alt": "Logo", "loading": "auto"},
+                    {"src": "/missing.png", "complete": true, "natural_width": 0, "natural_height": 0,
+                     "current_src": "http://localhost/missing.png", "alt": null, "loading": "auto"},
+                    {"src": "/below-fold.png", "complete": false, "natural_width": 0, "natural_height": 0,
+                     "current_src": "", "alt": "", "loading": "lazy"}
+                ]);
+                let encoded = serde_json::to_string(&payload.to_string()).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(Request::get("/image-status").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["images"].as_array().unwrap().len(), 3);
+        assert_eq!(json["broken_count"], 1);
+        assert_eq!(json["missing_alt_count"], 1);
+    }
+}
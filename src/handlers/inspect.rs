@@ -0,0 +1,181 @@
+//! Handlers that check why elements look the way they do.
+
+use std::collections::HashMap;
+use std::sync::{Arc, PoisonError};
+
+use axum::{extract::State, http::StatusCode, response::Json};
+use serde_json::Value;
+
+use super::send_eval;
+use crate::template::ScriptTemplate;
+use crate::types::{EvalResponse, InspectRequest, ValidateClassesRequest};
+use crate::BridgeState;
+
+/// POST /inspect - Element visibility analysis.
+pub async fn inspect(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<InspectRequest>,
+) -> Result<Json<EvalResponse>, StatusCode> {
+    let script =
+        ScriptTemplate::new(include_str!("../scripts/inspect.js")).render_json(HashMap::from([
+            ("selector", Value::from(req.selector.as_str())),
+            ("include_listeners", Value::from(req.include_listeners)),
+        ]));
+    let response = send_eval(&state, script).await?;
+    Ok(Json(response))
+}
+
+/// POST /validate-classes - Check CSS class availability.
+///
+/// Classes defined by a style from `/inject-css` report its id as `injected`.
+pub async fn validate_classes(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<ValidateClassesRequest>,
+) -> Result<Json<EvalResponse>, StatusCode> {
+    let classes = req.classes.join(" ");
+    let script = ScriptTemplate::new(include_str!("../scripts/validate_classes.js"))
+        .render(HashMap::from([("classes", classes.as_str())]));
+    let response = send_eval(&state, script).await?;
+    Ok(Json(response))
+}
+
+/// GET /diagnose - Quick UI health check.
+pub async fn diagnose(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<EvalResponse>, StatusCode> {
+    let script = include_str!("../scripts/diagnose.js");
+    let response = send_eval(&state, script.to_string()).await?;
+    *state
+        .last_diagnose
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = Some(response.clone());
+    Ok(Json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_inspect_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/inspect", axum::routing::post(inspect))
+            .with_state(state);
+
+        // Spawn responder
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains(".modal"));
+                assert!(cmd.script.contains("INCLUDE_LISTENERS = false"));
+                let _ = cmd
+                    .response_tx
+                    .send(EvalResponse::success(r#"{"visible": true, "rect": {}}"#));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/inspect")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"selector": ".modal"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn test_inspect_handler_with_listeners() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/inspect", axum::routing::post(inspect))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("INCLUDE_LISTENERS = true"));
+                let _ = cmd.response_tx.send(EvalResponse::success(
+                    r#"{"found": true, "event_listeners": ["click"]}"#,
+                ));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/inspect")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r#"{"selector": ".modal", "include_listeners": true}"#,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn test_validate_classes_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/validate-classes", axum::routing::post(validate_classes))
+            .with_state(state);
+
+        // Spawn responder
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("flex"));
+                assert!(cmd.script.contains("p-4"));
+                assert!(cmd.script.contains("\"dioxus-inspector-\""));
+                let _ = cmd.response_tx.send(EvalResponse::success(
+                    r#"{"available": ["flex"], "missing": ["p-4"]}"#,
+                ));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/validate-classes")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"classes": ["flex", "p-4"]}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn test_diagnose_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/diagnose", get(diagnose))
+            .with_state(state);
+
+        // Spawn responder
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                // diagnose.js should be embedded
+                assert!(!cmd.script.is_empty());
+                let _ = cmd
+                    .response_tx
+                    .send(EvalResponse::success(r#"{"healthy": true}"#));
+            }
+        });
+
+        let response = app
+            .oneshot(Request::get("/diagnose").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+    }
+}
@@ -0,0 +1,504 @@
+//! Handlers that simulate user interaction in the webview.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::Json,
+};
+use serde_json::Value;
+
+use super::{eval_json, send_eval};
+use crate::template::ScriptTemplate;
+use crate::types::{
+    DragRequest, EvalResponse, FormFillRequest, FormFillResponse, InputStateQuery,
+    InputStateResponse, ScrollIntoViewRequest, SelectRequest, SelectResponse, TouchPoint,
+    TouchRequest,
+};
+use crate::BridgeState;
+
+/// POST /drag - Simulate an HTML5 drag-and-drop sequence.
+pub async fn drag(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<DragRequest>,
+) -> Result<Json<EvalResponse>, StatusCode> {
+    let script = build_drag_script(&req.from_selector, &req.to_selector);
+    let response = send_eval(&state, script).await?;
+    Ok(Json(response))
+}
+
+fn build_drag_script(from_selector: &str, to_selector: &str) -> String {
+    ScriptTemplate::new(include_str!("../scripts/drag.js")).render(HashMap::from([
+        ("from_selector", from_selector),
+        ("to_selector", to_selector),
+    ]))
+}
+
+/// Touch actions accepted by `POST /touch`.
+const TOUCH_ACTIONS: &[&str] = &["start", "move", "end", "cancel"];
+
+/// POST /touch - Dispatch a `touchstart`, `touchmove`, `touchend` or
+/// `touchcancel` event on an element.
+pub async fn touch(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<TouchRequest>,
+) -> Result<Json<EvalResponse>, (StatusCode, String)> {
+    validate_touch(&req).map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+    let script = build_touch_script(&req.selector, &req.action, &req.touches);
+    let response = send_eval(&state, script)
+        .await
+        .map_err(|status| (status, status.to_string()))?;
+    Ok(Json(response))
+}
+
+fn validate_touch(req: &TouchRequest) -> Result<(), String> {
+    if !TOUCH_ACTIONS.contains(&req.action.as_str()) {
+        return Err(format!(
+            "Unknown action '{}', expected one of: {}",
+            req.action,
+            TOUCH_ACTIONS.join(", ")
+        ));
+    }
+    if req.touches.is_empty() {
+        return Err("At least one touch point is required".to_string());
+    }
+    if let Some(point) = req
+        .touches
+        .iter()
+        .find(|p| !(p.x.is_finite() && p.y.is_finite()))
+    {
+        return Err(format!(
+            "Touch {} has invalid coordinates ({}, {})",
+            point.id, point.x, point.y
+        ));
+    }
+    Ok(())
+}
+
+fn build_touch_script(selector: &str, action: &str, touches: &[TouchPoint]) -> String {
+    ScriptTemplate::new(include_str!("../scripts/touch.js")).render_json(HashMap::from([
+        ("selector", Value::from(selector)),
+        ("action", Value::from(action)),
+        ("touches", serde_json::json!(touches)),
+    ]))
+}
+
+/// POST /scroll-into-view - Scroll an element into the visible viewport.
+pub async fn scroll_into_view(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<ScrollIntoViewRequest>,
+) -> Result<Json<EvalResponse>, StatusCode> {
+    let script = build_scroll_into_view_script(&req);
+    let response = send_eval(&state, script).await?;
+    Ok(Json(response))
+}
+
+fn build_scroll_into_view_script(req: &ScrollIntoViewRequest) -> String {
+    let mut options = serde_json::Map::new();
+    for (key, value) in [
+        ("behavior", &req.behavior),
+        ("block", &req.block),
+        ("inline", &req.inline),
+    ] {
+        if let Some(value) = value {
+            options.insert(key.to_string(), value.clone().into());
+        }
+    }
+
+    ScriptTemplate::new(include_str!("../scripts/scroll_into_view.js")).render_json(HashMap::from(
+        [
+            ("selector", Value::from(req.selector.as_str())),
+            ("options", Value::Object(options)),
+        ],
+    ))
+}
+
+/// POST /select - Select a range of text inside an element.
+pub async fn select_text(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<SelectRequest>,
+) -> Result<Json<SelectResponse>, (StatusCode, String)> {
+    if req.start > req.end {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("start ({}) must not be after end ({})", req.start, req.end),
+        ));
+    }
+    let script = build_select_script(&req.selector, req.start, req.end);
+    let response = eval_json(&state, script).await?;
+    Ok(Json(response))
+}
+
+fn build_select_script(selector: &str, start: usize, end: usize) -> String {
+    ScriptTemplate::new(include_str!("../scripts/select.js")).render_json(HashMap::from([
+        ("selector", Value::from(selector)),
+        ("start", Value::from(start)),
+        ("end", Value::from(end)),
+    ]))
+}
+
+/// POST /form - Fill a form's fields by name and optionally submit it.
+///
+/// Each field gets `input` and `change` events so Dioxus handlers run. The
+/// form is only submitted when every field matched a control.
+pub async fn fill_form(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<FormFillRequest>,
+) -> Result<Json<FormFillResponse>, (StatusCode, String)> {
+    let response = eval_json(&state, build_form_fill_script(&req)).await?;
+    Ok(Json(response))
+}
+
+fn build_form_fill_script(req: &FormFillRequest) -> String {
+    ScriptTemplate::new(include_str!("../scripts/form_fill.js")).render_json(HashMap::from([
+        ("form_selector", Value::from(req.form_selector.as_str())),
+        ("fields", serde_json::json!(req.fields)),
+        ("submit", Value::from(req.submit)),
+    ]))
+}
+
+/// GET /input-state - Read the value and validation state of every form
+/// control, or only those of `form_selector`.
+///
+/// Controls outside the form that belong to it through `form="id"` are
+/// included.
+pub async fn input_state(
+    State(state): State<Arc<BridgeState>>,
+    Query(query): Query<InputStateQuery>,
+) -> Result<Json<InputStateResponse>, (StatusCode, String)> {
+    let script = build_input_state_script(query.form_selector.as_deref());
+    let response = eval_json(&state, script).await?;
+    Ok(Json(response))
+}
+
+fn build_input_state_script(form_selector: Option<&str>) -> String {
+    ScriptTemplate::new(include_str!("../scripts/input_state.js")).render_json(HashMap::from([(
+        "form_selector",
+        serde_json::json!(form_selector),
+    )]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use axum::{
+        body::Body,
+        http::Request,
+        routing::{get, post},
+        Router,
+    };
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_build_drag_script() {
+        let script = build_drag_script(".card", "#done");
+        assert!(script.contains("const fromSelector = \".card\""));
+        assert!(script.contains("const toSelector = \"#done\""));
+        for event in ["dragstart", "dragenter", "dragover", "drop", "dragend"] {
+            assert!(script.contains(event));
+        }
+    }
+
+    #[test]
+    fn test_build_drag_script_escapes_selectors() {
+        let script = build_drag_script("[data-id=\"a\"]", "[data-id=\"b\"]");
+        assert!(script.contains("\\\"a\\\""));
+        assert!(script.contains("\\\"b\\\""));
+    }
+
+    #[tokio::test]
+    async fn test_drag_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new().route("/drag", post(drag)).with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains(".card"));
+                assert!(cmd.script.contains(".column"));
+                let _ = cmd
+                    .response_tx
+                    .send(EvalResponse::success(r#"{"success": true}"#));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/drag")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r#"{"from_selector": ".card", "to_selector": ".column"}"#,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["success"], true);
+    }
+
+    #[test]
+    fn test_build_touch_script() {
+        let touches = [TouchPoint {
+            x: 10.0,
+            y: 20.5,
+            id: 3,
+        }];
+        let script = build_touch_script(".carousel", "end", &touches);
+        assert!(script.contains("const selector = \".carousel\""));
+        assert!(script.contains("const action = \"end\""));
+        assert!(script.contains(r#"const points = [{"id":3,"x":10.0,"y":20.5}]"#));
+    }
+
+    #[tokio::test]
+    async fn test_touch_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new().route("/touch", post(touch)).with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("const action = \"move\""));
+                let _ = cmd.response_tx.send(EvalResponse::success(
+                    r#"{"success": true, "type": "touchmove"}"#,
+                ));
+            }
+        });
+
+        let touch_request = |body: &'static str| {
+            Request::post("/touch")
+                .header("content-type", "application/json")
+                .body(Body::from(body))
+                .unwrap()
+        };
+
+        let response = app
+            .clone()
+            .oneshot(touch_request(
+                r#"{"selector": ".carousel", "action": "move", "touches": [{"x": 50, "y": 10, "id": 0}]}"#,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["success"], true);
+
+        for body in [
+            r#"{"selector": ".carousel", "action": "swipe", "touches": [{"x": 0, "y": 0, "id": 0}]}"#,
+            r#"{"selector": ".carousel", "action": "start", "touches": []}"#,
+        ] {
+            let response = app.clone().oneshot(touch_request(body)).await.unwrap();
+            assert_eq!(response.status(), 400);
+        }
+    }
+
+    #[test]
+    fn test_build_scroll_into_view_script() {
+        let req = ScrollIntoViewRequest {
+            selector: "#pricing".to_string(),
+            behavior: Some("smooth".to_string()),
+            block: None,
+            inline: Some("nearest".to_string()),
+        };
+        let script = build_scroll_into_view_script(&req);
+        assert!(script.contains(r##"document.querySelector("#pricing")"##));
+        assert!(script.contains(r#"el.scrollIntoView({"behavior":"smooth","inline":"nearest"});"#));
+    }
+
+    #[tokio::test]
+    async fn test_scroll_into_view_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/scroll-into-view", post(scroll_into_view))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("scrollIntoView"));
+                let _ = cmd
+                    .response_tx
+                    .send(EvalResponse::success(r#"{"success": true}"#));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/scroll-into-view")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r##"{"selector": "#pricing"}"##))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+    }
+
+    #[test]
+    fn test_build_select_script() {
+        let script = build_select_script("#bio", 2, 7);
+        assert!(script.contains("const selector = \"#bio\""));
+        assert!(script.contains("const start = 2;"));
+        assert!(script.contains("const end = 7;"));
+        assert!(script.contains("setSelectionRange"));
+        assert!(script.contains("setBaseAndExtent"));
+    }
+
+    #[tokio::test]
+    async fn test_select_text_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/select", post(select_text))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                let payload = r#"{"success": true, "selected_text": "Hello"}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/select")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r##"{"selector": "#bio", "start": 0, "end": 5}"##,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["success"], true);
+        assert_eq!(json["selected_text"], "Hello");
+    }
+
+    #[tokio::test]
+    async fn test_select_text_rejects_reversed_range() {
+        let (state, _rx) = create_test_state();
+        let app = Router::new()
+            .route("/select", post(select_text))
+            .with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::post("/select")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r##"{"selector": "#bio", "start": 5, "end": 1}"##,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 400);
+    }
+
+    #[test]
+    fn test_build_form_fill_script() {
+        let req = FormFillRequest {
+            form_selector: "#login".to_string(),
+            fields: HashMap::from([("email".to_string(), "a\"b".to_string())]),
+            submit: true,
+        };
+        let script = build_form_fill_script(&req);
+        assert!(script.contains(r##"document.querySelector("#login")"##));
+        assert!(script.contains(r#"const fields = {"email":"a\"b"};"#));
+        assert!(script.contains("const submit = true;"));
+    }
+
+    #[test]
+    fn test_build_input_state_script() {
+        let script = build_input_state_script(Some("#login"));
+        assert!(script.contains(r##"const formSelector = "#login";"##));
+        let script = build_input_state_script(None);
+        assert!(script.contains("const formSelector = null;"));
+    }
+
+    #[tokio::test]
+    async fn test_input_state_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/input-state", get(input_state))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains(r##"const formSelector = "#signup";"##));
+                let payload = serde_json::json!({"inputs": [{
+                    "name": "email", "id": "email", "type": "email", "value": "a@",
+                    "checked": null, "validity_valid": false,
+                    "validation_message": "Please enter an email address.",
+                    "required": true, "disabled": false
+                }]});
+                let encoded = serde_json::to_string(&payload.to_string()).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::get("/input-state?form_selector=%23signup")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["inputs"][0]["type"], "email");
+        assert_eq!(json["inputs"][0]["validity_valid"], false);
+    }
+
+    #[tokio::test]
+    async fn test_fill_form_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/form", post(fill_form))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                let payload =
+                    r#"{"success": true, "fields_filled": 2, "submitted": true, "error": null}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/form")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r##"{"form_selector": "#login", "fields": {"email": "a@b.c", "password": "x"}, "submit": true}"##,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["fields_filled"], 2);
+        assert_eq!(json["submitted"], true);
+    }
+}
@@ -0,0 +1,447 @@
+//! Handlers for inspecting flexbox and grid layout, box models, ancestors,
+//! stacking contexts, viewport intersection and scroll containers.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::Json,
+};
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::eval_json;
+use crate::template::ScriptTemplate;
+use crate::types::{
+    ComputedLayoutRequest, ComputedLayoutResponse, IntersectionQuery, IntersectionResponse,
+    LayoutInspectRequest, LayoutInspectResponse, ParentChainRequest, ParentChainResponse,
+    ParentNode, ScrollContainersResponse, StackingContext, StackingInspectRequest,
+    StackingResponse, DEFAULT_PARENT_DEPTH,
+};
+use crate::BridgeState;
+
+/// Result of the layout script; `layout` is absent when nothing matched.
+#[derive(Deserialize)]
+struct LayoutResult {
+    found: bool,
+    #[serde(flatten)]
+    layout: Option<LayoutInspectResponse>,
+}
+
+/// POST /inspect/layout - Resolved flex and grid properties of a container
+/// and its direct children.
+///
+/// Responds with 404 when no element matches the selector.
+pub async fn inspect_layout(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<LayoutInspectRequest>,
+) -> Result<Json<LayoutInspectResponse>, (StatusCode, String)> {
+    let script = ScriptTemplate::new(include_str!("../scripts/layout.js"))
+        .render(HashMap::from([("selector", req.selector.as_str())]));
+    let result: LayoutResult = eval_json(&state, script).await?;
+    match result.layout {
+        Some(layout) if result.found => Ok(Json(layout)),
+        _ => Err((
+            StatusCode::NOT_FOUND,
+            format!("Element not found: {}", req.selector),
+        )),
+    }
+}
+
+/// Result of the parent chain script; `chain` is absent when nothing matched.
+#[derive(Deserialize)]
+struct ParentChainResult {
+    found: bool,
+    #[serde(default)]
+    chain: Vec<ParentNode>,
+}
+
+/// POST /inspect/parent - Ancestors of an element with the styles that can
+/// clip or hide it.
+///
+/// Responds with 404 when no element matches the selector.
+pub async fn parent_chain(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<ParentChainRequest>,
+) -> Result<Json<ParentChainResponse>, (StatusCode, String)> {
+    let depth = req.depth.unwrap_or(DEFAULT_PARENT_DEPTH);
+    let script = ScriptTemplate::new(include_str!("../scripts/parent_chain.js")).render_json(
+        HashMap::from([
+            ("selector", Value::from(req.selector.as_str())),
+            ("depth", Value::from(depth)),
+        ]),
+    );
+    let result: ParentChainResult = eval_json(&state, script).await?;
+    if !result.found {
+        return Err((
+            StatusCode::NOT_FOUND,
+            format!("Element not found: {}", req.selector),
+        ));
+    }
+    Ok(Json(ParentChainResponse {
+        chain: result.chain,
+    }))
+}
+
+/// Result of the stacking script; `contexts` is absent when nothing matched.
+#[derive(Deserialize)]
+struct StackingResult {
+    found: bool,
+    #[serde(default)]
+    contexts: Vec<StackingContext>,
+}
+
+/// POST /inspect/stacking - Elements in a subtree that create a stacking
+/// context, with the styles responsible.
+///
+/// Responds with 404 when no element matches the selector.
+pub async fn stacking_contexts(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<StackingInspectRequest>,
+) -> Result<Json<StackingResponse>, (StatusCode, String)> {
+    let script = ScriptTemplate::new(include_str!("../scripts/stacking.js"))
+        .render(HashMap::from([("selector", req.selector.as_str())]));
+    let result: StackingResult = eval_json(&state, script).await?;
+    if !result.found {
+        return Err((
+            StatusCode::NOT_FOUND,
+            format!("Element not found: {}", req.selector),
+        ));
+    }
+    Ok(Json(StackingResponse {
+        contexts: result.contexts,
+    }))
+}
+
+/// GET /intersection - Whether each element matching `?selector=` is inside
+/// the viewport, and how much of it.
+pub async fn intersection(
+    State(state): State<Arc<BridgeState>>,
+    Query(query): Query<IntersectionQuery>,
+) -> Result<Json<IntersectionResponse>, (StatusCode, String)> {
+    if query.selector.trim().is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "selector is required".to_string()));
+    }
+    let script = ScriptTemplate::new(include_str!("../scripts/intersection.js"))
+        .render(HashMap::from([("selector", query.selector.as_str())]));
+    let response = eval_json(&state, script).await?;
+    Ok(Json(response))
+}
+
+/// GET /scroll/all - List every scrollable element with its scroll position.
+///
+/// Elements count when their content overflows and `overflow` lets the user
+/// scroll them (`auto`, `scroll` or `overlay`); the document's scrolling
+/// element counts whenever the page is taller or wider than the window.
+pub async fn scroll_containers(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<ScrollContainersResponse>, (StatusCode, String)> {
+    let script =
+        ScriptTemplate::new(include_str!("../scripts/scroll_containers.js")).render(HashMap::new());
+    let response = eval_json(&state, script).await?;
+    Ok(Json(response))
+}
+
+/// Result of the computed layout script; `layout` is absent when nothing
+/// matched.
+#[derive(Deserialize)]
+struct ComputedLayoutResult {
+    found: bool,
+    #[serde(flatten)]
+    layout: Option<ComputedLayoutResponse>,
+}
+
+/// POST /computed-layout - Resolved box model of an element, plus its flex
+/// and grid values when it is a flex or grid container or item.
+///
+/// Responds with 404 when no element matches the selector.
+pub async fn computed_layout(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<ComputedLayoutRequest>,
+) -> Result<Json<ComputedLayoutResponse>, (StatusCode, String)> {
+    let script = ScriptTemplate::new(include_str!("../scripts/computed_layout.js"))
+        .render(HashMap::from([("selector", req.selector.as_str())]));
+    let result: ComputedLayoutResult = eval_json(&state, script).await?;
+    match result.layout {
+        Some(layout) if result.found => Ok(Json(layout)),
+        _ => Err((
+            StatusCode::NOT_FOUND,
+            format!("Element not found: {}", req.selector),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{
+        body::Body,
+        http::Request,
+        routing::{get, post},
+        Router,
+    };
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn layout_request(selector: &str) -> Request<Body> {
+        Request::post("/inspect/layout")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                serde_json::json!({ "selector": selector }).to_string(),
+            ))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_inspect_layout_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/inspect/layout", post(inspect_layout))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("document.querySelector(\".toolbar\")"));
+                let payload = r#"{"found": true, "display": "flex", "flex_direction": "row", "flex_wrap": "wrap", "grid_template_columns": null, "grid_template_rows": null, "children": [{"selector_path": "div.toolbar > button", "flex_basis": "auto", "flex_grow": "1", "flex_shrink": "1", "grid_column": "auto", "grid_row": "auto"}]}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app.oneshot(layout_request(".toolbar")).await.unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["display"], "flex");
+        assert_eq!(json["flex_wrap"], "wrap");
+        assert!(json["grid_template_columns"].is_null());
+        assert_eq!(json["children"][0]["flex_grow"], "1");
+    }
+
+    #[tokio::test]
+    async fn test_inspect_layout_not_found() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/inspect/layout", post(inspect_layout))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                let encoded = serde_json::to_string(r#"{"found": false}"#).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app.oneshot(layout_request(".missing")).await.unwrap();
+
+        assert_eq!(response.status(), 404);
+    }
+
+    #[tokio::test]
+    async fn test_parent_chain_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/inspect/parent", post(parent_chain))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("chain.length < 3"));
+                let payload = r#"{"found": true, "chain": [{"tag": "div", "id": null, "classes": ["panel"], "display": "block", "overflow": "hidden", "visibility": "visible", "z_index": "auto", "position": "relative"}]}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/inspect/parent")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::json!({ "selector": ".menu", "depth": 3 }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["chain"][0]["overflow"], "hidden");
+        assert!(json["chain"][0]["id"].is_null());
+    }
+
+    #[tokio::test]
+    async fn test_stacking_contexts_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/inspect/stacking", post(stacking_contexts))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("document.querySelector(\"body\")"));
+                let payload = r#"{"found": true, "contexts": [{"selector_path": "div#modal", "z_index": "100", "creates_context_reason": ["position: fixed"], "bounding_rect": {"top": 10, "left": 20, "width": 300, "height": 200}}]}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/inspect/stacking")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::json!({ "selector": "body" }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["contexts"][0]["z_index"], "100");
+        assert_eq!(
+            json["contexts"][0]["creates_context_reason"][0],
+            "position: fixed"
+        );
+        assert_eq!(json["contexts"][0]["bounding_rect"]["width"], 300.0);
+    }
+
+    #[tokio::test]
+    async fn test_scroll_containers_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/scroll/all", get(scroll_containers))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                let payload = r#"{"containers": [{"selector_path": "ul#feed", "scroll_x": 0, "scroll_y": 700, "scroll_width": 320, "scroll_height": 1000, "client_width": 320, "client_height": 300, "is_at_bottom": true}]}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(Request::get("/scroll/all").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["containers"][0]["selector_path"], "ul#feed");
+        assert_eq!(json["containers"][0]["scroll_y"], 700.0);
+        assert_eq!(json["containers"][0]["is_at_bottom"], true);
+    }
+
+    #[tokio::test]
+    async fn test_intersection_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/intersection", get(intersection))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("document.querySelectorAll(\".card\")"));
+                let payload = r#"{"elements": [{"selector_path": "div.card", "in_viewport": true, "intersection_ratio": 0.5, "bounding_rect": {"top": 700, "left": 0, "width": 200, "height": 200}}]}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::get("/intersection?selector=.card")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["elements"][0]["in_viewport"], true);
+        assert_eq!(json["elements"][0]["intersection_ratio"], 0.5);
+
+        let response = app
+            .oneshot(
+                Request::get("/intersection?selector=")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn test_computed_layout_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/computed-layout", post(computed_layout))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("document.querySelector(\".toolbar\")"));
+                let payload = serde_json::json!({
+                    "found": true,
+                    "display": "flex",
+                    "box_model": {
+                        "box_sizing": "content-box",
+                        "content_width": 300, "content_height": 40,
+                        "padding_width": 316, "padding_height": 56,
+                        "border_width": 318, "border_height": 58,
+                        "margin_width": 318, "margin_height": 58,
+                        "padding": {"top": 8, "right": 8, "bottom": 8, "left": 8},
+                        "border": {"top": 1, "right": 1, "bottom": 1, "left": 1},
+                        "margin": {"top": 0, "right": 0, "bottom": 0, "left": 0}
+                    },
+                    "flex": {
+                        "is_container": true, "is_item": false,
+                        "direction": "row", "wrap": "nowrap",
+                        "justify_content": "space-between", "align_items": "center",
+                        "align_content": "normal", "row_gap": "normal", "column_gap": "8px",
+                        "grow": 0, "shrink": 1, "basis": "auto", "align_self": "auto", "order": 0
+                    },
+                    "grid": null
+                });
+                let encoded = serde_json::to_string(&payload.to_string()).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/computed-layout")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"selector": ".toolbar"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["box_model"]["content_width"], 300.0);
+        assert_eq!(json["flex"]["justify_content"], "space-between");
+        assert!(json["grid"].is_null());
+        assert!(json.get("found").is_none());
+    }
+}
@@ -0,0 +1,74 @@
+//! Handler for reading lists.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+
+use super::eval_json;
+use crate::template::ScriptTemplate;
+use crate::types::{ListRequest, ListResponse};
+use crate::BridgeState;
+
+/// POST /list - Extract the items of the first list matching `selector`,
+/// e.g. a navigation menu, an options list or an accordion.
+pub async fn extract_list(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<ListRequest>,
+) -> Result<Json<ListResponse>, (StatusCode, String)> {
+    let script = ScriptTemplate::new(include_str!("../scripts/list.js"))
+        .render(HashMap::from([("selector", req.selector.as_str())]));
+    let response = eval_json(&state, script).await?;
+    Ok(Json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::post, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_extract_list_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/list", post(extract_list))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains(r#"const selector = "nav ul";"#));
+                let payload = serde_json::json!({
+                    "items": [{
+                        "tag": "li", "text": "Home", "href": "http://localhost/",
+                        "data_attributes": {"data-active": "true"}
+                    }],
+                    "list_type": "ul",
+                    "ordered": false
+                });
+                let encoded = serde_json::to_string(&payload.to_string()).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/list")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"selector": "nav ul"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["list_type"], "ul");
+        assert_eq!(json["items"][0]["href"], "http://localhost/");
+        assert_eq!(json["items"][0]["data_attributes"]["data-active"], "true");
+    }
+}
@@ -0,0 +1,163 @@
+//! Handler for timing scripts in the webview.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::eval_json;
+use crate::template::ScriptTemplate;
+use crate::types::{MeasureRequest, MeasureResponse};
+use crate::BridgeState;
+
+/// Runs when [`MeasureRequest::repetitions`] is omitted.
+const DEFAULT_REPETITIONS: u32 = 10;
+/// Most runs one `/measure` request may ask for.
+const MAX_REPETITIONS: u32 = 1_000;
+
+/// What `measure.js` reports.
+#[derive(Deserialize)]
+struct Samples {
+    samples: Vec<f64>,
+}
+
+/// POST /measure - Run a script repeatedly between `performance.mark` and
+/// `performance.measure`, and report its run time statistics.
+///
+/// All runs happen in one eval, back to back, so later runs see what earlier
+/// ones changed.
+pub async fn measure(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<MeasureRequest>,
+) -> Result<Json<MeasureResponse>, (StatusCode, String)> {
+    let repetitions = req.repetitions.unwrap_or(DEFAULT_REPETITIONS);
+    if repetitions == 0 || repetitions > MAX_REPETITIONS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Repetitions must be between 1 and {}", MAX_REPETITIONS),
+        ));
+    }
+    if req.label.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Label must not be empty".to_string(),
+        ));
+    }
+
+    let script = build_measure_script(&req.label, &req.script, repetitions);
+    let Samples { samples } = eval_json(&state, script).await?;
+    Ok(Json(summarize(req.label, samples)))
+}
+
+fn build_measure_script(label: &str, script: &str, repetitions: u32) -> String {
+    // The script is spliced in as code, not a value; it goes in last and
+    // precedes the label in measure.js, so its text is never substituted
+    ScriptTemplate::new(include_str!("../scripts/measure.js"))
+        .render_json(HashMap::from([
+            ("label", Value::from(label)),
+            ("repetitions", Value::from(repetitions)),
+        ]))
+        .replacen("{SCRIPT}", script, 1)
+}
+
+fn summarize(label: String, samples: Vec<f64>) -> MeasureResponse {
+    let count = samples.len().max(1) as f64;
+    let mean_ms = samples.iter().sum::<f64>() / count;
+    let variance = samples.iter().map(|s| (s - mean_ms).powi(2)).sum::<f64>() / count;
+    let min_ms = samples.iter().copied().reduce(f64::min).unwrap_or(0.0);
+    let max_ms = samples.iter().copied().reduce(f64::max).unwrap_or(0.0);
+    MeasureResponse {
+        label,
+        mean_ms,
+        min_ms,
+        max_ms,
+        stddev_ms: variance.sqrt(),
+        samples,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::post, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    async fn measure_with(body: &str) -> (u16, serde_json::Value) {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/measure", post(measure))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("const repetitions = 4;"));
+                let payload = serde_json::json!({ "samples": [2.0, 4.0, 4.0, 6.0] });
+                let encoded = serde_json::to_string(&payload.to_string()).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/measure")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status().as_u16();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json = serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null);
+        (status, json)
+    }
+
+    #[test]
+    fn test_build_measure_script() {
+        let script = build_measure_script("{{repetitions}} {SCRIPT}", "return '{{label}}';", 3);
+        assert!(script.contains("\n    function run() {\nreturn '{{label}}';\n    }"));
+        assert!(script.contains(r#"const label = "{{repetitions}} {SCRIPT}";"#));
+        assert!(script.contains("const repetitions = 3;"));
+    }
+
+    #[test]
+    fn test_summarize() {
+        let stats = summarize("x".to_string(), vec![2.0, 4.0, 4.0, 6.0]);
+        assert_eq!(stats.mean_ms, 4.0);
+        assert_eq!(stats.min_ms, 2.0);
+        assert_eq!(stats.max_ms, 6.0);
+        assert_eq!(stats.stddev_ms, 2.0_f64.sqrt());
+
+        let empty = summarize("x".to_string(), Vec::new());
+        assert_eq!(empty.mean_ms, 0.0);
+        assert_eq!(empty.min_ms, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_measure_handler() {
+        let (status, json) = measure_with(
+            r#"{"label": "render", "script": "document.body.offsetHeight", "repetitions": 4}"#,
+        )
+        .await;
+        assert_eq!(status, 200);
+        assert_eq!(json["label"], "render");
+        assert_eq!(json["mean_ms"], 4.0);
+        assert_eq!(json["samples"], serde_json::json!([2.0, 4.0, 4.0, 6.0]));
+    }
+
+    #[tokio::test]
+    async fn test_measure_rejects_bad_requests() {
+        let (status, _) = measure_with(r#"{"label": "x", "script": "1", "repetitions": 0}"#).await;
+        assert_eq!(status, 400);
+        let (status, _) =
+            measure_with(r#"{"label": "x", "script": "1", "repetitions": 1001}"#).await;
+        assert_eq!(status, 400);
+        let (status, _) = measure_with(r#"{"label": "", "script": "1"}"#).await;
+        assert_eq!(status, 400);
+    }
+}
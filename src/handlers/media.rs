@@ -0,0 +1,201 @@
+//! Handlers for inspecting and controlling `<audio>` and `<video>` elements.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::Json,
+};
+use serde_json::Value;
+
+use super::eval_json;
+use crate::template::ScriptTemplate;
+use crate::types::{MediaControlRequest, MediaQuery, MediaResponse};
+use crate::BridgeState;
+
+const ACTIONS: &[&str] = &["play", "pause", "seek", "mute", "volume"];
+const DEFAULT_SELECTOR: &str = "audio, video";
+
+/// GET /media - Report playback state of media elements.
+///
+/// Defaults to every `<audio>` and `<video>` element when no selector is given.
+pub async fn media(
+    State(state): State<Arc<BridgeState>>,
+    Query(query): Query<MediaQuery>,
+) -> Result<Json<MediaResponse>, (StatusCode, String)> {
+    let selector = query.selector.as_deref().unwrap_or(DEFAULT_SELECTOR);
+    let response = eval_json(&state, build_media_script(selector, None, None)).await?;
+    Ok(Json(response))
+}
+
+/// POST /media/control - Play, pause, seek, mute or set the volume.
+///
+/// The action applies to every matching element. Returns their state
+/// afterwards, or 404 when the selector matches no media element.
+pub async fn control_media(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<MediaControlRequest>,
+) -> Result<Json<MediaResponse>, (StatusCode, String)> {
+    validate_control(&req).map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+    let script = build_media_script(&req.selector, Some(&req.action), req.value);
+    let response: MediaResponse = eval_json(&state, script).await?;
+    if response.media.is_empty() {
+        return Err((
+            StatusCode::NOT_FOUND,
+            format!("No media element matches '{}'", req.selector),
+        ));
+    }
+    Ok(Json(response))
+}
+
+fn validate_control(req: &MediaControlRequest) -> Result<(), String> {
+    if !ACTIONS.contains(&req.action.as_str()) {
+        return Err(format!(
+            "Unknown action '{}', expected one of: {}",
+            req.action,
+            ACTIONS.join(", ")
+        ));
+    }
+    match (req.action.as_str(), req.value) {
+        ("seek" | "volume", None) => Err(format!("Action '{}' requires a value", req.action)),
+        ("seek", Some(v)) if !(v.is_finite() && v >= 0.0) => Err(format!(
+            "Seek position must be a non-negative number, got {}",
+            v
+        )),
+        ("volume", Some(v)) if !(0.0..=1.0).contains(&v) => {
+            Err(format!("Volume must be between 0 and 1, got {}", v))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn build_media_script(selector: &str, action: Option<&str>, value: Option<f64>) -> String {
+    ScriptTemplate::new(include_str!("../scripts/media.js")).render_json(HashMap::from([
+        ("selector", Value::from(selector)),
+        ("action", serde_json::json!(action)),
+        ("value", serde_json::json!(value)),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn app(state: Arc<BridgeState>) -> Router {
+        Router::new()
+            .route("/media", get(media))
+            .route("/media/control", axum::routing::post(control_media))
+            .with_state(state)
+    }
+
+    fn control_request(body: &'static str) -> Request<Body> {
+        Request::post("/media/control")
+            .header("content-type", "application/json")
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    fn control(action: &str, value: Option<f64>) -> MediaControlRequest {
+        MediaControlRequest {
+            selector: "video".to_string(),
+            action: action.to_string(),
+            value,
+        }
+    }
+
+    #[test]
+    fn test_build_media_script() {
+        let script = build_media_script(DEFAULT_SELECTOR, None, None);
+        assert!(script.contains("const selector = \"audio, video\";"));
+        assert!(script.contains("const action = null;"));
+        assert!(script.contains("const value = null;"));
+
+        let script = build_media_script("video#player", Some("seek"), Some(12.5));
+        assert!(script.contains("const action = \"seek\";"));
+        assert!(script.contains("const value = 12.5;"));
+    }
+
+    #[test]
+    fn test_validate_control() {
+        assert!(validate_control(&control("play", None)).is_ok());
+        assert!(validate_control(&control("mute", Some(0.0))).is_ok());
+        assert!(validate_control(&control("volume", Some(0.5))).is_ok());
+        assert!(validate_control(&control("volume", None)).is_err());
+        assert!(validate_control(&control("volume", Some(1.5))).is_err());
+        assert!(validate_control(&control("seek", Some(-1.0))).is_err());
+        assert!(validate_control(&control("rewind", None)).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_media_handler() {
+        let (state, mut rx) = create_test_state();
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("const selector = \"video\";"));
+                let payload = r#"{"media": [{"element": "video#player", "src": "movie.mp4", "current_time": 3.5, "duration": 60, "paused": false, "muted": false, "volume": 1, "buffered": [{"start": 0, "end": 10}], "ready_state": 4, "network_state": 2}]}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app(state)
+            .oneshot(
+                Request::get("/media?selector=video")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["media"][0]["src"], "movie.mp4");
+        assert_eq!(json["media"][0]["buffered"][0]["end"], 10.0);
+    }
+
+    #[tokio::test]
+    async fn test_control_media_no_match() {
+        let (state, mut rx) = create_test_state();
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                let encoded = serde_json::to_string(r#"{"media": []}"#).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app(state)
+            .oneshot(control_request(
+                r##"{"selector": "#missing", "action": "pause"}"##,
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 404);
+    }
+
+    #[tokio::test]
+    async fn test_control_media_rejects_missing_value() {
+        let (state, _rx) = create_test_state();
+
+        let response = app(state)
+            .oneshot(control_request(
+                r#"{"selector": "video", "action": "seek"}"#,
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 400);
+    }
+}
@@ -0,0 +1,123 @@
+//! Handlers that evaluate CSS media queries in the webview.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::Json,
+};
+
+use super::eval_json;
+use crate::types::{
+    MediaQueryAllResponse, MediaQueryParams, MediaQueryResponse, COMMON_BREAKPOINTS,
+};
+use crate::BridgeState;
+
+/// GET /media-query - Whether `?query=` matches, via `window.matchMedia`.
+///
+/// Unparseable queries don't match, as in CSS.
+pub async fn media_query(
+    State(state): State<Arc<BridgeState>>,
+    Query(params): Query<MediaQueryParams>,
+) -> Result<Json<MediaQueryResponse>, (StatusCode, String)> {
+    if params.query.trim().is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "query is required".to_string()));
+    }
+    let matches: HashMap<String, bool> =
+        eval_json(&state, build_media_query_script(&[params.query.as_str()])).await?;
+    Ok(Json(MediaQueryResponse {
+        matches: matches.get(&params.query).copied().unwrap_or(false),
+        query: params.query,
+    }))
+}
+
+/// GET /media-query/all - Which of the [`COMMON_BREAKPOINTS`] match.
+pub async fn media_query_all(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<MediaQueryAllResponse>, (StatusCode, String)> {
+    let matches = eval_json(&state, build_media_query_script(COMMON_BREAKPOINTS)).await?;
+    Ok(Json(MediaQueryAllResponse { matches }))
+}
+
+fn build_media_query_script(queries: &[&str]) -> String {
+    let queries = serde_json::to_string(queries).unwrap_or_else(|_| "[]".to_string());
+    format!(
+        r#"return JSON.stringify(Object.fromEntries({}.map(q => [q, window.matchMedia(q).matches])));"#,
+        queries
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_media_query_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/media-query", get(media_query))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains(r#"["(max-width: 768px)"].map"#));
+                let encoded = serde_json::to_string(r#"{"(max-width: 768px)": true}"#).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::get("/media-query?query=%28max-width%3A%20768px%29")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["matches"], true);
+        assert_eq!(json["query"], "(max-width: 768px)");
+    }
+
+    #[tokio::test]
+    async fn test_media_query_all_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/media-query/all", get(media_query_all))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("(max-width: 1280px)"));
+                let payload = r#"{"(max-width: 640px)": false, "(max-width: 768px)": false, "(max-width: 1024px)": true, "(max-width: 1280px)": true}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::get("/media-query/all")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["matches"]["(max-width: 1024px)"], true);
+        assert_eq!(json["matches"]["(max-width: 640px)"], false);
+    }
+}
@@ -0,0 +1,127 @@
+//! Prometheus scrape endpoint (`metrics` feature).
+
+use std::fmt::Write;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use axum::{extract::State, http::header, response::IntoResponse};
+
+use crate::BridgeState;
+
+/// GET /metrics - Eval queue and counter metrics in Prometheus text format.
+pub async fn metrics(State(state): State<Arc<BridgeState>>) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        render_metrics(&state),
+    )
+}
+
+fn render_metrics(state: &BridgeState) -> String {
+    let app = escape_label(&state.app_name);
+    let queue_depth = state.eval_tx.max_capacity() - state.eval_tx.capacity();
+    let metrics = [
+        (
+            "eval_queue_depth",
+            "gauge",
+            "Eval commands waiting for the app to pick them up.",
+            queue_depth as u64,
+        ),
+        (
+            "eval_overflow_depth",
+            "gauge",
+            "Eval commands held in the overflow buffer.",
+            state.eval_overflow_depth.load(Ordering::Relaxed) as u64,
+        ),
+        (
+            "eval_dropped_total",
+            "counter",
+            "Evals rejected because the app fell behind.",
+            state.eval_dropped_total.load(Ordering::Relaxed),
+        ),
+        (
+            "active_connections",
+            "gauge",
+            "HTTP connections currently open.",
+            u64::from(state.active_connections.load(Ordering::Relaxed)),
+        ),
+        (
+            "requests_total",
+            "counter",
+            "HTTP requests received, including rejected ones.",
+            state.total_requests.load(Ordering::Relaxed),
+        ),
+        (
+            "eval_total",
+            "counter",
+            "Eval commands sent to the app.",
+            state.eval_total.load(Ordering::Relaxed),
+        ),
+        (
+            "eval_errors_total",
+            "counter",
+            "Evals that failed or were dropped without a response.",
+            state.eval_errors_total.load(Ordering::Relaxed),
+        ),
+    ];
+
+    let mut out = String::new();
+    for (name, kind, help, value) in metrics {
+        let _ = writeln!(out, "# HELP {} {}", name, help);
+        let _ = writeln!(out, "# TYPE {} {}", name, kind);
+        let _ = writeln!(out, "{}{{app=\"{}\"}} {}", name, app, value);
+    }
+    out
+}
+
+/// Escape a label value per the Prometheus text exposition format.
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_escape_label() {
+        assert_eq!(escape_label(r#"my "app"\x"#), r#"my \"app\"\\x"#);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_handler() {
+        let (state, _rx) = create_test_state();
+        state.eval_total.store(7, Ordering::Relaxed);
+        state.eval_errors_total.store(2, Ordering::Relaxed);
+        state.total_requests.store(4, Ordering::Relaxed);
+        let app = Router::new()
+            .route("/metrics", get(metrics))
+            .with_state(state);
+
+        let response = app
+            .oneshot(Request::get("/metrics").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+        assert!(response.headers()[header::CONTENT_TYPE]
+            .to_str()
+            .unwrap()
+            .starts_with("text/plain"));
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let text = String::from_utf8(body.to_vec()).unwrap();
+        assert!(text.contains("# TYPE eval_queue_depth gauge\n"));
+        assert!(text.contains("eval_queue_depth{app=\"test-app\"} 0\n"));
+        assert!(text.contains("eval_total{app=\"test-app\"} 7\n"));
+        assert!(text.contains("eval_errors_total{app=\"test-app\"} 2\n"));
+        assert!(text.contains("requests_total{app=\"test-app\"} 4\n"));
+        assert!(text.contains("# TYPE active_connections gauge\n"));
+    }
+}
@@ -0,0 +1,191 @@
+//! Handlers that read data recorded by the bridge's setup trackers.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::Json,
+};
+
+use super::{eval_json, eval_json_fresh};
+use crate::types::{JsErrorsQuery, JsErrorsResponse, NetworkResponse};
+use crate::BridgeState;
+
+const READ_NETWORK_SCRIPT: &str = r#"return (() => {
+    const log = window.__dioxusInspectorNetwork__ || [];
+    return JSON.stringify({ requests: log });
+})()"#;
+
+/// GET /network - List fetch/XHR requests captured since startup.
+pub async fn network(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<NetworkResponse>, (StatusCode, String)> {
+    let response = eval_json(&state, READ_NETWORK_SCRIPT.to_string()).await?;
+    Ok(Json(response))
+}
+
+/// GET /errors - List uncaught errors and unhandled rejections since the
+/// last read.
+///
+/// Reading empties the buffer unless `?clear=false` is given, so the read
+/// bypasses the dedup cache: a repeat within its TTL must see the emptied
+/// buffer, not the errors it already returned.
+pub async fn js_errors(
+    State(state): State<Arc<BridgeState>>,
+    Query(query): Query<JsErrorsQuery>,
+) -> Result<Json<JsErrorsResponse>, (StatusCode, String)> {
+    let script = build_read_errors_script(query.clear.unwrap_or(true));
+    let response = eval_json_fresh(&state, script).await?;
+    Ok(Json(response))
+}
+
+fn build_read_errors_script(clear: bool) -> String {
+    format!(
+        r#"return (() => {{
+    const log = window.__dioxusInspectorErrors__ || [];
+    const errors = log.slice();
+    if ({}) log.length = 0;
+    return JSON.stringify({{ errors }});
+}})()"#,
+        clear
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_build_read_errors_script() {
+        assert!(build_read_errors_script(true).contains("if (true) log.length = 0;"));
+        assert!(build_read_errors_script(false).contains("if (false) log.length = 0;"));
+    }
+
+    #[tokio::test]
+    async fn test_js_errors_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/errors", get(js_errors))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("if (false)"));
+                let payload = r#"{"errors": [{"message": "Uncaught TypeError: x is null", "source": "app.js", "line": 12, "col": 5, "stack": null, "timestamp_ms": 1700000000000}]}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::get("/errors?clear=false")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["errors"][0]["line"], 12);
+        assert_eq!(json["errors"][0]["source"], "app.js");
+    }
+
+    #[tokio::test]
+    async fn test_js_errors_repeated_read_is_not_cached() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/errors", get(js_errors))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            let payloads = [
+                r#"{"errors": [{"message": "boom", "source": null, "line": null, "col": null, "stack": null, "timestamp_ms": 1}]}"#,
+                r#"{"errors": []}"#,
+            ];
+            for payload in payloads {
+                if let Some(cmd) = rx.recv().await {
+                    let encoded = serde_json::to_string(payload).unwrap();
+                    let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+                }
+            }
+        });
+
+        let mut counts = Vec::new();
+        for _ in 0..2 {
+            let response = app
+                .clone()
+                .oneshot(Request::get("/errors").body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), 200);
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            counts.push(json["errors"].as_array().unwrap().len());
+        }
+        // The first read emptied the buffer, so the second finds nothing
+        assert_eq!(counts, [1, 0]);
+    }
+
+    #[tokio::test]
+    async fn test_network_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/network", get(network))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("__dioxusInspectorNetwork__"));
+                // Script results arrive JSON-encoded as a string
+                let payload = r#"{"requests": [{"url": "/api", "method": "GET", "status": 200, "duration_ms": 5.0, "request_body": null, "response_preview": "ok"}]}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(Request::get("/network").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["requests"][0]["url"], "/api");
+        assert_eq!(json["requests"][0]["status"], 200);
+    }
+
+    #[tokio::test]
+    async fn test_network_handler_script_error() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/network", get(network))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                let _ = cmd.response_tx.send(EvalResponse::error("boom"));
+            }
+        });
+
+        let response = app
+            .oneshot(Request::get("/network").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 502);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(&body[..], b"boom");
+    }
+}
@@ -0,0 +1,172 @@
+//! Handlers for logging DOM mutations with a `MutationObserver`.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+use serde_json::Value;
+
+use super::eval_json;
+use crate::template::ScriptTemplate;
+use crate::types::{
+    MutationLogStartRequest, MutationLogStatusResponse, MutationSummaryResponse,
+    DEFAULT_MUTATION_LOG_ENTRIES,
+};
+use crate::BridgeState;
+
+const READ_MUTATIONS_SCRIPT: &str = r#"return (() => {
+    const log = window.__dioxusInspectorMutations__;
+    if (!log) throw new Error("Mutation log not started, POST /mutation-log/start first");
+    const { added_nodes, removed_nodes, attribute_changes, text_changes, entries } = log;
+    return JSON.stringify({ added_nodes, removed_nodes, attribute_changes, text_changes, entries });
+})()"#;
+
+const STOP_MUTATIONS_SCRIPT: &str = r#"return (() => {
+    const log = window.__dioxusInspectorMutations__;
+    if (log) {
+        log.observer.disconnect();
+        delete window.__dioxusInspectorMutations__;
+    }
+    return JSON.stringify({ observing: false });
+})()"#;
+
+/// POST /mutation-log/start - Start recording changes under `<body>`:
+/// children, attributes and text, in the whole subtree.
+///
+/// Starting while already recording clears the log and starts over.
+/// Responds with 400 for a `max_entries` of 0.
+pub async fn start_mutation_log(
+    State(state): State<Arc<BridgeState>>,
+    req: Option<Json<MutationLogStartRequest>>,
+) -> Result<Json<MutationLogStatusResponse>, (StatusCode, String)> {
+    let Json(req) = req.unwrap_or_default();
+    let max_entries = req.max_entries.unwrap_or(DEFAULT_MUTATION_LOG_ENTRIES);
+    if max_entries == 0 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "max_entries must be at least 1".to_string(),
+        ));
+    }
+    let script = ScriptTemplate::new(include_str!("../scripts/mutation_log.js"))
+        .render_json(HashMap::from([("max_entries", Value::from(max_entries))]));
+    let response = eval_json(&state, script).await?;
+    Ok(Json(response))
+}
+
+/// GET /mutation-log - Count and list the changes recorded since the log
+/// was started.
+///
+/// Responds with 502 if the log hasn't been started, or the page has
+/// reloaded since.
+pub async fn mutation_summary(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<MutationSummaryResponse>, (StatusCode, String)> {
+    let response = eval_json(&state, READ_MUTATIONS_SCRIPT.to_string()).await?;
+    Ok(Json(response))
+}
+
+/// DELETE /mutation-log - Stop recording and discard the log.
+pub async fn stop_mutation_log(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<MutationLogStatusResponse>, (StatusCode, String)> {
+    let response = eval_json(&state, STOP_MUTATIONS_SCRIPT.to_string()).await?;
+    Ok(Json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn app(state: Arc<BridgeState>) -> Router {
+        Router::new()
+            .route(
+                "/mutation-log",
+                get(mutation_summary).delete(stop_mutation_log),
+            )
+            .route(
+                "/mutation-log/start",
+                axum::routing::post(start_mutation_log),
+            )
+            .with_state(state)
+    }
+
+    async fn send(app: Router, request: Request<Body>) -> (u16, serde_json::Value) {
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status().as_u16();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json = serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null);
+        (status, json)
+    }
+
+    #[tokio::test]
+    async fn test_start_mutation_log() {
+        let (state, mut rx) = create_test_state();
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("const MAX_ENTRIES = 500;"));
+                let payload = serde_json::json!({ "observing": true });
+                let encoded = serde_json::to_string(&payload.to_string()).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let request = Request::post("/mutation-log/start")
+            .body(Body::empty())
+            .unwrap();
+        let (status, json) = send(app(state), request).await;
+        assert_eq!(status, 200);
+        assert_eq!(json["observing"], true);
+    }
+
+    #[tokio::test]
+    async fn test_start_mutation_log_rejects_zero_entries() {
+        let (state, _rx) = create_test_state();
+        let request = Request::post("/mutation-log/start")
+            .header("content-type", "application/json")
+            .body(Body::from(r#"{"max_entries": 0}"#))
+            .unwrap();
+        let (status, _) = send(app(state), request).await;
+        assert_eq!(status, 400);
+    }
+
+    #[tokio::test]
+    async fn test_mutation_summary_handler() {
+        let (state, mut rx) = create_test_state();
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("__dioxusInspectorMutations__"));
+                let payload = serde_json::json!({
+                    "added_nodes": 3,
+                    "removed_nodes": 1,
+                    "attribute_changes": 1,
+                    "text_changes": 0,
+                    "entries": [{
+                        "kind": "child_list",
+                        "target_selector": "ul#items",
+                        "timestamp_ms": 1700000000000u64,
+                        "added_nodes": 3,
+                        "removed_nodes": 1,
+                        "attribute_name": null,
+                        "old_value": null,
+                        "new_value": null
+                    }]
+                });
+                let encoded = serde_json::to_string(&payload.to_string()).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let request = Request::get("/mutation-log").body(Body::empty()).unwrap();
+        let (status, json) = send(app(state), request).await;
+        assert_eq!(status, 200);
+        assert_eq!(json["added_nodes"], 3);
+        assert_eq!(json["entries"][0]["kind"], "child_list");
+    }
+}
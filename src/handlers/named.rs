@@ -0,0 +1,220 @@
+//! Handlers for script templates stored under a name and run on demand.
+
+use std::sync::{Arc, PoisonError};
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+};
+
+use super::send_eval;
+use crate::template::ScriptTemplate;
+use crate::types::{
+    EvalResponse, NamedEvalRequest, NamedScript, NamedScriptRequest, NamedScriptsResponse,
+};
+use crate::BridgeState;
+
+/// GET /eval/named - List the stored script templates.
+pub async fn list_named_scripts(
+    State(state): State<Arc<BridgeState>>,
+) -> Json<NamedScriptsResponse> {
+    Json(named_scripts(&state))
+}
+
+/// PUT /eval/named/{name} - Store a script template, replacing any with the
+/// same name.
+pub async fn store_named_script(
+    State(state): State<Arc<BridgeState>>,
+    Path(name): Path<String>,
+    Json(req): Json<NamedScriptRequest>,
+) -> Result<Json<NamedScriptsResponse>, (StatusCode, String)> {
+    if name.trim().is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Script name must not be empty".to_string(),
+        ));
+    }
+    state
+        .named_scripts
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(name, req.script);
+    Ok(Json(named_scripts(&state)))
+}
+
+/// POST /eval/named/{name} - Fill in a stored template's `{{param}}`
+/// placeholders and run it.
+///
+/// Placeholders without a matching param are left in the script as-is.
+pub async fn run_named_script(
+    State(state): State<Arc<BridgeState>>,
+    Path(name): Path<String>,
+    req: Option<Json<NamedEvalRequest>>,
+) -> Result<Json<EvalResponse>, (StatusCode, String)> {
+    let template = state
+        .named_scripts
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&name)
+        .cloned()
+        .ok_or_else(|| not_found(&name))?;
+    let Json(req) = req.unwrap_or_default();
+
+    let params = req
+        .params
+        .iter()
+        .map(|(key, value)| (key.as_str(), value.as_str()))
+        .collect();
+    let script = ScriptTemplate::new(&template).render(params);
+    let response = send_eval(&state, script)
+        .await
+        .map_err(|status| (status, status.to_string()))?;
+    Ok(Json(response))
+}
+
+/// DELETE /eval/named/{name} - Remove a stored script template.
+pub async fn remove_named_script(
+    State(state): State<Arc<BridgeState>>,
+    Path(name): Path<String>,
+) -> Result<Json<NamedScriptsResponse>, (StatusCode, String)> {
+    state
+        .named_scripts
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .remove(&name)
+        .ok_or_else(|| not_found(&name))?;
+    Ok(Json(named_scripts(&state)))
+}
+
+fn named_scripts(state: &BridgeState) -> NamedScriptsResponse {
+    let scripts = state
+        .named_scripts
+        .read()
+        .unwrap_or_else(PoisonError::into_inner);
+    let mut scripts: Vec<NamedScript> = scripts
+        .iter()
+        .map(|(name, script)| NamedScript {
+            name: name.clone(),
+            script: script.clone(),
+        })
+        .collect();
+    scripts.sort_by(|a, b| a.name.cmp(&b.name));
+    NamedScriptsResponse { scripts }
+}
+
+fn not_found(name: &str) -> (StatusCode, String) {
+    (StatusCode::NOT_FOUND, format!("No script named '{}'", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn app(state: Arc<BridgeState>) -> Router {
+        Router::new()
+            .route("/eval/named", get(list_named_scripts))
+            .route(
+                "/eval/named/{name}",
+                axum::routing::put(store_named_script)
+                    .post(run_named_script)
+                    .delete(remove_named_script),
+            )
+            .with_state(state)
+    }
+
+    async fn send(app: &Router, method: &str, uri: &str, body: &str) -> (u16, serde_json::Value) {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status().as_u16();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json = serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null);
+        (status, json)
+    }
+
+    #[tokio::test]
+    async fn test_store_and_run_named_script() {
+        let (state, mut rx) = create_test_state();
+        let app = app(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert_eq!(
+                    cmd.script,
+                    r##"return document.querySelector("#app \"main\"").id"##
+                );
+                let _ = cmd.response_tx.send(EvalResponse::success("\"app\""));
+            }
+        });
+
+        let (status, json) = send(
+            &app,
+            "PUT",
+            "/eval/named/inner",
+            r#"{"script": "return document.querySelector({{selector}}).id"}"#,
+        )
+        .await;
+        assert_eq!(status, 200);
+        assert_eq!(json["scripts"][0]["name"], "inner");
+
+        let (status, json) = send(
+            &app,
+            "POST",
+            "/eval/named/inner",
+            r##"{"params": {"selector": "#app \"main\""}}"##,
+        )
+        .await;
+        assert_eq!(status, 200);
+        assert_eq!(json["result"], "\"app\"");
+
+        let (status, json) = send(&app, "DELETE", "/eval/named/inner", "").await;
+        assert_eq!(status, 200);
+        assert_eq!(json["scripts"], serde_json::json!([]));
+    }
+
+    #[tokio::test]
+    async fn test_named_script_not_found() {
+        let (state, _rx) = create_test_state();
+        let app = app(state);
+
+        let (status, _) = send(&app, "POST", "/eval/named/missing", "{}").await;
+        assert_eq!(status, 404);
+        let (status, _) = send(&app, "DELETE", "/eval/named/missing", "").await;
+        assert_eq!(status, 404);
+    }
+
+    #[tokio::test]
+    async fn test_list_named_scripts_sorted() {
+        let (state, _rx) = create_test_state();
+        let app = app(state);
+
+        for name in ["b", "a"] {
+            let (status, _) = send(
+                &app,
+                "PUT",
+                &format!("/eval/named/{}", name),
+                r#"{"script": "return 1"}"#,
+            )
+            .await;
+            assert_eq!(status, 200);
+        }
+        let (status, json) = send(&app, "GET", "/eval/named", "").await;
+        assert_eq!(status, 200);
+        assert_eq!(json["scripts"][0]["name"], "a");
+        assert_eq!(json["scripts"][1]["name"], "b");
+    }
+}
@@ -0,0 +1,245 @@
+//! Handlers that navigate the webview.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+use tracing::instrument::WithSubscriber;
+
+use super::channel::dispatch_eval;
+use super::{eval_json, run_setup_scripts, send_eval};
+use crate::types::{HotReloadResponse, ReloadRequest, ReloadResponse};
+use crate::BridgeState;
+
+// The reload is deferred so the eval can return before the page unloads;
+// otherwise the response would never reach the bridge. The marker is gone
+// on the new page, which is how `reinstall_after_reload` spots it.
+const RELOAD_SCRIPT: &str = "window.__dioxusInspectorReloading__ = true; \
+    setTimeout(() => window.location.reload(), 0); return true;";
+const SOFT_RELOAD_SCRIPT: &str = "window.__dioxusInspectorReloading__ = true; \
+    setTimeout(() => { window.location.href = window.location.href; }, 0); return true;";
+
+const RELOADED_SCRIPT: &str =
+    "return !window.__dioxusInspectorReloading__ && document.readyState === 'complete';";
+
+/// How often, and how many times, to check whether the page has reloaded.
+const RELOAD_POLL_INTERVAL: Duration = Duration::from_millis(100);
+const MAX_RELOAD_POLLS: u32 = 100;
+const RELOAD_POLL_TIMEOUT_MS: u64 = 1_000;
+
+/// Re-run the setup scripts once a reload has replaced the page, which
+/// drops the trackers they installed.
+///
+/// Gives up after [`MAX_RELOAD_POLLS`] checks, e.g. when the reload never
+/// happened, or as soon as the app stops answering evals.
+fn reinstall_after_reload(state: Arc<BridgeState>) {
+    tokio::spawn(
+        async move {
+            for _ in 0..MAX_RELOAD_POLLS {
+                tokio::time::sleep(RELOAD_POLL_INTERVAL).await;
+                let script = RELOADED_SCRIPT.to_string();
+                match dispatch_eval(&state, script, Some(RELOAD_POLL_TIMEOUT_MS)).await {
+                    Ok(resp) if resp.success && resp.result.as_deref() == Some("true") => {
+                        run_setup_scripts(state).await;
+                        return;
+                    }
+                    Ok(_) => {}
+                    Err(_) => return,
+                }
+            }
+            tracing::warn!("Page did not reload, the trackers were not reinstalled");
+        }
+        .with_subscriber(state.log_dispatch.clone()),
+    );
+}
+
+/// POST /reload - Reload the page in the webview.
+///
+/// Pass `{"soft": true}` to re-navigate to the current URL instead.
+pub async fn reload(
+    State(state): State<Arc<BridgeState>>,
+    body: Option<Json<ReloadRequest>>,
+) -> Result<Json<ReloadResponse>, StatusCode> {
+    let req = body.map(|j| j.0).unwrap_or_default();
+    let script = if req.soft {
+        SOFT_RELOAD_SCRIPT
+    } else {
+        RELOAD_SCRIPT
+    };
+
+    let response = send_eval(&state, script.to_string()).await?;
+    if response.success {
+        reinstall_after_reload(state);
+    }
+    Ok(Json(ReloadResponse {
+        success: response.success,
+        error: response.error,
+    }))
+}
+
+// `readyState` 1 is `WebSocket.OPEN`
+const HOT_RELOAD_SCRIPT: &str = r#"return (() => {
+    const ws = window.__dioxus_ws;
+    const connected = Boolean(ws) && ws.readyState === 1;
+    if (connected) {
+        window.__dioxusInspectorReloading__ = true;
+        ws.send(JSON.stringify({ type: "reload" }));
+    }
+    return JSON.stringify({ triggered: connected, ws_connected: connected });
+})()"#;
+
+/// POST /reload/hot - Ask the Dioxus dev server to hot-reload the app.
+///
+/// Sends a reload message over the page's hot-reload WebSocket
+/// (`window.__dioxus_ws`). Without an open socket nothing is sent, and
+/// `ws_connected` is `false` rather than an error.
+pub async fn hot_reload(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<HotReloadResponse>, (StatusCode, String)> {
+    let response: HotReloadResponse = eval_json(&state, HOT_RELOAD_SCRIPT.to_string()).await?;
+    if response.triggered {
+        reinstall_after_reload(state);
+    }
+    Ok(Json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::post, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    async fn reload_with(body: Body, content_type: Option<&str>) -> (String, serde_json::Value) {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/reload", post(reload))
+            .with_state(state);
+
+        let script = tokio::spawn(async move {
+            let cmd = rx.recv().await.unwrap();
+            let _ = cmd.response_tx.send(EvalResponse::success("true"));
+            cmd.script
+        });
+
+        let mut request = Request::post("/reload");
+        if let Some(content_type) = content_type {
+            request = request.header("content-type", content_type);
+        }
+        let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json = serde_json::from_slice(&body).unwrap();
+        (script.await.unwrap(), json)
+    }
+
+    #[tokio::test]
+    async fn test_hot_reload_without_websocket() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/reload/hot", post(hot_reload))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("window.__dioxus_ws"));
+                let payload = r#"{"triggered": false, "ws_connected": false}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(Request::post("/reload/hot").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["triggered"], false);
+        assert_eq!(json["ws_connected"], false);
+    }
+
+    #[tokio::test]
+    async fn test_reload_without_body() {
+        let (script, json) = reload_with(Body::empty(), None).await;
+        assert!(script.contains("location.reload()"));
+        assert_eq!(json["success"], true);
+    }
+
+    #[tokio::test]
+    async fn test_reload_reinstalls_setup_scripts() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/reload", post(reload))
+            .with_state(state);
+
+        let responder = tokio::spawn(async move {
+            let mut scripts = Vec::new();
+            while let Some(cmd) = rx.recv().await {
+                let reloaded = cmd.script == RELOADED_SCRIPT;
+                scripts.push(cmd.script);
+                // The first check still sees the old page
+                let result = if reloaded && scripts.len() == 2 {
+                    "false"
+                } else {
+                    "true"
+                };
+                let _ = cmd.response_tx.send(EvalResponse::success(result));
+                if scripts
+                    .iter()
+                    .any(|s| s.contains("__dioxusInspectorListeners__"))
+                {
+                    break;
+                }
+            }
+            scripts
+        });
+
+        let response = app
+            .oneshot(Request::post("/reload").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let scripts = responder.await.unwrap();
+        assert!(scripts[0].contains("location.reload()"));
+        assert_eq!(scripts[1..3], [RELOADED_SCRIPT, RELOADED_SCRIPT]);
+        assert!(scripts[3].contains("__dioxusInspectorListeners__"));
+    }
+
+    #[tokio::test]
+    async fn test_soft_reload() {
+        let (script, json) =
+            reload_with(Body::from(r#"{"soft": true}"#), Some("application/json")).await;
+        assert!(script.contains("window.location.href = window.location.href"));
+        assert_eq!(json["success"], true);
+    }
+
+    #[tokio::test]
+    async fn test_reload_reports_script_error() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/reload", post(reload))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                let _ = cmd.response_tx.send(EvalResponse::error("blocked"));
+            }
+        });
+
+        let response = app
+            .oneshot(Request::post("/reload").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["success"], false);
+        assert_eq!(json["error"], "blocked");
+    }
+}
@@ -0,0 +1,156 @@
+//! Handlers for simulating slow or missing network connections.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+use serde_json::Value;
+
+use super::eval_json;
+use crate::template::ScriptTemplate;
+use crate::types::{NetworkCondition, NetworkSimRequest, NetworkSimResponse};
+use crate::BridgeState;
+
+/// POST /simulate-network - Make `fetch` slow or fail as if offline.
+///
+/// Only `fetch` is patched. XHR, WebSockets, `EventSource` and resources the
+/// page loads itself (images, scripts, styles) use the real network.
+/// Simulating again replaces the current condition, and `normal` restores
+/// the real `fetch`.
+pub async fn simulate_network(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<NetworkSimRequest>,
+) -> Result<Json<NetworkSimResponse>, (StatusCode, String)> {
+    validate_network_condition(&req.condition).map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+    let condition = serde_json::to_value(&req.condition).unwrap_or(Value::Null);
+    let response = eval_json(&state, build_network_script(condition)).await?;
+    Ok(Json(response))
+}
+
+/// DELETE /simulate-network - Restore the real `fetch`.
+pub async fn clear_network_simulation(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<NetworkSimResponse>, (StatusCode, String)> {
+    let response = eval_json(&state, build_network_script(Value::Null)).await?;
+    Ok(Json(response))
+}
+
+fn validate_network_condition(condition: &NetworkCondition) -> Result<(), String> {
+    match condition {
+        NetworkCondition::Slow3G { bandwidth_kbps, .. } if *bandwidth_kbps == 0 => {
+            Err("bandwidth_kbps must be at least 1".to_string())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn build_network_script(condition: Value) -> String {
+    ScriptTemplate::new(include_str!("../scripts/simulate_network.js"))
+        .render_json(HashMap::from([("condition", condition)]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::post, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn app() -> (
+        Router,
+        tokio::sync::mpsc::Receiver<crate::types::EvalCommand>,
+    ) {
+        let (state, rx) = create_test_state();
+        let app = Router::new()
+            .route(
+                "/simulate-network",
+                post(simulate_network).delete(clear_network_simulation),
+            )
+            .with_state(state);
+        (app, rx)
+    }
+
+    fn post_condition(condition: serde_json::Value) -> Request<Body> {
+        Request::post("/simulate-network")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                serde_json::json!({ "condition": condition }).to_string(),
+            ))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_validate_network_condition() {
+        let slow = |bandwidth_kbps| NetworkCondition::Slow3G {
+            latency_ms: 400,
+            bandwidth_kbps,
+        };
+        assert!(validate_network_condition(&slow(400)).is_ok());
+        assert!(validate_network_condition(&slow(0)).is_err());
+        assert!(validate_network_condition(&NetworkCondition::Offline).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_simulate_network_handler() {
+        let (app, mut rx) = app();
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains(
+                    r#"const condition = {"bandwidth_kbps":400,"latency_ms":400,"type":"slow_3g"};"#
+                ));
+                let encoded = serde_json::to_string(r#"{"simulated": true}"#).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(post_condition(serde_json::json!({
+                "type": "slow_3g", "latency_ms": 400, "bandwidth_kbps": 400
+            })))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["simulated"], true);
+    }
+
+    #[tokio::test]
+    async fn test_simulate_network_rejects_zero_bandwidth() {
+        let (app, _rx) = app();
+        let response = app
+            .oneshot(post_condition(serde_json::json!({
+                "type": "slow_3g", "latency_ms": 400, "bandwidth_kbps": 0
+            })))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn test_clear_network_simulation_handler() {
+        let (app, mut rx) = app();
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("const condition = null;"));
+                let encoded = serde_json::to_string(r#"{"simulated": false}"#).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::delete("/simulate-network")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+    }
+}
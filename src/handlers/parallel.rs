@@ -0,0 +1,142 @@
+//! Handler for running independent scripts concurrently.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+use futures_util::future::join_all;
+use tokio::time::Instant;
+
+use super::send_eval_within;
+use crate::types::{EvalResponse, EvalResult, ParallelEvalRequest, ParallelEvalResponse};
+use crate::BridgeState;
+
+/// Most scripts one `/eval/parallel` request may run.
+const MAX_PARALLEL_SCRIPTS: usize = 100;
+
+/// POST /eval/parallel - Run several scripts at once, timing each.
+///
+/// Scripts fail independently: one that errors, or doesn't answer within
+/// `timeout_ms` (default: the bridge's eval timeout), gets an error entry
+/// while the others still return their results. A timed-out script may keep
+/// running in the page.
+pub async fn eval_parallel(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<ParallelEvalRequest>,
+) -> Result<Json<ParallelEvalResponse>, (StatusCode, String)> {
+    validate_parallel_request(&req).map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+    let timeout_ms = req.timeout_ms;
+    let started = Instant::now();
+    let results = join_all(req.scripts.into_iter().enumerate().map(|(index, script)| {
+        let state = &state;
+        async move {
+            let script_started = Instant::now();
+            let response = send_eval_within(state, script, timeout_ms).await;
+            EvalResult {
+                script_index: index,
+                eval: response.unwrap_or_else(|status| EvalResponse::error(status.to_string())),
+                duration_ms: millis(script_started.elapsed()),
+            }
+        }
+    }))
+    .await;
+
+    Ok(Json(ParallelEvalResponse {
+        results,
+        total_ms: millis(started.elapsed()),
+    }))
+}
+
+fn validate_parallel_request(req: &ParallelEvalRequest) -> Result<(), String> {
+    if req.scripts.is_empty() || req.scripts.len() > MAX_PARALLEL_SCRIPTS {
+        return Err(format!(
+            "scripts must have between 1 and {} entries",
+            MAX_PARALLEL_SCRIPTS
+        ));
+    }
+    if req.timeout_ms == Some(0) {
+        return Err("timeout_ms must be at least 1".to_string());
+    }
+    Ok(())
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use axum::{body::Body, http::Request, routing::post, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn parallel_request(body: serde_json::Value) -> Request<Body> {
+        Request::post("/eval/parallel")
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_eval_parallel_partial_failure() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/eval/parallel", post(eval_parallel))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            // Hold on to the hanging script so it never answers
+            let mut pending = Vec::new();
+            while let Some(cmd) = rx.recv().await {
+                match cmd.script.as_str() {
+                    "return 1" => {
+                        let _ = cmd.response_tx.send(EvalResponse::success("1"));
+                    }
+                    "throw 1" => {
+                        let _ = cmd.response_tx.send(EvalResponse::error("1"));
+                    }
+                    _ => pending.push(cmd),
+                }
+            }
+        });
+
+        let response = app
+            .oneshot(parallel_request(serde_json::json!({
+                "scripts": ["return 1", "while (true) {}", "throw 1"],
+                "timeout_ms": 100
+            })))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let results = json["results"].as_array().unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0]["script_index"], 0);
+        assert_eq!(results[0]["result"], "1");
+        assert_eq!(results[1]["script_index"], 1);
+        assert_eq!(results[1]["success"], false);
+        assert_eq!(results[1]["error"], "Script timed out after 100ms");
+        assert!(results[1]["duration_ms"].as_f64().unwrap() >= 100.0);
+        assert_eq!(results[2]["success"], false);
+        assert!(json["total_ms"].as_f64().unwrap() >= 100.0);
+    }
+
+    #[tokio::test]
+    async fn test_eval_parallel_rejects_empty_scripts() {
+        let (state, _rx) = create_test_state();
+        let app = Router::new()
+            .route("/eval/parallel", post(eval_parallel))
+            .with_state(state);
+
+        let response = app
+            .oneshot(parallel_request(serde_json::json!({"scripts": []})))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 400);
+    }
+}
@@ -0,0 +1,422 @@
+//! Handlers that read elements matching a CSS selector.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{eval_json, send_eval};
+use crate::template::ScriptTemplate;
+use crate::types::{
+    ComputedStylesRequest, ComputedStylesResponse, DataAttributesRequest, DataAttributesResponse,
+    EvalResponse, QueryAllRequest, QueryAllResponse, QueryRequest,
+};
+use crate::BridgeState;
+
+/// POST /query - Query DOM by CSS selector.
+pub async fn query(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<QueryRequest>,
+) -> Result<Json<EvalResponse>, StatusCode> {
+    let property = req.property.as_deref().unwrap_or("text");
+    let script = build_query_script(&req.selector, property);
+    let response = send_eval(&state, script).await?;
+    Ok(Json(response))
+}
+
+fn build_query_script(selector: &str, property: &str) -> String {
+    ScriptTemplate::new(include_str!("../scripts/query.js")).render(HashMap::from([
+        ("selector", selector),
+        ("property", property),
+    ]))
+}
+
+/// Default number of elements returned by `/query/all`.
+const DEFAULT_QUERY_ALL_LIMIT: u32 = 100;
+
+/// Result of the query-all script.
+#[derive(Deserialize)]
+struct QueryAllResult {
+    total_count: u32,
+    values: Vec<Option<String>>,
+}
+
+/// POST /query/all - Query every element matching a CSS selector.
+pub async fn query_all(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<QueryAllRequest>,
+) -> Result<Json<QueryAllResponse>, (StatusCode, String)> {
+    let property = req.property.as_deref().unwrap_or("text");
+    let limit = req.limit.unwrap_or(DEFAULT_QUERY_ALL_LIMIT);
+    let script = build_query_all_script(&req.selector, property, limit);
+    let result: QueryAllResult = eval_json(&state, script).await?;
+
+    let truncated = result.total_count as usize > result.values.len();
+    let elements = result
+        .values
+        .into_iter()
+        .map(|value| EvalResponse {
+            success: true,
+            result: value,
+            error: None,
+        })
+        .collect();
+
+    Ok(Json(QueryAllResponse {
+        elements,
+        total_count: result.total_count,
+        truncated,
+    }))
+}
+
+fn build_query_all_script(selector: &str, property: &str, limit: u32) -> String {
+    ScriptTemplate::new(include_str!("../scripts/query_all.js")).render_json(HashMap::from([
+        ("selector", Value::from(selector)),
+        ("property", Value::from(property)),
+        ("limit", Value::from(limit)),
+    ]))
+}
+
+/// Result of the data attributes script.
+#[derive(Deserialize)]
+struct DataAttributesResult {
+    found: bool,
+    attributes: HashMap<String, String>,
+}
+
+/// POST /data-attributes - List the `data-*` attributes of an element.
+///
+/// Responds with 404 when no element matches the selector.
+pub async fn data_attributes(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<DataAttributesRequest>,
+) -> Result<Json<DataAttributesResponse>, (StatusCode, String)> {
+    let script = ScriptTemplate::new(include_str!("../scripts/data_attributes.js"))
+        .render(HashMap::from([("selector", req.selector.as_str())]));
+    let result: DataAttributesResult = eval_json(&state, script).await?;
+    if !result.found {
+        return Err((
+            StatusCode::NOT_FOUND,
+            format!("Element not found: {}", req.selector),
+        ));
+    }
+    Ok(Json(DataAttributesResponse {
+        attributes: result.attributes,
+    }))
+}
+
+/// Result of the computed styles script.
+#[derive(Deserialize)]
+struct ComputedStylesResult {
+    found: bool,
+    styles: HashMap<String, String>,
+}
+
+/// POST /computed-styles - Get the computed CSS properties of an element.
+///
+/// Responds with 404 when no element matches the selector.
+pub async fn computed_styles(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<ComputedStylesRequest>,
+) -> Result<Json<ComputedStylesResponse>, (StatusCode, String)> {
+    let script = build_computed_styles_script(&req.selector, req.properties.as_deref());
+    let result: ComputedStylesResult = eval_json(&state, script).await?;
+    if !result.found {
+        return Err((
+            StatusCode::NOT_FOUND,
+            format!("Element not found: {}", req.selector),
+        ));
+    }
+    Ok(Json(ComputedStylesResponse {
+        styles: result.styles,
+    }))
+}
+
+fn build_computed_styles_script(selector: &str, properties: Option<&[String]>) -> String {
+    ScriptTemplate::new(include_str!("../scripts/computed_styles.js")).render_json(HashMap::from([
+        ("selector", Value::from(selector)),
+        ("properties", serde_json::json!(properties)),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use axum::{body::Body, http::Request, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    // build_query_script tests
+    #[test]
+    fn test_build_query_script_text() {
+        let script = build_query_script(".btn", "text");
+        assert!(script.contains("textContent"));
+        assert!(script.contains("\".btn\""));
+    }
+
+    #[test]
+    fn test_build_query_script_html() {
+        let script = build_query_script("#main", "html");
+        assert!(script.contains("innerHTML"));
+    }
+
+    #[test]
+    fn test_build_query_script_outer_html() {
+        let script = build_query_script("div", "outerHTML");
+        assert!(script.contains("outerHTML"));
+        assert!(script.contains("\"div\""));
+    }
+
+    #[test]
+    fn test_build_query_script_value() {
+        let script = build_query_script("input", "value");
+        assert!(script.contains(".value"));
+    }
+
+    #[test]
+    fn test_build_query_script_attribute() {
+        let script = build_query_script("a", "href");
+        assert!(script.contains("getAttribute"));
+        assert!(script.contains("\"href\""));
+    }
+
+    #[test]
+    fn test_build_query_script_data_attribute() {
+        let script = build_query_script("[data-id]", "data-id");
+        assert!(script.contains("getAttribute"));
+        assert!(script.contains("\"data-id\""));
+    }
+
+    #[test]
+    fn test_build_query_script_escapes_selector() {
+        let script = build_query_script("div[data-name=\"test\"]", "text");
+        // Selector should be JSON-escaped
+        assert!(script.contains("\\\"test\\\""));
+    }
+
+    #[test]
+    fn test_build_query_all_script() {
+        let script = build_query_all_script("li.item", "data-id", 5);
+        assert!(script.contains("querySelectorAll(\"li.item\")"));
+        assert!(script.contains("const property = \"data-id\";"));
+        assert!(script.contains("const limit = 5;"));
+    }
+
+    #[tokio::test]
+    async fn test_query_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/query", axum::routing::post(query))
+            .with_state(state);
+
+        // Spawn responder that validates the script
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                // Verify the script contains textContent (default property)
+                assert!(cmd.script.contains("textContent"));
+                assert!(cmd.script.contains(".btn"));
+                let _ = cmd.response_tx.send(EvalResponse::success("Click me"));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/query")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"selector": ".btn"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(json["success"], true);
+        assert_eq!(json["result"], "Click me");
+    }
+
+    #[tokio::test]
+    async fn test_query_handler_with_property() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/query", axum::routing::post(query))
+            .with_state(state);
+
+        // Spawn responder that validates the script
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                // Verify the script contains innerHTML
+                assert!(cmd.script.contains("innerHTML"));
+                let _ = cmd
+                    .response_tx
+                    .send(EvalResponse::success("<span>Content</span>"));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/query")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r##"{"selector": "#main", "property": "html"}"##))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(json["result"], "<span>Content</span>");
+    }
+
+    #[tokio::test]
+    async fn test_query_all_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/query/all", axum::routing::post(query_all))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("const limit = 2;"));
+                let payload = r#"{"total_count": 3, "values": ["One", null]}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/query/all")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"selector": "li", "limit": 2}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["total_count"], 3);
+        assert_eq!(json["truncated"], true);
+        assert_eq!(json["elements"][0]["result"], "One");
+        assert!(json["elements"][1].get("result").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_data_attributes_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/data-attributes", axum::routing::post(data_attributes))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("el.dataset"));
+                let payload = r#"{"found": true, "attributes": {"data-state": "open", "data-side": "bottom"}}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/data-attributes")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"selector": ".popover"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["attributes"]["data-state"], "open");
+        assert_eq!(json["attributes"]["data-side"], "bottom");
+    }
+
+    #[tokio::test]
+    async fn test_data_attributes_handler_not_found() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/data-attributes", axum::routing::post(data_attributes))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                let payload = r#"{"found": false, "attributes": {}}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/data-attributes")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"selector": ".missing"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 404);
+    }
+
+    #[test]
+    fn test_build_computed_styles_script() {
+        let script = build_computed_styles_script(".card", None);
+        assert!(script.contains("const properties = null;"));
+
+        let properties = vec!["display".to_string(), "margin-top".to_string()];
+        let script = build_computed_styles_script(".card", Some(&properties));
+        assert!(script.contains(r#"const properties = ["display","margin-top"];"#));
+    }
+
+    #[tokio::test]
+    async fn test_computed_styles_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/computed-styles", axum::routing::post(computed_styles))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("getComputedStyle"));
+                let payload = r#"{"found": true, "styles": {"display": "flex"}}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/computed-styles")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r#"{"selector": ".card", "properties": ["display"]}"#,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["styles"]["display"], "flex");
+    }
+}
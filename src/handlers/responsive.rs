@@ -0,0 +1,368 @@
+//! Handlers that step the window through sizes and record the page at each.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+
+use super::dom::{build_dom_script, DomQuery};
+use super::viewport::build_viewport_script;
+use super::window::build_resize_script;
+use super::{eval_json, send_eval};
+use crate::screenshot::capture_screenshot;
+use crate::types::{
+    BreakpointResult, BreakpointSimRequest, BreakpointSimResponse, BreakpointStep,
+    ResponsiveResult, ResponsiveTestRequest, ResponsiveTestResponse, ScreenshotFormat,
+    ViewportSize,
+};
+use crate::BridgeState;
+
+/// How long to let the page reflow after each resize in `/responsive`.
+const REFLOW_DELAY: Duration = Duration::from_millis(200);
+/// Longest a `/breakpoints/simulate` step may wait.
+const MAX_BREAKPOINT_DELAY_MS: u64 = 10_000;
+
+/// POST /responsive - Resize the window to each size in turn and record the DOM,
+/// and optionally a screenshot, at each one.
+///
+/// Uses the same resize command as `/resize`, so the app must handle it. The
+/// window is left at the last size. Failures are reported per size in `error`.
+pub async fn responsive_test(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<ResponsiveTestRequest>,
+) -> Result<Json<ResponsiveTestResponse>, (StatusCode, String)> {
+    if req.sizes.is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "No sizes given".to_string()));
+    }
+    if let Some(size) = req.sizes.iter().find(|s| s.width == 0 || s.height == 0) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!(
+                "Size '{}' must be non-zero, got {}x{}",
+                size.name, size.width, size.height
+            ),
+        ));
+    }
+
+    let mut results = Vec::with_capacity(req.sizes.len());
+    for size in &req.sizes {
+        results.push(test_size(&state, size, req.screenshot).await);
+    }
+    Ok(Json(ResponsiveTestResponse { results }))
+}
+
+async fn test_size(state: &BridgeState, size: &ViewportSize, screenshot: bool) -> ResponsiveResult {
+    let mut result = ResponsiveResult {
+        name: size.name.clone(),
+        size: format!("{}x{}", size.width, size.height),
+        screenshot_path: None,
+        dom: None,
+        error: None,
+    };
+
+    if let Err(e) = resize_window(state, size.width, size.height).await {
+        result.error = Some(e);
+        return result;
+    }
+    tokio::time::sleep(REFLOW_DELAY).await;
+
+    if screenshot {
+        let path = sized_screenshot_path("responsive", &size.name);
+        match capture_png(&state.app_name, path).await {
+            Ok(path) => result.screenshot_path = Some(path),
+            Err(e) => result.error = Some(e),
+        }
+    }
+
+    match eval_json(state, build_dom_script(&DomQuery::default())).await {
+        Ok(dom) => result.dom = Some(dom),
+        Err((_, e)) => {
+            result.error.get_or_insert(e);
+        }
+    }
+    result
+}
+
+/// POST /breakpoints/simulate - Step the window through breakpoints, waiting
+/// `delay_ms` at each, and record what changed.
+///
+/// Each step reports the viewport size the page ended up with and whether the
+/// DOM differs from the previous step's, plus a screenshot if requested. Like
+/// `/responsive`, it relies on the app handling the resize command and leaves
+/// the window at the last size. Failures are reported per step in `error`.
+pub async fn simulate_breakpoints(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<BreakpointSimRequest>,
+) -> Result<Json<BreakpointSimResponse>, (StatusCode, String)> {
+    validate_breakpoints(&req.breakpoints).map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+    let mut results = Vec::with_capacity(req.breakpoints.len());
+    let mut previous_dom = None;
+    for step in &req.breakpoints {
+        let (result, dom) =
+            simulate_step(&state, step, req.screenshot, previous_dom.as_ref()).await;
+        if dom.is_some() {
+            previous_dom = dom;
+        }
+        results.push(result);
+    }
+    Ok(Json(BreakpointSimResponse { results }))
+}
+
+fn validate_breakpoints(steps: &[BreakpointStep]) -> Result<(), String> {
+    if steps.is_empty() {
+        return Err("No breakpoints given".to_string());
+    }
+    for step in steps {
+        if step.width == 0 || step.height == 0 {
+            return Err(format!(
+                "Breakpoint '{}' must be non-zero, got {}x{}",
+                step.name, step.width, step.height
+            ));
+        }
+        if step.delay_ms > MAX_BREAKPOINT_DELAY_MS {
+            return Err(format!(
+                "Breakpoint '{}' delay_ms must be at most {}",
+                step.name, MAX_BREAKPOINT_DELAY_MS
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Run one breakpoint step, returning its result and the DOM it saw.
+async fn simulate_step(
+    state: &BridgeState,
+    step: &BreakpointStep,
+    screenshot: bool,
+    previous_dom: Option<&serde_json::Value>,
+) -> (BreakpointResult, Option<serde_json::Value>) {
+    let mut result = BreakpointResult {
+        name: step.name.clone(),
+        size: format!("{}x{}", step.width, step.height),
+        viewport: None,
+        dom_changed: false,
+        screenshot_path: None,
+        error: None,
+    };
+
+    if let Err(e) = resize_window(state, step.width, step.height).await {
+        result.error = Some(e);
+        return (result, None);
+    }
+    tokio::time::sleep(Duration::from_millis(step.delay_ms)).await;
+
+    if screenshot {
+        let path = sized_screenshot_path("breakpoint", &step.name);
+        match capture_png(&state.app_name, path).await {
+            Ok(path) => result.screenshot_path = Some(path),
+            Err(e) => result.error = Some(e),
+        }
+    }
+
+    match eval_json(state, build_viewport_script(None)).await {
+        Ok(viewport) => result.viewport = Some(viewport),
+        Err((_, e)) => {
+            result.error.get_or_insert(e);
+        }
+    }
+    let dom =
+        match eval_json::<serde_json::Value>(state, build_dom_script(&DomQuery::default())).await {
+            Ok(dom) => Some(dom),
+            Err((_, e)) => {
+                result.error.get_or_insert(e);
+                None
+            }
+        };
+    if let (Some(dom), Some(previous)) = (&dom, previous_dom) {
+        result.dom_changed = dom != previous;
+    }
+    (result, dom)
+}
+
+/// Capture the window as a PNG at `path`, off the async runtime, returning
+/// the path.
+async fn capture_png(app_name: &str, path: String) -> Result<String, String> {
+    let app_name = app_name.to_string();
+    let capture = tokio::task::spawn_blocking(move || {
+        capture_screenshot(&app_name, &path, ScreenshotFormat::Png).map(|()| path)
+    });
+    capture.await.unwrap_or_else(|e| Err(e.to_string()))
+}
+
+/// Send the resize command, turning a failed or unhandled resize into an error.
+async fn resize_window(state: &BridgeState, width: u32, height: u32) -> Result<(), String> {
+    match send_eval(state, build_resize_script(width, height, None)).await {
+        Ok(response) if response.success => Ok(()),
+        Ok(response) => Err(response
+            .error
+            .unwrap_or_else(|| "Resize failed".to_string())),
+        Err(status) => Err(status.to_string()),
+    }
+}
+
+/// `/tmp/dioxus-{kind}-{name}.png`, keeping only file-name-safe characters
+/// of `name`.
+fn sized_screenshot_path(kind: &str, name: &str) -> String {
+    let safe: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("/tmp/dioxus-{}-{}.png", kind, safe)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_sized_screenshot_path() {
+        assert_eq!(
+            sized_screenshot_path("responsive", "mobile"),
+            "/tmp/dioxus-responsive-mobile.png"
+        );
+        assert_eq!(
+            sized_screenshot_path("breakpoint", "../iPad Pro"),
+            "/tmp/dioxus-breakpoint-___iPad_Pro.png"
+        );
+    }
+
+    #[test]
+    fn test_validate_breakpoints() {
+        let step = |width, delay_ms| BreakpointStep {
+            name: "md".to_string(),
+            width,
+            height: 800,
+            delay_ms,
+        };
+        assert!(validate_breakpoints(&[step(768, 200)]).is_ok());
+        assert!(validate_breakpoints(&[]).is_err());
+        assert!(validate_breakpoints(&[step(0, 200)]).is_err());
+        assert!(validate_breakpoints(&[step(768, MAX_BREAKPOINT_DELAY_MS + 1)]).is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_simulate_breakpoints() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route(
+                "/breakpoints/simulate",
+                axum::routing::post(simulate_breakpoints),
+            )
+            .with_state(state);
+
+        tokio::spawn(async move {
+            let mut width = 0;
+            while let Some(cmd) = rx.recv().await {
+                let response = if cmd.script.contains("__DIOXUS_INSPECTOR_RESIZE__") {
+                    width = if cmd.script.contains("__640x") {
+                        640
+                    } else {
+                        1024
+                    };
+                    EvalResponse::success("ok")
+                } else if cmd.script.contains("inner_width") {
+                    let payload = format!(
+                        r#"{{"inner_width": {}, "inner_height": 800, "outer_width": {}, "outer_height": 828, "device_pixel_ratio": 2.0, "orientation": "landscape"}}"#,
+                        width, width
+                    );
+                    EvalResponse::success(serde_json::to_string(&payload).unwrap())
+                } else {
+                    // Only the widest layout shows the sidebar
+                    let payload = if width >= 1024 {
+                        r#"{"root": {"tag": "body", "children": [{"tag": "aside"}]}}"#
+                    } else {
+                        r#"{"root": {"tag": "body", "children": []}}"#
+                    };
+                    EvalResponse::success(serde_json::to_string(payload).unwrap())
+                };
+                let _ = cmd.response_tx.send(response);
+            }
+        });
+
+        let body = serde_json::json!({"breakpoints": [
+            {"name": "sm", "width": 640, "height": 800, "delay_ms": 100},
+            {"name": "sm-again", "width": 640, "height": 800, "delay_ms": 100},
+            {"name": "lg", "width": 1024, "height": 800, "delay_ms": 100},
+        ]});
+        let response = app
+            .oneshot(
+                Request::post("/breakpoints/simulate")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let results = json["results"].as_array().unwrap();
+        assert_eq!(results[0]["size"], "640x800");
+        assert_eq!(results[0]["viewport"]["inner_width"], 640);
+        assert_eq!(results[0]["dom_changed"], false);
+        assert_eq!(results[1]["dom_changed"], false);
+        assert_eq!(results[2]["viewport"]["inner_width"], 1024);
+        assert_eq!(results[2]["dom_changed"], true);
+        assert!(results[2]["error"].is_null());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_responsive_test() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/responsive", axum::routing::post(responsive_test))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            while let Some(cmd) = rx.recv().await {
+                let response = if cmd.script.contains("__DIOXUS_INSPECTOR_RESIZE__1x1__") {
+                    EvalResponse::error("unsupported")
+                } else if cmd.script.contains("__DIOXUS_INSPECTOR_RESIZE__") {
+                    EvalResponse::success("ok")
+                } else {
+                    let payload = r#"{"root": {"tag": "body"}}"#;
+                    EvalResponse::success(serde_json::to_string(payload).unwrap())
+                };
+                let _ = cmd.response_tx.send(response);
+            }
+        });
+
+        let body = serde_json::json!({"sizes": [
+            {"name": "mobile", "width": 375, "height": 812},
+            {"name": "tiny", "width": 1, "height": 1},
+        ]});
+        let response = app
+            .oneshot(
+                Request::post("/responsive")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let results = json["results"].as_array().unwrap();
+        assert_eq!(results[0]["size"], "375x812");
+        assert_eq!(results[0]["dom"]["root"]["tag"], "body");
+        assert!(results[0]["screenshot_path"].is_null());
+        assert!(results[0]["error"].is_null());
+        assert_eq!(results[1]["error"], "unsupported");
+        assert!(results[1]["dom"].is_null());
+    }
+}
@@ -0,0 +1,228 @@
+//! Handlers for running untrusted scripts off the page's thread.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::eval::build_async_script;
+use super::{decode_result, send_eval_within};
+use crate::template::ScriptTemplate;
+use crate::types::{
+    EvalResponse, SandboxEvalRequest, SandboxEvalResponse, DEFAULT_SANDBOX_MAX_OUTPUT_BYTES,
+    DEFAULT_SANDBOX_TIMEOUT_MS,
+};
+use crate::BridgeState;
+
+/// Longest [`SandboxEvalRequest::timeout_ms`] allowed.
+const MAX_SANDBOX_TIMEOUT_MS: u64 = 60_000;
+
+/// What `eval_sandbox.js` reports about the script it ran.
+#[derive(Deserialize)]
+struct SandboxOutcome {
+    ok: bool,
+    value: Option<String>,
+    error: Option<String>,
+    cpu_ms: Option<f64>,
+    truncated: bool,
+}
+
+/// POST /eval/sandbox - Execute JavaScript in a Web Worker with time and
+/// output limits.
+///
+/// The worker is terminated after `timeout_ms`, so even a busy loop can't
+/// wedge the webview, and a result longer than `max_output_bytes` is cut
+/// short with `truncated` set.
+pub async fn eval_sandboxed(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<SandboxEvalRequest>,
+) -> Result<Json<SandboxEvalResponse>, (StatusCode, String)> {
+    let timeout_ms = req.timeout_ms.unwrap_or(DEFAULT_SANDBOX_TIMEOUT_MS);
+    let max_output_bytes = req
+        .max_output_bytes
+        .unwrap_or(DEFAULT_SANDBOX_MAX_OUTPUT_BYTES);
+    validate_sandbox_limits(timeout_ms, max_output_bytes)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+    let script = build_sandbox_script(&req.script, timeout_ms, max_output_bytes);
+    let response = send_eval_within(&state, script, Some(timeout_ms))
+        .await
+        .map_err(|status| (status, status.to_string()))?;
+    if !response.success {
+        return Ok(Json(SandboxEvalResponse {
+            eval: response,
+            truncated: false,
+            cpu_ms: None,
+        }));
+    }
+
+    let outcome: SandboxOutcome =
+        decode_result(response).map_err(|e| (StatusCode::BAD_GATEWAY, e))?;
+    let eval = if outcome.ok {
+        EvalResponse {
+            success: true,
+            result: outcome.value,
+            error: None,
+        }
+    } else {
+        EvalResponse::error(outcome.error.unwrap_or_else(|| "Unknown error".to_string()))
+    };
+    Ok(Json(SandboxEvalResponse {
+        eval,
+        truncated: outcome.truncated,
+        cpu_ms: outcome.cpu_ms,
+    }))
+}
+
+fn validate_sandbox_limits(timeout_ms: u64, max_output_bytes: usize) -> Result<(), String> {
+    if timeout_ms == 0 || timeout_ms > MAX_SANDBOX_TIMEOUT_MS {
+        return Err(format!(
+            "timeout_ms must be between 1 and {}",
+            MAX_SANDBOX_TIMEOUT_MS
+        ));
+    }
+    if max_output_bytes == 0 {
+        return Err("max_output_bytes must be at least 1".to_string());
+    }
+    Ok(())
+}
+
+/// The worker enforces `timeout_ms` itself; the outer race with the same
+/// timeout covers webviews where the script runs in the page instead.
+fn build_sandbox_script(script: &str, timeout_ms: u64, max_output_bytes: usize) -> String {
+    let body = ScriptTemplate::new(include_str!("../scripts/eval_sandbox.js")).render_json(
+        HashMap::from([
+            ("script", Value::from(script)),
+            ("timeout_ms", Value::from(timeout_ms)),
+            ("max_output_bytes", Value::from(max_output_bytes)),
+        ]),
+    );
+    build_async_script(&body, Some(timeout_ms))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use axum::{body::Body, http::Request, routing::post, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    async fn sandbox_eval(
+        reply: EvalResponse,
+        body: serde_json::Value,
+    ) -> (u16, serde_json::Value) {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/eval/sandbox", post(eval_sandboxed))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                let _ = cmd.response_tx.send(reply);
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/eval/sandbox")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status().as_u16();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json = serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null);
+        (status, json)
+    }
+
+    #[test]
+    fn test_build_sandbox_script() {
+        let script = build_sandbox_script("return \"a\";", 250, 16);
+        assert!(script.starts_with("return Promise.race(["));
+        assert!(script.contains(r#"const script = "return \"a\";";"#));
+        assert!(script.contains("const maxBytes = 16;"));
+        assert!(script.contains("const timeoutMs = 250;"));
+        assert!(script.contains("new Worker(url)"));
+        assert!(script.contains("Script timed out after 250ms"));
+    }
+
+    #[test]
+    fn test_validate_sandbox_limits() {
+        assert!(validate_sandbox_limits(1000, 1024).is_ok());
+        assert!(validate_sandbox_limits(0, 1024).is_err());
+        assert!(validate_sandbox_limits(MAX_SANDBOX_TIMEOUT_MS + 1, 1024).is_err());
+        assert!(validate_sandbox_limits(1000, 0).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_eval_sandboxed_truncated() {
+        let outcome = serde_json::json!({
+            "ok": true,
+            "value": "[1,2,",
+            "error": null,
+            "cpu_ms": 2.5,
+            "truncated": true
+        });
+        let (status, json) = sandbox_eval(
+            EvalResponse::success(outcome.to_string()),
+            serde_json::json!({"script": "return [1, 2, 3]", "max_output_bytes": 5}),
+        )
+        .await;
+
+        assert_eq!(status, 200);
+        assert_eq!(json["success"], true);
+        assert_eq!(json["result"], "[1,2,");
+        assert_eq!(json["truncated"], true);
+        assert_eq!(json["cpu_ms"], 2.5);
+    }
+
+    #[tokio::test]
+    async fn test_eval_sandboxed_script_error() {
+        let outcome = serde_json::json!({
+            "ok": false,
+            "value": null,
+            "error": "Script timed out after 100ms",
+            "cpu_ms": 100.0,
+            "truncated": false
+        });
+        let (status, json) = sandbox_eval(
+            EvalResponse::success(outcome.to_string()),
+            serde_json::json!({"script": "while (true) {}", "timeout_ms": 100}),
+        )
+        .await;
+
+        assert_eq!(status, 200);
+        assert_eq!(json["success"], false);
+        assert_eq!(json["error"], "Script timed out after 100ms");
+        assert_eq!(json["cpu_ms"], 100.0);
+    }
+
+    #[tokio::test]
+    async fn test_eval_sandboxed_eval_failure() {
+        let (status, json) = sandbox_eval(
+            EvalResponse::error("Script timed out after 100ms"),
+            serde_json::json!({"script": "while (true) {}", "timeout_ms": 100}),
+        )
+        .await;
+
+        assert_eq!(status, 200);
+        assert_eq!(json["success"], false);
+        assert_eq!(json["truncated"], false);
+        assert_eq!(json["cpu_ms"], serde_json::Value::Null);
+    }
+
+    #[tokio::test]
+    async fn test_eval_sandboxed_rejects_zero_timeout() {
+        let (status, _) = sandbox_eval(
+            EvalResponse::success("null"),
+            serde_json::json!({"script": "return 1", "timeout_ms": 0}),
+        )
+        .await;
+        assert_eq!(status, 400);
+    }
+}
@@ -0,0 +1,117 @@
+//! Handler for finding elements by their text.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+
+use super::eval_json;
+use crate::template::ScriptTemplate;
+use crate::types::{DomSearchRequest, DomSearchResponse};
+use crate::BridgeState;
+
+/// POST /dom/search - Find the elements whose text contains `text`, which CSS
+/// selectors can't express.
+///
+/// Text is matched per text node, and each match is reported on the node's
+/// parent element, so a match is never split across child elements. Text in
+/// `<script>`, `<style>`, `<noscript>` and `<template>` is skipped.
+pub async fn dom_search(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<DomSearchRequest>,
+) -> Result<Json<DomSearchResponse>, (StatusCode, String)> {
+    if req.text.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Search text must not be empty".to_string(),
+        ));
+    }
+    let response = eval_json(&state, build_search_script(&req)).await?;
+    Ok(Json(response))
+}
+
+fn build_search_script(req: &DomSearchRequest) -> String {
+    let spec = serde_json::json!({
+        "text": req.text,
+        "case_sensitive": req.case_sensitive.unwrap_or(false),
+        "exact": req.exact.unwrap_or(false),
+        "selector_filter": req.selector_filter,
+    });
+    ScriptTemplate::new(include_str!("../scripts/dom_search.js"))
+        .render_json(HashMap::from([("spec", spec)]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::post, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    async fn search(body: &str) -> (u16, serde_json::Value) {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/dom/search", post(dom_search))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains(r#""text":"Sign in""#));
+                assert!(cmd.script.contains(r#""case_sensitive":false"#));
+                let payload = serde_json::json!({
+                    "matches": [{
+                        "selector_path": "header > button",
+                        "tag": "button",
+                        "text_snippet": "Sign in",
+                        "match_count": 1
+                    }]
+                });
+                let encoded = serde_json::to_string(&payload.to_string()).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/dom/search")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status().as_u16();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json = serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null);
+        (status, json)
+    }
+
+    #[test]
+    fn test_build_search_script() {
+        let script = build_search_script(&DomSearchRequest {
+            text: "it's \"quoted\"".to_string(),
+            case_sensitive: Some(true),
+            exact: None,
+            selector_filter: Some("main".to_string()),
+        });
+        assert!(script.contains(
+            r#"const spec = {"case_sensitive":true,"exact":false,"selector_filter":"main","text":"it's \"quoted\""};"#
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_dom_search_handler() {
+        let (status, json) = search(r#"{"text": "Sign in"}"#).await;
+        assert_eq!(status, 200);
+        assert_eq!(json["matches"][0]["selector_path"], "header > button");
+        assert_eq!(json["matches"][0]["match_count"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_dom_search_rejects_empty_text() {
+        let (status, _) = search(r#"{"text": ""}"#).await;
+        assert_eq!(status, 400);
+    }
+}
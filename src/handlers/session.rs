@@ -0,0 +1,239 @@
+//! Handlers that save and load debugging sessions on disk.
+
+use std::path::PathBuf;
+use std::sync::{Arc, PoisonError};
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::Json,
+};
+
+use super::dom::{build_dom_script, DomQuery};
+use super::send_eval;
+use crate::history::now_ms;
+use crate::types::{LoadSessionQuery, SaveSessionRequest, SaveSessionResponse, Session};
+use crate::BridgeState;
+
+/// POST /session/save - Write the DOM, last diagnose result and eval history to disk.
+///
+/// If the app doesn't answer the DOM snapshot eval, the session is saved
+/// without it.
+pub async fn save_session(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<SaveSessionRequest>,
+) -> Result<Json<SaveSessionResponse>, (StatusCode, String)> {
+    let path = session_path(&state, &req.name)?;
+
+    // Taken before the snapshot so the history doesn't include it
+    let history = state.history.entries();
+    let dom = send_eval(&state, build_dom_script(&DomQuery::default()))
+        .await
+        .ok();
+    let diagnose = state
+        .last_diagnose
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone();
+
+    let session = Session {
+        name: req.name.clone(),
+        app: state.app_name.clone(),
+        saved_at_ms: now_ms(),
+        dom,
+        diagnose,
+        history,
+    };
+
+    let json = serde_json::to_string_pretty(&session).map_err(internal_error)?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(internal_error)?;
+    }
+    std::fs::write(&path, json).map_err(internal_error)?;
+
+    Ok(Json(SaveSessionResponse {
+        success: true,
+        name: req.name,
+        path: path.display().to_string(),
+    }))
+}
+
+/// GET /session/load?name=... - Return a saved session.
+pub async fn load_session(
+    State(state): State<Arc<BridgeState>>,
+    Query(query): Query<LoadSessionQuery>,
+) -> Result<Json<Session>, (StatusCode, String)> {
+    let path = session_path(&state, &query.name)?;
+    let json = match std::fs::read_to_string(&path) {
+        Ok(json) => json,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return Err((
+                StatusCode::NOT_FOUND,
+                format!("No session named '{}'", query.name),
+            ))
+        }
+        Err(e) => return Err(internal_error(e)),
+    };
+    let session = serde_json::from_str(&json).map_err(internal_error)?;
+    Ok(Json(session))
+}
+
+fn session_path(state: &BridgeState, name: &str) -> Result<PathBuf, (StatusCode, String)> {
+    if !is_valid_session_name(name) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!(
+                "Invalid session name '{}': use letters, digits, '-', '_' and '.'",
+                name
+            ),
+        ));
+    }
+    let dir = state
+        .session_dir
+        .clone()
+        .or_else(default_session_dir)
+        .ok_or_else(|| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "No session directory: set BridgeConfig::session_dir or HOME".to_string(),
+            )
+        })?;
+    Ok(dir.join(format!("{}.json", name)))
+}
+
+/// Names become file names, so keep them to a single safe path component.
+fn is_valid_session_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+fn default_session_dir() -> Option<PathBuf> {
+    let home = std::env::var_os("HOME")?;
+    Some(PathBuf::from(home).join(".config/dioxus-inspector/sessions"))
+}
+
+fn internal_error(e: impl std::fmt::Display) -> (StatusCode, String) {
+    (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{EvalCommand, EvalResponse};
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tokio::sync::mpsc;
+    use tower::ServiceExt;
+
+    fn session_state(name: &str) -> (Arc<BridgeState>, mpsc::Receiver<EvalCommand>, PathBuf) {
+        let dir = std::env::temp_dir().join(format!(
+            "dioxus-inspector-sessions-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        let (eval_tx, eval_rx) = mpsc::channel(32);
+        let state = Arc::new(BridgeState {
+            session_dir: Some(dir.clone()),
+            ..BridgeState::new("test-app".to_string(), eval_tx)
+        });
+        (state, eval_rx, dir)
+    }
+
+    fn app(state: Arc<BridgeState>) -> Router {
+        Router::new()
+            .route("/session/save", axum::routing::post(save_session))
+            .route("/session/load", get(load_session))
+            .with_state(state)
+    }
+
+    #[test]
+    fn test_is_valid_session_name() {
+        assert!(is_valid_session_name("checkout-bug_2.v1"));
+        assert!(!is_valid_session_name(""));
+        assert!(!is_valid_session_name("../etc/passwd"));
+        assert!(!is_valid_session_name(".hidden"));
+        assert!(!is_valid_session_name("a/b"));
+    }
+
+    #[tokio::test]
+    async fn test_save_and_load_session() {
+        let (state, mut rx, dir) = session_state("roundtrip");
+        state
+            .history
+            .push("return 1".to_string(), EvalResponse::success("1"));
+        *state.last_diagnose.lock().unwrap() = Some(EvalResponse::success("{\"issues\":[]}"));
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                let _ = cmd
+                    .response_tx
+                    .send(EvalResponse::success("{\"tag\":\"body\"}"));
+            }
+        });
+
+        let response = app(state.clone())
+            .oneshot(
+                Request::post("/session/save")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"name": "bug"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        assert!(dir.join("bug.json").exists());
+
+        let response = app(state)
+            .oneshot(
+                Request::get("/session/load?name=bug")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["name"], "bug");
+        assert_eq!(json["app"], "test-app");
+        assert_eq!(json["dom"]["result"], "{\"tag\":\"body\"}");
+        assert_eq!(json["diagnose"]["success"], true);
+        assert_eq!(json["history"][0]["script"], "return 1");
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[tokio::test]
+    async fn test_load_missing_session() {
+        let (state, _rx, _dir) = session_state("missing");
+        let response = app(state)
+            .oneshot(
+                Request::get("/session/load?name=nope")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 404);
+    }
+
+    #[tokio::test]
+    async fn test_save_session_rejects_path_names() {
+        let (state, _rx, _dir) = session_state("invalid");
+        let response = app(state)
+            .oneshot(
+                Request::post("/session/save")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"name": "../escape"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 400);
+    }
+}
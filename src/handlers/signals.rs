@@ -0,0 +1,297 @@
+//! Handlers for reading and watching app signal values.
+
+use std::collections::{HashMap, VecDeque};
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        Json,
+    },
+};
+use futures_util::Stream;
+use serde_json::Value;
+
+use super::{eval_json, eval_json_fresh};
+use crate::types::{SignalChange, SignalWatchQuery, SignalsResponse, DEFAULT_SIGNAL_POLL_MS};
+use crate::BridgeState;
+
+const READ_SIGNALS_SCRIPT: &str =
+    "return JSON.stringify({ signals: window.__dioxus_signals__ ?? {} });";
+
+/// GET /signals - Values the app publishes to `window.__dioxus_signals__`,
+/// e.g. with the `use_signal_bridge` hook.
+pub async fn signals(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<SignalsResponse>, (StatusCode, String)> {
+    let response = eval_json(&state, READ_SIGNALS_SCRIPT.to_string()).await?;
+    Ok(Json(response))
+}
+
+/// Allowed range for [`SignalWatchQuery::interval_ms`].
+const SIGNAL_POLL_RANGE_MS: std::ops::RangeInclusive<u64> = 50..=60_000;
+
+/// State carried between polls of a signal watch stream.
+struct SignalWatcher {
+    state: Arc<BridgeState>,
+    interval: Duration,
+    names: Option<Vec<String>>,
+    previous: HashMap<String, Value>,
+    pending: VecDeque<SignalChange>,
+    done: bool,
+}
+
+/// GET /signals/watch - Stream signal changes as server-sent events.
+///
+/// Reads `window.__dioxus_signals__` every `interval_ms` and sends a
+/// `signal` event for each watched signal whose JSON differs from the
+/// previous read, starting from the values at the time of the request. If
+/// the page stops answering, an `error` event ends the stream.
+pub async fn watch_signals(
+    State(state): State<Arc<BridgeState>>,
+    Query(query): Query<SignalWatchQuery>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, (StatusCode, String)> {
+    let interval_ms = query.interval_ms.unwrap_or(DEFAULT_SIGNAL_POLL_MS);
+    if !SIGNAL_POLL_RANGE_MS.contains(&interval_ms) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!(
+                "interval_ms must be between {} and {}",
+                SIGNAL_POLL_RANGE_MS.start(),
+                SIGNAL_POLL_RANGE_MS.end()
+            ),
+        ));
+    }
+    let names = query.names.as_deref().map(parse_names);
+
+    // Only changes from here on are streamed
+    let start: SignalsResponse = eval_json(&state, READ_SIGNALS_SCRIPT.to_string()).await?;
+    let watcher = SignalWatcher {
+        state,
+        interval: Duration::from_millis(interval_ms),
+        previous: watched(start.signals, names.as_deref()),
+        names,
+        pending: VecDeque::new(),
+        done: false,
+    };
+
+    let stream = futures_util::stream::unfold(watcher, |mut watcher| async move {
+        loop {
+            if watcher.done {
+                return None;
+            }
+            if let Some(change) = watcher.pending.pop_front() {
+                let event = Event::default()
+                    .event("signal")
+                    .json_data(&change)
+                    .unwrap_or_default();
+                return Some((Ok(event), watcher));
+            }
+            tokio::time::sleep(watcher.interval).await;
+            let script = READ_SIGNALS_SCRIPT.to_string();
+            match eval_json_fresh::<SignalsResponse>(&watcher.state, script).await {
+                Ok(read) => {
+                    let current = watched(read.signals, watcher.names.as_deref());
+                    watcher
+                        .pending
+                        .extend(diff_signals(&watcher.previous, &current));
+                    watcher.previous = current;
+                }
+                Err((_, error)) => {
+                    watcher.done = true;
+                    return Some((Ok(Event::default().event("error").data(error)), watcher));
+                }
+            }
+        }
+    });
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+/// Split `counter, message` into names, skipping empty entries.
+fn parse_names(names: &str) -> Vec<String> {
+    names
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// The values of the signals in `names`, or all of them for `None`.
+fn watched(
+    mut signals: HashMap<String, Value>,
+    names: Option<&[String]>,
+) -> HashMap<String, Value> {
+    if let Some(names) = names {
+        signals.retain(|name, _| names.contains(name));
+    }
+    signals
+}
+
+/// Signals whose serialized value differs between two reads, by name.
+fn diff_signals(
+    previous: &HashMap<String, Value>,
+    current: &HashMap<String, Value>,
+) -> Vec<SignalChange> {
+    let mut names: Vec<&String> = previous.keys().chain(current.keys()).collect();
+    names.sort_unstable();
+    names.dedup();
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let old_value = previous.get(name);
+            let new_value = current.get(name);
+            let serialized = |value: Option<&Value>| value.map(Value::to_string);
+            (serialized(old_value) != serialized(new_value)).then(|| SignalChange {
+                name: name.clone(),
+                old_value: old_value.cloned(),
+                new_value: new_value.cloned(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_parse_names() {
+        assert_eq!(
+            parse_names("counter, message,,"),
+            vec!["counter", "message"]
+        );
+        assert!(parse_names("").is_empty());
+    }
+
+    #[test]
+    fn test_diff_signals() {
+        let previous = HashMap::from([
+            ("count".to_string(), serde_json::json!(1)),
+            ("name".to_string(), serde_json::json!("ada")),
+            ("gone".to_string(), serde_json::json!(true)),
+        ]);
+        let current = HashMap::from([
+            ("count".to_string(), serde_json::json!(2)),
+            ("name".to_string(), serde_json::json!("ada")),
+            ("new".to_string(), serde_json::json!([1])),
+        ]);
+        let changes = diff_signals(&previous, &current);
+        let names: Vec<&str> = changes.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["count", "gone", "new"]);
+        assert_eq!(changes[0].old_value, Some(serde_json::json!(1)));
+        assert_eq!(changes[0].new_value, Some(serde_json::json!(2)));
+        assert_eq!(changes[1].new_value, None);
+        assert_eq!(changes[2].old_value, None);
+    }
+
+    #[test]
+    fn test_watched_filters_names() {
+        let signals = HashMap::from([
+            ("count".to_string(), serde_json::json!(1)),
+            ("name".to_string(), serde_json::json!("ada")),
+        ]);
+        let names = vec!["count".to_string()];
+        let filtered = watched(signals.clone(), Some(&names));
+        assert_eq!(filtered.len(), 1);
+        assert!(filtered.contains_key("count"));
+        assert_eq!(watched(signals, None).len(), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_watch_signals_streams_changes() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/signals/watch", get(watch_signals))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            let mut reads = 0;
+            while let Some(cmd) = rx.recv().await {
+                reads += 1;
+                let payload = if reads == 1 {
+                    r#"{"signals": {"counter": 1, "other": 1}}"#
+                } else {
+                    r#"{"signals": {"counter": 2, "other": 2}}"#
+                };
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::get("/signals/watch?names=counter&interval_ms=100")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.headers()["content-type"], "text/event-stream");
+
+        let mut body = response.into_body();
+        let frame = body.frame().await.unwrap().unwrap();
+        let text = String::from_utf8(frame.into_data().unwrap().to_vec()).unwrap();
+        assert!(text.starts_with("event: signal\n"));
+        assert!(text.contains(r#""name":"counter""#));
+        assert!(text.contains(r#""old_value":1"#));
+        assert!(text.contains(r#""new_value":2"#));
+        assert!(!text.contains("other"));
+    }
+
+    #[tokio::test]
+    async fn test_watch_signals_rejects_bad_interval() {
+        let (state, _rx) = create_test_state();
+        let app = Router::new()
+            .route("/signals/watch", get(watch_signals))
+            .with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::get("/signals/watch?interval_ms=0")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn test_signals_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/signals", get(signals))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("window.__dioxus_signals__"));
+                let payload = r#"{"signals": {"count": 3, "user": {"name": "ada"}}}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(Request::get("/signals").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["signals"]["count"], 3);
+        assert_eq!(json["signals"]["user"]["name"], "ada");
+    }
+}
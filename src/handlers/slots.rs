@@ -0,0 +1,91 @@
+//! Handler for inspecting Web Component slots.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+
+use super::eval_json;
+use crate::template::ScriptTemplate;
+use crate::types::{SlotInspectRequest, SlotResponse};
+use crate::BridgeState;
+
+/// POST /slot - List the `<slot>`s in a custom element's open shadow root
+/// and the elements assigned to each.
+///
+/// Elements forwarded through nested slots are resolved to the elements
+/// themselves. Missing hosts and hosts without an open shadow root fail
+/// with an error.
+pub async fn inspect_slots(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<SlotInspectRequest>,
+) -> Result<Json<SlotResponse>, (StatusCode, String)> {
+    let script =
+        ScriptTemplate::new(include_str!("../scripts/slots.js")).render(HashMap::from([(
+            "host_selector",
+            req.host_selector.as_str(),
+        )]));
+    let response = eval_json(&state, script).await?;
+    Ok(Json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::post, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    async fn post_slot(reply: EvalResponse) -> (u16, Vec<u8>) {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/slot", post(inspect_slots))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains(r#"const hostSelector = "my-card";"#));
+                let _ = cmd.response_tx.send(reply);
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/slot")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"host_selector": "my-card"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status().as_u16();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        (status, body.to_vec())
+    }
+
+    #[tokio::test]
+    async fn test_inspect_slots_handler() {
+        let payload = serde_json::json!({"slots": [
+            {"name": "title", "assigned_elements": ["my-card > h2"], "fallback_content": ""},
+            {"name": "", "assigned_elements": [], "fallback_content": "<p>Empty</p>"}
+        ]});
+        let reply = EvalResponse::success(serde_json::to_string(&payload.to_string()).unwrap());
+        let (status, body) = post_slot(reply).await;
+
+        assert_eq!(status, 200);
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["slots"][0]["assigned_elements"][0], "my-card > h2");
+        assert_eq!(json["slots"][1]["fallback_content"], "<p>Empty</p>");
+    }
+
+    #[tokio::test]
+    async fn test_inspect_slots_without_shadow_root() {
+        let reply = EvalResponse::error("Error: No open shadow root on my-card");
+        let (status, body) = post_slot(reply).await;
+
+        assert_eq!(status, 502);
+        assert_eq!(body, b"Error: No open shadow root on my-card");
+    }
+}
@@ -0,0 +1,196 @@
+//! Handlers for taking DOM snapshots and diffing them.
+
+use std::sync::{Arc, PoisonError};
+use std::time::Instant;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+use serde::Deserialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::dom::{build_dom_script, DomQuery};
+use super::eval_json_fresh;
+use crate::dom_diff::{self, DomSnapshot, MAX_DOM_SNAPSHOTS};
+use crate::types::{DomDiffRequest, DomDiffResponse, DomSnapshotResponse};
+use crate::BridgeState;
+
+/// Result of the DOM script.
+#[derive(Deserialize)]
+struct DomResult {
+    root: Option<Value>,
+    error: Option<String>,
+}
+
+/// GET /dom/snapshot - Store the current DOM for a later `/dom/diff`.
+///
+/// The bridge keeps the most recent snapshots only; see
+/// [`MAX_DOM_SNAPSHOTS`].
+pub async fn dom_snapshot(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<DomSnapshotResponse>, (StatusCode, String)> {
+    let (snapshot_id, node_count) = take_snapshot(&state).await?;
+    Ok(Json(DomSnapshotResponse {
+        snapshot_id,
+        node_count,
+    }))
+}
+
+/// POST /dom/diff - Compare two snapshots, taking the "after" one now if
+/// `after_id` is omitted.
+///
+/// Responds with 404 when a snapshot id is unknown or was evicted.
+pub async fn dom_diff(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<DomDiffRequest>,
+) -> Result<Json<DomDiffResponse>, (StatusCode, String)> {
+    let before = stored_root(&state, req.before_id)?;
+    let after_id = match req.after_id {
+        Some(id) => id,
+        None => take_snapshot(&state).await?.0,
+    };
+    let after = stored_root(&state, after_id)?;
+
+    Ok(Json(DomDiffResponse {
+        before_id: req.before_id,
+        after_id,
+        changes: dom_diff::diff(&before, &after),
+    }))
+}
+
+async fn take_snapshot(state: &BridgeState) -> Result<(Uuid, usize), (StatusCode, String)> {
+    let result: DomResult = eval_json_fresh(state, build_dom_script(&DomQuery::default())).await?;
+    if let Some(error) = result.error {
+        return Err((StatusCode::BAD_GATEWAY, error));
+    }
+    let root = result.root.ok_or_else(|| {
+        (
+            StatusCode::BAD_GATEWAY,
+            "DOM script returned no root".to_string(),
+        )
+    })?;
+
+    let id = Uuid::new_v4();
+    let node_count = dom_diff::count_nodes(&root);
+    let mut snapshots = state
+        .dom_snapshots
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    if snapshots.len() >= MAX_DOM_SNAPSHOTS {
+        let oldest = snapshots
+            .iter()
+            .min_by_key(|(_, snapshot)| snapshot.taken_at)
+            .map(|(id, _)| *id);
+        if let Some(oldest) = oldest {
+            snapshots.remove(&oldest);
+        }
+    }
+    snapshots.insert(
+        id,
+        DomSnapshot {
+            root,
+            taken_at: Instant::now(),
+        },
+    );
+    Ok((id, node_count))
+}
+
+fn stored_root(state: &BridgeState, id: Uuid) -> Result<Value, (StatusCode, String)> {
+    state
+        .dom_snapshots
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&id)
+        .map(|snapshot| snapshot.root.clone())
+        .ok_or_else(|| (StatusCode::NOT_FOUND, format!("No snapshot with id {}", id)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{
+        body::Body,
+        http::Request,
+        routing::{get, post},
+        Router,
+    };
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn app(state: Arc<BridgeState>) -> Router {
+        Router::new()
+            .route("/dom/snapshot", get(dom_snapshot))
+            .route("/dom/diff", post(dom_diff))
+            .with_state(state)
+    }
+
+    fn dom_payload(items: &[&str]) -> EvalResponse {
+        let children: Vec<Value> = items
+            .iter()
+            .map(|id| serde_json::json!({"tag": "li", "id": id}))
+            .collect();
+        let payload = serde_json::json!({"root": {"tag": "ul", "children": children}});
+        EvalResponse::success(serde_json::to_string(&payload.to_string()).unwrap())
+    }
+
+    async fn json_body(response: axum::response::Response) -> Value {
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_then_diff_against_fresh_dom() {
+        let (state, mut rx) = create_test_state();
+
+        tokio::spawn(async move {
+            for items in [&["a"][..], &["a", "b"][..]] {
+                if let Some(cmd) = rx.recv().await {
+                    let _ = cmd.response_tx.send(dom_payload(items));
+                }
+            }
+        });
+
+        let response = app(state.clone())
+            .oneshot(Request::get("/dom/snapshot").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let snapshot = json_body(response).await;
+        assert_eq!(snapshot["node_count"], 2);
+
+        let body = serde_json::json!({"before_id": snapshot["snapshot_id"]}).to_string();
+        let response = app(state)
+            .oneshot(
+                Request::post("/dom/diff")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let diff = json_body(response).await;
+        assert_eq!(diff["before_id"], snapshot["snapshot_id"]);
+        assert_ne!(diff["after_id"], snapshot["snapshot_id"]);
+        assert_eq!(diff["changes"][0]["kind"], "added");
+        assert_eq!(diff["changes"][0]["path"], "ul > li#b[1]");
+    }
+
+    #[tokio::test]
+    async fn test_diff_unknown_snapshot() {
+        let (state, _rx) = create_test_state();
+        let body = r#"{"before_id": "67e55044-10b1-426f-9247-bb680e5fe0c8"}"#;
+        let response = app(state)
+            .oneshot(
+                Request::post("/dom/diff")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 404);
+    }
+}
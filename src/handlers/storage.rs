@@ -0,0 +1,282 @@
+//! Handlers for reading and watching browser storage.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        Json,
+    },
+};
+use futures_util::Stream;
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::eval::build_async_script;
+use super::{eval_json, eval_json_fresh};
+use crate::template::ScriptTemplate;
+use crate::types::{
+    IndexedDbRequest, IndexedDbResponse, StorageEvent, StorageWatchQuery, DEFAULT_STORAGE_POLL_MS,
+};
+use crate::BridgeState;
+
+/// How long to wait for IndexedDB, e.g. while another tab holds a lock.
+const INDEXEDDB_TIMEOUT_MS: u64 = 10_000;
+
+/// POST /indexeddb - Read every record of an object store, or one by key.
+///
+/// A missing database or store is reported in `error`; the database is not
+/// created as a side effect.
+pub async fn read_indexeddb(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<IndexedDbRequest>,
+) -> Result<Json<IndexedDbResponse>, (StatusCode, String)> {
+    if req.database.is_empty() || req.store.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Both database and store are required".to_string(),
+        ));
+    }
+    let response = eval_json(&state, build_indexeddb_script(&req)).await?;
+    Ok(Json(response))
+}
+
+fn build_indexeddb_script(req: &IndexedDbRequest) -> String {
+    let body =
+        ScriptTemplate::new(include_str!("../scripts/indexeddb.js")).render_json(HashMap::from([
+            ("database", Value::from(req.database.as_str())),
+            ("store", Value::from(req.store.as_str())),
+            ("key", serde_json::json!(req.key)),
+        ]));
+    build_async_script(&body, Some(INDEXEDDB_TIMEOUT_MS))
+}
+
+/// Allowed range for [`StorageWatchQuery::interval_ms`].
+const STORAGE_POLL_RANGE_MS: std::ops::RangeInclusive<u64> = 50..=60_000;
+
+/// Events recorded by the storage tracker after the cursor.
+#[derive(Deserialize)]
+struct StoragePoll {
+    seq: u64,
+    events: Vec<StorageEvent>,
+}
+
+/// State carried between polls of a storage watch stream.
+struct StorageWatcher {
+    state: Arc<BridgeState>,
+    interval: Duration,
+    cursor: u64,
+    pending: VecDeque<StorageEvent>,
+    done: bool,
+}
+
+/// GET /storage/watch - Stream `localStorage` changes as server-sent events.
+///
+/// Installs a tracker on first use that records `storage` events from other
+/// windows and this window's own `setItem`, `removeItem` and `clear` calls,
+/// then sends each change made after the request as a `storage` event.
+/// Property assignment (`localStorage.key = value`) bypasses the tracker.
+/// If the page stops answering, an `error` event ends the stream.
+pub async fn watch_storage(
+    State(state): State<Arc<BridgeState>>,
+    Query(query): Query<StorageWatchQuery>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, (StatusCode, String)> {
+    let interval_ms = query.interval_ms.unwrap_or(DEFAULT_STORAGE_POLL_MS);
+    if !STORAGE_POLL_RANGE_MS.contains(&interval_ms) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!(
+                "interval_ms must be between {} and {}",
+                STORAGE_POLL_RANGE_MS.start(),
+                STORAGE_POLL_RANGE_MS.end()
+            ),
+        ));
+    }
+
+    // Only changes from here on are streamed
+    let start: StoragePoll = eval_json(&state, build_storage_watch_script(None)).await?;
+    let watcher = StorageWatcher {
+        state,
+        interval: Duration::from_millis(interval_ms),
+        cursor: start.seq,
+        pending: VecDeque::new(),
+        done: false,
+    };
+
+    let stream = futures_util::stream::unfold(watcher, |mut watcher| async move {
+        loop {
+            if watcher.done {
+                return None;
+            }
+            if let Some(event) = watcher.pending.pop_front() {
+                let event = Event::default()
+                    .event("storage")
+                    .json_data(&event)
+                    .unwrap_or_default();
+                return Some((Ok(event), watcher));
+            }
+            tokio::time::sleep(watcher.interval).await;
+            let script = build_storage_watch_script(Some(watcher.cursor));
+            match eval_json_fresh::<StoragePoll>(&watcher.state, script).await {
+                Ok(poll) => {
+                    watcher.cursor = poll.seq;
+                    watcher.pending.extend(poll.events);
+                }
+                Err((_, error)) => {
+                    watcher.done = true;
+                    return Some((Ok(Event::default().event("error").data(error)), watcher));
+                }
+            }
+        }
+    });
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+fn build_storage_watch_script(since: Option<u64>) -> String {
+    ScriptTemplate::new(include_str!("../scripts/storage_watch.js"))
+        .render_json(HashMap::from([("since", serde_json::json!(since))]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{
+        body::Body,
+        http::Request,
+        routing::{get, post},
+        Router,
+    };
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn indexeddb_request(body: serde_json::Value) -> Request<Body> {
+        Request::post("/indexeddb")
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_build_indexeddb_script() {
+        let req = IndexedDbRequest {
+            database: "app\"cache".to_string(),
+            store: "todos".to_string(),
+            key: None,
+        };
+        let script = build_indexeddb_script(&req);
+        assert!(script.contains(r#"const database = "app\"cache";"#));
+        assert!(script.contains("const key = null;"));
+        assert!(script.contains("Script timed out after 10000ms"));
+    }
+
+    #[tokio::test]
+    async fn test_read_indexeddb() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/indexeddb", post(read_indexeddb))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains(r#"const key = "42";"#));
+                let payload = r#"{"records": [{"id": 42, "title": "Ship it"}], "error": null}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(indexeddb_request(serde_json::json!({
+                "database": "app",
+                "store": "todos",
+                "key": "42",
+            })))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["records"][0]["title"], "Ship it");
+        assert!(json["error"].is_null());
+    }
+
+    #[tokio::test]
+    async fn test_read_indexeddb_requires_store() {
+        let (state, _rx) = create_test_state();
+        let app = Router::new()
+            .route("/indexeddb", post(read_indexeddb))
+            .with_state(state);
+
+        let response = app
+            .oneshot(indexeddb_request(serde_json::json!({
+                "database": "app",
+                "store": "",
+            })))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 400);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_watch_storage_streams_new_events() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/storage/watch", get(watch_storage))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            while let Some(cmd) = rx.recv().await {
+                let payload = if cmd.script.contains("const since = null;") {
+                    r#"{"seq": 5, "events": []}"#
+                } else {
+                    assert!(cmd.script.contains("const since = 5;"));
+                    r#"{"seq": 6, "events": [{"seq": 6, "key": "theme", "old_value": "light", "new_value": "dark", "url": "app://index.html", "timestamp_ms": 1700000000000}]}"#
+                };
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(Request::get("/storage/watch").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.headers()["content-type"], "text/event-stream");
+
+        let mut body = response.into_body();
+        let frame = body.frame().await.unwrap().unwrap();
+        let text = String::from_utf8(frame.into_data().unwrap().to_vec()).unwrap();
+        assert!(text.starts_with("event: storage\n"));
+        assert!(text.contains(r#""key":"theme""#));
+        assert!(text.contains(r#""new_value":"dark""#));
+    }
+
+    #[tokio::test]
+    async fn test_watch_storage_rejects_bad_interval() {
+        let (state, _rx) = create_test_state();
+        let app = Router::new()
+            .route("/storage/watch", get(watch_storage))
+            .with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::get("/storage/watch?interval_ms=0")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 400);
+    }
+}
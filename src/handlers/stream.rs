@@ -0,0 +1,268 @@
+//! Handlers for streaming the results of polled evals.
+
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        Json,
+    },
+};
+use futures_util::Stream;
+
+use super::{decode_result, send_eval, send_eval_fresh};
+use crate::types::{EvalResponse, StreamEvalRequest};
+use crate::BridgeState;
+
+/// Allowed range for [`StreamEvalRequest::interval_ms`].
+const STREAM_POLL_RANGE_MS: std::ops::RangeInclusive<u64> = 50..=60_000;
+/// Allowed range for [`StreamEvalRequest::max_events`].
+const STREAM_EVENTS_RANGE: std::ops::RangeInclusive<u32> = 1..=1_000;
+
+/// State carried between polls of an eval stream.
+struct StreamPoller {
+    state: Arc<BridgeState>,
+    script: String,
+    interval: Duration,
+    remaining: u32,
+    started: bool,
+    done: bool,
+}
+
+/// POST /eval/stream - Run a setup script, then stream poll results as
+/// server-sent events.
+///
+/// Each poll's [`EvalResponse`] is sent as a `result` event, the first
+/// right after `setup_script`. The stream ends after `max_events` results,
+/// once `poll_script` returns `null`, or after the first failed poll, which
+/// is still sent. A failing `setup_script` is reported as 502 before the
+/// stream starts.
+pub async fn eval_stream(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<StreamEvalRequest>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, (StatusCode, String)> {
+    validate_stream_eval(&req).map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+    if !req.setup_script.is_empty() {
+        let setup = send_eval(&state, req.setup_script)
+            .await
+            .map_err(|status| (status, status.to_string()))?;
+        if !setup.success {
+            let error = setup.error.unwrap_or_else(|| "Unknown error".to_string());
+            return Err((
+                StatusCode::BAD_GATEWAY,
+                format!("setup_script failed: {}", error),
+            ));
+        }
+    }
+
+    let poller = StreamPoller {
+        state,
+        script: req.poll_script,
+        interval: Duration::from_millis(req.interval_ms),
+        remaining: req.max_events,
+        started: false,
+        done: false,
+    };
+
+    let stream = futures_util::stream::unfold(poller, |mut poller| async move {
+        if poller.done || poller.remaining == 0 {
+            return None;
+        }
+        if poller.started {
+            tokio::time::sleep(poller.interval).await;
+        }
+        poller.started = true;
+        // Polls must not be answered from the dedup cache
+        let response = match send_eval_fresh(&poller.state, poller.script.clone()).await {
+            Ok(response) => response,
+            Err(status) => EvalResponse::error(status.to_string()),
+        };
+        if is_null_result(&response) {
+            return None;
+        }
+        poller.remaining -= 1;
+        poller.done = !response.success;
+        let event = Event::default()
+            .event("result")
+            .json_data(&response)
+            .unwrap_or_default();
+        Some((Ok(event), poller))
+    });
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+fn validate_stream_eval(req: &StreamEvalRequest) -> Result<(), String> {
+    if req.poll_script.trim().is_empty() {
+        return Err("poll_script is required".to_string());
+    }
+    if !STREAM_POLL_RANGE_MS.contains(&req.interval_ms) {
+        return Err(format!(
+            "interval_ms must be between {} and {}",
+            STREAM_POLL_RANGE_MS.start(),
+            STREAM_POLL_RANGE_MS.end()
+        ));
+    }
+    if !STREAM_EVENTS_RANGE.contains(&req.max_events) {
+        return Err(format!(
+            "max_events must be between {} and {}",
+            STREAM_EVENTS_RANGE.start(),
+            STREAM_EVENTS_RANGE.end()
+        ));
+    }
+    Ok(())
+}
+
+/// Whether a successful poll returned `null`, directly or as
+/// `JSON.stringify(null)`.
+fn is_null_result(response: &EvalResponse) -> bool {
+    response.success
+        && response.result.is_some()
+        && matches!(
+            decode_result::<serde_json::Value>(response.clone()),
+            Ok(serde_json::Value::Null)
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use axum::{body::Body, http::Request, routing::post, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn stream_request(body: serde_json::Value) -> Request<Body> {
+        Request::post("/eval/stream")
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_validate_stream_eval() {
+        let req = |poll_script: &str, interval_ms, max_events| StreamEvalRequest {
+            setup_script: String::new(),
+            poll_script: poll_script.to_string(),
+            interval_ms,
+            max_events,
+        };
+        assert!(validate_stream_eval(&req("return 1", 100, 10)).is_ok());
+        assert!(validate_stream_eval(&req(" ", 100, 10)).is_err());
+        assert!(validate_stream_eval(&req("return 1", 0, 10)).is_err());
+        assert!(validate_stream_eval(&req("return 1", 100, 0)).is_err());
+    }
+
+    #[test]
+    fn test_is_null_result() {
+        assert!(is_null_result(&EvalResponse::success("null")));
+        assert!(is_null_result(&EvalResponse::success(r#""null""#)));
+        assert!(!is_null_result(&EvalResponse::success("0")));
+        assert!(!is_null_result(&EvalResponse::error("null")));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_eval_stream_until_null() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/eval/stream", post(eval_stream))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            let mut progress = 0;
+            while let Some(cmd) = rx.recv().await {
+                let result = if cmd.script == "window.__progress = 0" {
+                    "null".to_string()
+                } else {
+                    progress += 50;
+                    if progress > 100 {
+                        "null".to_string()
+                    } else {
+                        progress.to_string()
+                    }
+                };
+                let _ = cmd.response_tx.send(EvalResponse::success(result));
+            }
+        });
+
+        let response = app
+            .oneshot(stream_request(serde_json::json!({
+                "setup_script": "window.__progress = 0",
+                "poll_script": "return window.__progress",
+                "interval_ms": 100,
+                "max_events": 10
+            })))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.headers()["content-type"], "text/event-stream");
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let text = String::from_utf8(body.to_vec()).unwrap();
+        assert_eq!(text.matches("event: result\n").count(), 2);
+        assert!(text.contains(r#""result":"50""#));
+        assert!(text.contains(r#""result":"100""#));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_eval_stream_stops_at_max_events() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/eval/stream", post(eval_stream))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            while let Some(cmd) = rx.recv().await {
+                let _ = cmd.response_tx.send(EvalResponse::success("1"));
+            }
+        });
+
+        let response = app
+            .oneshot(stream_request(serde_json::json!({
+                "poll_script": "return 1",
+                "interval_ms": 100,
+                "max_events": 3
+            })))
+            .await
+            .unwrap();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let text = String::from_utf8(body.to_vec()).unwrap();
+        assert_eq!(text.matches("event: result\n").count(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_eval_stream_reports_failed_setup() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/eval/stream", post(eval_stream))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                let _ = cmd
+                    .response_tx
+                    .send(EvalResponse::error("ReferenceError: startImport"));
+            }
+        });
+
+        let response = app
+            .oneshot(stream_request(serde_json::json!({
+                "setup_script": "startImport()",
+                "poll_script": "return 1",
+                "interval_ms": 100,
+                "max_events": 3
+            })))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 502);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(
+            String::from_utf8(body.to_vec()).unwrap(),
+            "setup_script failed: ReferenceError: startImport"
+        );
+    }
+}
@@ -0,0 +1,176 @@
+//! Handlers for injecting CSS at runtime.
+
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+
+use super::eval_json;
+use crate::types::{InjectCssRequest, InjectCssResponse, RemoveCssRequest};
+use crate::BridgeState;
+
+/// Prefix of the ids of injected `<style>` elements.
+const STYLE_ID_PREFIX: &str = "dioxus-inspector-";
+
+/// POST /inject-css - Add or replace a `<style>` element in the document head.
+pub async fn inject_css(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<InjectCssRequest>,
+) -> Result<Json<InjectCssResponse>, (StatusCode, String)> {
+    validate_style_id(&req.id).map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+    let response = eval_json(&state, build_inject_css_script(&req.id, &req.css)).await?;
+    Ok(Json(response))
+}
+
+/// POST /remove-css - Remove a style added by `/inject-css`.
+///
+/// `success` is false when no style with that id exists.
+pub async fn remove_css(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<RemoveCssRequest>,
+) -> Result<Json<InjectCssResponse>, (StatusCode, String)> {
+    validate_style_id(&req.id).map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+    let response = eval_json(&state, build_remove_css_script(&req.id)).await?;
+    Ok(Json(response))
+}
+
+fn validate_style_id(id: &str) -> Result<(), String> {
+    let valid = !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "Invalid style id '{}', use letters, digits, '-' and '_'",
+            id
+        ))
+    }
+}
+
+fn build_inject_css_script(id: &str, css: &str) -> String {
+    let style_id = serde_json::to_string(&format!("{}{}", STYLE_ID_PREFIX, id))
+        .unwrap_or_else(|_| "\"\"".to_string());
+    let css = serde_json::to_string(css).unwrap_or_else(|_| "\"\"".to_string());
+    format!(
+        r#"return (() => {{
+    const id = {};
+    let style = document.getElementById(id);
+    if (!style) {{
+        style = document.createElement("style");
+        style.id = id;
+        document.head.appendChild(style);
+    }}
+    style.textContent = {};
+    return JSON.stringify({{ success: true, style_id: id }});
+}})()"#,
+        style_id, css
+    )
+}
+
+fn build_remove_css_script(id: &str) -> String {
+    let style_id = serde_json::to_string(&format!("{}{}", STYLE_ID_PREFIX, id))
+        .unwrap_or_else(|_| "\"\"".to_string());
+    format!(
+        r#"return (() => {{
+    const id = {};
+    const style = document.getElementById(id);
+    if (style) style.remove();
+    return JSON.stringify({{ success: Boolean(style), style_id: id }});
+}})()"#,
+        style_id
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::post, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn app() -> (
+        Router,
+        tokio::sync::mpsc::Receiver<crate::types::EvalCommand>,
+    ) {
+        let (state, rx) = create_test_state();
+        let app = Router::new()
+            .route("/inject-css", post(inject_css))
+            .route("/remove-css", post(remove_css))
+            .with_state(state);
+        (app, rx)
+    }
+
+    fn json_request(path: &str, body: serde_json::Value) -> Request<Body> {
+        Request::post(path)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_build_inject_css_script() {
+        let script = build_inject_css_script("wide", ".a::after { content: \"x\" }");
+        assert!(script.contains(r#"const id = "dioxus-inspector-wide";"#));
+        assert!(script.contains(r#"style.textContent = ".a::after { content: \"x\" }";"#));
+    }
+
+    #[test]
+    fn test_validate_style_id() {
+        assert!(validate_style_id("wider-sidebar_2").is_ok());
+        assert!(validate_style_id("").is_err());
+        assert!(validate_style_id("a b").is_err());
+        assert!(validate_style_id("\"><script>").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_inject_and_remove_css() {
+        let (app, mut rx) = app();
+        tokio::spawn(async move {
+            while let Some(cmd) = rx.recv().await {
+                let success = cmd.script.contains("textContent");
+                let payload = serde_json::json!({
+                    "success": success,
+                    "style_id": "dioxus-inspector-wide"
+                })
+                .to_string();
+                let encoded = serde_json::to_string(&payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .clone()
+            .oneshot(json_request(
+                "/inject-css",
+                serde_json::json!({"id": "wide", "css": "main { width: 100% }"}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["success"], true);
+        assert_eq!(json["style_id"], "dioxus-inspector-wide");
+
+        let response = app
+            .clone()
+            .oneshot(json_request(
+                "/remove-css",
+                serde_json::json!({"id": "wide"}),
+            ))
+            .await
+            .unwrap();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["success"], false);
+
+        let response = app
+            .oneshot(json_request("/remove-css", serde_json::json!({"id": ""})))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 400);
+    }
+}
@@ -0,0 +1,151 @@
+//! Handlers for inspecting and animating SVG elements.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+use serde_json::Value;
+
+use super::eval_json;
+use crate::template::ScriptTemplate;
+use crate::types::{SvgAnimateRequest, SvgAnimateResponse, SvgResponse};
+use crate::BridgeState;
+
+/// GET /svg - List every `<svg>` and its descendants with their geometry
+/// attributes.
+pub async fn svg_elements(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<SvgResponse>, (StatusCode, String)> {
+    let script = ScriptTemplate::new(include_str!("../scripts/svg.js")).render(HashMap::new());
+    let response = eval_json(&state, script).await?;
+    Ok(Json(response))
+}
+
+/// POST /svg/animate - Animate an attribute of an SVG element by appending
+/// an `<animate>` element to it.
+pub async fn animate_svg(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<SvgAnimateRequest>,
+) -> Result<Json<SvgAnimateResponse>, (StatusCode, String)> {
+    if req.attribute.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "attribute must not be empty".to_string(),
+        ));
+    }
+    if req.duration_ms == 0 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "duration_ms must be at least 1".to_string(),
+        ));
+    }
+    let response = eval_json(&state, build_animate_script(&req)).await?;
+    Ok(Json(response))
+}
+
+fn build_animate_script(req: &SvgAnimateRequest) -> String {
+    ScriptTemplate::new(include_str!("../scripts/svg_animate.js")).render_json(HashMap::from([
+        ("selector", Value::from(req.selector.as_str())),
+        ("attribute", Value::from(req.attribute.as_str())),
+        ("from", Value::from(req.from.as_str())),
+        ("to", Value::from(req.to.as_str())),
+        ("duration_ms", Value::from(req.duration_ms)),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{
+        body::Body,
+        http::Request,
+        routing::{get, post},
+        Router,
+    };
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn animate_request(duration_ms: u64) -> SvgAnimateRequest {
+        SvgAnimateRequest {
+            selector: "circle#dot".to_string(),
+            attribute: "r".to_string(),
+            from: "5".to_string(),
+            to: "{{duration_ms}}".to_string(),
+            duration_ms,
+        }
+    }
+
+    #[test]
+    fn test_build_animate_script() {
+        let script = build_animate_script(&animate_request(500));
+        assert!(script.contains(r##"const selector = "circle#dot";"##));
+        assert!(script.contains(r#"setAttribute("attributeName", "r")"#));
+        assert!(script.contains(r#"setAttribute("from", "5")"#));
+        assert!(script.contains(r#"setAttribute("to", "{{duration_ms}}")"#));
+        assert!(script.contains(r#"setAttribute("dur", 500 + "ms")"#));
+        assert!(script.contains("beginElement()"));
+    }
+
+    #[tokio::test]
+    async fn test_svg_elements_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/svg", get(svg_elements))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                let payload = serde_json::json!({
+                    "elements": [
+                        {"selector_path": "svg#chart", "tag": "svg", "view_box": "0 0 100 100",
+                         "width": "200", "height": "200", "preserve_aspect_ratio": null,
+                         "transform": null, "d": null, "cx": null, "cy": null, "r": null,
+                         "points": null},
+                        {"selector_path": "svg#chart > circle", "tag": "circle", "view_box": null,
+                         "width": null, "height": null, "preserve_aspect_ratio": null,
+                         "transform": null, "d": null, "cx": "50", "cy": "50", "r": "10",
+                         "points": null}
+                    ]
+                });
+                let encoded = serde_json::to_string(&payload.to_string()).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(Request::get("/svg").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["elements"][0]["view_box"], "0 0 100 100");
+        assert_eq!(json["elements"][1]["r"], "10");
+    }
+
+    #[tokio::test]
+    async fn test_animate_svg_rejects_zero_duration() {
+        let (state, _rx) = create_test_state();
+        let app = Router::new()
+            .route("/svg/animate", post(animate_svg))
+            .with_state(state);
+
+        let body = serde_json::json!({
+            "selector": "circle", "attribute": "r", "from": "5", "to": "20", "duration_ms": 0
+        });
+        let response = app
+            .oneshot(
+                Request::post("/svg/animate")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 400);
+    }
+}
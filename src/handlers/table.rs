@@ -0,0 +1,75 @@
+//! Handler for reading HTML tables.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+
+use super::eval_json;
+use crate::template::ScriptTemplate;
+use crate::types::{TableRequest, TableResponse};
+use crate::BridgeState;
+
+/// POST /table - Extract the header and cell text of the first `<table>`
+/// matching `selector`.
+///
+/// `<tfoot>` rows are left out. Merged cells are repeated in every slot they
+/// cover and listed in `merged_cells`.
+pub async fn extract_table(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<TableRequest>,
+) -> Result<Json<TableResponse>, (StatusCode, String)> {
+    let script = ScriptTemplate::new(include_str!("../scripts/table.js"))
+        .render(HashMap::from([("selector", req.selector.as_str())]));
+    let response = eval_json(&state, script).await?;
+    Ok(Json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::post, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_extract_table_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/table", post(extract_table))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains(r#"const selector = "table.orders";"#));
+                let payload = serde_json::json!({
+                    "headers": ["Item", "Qty"],
+                    "rows": [["Apples", "3"], ["Total", "Total"]],
+                    "row_count": 2,
+                    "merged_cells": [{"row": 1, "column": 0, "rowspan": 1, "colspan": 2}]
+                });
+                let encoded = serde_json::to_string(&payload.to_string()).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/table")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"selector": "table.orders"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["headers"][1], "Qty");
+        assert_eq!(json["row_count"], 2);
+        assert_eq!(json["merged_cells"][0]["colspan"], 2);
+    }
+}
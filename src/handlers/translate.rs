@@ -0,0 +1,182 @@
+//! Handlers for simulating translated text.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+use serde::Deserialize;
+
+use super::eval_json;
+use crate::template::ScriptTemplate;
+use crate::types::{TranslateRequest, TranslateResponse, TranslateRestoreResponse};
+use crate::BridgeState;
+
+const RESTORE_SCRIPT: &str = r#"return (() => {
+    const backup = window.__dioxusInspectorTranslation__ || [];
+    let restored = 0;
+    for (const entry of backup) {
+        // Leave nodes alone if the app has rendered new text into them
+        if (entry.node.isConnected && entry.node.nodeValue === entry.translated) {
+            entry.node.nodeValue = entry.original;
+            restored += 1;
+        }
+    }
+    window.__dioxusInspectorTranslation__ = [];
+    return JSON.stringify({ restored_count: restored });
+})()"#;
+
+/// Result of the translate script; `found` is false when nothing matched.
+#[derive(Deserialize)]
+struct TranslateResult {
+    found: bool,
+    replaced_count: u32,
+}
+
+/// POST /translate - Replace text in the page's text nodes.
+///
+/// Original text is kept in the page so `/translate/restore` can put it
+/// back. Responds with 404 when no element matches the selector.
+pub async fn translate(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<TranslateRequest>,
+) -> Result<Json<TranslateResponse>, (StatusCode, String)> {
+    if req.replacements.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "replacements must not be empty".to_string(),
+        ));
+    }
+    if req.replacements.contains_key("") {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "replacement keys must not be empty".to_string(),
+        ));
+    }
+    let result: TranslateResult = eval_json(&state, build_translate_script(&req)).await?;
+    if !result.found {
+        return Err((
+            StatusCode::NOT_FOUND,
+            format!(
+                "Element not found: {}",
+                req.selector.as_deref().unwrap_or_default()
+            ),
+        ));
+    }
+    Ok(Json(TranslateResponse {
+        replaced_count: result.replaced_count,
+    }))
+}
+
+/// POST /translate/restore - Put back the text changed by `/translate`.
+pub async fn restore_translation(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<TranslateRestoreResponse>, (StatusCode, String)> {
+    let response = eval_json(&state, RESTORE_SCRIPT.to_string()).await?;
+    Ok(Json(response))
+}
+
+fn build_translate_script(req: &TranslateRequest) -> String {
+    ScriptTemplate::new(include_str!("../scripts/translate.js")).render_json(HashMap::from([
+        ("selector", serde_json::json!(req.selector)),
+        ("replacements", serde_json::json!(req.replacements)),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::post, Router};
+    use http_body_util::BodyExt;
+    use std::collections::HashMap;
+    use tower::ServiceExt;
+
+    fn app() -> (
+        Router,
+        tokio::sync::mpsc::Receiver<crate::types::EvalCommand>,
+    ) {
+        let (state, rx) = create_test_state();
+        let app = Router::new()
+            .route("/translate", post(translate))
+            .route("/translate/restore", post(restore_translation))
+            .with_state(state);
+        (app, rx)
+    }
+
+    fn translate_request(body: &'static str) -> Request<Body> {
+        Request::post("/translate")
+            .header("content-type", "application/json")
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_build_translate_script() {
+        let req = TranslateRequest {
+            replacements: HashMap::from([("Save".to_string(), "Speichern".to_string())]),
+            selector: None,
+        };
+        let script = build_translate_script(&req);
+        assert!(script.contains("const selector = null;"));
+        assert!(script.contains(r#"const replacements = {"Save":"Speichern"};"#));
+    }
+
+    #[tokio::test]
+    async fn test_translate_handler() {
+        let (app, mut rx) = app();
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains(r#"const selector = "nav";"#));
+                let encoded =
+                    serde_json::to_string(r#"{"found": true, "replaced_count": 3}"#).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(translate_request(
+                r#"{"replacements": {"Home": "Startseite"}, "selector": "nav"}"#,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["replaced_count"], 3);
+    }
+
+    #[tokio::test]
+    async fn test_translate_rejects_empty_keys() {
+        let (app, _rx) = app();
+        for body in [r#"{"replacements": {}}"#, r#"{"replacements": {"": "x"}}"#] {
+            let response = app.clone().oneshot(translate_request(body)).await.unwrap();
+            assert_eq!(response.status(), 400);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_restore_translation() {
+        let (app, mut rx) = app();
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("entry.original"));
+                let encoded = serde_json::to_string(r#"{"restored_count": 2}"#).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/translate/restore")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["restored_count"], 2);
+    }
+}
@@ -0,0 +1,61 @@
+//! Handler for reconstructing the Dioxus component tree from the DOM.
+
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+
+use super::eval_json;
+use crate::types::ComponentTreeResponse;
+use crate::BridgeState;
+
+/// GET /tree - Nest the elements carrying `data-dioxus-id` by their DOM
+/// ancestry.
+///
+/// Dioxus only emits the attribute in debug builds, so release builds return
+/// a root without children.
+pub async fn component_tree(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<ComponentTreeResponse>, (StatusCode, String)> {
+    let script = include_str!("../scripts/component_tree.js").to_string();
+    let response = eval_json(&state, script).await?;
+    Ok(Json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_component_tree_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/tree", get(component_tree))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("data-dioxus-id"));
+                let payload = r#"{"root": {"name": null, "id": "root", "children": [{"name": "Counter", "id": "3", "children": []}]}}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(Request::get("/tree").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["root"]["id"], "root");
+        assert_eq!(json["root"]["children"][0]["name"], "Counter");
+    }
+}
@@ -0,0 +1,451 @@
+//! Handlers that read and change how the webview renders the page.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+
+use super::window::build_resize_script;
+use super::{eval_json, send_eval};
+use crate::template::ScriptTemplate;
+use crate::types::{
+    DarkModeResponse, SetDarkModeRequest, SetViewportRequest, ViewportResponse,
+    WindowStateResponse, ZoomRequest, ZoomResponse,
+};
+use crate::BridgeState;
+
+const READ_ZOOM_SCRIPT: &str = r#"return (() => {
+    return JSON.stringify({
+        success: true,
+        level: parseFloat(document.body.style.zoom) || 1,
+        device_pixel_ratio: window.devicePixelRatio
+    });
+})()"#;
+
+/// GET /zoom - Read the document zoom level and device pixel ratio.
+pub async fn get_zoom(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<ZoomResponse>, (StatusCode, String)> {
+    let response = eval_json(&state, READ_ZOOM_SCRIPT.to_string()).await?;
+    Ok(Json(response))
+}
+
+/// POST /zoom - Set the CSS zoom level of `document.body`.
+pub async fn set_zoom(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<ZoomRequest>,
+) -> Result<Json<ZoomResponse>, (StatusCode, String)> {
+    if !req.level.is_finite() || req.level <= 0.0 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Zoom level must be a positive number, got {}", req.level),
+        ));
+    }
+    let response = eval_json(&state, build_set_zoom_script(req.level)).await?;
+    Ok(Json(response))
+}
+
+fn build_set_zoom_script(level: f64) -> String {
+    format!(
+        r#"return (() => {{
+    document.body.style.zoom = ({}).toString();
+    return JSON.stringify({{
+        success: true,
+        level: parseFloat(document.body.style.zoom) || 1,
+        device_pixel_ratio: window.devicePixelRatio
+    }});
+}})()"#,
+        level
+    )
+}
+
+/// GET /viewport - Read the viewport and window dimensions.
+pub async fn get_viewport(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<ViewportResponse>, (StatusCode, String)> {
+    let response = eval_json(&state, build_viewport_script(None)).await?;
+    Ok(Json(response))
+}
+
+/// POST /viewport - Resize the viewport to simulate a screen size.
+///
+/// Sends the same resize command as `/resize` for apps that handle it, then
+/// calls `window.resizeTo()` in the webview. Returns the dimensions read right
+/// after, which may lag behind the resize if the window manager applies it
+/// asynchronously.
+pub async fn set_viewport(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<SetViewportRequest>,
+) -> Result<Json<ViewportResponse>, (StatusCode, String)> {
+    if req.width == 0 || req.height == 0 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!(
+                "Viewport size must be non-zero, got {}x{}",
+                req.width, req.height
+            ),
+        ));
+    }
+
+    send_eval(&state, build_resize_script(req.width, req.height, None))
+        .await
+        .map_err(|status| (status, status.to_string()))?;
+    let script = build_viewport_script(Some((req.width, req.height)));
+    let response = eval_json(&state, script).await?;
+    Ok(Json(response))
+}
+
+pub(super) fn build_viewport_script(size: Option<(u32, u32)>) -> String {
+    let size = size.map(|(width, height)| serde_json::json!({ "width": width, "height": height }));
+    ScriptTemplate::new(include_str!("../scripts/viewport.js"))
+        .render_json(HashMap::from([("size", serde_json::json!(size))]))
+}
+
+const WINDOW_STATE_SCRIPT: &str = r#"return (() => {
+    return JSON.stringify({
+        title: document.title,
+        url: window.location.href,
+        ready_state: document.readyState,
+        visibility_state: document.visibilityState,
+        has_focus: document.hasFocus(),
+        scroll_x: window.scrollX,
+        scroll_y: window.scrollY,
+        inner_width: window.innerWidth,
+        inner_height: window.innerHeight,
+        outer_width: window.outerWidth,
+        outer_height: window.outerHeight,
+        screen_x: window.screenX,
+        screen_y: window.screenY,
+        device_pixel_ratio: window.devicePixelRatio
+    });
+})()"#;
+
+/// GET /window/state - Read the document's title, URL, load and focus state
+/// together with the window's scroll position, size and placement.
+///
+/// Covers what would otherwise take `/status`, `/viewport` and a custom eval.
+pub async fn window_state(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<WindowStateResponse>, (StatusCode, String)> {
+    let response = eval_json(&state, WINDOW_STATE_SCRIPT.to_string()).await?;
+    Ok(Json(response))
+}
+
+/// GET /dark-mode - Read the system color scheme and the document's `data-theme`.
+pub async fn get_dark_mode(
+    State(state): State<Arc<BridgeState>>,
+) -> Result<Json<DarkModeResponse>, (StatusCode, String)> {
+    let response = eval_json(&state, build_dark_mode_script(None)).await?;
+    Ok(Json(response))
+}
+
+/// POST /dark-mode - Set `data-theme` on `<html>` to `dark` or `light`.
+///
+/// Only apps that style from `data-theme` follow this; the system preference
+/// reported as `system_dark` can't be changed from the webview.
+pub async fn set_dark_mode(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<SetDarkModeRequest>,
+) -> Result<Json<DarkModeResponse>, (StatusCode, String)> {
+    let response = eval_json(&state, build_dark_mode_script(Some(req.dark))).await?;
+    Ok(Json(response))
+}
+
+fn build_dark_mode_script(dark: Option<bool>) -> String {
+    let apply = match dark {
+        Some(dark) => format!(
+            "root.setAttribute(\"data-theme\", \"{}\");",
+            if dark { "dark" } else { "light" }
+        ),
+        None => String::new(),
+    };
+    format!(
+        r#"return (() => {{
+    const root = document.documentElement;
+    {}
+    const theme = root.getAttribute("data-theme");
+    return JSON.stringify({{
+        system_dark: window.matchMedia("(prefers-color-scheme: dark)").matches,
+        document_dark: theme === "dark",
+        data_theme: theme
+    }});
+}})()"#,
+        apply
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn app() -> (
+        Router,
+        tokio::sync::mpsc::Receiver<crate::types::EvalCommand>,
+    ) {
+        let (state, rx) = create_test_state();
+        let app = Router::new()
+            .route("/zoom", get(get_zoom).post(set_zoom))
+            .with_state(state);
+        (app, rx)
+    }
+
+    #[test]
+    fn test_build_set_zoom_script() {
+        let script = build_set_zoom_script(1.5);
+        assert!(script.contains("document.body.style.zoom = (1.5).toString()"));
+    }
+
+    #[test]
+    fn test_build_dark_mode_script() {
+        let script = build_dark_mode_script(None);
+        assert!(!script.contains("setAttribute"));
+        assert!(script.contains("prefers-color-scheme: dark"));
+
+        let script = build_dark_mode_script(Some(true));
+        assert!(script.contains(r#"root.setAttribute("data-theme", "dark");"#));
+        let script = build_dark_mode_script(Some(false));
+        assert!(script.contains(r#"root.setAttribute("data-theme", "light");"#));
+    }
+
+    #[tokio::test]
+    async fn test_set_dark_mode() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/dark-mode", get(get_dark_mode).post(set_dark_mode))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains(r#""data-theme", "dark""#));
+                let payload =
+                    r#"{"system_dark": false, "document_dark": true, "data_theme": "dark"}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/dark-mode")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"dark": true}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["document_dark"], true);
+        assert_eq!(json["data_theme"], "dark");
+    }
+
+    const VIEWPORT_PAYLOAD: &str = r#"{"inner_width": 375, "inner_height": 812, "outer_width": 375, "outer_height": 840, "device_pixel_ratio": 3, "orientation": "portrait"}"#;
+
+    #[test]
+    fn test_build_viewport_script() {
+        assert!(build_viewport_script(None).contains("const size = null;"));
+        let script = build_viewport_script(Some((375, 812)));
+        assert!(script.contains(r#"const size = {"height":812,"width":375};"#));
+    }
+
+    #[tokio::test]
+    async fn test_get_viewport() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/viewport", get(get_viewport))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("innerWidth"));
+                let encoded = serde_json::to_string(VIEWPORT_PAYLOAD).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(Request::get("/viewport").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["inner_width"], 375);
+        assert_eq!(json["orientation"], "portrait");
+    }
+
+    #[tokio::test]
+    async fn test_window_state() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/window/state", get(window_state))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("document.hasFocus()"));
+                let payload = serde_json::json!({
+                    "title": "Home",
+                    "url": "http://localhost/",
+                    "ready_state": "complete",
+                    "visibility_state": "visible",
+                    "has_focus": true,
+                    "scroll_x": 0,
+                    "scroll_y": 120.5,
+                    "inner_width": 375,
+                    "inner_height": 812,
+                    "outer_width": 375,
+                    "outer_height": 840,
+                    "screen_x": -1440,
+                    "screen_y": 0,
+                    "device_pixel_ratio": 3
+                });
+                let encoded = serde_json::to_string(&payload.to_string()).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(Request::get("/window/state").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["ready_state"], "complete");
+        assert_eq!(json["scroll_y"], 120.5);
+        assert_eq!(json["screen_x"], -1440);
+    }
+
+    #[tokio::test]
+    async fn test_set_viewport_sends_resize_then_reads() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/viewport", get(get_viewport).post(set_viewport))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            let cmd = rx.recv().await.unwrap();
+            assert!(cmd.script.contains("__DIOXUS_INSPECTOR_RESIZE__375x812__"));
+            let _ = cmd.response_tx.send(EvalResponse::success("ok"));
+
+            let cmd = rx.recv().await.unwrap();
+            assert!(cmd.script.contains("window.resizeTo"));
+            let encoded = serde_json::to_string(VIEWPORT_PAYLOAD).unwrap();
+            let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/viewport")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"width": 375, "height": 812}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["inner_height"], 812);
+    }
+
+    #[tokio::test]
+    async fn test_set_viewport_rejects_zero_size() {
+        let (state, _rx) = create_test_state();
+        let app = Router::new()
+            .route("/viewport", get(get_viewport).post(set_viewport))
+            .with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::post("/viewport")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"width": 0, "height": 600}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn test_get_zoom() {
+        let (app, mut rx) = app();
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("devicePixelRatio"));
+                let payload = r#"{"success": true, "level": 1, "device_pixel_ratio": 2}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(Request::get("/zoom").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["level"], 1.0);
+        assert_eq!(json["device_pixel_ratio"], 2.0);
+    }
+
+    #[tokio::test]
+    async fn test_set_zoom() {
+        let (app, mut rx) = app();
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("(1.25).toString()"));
+                let payload = r#"{"success": true, "level": 1.25, "device_pixel_ratio": 2}"#;
+                let encoded = serde_json::to_string(payload).unwrap();
+                let _ = cmd.response_tx.send(EvalResponse::success(encoded));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/zoom")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"level": 1.25}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["level"], 1.25);
+    }
+
+    #[tokio::test]
+    async fn test_set_zoom_rejects_non_positive_level() {
+        let (app, _rx) = app();
+
+        let response = app
+            .oneshot(
+                Request::post("/zoom")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"level": 0}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 400);
+    }
+}
@@ -0,0 +1,175 @@
+//! Handler for waiting until an element appears, disappears or changes.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+use serde::Deserialize;
+use tokio::time::Instant;
+
+use super::eval_json_fresh;
+use crate::template::ScriptTemplate;
+use crate::types::{WatchCondition, WatchRequest, WatchResponse};
+use crate::BridgeState;
+
+/// How often the condition is checked.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Longest `timeout_ms` accepted, so a request can't hold a connection forever.
+const MAX_WATCH_TIMEOUT_MS: u64 = 5 * 60 * 1000;
+
+/// Result of one check.
+#[derive(Deserialize)]
+struct WatchCheck {
+    matched: bool,
+    value: Option<String>,
+}
+
+/// POST /watch - Wait until an element meets a condition, like Playwright's
+/// `waitForSelector`.
+///
+/// Checks every 100ms and answers as soon as the condition holds, or with
+/// `matched: false` once `timeout_ms` has passed. Responds with 400 for
+/// timeouts over five minutes.
+pub async fn watch(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<WatchRequest>,
+) -> Result<Json<WatchResponse>, (StatusCode, String)> {
+    if req.timeout_ms > MAX_WATCH_TIMEOUT_MS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!(
+                "timeout_ms must be at most {}, got {}",
+                MAX_WATCH_TIMEOUT_MS, req.timeout_ms
+            ),
+        ));
+    }
+
+    let script = build_watch_script(&req.selector, &req.condition);
+    let timeout = Duration::from_millis(req.timeout_ms);
+    let started = Instant::now();
+    loop {
+        let check: WatchCheck = eval_json_fresh(&state, script.clone()).await?;
+        let elapsed = started.elapsed();
+        if check.matched || elapsed >= timeout {
+            return Ok(Json(WatchResponse {
+                matched: check.matched,
+                elapsed_ms: elapsed.as_millis() as u64,
+                value: check.value,
+            }));
+        }
+        tokio::time::sleep(POLL_INTERVAL.min(timeout - elapsed)).await;
+    }
+}
+
+fn build_watch_script(selector: &str, condition: &WatchCondition) -> String {
+    let expected = match condition {
+        WatchCondition::TextEquals { value } => value.as_str(),
+        _ => "",
+    };
+    ScriptTemplate::new(include_str!("../scripts/watch.js")).render(HashMap::from([
+        ("selector", selector),
+        ("condition", condition.name()),
+        ("value", expected),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, routing::post, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn check(matched: bool) -> EvalResponse {
+        let payload = format!(r#"{{"matched": {}, "value": "Saved"}}"#, matched);
+        EvalResponse::success(serde_json::to_string(&payload).unwrap())
+    }
+
+    async fn post_watch(app: Router, body: serde_json::Value) -> (StatusCode, serde_json::Value) {
+        let response = app
+            .oneshot(
+                Request::post("/watch")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        (status, serde_json::from_slice(&body).unwrap_or_default())
+    }
+
+    #[test]
+    fn test_build_watch_script() {
+        let condition = WatchCondition::TextEquals {
+            value: "Say \"hi\"".to_string(),
+        };
+        let script = build_watch_script("#toast", &condition);
+        assert!(script.contains(r##"document.querySelector("#toast")"##));
+        assert!(script.contains(r#"const condition = "text_equals";"#));
+        assert!(script.contains(r#"const expected = "Say \"hi\"";"#));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_watch_polls_until_matched() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new().route("/watch", post(watch)).with_state(state);
+
+        tokio::spawn(async move {
+            let mut polls = 0;
+            while let Some(cmd) = rx.recv().await {
+                polls += 1;
+                let _ = cmd.response_tx.send(check(polls == 3));
+            }
+        });
+
+        let (status, json) = post_watch(
+            app,
+            serde_json::json!({"selector": "#toast", "condition": {"type": "visible"}}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["matched"], true);
+        assert_eq!(json["elapsed_ms"], 200);
+        assert_eq!(json["value"], "Saved");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_watch_times_out() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new().route("/watch", post(watch)).with_state(state);
+
+        tokio::spawn(async move {
+            while let Some(cmd) = rx.recv().await {
+                let _ = cmd.response_tx.send(check(false));
+            }
+        });
+
+        let (status, json) = post_watch(
+            app,
+            serde_json::json!({"selector": "#toast", "condition": {"type": "exists"}, "timeout_ms": 250}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["matched"], false);
+        assert_eq!(json["elapsed_ms"], 250);
+    }
+
+    #[tokio::test]
+    async fn test_watch_rejects_long_timeout() {
+        let (state, _rx) = create_test_state();
+        let app = Router::new().route("/watch", post(watch)).with_state(state);
+
+        let (status, _) = post_watch(
+            app,
+            serde_json::json!({"selector": "#toast", "condition": {"type": "exists"}, "timeout_ms": 600_000}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+}
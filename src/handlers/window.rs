@@ -0,0 +1,342 @@
+//! Handlers that capture, resize and move the window.
+
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::Json};
+
+use super::send_eval;
+use crate::screenshot::capture_screenshot;
+use crate::types::{
+    MoveRequest, MoveResponse, ResizeRequest, ResizeResponse, ScreenshotRequest, ScreenshotResponse,
+};
+use crate::BridgeState;
+
+/// POST /screenshot - Capture window.
+#[cfg(not(tarpaulin_include))]
+pub async fn screenshot(
+    State(state): State<Arc<BridgeState>>,
+    body: Option<Json<ScreenshotRequest>>,
+) -> Json<ScreenshotResponse> {
+    let req = body.map(|j| j.0).unwrap_or_default();
+    let format = req.format.unwrap_or_default();
+    let output_path = req
+        .path
+        .unwrap_or_else(|| format!("/tmp/dioxus-screenshot.{}", format.extension()));
+
+    match capture_screenshot(&state.app_name, &output_path, format) {
+        Ok(()) => Json(ScreenshotResponse {
+            success: true,
+            path: Some(output_path),
+            mime_type: Some(format.mime_type().to_string()),
+            error: None,
+        }),
+        Err(e) => Json(ScreenshotResponse {
+            success: false,
+            path: None,
+            mime_type: None,
+            error: Some(e),
+        }),
+    }
+}
+
+/// Anchors accepted by `/resize`.
+const RESIZE_ANCHORS: &[&str] = &[
+    "top-left",
+    "top-right",
+    "bottom-left",
+    "bottom-right",
+    "center",
+];
+
+/// POST /resize - Resize the window.
+///
+/// Sends a resize command via eval. The app must handle the special
+/// `__DIOXUS_INSPECTOR_RESIZE__` script pattern to apply the resize; see
+/// [`ResizeRequest`] for the format. Responds with 400 naming the problem
+/// for a zero width or height, or an unknown anchor.
+pub async fn resize(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<ResizeRequest>,
+) -> Result<Json<ResizeResponse>, (StatusCode, String)> {
+    validate_resize(&req).map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    let script = build_resize_script(req.width, req.height, req.anchor.as_deref());
+    let response = send_eval(&state, script)
+        .await
+        .map_err(|status| (status, status.to_string()))?;
+
+    if response.success {
+        Ok(Json(ResizeResponse {
+            success: true,
+            width: req.width,
+            height: req.height,
+            anchor: req.anchor,
+            error: None,
+        }))
+    } else {
+        Ok(Json(ResizeResponse {
+            success: false,
+            width: req.width,
+            height: req.height,
+            anchor: req.anchor,
+            error: response.error,
+        }))
+    }
+}
+
+fn validate_resize(req: &ResizeRequest) -> Result<(), String> {
+    if req.width == 0 || req.height == 0 {
+        return Err(format!(
+            "Invalid size {}x{}: width and height must be greater than 0",
+            req.width, req.height
+        ));
+    }
+    match &req.anchor {
+        Some(anchor) if !RESIZE_ANCHORS.contains(&anchor.as_str()) => Err(format!(
+            "Unknown anchor {:?}: expected one of {}",
+            anchor,
+            RESIZE_ANCHORS.join(", ")
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Build the special script that the app can intercept to resize its window.
+///
+/// Format: `__DIOXUS_INSPECTOR_RESIZE__{width}x{height}__`, with
+/// `@{anchor}` before the closing underscores when an anchor is given.
+pub(super) fn build_resize_script(width: u32, height: u32, anchor: Option<&str>) -> String {
+    match anchor {
+        Some(anchor) => format!(
+            "return '__DIOXUS_INSPECTOR_RESIZE__{}x{}@{}__'",
+            width, height, anchor
+        ),
+        None => format!("return '__DIOXUS_INSPECTOR_RESIZE__{}x{}__'", width, height),
+    }
+}
+
+/// POST /move - Move the window.
+///
+/// Sends a move command via eval. The app must handle the special
+/// `__DIOXUS_INSPECTOR_MOVE__` script pattern to apply the move; see
+/// [`MoveRequest`] for the format.
+pub async fn move_window(
+    State(state): State<Arc<BridgeState>>,
+    Json(req): Json<MoveRequest>,
+) -> Result<Json<MoveResponse>, StatusCode> {
+    let response = send_eval(&state, build_move_script(req.x, req.y, req.monitor)).await?;
+
+    Ok(Json(MoveResponse {
+        success: response.success,
+        x: req.x,
+        y: req.y,
+        error: response.error,
+    }))
+}
+
+/// Build the special script that the app can intercept to move its window.
+///
+/// Format: `__DIOXUS_INSPECTOR_MOVE__{x},{y}__`, with `@monitor{n}` before
+/// the closing underscores when a monitor is given.
+fn build_move_script(x: i32, y: i32, monitor: Option<u32>) -> String {
+    match monitor {
+        Some(n) => format!(
+            "return '__DIOXUS_INSPECTOR_MOVE__{},{}@monitor{}__'",
+            x, y, n
+        ),
+        None => format!("return '__DIOXUS_INSPECTOR_MOVE__{},{}__'", x, y),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::create_test_state;
+    use crate::types::EvalResponse;
+    use axum::{body::Body, http::Request, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_build_resize_script() {
+        assert_eq!(
+            build_resize_script(800, 600, None),
+            "return '__DIOXUS_INSPECTOR_RESIZE__800x600__'"
+        );
+        assert_eq!(
+            build_resize_script(800, 600, Some("center")),
+            "return '__DIOXUS_INSPECTOR_RESIZE__800x600@center__'"
+        );
+    }
+
+    #[test]
+    fn test_build_move_script() {
+        assert_eq!(
+            build_move_script(100, -50, None),
+            "return '__DIOXUS_INSPECTOR_MOVE__100,-50__'"
+        );
+        assert_eq!(
+            build_move_script(100, 50, Some(1)),
+            "return '__DIOXUS_INSPECTOR_MOVE__100,50@monitor1__'"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resize_handler_success() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/resize", axum::routing::post(resize))
+            .with_state(state);
+
+        // Spawn responder
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd.script.contains("800x600"));
+                let _ = cmd.response_tx.send(EvalResponse::success(
+                    "__DIOXUS_INSPECTOR_RESIZE__800x600__",
+                ));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/resize")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"width": 800, "height": 600}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(json["success"], true);
+        assert_eq!(json["width"], 800);
+        assert_eq!(json["height"], 600);
+    }
+
+    #[tokio::test]
+    async fn test_resize_handler_error() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/resize", axum::routing::post(resize))
+            .with_state(state);
+
+        // Spawn responder with error
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                let _ = cmd
+                    .response_tx
+                    .send(EvalResponse::error("Window not found"));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/resize")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"width": 800, "height": 600}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(json["success"], false);
+        assert_eq!(json["error"], "Window not found");
+    }
+
+    #[tokio::test]
+    async fn test_move_window_handler() {
+        let (state, mut rx) = create_test_state();
+        let app = Router::new()
+            .route("/move", axum::routing::post(move_window))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            if let Some(cmd) = rx.recv().await {
+                assert!(cmd
+                    .script
+                    .contains("__DIOXUS_INSPECTOR_MOVE__10,20@monitor2__"));
+                let _ = cmd.response_tx.send(EvalResponse::success("ok"));
+            }
+        });
+
+        let response = app
+            .oneshot(
+                Request::post("/move")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"x": 10, "y": 20, "monitor": 2}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["success"], true);
+        assert_eq!(json["x"], 10);
+        assert_eq!(json["y"], 20);
+    }
+
+    #[tokio::test]
+    async fn test_resize_handler_rejects_unknown_anchor() {
+        let (state, _rx) = create_test_state();
+        let app = Router::new()
+            .route("/resize", axum::routing::post(resize))
+            .with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::post("/resize")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r#"{"width": 800, "height": 600, "anchor": "middle"}"#,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 400);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(
+            String::from_utf8_lossy(&body),
+            "Unknown anchor \"middle\": expected one of top-left, top-right, bottom-left, bottom-right, center"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resize_handler_rejects_zero_size() {
+        let (state, _rx) = create_test_state();
+        let app = Router::new()
+            .route("/resize", axum::routing::post(resize))
+            .with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::post("/resize")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"width": 0, "height": 600}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 400);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(
+            String::from_utf8_lossy(&body),
+            "Invalid size 0x600: width and height must be greater than 0"
+        );
+    }
+}
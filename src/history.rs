@@ -0,0 +1,146 @@
+//! Ring buffer of recent evals sent through the bridge.
+
+use std::collections::VecDeque;
+use std::sync::{Mutex, PoisonError};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::EvalResponse;
+
+/// Number of evals kept by default.
+pub const DEFAULT_MAX_HISTORY: usize = 100;
+
+/// A script sent to the app and the response it produced.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvalHistoryEntry {
+    /// The JavaScript that was evaluated.
+    pub script: String,
+    /// The app's response, or the bridge error if none arrived.
+    pub response: EvalResponse,
+    /// When the response was recorded, in milliseconds since the Unix epoch.
+    pub timestamp_ms: u64,
+}
+
+/// The last `capacity` evals, oldest first.
+pub struct EvalHistory {
+    entries: Mutex<VecDeque<EvalHistoryEntry>>,
+    capacity: usize,
+}
+
+impl EvalHistory {
+    /// Create an empty history keeping at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity,
+        }
+    }
+
+    /// Record an eval, evicting the oldest entry when full.
+    pub fn push(&self, script: String, response: EvalResponse) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        if entries.len() == self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(EvalHistoryEntry {
+            script,
+            response,
+            timestamp_ms: now_ms(),
+        });
+    }
+
+    /// A copy of the recorded entries, oldest first.
+    pub fn entries(&self) -> Vec<EvalHistoryEntry> {
+        let entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries.iter().cloned().collect()
+    }
+
+    /// A copy of the most recent `n` entries, oldest first.
+    pub fn last(&self, n: usize) -> Vec<EvalHistoryEntry> {
+        let entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        let skip = entries.len().saturating_sub(n);
+        entries.iter().skip(skip).cloned().collect()
+    }
+
+    /// Remove all entries, returning how many there were.
+    pub fn clear(&self) -> usize {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        let cleared = entries.len();
+        entries.clear();
+        cleared
+    }
+
+    /// Maximum number of entries kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl Default for EvalHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_HISTORY)
+    }
+}
+
+/// Milliseconds since the Unix epoch.
+pub(crate) fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Microseconds since the Unix epoch.
+pub(crate) fn now_us() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_micros() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_keeps_last_entries() {
+        let history = EvalHistory::new(2);
+        for i in 0..3 {
+            history.push(
+                format!("return {}", i),
+                EvalResponse::success(i.to_string()),
+            );
+        }
+        let scripts: Vec<_> = history.entries().into_iter().map(|e| e.script).collect();
+        assert_eq!(scripts, ["return 1", "return 2"]);
+    }
+
+    #[test]
+    fn test_history_last_and_clear() {
+        let history = EvalHistory::new(5);
+        for i in 0..3 {
+            history.push(
+                format!("return {}", i),
+                EvalResponse::success(i.to_string()),
+            );
+        }
+        let scripts: Vec<_> = history.last(2).into_iter().map(|e| e.script).collect();
+        assert_eq!(scripts, ["return 1", "return 2"]);
+        assert_eq!(history.last(10).len(), 3);
+        assert!(history.last(0).is_empty());
+
+        assert_eq!(history.clear(), 3);
+        assert!(history.entries().is_empty());
+    }
+
+    #[test]
+    fn test_history_with_zero_capacity() {
+        let history = EvalHistory::new(0);
+        history.push("return 1".to_string(), EvalResponse::success("1"));
+        assert!(history.entries().is_empty());
+    }
+}
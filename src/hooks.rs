@@ -0,0 +1,86 @@
+//! Dioxus hooks that expose app state to the bridge.
+//!
+//! Requires the `hooks` feature.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use serde_json::{Map, Value};
+
+/// How often [`use_signal_bridge`] pushes values to the page.
+pub const SIGNAL_PUSH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Named accessors returning the current value of some app state.
+pub type SignalAccessors = HashMap<String, Box<dyn Fn() -> String>>;
+
+/// Publish values to `window.__dioxus_signals__`, where `GET /signals` reads
+/// them, every [`SIGNAL_PUSH_INTERVAL`].
+///
+/// Accessors returning valid JSON are published as that JSON, anything else
+/// as a string. Only the accessors passed on the first render are used, so
+/// have them capture signals and read them with `peek()`:
+///
+/// ```rust,ignore
+/// let count = use_signal(|| 0);
+/// use_signal_bridge(HashMap::from([(
+///     "count".to_string(),
+///     Box::new(move || count.peek().to_string()) as Box<dyn Fn() -> String>,
+/// )]));
+/// ```
+///
+/// Several components can each call the hook; their values are merged.
+pub fn use_signal_bridge(accessors: SignalAccessors) {
+    use_hook(move || {
+        spawn(async move {
+            let mut interval = tokio::time::interval(SIGNAL_PUSH_INTERVAL);
+            loop {
+                interval.tick().await;
+                let _ = document::eval(&push_script(&accessors)).await;
+            }
+        })
+    });
+}
+
+/// Script merging the accessors' current values into `window.__dioxus_signals__`.
+fn push_script(accessors: &SignalAccessors) -> String {
+    let values: Map<String, Value> = accessors
+        .iter()
+        .map(|(name, accessor)| {
+            let raw = accessor();
+            let value = serde_json::from_str(&raw).unwrap_or(Value::String(raw));
+            (name.clone(), value)
+        })
+        .collect();
+    format!(
+        "Object.assign(window.__dioxus_signals__ ??= {{}}, {});",
+        Value::Object(values)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_script_keeps_json_values() {
+        let accessors: SignalAccessors = HashMap::from([
+            (
+                "count".to_string(),
+                Box::new(|| "3".to_string()) as Box<dyn Fn() -> String>,
+            ),
+            (
+                "user".to_string(),
+                Box::new(|| "not json".to_string()) as Box<dyn Fn() -> String>,
+            ),
+        ]);
+        let script = push_script(&accessors);
+        let json = script
+            .strip_prefix("Object.assign(window.__dioxus_signals__ ??= {}, ")
+            .and_then(|s| s.strip_suffix(");"))
+            .unwrap();
+        let values: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(values["count"], 3);
+        assert_eq!(values["user"], "not json");
+    }
+}
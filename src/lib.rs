@@ -49,53 +49,249 @@
 //!
 //! | Endpoint | Method | Purpose |
 //! |----------|--------|---------|
-//! | `/status` | GET | App status, PID, uptime |
+//! | `/status` | GET | App status, PID, uptime, eval queue and connection counters |
+//! | `/ping` | GET | Liveness check that skips the app, with server time |
+//! | `/config` | GET | Current bridge settings, such as the default eval timeout |
+//! | `/config/eval-timeout` | PUT | Set how long evals without their own timeout wait for the app |
 //! | `/eval` | POST | Execute JavaScript in webview |
+//! | `/eval/conditional` | POST | Execute JavaScript only if a CSS selector matches |
+//! | `/eval/module` | POST | Execute JavaScript as an ES module, returning its default export |
+//! | `/eval/async` | POST | Execute JavaScript that may `await`, returning the resolved value |
+//! | `/eval/stream` | POST | Run a setup script, then stream the results of polling another as server-sent events |
+//! | `/eval/sandbox` | POST | Execute JavaScript in a Web Worker with a timeout and output size limit |
+//! | `/eval/parallel` | POST | Run several scripts concurrently, timing each, with a per-script timeout |
+//! | `/eval/named` | GET | List stored script templates |
+//! | `/eval/named/{name}` | PUT/POST/DELETE | Store a script template, run it with `{{param}}` values filled in, or remove it |
 //! | `/query` | POST | Query DOM by CSS selector |
-//! | `/dom` | GET | Get simplified DOM tree |
+//! | `/query/all` | POST | Query every element matching a CSS selector |
+//! | `/dom` | GET | Get simplified DOM tree (`?include_shadow=true` enters shadow roots, `?page_size=` or `?cursor=` returns it flattened a page at a time) |
+//! | `/dom/snapshot` | GET | Store the current DOM and return its snapshot id |
+//! | `/dom/diff` | POST | Structural diff between two snapshots, or a snapshot and the current DOM |
+//! | `/dom/search` | POST | Find elements by text content, with selector paths, snippets and match counts |
+//! | `/dom/ancestors` | POST | Lowest common ancestor of every element matching a list of selectors |
+//! | `/tree` | GET | Component tree rebuilt from `data-dioxus-id` elements (debug builds) |
 //! | `/inspect` | POST | Element visibility analysis |
+//! | `/inspect/layout` | POST | Flexbox and grid properties of a container and its children |
+//! | `/computed-layout` | POST | Resolved box model of an element, with its flex and grid values |
+//! | `/inspect/parent` | POST | Ancestors of an element with their display, overflow, visibility, z-index and position |
+//! | `/inspect/stacking` | POST | Elements in a subtree that create a stacking context, with the styles responsible |
+//! | `/intersection` | GET | Whether each element matching `?selector=` is in the viewport, and how much of it |
 //! | `/validate-classes` | POST | Check CSS class availability |
 //! | `/diagnose` | GET | Quick UI health check |
 //! | `/screenshot` | POST | Capture window (macOS only) |
 //! | `/resize` | POST | Resize window (requires app handling) |
+//! | `/move` | POST | Move window (requires app handling) |
+//! | `/drag` | POST | Simulate HTML5 drag-and-drop |
+//! | `/touch` | POST | Dispatch a touchstart, touchmove, touchend or touchcancel event on an element |
+//! | `/validate-colors` | POST | Check WCAG contrast ratios |
+//! | `/network` | GET | Captured fetch/XHR requests |
+//! | `/errors` | GET | Uncaught JS errors and rejections since the last read (`?clear=false` keeps them) |
+//! | `/event-log/watch` | POST | Record events of given types on elements matching a selector |
+//! | `/event-log` | GET | Events recorded by watches since the last read (`?clear=false` keeps them) |
+//! | `/mutation-log/start` | POST | Start recording DOM mutations under `<body>` |
+//! | `/mutation-log` | GET/DELETE | Mutation counts and recent entries since the start, or stop recording |
+//! | `/reload` | POST | Reload the page (`{"soft": true}` re-navigates instead) |
+//! | `/reload/hot` | POST | Ask the Dioxus dev server to hot-reload over its WebSocket |
+//! | `/zoom` | GET/POST | Read or set the document zoom level |
+//! | `/metrics` | GET | Prometheus metrics (requires the `metrics` feature) |
+//! | `/select` | POST | Select a text range inside an element |
+//! | `/animations` | GET/POST | List running animations, or pause/play/cancel them |
+//! | `/session/save` | POST | Save DOM, last diagnose and eval history to disk |
+//! | `/session/load` | GET | Load a saved session (`?name=...`) |
+//! | `/diagnose/register` | POST | Register a custom health check script |
+//! | `/diagnose/custom` | GET | Run all registered health checks |
+//! | `/diagnose/performance` | GET | Long tasks, layout thrashing, DOM size, animations without `will-change` and `document.write`, with a 0-100 score |
+//! | `/measure` | POST | Time a script over repeated runs with `performance.measure` |
+//! | `/viewport` | GET/POST | Read viewport dimensions, or resize to simulate a screen size |
+//! | `/window/state` | GET | Document title, URL, ready and focus state plus window scroll, size and position in one call |
+//! | `/history` | GET/DELETE | Recent evals (`?last=N`), or clear them |
+//! | `/trace` | GET/DELETE | Timing and outcome of the last 500 evals, or clear them |
+//! | `/data-attributes` | POST | List `data-*` attributes of the first matching element |
+//! | `/tab-order` | GET | Focusable elements in keyboard tab order |
+//! | `/computed-styles` | POST | Computed CSS properties of the first matching element |
+//! | `/scroll-into-view` | POST | Scroll the first matching element into view |
+//! | `/clipboard` | GET/POST | Read or write the clipboard text (the window must have focus) |
+//! | `/media` | GET | Playback state of audio/video elements (`?selector=`) |
+//! | `/media/control` | POST | Play, pause, seek, mute or set the volume of media elements |
+//! | `/canvas` | POST | Capture a canvas element as a data URL, optionally saving it to disk |
+//! | `/form` | POST | Fill form fields by name and optionally submit |
+//! | `/dark-mode` | GET/POST | Read `prefers-color-scheme` and `data-theme`, or set `data-theme` to dark/light |
+//! | `/indexeddb` | POST | Read records from an IndexedDB object store |
+//! | `/responsive` | POST | Resize the window to several sizes, recording the DOM and optional screenshots |
+//! | `/breakpoints/simulate` | POST | Step the window through breakpoints with a delay at each, reporting viewport, DOM changes and optional screenshots |
+//! | `/aria` | POST | Read the ARIA attributes of an element, optionally setting some first |
+//! | `/role` | GET | Elements with an ARIA role (`?role=button&name=Submit`), with accessible name, description and states |
+//! | `/watch` | POST | Wait until an element exists, is visible, is hidden or has some text |
+//! | `/coverage/start` | POST | Start collecting JS coverage through the app's `window.__coverage_start` hook |
+//! | `/coverage/report` | GET | Executed byte ranges per script, from `window.__coverage_report` |
+//! | `/signals` | GET | Values published to `window.__dioxus_signals__`, e.g. by the `use_signal_bridge` hook |
+//! | `/signals/watch` | GET | Stream signal changes as server-sent events (`?names=a,b&interval_ms=500`) |
+//! | `/storage/watch` | GET | Stream `localStorage` changes as server-sent events |
+//! | `/geo/mock` | POST | Make `navigator.geolocation` report a fixed position or error |
+//! | `/geo/mock` | DELETE | Restore the real Geolocation API |
+//! | `/media-query` | GET | Whether `?query=` matches, via `window.matchMedia` |
+//! | `/media-query/all` | GET | Which of the common breakpoints (640, 768, 1024 and 1280px max-width) match |
+//! | `/translate` | POST | Replace text in text nodes from a dictionary, to test layouts with translated strings |
+//! | `/translate/restore` | POST | Put back the text changed by `/translate` |
+//! | `/inject-css` | POST | Add or replace a `<style id="dioxus-inspector-{id}">` element in the document head |
+//! | `/remove-css` | POST | Remove a style added by `/inject-css` |
+//! | `/fonts/missing` | GET | Font families in use that failed to load or aren't installed, still loading, or loaded |
+//! | `/image-status` | GET | Load state and `alt` text of every `<img>`, counting broken images and missing `alt` |
+//! | `/simulate-network` | POST | Make `fetch` slow or fail as if offline (XHR and WebSockets are unaffected) |
+//! | `/simulate-network` | DELETE | Restore the real `fetch` |
+//! | `/snapshot` | GET | Capture the DOM, CSS variables, JS errors, network log, performance diagnosis and optionally a screenshot as a ZIP archive (`?screenshot=true`) |
+//! | `/snapshot/{id}` | GET | Download an archive stored by `/snapshot` |
+//! | `/colors` | GET | Distinct colors used by text, backgrounds, borders and SVG, with usage counts |
+//! | `/svg` | GET | Geometry attributes (`viewBox`, `d`, `cx`/`cy`/`r`, `points`, ...) of every `<svg>` and its children |
+//! | `/svg/animate` | POST | Animate an SVG attribute by adding an `<animate>` element |
+//! | `/contrast-audit` | GET | Check WCAG contrast of all visible text against its background, as AA failures and AAA warnings |
+//! | `/input-state` | GET | Values and validation state of every form control, optionally within one form (`?form_selector=`) |
+//! | `/element/create` | POST | Insert an element with attributes and text into or next to another, e.g. a test fixture |
+//! | `/element/remove` | DELETE | Remove every element matching `?selector=` |
+//! | `/table` | POST | Header and cell text of a `<table>`, with merged cells spread out and listed |
+//! | `/list` | POST | Text, link and `data-*` attributes of each item of a list (`ul`, `ol`, `dl`, ARIA `listbox`, ...) |
+//! | `/slot` | POST | Slots of a custom element's shadow root with their assigned elements and fallback content |
+//! | `/scroll/all` | GET | Scroll position and size of every scrollable element |
 //!
 //! ## Platform Support
 //!
 //! - **Screenshot capture**: macOS only (uses Core Graphics)
 //! - **All other features**: Cross-platform
 
+mod access;
+mod config;
+mod connections;
+mod contrast;
+mod dedup;
+mod discovery;
+mod dom_diff;
+mod dom_page;
 mod handlers;
+mod history;
+#[cfg(feature = "hooks")]
+pub mod hooks;
+mod log_filter;
+mod routes;
 mod screenshot;
+mod server;
+mod state;
+mod template;
+mod trace;
+mod transport;
 mod types;
 
+pub use access::RateLimiter;
+#[cfg(feature = "tls")]
+pub use config::TlsConfig;
+pub use config::{BackpressureStrategy, BridgeConfig, Transport};
+pub use discovery::{lock_file_path, BridgeLock, LOCK_FILE_NAME};
+pub use dom_diff::{DomSnapshot, MAX_DOM_SNAPSHOTS};
+pub use history::{EvalHistory, EvalHistoryEntry, DEFAULT_MAX_HISTORY};
+pub use state::BridgeState;
+pub use trace::{EvalTrace, TraceEntry, MAX_TRACE_ENTRIES};
+
 pub use types::{
-    EvalCommand, EvalRequest, EvalResponse, QueryRequest, ResizeRequest, ResizeResponse,
-    StatusResponse,
+    AncestorRequest, AncestorResponse, AnimationControlRequest, AnimationEntry, AnimationsResponse,
+    AriaRequest, AriaResponse, AsyncEvalRequest, BoundingRect, BoxEdges, BoxModel,
+    BreakpointResult, BreakpointSimRequest, BreakpointSimResponse, BreakpointStep,
+    BridgeConfigResponse, CanvasRequest, CanvasResponse, CheckResult, ClearHistoryResponse,
+    ClipboardResponse, ColorContrastResult, ColorEntry, ColorPair, ColorsResponse, ComponentNode,
+    ComponentTreeResponse, ComputedLayoutRequest, ComputedLayoutResponse, ComputedStylesRequest,
+    ComputedStylesResponse, ConditionalEvalRequest, ContrastAuditRequest, ContrastAuditResponse,
+    ContrastFailure, ContrastSample, ContrastWarning, CoverageResponse, CoverageScript,
+    CoverageStartResponse, CreateElementRequest, CreateElementResponse, CustomCheck,
+    CustomCheckRequest, CustomDiagnoseResponse, DarkModeResponse, DataAttributesRequest,
+    DataAttributesResponse, DomChange, DomChangeKind, DomDiffRequest, DomDiffResponse, DomEvent,
+    DomNode, DomPageResponse, DomSearchRequest, DomSearchResponse, DomSnapshotResponse,
+    DragRequest, EvalCommand, EvalRequest, EvalResponse, EvalResult, EvalTimeoutRequest,
+    EventLogQuery, EventLogResponse, EventWatchRequest, EventWatchResponse, FlexProperties,
+    FormFillRequest, FormFillResponse, GeoMockRequest, GeoMockResponse, GridProperties,
+    HistoryQuery, HistoryResponse, HotReloadResponse, ImageStatus, ImageStatusResponse,
+    IndexedDbRequest, IndexedDbResponse, InjectCssRequest, InjectCssResponse, InputState,
+    InputStateQuery, InputStateResponse, IntersectionEntry, IntersectionQuery,
+    IntersectionResponse, JsError, JsErrorsQuery, JsErrorsResponse, LayoutChildInfo,
+    LayoutInspectRequest, LayoutInspectResponse, ListItem, ListRequest, ListResponse,
+    LoadSessionQuery, MeasureRequest, MeasureResponse, MediaControlRequest, MediaElementState,
+    MediaQuery, MediaQueryAllResponse, MediaQueryParams, MediaQueryResponse, MediaResponse,
+    MediaTimeRange, MergedCell, MissingFontsResponse, ModuleEvalRequest, MoveRequest, MoveResponse,
+    MutationEntry, MutationKind, MutationLogStartRequest, MutationLogStatusResponse,
+    MutationSummaryResponse, NamedEvalRequest, NamedScript, NamedScriptRequest,
+    NamedScriptsResponse, NetworkCondition, NetworkRequest, NetworkResponse, NetworkSimRequest,
+    NetworkSimResponse, ParallelEvalRequest, ParallelEvalResponse, ParentChainRequest,
+    ParentChainResponse, ParentNode, PerfDiagnoseResponse, PerfIssue, PingResponse,
+    QueryAllRequest, QueryAllResponse, QueryRequest, RegisterCheckResponse, ReloadRequest,
+    ReloadResponse, RemoveCssRequest, RemoveElementQuery, RemoveElementResponse, ResizeRequest,
+    ResizeResponse, ResponsiveResult, ResponsiveTestRequest, ResponsiveTestResponse, RoleElement,
+    RoleQuery, RoleQueryResponse, SandboxEvalRequest, SandboxEvalResponse, SaveSessionRequest,
+    SaveSessionResponse, ScreenshotFormat, ScrollContainer, ScrollContainersResponse,
+    ScrollIntoViewRequest, SearchMatch, SelectRequest, SelectResponse, Session,
+    SetClipboardRequest, SetDarkModeRequest, SetViewportRequest, SignalChange, SignalWatchQuery,
+    SignalsResponse, SlotInfo, SlotInspectRequest, SlotResponse, SnapshotArchiveQuery,
+    SnapshotManifest, StackingContext, StackingInspectRequest, StackingResponse, StatusResponse,
+    StorageEvent, StorageWatchQuery, StreamEvalRequest, SvgAnimateRequest, SvgAnimateResponse,
+    SvgElement, SvgResponse, TabOrderEntry, TabOrderResponse, TableRequest, TableResponse,
+    TextSize, TouchPoint, TouchRequest, TraceResponse, TranslateRequest, TranslateResponse,
+    TranslateRestoreResponse, ValidateColorsRequest, ValidateColorsResponse, ViewportResponse,
+    ViewportSize, WatchCondition, WatchRequest, WatchResponse, WcagLevel, WindowStateResponse,
+    ZoomRequest, ZoomResponse, COMMON_BREAKPOINTS, DEFAULT_BREAKPOINT_DELAY_MS,
+    DEFAULT_EVENT_LOG_MAX_EVENTS, DEFAULT_MUTATION_LOG_ENTRIES, DEFAULT_PARENT_DEPTH,
+    DEFAULT_SANDBOX_MAX_OUTPUT_BYTES, DEFAULT_SANDBOX_TIMEOUT_MS, DEFAULT_SIGNAL_POLL_MS,
+    DEFAULT_STORAGE_POLL_MS, DEFAULT_WATCH_TIMEOUT_MS,
 };
 
-use axum::{routing::get, Router};
+use discovery::LockGuard;
 use std::sync::Arc;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch};
+use tracing::instrument::WithSubscriber;
+use transport::BoundListener;
 
-/// Shared state for the HTTP bridge.
+/// Handle to a running inspector bridge.
 ///
-/// This struct holds the internal state used by the Axum handlers.
-/// You don't need to interact with this directly.
-pub struct BridgeState {
-    /// The application name, used in status responses.
-    pub app_name: String,
-    /// Channel to send eval commands to the Dioxus app.
-    pub eval_tx: mpsc::Sender<EvalCommand>,
-    /// When the bridge was started, for uptime calculation.
-    pub started_at: std::time::Instant,
-    /// Process ID of the running application.
-    pub pid: u32,
+/// Poll [`recv`](Self::recv) to execute JavaScript for the bridge. Dropping
+/// the handle shuts the HTTP server down once in-flight requests finish.
+pub struct BridgeHandle {
+    eval_rx: mpsc::Receiver<EvalCommand>,
+    shutdown_tx: watch::Sender<()>,
+    port: Option<u16>,
+    /// Removes the lock file when the handle is dropped.
+    _lock: Option<LockGuard>,
+}
+
+impl BridgeHandle {
+    /// The port the bridge is listening on, or `None` if it failed to bind or
+    /// listens on a Unix socket.
+    ///
+    /// Useful when starting the bridge on port 0 to let the OS pick one.
+    pub fn port(&self) -> Option<u16> {
+        self.port
+    }
+
+    /// Receive the next eval command, or `None` once the bridge has stopped.
+    pub async fn recv(&mut self) -> Option<EvalCommand> {
+        self.eval_rx.recv().await
+    }
+}
+
+impl Drop for BridgeHandle {
+    fn drop(&mut self) {
+        let _ = self.shutdown_tx.send(());
+    }
 }
 
 /// Start the inspector HTTP bridge.
 ///
-/// Returns a receiver that your Dioxus app should poll to execute JavaScript.
-/// The bridge listens on `127.0.0.1:{port}`.
+/// Returns a [`BridgeHandle`] that your Dioxus app should poll to execute
+/// JavaScript. The bridge listens on `127.0.0.1:{port}` until the handle is
+/// dropped.
+///
+/// Pass port `0` to let the OS pick a free port and read it back with
+/// [`BridgeHandle::port`]. Either way the bridge's URL is written to
+/// [`lock_file_path`], so the MCP server can find it with
+/// `DIOXUS_BRIDGE_URL=http://auto`.
+///
+/// The `DIOXUS_INSPECTOR_*` environment variables override these defaults;
+/// see [`BridgeConfig`]. With `DIOXUS_INSPECTOR_DISABLED=1` nothing is
+/// started and the handle's [`recv`](BridgeHandle::recv) returns `None`.
 ///
 /// # Example
 ///
@@ -113,62 +309,215 @@ pub struct BridgeState {
 /// });
 /// ```
 #[cfg(not(tarpaulin_include))]
-pub fn start_bridge(port: u16, app_name: impl Into<String>) -> mpsc::Receiver<EvalCommand> {
+pub fn start_bridge(port: u16, app_name: impl Into<String>) -> BridgeHandle {
+    start_bridge_with_config(BridgeConfig::from_env(port, app_name))
+}
+
+/// Start the inspector HTTP bridge with custom options.
+///
+/// Behaves like [`start_bridge`], using only the options in `config`; use
+/// [`BridgeConfig::from_env`] to also apply the environment variables.
+///
+/// With the `tls` feature enabled and [`BridgeConfig::tls`] set, the bridge
+/// serves HTTPS instead of plain HTTP. With [`Transport::Unix`] it listens on
+/// a Unix socket instead of a TCP port.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let config = BridgeConfig::new(9999, "my-app").with_session_dir("/tmp/inspector-sessions");
+/// let mut eval_rx = start_bridge_with_config(config);
+/// ```
+#[cfg(not(tarpaulin_include))]
+pub fn start_bridge_with_config(config: BridgeConfig) -> BridgeHandle {
     let (eval_tx, eval_rx) = mpsc::channel::<EvalCommand>(32);
+    let (shutdown_tx, shutdown_rx) = watch::channel(());
 
-    let state = Arc::new(BridgeState {
-        app_name: app_name.into(),
-        eval_tx,
-        started_at: std::time::Instant::now(),
-        pid: std::process::id(),
-    });
-
-    let app = Router::new()
-        .route("/status", get(handlers::status))
-        .route("/eval", axum::routing::post(handlers::eval))
-        .route("/query", axum::routing::post(handlers::query))
-        .route("/dom", get(handlers::dom))
-        .route("/inspect", axum::routing::post(handlers::inspect))
-        .route(
-            "/validate-classes",
-            axum::routing::post(handlers::validate_classes),
-        )
-        .route("/diagnose", get(handlers::diagnose))
-        .route("/screenshot", axum::routing::post(handlers::screenshot))
-        .route("/resize", axum::routing::post(handlers::resize))
-        .with_state(state);
-
-    tokio::spawn(async move {
-        let addr = format!("127.0.0.1:{}", port);
-        let listener = match tokio::net::TcpListener::bind(&addr).await {
-            Ok(l) => l,
-            Err(e) => {
-                tracing::error!("Failed to bind inspector bridge on {}: {}", addr, e);
-                return;
-            }
+    if !config.enabled {
+        // Dropping the sender closes the channel, so `recv` returns `None`
+        drop(eval_tx);
+        return BridgeHandle {
+            eval_rx,
+            shutdown_tx,
+            port: None,
+            _lock: None,
         };
+    }
+
+    if config.is_exposed_without_auth() {
+        tracing::warn!(
+            "Inspector bridge on {} has no auth token: anyone who can reach it can run JavaScript in the app; set DIOXUS_INSPECTOR_AUTH_TOKEN",
+            config.addr
+        );
+    }
+
+    let state = Arc::new(BridgeState::from_config(&config, eval_tx));
+    let _logs = tracing::dispatcher::set_default(&state.log_dispatch);
+
+    let app = routes::app(state.clone());
+    let log_dispatch = state.log_dispatch.clone();
+    tokio::spawn(handlers::run_setup_scripts(state.clone()).with_subscriber(log_dispatch));
 
-        tracing::info!("Inspector bridge listening on http://{}", addr);
-        let _ = axum::serve(listener, app).await;
-    });
+    // Bind before returning so the handle knows the port, even when it's 0
+    let listener = bind(&config);
+    let port = listener.as_ref().and_then(BoundListener::port);
+    let lock = listener
+        .as_ref()
+        .and_then(|l| write_lock_file(l, &config, state.app_name.clone()));
 
-    eval_rx
+    if let Some(listener) = listener {
+        #[cfg(feature = "tls")]
+        server::spawn(listener, app, config.tls, shutdown_rx, &state);
+        #[cfg(not(feature = "tls"))]
+        server::spawn(listener, app, shutdown_rx, &state);
+    }
+
+    BridgeHandle {
+        eval_rx,
+        shutdown_tx,
+        port,
+        _lock: lock,
+    }
+}
+
+/// Bind the configured transport, logging why when it fails.
+fn bind(config: &BridgeConfig) -> Option<BoundListener> {
+    BoundListener::bind(&config.transport, config.addr)
+        .map_err(|e| {
+            tracing::error!(
+                "Failed to bind inspector bridge on {}: {}",
+                transport::describe(&config.transport, config.addr),
+                e
+            )
+        })
+        .ok()
+}
+
+/// Write the lock file pointing at `listener`, if it has a URL.
+fn write_lock_file(
+    listener: &BoundListener,
+    config: &BridgeConfig,
+    app_name: String,
+) -> Option<LockGuard> {
+    #[cfg(feature = "tls")]
+    let scheme = if config.tls.is_some() {
+        "https"
+    } else {
+        "http"
+    };
+    #[cfg(not(feature = "tls"))]
+    let scheme = "http";
+    let lock = BridgeLock {
+        url: listener.url(scheme)?,
+        app_name,
+        pid: std::process::id(),
+    };
+    let path = config.lock_file.clone().unwrap_or_else(lock_file_path);
+    LockGuard::write(&path, &lock)
+        .map_err(|e| tracing::warn!("Failed to write {}: {}", path.display(), e))
+        .ok()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_bridge_state_creation() {
-        let (tx, _rx) = mpsc::channel(1);
-        let state = BridgeState {
-            app_name: "test".to_string(),
-            eval_tx: tx,
-            started_at: std::time::Instant::now(),
-            pid: 12345,
-        };
-        assert_eq!(state.app_name, "test");
-        assert_eq!(state.pid, 12345);
+    /// Start a bridge on a free port that ignores `DIOXUS_INSPECTOR_*` and
+    /// keeps out of the shared lock file.
+    #[cfg(not(tarpaulin_include))]
+    fn start_test_bridge(app_name: &str) -> BridgeHandle {
+        let lock_file = std::env::temp_dir().join(format!(
+            "dioxus-inspector-{}-{}.lock",
+            app_name,
+            std::process::id()
+        ));
+        start_bridge_with_config(BridgeConfig::new(0, app_name).with_lock_file(lock_file))
+    }
+
+    #[cfg(not(tarpaulin_include))]
+    #[tokio::test]
+    async fn test_port_zero_binds_distinct_ports() {
+        let first = start_test_bridge("port-test-1");
+        let second = start_test_bridge("port-test-2");
+
+        let (first_port, second_port) = (first.port().unwrap(), second.port().unwrap());
+        assert_ne!(first_port, 0);
+        assert_ne!(second_port, 0);
+        assert_ne!(first_port, second_port);
+    }
+
+    #[cfg(not(tarpaulin_include))]
+    #[tokio::test]
+    async fn test_dropping_handle_stops_server() {
+        use std::time::Duration;
+        use tokio::net::TcpStream;
+        use tokio::time::{sleep, timeout};
+
+        let handle = start_test_bridge("shutdown-test");
+        let addr = format!("127.0.0.1:{}", handle.port().unwrap());
+
+        timeout(Duration::from_secs(5), async {
+            while TcpStream::connect(&addr).await.is_err() {
+                sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("bridge never started listening");
+
+        drop(handle);
+
+        timeout(Duration::from_secs(5), async {
+            loop {
+                match TcpStream::connect(&addr).await {
+                    Err(e) if e.kind() == std::io::ErrorKind::ConnectionRefused => break,
+                    _ => sleep(Duration::from_millis(10)).await,
+                }
+            }
+        })
+        .await
+        .expect("bridge still accepting connections after the handle was dropped");
+    }
+
+    #[cfg(all(unix, not(tarpaulin_include)))]
+    #[tokio::test]
+    async fn test_unix_socket_transport() {
+        use std::time::Duration;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        use tokio::net::UnixStream;
+        use tokio::time::{sleep, timeout};
+
+        let path = std::env::temp_dir().join(format!("dioxus-unix-{}.sock", std::process::id()));
+        let handle =
+            start_bridge_with_config(BridgeConfig::new(0, "unix-test").with_unix_socket(&path));
+        assert_eq!(handle.port(), None);
+
+        let mut stream = timeout(Duration::from_secs(5), async {
+            loop {
+                match UnixStream::connect(&path).await {
+                    Ok(stream) => break stream,
+                    Err(_) => sleep(Duration::from_millis(10)).await,
+                }
+            }
+        })
+        .await
+        .expect("bridge never started listening");
+
+        stream
+            .write_all(b"GET /status HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains(r#""app":"unix-test""#));
+
+        drop(handle);
+        timeout(Duration::from_secs(5), async {
+            while path.exists() {
+                sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("socket file not removed after the handle was dropped");
     }
 }
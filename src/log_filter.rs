@@ -0,0 +1,207 @@
+//! Filtering the bridge's own log output by level.
+//!
+//! The host app owns the global subscriber, so the bridge can't add a layer
+//! to it. Instead the bridge's tasks, requests and startup run with a
+//! [`LevelFiltered`] dispatcher, which forwards to the subscriber that was
+//! the default when the bridge started, minus events more verbose than
+//! [`BridgeConfig::log_level`](crate::BridgeConfig::log_level). The app's own
+//! logs are unaffected.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::Response,
+};
+use tracing::instrument::WithSubscriber;
+use tracing::level_filters::LevelFilter;
+use tracing::span::{Attributes, Current, Id, Record};
+use tracing::subscriber::Interest;
+use tracing::{Dispatch, Event, Level, Metadata, Subscriber};
+
+use crate::BridgeState;
+
+/// Forwards to `inner` everything at `max` or less verbose.
+struct LevelFiltered {
+    inner: Dispatch,
+    max: Level,
+}
+
+impl LevelFiltered {
+    fn allows(&self, metadata: &Metadata<'_>) -> bool {
+        *metadata.level() <= self.max
+    }
+}
+
+impl Subscriber for LevelFiltered {
+    fn register_callsite(&self, metadata: &'static Metadata<'static>) -> Interest {
+        // Interest is combined across dispatchers, so `never` here still
+        // leaves the callsite enabled for the app's own subscriber
+        if self.allows(metadata) {
+            self.inner.register_callsite(metadata)
+        } else {
+            Interest::never()
+        }
+    }
+
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        self.allows(metadata) && self.inner.enabled(metadata)
+    }
+
+    fn max_level_hint(&self) -> Option<LevelFilter> {
+        // Never hint more verbose than the app's subscriber, which would turn
+        // off its level fast-path; with no hint from it, neither can we
+        self.inner
+            .max_level_hint()
+            .map(|inner| inner.min(LevelFilter::from_level(self.max)))
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        self.inner.new_span(span)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        self.inner.record(span, values)
+    }
+
+    fn record_follows_from(&self, span: &Id, follows: &Id) {
+        self.inner.record_follows_from(span, follows)
+    }
+
+    fn event(&self, event: &Event<'_>) {
+        self.inner.event(event)
+    }
+
+    fn enter(&self, span: &Id) {
+        self.inner.enter(span)
+    }
+
+    fn exit(&self, span: &Id) {
+        self.inner.exit(span)
+    }
+
+    fn clone_span(&self, id: &Id) -> Id {
+        self.inner.clone_span(id)
+    }
+
+    fn try_close(&self, id: Id) -> bool {
+        self.inner.try_close(id)
+    }
+
+    fn current_span(&self) -> Current {
+        self.inner.current_span()
+    }
+}
+
+/// The current default dispatcher, limited to events at `max` or less verbose.
+pub(crate) fn filtered(max: Level) -> Dispatch {
+    let inner = tracing::dispatcher::get_default(Dispatch::clone);
+    Dispatch::new(LevelFiltered { inner, max })
+}
+
+/// Middleware running each request with [`BridgeState::log_dispatch`].
+pub(crate) async fn filter_logs(
+    State(state): State<Arc<BridgeState>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    next.run(req)
+        .with_subscriber(state.log_dispatch.clone())
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Counts the events it receives.
+    struct Counter(Arc<AtomicUsize>);
+
+    impl Subscriber for Counter {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, _: &Event<'_>) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    /// Hints the level it was built with.
+    struct Hinted(Option<LevelFilter>);
+
+    impl Subscriber for Hinted {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn max_level_hint(&self) -> Option<LevelFilter> {
+            self.0
+        }
+
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, _: &Event<'_>) {}
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[test]
+    fn test_filtered_max_level_hint() {
+        let hint = |app: Option<LevelFilter>, max: Level| {
+            let app = Dispatch::new(Hinted(app));
+            let bridge = tracing::dispatcher::with_default(&app, || filtered(max));
+            bridge.max_level_hint()
+        };
+        assert_eq!(
+            hint(Some(LevelFilter::INFO), Level::TRACE),
+            Some(LevelFilter::INFO)
+        );
+        assert_eq!(
+            hint(Some(LevelFilter::TRACE), Level::WARN),
+            Some(LevelFilter::WARN)
+        );
+        assert_eq!(hint(None, Level::WARN), None);
+    }
+
+    #[test]
+    fn test_filtered_drops_verbose_events() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let app = Dispatch::new(Counter(count.clone()));
+
+        let bridge = tracing::dispatcher::with_default(&app, || filtered(Level::WARN));
+        tracing::dispatcher::with_default(&bridge, || {
+            tracing::debug!("dropped");
+            tracing::info!("dropped");
+            tracing::warn!("kept");
+            tracing::error!("kept");
+        });
+        assert_eq!(count.load(Ordering::Relaxed), 2);
+
+        // The app's own subscriber still gets everything
+        tracing::dispatcher::with_default(&app, || tracing::info!("kept"));
+        assert_eq!(count.load(Ordering::Relaxed), 3);
+    }
+}
@@ -0,0 +1,221 @@
+//! The bridge's HTTP routes.
+
+use std::sync::Arc;
+
+use axum::{
+    routing::{delete, get, post, put},
+    Router,
+};
+
+use crate::{access, connections, handlers, log_filter, BridgeState};
+
+type BridgeRouter = Router<Arc<BridgeState>>;
+
+/// Every route, behind the auth, counting and log filter middleware.
+pub(crate) fn app(state: Arc<BridgeState>) -> Router {
+    let router = core_routes()
+        .merge(eval_routes())
+        .merge(dom_routes())
+        .merge(inspect_routes())
+        .merge(accessibility_routes())
+        .merge(interaction_routes())
+        .merge(window_routes())
+        .merge(monitoring_routes())
+        .merge(media_routes());
+
+    #[cfg(feature = "metrics")]
+    let router = router.route("/metrics", get(handlers::metrics));
+
+    // Layers run outside-in, so rejected requests are counted too
+    router
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            access::guard,
+        ))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            connections::count_requests,
+        ))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            log_filter::filter_logs,
+        ))
+        .with_state(state)
+}
+
+/// Bridge health and settings.
+fn core_routes() -> BridgeRouter {
+    Router::new()
+        .route("/status", get(handlers::status))
+        .route("/ping", get(handlers::ping))
+        .route("/config", get(handlers::bridge_config))
+        .route("/config/eval-timeout", put(handlers::set_eval_timeout))
+}
+
+/// Running scripts, and the record of what ran.
+fn eval_routes() -> BridgeRouter {
+    Router::new()
+        .route("/eval", post(handlers::eval))
+        .route("/eval/conditional", post(handlers::eval_conditional))
+        .route("/eval/module", post(handlers::eval_module))
+        .route("/eval/async", post(handlers::eval_async))
+        .route("/eval/stream", post(handlers::eval_stream))
+        .route("/eval/sandbox", post(handlers::eval_sandboxed))
+        .route("/eval/parallel", post(handlers::eval_parallel))
+        .route("/eval/named", get(handlers::list_named_scripts))
+        .route(
+            "/eval/named/{name}",
+            put(handlers::store_named_script)
+                .post(handlers::run_named_script)
+                .delete(handlers::remove_named_script),
+        )
+        .route(
+            "/history",
+            get(handlers::history).delete(handlers::clear_history),
+        )
+        .route("/trace", get(handlers::trace).delete(handlers::clear_trace))
+}
+
+/// Reading and changing the DOM, and saving it for later.
+fn dom_routes() -> BridgeRouter {
+    Router::new()
+        .route("/query", post(handlers::query))
+        .route("/query/all", post(handlers::query_all))
+        .route("/dom", get(handlers::dom))
+        .route("/tree", get(handlers::component_tree))
+        .route("/dom/snapshot", get(handlers::dom_snapshot))
+        .route("/dom/diff", post(handlers::dom_diff))
+        .route("/dom/search", post(handlers::dom_search))
+        .route("/dom/ancestors", post(handlers::common_ancestor))
+        .route("/data-attributes", post(handlers::data_attributes))
+        .route("/computed-styles", post(handlers::computed_styles))
+        .route("/table", post(handlers::extract_table))
+        .route("/list", post(handlers::extract_list))
+        .route("/slot", post(handlers::inspect_slots))
+        .route("/element/create", post(handlers::create_element))
+        .route("/element/remove", delete(handlers::remove_element))
+        .route("/snapshot", get(handlers::capture_snapshot))
+        .route("/snapshot/{id}", get(handlers::load_snapshot))
+        .route("/session/save", post(handlers::save_session))
+        .route("/session/load", get(handlers::load_session))
+}
+
+/// Layout inspection and diagnostics.
+fn inspect_routes() -> BridgeRouter {
+    Router::new()
+        .route("/inspect", post(handlers::inspect))
+        .route("/inspect/layout", post(handlers::inspect_layout))
+        .route("/computed-layout", post(handlers::computed_layout))
+        .route("/inspect/parent", post(handlers::parent_chain))
+        .route("/inspect/stacking", post(handlers::stacking_contexts))
+        .route("/intersection", get(handlers::intersection))
+        .route("/scroll/all", get(handlers::scroll_containers))
+        .route("/validate-classes", post(handlers::validate_classes))
+        .route("/diagnose", get(handlers::diagnose))
+        .route("/diagnose/register", post(handlers::register_check))
+        .route("/diagnose/custom", get(handlers::run_custom_checks))
+        .route("/diagnose/performance", get(handlers::diagnose_performance))
+        .route("/measure", post(handlers::measure))
+        .route("/fonts/missing", get(handlers::missing_fonts))
+        .route("/image-status", get(handlers::image_status))
+        .route("/coverage/start", post(handlers::coverage_start))
+        .route("/coverage/report", get(handlers::coverage_report))
+}
+
+/// Accessibility and color checks.
+fn accessibility_routes() -> BridgeRouter {
+    Router::new()
+        .route("/validate-colors", post(handlers::validate_colors))
+        .route("/tab-order", get(handlers::tab_order))
+        .route("/aria", post(handlers::aria))
+        .route("/role", get(handlers::query_by_role))
+        .route("/colors", get(handlers::colors))
+        .route("/contrast-audit", get(handlers::contrast_audit))
+}
+
+/// Simulated input, and waiting on what it causes.
+fn interaction_routes() -> BridgeRouter {
+    Router::new()
+        .route("/drag", post(handlers::drag))
+        .route("/select", post(handlers::select_text))
+        .route("/scroll-into-view", post(handlers::scroll_into_view))
+        .route("/form", post(handlers::fill_form))
+        .route("/touch", post(handlers::touch))
+        .route("/watch", post(handlers::watch))
+        .route("/input-state", get(handlers::input_state))
+        .route(
+            "/clipboard",
+            get(handlers::get_clipboard).post(handlers::set_clipboard),
+        )
+}
+
+/// The window, viewport and page as a whole.
+fn window_routes() -> BridgeRouter {
+    Router::new()
+        .route("/screenshot", post(handlers::screenshot))
+        .route("/resize", post(handlers::resize))
+        .route("/move", post(handlers::move_window))
+        .route("/zoom", get(handlers::get_zoom).post(handlers::set_zoom))
+        .route(
+            "/viewport",
+            get(handlers::get_viewport).post(handlers::set_viewport),
+        )
+        .route("/window/state", get(handlers::window_state))
+        .route(
+            "/dark-mode",
+            get(handlers::get_dark_mode).post(handlers::set_dark_mode),
+        )
+        .route("/responsive", post(handlers::responsive_test))
+        .route(
+            "/breakpoints/simulate",
+            post(handlers::simulate_breakpoints),
+        )
+        .route("/media-query", get(handlers::media_query))
+        .route("/media-query/all", get(handlers::media_query_all))
+        .route("/translate", post(handlers::translate))
+        .route("/translate/restore", post(handlers::restore_translation))
+        .route("/reload", post(handlers::reload))
+        .route("/reload/hot", post(handlers::hot_reload))
+}
+
+/// What the page did: network, errors, events, signals and storage.
+fn monitoring_routes() -> BridgeRouter {
+    Router::new()
+        .route("/network", get(handlers::network))
+        .route("/errors", get(handlers::js_errors))
+        .route("/event-log", get(handlers::event_log))
+        .route("/event-log/watch", post(handlers::watch_events))
+        .route(
+            "/mutation-log",
+            get(handlers::mutation_summary).delete(handlers::stop_mutation_log),
+        )
+        .route("/mutation-log/start", post(handlers::start_mutation_log))
+        .route("/signals", get(handlers::signals))
+        .route("/signals/watch", get(handlers::watch_signals))
+        .route("/storage/watch", get(handlers::watch_storage))
+        .route("/indexeddb", post(handlers::read_indexeddb))
+        .route(
+            "/geo/mock",
+            post(handlers::mock_geolocation).delete(handlers::clear_geolocation_mock),
+        )
+        .route(
+            "/simulate-network",
+            post(handlers::simulate_network).delete(handlers::clear_network_simulation),
+        )
+}
+
+/// Animations, media elements, canvas, SVG and injected styles.
+fn media_routes() -> BridgeRouter {
+    Router::new()
+        .route(
+            "/animations",
+            get(handlers::animations).post(handlers::control_animations),
+        )
+        .route("/media", get(handlers::media))
+        .route("/media/control", post(handlers::control_media))
+        .route("/canvas", post(handlers::capture_canvas))
+        .route("/svg", get(handlers::svg_elements))
+        .route("/svg/animate", post(handlers::animate_svg))
+        .route("/inject-css", post(handlers::inject_css))
+        .route("/remove-css", post(handlers::remove_css))
+}
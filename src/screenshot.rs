@@ -1,20 +1,40 @@
 //! Screenshot capture functionality (macOS only).
 
-/// Capture a screenshot of the app window.
+use crate::types::ScreenshotFormat;
+
+/// Capture a screenshot of the app window and save it to `output_path`.
 ///
 /// On macOS, uses Core Graphics to find and capture the window.
 /// On other platforms, returns an error.
 #[cfg(not(tarpaulin_include))]
-pub fn capture_screenshot(app_name: &str, output_path: &str) -> Result<(), String> {
+pub fn capture_screenshot(
+    app_name: &str,
+    output_path: &str,
+    format: ScreenshotFormat,
+) -> Result<(), String> {
+    let bytes = capture_screenshot_bytes(app_name, format)?;
+    std::fs::write(output_path, bytes).map_err(|e| format!("Failed to save screenshot: {}", e))
+}
+
+/// Capture a screenshot of the app window, encoded in `format`.
+///
+/// On macOS, uses Core Graphics to find and capture the window.
+/// On other platforms, returns an error.
+#[cfg(not(tarpaulin_include))]
+pub fn capture_screenshot_bytes(
+    app_name: &str,
+    format: ScreenshotFormat,
+) -> Result<Vec<u8>, String> {
     #[cfg(target_os = "macos")]
     {
         let window_id = find_window_id(app_name)?;
-        capture_window_to_png(window_id, output_path)
+        let img = capture_window_image(window_id)?;
+        encode_image(&img, format)
     }
 
     #[cfg(not(target_os = "macos"))]
     {
-        let _ = (app_name, output_path);
+        let _ = (app_name, format);
         Err("Screenshot capture only supported on macOS".to_string())
     }
 }
@@ -179,7 +199,7 @@ mod cg_ffi {
 
 #[cfg(target_os = "macos")]
 #[cfg(not(tarpaulin_include))]
-fn capture_window_to_png(window_id: u32, output_path: &str) -> Result<(), String> {
+fn capture_window_image(window_id: u32) -> Result<image::RgbaImage, String> {
     use cg_ffi::*;
     use core_graphics::display::CGRectNull;
     use core_graphics::window::{
@@ -242,12 +262,28 @@ fn capture_window_to_png(window_id: u32, output_path: &str) -> Result<(), String
         CGImageRelease(image);
     }
 
-    let img: image::RgbaImage =
-        image::ImageBuffer::from_raw(width as u32, height as u32, rgba_data)
-            .ok_or("Failed to create image buffer")?;
+    image::ImageBuffer::from_raw(width as u32, height as u32, rgba_data)
+        .ok_or_else(|| "Failed to create image buffer".to_string())
+}
 
-    img.save(output_path)
-        .map_err(|e| format!("Failed to save PNG: {}", e))
+#[cfg(target_os = "macos")]
+fn encode_image(img: &image::RgbaImage, format: ScreenshotFormat) -> Result<Vec<u8>, String> {
+    use image::codecs::jpeg::JpegEncoder;
+    use image::ImageFormat;
+    use std::io::Cursor;
+
+    let mut bytes = Cursor::new(Vec::new());
+    let result = match format {
+        ScreenshotFormat::Png => img.write_to(&mut bytes, ImageFormat::Png),
+        ScreenshotFormat::WebP => img.write_to(&mut bytes, ImageFormat::WebP),
+        ScreenshotFormat::Jpeg { quality } => {
+            // JPEG has no alpha channel
+            let rgb = image::DynamicImage::ImageRgba8(img.clone()).to_rgb8();
+            JpegEncoder::new_with_quality(&mut bytes, quality.clamp(1, 100)).encode_image(&rgb)
+        }
+    };
+    result.map_err(|e| format!("Failed to encode {}: {}", format.mime_type(), e))?;
+    Ok(bytes.into_inner())
 }
 
 #[cfg(test)]
@@ -255,7 +291,7 @@ mod tests {
     #[test]
     #[cfg(not(target_os = "macos"))]
     fn test_capture_unsupported() {
-        let result = super::capture_screenshot("test", "/tmp/test.png");
+        let result = super::capture_screenshot("test", "/tmp/test.png", Default::default());
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("only supported on macOS"));
     }
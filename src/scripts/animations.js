@@ -0,0 +1,49 @@
+return (() => {
+    const id = {{id}};
+    const action = {{action}};
+
+    function describe(el) {
+        if (!el || !el.tagName) return "";
+        let desc = el.tagName.toLowerCase();
+        if (el.id) desc += "#" + el.id;
+        if (typeof el.className === "string" && el.className.trim()) {
+            desc += "." + el.className.trim().split(/\s+/).join(".");
+        }
+        return desc;
+    }
+
+    // CSS animations and transitions have no id; use their name instead
+    function animationId(anim) {
+        return anim.id || anim.animationName || anim.transitionProperty || null;
+    }
+
+    function finite(value) {
+        return typeof value === "number" && Number.isFinite(value) ? value : 0;
+    }
+
+    let animations = document.getAnimations();
+    let matched = 0;
+
+    if (action) {
+        for (const anim of animations) {
+            if (id !== null && animationId(anim) !== id) continue;
+            matched++;
+            anim[action]();
+        }
+        animations = document.getAnimations();
+    }
+
+    return JSON.stringify({
+        matched,
+        animations: animations.map(anim => {
+            const timing = anim.effect ? anim.effect.getComputedTiming() : {};
+            return {
+                id: animationId(anim),
+                state: anim.playState,
+                current_time: finite(anim.currentTime),
+                effect_target: describe(anim.effect && anim.effect.target),
+                duration_ms: finite(timing.duration)
+            };
+        })
+    });
+})()
@@ -0,0 +1,17 @@
+return (() => {
+    const el = document.querySelector({{selector}});
+    const attributes = {{attributes}};
+    if (!el) return JSON.stringify({ current_attributes: {}, applied: 0, error: "Element not found" });
+
+    let applied = 0;
+    for (const [name, value] of Object.entries(attributes || {})) {
+        el.setAttribute(name, value);
+        applied++;
+    }
+
+    const current = {};
+    for (const attr of el.attributes) {
+        if (attr.name === "role" || attr.name.startsWith("aria-")) current[attr.name] = attr.value;
+    }
+    return JSON.stringify({ current_attributes: current, applied, error: null });
+})()
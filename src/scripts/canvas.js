@@ -0,0 +1,20 @@
+return (() => {
+    const el = document.querySelector({{selector}});
+    const empty = { data_url: "", width: 0, height: 0 };
+    if (!el) return JSON.stringify({ ...empty, error: "Element not found" });
+    if (!(el instanceof HTMLCanvasElement)) {
+        return JSON.stringify({ ...empty, error: "Not a canvas: <" + el.tagName.toLowerCase() + ">" });
+    }
+
+    // Canvases that drew cross-origin images are tainted and throw a SecurityError
+    try {
+        return JSON.stringify({
+            data_url: el.toDataURL({{format}}),
+            width: el.width,
+            height: el.height,
+            error: null
+        });
+    } catch (e) {
+        return JSON.stringify({ ...empty, width: el.width, height: el.height, error: String(e) });
+    }
+})()
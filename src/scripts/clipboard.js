@@ -0,0 +1,8 @@
+return (async () => {
+    const text = {{text}};
+    // The Clipboard API only works while the document has focus
+    if (text !== null) {
+        await navigator.clipboard.writeText(text);
+    }
+    return JSON.stringify({ text: await navigator.clipboard.readText() });
+})()
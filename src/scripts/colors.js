@@ -0,0 +1,37 @@
+return (() => {
+    const maxExamples = {{max_examples}};
+
+{{> selector_path}}
+
+    const usage = new Map();
+    function record(value, el) {
+        if (!value) return;
+        let entry = usage.get(value);
+        if (!entry) {
+            entry = { value, count: 0, examples: [] };
+            usage.set(value, entry);
+        }
+        entry.count += 1;
+        if (entry.examples.length < maxExamples) entry.examples.push(selectorPath(el));
+    }
+
+    const SIDES = ["top", "right", "bottom", "left"];
+    for (const el of document.querySelectorAll("*")) {
+        const style = getComputedStyle(el);
+        record(style.color, el);
+        record(style.backgroundColor, el);
+        // Borders default to the text color, so only drawn ones count
+        for (const side of SIDES) {
+            if (style.getPropertyValue("border-" + side + "-style") !== "none" &&
+                parseFloat(style.getPropertyValue("border-" + side + "-width")) > 0) {
+                record(style.getPropertyValue("border-" + side + "-color"), el);
+            }
+        }
+        // `fill` and `stroke` are inherited by every element but only paint SVG
+        if (el instanceof SVGElement) {
+            record(style.fill, el);
+            record(style.stroke, el);
+        }
+    }
+    return JSON.stringify({ values: Array.from(usage.values()) });
+})()
@@ -0,0 +1,36 @@
+return (() => {
+    const selectors = {{selectors}};
+
+{{> selector_path}}
+
+    const elements = [];
+    const unmatched = [];
+    for (const selector of selectors) {
+        let matches;
+        try {
+            matches = document.querySelectorAll(selector);
+        } catch (e) {
+            throw new Error("Invalid selector: " + selector);
+        }
+        if (matches.length === 0) unmatched.push(selector);
+        elements.push(...matches);
+    }
+    if (unmatched.length > 0) {
+        return JSON.stringify({ found: false, unmatched });
+    }
+
+    // Climb from the first match until an element contains all the others;
+    // <html> contains everything, so the climb always ends
+    let ancestor = elements[0];
+    while (!elements.every(el => ancestor.contains(el))) {
+        ancestor = ancestor.parentElement;
+    }
+    let depth = 0;
+    for (let node = ancestor.parentElement; node; node = node.parentElement) depth++;
+    return JSON.stringify({
+        found: true,
+        ancestor_selector: selectorPath(ancestor),
+        ancestor_tag: ancestor.tagName.toLowerCase(),
+        depth
+    });
+})()
@@ -0,0 +1,23 @@
+return (() => {
+    // Dioxus tags elements it tracks with data-dioxus-id; the DOM carries no
+    // component names, so apps can opt in with a data-component attribute
+    function collect(el) {
+        const nodes = [];
+        for (const child of el.children) {
+            if (child.hasAttribute("data-dioxus-id")) {
+                nodes.push({
+                    name: child.getAttribute("data-component"),
+                    id: child.getAttribute("data-dioxus-id"),
+                    children: collect(child)
+                });
+            } else {
+                nodes.push(...collect(child));
+            }
+        }
+        return nodes;
+    }
+
+    return JSON.stringify({
+        root: { name: null, id: "root", children: collect(document.body) }
+    });
+})()
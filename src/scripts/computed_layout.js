@@ -0,0 +1,83 @@
+return (() => {
+    const el = document.querySelector({{selector}});
+    if (!el) return JSON.stringify({ found: false });
+
+    const style = getComputedStyle(el);
+    const parentStyle = el.parentElement ? getComputedStyle(el.parentElement) : null;
+    const px = value => parseFloat(value) || 0;
+    const edges = (prefix, suffix = "") => ({
+        top: px(style[prefix + "Top" + suffix]),
+        right: px(style[prefix + "Right" + suffix]),
+        bottom: px(style[prefix + "Bottom" + suffix]),
+        left: px(style[prefix + "Left" + suffix])
+    });
+
+    // Layout sizes, unaffected by transforms; SVG elements have no offset sizes
+    const rect = el.getBoundingClientRect();
+    const borderWidth = "offsetWidth" in el ? el.offsetWidth : rect.width;
+    const borderHeight = "offsetHeight" in el ? el.offsetHeight : rect.height;
+    const padding = edges("padding");
+    const border = edges("border", "Width");
+    const margin = edges("margin");
+    const paddingWidth = Math.max(0, borderWidth - border.left - border.right);
+    const paddingHeight = Math.max(0, borderHeight - border.top - border.bottom);
+
+    const isFlexContainer = style.display.includes("flex");
+    const isFlexItem = !!parentStyle && parentStyle.display.includes("flex");
+    const isGridContainer = style.display.includes("grid");
+    const isGridItem = !!parentStyle && parentStyle.display.includes("grid");
+
+    return JSON.stringify({
+        found: true,
+        display: style.display,
+        box_model: {
+            box_sizing: style.boxSizing,
+            content_width: Math.max(0, paddingWidth - padding.left - padding.right),
+            content_height: Math.max(0, paddingHeight - padding.top - padding.bottom),
+            padding_width: paddingWidth,
+            padding_height: paddingHeight,
+            border_width: borderWidth,
+            border_height: borderHeight,
+            margin_width: borderWidth + margin.left + margin.right,
+            margin_height: borderHeight + margin.top + margin.bottom,
+            padding,
+            border,
+            margin
+        },
+        flex: isFlexContainer || isFlexItem ? {
+            is_container: isFlexContainer,
+            is_item: isFlexItem,
+            direction: style.flexDirection,
+            wrap: style.flexWrap,
+            justify_content: style.justifyContent,
+            align_items: style.alignItems,
+            align_content: style.alignContent,
+            row_gap: style.rowGap,
+            column_gap: style.columnGap,
+            grow: px(style.flexGrow),
+            shrink: px(style.flexShrink),
+            basis: style.flexBasis,
+            align_self: style.alignSelf,
+            order: parseInt(style.order, 10) || 0
+        } : null,
+        grid: isGridContainer || isGridItem ? {
+            is_container: isGridContainer,
+            is_item: isGridItem,
+            template_columns: style.gridTemplateColumns,
+            template_rows: style.gridTemplateRows,
+            template_areas: style.gridTemplateAreas,
+            auto_flow: style.gridAutoFlow,
+            auto_columns: style.gridAutoColumns,
+            auto_rows: style.gridAutoRows,
+            row_gap: style.rowGap,
+            column_gap: style.columnGap,
+            justify_items: style.justifyItems,
+            align_items: style.alignItems,
+            column: style.gridColumn,
+            row: style.gridRow,
+            area: style.gridArea,
+            justify_self: style.justifySelf,
+            align_self: style.alignSelf
+        } : null
+    });
+})()
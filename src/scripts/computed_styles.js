@@ -0,0 +1,14 @@
+return (() => {
+    const el = document.querySelector({{selector}});
+    if (!el) return JSON.stringify({ found: false, styles: {} });
+
+    const computed = window.getComputedStyle(el);
+    const properties = {{properties}};
+
+    // Iterating the declaration yields every property name it knows
+    const styles = {};
+    for (const name of properties || Array.from(computed)) {
+        styles[name] = computed.getPropertyValue(name).trim();
+    }
+    return JSON.stringify({ found: true, styles });
+})()
@@ -0,0 +1,57 @@
+return (() => {
+    const MAX_TEXT_LEN = 60;
+    const WHITE = "rgb(255, 255, 255)";
+
+{{> selector_path}}
+
+    function transparent(color) {
+        return color === "transparent" || /^rgba\(.*,\s*0\)$/.test(color);
+    }
+
+    // The nearest background that isn't transparent, or null when text sits
+    // on a background image whose colors can't be known
+    function effectiveBackground(el) {
+        for (let node = el; node && node.nodeType === 1; node = node.parentElement) {
+            const style = getComputedStyle(node);
+            if (style.backgroundImage !== "none") return null;
+            if (!transparent(style.backgroundColor)) return style.backgroundColor;
+        }
+        return WHITE;
+    }
+
+    // Elements with text nodes of their own, each once
+    const elements = new Set();
+    const walker = document.createTreeWalker(document.body, NodeFilter.SHOW_TEXT);
+    while (walker.nextNode()) {
+        const node = walker.currentNode;
+        const parent = node.parentElement;
+        if (!parent || !node.textContent.trim()) continue;
+        if (["SCRIPT", "STYLE", "NOSCRIPT", "TEMPLATE"].includes(parent.tagName)) continue;
+        elements.add(parent);
+    }
+
+    const pairs = [];
+    for (const el of elements) {
+        if (el.getClientRects().length === 0) continue;
+        const style = getComputedStyle(el);
+        if (style.visibility !== "visible" || transparent(style.color)) continue;
+        const background = effectiveBackground(el);
+        if (!background) continue;
+
+        const text = Array.from(el.childNodes)
+            .filter(n => n.nodeType === Node.TEXT_NODE)
+            .map(n => n.textContent)
+            .join("")
+            .trim()
+            .replace(/\s+/g, " ");
+        pairs.push({
+            selector_path: selectorPath(el),
+            text: text.slice(0, MAX_TEXT_LEN),
+            foreground: style.color,
+            background,
+            font_size_px: parseFloat(style.fontSize) || 16,
+            font_weight: parseInt(style.fontWeight, 10) || 400
+        });
+    }
+    return JSON.stringify({ pairs });
+})()
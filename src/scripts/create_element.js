@@ -0,0 +1,23 @@
+return (() => {
+    const spec = {{spec}};
+    const target = document.querySelector(spec.parent_selector);
+    if (!target) {
+        return JSON.stringify({ success: false, error: "Element not found: " + spec.parent_selector });
+    }
+
+    // Keep the SVG or MathML namespace of the parent, so e.g. a <circle>
+    // inside an <svg> renders
+    const namespace = spec.position === "before" || spec.position === "after"
+        ? target.parentNode && target.parentNode.namespaceURI
+        : target.namespaceURI;
+    const el = namespace && namespace !== "http://www.w3.org/1999/xhtml"
+        ? document.createElementNS(namespace, spec.tag)
+        : document.createElement(spec.tag);
+    for (const [name, value] of Object.entries(spec.attributes)) {
+        el.setAttribute(name, value);
+    }
+    if (spec.text !== null) el.textContent = spec.text;
+    target[spec.position](el);
+
+    return JSON.stringify({ success: true });
+})()
@@ -0,0 +1,33 @@
+return (() => {
+    const names = new Set();
+    function collect(rules) {
+        for (const rule of rules) {
+            if (rule.style) {
+                for (const property of rule.style) {
+                    if (property.startsWith("--")) names.add(property);
+                }
+            }
+            // @media, @supports and nested rules
+            if (rule.cssRules) collect(rule.cssRules);
+        }
+    }
+    for (const sheet of document.styleSheets) {
+        try {
+            collect(sheet.cssRules);
+        } catch (e) {
+            // Cross-origin stylesheets can't be read
+        }
+    }
+    for (const property of document.documentElement.style) {
+        if (property.startsWith("--")) names.add(property);
+    }
+
+    // Values as the root element resolves them; variables set only on
+    // descendants resolve to ""
+    const computed = getComputedStyle(document.documentElement);
+    const variables = {};
+    for (const name of Array.from(names).sort()) {
+        variables[name] = computed.getPropertyValue(name).trim();
+    }
+    return JSON.stringify({ variables });
+})()
@@ -0,0 +1,12 @@
+return (() => {
+    const el = document.querySelector({{selector}});
+    if (!el) return JSON.stringify({ found: false, attributes: {} });
+
+    // dataset keys are camelCase; report them as the attribute names
+    const attributes = {};
+    for (const [key, value] of Object.entries(el.dataset)) {
+        const name = "data-" + key.replace(/[A-Z]/g, c => "-" + c.toLowerCase());
+        attributes[name] = value;
+    }
+    return JSON.stringify({ found: true, attributes });
+})()
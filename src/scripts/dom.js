@@ -1,9 +1,10 @@
 return (() => {
     const SKIP_TAGS = new Set(['script', 'style', 'noscript', 'link', 'meta']);
     const MAX_TEXT_LEN = 100;
-    const MAX_DEPTH = {MAX_DEPTH};
-    const MAX_NODES = {MAX_NODES};
-    const SELECTOR = {SELECTOR};
+    const MAX_DEPTH = {{max_depth}};
+    const MAX_NODES = {{max_nodes}};
+    const SELECTOR = {{selector}};
+    const INCLUDE_SHADOW = {{include_shadow}};
 
     let nodeCount = 0;
     let truncatedByLimit = false;
@@ -38,18 +39,28 @@ return (() => {
             node.class = el.className;
         }
 
+        // Shadow content renders in place of the light DOM, which only shows through <slot>s
+        if (INCLUDE_SHADOW && el.shadowRoot) {
+            node.shadow_root = serializeChildren(el.shadowRoot, depth);
+        }
+
+        const children = serializeChildren(el, depth);
+        if (children.length > 0) node.children = children;
+
+        return node;
+    }
+
+    function serializeChildren(parent, depth) {
         const children = [];
-        for (const child of el.childNodes) {
+        for (const child of parent.childNodes) {
             if (nodeCount >= MAX_NODES) {
-                children.push({ tag: '...', truncated: 'max_nodes', remaining: el.childNodes.length - children.length });
+                children.push({ tag: '...', truncated: 'max_nodes', remaining: parent.childNodes.length - children.length });
                 break;
             }
             const serialized = serialize(child, depth + 1);
             if (serialized) children.push(serialized);
         }
-        if (children.length > 0) node.children = children;
-
-        return node;
+        return children;
     }
 
     const root = SELECTOR ? document.querySelector(SELECTOR) : document.body;
@@ -0,0 +1,61 @@
+return (() => {
+    const spec = {{spec}};
+    const SKIPPED = ["SCRIPT", "STYLE", "NOSCRIPT", "TEMPLATE"];
+    const CONTEXT = 30;
+
+{{> selector_path}}
+
+    if (spec.selector_filter) {
+        try {
+            document.querySelector(spec.selector_filter);
+        } catch (e) {
+            throw new Error("Invalid selector_filter: " + spec.selector_filter);
+        }
+    }
+
+    const fold = s => spec.case_sensitive ? s : s.toLowerCase();
+    const needle = fold(spec.text);
+
+    // Positions of every occurrence of the needle in one text node
+    function occurrences(text) {
+        const haystack = fold(text);
+        if (spec.exact) {
+            return haystack.trim() === needle ? [text.indexOf(text.trim())] : [];
+        }
+        const found = [];
+        for (let i = haystack.indexOf(needle); i !== -1; i = haystack.indexOf(needle, i + needle.length)) {
+            found.push(i);
+        }
+        return found;
+    }
+
+    function snippet(text, index) {
+        const start = Math.max(0, index - CONTEXT);
+        const end = Math.min(text.length, index + spec.text.length + CONTEXT);
+        const middle = text.slice(start, end).replace(/\s+/g, " ").trim();
+        return (start > 0 ? "..." : "") + middle + (end < text.length ? "..." : "");
+    }
+
+    const byElement = new Map();
+    const walker = document.createTreeWalker(document.body, NodeFilter.SHOW_TEXT);
+    for (let node = walker.nextNode(); node; node = walker.nextNode()) {
+        const el = node.parentElement;
+        if (!el || SKIPPED.includes(el.tagName)) continue;
+        if (spec.selector_filter && !el.closest(spec.selector_filter)) continue;
+        const found = occurrences(node.data);
+        if (found.length === 0) continue;
+
+        const entry = byElement.get(el);
+        if (entry) {
+            entry.match_count += found.length;
+        } else {
+            byElement.set(el, {
+                selector_path: selectorPath(el),
+                tag: el.tagName.toLowerCase(),
+                text_snippet: snippet(node.data, found[0]),
+                match_count: found.length
+            });
+        }
+    }
+    return JSON.stringify({ matches: Array.from(byElement.values()) });
+})()
@@ -0,0 +1,49 @@
+return (() => {
+    const fromSelector = {{from_selector}};
+    const toSelector = {{to_selector}};
+    const source = document.querySelector(fromSelector);
+    const target = document.querySelector(toSelector);
+
+    if (!source) {
+        return JSON.stringify({ success: false, error: "Source element not found: " + fromSelector });
+    }
+    if (!target) {
+        return JSON.stringify({ success: false, error: "Target element not found: " + toSelector });
+    }
+
+    function center(el) {
+        const rect = el.getBoundingClientRect();
+        return { x: rect.left + rect.width / 2, y: rect.top + rect.height / 2 };
+    }
+
+    const from = center(source);
+    const to = center(target);
+    const dataTransfer = new DataTransfer();
+
+    // Returns false when a listener called preventDefault()
+    function fire(el, type, point) {
+        const event = new DragEvent(type, {
+            bubbles: true,
+            cancelable: true,
+            composed: true,
+            clientX: point.x,
+            clientY: point.y,
+            dataTransfer: dataTransfer
+        });
+        return el.dispatchEvent(event);
+    }
+
+    fire(source, "dragstart", from);
+    fire(source, "drag", from);
+    fire(target, "dragenter", to);
+    fire(target, "dragover", to);
+    const dropAccepted = !fire(target, "drop", to);
+    fire(source, "dragend", to);
+
+    return JSON.stringify({
+        success: true,
+        from: { x: Math.round(from.x), y: Math.round(from.y) },
+        to: { x: Math.round(to.x), y: Math.round(to.y) },
+        dropAccepted: dropAccepted
+    });
+})()
@@ -0,0 +1,10 @@
+return (async () => {
+    const blob = new Blob([{{code}}], { type: "text/javascript" });
+    const url = URL.createObjectURL(blob);
+    try {
+        const module = await import(url);
+        return module.default === undefined ? null : module.default;
+    } finally {
+        URL.revokeObjectURL(url);
+    }
+})()
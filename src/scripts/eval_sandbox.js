@@ -0,0 +1,81 @@
+const script = {{script}};
+const maxBytes = {{max_output_bytes}};
+const timeoutMs = {{timeout_ms}};
+
+// Runs in the worker; `script` is appended as the body of `run`
+const WORKER_SOURCE = `
+self.onmessage = async () => {
+    const started = performance.now();
+    try {
+        const value = await run();
+        self.postMessage({ ok: true, value: value === undefined ? null : JSON.stringify(value), cpu_ms: performance.now() - started });
+    } catch (e) {
+        self.postMessage({ ok: false, error: String((e && e.message) || e), cpu_ms: performance.now() - started });
+    }
+};
+async function run() {
+`;
+
+// A worker runs on its own thread, so even a busy loop can be terminated
+function runInWorker() {
+    let url;
+    let worker;
+    try {
+        url = URL.createObjectURL(new Blob([WORKER_SOURCE + script + "\n}"], { type: "text/javascript" }));
+        worker = new Worker(url);
+    } catch (e) {
+        if (url) URL.revokeObjectURL(url);
+        return null;
+    }
+    return new Promise(resolve => {
+        const finish = outcome => {
+            clearTimeout(timer);
+            worker.terminate();
+            URL.revokeObjectURL(url);
+            resolve(outcome);
+        };
+        const timer = setTimeout(() => finish({
+            ok: false,
+            error: "Script timed out after " + timeoutMs + "ms",
+            cpu_ms: timeoutMs
+        }), timeoutMs);
+        worker.onmessage = event => finish(event.data);
+        worker.onerror = event => {
+            event.preventDefault();
+            finish({ ok: false, error: event.message || "Worker failed to start", cpu_ms: null });
+        };
+        worker.postMessage(null);
+    });
+}
+
+// Without workers the script shares the page's thread, and only the outer
+// timeout guards it
+async function runInPage() {
+    const AsyncFunction = (async () => {}).constructor;
+    const started = performance.now();
+    try {
+        const value = await new AsyncFunction(script)();
+        return { ok: true, value: value === undefined ? null : JSON.stringify(value), cpu_ms: performance.now() - started };
+    } catch (e) {
+        return { ok: false, error: String((e && e.message) || e), cpu_ms: performance.now() - started };
+    }
+}
+
+const outcome = (typeof Worker === "function" && await runInWorker()) || await runInPage();
+
+let truncated = false;
+if (typeof outcome.value === "string") {
+    const bytes = new TextEncoder().encode(outcome.value);
+    if (bytes.length > maxBytes) {
+        // A character cut in half decodes as U+FFFD, which is dropped
+        outcome.value = new TextDecoder().decode(bytes.slice(0, maxBytes)).replace(/�$/, "");
+        truncated = true;
+    }
+}
+return JSON.stringify({
+    ok: outcome.ok,
+    value: outcome.value === undefined ? null : outcome.value,
+    error: outcome.error || null,
+    cpu_ms: typeof outcome.cpu_ms === "number" ? outcome.cpu_ms : null,
+    truncated
+});
@@ -0,0 +1,77 @@
+return (() => {
+    const spec = {{spec}};
+
+{{> selector_path}}
+
+    function detail(event) {
+        const d = {};
+        if (typeof event.clientX === "number") {
+            d.x = event.clientX;
+            d.y = event.clientY;
+            d.button = event.button;
+        }
+        if (typeof event.key === "string") {
+            d.key = event.key;
+            d.code = event.code;
+        }
+        for (const modifier of ["altKey", "ctrlKey", "metaKey", "shiftKey"]) {
+            if (event[modifier]) d[modifier.replace("Key", "")] = true;
+        }
+        if (typeof event.inputType === "string") d.input_type = event.inputType;
+        const target = event.target;
+        if (target && typeof target.value === "string" && target.type !== "password") {
+            d.value = target.value;
+        }
+        if (target && (target.type === "checkbox" || target.type === "radio")) {
+            d.checked = target.checked;
+        }
+        if (event instanceof CustomEvent && event.detail !== null && event.detail !== undefined) {
+            try {
+                d.detail = JSON.parse(JSON.stringify(event.detail));
+            } catch (e) {
+                d.detail = String(event.detail);
+            }
+        }
+        return d;
+    }
+
+    // Throws a SyntaxError for invalid selectors before anything is installed
+    const matchedElements = document.querySelectorAll(spec.selector).length;
+
+    if (!window.__dioxusInspectorEvents__) {
+        const log = { watches: {}, events: [], listening: new Set() };
+        window.__dioxusInspectorEvents__ = log;
+
+        // One capture listener per event type on the document, so elements
+        // re-rendered after the watch started are still covered and
+        // stopPropagation in the app can't hide events
+        log.listen = type => {
+            if (log.listening.has(type)) return;
+            log.listening.add(type);
+            document.addEventListener(type, event => {
+                const el = event.target instanceof Element ? event.target : event.target && event.target.parentElement;
+                if (!el) return;
+                const watch = Object.values(log.watches)
+                    .find(w => w.events.includes(type) && el.closest(w.selector));
+                if (!watch) return;
+                log.events.push({
+                    selector: watch.selector,
+                    type,
+                    target_selector: selectorPath(el),
+                    timestamp_ms: Date.now(),
+                    detail: detail(event)
+                });
+                const own = log.events.filter(e => e.selector === watch.selector);
+                if (own.length > watch.max_events) log.events.splice(log.events.indexOf(own[0]), 1);
+            }, true);
+        };
+    }
+
+    const log = window.__dioxusInspectorEvents__;
+    log.watches[spec.selector] = spec;
+    spec.events.forEach(log.listen);
+    return JSON.stringify({
+        matched_elements: matchedElements,
+        watched_selectors: Object.keys(log.watches)
+    });
+})()
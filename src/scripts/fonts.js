@@ -0,0 +1,41 @@
+return (() => {
+    // Every font-family list in use, e.g. "\"Inter\", system-ui, sans-serif"
+    const used = new Set();
+    for (const el of document.querySelectorAll("*")) {
+        used.add(getComputedStyle(el).fontFamily);
+    }
+
+    const faces = [];
+    for (const face of document.fonts) {
+        faces.push({ family: face.family, status: face.status });
+    }
+
+    // A family that isn't a web font can still be installed locally. Text
+    // rendered in it measures differently from the generic fallbacks.
+    const canvas = document.createElement("canvas");
+    const ctx = canvas.getContext("2d");
+    const SAMPLE = "mmmmmmmmmmlli1WQ@#";
+    function width(font) {
+        ctx.font = "72px " + font;
+        return ctx.measureText(SAMPLE).width;
+    }
+    const fallbacks = ["monospace", "serif", "sans-serif"].map(g => [g, width(g)]);
+    function installed(family) {
+        const quoted = "\"" + family.replace(/["\\]/g, "\\$&") + "\"";
+        return fallbacks.some(([generic, base]) => width(quoted + ", " + generic) !== base);
+    }
+
+    const families = new Set();
+    for (const list of used) {
+        for (const part of list.split(",")) {
+            const family = part.trim().replace(/^["']|["']$/g, "");
+            if (family) families.add(family);
+        }
+    }
+    const local = [];
+    for (const family of families) {
+        if (installed(family)) local.push(family);
+    }
+
+    return JSON.stringify({ used: Array.from(used), faces, installed: local });
+})()
@@ -0,0 +1,53 @@
+return (() => {
+    const form = document.querySelector({{form_selector}});
+    const fields = {{fields}};
+    const submit = {{submit}};
+
+    if (!form) {
+        return JSON.stringify({ success: false, fields_filled: 0, submitted: false, error: "Form not found" });
+    }
+
+    // Go through the prototype's setter so frameworks tracking `value` see the change
+    function setValue(el, value) {
+        const proto = Object.getPrototypeOf(el);
+        const setter = Object.getOwnPropertyDescriptor(proto, "value")?.set;
+        if (setter) setter.call(el, value); else el.value = value;
+    }
+
+    let filled = 0;
+    const missing = [];
+    for (const [name, value] of Object.entries(fields)) {
+        const controls = Array.from(form.elements).filter(el => el.name === name);
+        if (controls.length === 0) {
+            missing.push(name);
+            continue;
+        }
+        for (const el of controls) {
+            if (el.type === "checkbox") {
+                el.checked = value === "true" || value === "on" || value === el.value;
+            } else if (el.type === "radio") {
+                el.checked = el.value === value;
+            } else {
+                setValue(el, value);
+            }
+            el.dispatchEvent(new Event("input", { bubbles: true }));
+            el.dispatchEvent(new Event("change", { bubbles: true }));
+        }
+        filled++;
+    }
+
+    if (missing.length > 0) {
+        return JSON.stringify({
+            success: false,
+            fields_filled: filled,
+            submitted: false,
+            error: "No field named: " + missing.join(", ")
+        });
+    }
+
+    // requestSubmit fires the submit event (and onsubmit handlers); submit() skips it
+    if (submit) {
+        if (typeof form.requestSubmit === "function") form.requestSubmit(); else form.submit();
+    }
+    return JSON.stringify({ success: true, fields_filled: filled, submitted: submit, error: null });
+})()
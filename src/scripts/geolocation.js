@@ -0,0 +1,66 @@
+return (() => {
+    const geo = navigator.geolocation;
+    if (!geo) throw new Error("navigator.geolocation is not available");
+    const mock = {{mock}};
+
+    if (mock === null) {
+        // The overrides are own properties, so deleting them exposes the real API again
+        delete geo.getCurrentPosition;
+        delete geo.watchPosition;
+        delete geo.clearWatch;
+        delete window.__dioxusInspectorGeo__;
+        return JSON.stringify({ mocked: false });
+    }
+
+    const MESSAGES = { 1: "User denied Geolocation", 2: "Position unavailable", 3: "Timeout expired" };
+    const existing = window.__dioxusInspectorGeo__;
+    const state = existing || { mock, watchers: new Map(), nextId: 1 };
+    state.mock = mock;
+    window.__dioxusInspectorGeo__ = state;
+
+    // Like the real API, callbacks run asynchronously
+    function respond(success, error) {
+        setTimeout(() => {
+            const current = state.mock;
+            if (current.error_code !== null) {
+                if (typeof error === "function") {
+                    error({
+                        code: current.error_code,
+                        message: MESSAGES[current.error_code],
+                        PERMISSION_DENIED: 1,
+                        POSITION_UNAVAILABLE: 2,
+                        TIMEOUT: 3
+                    });
+                }
+                return;
+            }
+            success({
+                coords: {
+                    latitude: current.latitude,
+                    longitude: current.longitude,
+                    accuracy: current.accuracy,
+                    altitude: null,
+                    altitudeAccuracy: null,
+                    heading: null,
+                    speed: null
+                },
+                timestamp: Date.now()
+            });
+        }, 0);
+    }
+
+    if (existing) {
+        // Active watchers see the new position, as if the device moved
+        for (const [success, error] of state.watchers.values()) respond(success, error);
+    } else {
+        geo.getCurrentPosition = (success, error) => respond(success, error);
+        geo.watchPosition = (success, error) => {
+            const id = state.nextId++;
+            state.watchers.set(id, [success, error]);
+            respond(success, error);
+            return id;
+        };
+        geo.clearWatch = id => state.watchers.delete(id);
+    }
+    return JSON.stringify({ mocked: true });
+})()
@@ -0,0 +1,12 @@
+return (() => {
+    const images = Array.from(document.images).map(img => ({
+        src: img.getAttribute("src") || "",
+        complete: img.complete,
+        natural_width: img.naturalWidth,
+        natural_height: img.naturalHeight,
+        current_src: img.currentSrc || "",
+        alt: img.getAttribute("alt"),
+        loading: img.loading || "auto"
+    }));
+    return JSON.stringify(images);
+})()
@@ -0,0 +1,60 @@
+const database = {{database}};
+const storeName = {{store}};
+const key = {{key}};
+
+function fail(error) {
+    return JSON.stringify({ records: [], error });
+}
+
+// open() creates missing databases, so abort the upgrade that signals one
+let missing = false;
+const db = await new Promise((resolve, reject) => {
+    const req = indexedDB.open(database);
+    req.onupgradeneeded = () => {
+        missing = true;
+        req.transaction.abort();
+    };
+    req.onsuccess = () => resolve(req.result);
+    req.onerror = () => (missing ? resolve(null) : reject(req.error));
+});
+if (!db) return fail("Database not found: " + database);
+
+function request(req) {
+    return new Promise((resolve, reject) => {
+        req.onsuccess = () => resolve(req.result);
+        req.onerror = () => reject(req.error);
+    });
+}
+
+try {
+    if (!db.objectStoreNames.contains(storeName)) {
+        return fail("Object store not found: " + storeName);
+    }
+    const store = db.transaction(storeName, "readonly").objectStore(storeName);
+
+    if (key !== null) {
+        let value = await request(store.get(key));
+        // Keys arrive as strings; stores keyed by number need the number
+        if (value === undefined && key.trim() !== "" && !isNaN(Number(key))) {
+            value = await request(store.get(Number(key)));
+        }
+        return JSON.stringify({ records: value === undefined ? [] : [value], error: null });
+    }
+
+    const records = await new Promise((resolve, reject) => {
+        const out = [];
+        const req = store.openCursor();
+        req.onsuccess = () => {
+            const cursor = req.result;
+            if (!cursor) return resolve(out);
+            out.push(cursor.value);
+            cursor.continue();
+        };
+        req.onerror = () => reject(req.error);
+    });
+    return JSON.stringify({ records, error: null });
+} catch (e) {
+    return fail(String(e));
+} finally {
+    db.close();
+}
@@ -0,0 +1,29 @@
+return (() => {
+    const formSelector = {{form_selector}};
+    const root = formSelector === null ? document : document.querySelector(formSelector);
+    if (!root) throw new Error("Form not found: " + formSelector);
+
+    // A form's elements also include controls outside it that use form="id"
+    const controls = root instanceof HTMLFormElement
+        ? Array.from(root.elements).filter(el => el.matches("input, select, textarea"))
+        : Array.from(root.querySelectorAll("input, select, textarea"));
+
+    const inputs = controls.map(el => {
+        const checkable = el.type === "checkbox" || el.type === "radio";
+        const value = el.type === "select-multiple"
+            ? Array.from(el.selectedOptions).map(o => o.value).join(",")
+            : el.value;
+        return {
+            name: el.name || "",
+            id: el.id || "",
+            type: el.type,
+            value,
+            checked: checkable ? el.checked : null,
+            validity_valid: el.validity.valid,
+            validation_message: el.validationMessage,
+            required: el.required,
+            disabled: el.matches(":disabled")
+        };
+    });
+    return JSON.stringify({ inputs });
+})()
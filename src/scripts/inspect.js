@@ -1,5 +1,6 @@
 return (() => {
-    const selector = {SELECTOR};
+    const selector = {{selector}};
+    const INCLUDE_LISTENERS = {{include_listeners}};
     const el = document.querySelector(selector);
 
     if (!el) {
@@ -67,6 +68,23 @@ return (() => {
         });
     }
 
+    // Prefers the devtools console API, then the tracker the bridge installs on
+    // startup (only sees listeners added after it ran), plus inline on* handlers.
+    function listEventListeners(el) {
+        let types = null;
+        if (typeof getEventListeners === "function") {
+            types = Object.keys(getEventListeners(el));
+        } else if (typeof window.__dioxusInspectorListeners__ === "function") {
+            types = window.__dioxusInspectorListeners__(el);
+        }
+        const inline = [];
+        for (const key in el) {
+            if (key.startsWith("on") && typeof el[key] === "function") inline.push(key.slice(2));
+        }
+        if (types === null && inline.length === 0) return null;
+        return Array.from(new Set([...(types || []), ...inline])).sort();
+    }
+
     const isVisible = !issues.some(i =>
         ["out_of_viewport", "display_none", "visibility_hidden", "opacity_zero", "zero_dimensions"].includes(i.type)
     );
@@ -90,6 +108,7 @@ return (() => {
         },
         viewport: viewport,
         issues: issues,
+        event_listeners: INCLUDE_LISTENERS ? listEventListeners(el) : null,
         summary: issues.length === 0 ? "Element is visible" : issues.map(i => i.message).join("; ")
     });
 })()
@@ -0,0 +1,27 @@
+return (() => {
+{{> selector_path}}
+
+    const viewportWidth = window.innerWidth;
+    const viewportHeight = window.innerHeight;
+    const elements = Array.from(document.querySelectorAll({{selector}})).map(el => {
+        const rect = el.getBoundingClientRect();
+        const visibleWidth = Math.min(rect.right, viewportWidth) - Math.max(rect.left, 0);
+        const visibleHeight = Math.min(rect.bottom, viewportHeight) - Math.max(rect.top, 0);
+        const area = rect.width * rect.height;
+        // Zero-size elements have no visible area, so only their position counts
+        const inViewport = area > 0
+            ? visibleWidth > 0 && visibleHeight > 0
+            : visibleWidth >= 0 && visibleHeight >= 0;
+        let ratio = 0;
+        if (inViewport) {
+            ratio = area > 0 ? Math.min(1, (visibleWidth * visibleHeight) / area) : 1;
+        }
+        return {
+            selector_path: selectorPath(el),
+            in_viewport: inViewport,
+            intersection_ratio: ratio,
+            bounding_rect: { top: rect.top, left: rect.left, width: rect.width, height: rect.height }
+        };
+    });
+    return JSON.stringify({ elements });
+})()
@@ -0,0 +1,30 @@
+return (() => {
+    const container = document.querySelector({{selector}});
+    if (!container) return JSON.stringify({ found: false });
+
+{{> selector_path}}
+
+    const style = getComputedStyle(container);
+    const isFlex = style.display.includes("flex");
+    const isGrid = style.display.includes("grid");
+
+    return JSON.stringify({
+        found: true,
+        display: style.display,
+        flex_direction: isFlex ? style.flexDirection : null,
+        flex_wrap: isFlex ? style.flexWrap : null,
+        grid_template_columns: isGrid ? style.gridTemplateColumns : null,
+        grid_template_rows: isGrid ? style.gridTemplateRows : null,
+        children: Array.from(container.children).map(child => {
+            const childStyle = getComputedStyle(child);
+            return {
+                selector_path: selectorPath(child),
+                flex_basis: childStyle.flexBasis,
+                flex_grow: childStyle.flexGrow,
+                flex_shrink: childStyle.flexShrink,
+                grid_column: childStyle.gridColumn,
+                grid_row: childStyle.gridRow
+            };
+        })
+    });
+})()
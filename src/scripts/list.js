@@ -0,0 +1,45 @@
+return (() => {
+    const selector = {{selector}};
+    const LIST_TAGS = ["UL", "OL", "MENU", "DL"];
+    const ITEM_ROLES = { list: "listitem", listbox: "option", menu: "menuitem" };
+    const NESTED = "ul, ol, menu, dl, [role=list], [role=listbox], [role=menu]";
+
+    const list = Array.from(document.querySelectorAll(selector))
+        .find(el => LIST_TAGS.includes(el.tagName) || el.getAttribute("role") in ITEM_ROLES);
+    if (!list) throw new Error("No list matches " + selector);
+
+    const itemSelector = list.tagName === "DL" ? "dt, dd"
+        : LIST_TAGS.includes(list.tagName) ? "li"
+        : "[role=" + ITEM_ROLES[list.getAttribute("role")] + "]";
+    // Skip items of nested lists; <dl> items may sit in a <div> per term
+    const items = Array.from(list.querySelectorAll(itemSelector))
+        .filter(el => el.parentElement.closest(NESTED) === list);
+
+    function ownText(item) {
+        const copy = item.cloneNode(true);
+        copy.querySelectorAll(NESTED).forEach(nested => nested.remove());
+        return (copy.textContent || "").trim().replace(/\s+/g, " ");
+    }
+
+    function dataAttributes(item) {
+        const attributes = {};
+        for (const [key, value] of Object.entries(item.dataset)) {
+            attributes["data-" + key.replace(/[A-Z]/g, c => "-" + c.toLowerCase())] = value;
+        }
+        return attributes;
+    }
+
+    return JSON.stringify({
+        items: items.map(item => {
+            const link = item.matches("a[href]") ? item : item.querySelector("a[href]");
+            return {
+                tag: item.tagName.toLowerCase(),
+                text: ownText(item),
+                href: link ? link.href : null,
+                data_attributes: dataAttributes(item)
+            };
+        }),
+        list_type: LIST_TAGS.includes(list.tagName) ? list.tagName.toLowerCase() : list.getAttribute("role"),
+        ordered: list.tagName === "OL"
+    });
+})()
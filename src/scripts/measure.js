@@ -0,0 +1,21 @@
+return (() => {
+    function run() {
+{SCRIPT}
+    }
+
+    const repetitions = {{repetitions}};
+    const label = {{label}};
+    const samples = [];
+    for (let i = 0; i < repetitions; i++) {
+        const start = label + ":start:" + i;
+        performance.mark(start);
+        run();
+        // Older WebKit returns undefined instead of the measure
+        const measure = performance.measure(label, start)
+            || performance.getEntriesByName(label, "measure").pop();
+        samples.push(measure.duration);
+        performance.clearMarks(start);
+    }
+    performance.clearMeasures(label);
+    return JSON.stringify({ samples });
+})()
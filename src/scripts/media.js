@@ -0,0 +1,53 @@
+return (async () => {
+    const selector = {{selector}};
+    const action = {{action}};
+    const value = {{value}};
+
+    function describe(el) {
+        let desc = el.tagName.toLowerCase();
+        if (el.id) desc += "#" + el.id;
+        if (typeof el.className === "string" && el.className.trim()) {
+            desc += "." + el.className.trim().split(/\s+/).join(".");
+        }
+        return desc;
+    }
+
+    // NaN (no metadata yet) and Infinity (live streams) aren't valid JSON
+    function finite(n) {
+        return Number.isFinite(n) ? n : null;
+    }
+
+    const elements = Array.from(document.querySelectorAll(selector))
+        .filter(el => el instanceof HTMLMediaElement);
+
+    for (const el of elements) {
+        switch (action) {
+            case "play": await el.play(); break;
+            case "pause": el.pause(); break;
+            case "seek": el.currentTime = value; break;
+            case "mute": el.muted = value !== 0; break;
+            case "volume": el.volume = value; break;
+        }
+    }
+
+    return JSON.stringify({
+        media: elements.map(el => {
+            const buffered = [];
+            for (let i = 0; i < el.buffered.length; i++) {
+                buffered.push({ start: el.buffered.start(i), end: el.buffered.end(i) });
+            }
+            return {
+                element: describe(el),
+                src: el.currentSrc || el.src || null,
+                current_time: el.currentTime,
+                duration: finite(el.duration),
+                paused: el.paused,
+                muted: el.muted,
+                volume: el.volume,
+                buffered,
+                ready_state: el.readyState,
+                network_state: el.networkState
+            };
+        })
+    });
+})()
@@ -0,0 +1,59 @@
+return (() => {
+    const MAX_ENTRIES = {{max_entries}};
+    const MAX_VALUE_CHARS = 200;
+
+{{> selector_path}}
+
+    const clip = value => value === null || value === undefined ? null : String(value).slice(0, MAX_VALUE_CHARS);
+
+    // Starting again restarts the log, so counts are always since the last start
+    const previous = window.__dioxusInspectorMutations__;
+    if (previous) previous.observer.disconnect();
+
+    const log = {
+        added_nodes: 0,
+        removed_nodes: 0,
+        attribute_changes: 0,
+        text_changes: 0,
+        entries: []
+    };
+
+    log.observer = new MutationObserver(records => {
+        for (const record of records) {
+            const target = record.target.nodeType === 1 ? record.target : record.target.parentElement;
+            const entry = {
+                kind: record.type === "childList" ? "child_list"
+                    : record.type === "characterData" ? "character_data" : "attributes",
+                target_selector: target ? selectorPath(target) : "",
+                timestamp_ms: Date.now(),
+                added_nodes: record.addedNodes.length,
+                removed_nodes: record.removedNodes.length,
+                attribute_name: record.attributeName,
+                old_value: clip(record.oldValue),
+                new_value: null
+            };
+            if (record.type === "childList") {
+                log.added_nodes += entry.added_nodes;
+                log.removed_nodes += entry.removed_nodes;
+            } else if (record.type === "attributes") {
+                log.attribute_changes += 1;
+                entry.new_value = clip(record.target.getAttribute(record.attributeName));
+            } else {
+                log.text_changes += 1;
+                entry.new_value = clip(record.target.data);
+            }
+            log.entries.push(entry);
+            if (log.entries.length > MAX_ENTRIES) log.entries.shift();
+        }
+    });
+    log.observer.observe(document.body, {
+        subtree: true,
+        childList: true,
+        attributes: true,
+        attributeOldValue: true,
+        characterData: true,
+        characterDataOldValue: true
+    });
+    window.__dioxusInspectorMutations__ = log;
+    return JSON.stringify({ observing: true });
+})()
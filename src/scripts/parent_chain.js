@@ -0,0 +1,20 @@
+return (() => {
+    const el = document.querySelector({{selector}});
+    if (!el) return JSON.stringify({ found: false });
+
+    const chain = [];
+    for (let node = el.parentElement; node && chain.length < {{depth}}; node = node.parentElement) {
+        const style = getComputedStyle(node);
+        chain.push({
+            tag: node.tagName.toLowerCase(),
+            id: node.id || null,
+            classes: Array.from(node.classList),
+            display: style.display,
+            overflow: style.overflow,
+            visibility: style.visibility,
+            z_index: style.zIndex,
+            position: style.position
+        });
+    }
+    return JSON.stringify({ found: true, chain });
+})()
@@ -0,0 +1,86 @@
+return (() => {
+    const MAX_NODES = 2000;
+    const MAX_DEPTH = 32;
+    const MAX_LISTED = 5;
+    const issues = [];
+    const perf = window.__dioxusInspectorPerf__;
+
+    function describe(el) {
+        if (el.id) return el.tagName.toLowerCase() + "#" + el.id;
+        const cls = el.classList[0];
+        return cls ? el.tagName.toLowerCase() + "." + cls : el.tagName.toLowerCase();
+    }
+
+    if (perf && perf.longTasks.supported && perf.longTasks.count > 0) {
+        const { count, total_ms } = perf.longTasks;
+        issues.push({
+            kind: "long_tasks",
+            severity: count > 10 ? "error" : "warning",
+            message: count + " long task(s) blocked the main thread for " + Math.round(total_ms) + "ms in total"
+        });
+    }
+
+    if (perf && perf.lastFrame.forced > 1) {
+        const { reads, writes, forced } = perf.lastFrame;
+        issues.push({
+            kind: "layout_thrashing",
+            severity: forced > 10 ? "error" : "warning",
+            message: forced + " forced layouts in the last frame that touched layout (" +
+                reads + " reads interleaved with " + writes + " writes)"
+        });
+    }
+
+    const nodes = document.getElementsByTagName("*").length;
+    let maxDepth = 0;
+    const stack = [[document.documentElement, 1]];
+    while (stack.length) {
+        const [el, depth] = stack.pop();
+        if (depth > maxDepth) maxDepth = depth;
+        for (const child of el.children) stack.push([child, depth + 1]);
+    }
+    if (nodes > MAX_NODES || maxDepth > MAX_DEPTH) {
+        issues.push({
+            kind: "dom_size",
+            severity: nodes > MAX_NODES * 2 ? "error" : "warning",
+            message: nodes + " elements, nested up to " + maxDepth +
+                " deep; large trees make every rerender and layout slower"
+        });
+    }
+
+    if (typeof document.getAnimations === "function") {
+        const missing = new Set();
+        for (const animation of document.getAnimations()) {
+            const target = animation.effect && animation.effect.target;
+            if (animation.playState !== "running" || !(target instanceof Element)) continue;
+            if (getComputedStyle(target).willChange === "auto") missing.add(target);
+        }
+        if (missing.size > 0) {
+            const names = Array.from(missing).slice(0, MAX_LISTED).map(describe);
+            issues.push({
+                kind: "missing_will_change",
+                severity: "warning",
+                message: missing.size + " animated element(s) without a will-change hint: " +
+                    names.join(", ") + (missing.size > MAX_LISTED ? ", ..." : "")
+            });
+        }
+    }
+
+    if (perf && perf.documentWrites > 0) {
+        issues.push({
+            kind: "document_write",
+            severity: "error",
+            message: "document.write was called " + perf.documentWrites +
+                " time(s); it blocks parsing and can wipe the page"
+        });
+    }
+
+    if (!perf) {
+        issues.push({
+            kind: "tracker_missing",
+            severity: "info",
+            message: "The performance tracker isn't installed, so long tasks, layout thrashing and document.write weren't checked"
+        });
+    }
+
+    return JSON.stringify({ issues });
+})()
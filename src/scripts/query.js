@@ -0,0 +1,13 @@
+return (() => {
+    const el = document.querySelector({{selector}});
+    if (!el) return null;
+
+    const property = {{property}};
+    switch (property) {
+        case "text": return el.textContent;
+        case "html": return el.innerHTML;
+        case "outerHTML": return el.outerHTML;
+        case "value": return el.value;
+        default: return el.getAttribute(property);
+    }
+})()
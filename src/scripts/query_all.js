@@ -0,0 +1,20 @@
+return (() => {
+    const elements = document.querySelectorAll({{selector}});
+    const property = {{property}};
+    const limit = {{limit}};
+
+    function read(el) {
+        switch (property) {
+            case "text": return el.textContent;
+            case "html": return el.innerHTML;
+            case "outerHTML": return el.outerHTML;
+            case "value": return el.value ?? null;
+            default: return el.getAttribute(property);
+        }
+    }
+
+    return JSON.stringify({
+        total_count: elements.length,
+        values: Array.from(elements).slice(0, limit).map(read)
+    });
+})()
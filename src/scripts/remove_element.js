@@ -0,0 +1,5 @@
+return (() => {
+    const elements = document.querySelectorAll({{selector}});
+    elements.forEach(el => el.remove());
+    return JSON.stringify({ removed: elements.length });
+})()
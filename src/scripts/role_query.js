@@ -0,0 +1,106 @@
+return (() => {
+    const role = {{role}};
+    const name = {{name}};
+
+    // Elements that have a role without a role attribute
+    const IMPLICIT_ROLES = {
+        button: "button, input[type=button], input[type=submit], input[type=reset], input[type=image], summary",
+        link: "a[href], area[href]",
+        heading: "h1, h2, h3, h4, h5, h6",
+        checkbox: "input[type=checkbox]",
+        radio: "input[type=radio]",
+        textbox: "textarea, input:not([type]), input[type=text], input[type=email], input[type=tel], input[type=url]",
+        searchbox: "input[type=search]",
+        slider: "input[type=range]",
+        spinbutton: "input[type=number]",
+        combobox: "select:not([multiple]):not([size])",
+        listbox: "select[multiple], select[size]",
+        option: "option",
+        img: "img[alt]:not([alt=''])",
+        list: "ul, ol",
+        listitem: "li",
+        navigation: "nav",
+        main: "main",
+        dialog: "dialog",
+        table: "table",
+        row: "tr",
+        cell: "td",
+        form: "form[aria-label], form[aria-labelledby]",
+        progressbar: "progress"
+    };
+    const NAMING_ATTRIBUTES = ["aria-label", "aria-labelledby", "aria-describedby", "aria-description"];
+
+{{> selector_path}}
+
+    const collapse = text => String(text || "").replace(/\s+/g, " ").trim();
+
+    function textOfIds(ids) {
+        return collapse(ids.split(/\s+/)
+            .map(id => document.getElementById(id))
+            .filter(Boolean)
+            .map(node => node.textContent)
+            .join(" "));
+    }
+
+    function accessibleName(el) {
+        const labelledBy = el.getAttribute("aria-labelledby");
+        if (labelledBy && textOfIds(labelledBy)) return textOfIds(labelledBy);
+        if (collapse(el.getAttribute("aria-label"))) return collapse(el.getAttribute("aria-label"));
+        if (el.labels && el.labels.length > 0) {
+            return collapse(Array.from(el.labels).map(label => label.textContent).join(" "));
+        }
+        if (el.tagName === "IMG" || (el.tagName === "INPUT" && el.type === "image")) {
+            if (collapse(el.getAttribute("alt"))) return collapse(el.getAttribute("alt"));
+        }
+        if (el.tagName === "INPUT" && ["button", "submit", "reset"].includes(el.type)) {
+            return collapse(el.value);
+        }
+        return collapse(el.textContent) || collapse(el.getAttribute("title"));
+    }
+
+    function accessibleDescription(el) {
+        const describedBy = el.getAttribute("aria-describedby");
+        if (describedBy && textOfIds(describedBy)) return textOfIds(describedBy);
+        // A title already used as the name doesn't describe the element too
+        const title = collapse(el.getAttribute("title"));
+        return collapse(el.getAttribute("aria-description")) || (title === accessibleName(el) ? "" : title);
+    }
+
+    function states(el) {
+        const result = {};
+        for (const attr of Array.from(el.attributes)) {
+            if (attr.name.startsWith("aria-") && !NAMING_ATTRIBUTES.includes(attr.name)) {
+                result[attr.name.slice(5)] = attr.value;
+            }
+        }
+        // Native state the ARIA attributes would otherwise duplicate
+        if (el.disabled && !("disabled" in result)) result.disabled = "true";
+        if ((el.type === "checkbox" || el.type === "radio") && !("checked" in result)) {
+            result.checked = el.indeterminate ? "mixed" : String(el.checked);
+        }
+        if (el.required && !("required" in result)) result.required = "true";
+        if (el.tagName === "OPTION" && !("selected" in result)) result.selected = String(el.selected);
+        return result;
+    }
+
+    const explicit = Array.from(document.querySelectorAll("[role]"))
+        .filter(el => el.getAttribute("role").trim().split(/\s+/)[0] === role);
+    // An explicit role replaces the implicit one
+    const implicit = IMPLICIT_ROLES[role]
+        ? Array.from(document.querySelectorAll(IMPLICIT_ROLES[role])).filter(el => !el.hasAttribute("role"))
+        : [];
+    const inDomOrder = Array.from(new Set([...explicit, ...implicit])).sort((a, b) =>
+        a.compareDocumentPosition(b) & Node.DOCUMENT_POSITION_FOLLOWING ? -1 : 1);
+
+    const wanted = name === null ? null : collapse(name).toLowerCase();
+    const elements = inDomOrder
+        .map(el => ({ el, accessibleName: accessibleName(el) }))
+        .filter(({ accessibleName }) => wanted === null || accessibleName.toLowerCase().includes(wanted))
+        .map(({ el, accessibleName }) => ({
+            selector_path: selectorPath(el),
+            accessible_name: accessibleName,
+            accessible_description: accessibleDescription(el),
+            states: states(el)
+        }));
+    return JSON.stringify({ elements });
+})()
@@ -0,0 +1,32 @@
+return (() => {
+    const SCROLLABLE = ["auto", "scroll", "overlay"];
+
+{{> selector_path}}
+
+    function overflows(el) {
+        return el.scrollHeight > el.clientHeight || el.scrollWidth > el.clientWidth;
+    }
+
+    // The scrolling element follows the viewport's overflow, not its own
+    function scrollable(el) {
+        if (el === document.scrollingElement) return true;
+        const style = getComputedStyle(el);
+        return SCROLLABLE.includes(style.overflowY) || SCROLLABLE.includes(style.overflowX);
+    }
+
+    const containers = [];
+    for (const el of document.querySelectorAll("*")) {
+        if (!overflows(el) || !scrollable(el)) continue;
+        containers.push({
+            selector_path: selectorPath(el),
+            scroll_x: el.scrollLeft,
+            scroll_y: el.scrollTop,
+            scroll_width: el.scrollWidth,
+            scroll_height: el.scrollHeight,
+            client_width: el.clientWidth,
+            client_height: el.clientHeight,
+            is_at_bottom: el.scrollTop + el.clientHeight >= el.scrollHeight - 1
+        });
+    }
+    return JSON.stringify({ containers });
+})()
@@ -0,0 +1,18 @@
+return (() => {
+    const el = document.querySelector({{selector}});
+    if (!el) {
+        return JSON.stringify({ success: false, error: "Element not found: " + {{selector}} });
+    }
+
+    el.scrollIntoView({{options}});
+    const rect = el.getBoundingClientRect();
+    return JSON.stringify({
+        success: true,
+        rect: {
+            x: Math.round(rect.x),
+            y: Math.round(rect.y),
+            width: Math.round(rect.width),
+            height: Math.round(rect.height)
+        }
+    });
+})()
@@ -0,0 +1,48 @@
+return (() => {
+    const selector = {{selector}};
+    const start = {{start}};
+    const end = {{end}};
+    const el = document.querySelector(selector);
+
+    if (!el) {
+        return JSON.stringify({ success: false, error: "Element not found: " + selector });
+    }
+
+    // Text controls keep their own selection, separate from the document's
+    if (typeof el.setSelectionRange === "function" && typeof el.value === "string") {
+        try {
+            el.focus();
+            el.setSelectionRange(start, end);
+        } catch (e) {
+            // e.g. <input type="number"> doesn't support selection
+            return JSON.stringify({ success: false, error: e.message });
+        }
+        return JSON.stringify({
+            success: true,
+            selected_text: el.value.substring(el.selectionStart, el.selectionEnd)
+        });
+    }
+
+    // Map character offsets in textContent to (text node, offset) positions
+    function locate(offset) {
+        const walker = document.createTreeWalker(el, NodeFilter.SHOW_TEXT);
+        let remaining = offset;
+        let last = null;
+        while (walker.nextNode()) {
+            const node = walker.currentNode;
+            if (remaining <= node.length) {
+                return { node, offset: remaining };
+            }
+            remaining -= node.length;
+            last = node;
+        }
+        return last ? { node: last, offset: last.length } : { node: el, offset: 0 };
+    }
+
+    const base = locate(start);
+    const extent = locate(end);
+    const selection = window.getSelection();
+    selection.setBaseAndExtent(base.node, base.offset, extent.node, extent.offset);
+
+    return JSON.stringify({ success: true, selected_text: selection.toString() });
+})()
@@ -0,0 +1,20 @@
+    // Path from the nearest ancestor with an id, e.g. `main#app > ul > li:nth-of-type(2)`
+    function selectorPath(el) {
+        const parts = [];
+        for (let node = el; node && node.nodeType === 1 && node !== document.documentElement; node = node.parentElement) {
+            const tag = node.tagName.toLowerCase();
+            if (node.id) {
+                parts.unshift(tag + "#" + CSS.escape(node.id));
+                break;
+            }
+            let part = tag;
+            const siblings = node.parentElement
+                ? Array.from(node.parentElement.children).filter(s => s.tagName === node.tagName)
+                : [];
+            if (siblings.length > 1) {
+                part += ":nth-of-type(" + (siblings.indexOf(node) + 1) + ")";
+            }
+            parts.unshift(part);
+        }
+        return parts.join(" > ") || "html";
+    }
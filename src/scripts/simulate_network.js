@@ -0,0 +1,64 @@
+return (() => {
+    const condition = {{condition}};
+    const existing = window.__dioxusInspectorNetworkSim__;
+
+    function setOnline(online) {
+        if (online) {
+            // The override is an own property, so deleting it exposes the real getter again
+            delete navigator.onLine;
+        } else {
+            Object.defineProperty(navigator, "onLine", { get: () => false, configurable: true });
+        }
+        window.dispatchEvent(new Event(online ? "online" : "offline"));
+    }
+
+    if (condition === null || condition.type === "normal") {
+        if (existing) {
+            // Leave fetch alone if something patched it after us
+            if (window.fetch === existing.patched) window.fetch = existing.originalFetch;
+            if (existing.condition.type === "offline") setOnline(true);
+            delete window.__dioxusInspectorNetworkSim__;
+        }
+        return JSON.stringify({ simulated: false });
+    }
+
+    const state = existing || { originalFetch: window.fetch, condition };
+    const wasOffline = Boolean(existing) && existing.condition.type === "offline";
+    state.condition = condition;
+    window.__dioxusInspectorNetworkSim__ = state;
+
+    const NULL_BODY_STATUSES = [101, 204, 205, 304];
+    const sleep = ms => new Promise(resolve => setTimeout(resolve, ms));
+
+    if (!existing) {
+        state.patched = function () {
+            const current = state.condition;
+            if (current.type === "offline") {
+                // Browsers reject failed fetches with a TypeError
+                return Promise.reject(new TypeError("NetworkError when attempting to fetch resource."));
+            }
+            const args = arguments;
+            return sleep(current.latency_ms)
+                .then(() => state.originalFetch.apply(this, args))
+                .then(response => {
+                    // Opaque and bodiless responses can't be rebuilt, so only the latency applies
+                    if (response.type === "opaque" || NULL_BODY_STATUSES.includes(response.status)) {
+                        return response;
+                    }
+                    return response.blob().then(blob => {
+                        const transferMs = (blob.size * 8) / current.bandwidth_kbps;
+                        return sleep(transferMs).then(() => new Response(blob, {
+                            status: response.status,
+                            statusText: response.statusText,
+                            headers: response.headers
+                        }));
+                    });
+                });
+        };
+        window.fetch = state.patched;
+    }
+
+    const isOffline = condition.type === "offline";
+    if (isOffline !== wasOffline) setOnline(!isOffline);
+    return JSON.stringify({ simulated: true });
+})()
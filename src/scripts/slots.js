@@ -0,0 +1,16 @@
+return (() => {
+    const hostSelector = {{host_selector}};
+    const host = document.querySelector(hostSelector);
+    if (!host) throw new Error("Element not found: " + hostSelector);
+    // Closed shadow roots aren't reachable from outside
+    if (!host.shadowRoot) throw new Error("No open shadow root on " + hostSelector);
+
+{{> selector_path}}
+
+    const slots = Array.from(host.shadowRoot.querySelectorAll("slot")).map(slot => ({
+        name: slot.name,
+        assigned_elements: slot.assignedElements({ flatten: true }).map(selectorPath),
+        fallback_content: slot.innerHTML.trim()
+    }));
+    return JSON.stringify({ slots });
+})()
@@ -0,0 +1,57 @@
+return (() => {
+    const root = document.querySelector({{selector}});
+    if (!root) return JSON.stringify({ found: false });
+
+{{> selector_path}}
+
+    // The conditions from the CSS spec under which an element creates a
+    // stacking context
+    function reasons(el, style) {
+        const found = [];
+        if (el === document.documentElement) found.push("root element");
+        const position = style.position;
+        if (position === "fixed" || position === "sticky") {
+            found.push("position: " + position);
+        } else if (position !== "static" && style.zIndex !== "auto") {
+            found.push("position: " + position + " with z-index");
+        } else if (style.zIndex !== "auto" && el.parentElement) {
+            const parentDisplay = getComputedStyle(el.parentElement).display;
+            if (parentDisplay.includes("flex") || parentDisplay.includes("grid")) {
+                found.push("z-index on a " + (parentDisplay.includes("flex") ? "flex" : "grid") + " item");
+            }
+        }
+        if (parseFloat(style.opacity) < 1) found.push("opacity: " + style.opacity);
+        for (const prop of ["transform", "scale", "rotate", "translate", "filter", "backdropFilter", "perspective", "clipPath", "mask", "maskImage"]) {
+            const value = style[prop];
+            if (value && value !== "none") {
+                found.push(prop.replace(/[A-Z]/g, c => "-" + c.toLowerCase()) + ": " + value);
+            }
+        }
+        if (style.mixBlendMode && style.mixBlendMode !== "normal") found.push("mix-blend-mode: " + style.mixBlendMode);
+        if (style.isolation === "isolate") found.push("isolation: isolate");
+        const willChange = (style.willChange || "").split(",").map(v => v.trim())
+            .filter(v => ["opacity", "transform", "filter", "z-index", "position", "perspective", "clip-path", "mask"].includes(v));
+        if (willChange.length) found.push("will-change: " + willChange.join(", "));
+        const contain = style.contain || "";
+        if (/\b(layout|paint|strict|content)\b/.test(contain)) found.push("contain: " + contain);
+        if (style.containerType === "size" || style.containerType === "inline-size") {
+            found.push("container-type: " + style.containerType);
+        }
+        return found;
+    }
+
+    const contexts = [];
+    for (const el of [root, ...root.querySelectorAll("*")]) {
+        const style = getComputedStyle(el);
+        const creates = reasons(el, style);
+        if (!creates.length) continue;
+        const rect = el.getBoundingClientRect();
+        contexts.push({
+            selector_path: selectorPath(el),
+            z_index: style.zIndex,
+            creates_context_reason: creates,
+            bounding_rect: { top: rect.top, left: rect.left, width: rect.width, height: rect.height }
+        });
+    }
+    return JSON.stringify({ found: true, contexts });
+})()
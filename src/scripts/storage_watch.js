@@ -0,0 +1,53 @@
+return (() => {
+    if (!window.__dioxusInspectorStorage__) {
+        const MAX_ENTRIES = 200;
+        const tracker = { seq: 0, events: [] };
+        window.__dioxusInspectorStorage__ = tracker;
+
+        function record(key, oldValue, newValue, url) {
+            tracker.seq += 1;
+            tracker.events.push({
+                seq: tracker.seq,
+                key,
+                old_value: oldValue,
+                new_value: newValue,
+                url,
+                timestamp_ms: Date.now()
+            });
+            if (tracker.events.length > MAX_ENTRIES) tracker.events.shift();
+        }
+
+        // Changes made by other windows of the same origin
+        window.addEventListener("storage", event => {
+            if (event.storageArea !== localStorage) return;
+            record(event.key, event.oldValue, event.newValue, event.url);
+        });
+
+        // This window's own changes never fire "storage" here, so record them at the source
+        const proto = Storage.prototype;
+        const { setItem, removeItem, clear } = proto;
+        proto.setItem = function (key, value) {
+            if (this !== localStorage) return setItem.call(this, key, value);
+            const oldValue = this.getItem(key);
+            setItem.call(this, key, value);
+            record(String(key), oldValue, String(value), location.href);
+        };
+        proto.removeItem = function (key) {
+            if (this !== localStorage) return removeItem.call(this, key);
+            const oldValue = this.getItem(key);
+            removeItem.call(this, key);
+            if (oldValue !== null) record(String(key), oldValue, null, location.href);
+        };
+        proto.clear = function () {
+            clear.call(this);
+            if (this === localStorage) record(null, null, null, location.href);
+        };
+    }
+
+    const tracker = window.__dioxusInspectorStorage__;
+    const since = {{since}};
+    return JSON.stringify({
+        seq: tracker.seq,
+        events: since === null ? [] : tracker.events.filter(e => e.seq > since)
+    });
+})()
@@ -0,0 +1,32 @@
+return (() => {
+{{> selector_path}}
+
+    const attributes = {
+        view_box: "viewBox",
+        width: "width",
+        height: "height",
+        preserve_aspect_ratio: "preserveAspectRatio",
+        transform: "transform",
+        d: "d",
+        cx: "cx",
+        cy: "cy",
+        r: "r",
+        points: "points"
+    };
+
+    // Nested <svg>s are already covered by their outermost ancestor
+    const seen = new Set();
+    const elements = [];
+    for (const svg of document.querySelectorAll("svg")) {
+        for (const el of [svg, ...svg.querySelectorAll("*")]) {
+            if (seen.has(el)) continue;
+            seen.add(el);
+            const entry = { selector_path: selectorPath(el), tag: el.tagName };
+            for (const [key, name] of Object.entries(attributes)) {
+                entry[key] = el.getAttribute(name);
+            }
+            elements.push(entry);
+        }
+    }
+    return JSON.stringify({ elements });
+})()
@@ -0,0 +1,23 @@
+return (() => {
+    const selector = {{selector}};
+    const el = document.querySelector(selector);
+    if (!el) {
+        return JSON.stringify({ success: false, error: "Element not found: " + selector });
+    }
+    if (!(el instanceof SVGElement)) {
+        return JSON.stringify({ success: false, error: "Not an SVG element: <" + el.tagName.toLowerCase() + ">" });
+    }
+
+    const animate = document.createElementNS("http://www.w3.org/2000/svg", "animate");
+    animate.setAttribute("attributeName", {{attribute}});
+    animate.setAttribute("from", {{from}});
+    animate.setAttribute("to", {{to}});
+    animate.setAttribute("dur", {{duration_ms}} + "ms");
+    animate.setAttribute("fill", "freeze");
+    // With the default begin="0s", an element added after the document
+    // timeline started would jump straight to its end
+    animate.setAttribute("begin", "indefinite");
+    el.appendChild(animate);
+    animate.beginElement();
+    return JSON.stringify({ success: true });
+})()
@@ -0,0 +1,46 @@
+return (() => {
+    const FOCUSABLE = [
+        "a[href]", "area[href]", "button", "input", "select", "textarea",
+        "iframe", "summary", "[contenteditable]", "[tabindex]"
+    ].join(",");
+    const MAX_TEXT_LEN = 100;
+
+    function reachable(el) {
+        if (el.tabIndex < 0 || el.disabled) return false;
+        if (el.tagName === "INPUT" && el.type === "hidden") return false;
+        if (el.closest("[inert]")) return false;
+        // Elements that aren't rendered (display: none, hidden ancestors) can't take focus
+        if (el.getClientRects().length === 0) return false;
+        return getComputedStyle(el).visibility !== "hidden";
+    }
+
+{{> selector_path}}
+
+    function label(el) {
+        const text = el.getAttribute("aria-label")
+            || (el.textContent || "").trim()
+            || el.getAttribute("placeholder")
+            || el.value
+            || el.getAttribute("title")
+            || "";
+        const collapsed = String(text).replace(/\s+/g, " ");
+        return collapsed.length > MAX_TEXT_LEN ? collapsed.slice(0, MAX_TEXT_LEN) + "..." : collapsed;
+    }
+
+    const candidates = Array.from(document.querySelectorAll(FOCUSABLE)).filter(reachable);
+
+    // Positive tabindex values come first in ascending order, then everything
+    // else in document order. The sort is stable, so ties keep DOM order.
+    const rank = el => (el.tabIndex > 0 ? el.tabIndex : Infinity);
+    candidates.sort((a, b) => rank(a) - rank(b) || 0);
+
+    return JSON.stringify({
+        elements: candidates.map((el, index) => ({
+            index,
+            selector_path: selectorPath(el),
+            tag: el.tagName.toLowerCase(),
+            text: label(el),
+            tabindex: el.tabIndex
+        }))
+    });
+})()
@@ -0,0 +1,53 @@
+return (() => {
+    const selector = {{selector}};
+    const table = Array.from(document.querySelectorAll(selector))
+        .find(el => el instanceof HTMLTableElement);
+    if (!table) throw new Error("No <table> matches " + selector);
+
+    const text = cell => (cell.innerText || cell.textContent || "").trim().replace(/\s+/g, " ");
+
+    // Lay rows out on a grid, repeating merged cells in every slot they
+    // cover, as the browser does when it draws them
+    function grid(rows, merged) {
+        const out = rows.map(() => []);
+        rows.forEach((row, r) => {
+            let column = 0;
+            for (const cell of row.cells) {
+                while (out[r][column] !== undefined) column++;
+                const colspan = Math.max(cell.colSpan, 1);
+                // rowspan="0" spans to the end of the section
+                const rowspan = cell.rowSpan === 0 ? rows.length - r : Math.max(cell.rowSpan, 1);
+                if (merged && (colspan > 1 || rowspan > 1)) {
+                    merged.push({ row: r, column, rowspan: Math.min(rowspan, rows.length - r), colspan });
+                }
+                for (let dr = 0; dr < rowspan && r + dr < rows.length; dr++) {
+                    for (let dc = 0; dc < colspan; dc++) {
+                        out[r + dr][column + dc] = text(cell);
+                    }
+                }
+                column += colspan;
+            }
+        });
+        return out.map(row => Array.from(row, value => value === undefined ? "" : value));
+    }
+
+    // Tables built with DOM calls can have rows outside any <tbody>
+    let bodyRows = Array.from(table.rows)
+        .filter(row => row.parentElement !== table.tHead && row.parentElement !== table.tFoot);
+    let headerRows = table.tHead ? Array.from(table.tHead.rows) : [];
+    if (headerRows.length === 0 && bodyRows.length > 0
+        && Array.from(bodyRows[0].cells).every(cell => cell.tagName === "TH")) {
+        headerRows = [bodyRows[0]];
+        bodyRows = bodyRows.slice(1);
+    }
+
+    const headerGrid = grid(headerRows, null);
+    const merged_cells = [];
+    const rows = grid(bodyRows, merged_cells);
+    return JSON.stringify({
+        headers: headerGrid.length > 0 ? headerGrid[headerGrid.length - 1] : [],
+        rows,
+        row_count: rows.length,
+        merged_cells
+    });
+})()
@@ -0,0 +1,56 @@
+return (() => {
+    const selector = {{selector}};
+    const action = {{action}};
+    const points = {{touches}};
+    const target = document.querySelector(selector);
+
+    if (!target) {
+        return JSON.stringify({ success: false, error: "Element not found: " + selector });
+    }
+
+    // Desktop webviews (e.g. WebKit on macOS) have no Touch or TouchEvent
+    // constructors, so fall back to plain objects on a generic event
+    const native = typeof Touch === "function" && typeof TouchEvent === "function";
+    const touches = points.map(p => {
+        const init = {
+            identifier: p.id,
+            target: target,
+            clientX: p.x,
+            clientY: p.y,
+            pageX: p.x + window.scrollX,
+            pageY: p.y + window.scrollY,
+            screenX: p.x + window.screenX,
+            screenY: p.y + window.screenY
+        };
+        return native ? new Touch(init) : init;
+    });
+
+    // Ended touches are no longer on the screen, only in changedTouches
+    const ended = action === "end" || action === "cancel";
+    const lists = {
+        touches: ended ? [] : touches,
+        targetTouches: ended ? [] : touches,
+        changedTouches: touches
+    };
+    const type = "touch" + action;
+    const options = { bubbles: true, cancelable: action !== "cancel", composed: true };
+
+    let event;
+    if (native) {
+        event = new TouchEvent(type, Object.assign(options, lists));
+    } else {
+        event = new Event(type, options);
+        for (const [name, list] of Object.entries(lists)) {
+            Object.defineProperty(event, name, { value: list });
+        }
+    }
+
+    const notCancelled = target.dispatchEvent(event);
+    return JSON.stringify({
+        success: true,
+        type: type,
+        touches: touches.length,
+        defaultPrevented: !notCancelled,
+        synthetic: !native
+    });
+})()
@@ -0,0 +1,37 @@
+return (() => {
+    if (window.__dioxusInspectorErrors__) return "already installed";
+
+    const MAX_ENTRIES = 100;
+    const log = [];
+    window.__dioxusInspectorErrors__ = log;
+
+    function record(entry) {
+        log.push({ ...entry, timestamp_ms: Date.now() });
+        if (log.length > MAX_ENTRIES) log.shift();
+    }
+
+    window.addEventListener("error", event => {
+        // Resource load failures (<img>, <script>) fire here too, without an error
+        if (!(event instanceof ErrorEvent)) return;
+        record({
+            message: event.message || String(event.error),
+            source: event.filename || null,
+            line: event.lineno || null,
+            col: event.colno || null,
+            stack: event.error && event.error.stack ? String(event.error.stack) : null
+        });
+    });
+
+    window.addEventListener("unhandledrejection", event => {
+        const reason = event.reason;
+        record({
+            message: "Unhandled rejection: " + (reason instanceof Error ? reason.message : String(reason)),
+            source: null,
+            line: null,
+            col: null,
+            stack: reason instanceof Error && reason.stack ? String(reason.stack) : null
+        });
+    });
+
+    return "installed";
+})()
@@ -0,0 +1,31 @@
+return (() => {
+    if (window.__dioxusInspectorListeners__) return "already installed";
+
+    // element -> Map<event type, Set<listener>>
+    const registry = new WeakMap();
+    const originalAdd = EventTarget.prototype.addEventListener;
+    const originalRemove = EventTarget.prototype.removeEventListener;
+
+    EventTarget.prototype.addEventListener = function (type, listener, options) {
+        let types = registry.get(this);
+        if (!types) {
+            types = new Map();
+            registry.set(this, types);
+        }
+        if (!types.has(type)) types.set(type, new Set());
+        types.get(type).add(listener);
+        return originalAdd.call(this, type, listener, options);
+    };
+
+    EventTarget.prototype.removeEventListener = function (type, listener, options) {
+        const types = registry.get(this);
+        if (types && types.has(type)) {
+            types.get(type).delete(listener);
+            if (types.get(type).size === 0) types.delete(type);
+        }
+        return originalRemove.call(this, type, listener, options);
+    };
+
+    window.__dioxusInspectorListeners__ = (el) => Array.from((registry.get(el) || new Map()).keys());
+    return "installed";
+})()
@@ -0,0 +1,88 @@
+return (() => {
+    if (window.__dioxusInspectorNetwork__) return "already installed";
+
+    const MAX_ENTRIES = 200;
+    const MAX_PREVIEW = 500;
+    const log = [];
+    window.__dioxusInspectorNetwork__ = log;
+
+    function record(entry) {
+        log.push(entry);
+        if (log.length > MAX_ENTRIES) log.shift();
+    }
+
+    function preview(text) {
+        if (typeof text !== "string") return null;
+        return text.length > MAX_PREVIEW ? text.slice(0, MAX_PREVIEW) + "..." : text;
+    }
+
+    function bodyText(body) {
+        if (body == null) return null;
+        if (typeof body === "string") return preview(body);
+        if (body instanceof URLSearchParams) return preview(body.toString());
+        return "[" + (body.constructor ? body.constructor.name : typeof body) + "]";
+    }
+
+    function newEntry(url, method, body) {
+        return {
+            url: String(url),
+            method: String(method || "GET").toUpperCase(),
+            status: null,
+            duration_ms: 0,
+            request_body: bodyText(body),
+            response_preview: null
+        };
+    }
+
+    const originalFetch = window.fetch;
+    window.fetch = function (input, init) {
+        const started = performance.now();
+        const request = input instanceof Request ? input : null;
+        const entry = newEntry(
+            request ? request.url : input,
+            (init && init.method) || (request && request.method),
+            init && init.body
+        );
+        return originalFetch.apply(this, arguments).then(response => {
+            entry.status = response.status;
+            entry.duration_ms = performance.now() - started;
+            record(entry);
+            response.clone().text()
+                .then(text => { entry.response_preview = preview(text); })
+                .catch(() => {});
+            return response;
+        }, error => {
+            entry.duration_ms = performance.now() - started;
+            entry.response_preview = preview(String(error));
+            record(entry);
+            throw error;
+        });
+    };
+
+    const originalOpen = XMLHttpRequest.prototype.open;
+    const originalSend = XMLHttpRequest.prototype.send;
+
+    XMLHttpRequest.prototype.open = function (method, url) {
+        this.__dioxusInspectorEntry__ = newEntry(url, method, null);
+        return originalOpen.apply(this, arguments);
+    };
+
+    XMLHttpRequest.prototype.send = function (body) {
+        const entry = this.__dioxusInspectorEntry__;
+        if (entry) {
+            const started = performance.now();
+            entry.request_body = bodyText(body);
+            this.addEventListener("loadend", () => {
+                entry.status = this.status || null;
+                entry.duration_ms = performance.now() - started;
+                if (this.responseType === "" || this.responseType === "text") {
+                    entry.response_preview = preview(this.responseText);
+                }
+                record(entry);
+            });
+        }
+        return originalSend.apply(this, arguments);
+    };
+
+    return "installed";
+})()
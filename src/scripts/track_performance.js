@@ -0,0 +1,101 @@
+return (() => {
+    if (window.__dioxusInspectorPerf__) return "already installed";
+
+    const perf = {
+        longTasks: { supported: false, count: 0, total_ms: 0 },
+        // The most recent frame that read or wrote layout
+        lastFrame: { reads: 0, writes: 0, forced: 0 },
+        documentWrites: 0
+    };
+    window.__dioxusInspectorPerf__ = perf;
+
+    if (typeof PerformanceObserver === "function" &&
+        (PerformanceObserver.supportedEntryTypes || []).includes("longtask")) {
+        perf.longTasks.supported = true;
+        new PerformanceObserver(list => {
+            for (const entry of list.getEntries()) {
+                perf.longTasks.count += 1;
+                perf.longTasks.total_ms += entry.duration;
+            }
+        }).observe({ type: "longtask", buffered: true });
+    }
+
+    // Layout thrashing: a layout read after a DOM or style write in the same
+    // frame forces a synchronous layout. Frames are only tracked while
+    // something touches layout, so idle pages cost nothing.
+    let frame = null;
+    let dirty = false;
+    function currentFrame() {
+        if (!frame) {
+            frame = { reads: 0, writes: 0, forced: 0 };
+            requestAnimationFrame(() => {
+                perf.lastFrame = frame;
+                frame = null;
+                dirty = false;
+            });
+        }
+        return frame;
+    }
+    function onRead() {
+        const f = currentFrame();
+        f.reads += 1;
+        if (dirty) {
+            f.forced += 1;
+            dirty = false;
+        }
+    }
+    function onWrite() {
+        currentFrame().writes += 1;
+        dirty = true;
+    }
+
+    function wrapMethod(proto, name, hook) {
+        const original = proto && proto[name];
+        if (typeof original !== "function") return;
+        proto[name] = function (...args) {
+            hook();
+            return original.apply(this, args);
+        };
+    }
+    function wrapGetter(proto, name) {
+        const descriptor = proto && Object.getOwnPropertyDescriptor(proto, name);
+        if (!descriptor || !descriptor.get || !descriptor.configurable) return;
+        Object.defineProperty(proto, name, {
+            ...descriptor,
+            get() {
+                onRead();
+                return descriptor.get.call(this);
+            }
+        });
+    }
+
+    for (const name of ["getBoundingClientRect", "getClientRects"]) {
+        wrapMethod(Element.prototype, name, onRead);
+    }
+    for (const name of ["clientWidth", "clientHeight", "scrollWidth", "scrollHeight", "scrollTop", "scrollLeft"]) {
+        wrapGetter(Element.prototype, name);
+    }
+    for (const name of ["offsetWidth", "offsetHeight", "offsetTop", "offsetLeft"]) {
+        wrapGetter(HTMLElement.prototype, name);
+    }
+    wrapMethod(window, "getComputedStyle", onRead);
+
+    for (const name of ["setAttribute", "removeAttribute", "toggleAttribute"]) {
+        wrapMethod(Element.prototype, name, onWrite);
+    }
+    for (const name of ["appendChild", "insertBefore", "removeChild", "replaceChild"]) {
+        wrapMethod(Node.prototype, name, onWrite);
+    }
+    for (const name of ["add", "remove", "toggle", "replace"]) {
+        wrapMethod(DOMTokenList.prototype, name, onWrite);
+    }
+    for (const name of ["setProperty", "removeProperty"]) {
+        wrapMethod(CSSStyleDeclaration.prototype, name, onWrite);
+    }
+
+    for (const name of ["write", "writeln"]) {
+        wrapMethod(document, name, () => { perf.documentWrites += 1; });
+    }
+
+    return "installed";
+})()
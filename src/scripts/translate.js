@@ -0,0 +1,43 @@
+return (() => {
+    const selector = {{selector}};
+    const replacements = {{replacements}};
+    const root = selector === null ? document.body : document.querySelector(selector);
+    if (!root) return JSON.stringify({ found: false, replaced_count: 0 });
+
+    // Original text of every node changed so far, for /translate/restore
+    const backup = window.__dioxusInspectorTranslation__ ??= [];
+
+    // Longer keys first, so "Save all" wins over "Save"
+    const keys = Object.keys(replacements).sort((a, b) => b.length - a.length);
+    const walker = document.createTreeWalker(root, NodeFilter.SHOW_TEXT, {
+        acceptNode(node) {
+            const parent = node.parentElement;
+            return parent && (parent.closest("script, style, noscript"))
+                ? NodeFilter.FILTER_REJECT
+                : NodeFilter.FILTER_ACCEPT;
+        }
+    });
+
+    let replacedCount = 0;
+    for (let node = walker.nextNode(); node; node = walker.nextNode()) {
+        const before = node.nodeValue;
+        let text = before;
+        for (const key of keys) {
+            const parts = text.split(key);
+            if (parts.length > 1) {
+                replacedCount += parts.length - 1;
+                text = parts.join(replacements[key]);
+            }
+        }
+        if (text === before) continue;
+
+        const entry = backup.find(e => e.node === node);
+        if (entry) {
+            entry.translated = text;
+        } else {
+            backup.push({ node, original: before, translated: text });
+        }
+        node.nodeValue = text;
+    }
+    return JSON.stringify({ found: true, replaced_count: replacedCount });
+})()
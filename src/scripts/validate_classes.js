@@ -1,10 +1,17 @@
 return (() => {
-    const classesToCheck = {CLASSES};
+    // Class names can't contain whitespace, so they're passed space-separated
+    const classesToCheck = {{classes}}.split(" ").filter(Boolean);
     const results = {};
     const availableClasses = new Set();
     const classRules = {};
+    // Class -> id of the /inject-css style that defines it
+    const injectedBy = {};
 
     for (const sheet of document.styleSheets) {
+        const owner = sheet.ownerNode;
+        const injectedId = owner && owner.id && owner.id.startsWith("dioxus-inspector-")
+            ? owner.id.slice("dioxus-inspector-".length)
+            : null;
         try {
             for (const rule of sheet.cssRules || []) {
                 if (rule.selectorText) {
@@ -15,6 +22,9 @@ return (() => {
                         if (!classRules[cls]) {
                             classRules[cls] = rule.cssText.substring(0, 200);
                         }
+                        if (injectedId && !injectedBy[cls]) {
+                            injectedBy[cls] = injectedId;
+                        }
                     }
                 }
             }
@@ -23,7 +33,11 @@ return (() => {
 
     for (const cls of classesToCheck) {
         const found = availableClasses.has(cls);
-        results[cls] = { found: found, rule: found ? classRules[cls] : null };
+        results[cls] = {
+            found: found,
+            rule: found ? classRules[cls] : null,
+            injected: injectedBy[cls] || null
+        };
     }
 
     const missing = classesToCheck.filter(c => !results[c].found);
@@ -35,7 +49,8 @@ return (() => {
             total: classesToCheck.length,
             found: found.length,
             missing: missing.length,
-            missingClasses: missing
+            missingClasses: missing,
+            injectedClasses: classesToCheck.filter(c => injectedBy[c])
         }
     });
 })()
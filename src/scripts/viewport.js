@@ -0,0 +1,16 @@
+return (() => {
+    const size = {{size}};
+    if (size) {
+        window.resizeTo(size.width, size.height);
+    }
+
+    return JSON.stringify({
+        inner_width: window.innerWidth,
+        inner_height: window.innerHeight,
+        outer_width: window.outerWidth,
+        outer_height: window.outerHeight,
+        device_pixel_ratio: window.devicePixelRatio,
+        // Matches the `orientation` media feature, which follows the viewport
+        orientation: window.innerWidth >= window.innerHeight ? "landscape" : "portrait"
+    });
+})()
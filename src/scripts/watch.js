@@ -0,0 +1,20 @@
+return (() => {
+    const el = document.querySelector({{selector}});
+    const condition = {{condition}};
+    const expected = {{value}};
+
+    // Rendered with a box and not visibility: hidden, like Playwright's check
+    const visible = !!el && el.getClientRects().length > 0 &&
+        getComputedStyle(el).visibility !== "hidden";
+    const text = el ? el.textContent.trim() : null;
+
+    let matched;
+    switch (condition) {
+        case "exists": matched = !!el; break;
+        case "visible": matched = visible; break;
+        case "hidden": matched = !visible; break;
+        case "text_equals": matched = text === expected; break;
+        default: matched = false;
+    }
+    return JSON.stringify({ matched, value: text });
+})()
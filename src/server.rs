@@ -0,0 +1,159 @@
+//! Serving the bridge's router on a bound listener.
+
+use std::sync::atomic::AtomicU32;
+use std::sync::Arc;
+
+use axum::Router;
+use tokio::sync::watch;
+use tracing::instrument::WithSubscriber;
+
+use crate::connections;
+use crate::transport::BoundListener;
+use crate::BridgeState;
+#[cfg(feature = "tls")]
+use crate::TlsConfig;
+
+/// Serve `app` on `listener` in the background until `shutdown_rx` changes.
+pub(crate) fn spawn(
+    listener: BoundListener,
+    app: Router,
+    #[cfg(feature = "tls")] tls: Option<TlsConfig>,
+    mut shutdown_rx: watch::Receiver<()>,
+    state: &BridgeState,
+) {
+    let shutdown = async move {
+        let _ = shutdown_rx.changed().await;
+    };
+    let active_connections = state.active_connections.clone();
+    #[cfg(feature = "tls")]
+    let server = serve(listener, app, tls, shutdown, active_connections);
+    #[cfg(not(feature = "tls"))]
+    let server = serve(listener, app, shutdown, active_connections);
+    tokio::spawn(server.with_subscriber(state.log_dispatch.clone()));
+}
+
+/// Serve `app` on `listener` until `shutdown` resolves, over HTTPS when
+/// `tls` is set.
+async fn serve(
+    listener: BoundListener,
+    app: Router,
+    #[cfg(feature = "tls")] tls: Option<TlsConfig>,
+    shutdown: impl std::future::Future<Output = ()> + Send + 'static,
+    active_connections: Arc<AtomicU32>,
+) {
+    match listener {
+        BoundListener::Tcp(listener) => {
+            #[cfg(feature = "tls")]
+            if let Some(tls) = tls {
+                serve_tls(listener, app, &tls, shutdown, active_connections).await;
+                return;
+            }
+            serve_tcp(listener, app, shutdown, active_connections).await;
+        }
+        #[cfg(unix)]
+        BoundListener::Unix { listener, path } => {
+            #[cfg(feature = "tls")]
+            if tls.is_some() {
+                tracing::warn!("TLS is not supported over Unix sockets, serving plain HTTP");
+            }
+            serve_unix(listener, &path, app, shutdown, active_connections).await;
+        }
+    }
+}
+
+/// Serve `app` over plain HTTP until `shutdown` resolves.
+async fn serve_tcp(
+    listener: std::net::TcpListener,
+    app: Router,
+    shutdown: impl std::future::Future<Output = ()> + Send + 'static,
+    active_connections: Arc<AtomicU32>,
+) {
+    let addr = listener
+        .local_addr()
+        .map_or_else(|_| "?".to_string(), |a| a.to_string());
+    let listener = match tokio::net::TcpListener::from_std(listener) {
+        Ok(l) => l,
+        Err(e) => {
+            tracing::error!("Failed to listen on {}: {}", addr, e);
+            return;
+        }
+    };
+    let listener = connections::CountingListener::new(listener, active_connections);
+    tracing::info!("Inspector bridge listening on http://{}", addr);
+    let _ = axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown)
+        .await;
+    tracing::info!("Inspector bridge on http://{} stopped", addr);
+}
+
+/// Serve `app` over a Unix socket until `shutdown` resolves, then remove the
+/// socket file.
+#[cfg(unix)]
+async fn serve_unix(
+    listener: std::os::unix::net::UnixListener,
+    path: &std::path::Path,
+    app: Router,
+    shutdown: impl std::future::Future<Output = ()> + Send + 'static,
+    active_connections: Arc<AtomicU32>,
+) {
+    let listener = match tokio::net::UnixListener::from_std(listener) {
+        Ok(l) => l,
+        Err(e) => {
+            tracing::error!("Failed to listen on {}: {}", path.display(), e);
+            return;
+        }
+    };
+    let listener = connections::CountingListener::new(listener, active_connections);
+    tracing::info!("Inspector bridge listening on unix://{}", path.display());
+    let _ = axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown)
+        .await;
+    let _ = std::fs::remove_file(path);
+    tracing::info!("Inspector bridge on unix://{} stopped", path.display());
+}
+
+/// Serve `app` over HTTPS with rustls until `shutdown` resolves.
+#[cfg(feature = "tls")]
+async fn serve_tls(
+    listener: std::net::TcpListener,
+    app: Router,
+    tls: &TlsConfig,
+    shutdown: impl std::future::Future<Output = ()> + Send + 'static,
+    active_connections: Arc<AtomicU32>,
+) {
+    let addr = listener
+        .local_addr()
+        .map_or_else(|_| "?".to_string(), |a| a.to_string());
+    let rustls =
+        match axum_server::tls_rustls::RustlsConfig::from_pem_file(&tls.cert_pem, &tls.key_pem)
+            .await
+        {
+            Ok(rustls) => rustls,
+            Err(e) => {
+                tracing::error!(
+                    "Failed to load TLS certificate {} / key {}: {}",
+                    tls.cert_pem.display(),
+                    tls.key_pem.display(),
+                    e
+                );
+                return;
+            }
+        };
+
+    let handle = axum_server::Handle::new();
+    let shutdown_handle = handle.clone();
+    tokio::spawn(async move {
+        shutdown.await;
+        shutdown_handle.graceful_shutdown(None);
+    });
+
+    tracing::info!("Inspector bridge listening on https://{}", addr);
+    let acceptor = axum_server::tls_rustls::RustlsAcceptor::new(rustls)
+        .acceptor(connections::CountingAcceptor::new(active_connections));
+    let _ = axum_server::from_tcp(listener)
+        .acceptor(acceptor)
+        .handle(handle)
+        .serve(app.into_make_service())
+        .await;
+    tracing::info!("Inspector bridge on https://{} stopped", addr);
+}
@@ -0,0 +1,151 @@
+//! Shared state for the bridge's HTTP handlers.
+
+use std::collections::{HashMap, VecDeque};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, AtomicU64, AtomicUsize};
+use std::sync::{Arc, Mutex, RwLock};
+
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+use crate::log_filter;
+use crate::{
+    BackpressureStrategy, BridgeConfig, CustomCheck, DomSnapshot, EvalCommand, EvalHistory,
+    EvalResponse, EvalTrace, RateLimiter,
+};
+
+/// Shared state for the HTTP bridge.
+///
+/// This struct holds the internal state used by the Axum handlers.
+/// You don't need to interact with this directly.
+pub struct BridgeState {
+    /// The application name, used in status responses.
+    pub app_name: String,
+    /// Channel to send eval commands to the Dioxus app.
+    pub eval_tx: mpsc::Sender<EvalCommand>,
+    /// When the bridge was started, for uptime calculation.
+    pub started_at: std::time::Instant,
+    /// Process ID of the running application.
+    pub pid: u32,
+    /// Number of eval commands sent to the app.
+    pub eval_total: Arc<AtomicU64>,
+    /// Number of evals that failed, or were dropped without a response.
+    pub eval_errors_total: Arc<AtomicU64>,
+    /// Number of HTTP connections currently open.
+    pub active_connections: Arc<AtomicU32>,
+    /// Number of HTTP requests received, including rejected ones.
+    pub total_requests: Arc<AtomicU64>,
+    /// Directory for saved sessions; `None` uses `~/.config/dioxus-inspector/sessions/`.
+    pub session_dir: Option<PathBuf>,
+    /// Directory for `/snapshot` archives; `None` uses `{temp_dir}/dioxus-inspector-snapshots/`.
+    pub snapshot_dir: Option<PathBuf>,
+    /// What to do with evals when the eval channel is full.
+    pub backpressure: BackpressureStrategy,
+    /// Evals waiting for room in the eval channel, with [`BackpressureStrategy::Queue`].
+    pub eval_overflow: tokio::sync::Mutex<VecDeque<EvalCommand>>,
+    /// Length of [`eval_overflow`](Self::eval_overflow), readable without
+    /// waiting for its lock.
+    pub eval_overflow_depth: Arc<AtomicUsize>,
+    /// Number of evals rejected because the app fell behind.
+    pub eval_dropped_total: Arc<AtomicU64>,
+    /// DOM trees stored by `/dom/snapshot`, compared by `/dom/diff`.
+    pub dom_snapshots: Mutex<HashMap<Uuid, DomSnapshot>>,
+    /// Bearer token every request must carry, if any.
+    pub auth_token: Option<String>,
+    /// Limits requests per second, if set.
+    pub rate_limiter: Option<RateLimiter>,
+    /// Recent evals, served by `/history` and saved with sessions.
+    pub history: EvalHistory,
+    /// Timing and outcome of recent evals, served by `/trace`.
+    pub trace: EvalTrace,
+    /// Response of the most recent `/diagnose` call.
+    pub last_diagnose: Mutex<Option<EvalResponse>>,
+    /// Health checks registered through `/diagnose/register`.
+    pub custom_checks: RwLock<Vec<CustomCheck>>,
+    /// Results of recent read-only evals by script hash, reused for 500ms so
+    /// identical back-to-back requests only run once.
+    pub recent_results: Mutex<HashMap<u64, (std::time::Instant, EvalResponse)>>,
+    /// Scripts run in order before the first eval.
+    pub startup_scripts: Vec<String>,
+    /// Set once [`startup_scripts`](Self::startup_scripts) have finished;
+    /// evals sent meanwhile wait on it.
+    pub startup_ran: tokio::sync::OnceCell<()>,
+    /// Script templates stored through `PUT /eval/named/{name}`.
+    pub named_scripts: RwLock<HashMap<String, String>>,
+    /// Dispatcher the bridge logs through, filtered to
+    /// [`BridgeConfig::log_level`].
+    pub log_dispatch: tracing::Dispatch,
+    /// Whether each eval's script is logged at TRACE level.
+    pub log_eval_scripts: bool,
+    /// Milliseconds to wait for an eval without its own timeout, set through
+    /// `PUT /config/eval-timeout`; `0` waits indefinitely.
+    pub default_eval_timeout_ms: AtomicU64,
+}
+
+impl BridgeState {
+    /// Create state for a bridge in this process, with empty counters and history.
+    pub fn new(app_name: String, eval_tx: mpsc::Sender<EvalCommand>) -> Self {
+        Self {
+            app_name,
+            eval_tx,
+            started_at: std::time::Instant::now(),
+            pid: std::process::id(),
+            eval_total: Arc::new(AtomicU64::new(0)),
+            eval_errors_total: Arc::new(AtomicU64::new(0)),
+            active_connections: Arc::new(AtomicU32::new(0)),
+            total_requests: Arc::new(AtomicU64::new(0)),
+            session_dir: None,
+            snapshot_dir: None,
+            backpressure: BackpressureStrategy::Block,
+            eval_overflow: tokio::sync::Mutex::new(VecDeque::new()),
+            eval_overflow_depth: Arc::new(AtomicUsize::new(0)),
+            eval_dropped_total: Arc::new(AtomicU64::new(0)),
+            history: EvalHistory::default(),
+            trace: EvalTrace::default(),
+            dom_snapshots: Mutex::new(HashMap::new()),
+            auth_token: None,
+            rate_limiter: None,
+            last_diagnose: Mutex::new(None),
+            custom_checks: RwLock::new(Vec::new()),
+            recent_results: Mutex::new(HashMap::new()),
+            startup_scripts: Vec::new(),
+            startup_ran: tokio::sync::OnceCell::new(),
+            named_scripts: RwLock::new(HashMap::new()),
+            log_dispatch: log_filter::filtered(tracing::Level::TRACE),
+            log_eval_scripts: false,
+            default_eval_timeout_ms: AtomicU64::new(0),
+        }
+    }
+
+    /// Create state for a bridge started with `config`.
+    pub(crate) fn from_config(config: &BridgeConfig, eval_tx: mpsc::Sender<EvalCommand>) -> Self {
+        Self {
+            session_dir: config.session_dir.clone(),
+            snapshot_dir: config.snapshot_dir.clone(),
+            startup_scripts: config.startup_scripts.clone(),
+            history: EvalHistory::new(config.max_history),
+            backpressure: config.backpressure,
+            auth_token: config.auth_token.clone(),
+            rate_limiter: config.rate_limit.map(RateLimiter::new),
+            log_dispatch: log_filter::filtered(config.log_level),
+            log_eval_scripts: config.log_eval_scripts,
+            ..Self::new(config.app_name.clone(), eval_tx)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bridge_state_creation() {
+        let (tx, _rx) = mpsc::channel(1);
+        let state = BridgeState {
+            pid: 12345,
+            ..BridgeState::new("test".to_string(), tx)
+        };
+        assert_eq!(state.app_name, "test");
+        assert_eq!(state.pid, 12345);
+    }
+}
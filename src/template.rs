@@ -0,0 +1,213 @@
+//! JavaScript templates with JSON-escaped parameters.
+//!
+//! Scripts mark parameters as `{{name}}`. Each one is replaced with the value
+//! encoded as a JSON string literal, or as any JSON value with
+//! [`ScriptTemplate::render_json`], so it can't break out of the expression
+//! it sits in, whatever the value contains.
+//!
+//! Helpers shared between scripts live in [`PARTIALS`]; `{{> name}}` pulls
+//! one in as code, e.g. `{{> selector_path}}` inside a script's function.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+/// Shared helper functions, by name, for `{{> name}}`.
+const PARTIALS: &[(&str, &str)] = &[("selector_path", include_str!("scripts/selector_path.js"))];
+
+/// A JavaScript source with `{{param}}` placeholders.
+#[derive(Debug, Clone, Copy)]
+pub struct ScriptTemplate<'a> {
+    source: &'a str,
+}
+
+impl<'a> ScriptTemplate<'a> {
+    pub const fn new(source: &'a str) -> Self {
+        Self { source }
+    }
+
+    /// Substitute each `{{param}}` with its value as a JSON string literal.
+    ///
+    /// The source is scanned once, so placeholders inside values are never
+    /// expanded. Placeholders without a matching parameter are left as-is;
+    /// `{{> name}}` is replaced with the partial's code.
+    pub fn render(&self, params: HashMap<&str, &str>) -> String {
+        self.substitute(|name| params.get(name).map(|value| json_string(value)))
+    }
+
+    /// Substitute each `{{param}}` with its value as JSON, for numbers,
+    /// booleans, arrays and objects. Otherwise the same as [`render`](Self::render).
+    pub fn render_json(&self, params: HashMap<&str, Value>) -> String {
+        self.substitute(|name| params.get(name).map(Value::to_string))
+    }
+
+    fn substitute(&self, value_of: impl Fn(&str) -> Option<String>) -> String {
+        let mut out = String::with_capacity(self.source.len());
+        let mut rest = self.source;
+
+        while let Some(start) = rest.find("{{") {
+            out.push_str(&rest[..start]);
+            let after = &rest[start + 2..];
+            let value = after.find("}}").and_then(|end| {
+                let name = &after[..end];
+                let value = match name.strip_prefix("> ") {
+                    Some(partial) => partial_source(partial).map(String::from),
+                    None => value_of(name),
+                };
+                value.map(|value| (end, value))
+            });
+
+            match value {
+                Some((end, value)) => {
+                    out.push_str(&value);
+                    rest = &after[end + 2..];
+                }
+                // Step past one brace only, so `{{{name}}` still matches
+                None => {
+                    out.push('{');
+                    rest = &rest[start + 1..];
+                }
+            }
+        }
+
+        out.push_str(rest);
+        out
+    }
+}
+
+fn partial_source(name: &str) -> Option<&'static str> {
+    PARTIALS
+        .iter()
+        .find(|(partial, _)| *partial == name)
+        .map(|(_, source)| source.trim_end())
+}
+
+fn json_string(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| "\"\"".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Characters that are awkward inside JS string literals and templates.
+    const ALPHABET: &[char] = &[
+        'a', 'Z', '0', ' ', '"', '\'', '`', '\\', '/', '{', '}', '$', '<', '>', '\n', '\r', '\t',
+        '\0', '\u{7f}', '\u{2028}', '\u{2029}', 'é', '😀',
+    ];
+
+    /// Small xorshift generator so fuzz runs are reproducible without extra deps.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn string(&mut self) -> String {
+            let len = (self.next() % 24) as usize;
+            let mut s: String = (0..len)
+                .map(|_| ALPHABET[(self.next() % ALPHABET.len() as u64) as usize])
+                .collect();
+            if self.next() % 4 == 0 {
+                s.push_str("{{b}}");
+            }
+            s
+        }
+    }
+
+    #[test]
+    fn test_render_escapes_values() {
+        let template = ScriptTemplate::new("const s = {{selector}};");
+        let script = template.render(HashMap::from([("selector", "div[data-x=\"y\"]")]));
+        assert_eq!(script, r#"const s = "div[data-x=\"y\"]";"#);
+    }
+
+    #[test]
+    fn test_render_repeated_placeholder() {
+        let template = ScriptTemplate::new("f({{a}}, {{a}})");
+        assert_eq!(
+            template.render(HashMap::from([("a", "x")])),
+            r#"f("x", "x")"#
+        );
+    }
+
+    #[test]
+    fn test_render_leaves_unknown_placeholders() {
+        let template = ScriptTemplate::new("const o = {{missing}}; const p = {{};");
+        assert_eq!(
+            template.render(HashMap::new()),
+            "const o = {{missing}}; const p = {{};"
+        );
+    }
+
+    #[test]
+    fn test_render_does_not_expand_values() {
+        let template = ScriptTemplate::new("[{{a}}, {{b}}]");
+        let script = template.render(HashMap::from([("a", "{{b}}"), ("b", "2")]));
+        assert_eq!(script, r#"["{{b}}", "2"]"#);
+    }
+
+    #[test]
+    fn test_render_includes_partials() {
+        let template = ScriptTemplate::new("(() => {\n{{> selector_path}}\n{{> missing}}\n})");
+        let script = template.render(HashMap::from([("> selector_path", "x")]));
+        assert!(script.starts_with("(() => {\n    // Path from the nearest ancestor"));
+        assert!(script.contains("\n    function selectorPath(el) {"));
+        assert!(script.ends_with("    }\n{{> missing}}\n})"));
+    }
+
+    #[test]
+    fn test_render_json_values() {
+        let template = ScriptTemplate::new("f({{n}}, {{flag}}, {{list}}, {{s}}, {{none}})");
+        let script = template.render_json(HashMap::from([
+            ("n", Value::from(5)),
+            ("flag", Value::Bool(true)),
+            ("list", serde_json::json!(["a\"b", "{{n}}"])),
+            ("s", Value::from("x")),
+            ("none", Value::Null),
+        ]));
+        assert_eq!(script, r#"f(5, true, ["a\"b","{{n}}"], "x", null)"#);
+    }
+
+    #[test]
+    fn test_render_fuzz_round_trips() {
+        let template = ScriptTemplate::new("[{{a}}, {{b}}]");
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+
+        for _ in 0..2000 {
+            let a = rng.string();
+            let b = rng.string();
+            let script = template.render(HashMap::from([("a", a.as_str()), ("b", b.as_str())]));
+
+            // The output must parse back to exactly the values that went in
+            let parsed: Vec<String> = serde_json::from_str(&script)
+                .unwrap_or_else(|e| panic!("{:?} / {:?} rendered {:?}: {}", a, b, script, e));
+            assert_eq!(parsed, [a, b]);
+        }
+    }
+
+    #[test]
+    fn test_render_fuzz_templates() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+        for _ in 0..2000 {
+            // Random source text around the placeholder must pass through untouched
+            // (the alphabet has no `v`, so `{{v}}` can only be the placeholder)
+            let prefix = rng.string();
+            let suffix = rng.string();
+            let value = rng.string();
+            let source = format!("{}{{{{v}}}}{}", prefix, suffix);
+
+            let script =
+                ScriptTemplate::new(&source).render(HashMap::from([("v", value.as_str())]));
+            assert_eq!(
+                script,
+                format!("{}{}{}", prefix, json_string(&value), suffix)
+            );
+        }
+    }
+}
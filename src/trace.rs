@@ -0,0 +1,134 @@
+//! Ring buffer of timing and outcome for every eval.
+//!
+//! Unlike [`EvalHistory`](crate::EvalHistory), which keeps whole scripts and
+//! responses for replay, the trace keeps a small fixed-size record per eval,
+//! so it can hold many more of them.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+use std::hash::{Hash, Hasher};
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::history::now_ms;
+use crate::types::EvalResponse;
+
+/// Number of trace entries kept.
+pub const MAX_TRACE_ENTRIES: usize = 500;
+
+/// Characters of the result or error kept in [`TraceEntry::result_preview`].
+const PREVIEW_CHARS: usize = 200;
+
+/// Outcome of one eval.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceEntry {
+    /// When the eval finished, in milliseconds since the Unix epoch.
+    pub timestamp_ms: u64,
+    /// Hash of the script, to spot the same script failing repeatedly.
+    pub script_hash: u64,
+    /// Whether the app reported success.
+    pub success: bool,
+    /// Time from sending the script to receiving the response.
+    pub duration_ms: f64,
+    /// Start of the result, or of the error when the eval failed.
+    pub result_preview: String,
+}
+
+/// The last [`MAX_TRACE_ENTRIES`] evals, oldest first.
+#[derive(Default)]
+pub struct EvalTrace {
+    entries: Mutex<VecDeque<TraceEntry>>,
+}
+
+impl EvalTrace {
+    /// Record an eval, evicting the oldest entry when full.
+    pub fn push(&self, script: &str, response: &EvalResponse, duration: Duration) {
+        let text = if response.success {
+            response.result.as_deref()
+        } else {
+            response.error.as_deref()
+        };
+        let entry = TraceEntry {
+            timestamp_ms: now_ms(),
+            script_hash: hash_script(script),
+            success: response.success,
+            duration_ms: duration.as_micros() as f64 / 1000.0,
+            result_preview: text
+                .unwrap_or_default()
+                .chars()
+                .take(PREVIEW_CHARS)
+                .collect(),
+        };
+
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        if entries.len() == MAX_TRACE_ENTRIES {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+    }
+
+    /// A copy of the recorded entries, oldest first.
+    pub fn entries(&self) -> Vec<TraceEntry> {
+        let entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries.iter().cloned().collect()
+    }
+
+    /// Remove all entries, returning how many there were.
+    pub fn clear(&self) -> usize {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        let cleared = entries.len();
+        entries.clear();
+        cleared
+    }
+}
+
+fn hash_script(script: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    script.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trace_keeps_last_entries() {
+        let trace = EvalTrace::default();
+        for i in 0..MAX_TRACE_ENTRIES + 2 {
+            trace.push(
+                "return 1",
+                &EvalResponse::success(i.to_string()),
+                Duration::ZERO,
+            );
+        }
+        let entries = trace.entries();
+        assert_eq!(entries.len(), MAX_TRACE_ENTRIES);
+        assert_eq!(entries[0].result_preview, "2");
+    }
+
+    #[test]
+    fn test_trace_entry_fields() {
+        let trace = EvalTrace::default();
+        trace.push(
+            "return 1",
+            &EvalResponse::success("x".repeat(500)),
+            Duration::from_micros(1500),
+        );
+        trace.push("throw 1", &EvalResponse::error("boom"), Duration::ZERO);
+
+        let entries = trace.entries();
+        assert!(entries[0].success);
+        assert_eq!(entries[0].result_preview.len(), PREVIEW_CHARS);
+        assert_eq!(entries[0].duration_ms, 1.5);
+        assert!(!entries[1].success);
+        assert_eq!(entries[1].result_preview, "boom");
+        assert_ne!(entries[0].script_hash, entries[1].script_hash);
+        assert_eq!(entries[0].script_hash, hash_script("return 1"));
+
+        assert_eq!(trace.clear(), 2);
+        assert!(trace.entries().is_empty());
+    }
+}
@@ -0,0 +1,199 @@
+//! Binding the socket the bridge listens on.
+//!
+//! Sockets are bound synchronously, before
+//! [`start_bridge_with_config`](crate::start_bridge_with_config) returns, so
+//! the handle knows the port and the lock file can be written right away.
+
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+#[cfg(unix)]
+use std::path::{Path, PathBuf};
+
+use crate::config::Transport;
+
+/// A socket bound for the bridge, not yet serving.
+pub(crate) enum BoundListener {
+    Tcp(std::net::TcpListener),
+    #[cfg(unix)]
+    Unix {
+        listener: std::os::unix::net::UnixListener,
+        path: PathBuf,
+    },
+}
+
+impl BoundListener {
+    /// Bind `transport`, using `addr` for TCP.
+    pub(crate) fn bind(transport: &Transport, addr: IpAddr) -> io::Result<Self> {
+        match transport {
+            Transport::Tcp { port } => {
+                let listener = std::net::TcpListener::bind(SocketAddr::new(addr, *port))?;
+                listener.set_nonblocking(true)?;
+                Ok(Self::Tcp(listener))
+            }
+            #[cfg(unix)]
+            Transport::Unix { path } => Ok(Self::Unix {
+                listener: bind_unix(path)?,
+                path: path.clone(),
+            }),
+        }
+    }
+
+    /// The TCP port actually bound, which differs from the requested one for port 0.
+    pub(crate) fn port(&self) -> Option<u16> {
+        match self {
+            Self::Tcp(listener) => listener.local_addr().ok().map(|a| a.port()),
+            #[cfg(unix)]
+            Self::Unix { .. } => None,
+        }
+    }
+
+    /// URL clients use to reach the socket, e.g. `http://127.0.0.1:9999` or
+    /// `unix:///tmp/dioxus-inspector.sock`. `scheme` applies to TCP only.
+    pub(crate) fn url(&self, scheme: &str) -> Option<String> {
+        match self {
+            Self::Tcp(listener) => {
+                let mut addr = listener.local_addr().ok()?;
+                // Clients can't connect to 0.0.0.0, but loopback reaches it
+                if addr.ip().is_unspecified() {
+                    addr.set_ip(IpAddr::V4(Ipv4Addr::LOCALHOST));
+                }
+                Some(format!("{}://{}", scheme, addr))
+            }
+            #[cfg(unix)]
+            Self::Unix { path, .. } => Some(format!("unix://{}", path.display())),
+        }
+    }
+}
+
+/// Describe `transport` for log messages.
+pub(crate) fn describe(transport: &Transport, addr: IpAddr) -> String {
+    match transport {
+        Transport::Tcp { port } => SocketAddr::new(addr, *port).to_string(),
+        #[cfg(unix)]
+        Transport::Unix { path } => path.display().to_string(),
+    }
+}
+
+/// Bind a socket at `path` that only the current user can connect to.
+///
+/// The socket is bound and tightened to `0600` inside a `0700` staging
+/// directory, then linked into place, so it is never reachable with the
+/// umask's permissions. Linking fails instead of replacing an existing file.
+#[cfg(unix)]
+fn bind_unix(path: &Path) -> io::Result<std::os::unix::net::UnixListener> {
+    use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+
+    remove_stale_socket(path)?;
+    let staging = staging_dir(path);
+    std::fs::DirBuilder::new().mode(0o700).create(&staging)?;
+    let bind = || -> io::Result<_> {
+        let staged = staging.join("socket");
+        let listener = std::os::unix::net::UnixListener::bind(&staged)?;
+        std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(0o600))?;
+        std::fs::hard_link(&staged, path)?;
+        listener.set_nonblocking(true)?;
+        Ok(listener)
+    };
+    let result = bind();
+    let _ = std::fs::remove_dir_all(&staging);
+    result
+}
+
+/// A hidden directory next to `path` to bind its socket in.
+#[cfg(unix)]
+fn staging_dir(path: &Path) -> PathBuf {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!(".{}.{}.tmp", name, std::process::id()))
+}
+
+/// Remove a socket left behind by a bridge that didn't shut down cleanly.
+///
+/// Sockets something still listens on, and files that aren't sockets, are
+/// left alone so the bind fails instead.
+#[cfg(unix)]
+fn remove_stale_socket(path: &Path) -> io::Result<()> {
+    use std::os::unix::fs::FileTypeExt;
+
+    let is_socket = std::fs::symlink_metadata(path).is_ok_and(|m| m.file_type().is_socket());
+    if !is_socket {
+        return Ok(());
+    }
+    if std::os::unix::net::UnixStream::connect(path).is_ok() {
+        return Err(io::Error::new(
+            io::ErrorKind::AddrInUse,
+            format!("{} is in use by another bridge", path.display()),
+        ));
+    }
+    std::fs::remove_file(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tcp_url_uses_loopback_for_unspecified_addr() {
+        let listener = BoundListener::bind(
+            &Transport::Tcp { port: 0 },
+            IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+        )
+        .unwrap();
+        let port = listener.port().unwrap();
+        assert_ne!(port, 0);
+        assert_eq!(
+            listener.url("http").unwrap(),
+            format!("http://127.0.0.1:{}", port)
+        );
+    }
+
+    #[cfg(unix)]
+    fn temp_socket_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "dioxus-inspector-{}-{}.sock",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_bind_unix_socket() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = temp_socket_path("bind");
+        let transport = Transport::Unix { path: path.clone() };
+        let listener = BoundListener::bind(&transport, IpAddr::V4(Ipv4Addr::LOCALHOST)).unwrap();
+
+        assert_eq!(listener.port(), None);
+        assert_eq!(
+            listener.url("https").unwrap(),
+            format!("unix://{}", path.display())
+        );
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert!(!staging_dir(&path).exists());
+
+        // A second bridge can't take over a socket that's still listening
+        let err = BoundListener::bind(&transport, IpAddr::V4(Ipv4Addr::LOCALHOST)).err();
+        assert_eq!(err.map(|e| e.kind()), Some(io::ErrorKind::AddrInUse));
+
+        // Once the first one is gone, its socket file is stale and replaced
+        drop(listener);
+        assert!(BoundListener::bind(&transport, IpAddr::V4(Ipv4Addr::LOCALHOST)).is_ok());
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_bind_unix_keeps_regular_files() {
+        let path = temp_socket_path("file");
+        std::fs::write(&path, "not a socket").unwrap();
+
+        let transport = Transport::Unix { path: path.clone() };
+        assert!(BoundListener::bind(&transport, IpAddr::V4(Ipv4Addr::LOCALHOST)).is_err());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "not a socket");
+        let _ = std::fs::remove_file(path);
+    }
+}
@@ -3,134 +3,143 @@
 //! This module contains all the types used for communication between the HTTP bridge
 //! and the Dioxus application, as well as the JSON request/response types for the API.
 
-use serde::{Deserialize, Serialize};
-use tokio::sync::oneshot;
-
-/// Command sent from HTTP server to Dioxus app for JavaScript evaluation.
-///
-/// When the bridge receives an eval request, it creates an `EvalCommand` and sends it
-/// through the channel. The Dioxus app should poll this channel and execute the script.
-///
-/// # Example
-///
-/// ```rust,ignore
-/// while let Some(cmd) = eval_rx.recv().await {
-///     let result = document::eval(&cmd.script).await;
-///     let response = match result {
-///         Ok(val) => EvalResponse::success(val.to_string()),
-///         Err(e) => EvalResponse::error(e.to_string()),
-///     };
-///     let _ = cmd.response_tx.send(response);
-/// }
-/// ```
-pub struct EvalCommand {
-    /// The JavaScript code to execute in the webview.
-    pub script: String,
-    /// Channel to send the evaluation result back to the HTTP handler.
-    pub response_tx: oneshot::Sender<EvalResponse>,
-}
-
-/// Request to evaluate JavaScript in the webview.
-///
-/// # JSON Format
-///
-/// ```json
-/// { "script": "return document.title" }
-/// ```
-#[derive(Debug, Deserialize)]
-pub struct EvalRequest {
-    /// The JavaScript code to execute. Should return a value.
-    pub script: String,
-}
-
-/// Response from JavaScript evaluation.
-///
-/// # JSON Format
-///
-/// Success:
-/// ```json
-/// { "success": true, "result": "Page Title" }
-/// ```
-///
-/// Error:
-/// ```json
-/// { "success": false, "error": "ReferenceError: x is not defined" }
-/// ```
-#[derive(Debug, Serialize, Clone)]
-pub struct EvalResponse {
-    /// Whether the evaluation succeeded.
-    pub success: bool,
-    /// The result of the evaluation, if successful.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<String>,
-    /// The error message, if evaluation failed.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<String>,
-}
-
-impl EvalResponse {
-    /// Create a successful response with the given result.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use dioxus_inspector::EvalResponse;
-    ///
-    /// let resp = EvalResponse::success("42");
-    /// assert!(resp.success);
-    /// assert_eq!(resp.result, Some("42".to_string()));
-    /// ```
-    pub fn success(result: impl Into<String>) -> Self {
-        Self {
-            success: true,
-            result: Some(result.into()),
-            error: None,
-        }
-    }
-
-    /// Create an error response with the given message.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use dioxus_inspector::EvalResponse;
-    ///
-    /// let resp = EvalResponse::error("Script timeout");
-    /// assert!(!resp.success);
-    /// assert_eq!(resp.error, Some("Script timeout".to_string()));
-    /// ```
-    pub fn error(message: impl Into<String>) -> Self {
-        Self {
-            success: false,
-            result: None,
-            error: Some(message.into()),
-        }
-    }
-}
-
-/// Query request for CSS selector.
-///
-/// # JSON Format
-///
-/// ```json
-/// { "selector": ".my-button", "property": "text" }
-/// ```
-///
-/// # Supported Properties
-///
-/// - `text` (default) - Element's `textContent`
-/// - `html` - Element's `innerHTML`
-/// - `outerHTML` - Element's `outerHTML`
-/// - `value` - Element's `value` (for inputs)
-/// - Any other string - Treated as an attribute name
-#[derive(Debug, Deserialize)]
-pub struct QueryRequest {
-    /// CSS selector to find the element.
-    pub selector: String,
-    /// Property to extract. Defaults to "text" if not specified.
-    #[serde(default)]
-    pub property: Option<String>,
-}
+use serde::Serialize;
+
+mod accessibility;
+mod ancestors;
+mod animation;
+mod bridge_config;
+mod canvas;
+mod clipboard;
+mod colors;
+mod coverage;
+mod diagnostics;
+mod dom;
+mod elements;
+mod eval;
+mod event_log;
+mod fonts;
+mod geolocation;
+mod history;
+mod images;
+mod inspect;
+mod interaction;
+mod layout;
+mod list;
+mod measure;
+mod media;
+mod monitoring;
+mod mutation;
+mod navigation;
+mod network;
+mod query;
+mod search;
+mod session;
+mod signals;
+mod slots;
+mod snapshot;
+mod storage;
+mod styles;
+mod svg;
+mod table;
+mod translate;
+mod tree;
+mod viewport;
+mod watch;
+mod window;
+
+pub use accessibility::{
+    AriaRequest, AriaResponse, ColorContrastResult, ColorPair, ContrastAuditRequest,
+    ContrastAuditResponse, ContrastFailure, ContrastSample, ContrastWarning, RoleElement,
+    RoleQuery, RoleQueryResponse, TabOrderEntry, TabOrderResponse, TextSize, ValidateColorsRequest,
+    ValidateColorsResponse, WcagLevel,
+};
+pub use ancestors::{AncestorRequest, AncestorResponse};
+pub use animation::{AnimationControlRequest, AnimationEntry, AnimationsResponse};
+pub use bridge_config::{BridgeConfigResponse, EvalTimeoutRequest};
+pub use canvas::{CanvasRequest, CanvasResponse};
+pub use clipboard::{ClipboardResponse, SetClipboardRequest};
+pub use colors::{ColorEntry, ColorsResponse};
+pub use coverage::{CoverageResponse, CoverageScript, CoverageStartResponse};
+pub use diagnostics::{
+    CheckResult, CustomCheck, CustomCheckRequest, CustomDiagnoseResponse, PerfDiagnoseResponse,
+    PerfIssue, RegisterCheckResponse,
+};
+pub use dom::{DomNode, DomPageResponse};
+pub use elements::{
+    CreateElementRequest, CreateElementResponse, RemoveElementQuery, RemoveElementResponse,
+};
+pub use eval::{
+    AsyncEvalRequest, ConditionalEvalRequest, EvalCommand, EvalRequest, EvalResponse, EvalResult,
+    ModuleEvalRequest, NamedEvalRequest, NamedScript, NamedScriptRequest, NamedScriptsResponse,
+    ParallelEvalRequest, ParallelEvalResponse, SandboxEvalRequest, SandboxEvalResponse,
+    StreamEvalRequest, DEFAULT_SANDBOX_MAX_OUTPUT_BYTES, DEFAULT_SANDBOX_TIMEOUT_MS,
+};
+pub use event_log::{
+    DomEvent, EventLogQuery, EventLogResponse, EventWatchRequest, EventWatchResponse,
+    DEFAULT_EVENT_LOG_MAX_EVENTS,
+};
+pub use fonts::MissingFontsResponse;
+pub use geolocation::{GeoMockRequest, GeoMockResponse};
+pub use history::{ClearHistoryResponse, HistoryQuery, HistoryResponse, TraceResponse};
+pub use images::{ImageStatus, ImageStatusResponse};
+pub use inspect::{InspectRequest, ValidateClassesRequest};
+pub use interaction::{
+    DragRequest, FormFillRequest, FormFillResponse, InputState, InputStateQuery,
+    InputStateResponse, ScrollIntoViewRequest, SelectRequest, SelectResponse, TouchPoint,
+    TouchRequest,
+};
+pub use layout::{
+    BoundingRect, BoxEdges, BoxModel, ComputedLayoutRequest, ComputedLayoutResponse,
+    FlexProperties, GridProperties, IntersectionEntry, IntersectionQuery, IntersectionResponse,
+    LayoutChildInfo, LayoutInspectRequest, LayoutInspectResponse, ParentChainRequest,
+    ParentChainResponse, ParentNode, ScrollContainer, ScrollContainersResponse, StackingContext,
+    StackingInspectRequest, StackingResponse, DEFAULT_PARENT_DEPTH,
+};
+pub use list::{ListItem, ListRequest, ListResponse};
+pub use measure::{MeasureRequest, MeasureResponse};
+pub use media::{
+    MediaControlRequest, MediaElementState, MediaQuery, MediaResponse, MediaTimeRange,
+};
+pub use monitoring::{JsError, JsErrorsQuery, JsErrorsResponse, NetworkRequest, NetworkResponse};
+pub use mutation::{
+    MutationEntry, MutationKind, MutationLogStartRequest, MutationLogStatusResponse,
+    MutationSummaryResponse, DEFAULT_MUTATION_LOG_ENTRIES,
+};
+pub use navigation::{HotReloadResponse, ReloadRequest, ReloadResponse};
+pub use network::{NetworkCondition, NetworkSimRequest, NetworkSimResponse};
+pub use query::{
+    ComputedStylesRequest, ComputedStylesResponse, DataAttributesRequest, DataAttributesResponse,
+    QueryAllRequest, QueryAllResponse, QueryRequest,
+};
+pub use search::{DomSearchRequest, DomSearchResponse, SearchMatch};
+pub use session::{LoadSessionQuery, SaveSessionRequest, SaveSessionResponse, Session};
+pub use signals::{SignalChange, SignalWatchQuery, SignalsResponse, DEFAULT_SIGNAL_POLL_MS};
+pub use slots::{SlotInfo, SlotInspectRequest, SlotResponse};
+pub use snapshot::{
+    DomChange, DomChangeKind, DomDiffRequest, DomDiffResponse, DomSnapshotResponse,
+    SnapshotArchiveQuery, SnapshotManifest,
+};
+pub use storage::{
+    IndexedDbRequest, IndexedDbResponse, StorageEvent, StorageWatchQuery, DEFAULT_STORAGE_POLL_MS,
+};
+pub use styles::{InjectCssRequest, InjectCssResponse, RemoveCssRequest};
+pub use svg::{SvgAnimateRequest, SvgAnimateResponse, SvgElement, SvgResponse};
+pub use table::{MergedCell, TableRequest, TableResponse};
+pub use translate::{TranslateRequest, TranslateResponse, TranslateRestoreResponse};
+pub use tree::{ComponentNode, ComponentTreeResponse};
+pub use viewport::{
+    BreakpointResult, BreakpointSimRequest, BreakpointSimResponse, BreakpointStep,
+    DarkModeResponse, MediaQueryAllResponse, MediaQueryParams, MediaQueryResponse,
+    ResponsiveResult, ResponsiveTestRequest, ResponsiveTestResponse, SetDarkModeRequest,
+    SetViewportRequest, ViewportResponse, ViewportSize, WindowStateResponse, ZoomRequest,
+    ZoomResponse, COMMON_BREAKPOINTS, DEFAULT_BREAKPOINT_DELAY_MS,
+};
+pub use watch::{WatchCondition, WatchRequest, WatchResponse, DEFAULT_WATCH_TIMEOUT_MS};
+pub use window::{
+    MoveRequest, MoveResponse, ResizeRequest, ResizeResponse, ScreenshotFormat, ScreenshotRequest,
+    ScreenshotResponse,
+};
 
 /// Status response showing bridge health.
 ///
@@ -147,164 +156,35 @@ pub struct StatusResponse {
     pub uptime_secs: u64,
     /// Human-readable uptime (e.g., "5m 30s", "2h 15m").
     pub uptime_human: String,
+    /// Eval commands sent but not yet picked up by the app.
+    pub eval_queue_depth: usize,
+    /// Size of the eval channel buffer.
+    pub eval_queue_capacity: usize,
+    /// Evals held in the overflow buffer, waiting for room in the channel.
+    pub eval_overflow_depth: usize,
+    /// Evals rejected with "rate limited" because the app fell behind.
+    pub eval_dropped_total: u64,
+    /// HTTP connections currently open.
+    pub active_connections: u32,
+    /// HTTP requests received since startup, including rejected ones.
+    pub total_requests: u64,
+    /// Eval commands sent to the app since startup.
+    pub total_eval_commands: u64,
 }
 
-/// Request for element inspection.
-///
-/// Returns detailed visibility and position information for an element.
-///
-/// # JSON Format
-///
-/// ```json
-/// { "selector": ".modal" }
-/// ```
-#[derive(Debug, Deserialize)]
-pub struct InspectRequest {
-    /// CSS selector to find the element to inspect.
-    pub selector: String,
-}
-
-/// Request to validate CSS classes.
-///
-/// Checks which CSS classes are available in the document's stylesheets.
-///
-/// # JSON Format
-///
-/// ```json
-/// { "classes": ["flex", "p-4", "bg-white"] }
-/// ```
-#[derive(Debug, Deserialize)]
-pub struct ValidateClassesRequest {
-    /// List of CSS class names to validate.
-    pub classes: Vec<String>,
-}
-
-/// Screenshot request.
-///
-/// Captures a screenshot of the application window (macOS only).
-///
-/// # JSON Format
-///
-/// ```json
-/// { "path": "/tmp/screenshot.png" }
-/// ```
-#[derive(Debug, Deserialize, Default)]
-pub struct ScreenshotRequest {
-    /// Output path for the screenshot. Defaults to `/tmp/dioxus-screenshot.png`.
-    #[serde(default)]
-    pub path: Option<String>,
-}
-
-/// Screenshot response.
+/// Response from `GET /ping`, answered without involving the app.
 #[derive(Debug, Serialize)]
-pub struct ScreenshotResponse {
-    /// Whether the screenshot was captured successfully.
-    pub success: bool,
-    /// Path where the screenshot was saved.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub path: Option<String>,
-    /// Error message if capture failed.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<String>,
-}
-
-/// Request to resize the window.
-///
-/// # JSON Format
-///
-/// ```json
-/// { "width": 1024, "height": 768 }
-/// ```
-///
-/// # Note
-///
-/// The application must handle the resize command. The bridge sends a special
-/// script pattern that the app can intercept to apply the resize.
-#[derive(Debug, Deserialize)]
-pub struct ResizeRequest {
-    /// Target width in pixels.
-    pub width: u32,
-    /// Target height in pixels.
-    pub height: u32,
-}
-
-/// Response from resize operation.
-#[derive(Debug, Serialize)]
-pub struct ResizeResponse {
-    /// Whether the resize command was sent successfully.
-    pub success: bool,
-    /// The requested width.
-    pub width: u32,
-    /// The requested height.
-    pub height: u32,
-    /// Error message if the resize failed.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<String>,
+pub struct PingResponse {
+    /// Always `true`.
+    pub pong: bool,
+    /// Server clock, in microseconds since the Unix epoch.
+    pub server_time_us: u64,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_eval_response_success() {
-        let resp = EvalResponse::success("42");
-        assert!(resp.success);
-        assert_eq!(resp.result, Some("42".to_string()));
-        assert!(resp.error.is_none());
-    }
-
-    #[test]
-    fn test_eval_response_error() {
-        let resp = EvalResponse::error("failed");
-        assert!(!resp.success);
-        assert!(resp.result.is_none());
-        assert_eq!(resp.error, Some("failed".to_string()));
-    }
-
-    #[test]
-    fn test_eval_request_deserialize() {
-        let json = r#"{"script": "return 1 + 1"}"#;
-        let req: EvalRequest = serde_json::from_str(json).unwrap();
-        assert_eq!(req.script, "return 1 + 1");
-    }
-
-    #[test]
-    fn test_query_request_deserialize() {
-        let json = r#"{"selector": ".button", "property": "text"}"#;
-        let req: QueryRequest = serde_json::from_str(json).unwrap();
-        assert_eq!(req.selector, ".button");
-        assert_eq!(req.property, Some("text".to_string()));
-    }
-
-    #[test]
-    fn test_query_request_without_property() {
-        let json = r##"{"selector": "#main"}"##;
-        let req: QueryRequest = serde_json::from_str(json).unwrap();
-        assert_eq!(req.selector, "#main");
-        assert!(req.property.is_none());
-    }
-
-    #[test]
-    fn test_inspect_request_deserialize() {
-        let json = r#"{"selector": ".modal"}"#;
-        let req: InspectRequest = serde_json::from_str(json).unwrap();
-        assert_eq!(req.selector, ".modal");
-    }
-
-    #[test]
-    fn test_validate_classes_request_deserialize() {
-        let json = r#"{"classes": ["flex", "p-4", "bg-white"]}"#;
-        let req: ValidateClassesRequest = serde_json::from_str(json).unwrap();
-        assert_eq!(req.classes, vec!["flex", "p-4", "bg-white"]);
-    }
-
-    #[test]
-    fn test_screenshot_request_default() {
-        let req = ScreenshotRequest::default();
-        assert!(req.path.is_none());
-    }
-
     #[test]
     fn test_status_response_serialize() {
         let resp = StatusResponse {
@@ -313,31 +193,17 @@ mod tests {
             pid: 1234,
             uptime_secs: 60,
             uptime_human: "1m 0s".to_string(),
+            eval_queue_depth: 0,
+            eval_queue_capacity: 32,
+            eval_overflow_depth: 0,
+            eval_dropped_total: 0,
+            active_connections: 1,
+            total_requests: 5,
+            total_eval_commands: 3,
         };
         let json = serde_json::to_string(&resp).unwrap();
         assert!(json.contains("\"status\":\"ok\""));
         assert!(json.contains("\"app\":\"test\""));
-    }
-
-    #[test]
-    fn test_resize_request_deserialize() {
-        let json = r#"{"width": 800, "height": 600}"#;
-        let req: ResizeRequest = serde_json::from_str(json).unwrap();
-        assert_eq!(req.width, 800);
-        assert_eq!(req.height, 600);
-    }
-
-    #[test]
-    fn test_resize_response_serialize() {
-        let resp = ResizeResponse {
-            success: true,
-            width: 1024,
-            height: 768,
-            error: None,
-        };
-        let json = serde_json::to_string(&resp).unwrap();
-        assert!(json.contains("\"width\":1024"));
-        assert!(json.contains("\"height\":768"));
-        assert!(!json.contains("error"));
+        assert!(json.contains("\"active_connections\":1"));
     }
 }
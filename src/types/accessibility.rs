@@ -0,0 +1,288 @@
+//! Request and response types for accessibility checks.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// A foreground/background color combination to check.
+#[derive(Debug, Deserialize)]
+pub struct ColorPair {
+    /// Text color as a CSS color string (e.g. `#333`, `rgb(0 0 0)`, `navy`).
+    pub foreground: String,
+    /// Background color as a CSS color string.
+    pub background: String,
+}
+
+/// Request to check WCAG contrast ratios for color pairs.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "pairs": [{ "foreground": "#767676", "background": "#ffffff" }] }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct ValidateColorsRequest {
+    /// Color combinations to check.
+    pub pairs: Vec<ColorPair>,
+}
+
+/// WCAG contrast result for a single color pair.
+#[derive(Debug, Serialize)]
+pub struct ColorContrastResult {
+    /// The foreground color as provided.
+    pub foreground: String,
+    /// The background color as provided.
+    pub background: String,
+    /// Contrast ratio, from 1.0 to 21.0 (rounded to two decimals).
+    pub ratio: f64,
+    /// Passes AA for normal text (>= 4.5:1).
+    pub aa_normal: bool,
+    /// Passes AA for large text (>= 3:1).
+    pub aa_large: bool,
+    /// Passes AAA for normal text (>= 7:1).
+    pub aaa_normal: bool,
+}
+
+/// Response from color contrast validation.
+#[derive(Debug, Serialize)]
+pub struct ValidateColorsResponse {
+    /// One result per requested pair, in request order.
+    pub results: Vec<ColorContrastResult>,
+}
+
+/// Text colors collected in the page by `GET /contrast-audit`, one sample
+/// per element with visible text of its own.
+#[derive(Debug, Deserialize)]
+pub struct ContrastAuditRequest {
+    /// Samples in document order.
+    pub pairs: Vec<ContrastSample>,
+}
+
+/// The computed colors and font of an element with visible text.
+#[derive(Debug, Deserialize)]
+pub struct ContrastSample {
+    /// CSS selector path to the element, from the nearest ancestor with an id.
+    pub selector_path: String,
+    /// The start of the element's own text.
+    pub text: String,
+    /// Computed `color`.
+    pub foreground: String,
+    /// Computed `background-color` of the element or the nearest ancestor
+    /// that has one, or `rgb(255, 255, 255)` when none does.
+    pub background: String,
+    /// Computed `font-size` in CSS pixels.
+    pub font_size_px: f64,
+    /// Computed `font-weight`, e.g. `400` or `700`.
+    pub font_weight: u32,
+}
+
+/// WCAG conformance level, serialized as `AA` or `AAA`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum WcagLevel {
+    Aa,
+    Aaa,
+}
+
+/// Text size as WCAG defines it, which lowers the required ratio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TextSize {
+    /// Smaller than large text.
+    Normal,
+    /// At least 18pt (24px), or 14pt (18.67px) and bold.
+    Large,
+}
+
+/// Text below the AA contrast ratio for its size.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ContrastFailure {
+    /// CSS selector path to the element, from the nearest ancestor with an id.
+    pub selector_path: String,
+    /// The start of the element's text.
+    pub text: String,
+    /// Computed text color.
+    pub foreground: String,
+    /// Effective background color.
+    pub background: String,
+    /// Contrast ratio, rounded to two decimals.
+    pub ratio: f64,
+    /// Ratio the text needs to meet `level`.
+    pub required_ratio: f64,
+    /// The level the text fails, always `AA`.
+    pub level: WcagLevel,
+    /// Whether the text counts as large.
+    pub text_size: TextSize,
+}
+
+/// Text that meets AA but not AAA.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ContrastWarning {
+    /// CSS selector path to the element, from the nearest ancestor with an id.
+    pub selector_path: String,
+    /// The start of the element's text.
+    pub text: String,
+    /// Computed text color.
+    pub foreground: String,
+    /// Effective background color.
+    pub background: String,
+    /// Contrast ratio, rounded to two decimals.
+    pub ratio: f64,
+    /// Ratio the text needs to meet `level`.
+    pub required_ratio: f64,
+    /// The level the text fails, always `AAA`.
+    pub level: WcagLevel,
+    /// Whether the text counts as large.
+    pub text_size: TextSize,
+}
+
+/// Response from `GET /contrast-audit`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ContrastAuditResponse {
+    /// Text failing AA, lowest ratio first.
+    pub failures: Vec<ContrastFailure>,
+    /// Text passing AA but failing AAA, lowest ratio first.
+    pub warnings: Vec<ContrastWarning>,
+    /// Number of elements whose text meets AAA.
+    pub pass_count: u32,
+}
+
+/// A focusable element, from `GET /tab-order`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TabOrderEntry {
+    /// Position in the tab sequence, starting at 0.
+    pub index: usize,
+    /// CSS selector path to the element, from the nearest ancestor with an id.
+    pub selector_path: String,
+    /// Lowercase tag name.
+    pub tag: String,
+    /// Accessible label: `aria-label`, text content, placeholder, value or title.
+    pub text: String,
+    /// The element's `tabIndex`.
+    pub tabindex: i32,
+}
+
+/// Focusable elements in keyboard tab order.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TabOrderResponse {
+    /// Elements reachable with Tab, first to last.
+    pub elements: Vec<TabOrderEntry>,
+}
+
+/// Request to read, and optionally set, ARIA attributes of an element.
+///
+/// Attribute names may omit the `aria-` prefix: `label` and `aria-label`
+/// both set `aria-label`. `role` is kept as-is.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "selector": "#menu", "attributes": { "expanded": "true", "role": "menu" } }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct AriaRequest {
+    /// CSS selector of the element.
+    pub selector: String,
+    /// Attributes to set. Only reads when omitted.
+    #[serde(default)]
+    pub attributes: Option<HashMap<String, String>>,
+}
+
+/// ARIA attributes of an element after any changes.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AriaResponse {
+    /// `role` and every `aria-*` attribute, by name.
+    pub current_attributes: HashMap<String, String>,
+    /// Number of attributes set.
+    pub applied: u32,
+    /// Why the request failed, e.g. no element matches the selector.
+    pub error: Option<String>,
+}
+
+/// Query parameters for `GET /role`.
+#[derive(Debug, Deserialize)]
+pub struct RoleQuery {
+    /// ARIA role, e.g. `button`. Elements with the role implicitly, such as
+    /// `<button>` or `<a href>`, match too.
+    pub role: String,
+    /// Only keep elements whose accessible name contains this, ignoring case.
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// Elements with an ARIA role, from `GET /role`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleQueryResponse {
+    /// Matching elements in DOM order; empty when none match.
+    pub elements: Vec<RoleElement>,
+}
+
+/// An element found by its ARIA role.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleElement {
+    /// CSS selector path to the element, from the nearest ancestor with an id.
+    pub selector_path: String,
+    /// Name from `aria-labelledby`, `aria-label`, `<label>`, `alt` or text
+    /// content, in that order.
+    pub accessible_name: String,
+    /// Description from `aria-describedby`, `aria-description` or `title`.
+    pub accessible_description: String,
+    /// `aria-*` states without the prefix (`expanded`, `pressed`, ...) plus
+    /// native `disabled`, `checked`, `required` and `selected`.
+    pub states: HashMap<String, String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_colors_request_deserialize() {
+        let json = r##"{"pairs": [{"foreground": "#000", "background": "white"}]}"##;
+        let req: ValidateColorsRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.pairs.len(), 1);
+        assert_eq!(req.pairs[0].foreground, "#000");
+        assert_eq!(req.pairs[0].background, "white");
+    }
+
+    #[test]
+    fn test_contrast_enums_serialize() {
+        assert_eq!(serde_json::to_string(&WcagLevel::Aaa).unwrap(), "\"AAA\"");
+        assert_eq!(
+            serde_json::to_string(&TextSize::Large).unwrap(),
+            "\"large\""
+        );
+    }
+
+    #[test]
+    fn test_aria_request_deserialize() {
+        let req: AriaRequest = serde_json::from_str(r##"{"selector": "#menu"}"##).unwrap();
+        assert!(req.attributes.is_none());
+
+        let json = r##"{"selector": "#menu", "attributes": {"expanded": "true"}}"##;
+        let req: AriaRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.attributes.unwrap()["expanded"], "true");
+    }
+
+    #[test]
+    fn test_role_query_deserialize() {
+        let query: RoleQuery = serde_json::from_str(r#"{"role": "button"}"#).unwrap();
+        assert_eq!(query.role, "button");
+        assert!(query.name.is_none());
+    }
+
+    #[test]
+    fn test_color_contrast_result_serialize() {
+        let result = ColorContrastResult {
+            foreground: "#000".to_string(),
+            background: "#fff".to_string(),
+            ratio: 21.0,
+            aa_normal: true,
+            aa_large: true,
+            aaa_normal: true,
+        };
+        let json = serde_json::to_string(&result).unwrap();
+        assert!(json.contains("\"ratio\":21.0"));
+        assert!(json.contains("\"aaa_normal\":true"));
+    }
+}
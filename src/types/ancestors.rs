@@ -0,0 +1,30 @@
+//! Request and response types for finding the common ancestor of elements.
+
+use serde::{Deserialize, Serialize};
+
+/// Request for the nearest element containing every match of some selectors.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "selectors": [".tooltip", "#save-button"] }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct AncestorRequest {
+    /// CSS selectors; every element each one matches is included.
+    pub selectors: Vec<String>,
+}
+
+/// The lowest common ancestor from `POST /dom/ancestors`.
+///
+/// When one matched element contains all the others, it is the ancestor
+/// itself.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AncestorResponse {
+    /// CSS selector path to the ancestor.
+    pub ancestor_selector: String,
+    /// Lowercase tag name.
+    pub ancestor_tag: String,
+    /// Number of elements above the ancestor; `<html>` is at depth 0.
+    pub depth: u32,
+}
@@ -0,0 +1,64 @@
+//! Request and response types for inspecting running animations.
+
+use serde::{Deserialize, Serialize};
+
+/// An animation returned by `document.getAnimations()`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AnimationEntry {
+    /// The animation's `id`, or the `animation-name`/`transition-property`
+    /// for CSS animations and transitions.
+    pub id: Option<String>,
+    /// Play state: `running`, `paused`, `finished` or `idle`.
+    pub state: String,
+    /// Current time in milliseconds (`0` when unresolved).
+    pub current_time: f64,
+    /// Short description of the animated element, e.g. `div#spinner.loading`.
+    pub effect_target: String,
+    /// Duration of one iteration in milliseconds.
+    pub duration_ms: f64,
+}
+
+/// Animations currently running, paused or finished in the document.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AnimationsResponse {
+    /// All animations returned by `document.getAnimations()`.
+    pub animations: Vec<AnimationEntry>,
+}
+
+/// Request to pause, play or cancel animations.
+///
+/// Without an `id` the action applies to every animation in the document.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "id": "spin", "action": "pause" }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct AnimationControlRequest {
+    /// Animation id to match (see [`AnimationEntry::id`]).
+    #[serde(default)]
+    pub id: Option<String>,
+    /// One of `pause`, `play` or `cancel`.
+    pub action: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_animation_control_request_without_id() {
+        let req: AnimationControlRequest = serde_json::from_str(r#"{"action": "pause"}"#).unwrap();
+        assert!(req.id.is_none());
+        assert_eq!(req.action, "pause");
+    }
+
+    #[test]
+    fn test_animations_response_deserialize() {
+        let json = r#"{"animations": [{"id": null, "state": "running", "current_time": 120.5, "effect_target": "div.spinner", "duration_ms": 1000}]}"#;
+        let resp: AnimationsResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(resp.animations[0].state, "running");
+        assert!(resp.animations[0].id.is_none());
+    }
+}
@@ -0,0 +1,43 @@
+//! Request and response types for reading and changing bridge settings at
+//! runtime.
+
+use serde::{Deserialize, Serialize};
+
+/// Request to change how long the bridge waits for an eval by default.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "timeout_ms": 5000 }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct EvalTimeoutRequest {
+    /// Milliseconds to wait for the app to answer an eval; `0` waits
+    /// indefinitely.
+    pub timeout_ms: u64,
+}
+
+/// The bridge's current settings, from `GET /config`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BridgeConfigResponse {
+    /// The application name, as reported by `/status`.
+    pub app_name: String,
+    /// Milliseconds the bridge waits for an eval without its own timeout, or
+    /// `None` to wait indefinitely.
+    pub default_eval_timeout_ms: Option<u64>,
+    /// How evals are handled once the app falls behind: `block`, `drop` or
+    /// `queue`.
+    pub backpressure: String,
+    /// Overflow buffer size, with `queue` backpressure.
+    pub backpressure_queue_max: Option<usize>,
+    /// Number of evals kept for `/history`.
+    pub max_history: usize,
+    /// Whether requests must carry a bearer token.
+    pub auth_required: bool,
+    /// Maximum requests per second, if limited.
+    pub rate_limit: Option<u32>,
+    /// Number of scripts run before the first eval.
+    pub startup_scripts: usize,
+    /// Whether each eval's script is logged at TRACE level.
+    pub log_eval_scripts: bool,
+}
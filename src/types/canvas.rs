@@ -0,0 +1,49 @@
+//! Request and response types for capturing `<canvas>` content.
+
+use serde::{Deserialize, Serialize};
+
+/// Request to capture a canvas as a data URL.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "selector": "canvas#chart", "format": "image/jpeg", "save_path": "/tmp/chart.jpg" }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct CanvasRequest {
+    /// CSS selector of the canvas element.
+    pub selector: String,
+    /// MIME type passed to `toDataURL`. Defaults to `image/png`; browsers
+    /// fall back to PNG for types they can't encode.
+    #[serde(default)]
+    pub format: Option<String>,
+    /// Also decode the image and write it to this path.
+    #[serde(default)]
+    pub save_path: Option<String>,
+}
+
+/// Captured canvas content.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CanvasResponse {
+    /// `data:` URL with the base64-encoded image, empty on error.
+    pub data_url: String,
+    /// Canvas width in pixels (the bitmap size, not the CSS size).
+    pub width: u32,
+    /// Canvas height in pixels.
+    pub height: u32,
+    /// Why the capture or the save failed, e.g. a missing element or a
+    /// canvas tainted by cross-origin images.
+    pub error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canvas_request_defaults() {
+        let req: CanvasRequest = serde_json::from_str(r#"{"selector": "canvas"}"#).unwrap();
+        assert!(req.format.is_none());
+        assert!(req.save_path.is_none());
+    }
+}
@@ -0,0 +1,22 @@
+//! Request and response types for the system clipboard.
+
+use serde::{Deserialize, Serialize};
+
+/// Request to replace the clipboard contents.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "text": "copied from the inspector" }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct SetClipboardRequest {
+    /// Text to write to the clipboard.
+    pub text: String,
+}
+
+/// Clipboard contents, as read back from the webview.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ClipboardResponse {
+    pub text: String,
+}
@@ -0,0 +1,25 @@
+//! Response types for the colors used in the document.
+
+use serde::{Deserialize, Serialize};
+
+/// Response from `GET /colors`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ColorsResponse {
+    /// One entry per distinct color, most used first.
+    pub colors: Vec<ColorEntry>,
+}
+
+/// A color read from computed `color`, `background-color`, border colors,
+/// or SVG `fill` and `stroke`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColorEntry {
+    /// The color as the webview computed it, e.g. `rgb(37, 99, 235)`. When
+    /// several spellings map to the same color, the most used one.
+    pub css_value: String,
+    /// `#rrggbb`, or `#rrggbbaa` for translucent colors.
+    pub hex: String,
+    /// How many element properties use the color.
+    pub usage_count: u32,
+    /// Selector paths of the first few elements using it.
+    pub element_examples: Vec<String>,
+}
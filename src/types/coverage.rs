@@ -0,0 +1,47 @@
+//! Request and response types for JavaScript code coverage.
+
+use serde::{Deserialize, Serialize};
+
+/// Reply to `POST /coverage/start`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CoverageStartResponse {
+    /// Whether the app's coverage hook ran.
+    pub started: bool,
+}
+
+/// Executed code of one script.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoverageScript {
+    /// URL of the script, empty for inline scripts.
+    pub url: String,
+    /// Executed `[start, end)` byte offsets, sorted and non-overlapping.
+    pub covered_ranges: Vec<[u32; 2]>,
+    /// Size of the script in bytes.
+    pub total_bytes: u32,
+    /// Bytes inside `covered_ranges`.
+    pub covered_bytes: u32,
+}
+
+/// Coverage collected since `POST /coverage/start`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CoverageResponse {
+    /// One entry per script that reported coverage.
+    pub scripts: Vec<CoverageScript>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coverage_script_serialize() {
+        let script = CoverageScript {
+            url: "app.js".to_string(),
+            covered_ranges: vec![[0, 10], [20, 25]],
+            total_bytes: 30,
+            covered_bytes: 15,
+        };
+        let json = serde_json::to_string(&script).unwrap();
+        assert!(json.contains(r#""covered_ranges":[[0,10],[20,25]]"#));
+    }
+}
@@ -0,0 +1,107 @@
+//! Request and response types for user-registered health checks and the
+//! performance diagnosis.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::EvalResponse;
+
+/// Request to register a custom health check.
+///
+/// The script should `return true` when the UI is healthy. Any other result
+/// (or a thrown error) fails the check; returning a string is a convenient
+/// way to say why.
+///
+/// Registering a check with an existing name replaces it.
+///
+/// # JSON Format
+///
+/// ```json
+/// {
+///   "name": "cart-badge",
+///   "script": "return document.querySelector('.cart-badge') !== null",
+///   "severity": "warning"
+/// }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct CustomCheckRequest {
+    /// Unique name of the check.
+    pub name: String,
+    /// JavaScript to evaluate. Should return `true` when healthy.
+    pub script: String,
+    /// One of `error`, `warning` or `info`.
+    pub severity: String,
+}
+
+/// A registered health check.
+#[derive(Debug, Clone, Serialize)]
+pub struct CustomCheck {
+    /// Unique name of the check.
+    pub name: String,
+    /// JavaScript to evaluate.
+    pub script: String,
+    /// One of `error`, `warning` or `info`.
+    pub severity: String,
+}
+
+/// Response from registering a check.
+#[derive(Debug, Serialize)]
+pub struct RegisterCheckResponse {
+    /// Whether the check was registered.
+    pub success: bool,
+    /// Names of all registered checks.
+    pub checks: Vec<String>,
+}
+
+/// Outcome of one custom check.
+#[derive(Debug, Serialize)]
+pub struct CheckResult {
+    /// Name of the check.
+    pub name: String,
+    /// Severity the check was registered with.
+    pub severity: String,
+    /// Whether the script returned `true`.
+    pub passed: bool,
+    /// The raw eval response.
+    pub response: EvalResponse,
+}
+
+/// Results of all registered checks, in registration order.
+#[derive(Debug, Serialize)]
+pub struct CustomDiagnoseResponse {
+    /// One result per registered check.
+    pub checks: Vec<CheckResult>,
+}
+
+/// Result of `GET /diagnose/performance`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PerfDiagnoseResponse {
+    /// Problems found, empty for a healthy page.
+    pub issues: Vec<PerfIssue>,
+    /// 100 minus 25 per `error` and 10 per `warning`, floored at 0.
+    pub score: u8,
+}
+
+/// A rendering performance problem.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PerfIssue {
+    /// What was checked: `long_tasks`, `layout_thrashing`, `dom_size`,
+    /// `missing_will_change`, `document_write` or `tracker_missing`.
+    pub kind: String,
+    /// One of `error`, `warning` or `info`.
+    pub severity: String,
+    /// Human-readable description with the numbers found.
+    pub message: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_check_request_deserialize() {
+        let json = r#"{"name": "badge", "script": "return true", "severity": "info"}"#;
+        let req: CustomCheckRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.name, "badge");
+        assert_eq!(req.severity, "info");
+    }
+}
@@ -0,0 +1,42 @@
+//! Response types for reading the DOM a page at a time.
+
+use serde::{Deserialize, Serialize};
+
+/// One page of the flattened DOM tree, from `GET /dom` with `cursor` or
+/// `page_size`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DomPageResponse {
+    /// Nodes of this page, in document order.
+    pub nodes: Vec<DomNode>,
+    /// Cursor for the next page, if there is one.
+    pub next_cursor: Option<String>,
+    /// Number of nodes in the whole flattened tree.
+    pub total_nodes: u32,
+    /// Whether nodes remain after this page.
+    pub has_more: bool,
+}
+
+/// A node of the flattened DOM tree, linked to its parent by index.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DomNode {
+    /// Position in the flattened tree, counting from 0 across all pages.
+    pub index: u32,
+    /// [`index`](Self::index) of the parent node, `None` for the root.
+    pub parent: Option<u32>,
+    /// Distance from the root, which has depth 0.
+    pub depth: u32,
+    /// Lowercase tag name, `None` for text nodes.
+    pub tag: Option<String>,
+    /// The element's `id`.
+    pub id: Option<String>,
+    /// The element's `class` attribute.
+    pub class: Option<String>,
+    /// Trimmed text of a text node, cut to 100 characters.
+    pub text: Option<String>,
+    /// Whether the node is in its parent's open shadow root rather than its
+    /// light DOM.
+    pub in_shadow_root: bool,
+    /// `"depth"` or `"max_nodes"` for the placeholder standing in for
+    /// children cut off by that limit.
+    pub truncated: Option<String>,
+}
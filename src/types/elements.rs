@@ -0,0 +1,80 @@
+//! Request and response types for adding and removing DOM elements.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Request to insert a new element, e.g. a test fixture.
+///
+/// `position` is `append` (default) or `prepend` to insert inside the
+/// element matching `parent_selector`, or `before` or `after` to insert
+/// next to it.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "parent_selector": "main", "tag": "div", "attributes": { "class": "toast" }, "text": "Saved", "position": "prepend" }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct CreateElementRequest {
+    /// CSS selector of the element to insert into or next to.
+    pub parent_selector: String,
+    /// Tag name of the new element, e.g. `div` or `my-widget`.
+    pub tag: String,
+    /// Attributes to set on the new element.
+    #[serde(default)]
+    pub attributes: HashMap<String, String>,
+    /// Text content of the new element.
+    #[serde(default)]
+    pub text: Option<String>,
+    /// `append`, `prepend`, `before` or `after`. Defaults to `append`.
+    #[serde(default)]
+    pub position: Option<String>,
+}
+
+/// Response from `POST /element/create`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateElementResponse {
+    /// Whether the element was inserted.
+    pub success: bool,
+    /// The `id` from `attributes`, if one was given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Generated `data-test-id`, set when `attributes` has no `id`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_id: Option<String>,
+    /// Selector matching just the new element, e.g. for `DELETE /element/remove`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
+    /// Error message if the parent was missing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Query parameters for `DELETE /element/remove`.
+#[derive(Debug, Deserialize)]
+pub struct RemoveElementQuery {
+    /// CSS selector; every matching element is removed.
+    pub selector: String,
+}
+
+/// Response from `DELETE /element/remove`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemoveElementResponse {
+    /// Number of elements removed; 0 when nothing matched.
+    pub removed: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_element_request_defaults() {
+        let req: CreateElementRequest =
+            serde_json::from_str(r#"{"parent_selector": "body", "tag": "div"}"#).unwrap();
+        assert!(req.attributes.is_empty());
+        assert!(req.text.is_none());
+        assert!(req.position.is_none());
+    }
+}
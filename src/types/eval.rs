@@ -0,0 +1,422 @@
+//! Request and response types for running scripts in the webview.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+
+/// Command sent from HTTP server to Dioxus app for JavaScript evaluation.
+///
+/// When the bridge receives an eval request, it creates an `EvalCommand` and sends it
+/// through the channel. The Dioxus app should poll this channel and execute the script.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// while let Some(cmd) = eval_rx.recv().await {
+///     let result = document::eval(&cmd.script).await;
+///     let response = match result {
+///         Ok(val) => EvalResponse::success(val.to_string()),
+///         Err(e) => EvalResponse::error(e.to_string()),
+///     };
+///     let _ = cmd.response_tx.send(response);
+/// }
+/// ```
+pub struct EvalCommand {
+    /// The JavaScript code to execute in the webview.
+    pub script: String,
+    /// Channel to send the evaluation result back to the HTTP handler.
+    pub response_tx: oneshot::Sender<EvalResponse>,
+}
+
+/// Request to evaluate JavaScript in the webview.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "script": "return document.title" }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct EvalRequest {
+    /// The JavaScript code to execute. Should return a value.
+    pub script: String,
+}
+
+/// Request to evaluate JavaScript only if a CSS selector matches.
+///
+/// Both scripts are function bodies, like [`EvalRequest::script`]. When the
+/// selector doesn't match and there is no `else_script`, the result is `null`.
+///
+/// # JSON Format
+///
+/// ```json
+/// {
+///   "condition_selector": "#spinner",
+///   "script": "return 'loading'",
+///   "else_script": "return 'ready'"
+/// }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct ConditionalEvalRequest {
+    /// CSS selector that must match at least one element.
+    pub condition_selector: String,
+    /// JavaScript to run when the selector matches.
+    pub script: String,
+    /// JavaScript to run when it doesn't.
+    #[serde(default)]
+    pub else_script: Option<String>,
+}
+
+/// Request to run JavaScript as an ES module.
+///
+/// The module may use `import` and top-level `await`; its default export is
+/// returned as the result.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "code": "import { h } from '/assets/util.js'; export default h(1);" }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct ModuleEvalRequest {
+    /// Source of the module.
+    pub code: String,
+}
+
+/// Request to evaluate JavaScript that awaits promises.
+///
+/// The script is the body of an `async` function, so it may use `await`. The
+/// value it resolves to is returned as the result.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "script": "const r = await fetch('/api'); return r.status", "timeout_ms": 5000 }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct AsyncEvalRequest {
+    /// The async function body to execute.
+    pub script: String,
+    /// Reject if the promise hasn't settled after this many milliseconds.
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+}
+
+/// Request to run a script once, then stream the results of polling another.
+///
+/// Both scripts are function bodies, like [`EvalRequest::script`]. Polling
+/// stops after `max_events` results, or when `poll_script` returns `null`.
+///
+/// # JSON Format
+///
+/// ```json
+/// {
+///   "setup_script": "window.__progress = 0; startImport(); return null",
+///   "poll_script": "return window.__progress < 100 ? window.__progress : null",
+///   "interval_ms": 250,
+///   "max_events": 40
+/// }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct StreamEvalRequest {
+    /// Script run once before polling starts. Empty to skip.
+    #[serde(default)]
+    pub setup_script: String,
+    /// Script whose result is sent as an event on every poll.
+    pub poll_script: String,
+    /// Delay between polls in milliseconds.
+    pub interval_ms: u64,
+    /// Most results to send before ending the stream.
+    pub max_events: u32,
+}
+
+/// Default for [`SandboxEvalRequest::timeout_ms`].
+pub const DEFAULT_SANDBOX_TIMEOUT_MS: u64 = 5_000;
+
+/// Default for [`SandboxEvalRequest::max_output_bytes`].
+pub const DEFAULT_SANDBOX_MAX_OUTPUT_BYTES: usize = 64 * 1024;
+
+/// Request to run a script in a Web Worker, away from the page's thread.
+///
+/// The script is an async function body, like [`AsyncEvalRequest::script`],
+/// but runs without access to the DOM. Webviews without workers run it in
+/// the page instead, where only the timeout guards it.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "script": "let n = 0; while (true) n++;", "max_output_bytes": 1024, "timeout_ms": 2000 }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct SandboxEvalRequest {
+    /// The async function body to execute.
+    pub script: String,
+    /// Truncate the JSON result beyond this many bytes. Defaults to 64 KiB.
+    #[serde(default)]
+    pub max_output_bytes: Option<usize>,
+    /// Terminate the worker after this many milliseconds. Defaults to 5000.
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+}
+
+/// Response from JavaScript evaluation.
+///
+/// # JSON Format
+///
+/// Success:
+/// ```json
+/// { "success": true, "result": "Page Title" }
+/// ```
+///
+/// Error:
+/// ```json
+/// { "success": false, "error": "ReferenceError: x is not defined" }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EvalResponse {
+    /// Whether the evaluation succeeded.
+    pub success: bool,
+    /// The result of the evaluation, if successful.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<String>,
+    /// The error message, if evaluation failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl EvalResponse {
+    /// Create a successful response with the given result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dioxus_inspector::EvalResponse;
+    ///
+    /// let resp = EvalResponse::success("42");
+    /// assert!(resp.success);
+    /// assert_eq!(resp.result, Some("42".to_string()));
+    /// ```
+    pub fn success(result: impl Into<String>) -> Self {
+        Self {
+            success: true,
+            result: Some(result.into()),
+            error: None,
+        }
+    }
+
+    /// Create an error response with the given message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dioxus_inspector::EvalResponse;
+    ///
+    /// let resp = EvalResponse::error("Script timeout");
+    /// assert!(!resp.success);
+    /// assert_eq!(resp.error, Some("Script timeout".to_string()));
+    /// ```
+    pub fn error(message: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            result: None,
+            error: Some(message.into()),
+        }
+    }
+}
+
+/// Response from `POST /eval/sandbox`.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "success": true, "result": "[1,2,3", "truncated": true, "cpu_ms": 1.5 }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SandboxEvalResponse {
+    /// The outcome, with `result` cut to `max_output_bytes`.
+    #[serde(flatten)]
+    pub eval: EvalResponse,
+    /// Whether `result` was cut short, leaving it invalid JSON.
+    pub truncated: bool,
+    /// How long the script ran, or `None` if it never started.
+    pub cpu_ms: Option<f64>,
+}
+
+/// Request to run several independent scripts at once.
+///
+/// Each script is a function body, like [`EvalRequest::script`].
+///
+/// # JSON Format
+///
+/// ```json
+/// { "scripts": ["return document.title", "return location.href"], "timeout_ms": 2000 }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct ParallelEvalRequest {
+    /// Scripts to run concurrently.
+    pub scripts: Vec<String>,
+    /// Give up on a script that hasn't answered after this many
+    /// milliseconds, reporting it as an error. No limit by default.
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+}
+
+/// The outcome of one script from `POST /eval/parallel`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EvalResult {
+    /// Index of the script in [`ParallelEvalRequest::scripts`].
+    pub script_index: usize,
+    /// Result or error, as for `/eval`.
+    #[serde(flatten)]
+    pub eval: EvalResponse,
+    /// Time from sending the script to its answer, or to the timeout.
+    pub duration_ms: f64,
+}
+
+/// Response from `POST /eval/parallel`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ParallelEvalResponse {
+    /// One result per script, in request order.
+    pub results: Vec<EvalResult>,
+    /// Time until every script had finished or timed out.
+    pub total_ms: f64,
+}
+
+/// Request to store a script template under a name, for `PUT /eval/named/{name}`.
+///
+/// The script is a function body, like [`EvalRequest::script`], with
+/// `{{param}}` placeholders filled in when it's run.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "script": "return document.querySelector({{selector}}).outerHTML" }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct NamedScriptRequest {
+    /// The script template.
+    pub script: String,
+}
+
+/// Request to run a stored script template, for `POST /eval/named/{name}`.
+///
+/// Each value replaces its `{{param}}` placeholder as a JSON string literal.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "params": { "selector": "#app" } }
+/// ```
+#[derive(Debug, Default, Deserialize)]
+pub struct NamedEvalRequest {
+    /// Values for the template's placeholders.
+    #[serde(default)]
+    pub params: HashMap<String, String>,
+}
+
+/// A stored script template.
+#[derive(Debug, Serialize)]
+pub struct NamedScript {
+    /// Name the script was stored under.
+    pub name: String,
+    /// The script template.
+    pub script: String,
+}
+
+/// Stored script templates, sorted by name.
+#[derive(Debug, Serialize)]
+pub struct NamedScriptsResponse {
+    /// Every stored script.
+    pub scripts: Vec<NamedScript>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eval_response_success() {
+        let resp = EvalResponse::success("42");
+        assert!(resp.success);
+        assert_eq!(resp.result, Some("42".to_string()));
+        assert!(resp.error.is_none());
+    }
+
+    #[test]
+    fn test_eval_response_error() {
+        let resp = EvalResponse::error("failed");
+        assert!(!resp.success);
+        assert!(resp.result.is_none());
+        assert_eq!(resp.error, Some("failed".to_string()));
+    }
+
+    #[test]
+    fn test_eval_request_deserialize() {
+        let json = r#"{"script": "return 1 + 1"}"#;
+        let req: EvalRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.script, "return 1 + 1");
+    }
+
+    #[test]
+    fn test_conditional_eval_request_deserialize() {
+        let json = r##"{"condition_selector": "#spinner", "script": "return 1"}"##;
+        let req: ConditionalEvalRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.condition_selector, "#spinner");
+        assert_eq!(req.script, "return 1");
+        assert!(req.else_script.is_none());
+    }
+
+    #[test]
+    fn test_module_eval_request_deserialize() {
+        let json = r#"{"code": "export default 42;"}"#;
+        let req: ModuleEvalRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.code, "export default 42;");
+    }
+
+    #[test]
+    fn test_async_eval_request_deserialize() {
+        let json = r#"{"script": "return await 1", "timeout_ms": 500}"#;
+        let req: AsyncEvalRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.script, "return await 1");
+        assert_eq!(req.timeout_ms, Some(500));
+
+        let req: AsyncEvalRequest = serde_json::from_str(r#"{"script": "x"}"#).unwrap();
+        assert_eq!(req.timeout_ms, None);
+    }
+
+    #[test]
+    fn test_stream_eval_request_deserialize() {
+        let json = r#"{"poll_script": "return 1", "interval_ms": 100, "max_events": 5}"#;
+        let req: StreamEvalRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.setup_script, "");
+        assert_eq!(req.poll_script, "return 1");
+        assert_eq!(req.interval_ms, 100);
+        assert_eq!(req.max_events, 5);
+    }
+
+    #[test]
+    fn test_sandbox_eval_request_deserialize() {
+        let json = r#"{"script": "return 1", "max_output_bytes": 16, "timeout_ms": 100}"#;
+        let req: SandboxEvalRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.max_output_bytes, Some(16));
+        assert_eq!(req.timeout_ms, Some(100));
+
+        let req: SandboxEvalRequest = serde_json::from_str(r#"{"script": "x"}"#).unwrap();
+        assert_eq!(req.max_output_bytes, None);
+        assert_eq!(req.timeout_ms, None);
+    }
+
+    #[test]
+    fn test_sandbox_eval_response_flattens_eval() {
+        let resp = SandboxEvalResponse {
+            eval: EvalResponse::success("[1,2"),
+            truncated: true,
+            cpu_ms: Some(1.5),
+        };
+        let json = serde_json::to_value(&resp).unwrap();
+        assert_eq!(json["success"], true);
+        assert_eq!(json["result"], "[1,2");
+        assert_eq!(json["truncated"], true);
+        assert_eq!(json["cpu_ms"], 1.5);
+    }
+}
@@ -0,0 +1,96 @@
+//! Request and response types for recording DOM events.
+
+use serde::{Deserialize, Serialize};
+
+/// Default for [`EventWatchRequest::max_events`].
+pub const DEFAULT_EVENT_LOG_MAX_EVENTS: u32 = 200;
+
+/// Request to record events on elements matching a selector.
+///
+/// Events are caught on their way down to the target, so elements rendered
+/// after the watch starts are covered too, and the app stopping propagation
+/// doesn't hide them. Watching a selector again replaces its event types and
+/// limit. Watches last until the page reloads.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "selector": "#signup", "events": ["click", "input", "change"], "max_events": 100 }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct EventWatchRequest {
+    /// CSS selector; events on matching elements and their descendants are
+    /// recorded.
+    pub selector: String,
+    /// Event types to record, e.g. `click` or `keydown`.
+    pub events: Vec<String>,
+    /// Most events kept for this selector; older ones are dropped. Defaults
+    /// to [`DEFAULT_EVENT_LOG_MAX_EVENTS`].
+    #[serde(default = "default_max_events")]
+    pub max_events: u32,
+}
+
+fn default_max_events() -> u32 {
+    DEFAULT_EVENT_LOG_MAX_EVENTS
+}
+
+/// Response from `POST /event-log/watch`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EventWatchResponse {
+    /// Elements matching the selector when the watch started.
+    pub matched_elements: u32,
+    /// Every selector being watched, including this one.
+    pub watched_selectors: Vec<String>,
+}
+
+/// Query parameters for `GET /event-log`.
+#[derive(Debug, Default, Deserialize)]
+pub struct EventLogQuery {
+    /// Empty the log after reading it. Defaults to `true`.
+    #[serde(default)]
+    pub clear: Option<bool>,
+}
+
+/// A DOM event recorded by a watch.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DomEvent {
+    /// Event type, e.g. `click`.
+    #[serde(rename = "type")]
+    pub event_type: String,
+    /// CSS selector path to the element the event was dispatched to.
+    pub target_selector: String,
+    /// When the event fired, in milliseconds since the Unix epoch.
+    pub timestamp_ms: u64,
+    /// Event-specific data: pointer position and button, key and code,
+    /// modifiers, the target's `value` (except for passwords) and `checked`,
+    /// and a `CustomEvent`'s `detail`.
+    pub detail: serde_json::Value,
+}
+
+/// Response from `GET /event-log`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EventLogResponse {
+    /// Recorded events, oldest first.
+    pub events: Vec<DomEvent>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_watch_request_defaults() {
+        let req: EventWatchRequest =
+            serde_json::from_str(r##"{"selector": "#signup", "events": ["click"]}"##).unwrap();
+        assert_eq!(req.events, vec!["click"]);
+        assert_eq!(req.max_events, DEFAULT_EVENT_LOG_MAX_EVENTS);
+    }
+
+    #[test]
+    fn test_dom_event_deserialize() {
+        let json = r#"{"type": "input", "target_selector": "form > input", "timestamp_ms": 1700000000000, "detail": {"value": "a"}}"#;
+        let event: DomEvent = serde_json::from_str(json).unwrap();
+        assert_eq!(event.event_type, "input");
+        assert_eq!(event.detail["value"], "a");
+    }
+}
@@ -0,0 +1,19 @@
+//! Response types for font loading checks.
+
+use serde::{Deserialize, Serialize};
+
+/// Font families the page uses, by whether they're available.
+///
+/// Generic families (`serif`, `system-ui`, ...) and their vendor aliases are
+/// left out, since they always resolve to some font. Families without an
+/// `@font-face` count as loaded when they're installed locally.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MissingFontsResponse {
+    /// Families that failed to load or aren't available at all, so text
+    /// silently falls back to the next family in the list.
+    pub missing: Vec<String>,
+    /// Web fonts still downloading.
+    pub loading: Vec<String>,
+    /// Families that are ready to render.
+    pub loaded: Vec<String>,
+}
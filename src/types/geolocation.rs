@@ -0,0 +1,45 @@
+//! Request and response types for mocking the Geolocation API.
+
+use serde::{Deserialize, Serialize};
+
+/// Position, or error, that `navigator.geolocation` should report.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "latitude": 52.52, "longitude": 13.405, "accuracy": 10.0 }
+/// ```
+///
+/// With `error_code` set, callers get a `GeolocationPositionError` instead:
+/// `1` (permission denied), `2` (position unavailable) or `3` (timeout).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeoMockRequest {
+    /// Latitude in degrees, -90 to 90.
+    pub latitude: f64,
+    /// Longitude in degrees, -180 to 180.
+    pub longitude: f64,
+    /// Accuracy radius in meters.
+    pub accuracy: f64,
+    /// Report this `GeolocationPositionError` code instead of the position.
+    #[serde(default)]
+    pub error_code: Option<u16>,
+}
+
+/// Whether `navigator.geolocation` is mocked after the request.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GeoMockResponse {
+    pub mocked: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geo_mock_request_without_error() {
+        let req: GeoMockRequest =
+            serde_json::from_str(r#"{"latitude": 1.5, "longitude": -2.0, "accuracy": 5}"#).unwrap();
+        assert_eq!(req.accuracy, 5.0);
+        assert!(req.error_code.is_none());
+    }
+}
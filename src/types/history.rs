@@ -0,0 +1,53 @@
+//! Request and response types for the eval history.
+
+use serde::{Deserialize, Serialize};
+
+use crate::history::EvalHistoryEntry;
+use crate::trace::TraceEntry;
+
+/// Query parameters for `GET /history`.
+#[derive(Debug, Default, Deserialize)]
+pub struct HistoryQuery {
+    /// Only return the most recent `last` entries.
+    #[serde(default)]
+    pub last: Option<usize>,
+}
+
+/// Recorded evals, oldest first.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HistoryResponse {
+    /// The returned entries.
+    pub entries: Vec<EvalHistoryEntry>,
+    /// Maximum number of entries the bridge keeps.
+    pub capacity: usize,
+}
+
+/// Response from `DELETE /history`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ClearHistoryResponse {
+    /// Number of entries that were removed.
+    pub cleared: usize,
+}
+
+/// Traced evals, oldest first.
+///
+/// `DELETE /trace` responds with a [`ClearHistoryResponse`].
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TraceResponse {
+    /// Up to [`MAX_TRACE_ENTRIES`](crate::MAX_TRACE_ENTRIES) entries.
+    pub entries: Vec<TraceEntry>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_query_deserialize() {
+        let query: HistoryQuery = serde_json::from_str(r#"{"last": 5}"#).unwrap();
+        assert_eq!(query.last, Some(5));
+
+        let query: HistoryQuery = serde_json::from_str("{}").unwrap();
+        assert!(query.last.is_none());
+    }
+}
@@ -0,0 +1,66 @@
+//! Response types for checking the page's images.
+
+use serde::{Deserialize, Serialize};
+
+/// Load state of every `<img>` in the document.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ImageStatusResponse {
+    /// One entry per `<img>`, in document order.
+    pub images: Vec<ImageStatus>,
+    /// Images that finished loading without any pixels, see [`ImageStatus::is_broken`].
+    pub broken_count: u32,
+    /// Images without an `alt` attribute. `alt=""` marks a decorative image
+    /// and doesn't count.
+    pub missing_alt_count: u32,
+}
+
+/// Load state of one `<img>`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ImageStatus {
+    /// The `src` attribute as written, empty when unset.
+    pub src: String,
+    /// Whether the browser has finished with the image, successfully or not.
+    pub complete: bool,
+    /// Intrinsic width in pixels, 0 until decoded.
+    pub natural_width: u32,
+    /// Intrinsic height in pixels, 0 until decoded.
+    pub natural_height: u32,
+    /// Resolved URL actually loaded, which `srcset` or `<picture>` can change.
+    pub current_src: String,
+    /// The `alt` attribute, if present.
+    pub alt: Option<String>,
+    /// The `loading` attribute: `eager`, `lazy` or `auto`.
+    pub loading: String,
+}
+
+impl ImageStatus {
+    /// Whether the image finished loading but has no size, i.e. it failed to
+    /// load or decode. Lazy images that haven't started yet aren't broken.
+    pub fn is_broken(&self) -> bool {
+        self.complete && self.natural_width == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(complete: bool, natural_width: u32) -> ImageStatus {
+        ImageStatus {
+            src: "/logo.png".to_string(),
+            complete,
+            natural_width,
+            natural_height: natural_width,
+            current_src: String::new(),
+            alt: None,
+            loading: "lazy".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_is_broken() {
+        assert!(image(true, 0).is_broken());
+        assert!(!image(true, 64).is_broken());
+        assert!(!image(false, 0).is_broken());
+    }
+}
@@ -0,0 +1,74 @@
+//! Request types for checking elements and their classes.
+
+use serde::Deserialize;
+
+/// Request for element inspection.
+///
+/// Returns detailed visibility and position information for an element.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "selector": ".modal", "include_listeners": true }
+/// ```
+///
+/// # Event Listeners
+///
+/// With `include_listeners`, the result includes an `event_listeners` array of
+/// event types. The script uses the devtools `getEventListeners()` API when it
+/// is available, and otherwise falls back to an `addEventListener` tracker the
+/// bridge installs on startup. The tracker only sees listeners added after it
+/// was installed and is lost on page reload. Inline `on*` handlers are always
+/// included. Listeners that Dioxus attaches for `onclick` and friends are
+/// delegated to the root element, so they don't show up on the element itself.
+/// The field is `null` when no source of listener information is available.
+#[derive(Debug, Deserialize)]
+pub struct InspectRequest {
+    /// CSS selector to find the element to inspect.
+    pub selector: String,
+    /// Also list the event listener types attached to the element.
+    #[serde(default)]
+    pub include_listeners: bool,
+}
+
+/// Request to validate CSS classes.
+///
+/// Checks which CSS classes are available in the document's stylesheets.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "classes": ["flex", "p-4", "bg-white"] }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct ValidateClassesRequest {
+    /// List of CSS class names to validate.
+    pub classes: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inspect_request_deserialize() {
+        let json = r#"{"selector": ".modal"}"#;
+        let req: InspectRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.selector, ".modal");
+        assert!(!req.include_listeners);
+    }
+
+    #[test]
+    fn test_inspect_request_with_listeners() {
+        let json = r#"{"selector": ".modal", "include_listeners": true}"#;
+        let req: InspectRequest = serde_json::from_str(json).unwrap();
+        assert!(req.include_listeners);
+    }
+
+    #[test]
+    fn test_validate_classes_request_deserialize() {
+        let json = r#"{"classes": ["flex", "p-4", "bg-white"]}"#;
+        let req: ValidateClassesRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.classes, vec!["flex", "p-4", "bg-white"]);
+    }
+}
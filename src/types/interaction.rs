@@ -0,0 +1,254 @@
+//! Request types for simulating user interaction in the webview.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Request to simulate an HTML5 drag-and-drop sequence.
+///
+/// Dispatches `dragstart`, `drag`, `dragenter`, `dragover`, `drop` and `dragend`
+/// in order, using the center of each element's bounding rect as coordinates.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "from_selector": ".card", "to_selector": ".column-done" }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct DragRequest {
+    /// CSS selector of the element being dragged.
+    pub from_selector: String,
+    /// CSS selector of the drop target.
+    pub to_selector: String,
+}
+
+/// Request to scroll an element into the visible viewport.
+///
+/// The options are passed to `Element.scrollIntoView`; omitted ones use the
+/// browser defaults.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "selector": "#pricing", "behavior": "smooth", "block": "center" }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct ScrollIntoViewRequest {
+    /// CSS selector; the first matching element is scrolled to.
+    pub selector: String,
+    /// `auto`, `instant` or `smooth`.
+    pub behavior: Option<String>,
+    /// Vertical alignment: `start`, `center`, `end` or `nearest`.
+    pub block: Option<String>,
+    /// Horizontal alignment: `start`, `center`, `end` or `nearest`.
+    pub inline: Option<String>,
+}
+
+/// Request to select a range of text inside an element.
+///
+/// Offsets are character positions. Inputs and textareas use
+/// `setSelectionRange`; other elements select across their text nodes, with
+/// offsets counted in `textContent`.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "selector": "#bio", "start": 0, "end": 5 }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct SelectRequest {
+    /// CSS selector of the element containing the text.
+    pub selector: String,
+    /// Offset where the selection starts.
+    pub start: usize,
+    /// Offset where the selection ends (exclusive).
+    pub end: usize,
+}
+
+/// Response from a text selection.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SelectResponse {
+    /// Whether the selection was applied.
+    pub success: bool,
+    /// The text that ended up selected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected_text: Option<String>,
+    /// Error message if the element was missing or can't be selected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Request to fill a form's fields and optionally submit it.
+///
+/// Fields are matched by `name` within the form. Checkboxes are checked for
+/// `"true"`, `"on"` or their own `value`; radio buttons for their `value`.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "form_selector": "#login", "fields": { "email": "a@b.c", "password": "hunter2" }, "submit": true }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct FormFillRequest {
+    /// CSS selector of the `<form>`.
+    pub form_selector: String,
+    /// Field values keyed by the control's `name` attribute.
+    pub fields: HashMap<String, String>,
+    /// Submit the form after filling it. Defaults to `false`.
+    #[serde(default)]
+    pub submit: bool,
+}
+
+/// Response from filling a form.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FormFillResponse {
+    /// Whether the form was found and every field matched a control.
+    pub success: bool,
+    /// Number of fields that matched a control and were set.
+    pub fields_filled: u32,
+    /// Whether the form was submitted.
+    pub submitted: bool,
+    /// Error message if the form or some fields were missing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Query parameters for `GET /input-state`.
+#[derive(Debug, Deserialize)]
+pub struct InputStateQuery {
+    /// CSS selector of the form to read. Reads every control in the
+    /// document when omitted.
+    #[serde(default)]
+    pub form_selector: Option<String>,
+}
+
+/// Form controls with their values and validation state.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InputStateResponse {
+    /// Every `<input>`, `<select>` and `<textarea>`, in document order.
+    pub inputs: Vec<InputState>,
+}
+
+/// The current value and validation state of a form control.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InputState {
+    /// The `name` attribute, empty when unset.
+    pub name: String,
+    /// The `id` attribute, empty when unset.
+    pub id: String,
+    /// `type` of an `<input>` (e.g. `email`), or `select-one`,
+    /// `select-multiple` or `textarea`.
+    #[serde(rename = "type")]
+    pub input_type: String,
+    /// Current value; the selected values joined by `,` for multi-selects.
+    pub value: String,
+    /// Whether a checkbox or radio button is checked, `None` for other controls.
+    pub checked: Option<bool>,
+    /// `validity.valid`, i.e. whether the control passes its constraints.
+    pub validity_valid: bool,
+    /// The browser's `validationMessage`, empty when the control is valid.
+    pub validation_message: String,
+    /// Whether the control has `required`.
+    pub required: bool,
+    /// Whether the control is disabled, directly or by a `<fieldset>`.
+    pub disabled: bool,
+}
+
+/// Request to dispatch a touch event on an element.
+///
+/// `action` is `start`, `move`, `end` or `cancel`, dispatched as
+/// `touchstart`, `touchmove`, `touchend` or `touchcancel`. A swipe is a
+/// `start`, one or more `move`s and an `end` with the same touch `id`s.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "selector": ".carousel", "action": "move", "touches": [{ "x": 120, "y": 300, "id": 0 }] }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct TouchRequest {
+    /// CSS selector of the element receiving the event.
+    pub selector: String,
+    /// `start`, `move`, `end` or `cancel`.
+    pub action: String,
+    /// The touch points that changed; at least one is required.
+    pub touches: Vec<TouchPoint>,
+}
+
+/// A finger on the screen, in viewport coordinates.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TouchPoint {
+    /// `clientX` of the touch.
+    pub x: f64,
+    /// `clientY` of the touch.
+    pub y: f64,
+    /// Identifier that ties a touch's `start`, `move` and `end` together.
+    pub id: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_state_serializes_type() {
+        let state = InputState {
+            name: "email".to_string(),
+            id: String::new(),
+            input_type: "email".to_string(),
+            value: "a@".to_string(),
+            checked: None,
+            validity_valid: false,
+            validation_message: "Please enter an email address.".to_string(),
+            required: true,
+            disabled: false,
+        };
+        let json = serde_json::to_value(&state).unwrap();
+        assert_eq!(json["type"], "email");
+        assert!(json.get("input_type").is_none());
+    }
+
+    #[test]
+    fn test_form_fill_request_defaults_to_no_submit() {
+        let json = r#"{"form_selector": "form", "fields": {"q": "dioxus"}}"#;
+        let req: FormFillRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.fields["q"], "dioxus");
+        assert!(!req.submit);
+    }
+
+    #[test]
+    fn test_drag_request_deserialize() {
+        let json = r#"{"from_selector": ".card", "to_selector": ".column"}"#;
+        let req: DragRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.from_selector, ".card");
+        assert_eq!(req.to_selector, ".column");
+    }
+
+    #[test]
+    fn test_scroll_into_view_request_deserialize() {
+        let json = r##"{"selector": "#pricing", "block": "center"}"##;
+        let req: ScrollIntoViewRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.selector, "#pricing");
+        assert_eq!(req.block.as_deref(), Some("center"));
+        assert!(req.behavior.is_none());
+        assert!(req.inline.is_none());
+    }
+
+    #[test]
+    fn test_select_response_from_script_error() {
+        let json = r#"{"success": false, "error": "Element not found: #bio"}"#;
+        let resp: SelectResponse = serde_json::from_str(json).unwrap();
+        assert!(!resp.success);
+        assert!(resp.selected_text.is_none());
+        assert_eq!(resp.error.as_deref(), Some("Element not found: #bio"));
+    }
+
+    #[test]
+    fn test_touch_request_deserialize() {
+        let json = r#"{"selector": ".carousel", "action": "start", "touches": [{"x": 10.5, "y": 20, "id": 1}]}"#;
+        let req: TouchRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.action, "start");
+        assert_eq!(req.touches[0].x, 10.5);
+        assert_eq!(req.touches[0].id, 1);
+    }
+}
@@ -0,0 +1,410 @@
+//! Request and response types for layout inspection.
+
+use serde::{Deserialize, Serialize};
+
+/// Request for the flexbox and grid layout of a container.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "selector": ".toolbar" }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct LayoutInspectRequest {
+    /// CSS selector of the container; the first match is used.
+    pub selector: String,
+}
+
+/// Resolved layout of a container and its direct children.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LayoutInspectResponse {
+    /// Computed `display`, e.g. `flex`, `inline-grid` or `block`.
+    pub display: String,
+    /// Computed `flex-direction`, for flex containers only.
+    pub flex_direction: Option<String>,
+    /// Computed `flex-wrap`, for flex containers only.
+    pub flex_wrap: Option<String>,
+    /// Computed `grid-template-columns`, for grid containers only.
+    pub grid_template_columns: Option<String>,
+    /// Computed `grid-template-rows`, for grid containers only.
+    pub grid_template_rows: Option<String>,
+    /// Direct children, in DOM order.
+    pub children: Vec<LayoutChildInfo>,
+}
+
+/// Flex and grid item properties of a container's child.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LayoutChildInfo {
+    /// Selector path to the child, e.g. `nav#main > a:nth-of-type(2)`.
+    pub selector_path: String,
+    /// Computed `flex-basis`.
+    pub flex_basis: String,
+    /// Computed `flex-grow`.
+    pub flex_grow: String,
+    /// Computed `flex-shrink`.
+    pub flex_shrink: String,
+    /// Computed `grid-column`.
+    pub grid_column: String,
+    /// Computed `grid-row`.
+    pub grid_row: String,
+}
+
+/// Default number of ancestors returned by `POST /inspect/parent`.
+pub const DEFAULT_PARENT_DEPTH: u32 = 10;
+
+/// Request for the ancestors of an element.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "selector": ".dropdown-menu", "depth": 5 }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct ParentChainRequest {
+    /// CSS selector of the element; the first match is used.
+    pub selector: String,
+    /// Max ancestors to return (default: [`DEFAULT_PARENT_DEPTH`]).
+    pub depth: Option<u32>,
+}
+
+/// Ancestors of an element, nearest first.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ParentChainResponse {
+    /// Parent, grandparent and so on, up to the requested depth or `<html>`.
+    pub chain: Vec<ParentNode>,
+}
+
+/// An ancestor and the computed styles that can clip or hide its descendants.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ParentNode {
+    /// Lowercase tag name.
+    pub tag: String,
+    /// `id` attribute, if set.
+    pub id: Option<String>,
+    /// Class names, in `classList` order.
+    pub classes: Vec<String>,
+    /// Computed `display`.
+    pub display: String,
+    /// Computed `overflow`, e.g. `hidden` or `visible auto`.
+    pub overflow: String,
+    /// Computed `visibility`.
+    pub visibility: String,
+    /// Computed `z-index`, `auto` unless set.
+    pub z_index: String,
+    /// Computed `position`.
+    pub position: String,
+}
+
+/// Request for the stacking contexts inside an element.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "selector": "body" }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct StackingInspectRequest {
+    /// CSS selector of the element whose subtree is searched, itself
+    /// included; the first match is used.
+    pub selector: String,
+}
+
+/// Elements that create a stacking context, in DOM order.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StackingResponse {
+    /// Empty when nothing in the subtree creates a stacking context.
+    pub contexts: Vec<StackingContext>,
+}
+
+/// An element that creates a stacking context, and why.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StackingContext {
+    /// Selector path to the element, e.g. `div#app > header`.
+    pub selector_path: String,
+    /// Computed `z-index`, `auto` unless set.
+    pub z_index: String,
+    /// The styles that create the context, e.g. `opacity: 0.5` or
+    /// `position: relative with z-index`.
+    pub creates_context_reason: Vec<String>,
+    /// Position and size in the viewport.
+    pub bounding_rect: BoundingRect,
+}
+
+/// An element's `getBoundingClientRect()`, in CSS pixels.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BoundingRect {
+    /// Distance from the top of the viewport.
+    pub top: f64,
+    /// Distance from the left of the viewport.
+    pub left: f64,
+    /// Rendered width.
+    pub width: f64,
+    /// Rendered height.
+    pub height: f64,
+}
+
+/// Query parameters for `GET /intersection`.
+#[derive(Debug, Deserialize)]
+pub struct IntersectionQuery {
+    /// CSS selector; every match is checked.
+    pub selector: String,
+}
+
+/// Whether each element matching a selector is in the viewport.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IntersectionResponse {
+    /// One entry per match, in DOM order; empty when nothing matched.
+    pub elements: Vec<IntersectionEntry>,
+}
+
+/// How much of an element is inside the viewport.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IntersectionEntry {
+    /// Selector path to the element, e.g. `ul#feed > li:nth-of-type(3)`.
+    pub selector_path: String,
+    /// Whether any part of the element is inside the viewport.
+    pub in_viewport: bool,
+    /// Visible fraction of the element's area, from 0 to 1. Like
+    /// `IntersectionObserver`, zero-size elements in the viewport count as 1.
+    pub intersection_ratio: f64,
+    /// Position and size in the viewport.
+    pub bounding_rect: BoundingRect,
+}
+
+/// Scrollable elements, from `GET /scroll/all`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScrollContainersResponse {
+    /// Every element whose content overflows it, in DOM order.
+    pub containers: Vec<ScrollContainer>,
+}
+
+/// Scroll position and extent of a scrollable element, in CSS pixels.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScrollContainer {
+    /// Selector path to the element; `html` for the document itself.
+    pub selector_path: String,
+    /// `scrollLeft`.
+    pub scroll_x: f64,
+    /// `scrollTop`.
+    pub scroll_y: f64,
+    /// `scrollWidth`, the width of the content.
+    pub scroll_width: f64,
+    /// `scrollHeight`, the height of the content.
+    pub scroll_height: f64,
+    /// `clientWidth`, the visible width.
+    pub client_width: f64,
+    /// `clientHeight`, the visible height.
+    pub client_height: f64,
+    /// Whether the element is scrolled to within a pixel of the bottom.
+    pub is_at_bottom: bool,
+}
+
+/// Request for the resolved box model and flex/grid values of an element.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "selector": "main > .sidebar" }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct ComputedLayoutRequest {
+    /// CSS selector of the element; the first match is used.
+    pub selector: String,
+}
+
+/// Resolved layout of one element, from `POST /computed-layout`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ComputedLayoutResponse {
+    /// Computed `display`.
+    pub display: String,
+    /// Sizes of the element's boxes.
+    pub box_model: BoxModel,
+    /// Flex values, when the element is a flex container or a flex item.
+    pub flex: Option<FlexProperties>,
+    /// Grid values, when the element is a grid container or a grid item.
+    pub grid: Option<GridProperties>,
+}
+
+/// An element's content, padding, border and margin boxes, in CSS pixels.
+///
+/// Sizes come from the layout, so transforms don't affect them. The padding
+/// box includes any scrollbar.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BoxModel {
+    /// Computed `box-sizing`.
+    pub box_sizing: String,
+    /// Width inside the padding.
+    pub content_width: f64,
+    /// Height inside the padding.
+    pub content_height: f64,
+    /// Width inside the border.
+    pub padding_width: f64,
+    /// Height inside the border.
+    pub padding_height: f64,
+    /// Width including the border, i.e. `offsetWidth`.
+    pub border_width: f64,
+    /// Height including the border, i.e. `offsetHeight`.
+    pub border_height: f64,
+    /// Width including the margin.
+    pub margin_width: f64,
+    /// Height including the margin.
+    pub margin_height: f64,
+    /// Computed padding on each side.
+    pub padding: BoxEdges,
+    /// Computed border widths on each side.
+    pub border: BoxEdges,
+    /// Computed margin on each side; negative margins stay negative.
+    pub margin: BoxEdges,
+}
+
+/// A length on each side of a box, in CSS pixels.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BoxEdges {
+    /// Top side.
+    pub top: f64,
+    /// Right side.
+    pub right: f64,
+    /// Bottom side.
+    pub bottom: f64,
+    /// Left side.
+    pub left: f64,
+}
+
+/// Resolved flex container and flex item values of an element.
+///
+/// Both sets are always filled in; `is_container` and `is_item` say which
+/// apply.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FlexProperties {
+    /// Whether the element's `display` is `flex` or `inline-flex`.
+    pub is_container: bool,
+    /// Whether the element's parent is a flex container.
+    pub is_item: bool,
+    /// Computed `flex-direction`.
+    pub direction: String,
+    /// Computed `flex-wrap`.
+    pub wrap: String,
+    /// Computed `justify-content`.
+    pub justify_content: String,
+    /// Computed `align-items`.
+    pub align_items: String,
+    /// Computed `align-content`.
+    pub align_content: String,
+    /// Computed `row-gap`.
+    pub row_gap: String,
+    /// Computed `column-gap`.
+    pub column_gap: String,
+    /// Computed `flex-grow`.
+    pub grow: f64,
+    /// Computed `flex-shrink`.
+    pub shrink: f64,
+    /// Computed `flex-basis`, e.g. `auto` or `120px`.
+    pub basis: String,
+    /// Computed `align-self`.
+    pub align_self: String,
+    /// Computed `order`.
+    pub order: i32,
+}
+
+/// Resolved grid container and grid item values of an element.
+///
+/// Both sets are always filled in; `is_container` and `is_item` say which
+/// apply.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GridProperties {
+    /// Whether the element's `display` is `grid` or `inline-grid`.
+    pub is_container: bool,
+    /// Whether the element's parent is a grid container.
+    pub is_item: bool,
+    /// Computed `grid-template-columns`, with track sizes resolved to pixels.
+    pub template_columns: String,
+    /// Computed `grid-template-rows`, with track sizes resolved to pixels.
+    pub template_rows: String,
+    /// Computed `grid-template-areas`, `none` unless set.
+    pub template_areas: String,
+    /// Computed `grid-auto-flow`.
+    pub auto_flow: String,
+    /// Computed `grid-auto-columns`.
+    pub auto_columns: String,
+    /// Computed `grid-auto-rows`.
+    pub auto_rows: String,
+    /// Computed `row-gap`.
+    pub row_gap: String,
+    /// Computed `column-gap`.
+    pub column_gap: String,
+    /// Computed `justify-items`.
+    pub justify_items: String,
+    /// Computed `align-items`.
+    pub align_items: String,
+    /// Computed `grid-column`.
+    pub column: String,
+    /// Computed `grid-row`.
+    pub row: String,
+    /// Computed `grid-area`.
+    pub area: String,
+    /// Computed `justify-self`.
+    pub justify_self: String,
+    /// Computed `align-self`.
+    pub align_self: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_inspect_response_without_grid() {
+        let json = r#"{
+            "display": "flex",
+            "flex_direction": "row",
+            "flex_wrap": "nowrap",
+            "grid_template_columns": null,
+            "grid_template_rows": null,
+            "children": []
+        }"#;
+        let resp: LayoutInspectResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(resp.flex_direction.as_deref(), Some("row"));
+        assert!(resp.grid_template_columns.is_none());
+    }
+
+    #[test]
+    fn test_parent_chain_request_default_depth() {
+        let req: ParentChainRequest = serde_json::from_str(r#"{"selector": ".menu"}"#).unwrap();
+        assert!(req.depth.is_none());
+    }
+
+    #[test]
+    fn test_stacking_response() {
+        let json = r#"{"contexts": [{
+            "selector_path": "div#modal",
+            "z_index": "10",
+            "creates_context_reason": ["position: fixed", "opacity: 0.9"],
+            "bounding_rect": {"top": 0, "left": 0, "width": 800, "height": 600.5}
+        }]}"#;
+        let resp: StackingResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(resp.contexts[0].creates_context_reason.len(), 2);
+        assert_eq!(resp.contexts[0].bounding_rect.height, 600.5);
+    }
+
+    #[test]
+    fn test_computed_layout_response_without_grid() {
+        let json = r#"{
+            "display": "block",
+            "box_model": {
+                "box_sizing": "border-box",
+                "content_width": 100, "content_height": 20,
+                "padding_width": 120, "padding_height": 40,
+                "border_width": 122, "border_height": 42,
+                "margin_width": 122, "margin_height": 58,
+                "padding": {"top": 10, "right": 10, "bottom": 10, "left": 10},
+                "border": {"top": 1, "right": 1, "bottom": 1, "left": 1},
+                "margin": {"top": 8, "right": 0, "bottom": 8, "left": 0}
+            },
+            "flex": null,
+            "grid": null
+        }"#;
+        let resp: ComputedLayoutResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(resp.box_model.margin_height, 58.0);
+        assert!(resp.flex.is_none());
+    }
+}
@@ -0,0 +1,47 @@
+//! Request and response types for reading lists.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Request to extract the items of a list.
+///
+/// Matches `<ul>`, `<ol>`, `<menu>` and `<dl>`, and elements with
+/// `role="list"`, `listbox` or `menu`.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "selector": "nav ul" }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct ListRequest {
+    /// CSS selector; the first matching list is read.
+    pub selector: String,
+}
+
+/// Items of a list, from `POST /list`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListResponse {
+    /// The list's own items in order; items of nested lists are left out.
+    pub items: Vec<ListItem>,
+    /// Lowercase tag name (`ul`, `ol`, `menu`, `dl`), or the ARIA role for
+    /// other elements, e.g. `listbox`.
+    pub list_type: String,
+    /// Whether item order is meaningful, i.e. the list is an `<ol>`.
+    pub ordered: bool,
+}
+
+/// One list item.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListItem {
+    /// Lowercase tag name: `li`, `dt` or `dd`, or e.g. `div` for an ARIA
+    /// `option`.
+    pub tag: String,
+    /// Text of the item without nested lists, with whitespace collapsed.
+    pub text: String,
+    /// `href` of the item's first link, resolved to an absolute URL.
+    pub href: Option<String>,
+    /// `data-*` attributes of the item, by full attribute name.
+    pub data_attributes: HashMap<String, String>,
+}
@@ -0,0 +1,45 @@
+//! Request and response types for timing scripts.
+
+use serde::{Deserialize, Serialize};
+
+/// Request to time a script over several runs.
+///
+/// The script is a function body, like
+/// [`EvalRequest::script`](crate::EvalRequest::script), and runs
+/// synchronously; only the time until it returns is measured, not promises it
+/// starts.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "label": "render-rows", "script": "document.querySelector('#add').click()", "repetitions": 20 }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct MeasureRequest {
+    /// Name of the `performance.measure` entries.
+    pub label: String,
+    /// JavaScript to time.
+    pub script: String,
+    /// How many times to run the script (default: 10).
+    pub repetitions: Option<u32>,
+}
+
+/// Timing statistics from `POST /measure`, in milliseconds.
+///
+/// Precision is whatever the webview's `performance.now()` offers, which may
+/// be coarsened to 0.1ms or more.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MeasureResponse {
+    /// The request's label.
+    pub label: String,
+    /// Average run time.
+    pub mean_ms: f64,
+    /// Fastest run.
+    pub min_ms: f64,
+    /// Slowest run.
+    pub max_ms: f64,
+    /// Population standard deviation of the run times.
+    pub stddev_ms: f64,
+    /// Each run's time, in order.
+    pub samples: Vec<f64>,
+}
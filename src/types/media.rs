@@ -0,0 +1,86 @@
+//! Request and response types for inspecting and controlling media elements.
+
+use serde::{Deserialize, Serialize};
+
+/// Query parameters for `GET /media`.
+#[derive(Debug, Default, Deserialize)]
+pub struct MediaQuery {
+    /// CSS selector for the elements to report (default: `audio, video`).
+    pub selector: Option<String>,
+}
+
+/// A buffered time range, in seconds.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MediaTimeRange {
+    pub start: f64,
+    pub end: f64,
+}
+
+/// Playback state of an `<audio>` or `<video>` element.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MediaElementState {
+    /// Short description of the element, e.g. `video#player.hero`.
+    pub element: String,
+    /// The resource being played (`currentSrc`), if any.
+    pub src: Option<String>,
+    /// Playback position in seconds.
+    pub current_time: f64,
+    /// Duration in seconds, or `None` before metadata loads and for live streams.
+    pub duration: Option<f64>,
+    pub paused: bool,
+    pub muted: bool,
+    /// Volume from `0.0` to `1.0`.
+    pub volume: f64,
+    /// Ranges of the resource the browser has buffered.
+    pub buffered: Vec<MediaTimeRange>,
+    /// `HTMLMediaElement.readyState`, `0` (nothing) to `4` (enough data).
+    pub ready_state: u8,
+    /// `HTMLMediaElement.networkState`, `0` (empty) to `3` (no source).
+    pub network_state: u8,
+}
+
+/// Media elements matching a selector.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MediaResponse {
+    pub media: Vec<MediaElementState>,
+}
+
+/// Request to control every media element matching a selector.
+///
+/// `seek` takes the position in seconds and `volume` a level from `0.0` to
+/// `1.0`. `mute` mutes unless `value` is `0`.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "selector": "video#player", "action": "seek", "value": 42.5 }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct MediaControlRequest {
+    pub selector: String,
+    /// One of `play`, `pause`, `seek`, `mute` or `volume`.
+    pub action: String,
+    #[serde(default)]
+    pub value: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_media_control_request_without_value() {
+        let req: MediaControlRequest =
+            serde_json::from_str(r#"{"selector": "video", "action": "play"}"#).unwrap();
+        assert_eq!(req.action, "play");
+        assert!(req.value.is_none());
+    }
+
+    #[test]
+    fn test_media_response_deserialize() {
+        let json = r#"{"media": [{"element": "video#player", "src": null, "current_time": 0, "duration": null, "paused": true, "muted": false, "volume": 1, "buffered": [{"start": 0, "end": 4.5}], "ready_state": 1, "network_state": 2}]}"#;
+        let resp: MediaResponse = serde_json::from_str(json).unwrap();
+        assert!(resp.media[0].duration.is_none());
+        assert_eq!(resp.media[0].buffered[0].end, 4.5);
+    }
+}
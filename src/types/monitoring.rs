@@ -0,0 +1,84 @@
+//! Response types for data recorded by the bridge's setup trackers.
+
+use serde::{Deserialize, Serialize};
+
+/// A single fetch or XHR request captured in the webview.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NetworkRequest {
+    /// Request URL as passed to `fetch`/`XMLHttpRequest.open`.
+    pub url: String,
+    /// Uppercase HTTP method.
+    pub method: String,
+    /// HTTP status, or `None` if the request failed before a response arrived.
+    pub status: Option<u16>,
+    /// Time from send to response (or failure), in milliseconds.
+    pub duration_ms: f64,
+    /// Request body, truncated to 500 characters. Non-text bodies are shown
+    /// as their type name, e.g. `[FormData]`.
+    pub request_body: Option<String>,
+    /// First 500 characters of the response body (or the error message).
+    pub response_preview: Option<String>,
+}
+
+/// Response from `GET /network`.
+///
+/// Holds the most recent 200 requests, oldest first.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NetworkResponse {
+    /// Captured requests.
+    pub requests: Vec<NetworkRequest>,
+}
+
+/// Query parameters for `GET /errors`.
+#[derive(Debug, Default, Deserialize)]
+pub struct JsErrorsQuery {
+    /// Empty the buffer after reading it. Defaults to `true`.
+    #[serde(default)]
+    pub clear: Option<bool>,
+}
+
+/// An uncaught error or unhandled promise rejection in the webview.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JsError {
+    /// Error message; rejections are prefixed with `Unhandled rejection:`.
+    pub message: String,
+    /// Script URL the error was thrown from, if known.
+    pub source: Option<String>,
+    /// Line number in `source`.
+    pub line: Option<u32>,
+    /// Column number in `source`.
+    pub col: Option<u32>,
+    /// Stack trace, when the thrown value was an `Error`.
+    pub stack: Option<String>,
+    /// When the error was caught, in milliseconds since the Unix epoch.
+    pub timestamp_ms: u64,
+}
+
+/// Response from `GET /errors`.
+///
+/// Holds the most recent 100 errors, oldest first.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JsErrorsResponse {
+    /// Captured errors.
+    pub errors: Vec<JsError>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_network_response_deserialize() {
+        let json = r#"{"requests": [
+            {"url": "/api/items", "method": "POST", "status": 201, "duration_ms": 12.5,
+             "request_body": "{}", "response_preview": "{\"id\":1}"},
+            {"url": "/api/down", "method": "GET", "status": null, "duration_ms": 3.0,
+             "request_body": null, "response_preview": "TypeError: Failed to fetch"}
+        ]}"#;
+        let resp: NetworkResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(resp.requests.len(), 2);
+        assert_eq!(resp.requests[0].status, Some(201));
+        assert!(resp.requests[1].status.is_none());
+        assert!(resp.requests[1].request_body.is_none());
+    }
+}
@@ -0,0 +1,94 @@
+//! Request and response types for logging DOM mutations.
+
+use serde::{Deserialize, Serialize};
+
+/// Default for [`MutationLogStartRequest::max_entries`].
+pub const DEFAULT_MUTATION_LOG_ENTRIES: u32 = 500;
+
+/// Optional body of `POST /mutation-log/start`.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "max_entries": 1000 }
+/// ```
+#[derive(Debug, Default, Deserialize)]
+pub struct MutationLogStartRequest {
+    /// Most entries kept; older ones are dropped, though the counts in
+    /// [`MutationSummaryResponse`] still include them. Defaults to
+    /// [`DEFAULT_MUTATION_LOG_ENTRIES`].
+    pub max_entries: Option<u32>,
+}
+
+/// Reply to `POST /mutation-log/start` and `DELETE /mutation-log`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MutationLogStatusResponse {
+    /// Whether mutations are now being recorded.
+    pub observing: bool,
+}
+
+/// What kind of change a [`MutationEntry`] records, after
+/// `MutationRecord.type`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MutationKind {
+    /// Children were added or removed.
+    ChildList,
+    /// An attribute was set, changed or removed.
+    Attributes,
+    /// A text node's content changed.
+    CharacterData,
+}
+
+/// One `MutationRecord` seen by the observer.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MutationEntry {
+    /// What changed.
+    pub kind: MutationKind,
+    /// CSS selector path to the changed element, or to the parent element of
+    /// a changed text node.
+    pub target_selector: String,
+    /// When the change was observed, in milliseconds since the Unix epoch.
+    pub timestamp_ms: u64,
+    /// Nodes added, for [`MutationKind::ChildList`].
+    pub added_nodes: u32,
+    /// Nodes removed, for [`MutationKind::ChildList`].
+    pub removed_nodes: u32,
+    /// Changed attribute, for [`MutationKind::Attributes`].
+    pub attribute_name: Option<String>,
+    /// Attribute value or text before the change, up to 200 characters.
+    pub old_value: Option<String>,
+    /// Attribute value or text when the change was observed, up to 200
+    /// characters. Later changes in the same batch may already show here.
+    pub new_value: Option<String>,
+}
+
+/// DOM changes under `<body>` since `POST /mutation-log/start`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MutationSummaryResponse {
+    /// Nodes added in total.
+    pub added_nodes: u32,
+    /// Nodes removed in total.
+    pub removed_nodes: u32,
+    /// Attribute changes in total.
+    pub attribute_changes: u32,
+    /// Text node changes in total.
+    pub text_changes: u32,
+    /// The most recent changes, oldest first.
+    pub entries: Vec<MutationEntry>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mutation_entry_deserialize() {
+        let json = r#"{"kind": "attributes", "target_selector": "main > div", "timestamp_ms": 1700000000000,
+            "added_nodes": 0, "removed_nodes": 0, "attribute_name": "class",
+            "old_value": "card", "new_value": "card active"}"#;
+        let entry: MutationEntry = serde_json::from_str(json).unwrap();
+        assert_eq!(entry.kind, MutationKind::Attributes);
+        assert_eq!(entry.attribute_name.as_deref(), Some("class"));
+    }
+}
@@ -0,0 +1,60 @@
+//! Request and response types for page navigation in the webview.
+
+use serde::{Deserialize, Serialize};
+
+/// Request to reload the page.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "soft": true }
+/// ```
+#[derive(Debug, Deserialize, Default)]
+pub struct ReloadRequest {
+    /// Reassign `location.href` instead of calling `location.reload()`.
+    ///
+    /// A soft reload is treated as a regular navigation, so the webview may
+    /// serve cached resources instead of revalidating them.
+    #[serde(default)]
+    pub soft: bool,
+}
+
+/// Response from a reload.
+#[derive(Debug, Serialize)]
+pub struct ReloadResponse {
+    /// Whether the reload was scheduled.
+    pub success: bool,
+    /// Error message if the reload script failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Response from `POST /reload/hot`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HotReloadResponse {
+    /// Whether the reload message was sent.
+    pub triggered: bool,
+    /// Whether the page had an open hot-reload WebSocket. `false` outside
+    /// `dx serve`, or while the dev server is reconnecting.
+    pub ws_connected: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reload_request_defaults_to_hard() {
+        let req: ReloadRequest = serde_json::from_str("{}").unwrap();
+        assert!(!req.soft);
+    }
+
+    #[test]
+    fn test_reload_response_skips_error() {
+        let resp = ReloadResponse {
+            success: true,
+            error: None,
+        };
+        assert_eq!(serde_json::to_string(&resp).unwrap(), r#"{"success":true}"#);
+    }
+}
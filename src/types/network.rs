@@ -0,0 +1,74 @@
+//! Request and response types for simulating network conditions.
+
+use serde::{Deserialize, Serialize};
+
+/// Network conditions `fetch` should behave under.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "type": "slow_3g", "latency_ms": 400, "bandwidth_kbps": 400 }
+/// ```
+///
+/// `{ "type": "offline" }` and `{ "type": "normal" }` take no options.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NetworkCondition {
+    /// Every `fetch` rejects with a `TypeError`, and `navigator.onLine` is
+    /// `false`.
+    Offline,
+    /// Every `fetch` waits `latency_ms` before the request, then for the
+    /// response body to arrive at `bandwidth_kbps`.
+    #[serde(rename = "slow_3g")]
+    Slow3G {
+        /// Delay before each request is sent.
+        latency_ms: u64,
+        /// Download speed in kilobits per second.
+        bandwidth_kbps: u64,
+    },
+    /// The real `fetch`, same as `DELETE /simulate-network`.
+    Normal,
+}
+
+/// Request to simulate network conditions.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "condition": { "type": "offline" } }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkSimRequest {
+    /// Conditions to simulate.
+    pub condition: NetworkCondition,
+}
+
+/// Whether network conditions are simulated after the request.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NetworkSimResponse {
+    pub simulated: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_network_condition_json() {
+        let req: NetworkSimRequest = serde_json::from_str(
+            r#"{"condition": {"type": "slow_3g", "latency_ms": 400, "bandwidth_kbps": 400}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            req.condition,
+            NetworkCondition::Slow3G {
+                latency_ms: 400,
+                bandwidth_kbps: 400
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&NetworkCondition::Offline).unwrap(),
+            r#"{"type":"offline"}"#
+        );
+    }
+}
@@ -0,0 +1,137 @@
+//! Request and response types for reading elements by CSS selector.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::EvalResponse;
+
+/// Query request for CSS selector.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "selector": ".my-button", "property": "text" }
+/// ```
+///
+/// # Supported Properties
+///
+/// - `text` (default) - Element's `textContent`
+/// - `html` - Element's `innerHTML`
+/// - `outerHTML` - Element's `outerHTML`
+/// - `value` - Element's `value` (for inputs)
+/// - Any other string - Treated as an attribute name
+#[derive(Debug, Deserialize)]
+pub struct QueryRequest {
+    /// CSS selector to find the element.
+    pub selector: String,
+    /// Property to extract. Defaults to "text" if not specified.
+    #[serde(default)]
+    pub property: Option<String>,
+}
+
+/// Request to query every element matching a CSS selector.
+///
+/// Supports the same properties as [`QueryRequest`].
+///
+/// # JSON Format
+///
+/// ```json
+/// { "selector": "li", "property": "text", "limit": 20 }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct QueryAllRequest {
+    /// CSS selector to match elements.
+    pub selector: String,
+    /// Property to extract. Defaults to "text" if not specified.
+    #[serde(default)]
+    pub property: Option<String>,
+    /// Maximum number of elements to return. Defaults to 100.
+    #[serde(default)]
+    pub limit: Option<u32>,
+}
+
+/// Response from `POST /query/all`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QueryAllResponse {
+    /// One entry per element, in document order. `result` holds the property
+    /// value as a plain string and is omitted when it is `null` (e.g. a
+    /// missing attribute).
+    pub elements: Vec<EvalResponse>,
+    /// Number of elements matching the selector, including any past the limit.
+    pub total_count: u32,
+    /// Whether `elements` was cut short by the limit.
+    pub truncated: bool,
+}
+
+/// Request for the `data-*` attributes of an element.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "selector": "[role=switch]" }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct DataAttributesRequest {
+    /// CSS selector; the first matching element is used.
+    pub selector: String,
+}
+
+/// Response from `POST /data-attributes`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DataAttributesResponse {
+    /// Attribute name (e.g. `data-state`) to value.
+    pub attributes: HashMap<String, String>,
+}
+
+/// Request for the computed CSS of an element.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "selector": ".card", "properties": ["display", "margin-top"] }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct ComputedStylesRequest {
+    /// CSS selector; the first matching element is used.
+    pub selector: String,
+    /// Property names to return. All computed properties when omitted.
+    pub properties: Option<Vec<String>>,
+}
+
+/// Response from `POST /computed-styles`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ComputedStylesResponse {
+    /// Property name to computed value.
+    pub styles: HashMap<String, String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_request_deserialize() {
+        let json = r#"{"selector": ".button", "property": "text"}"#;
+        let req: QueryRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.selector, ".button");
+        assert_eq!(req.property, Some("text".to_string()));
+    }
+
+    #[test]
+    fn test_query_request_without_property() {
+        let json = r##"{"selector": "#main"}"##;
+        let req: QueryRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.selector, "#main");
+        assert!(req.property.is_none());
+    }
+
+    #[test]
+    fn test_query_all_request_defaults() {
+        let json = r#"{"selector": "li"}"#;
+        let req: QueryAllRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.selector, "li");
+        assert!(req.property.is_none());
+        assert!(req.limit.is_none());
+    }
+}
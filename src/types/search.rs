@@ -0,0 +1,43 @@
+//! Request and response types for searching the page by text.
+
+use serde::{Deserialize, Serialize};
+
+/// Request to find the elements whose text contains a string.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "text": "Sign in", "case_sensitive": false, "exact": false, "selector_filter": "header" }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct DomSearchRequest {
+    /// Text to look for.
+    pub text: String,
+    /// Match case exactly (default: ignore case).
+    pub case_sensitive: Option<bool>,
+    /// Match only text nodes whose whole trimmed text equals `text` (default:
+    /// any text containing it).
+    pub exact: Option<bool>,
+    /// CSS selector; only text inside matching elements is searched.
+    pub selector_filter: Option<String>,
+}
+
+/// Elements containing the searched text, from `POST /dom/search`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DomSearchResponse {
+    /// Matching elements in document order.
+    pub matches: Vec<SearchMatch>,
+}
+
+/// An element whose own text nodes contain the searched text.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchMatch {
+    /// CSS selector path to the element.
+    pub selector_path: String,
+    /// Lowercase tag name.
+    pub tag: String,
+    /// The first match with some surrounding text, whitespace collapsed.
+    pub text_snippet: String,
+    /// Number of matches in the element's own text nodes.
+    pub match_count: usize,
+}
@@ -0,0 +1,76 @@
+//! Request and response types for saved debugging sessions.
+
+use serde::{Deserialize, Serialize};
+
+use crate::history::EvalHistoryEntry;
+use crate::types::EvalResponse;
+
+/// Request to save the current session.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "name": "checkout-bug" }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct SaveSessionRequest {
+    /// Session name, used as the file name. Letters, digits, `-`, `_` and `.` only.
+    pub name: String,
+}
+
+/// Response from saving a session.
+#[derive(Debug, Serialize)]
+pub struct SaveSessionResponse {
+    /// Whether the session was written.
+    pub success: bool,
+    /// The session name.
+    pub name: String,
+    /// Path of the session file.
+    pub path: String,
+}
+
+/// Query parameters for `GET /session/load`.
+#[derive(Debug, Deserialize)]
+pub struct LoadSessionQuery {
+    /// Name the session was saved under.
+    pub name: String,
+}
+
+/// A saved debugging session.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Session {
+    /// The session name.
+    pub name: String,
+    /// The app that was being debugged.
+    pub app: String,
+    /// When the session was saved, in milliseconds since the Unix epoch.
+    pub saved_at_ms: u64,
+    /// `/dom` snapshot taken when saving, if the app responded.
+    pub dom: Option<EvalResponse>,
+    /// Result of the last `/diagnose` call, if any.
+    pub diagnose: Option<EvalResponse>,
+    /// Recent evals, oldest first.
+    pub history: Vec<EvalHistoryEntry>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_roundtrip() {
+        let session = Session {
+            name: "bug".to_string(),
+            app: "test-app".to_string(),
+            saved_at_ms: 1,
+            dom: Some(EvalResponse::success("{}")),
+            diagnose: None,
+            history: vec![],
+        };
+        let json = serde_json::to_string(&session).unwrap();
+        let loaded: Session = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.name, "bug");
+        assert_eq!(loaded.dom.unwrap().result.as_deref(), Some("{}"));
+        assert!(loaded.diagnose.is_none());
+    }
+}
@@ -0,0 +1,37 @@
+//! Request and response types for app signal values.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Values published by `use_signal_bridge`, keyed by name.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SignalsResponse {
+    /// Latest value of each signal; empty if the app publishes none.
+    pub signals: HashMap<String, serde_json::Value>,
+}
+
+/// Default for [`SignalWatchQuery::interval_ms`].
+pub const DEFAULT_SIGNAL_POLL_MS: u64 = 500;
+
+/// Query parameters for `GET /signals/watch`.
+#[derive(Debug, Default, Deserialize)]
+pub struct SignalWatchQuery {
+    /// Comma-separated signal names to watch, e.g. `counter,message`. All
+    /// signals when omitted.
+    pub names: Option<String>,
+    /// How often to read the signals, in milliseconds (default:
+    /// [`DEFAULT_SIGNAL_POLL_MS`]).
+    pub interval_ms: Option<u64>,
+}
+
+/// A signal whose value changed between two reads, sent as a `signal`
+/// server-sent event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignalChange {
+    pub name: String,
+    /// Value at the previous read, `None` if the signal wasn't published yet.
+    pub old_value: Option<serde_json::Value>,
+    /// Value now, `None` if the signal is no longer published.
+    pub new_value: Option<serde_json::Value>,
+}
@@ -0,0 +1,35 @@
+//! Request and response types for inspecting Web Component slots.
+
+use serde::{Deserialize, Serialize};
+
+/// Request to list the slots in a custom element's shadow root.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "host_selector": "my-card" }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct SlotInspectRequest {
+    /// CSS selector of the element hosting the shadow root.
+    pub host_selector: String,
+}
+
+/// Slots of a shadow root, from `POST /slot`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SlotResponse {
+    /// Every `<slot>` in the shadow root, in tree order.
+    pub slots: Vec<SlotInfo>,
+}
+
+/// A `<slot>` and the light DOM elements assigned to it.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SlotInfo {
+    /// The slot's `name`, empty for the default slot.
+    pub name: String,
+    /// Selector paths of the elements shown in the slot, from
+    /// `assignedElements({ flatten: true })`.
+    pub assigned_elements: Vec<String>,
+    /// HTML shown when nothing is assigned, i.e. the slot's own children.
+    pub fallback_content: String,
+}
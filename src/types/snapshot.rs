@@ -0,0 +1,123 @@
+//! Request and response types for DOM snapshots and diffs.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+/// Response from `GET /dom/snapshot`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DomSnapshotResponse {
+    /// Id to pass to `/dom/diff`.
+    pub snapshot_id: Uuid,
+    /// Number of nodes captured.
+    pub node_count: usize,
+}
+
+/// Request to compare two DOM snapshots.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "before_id": "5f0c...", "after_id": null }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct DomDiffRequest {
+    /// Snapshot to compare from.
+    pub before_id: Uuid,
+    /// Snapshot to compare to. When omitted, a fresh snapshot is taken.
+    #[serde(default)]
+    pub after_id: Option<Uuid>,
+}
+
+/// Response from `POST /dom/diff`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DomDiffResponse {
+    pub before_id: Uuid,
+    /// The `after_id` from the request, or the id of the fresh snapshot.
+    pub after_id: Uuid,
+    /// Differences in document order; empty when nothing changed.
+    pub changes: Vec<DomChange>,
+}
+
+/// Query parameters for `GET /snapshot`.
+#[derive(Debug, Default, Deserialize)]
+pub struct SnapshotArchiveQuery {
+    /// Add a PNG screenshot of the window. Defaults to `false`.
+    #[serde(default)]
+    pub screenshot: bool,
+}
+
+/// Contents of `manifest.json` in a `GET /snapshot` archive.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SnapshotManifest {
+    /// Id to pass to `GET /snapshot/{id}`, also sent as `Snapshot-Id`.
+    pub snapshot_id: Uuid,
+    pub app_name: String,
+    /// When the capture started, in milliseconds since the Unix epoch.
+    pub captured_at_ms: u64,
+    /// Files in the archive besides the manifest.
+    pub files: Vec<String>,
+    /// Why each part that's missing from `files` couldn't be captured, by file name.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub errors: HashMap<String, String>,
+}
+
+/// One difference between two snapshots.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DomChange {
+    /// Location of the node, e.g. `body > div#app[0] > ul[2]`. Indexes are
+    /// positions among the parent's serialized children.
+    pub path: String,
+    pub kind: DomChangeKind,
+    /// For `changed`, which node field differs (`tag`, `id`, `class` or `text`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    /// The removed node, or the field's old value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<Value>,
+    /// The added node, or the field's new value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<Value>,
+}
+
+/// Kind of [`DomChange`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DomChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dom_diff_request_without_after() {
+        let json = r#"{"before_id": "67e55044-10b1-426f-9247-bb680e5fe0c8"}"#;
+        let req: DomDiffRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            req.before_id.to_string(),
+            "67e55044-10b1-426f-9247-bb680e5fe0c8"
+        );
+        assert!(req.after_id.is_none());
+    }
+
+    #[test]
+    fn test_dom_change_serialize() {
+        let change = DomChange {
+            path: "ul > li[1]".to_string(),
+            kind: DomChangeKind::Removed,
+            field: None,
+            before: Some(serde_json::json!({"tag": "li"})),
+            after: None,
+        };
+        let json = serde_json::to_value(&change).unwrap();
+        assert_eq!(json["kind"], "removed");
+        assert!(json.get("field").is_none());
+        assert!(json.get("after").is_none());
+    }
+}
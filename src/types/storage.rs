@@ -0,0 +1,77 @@
+//! Request and response types for reading and watching browser storage.
+
+use serde::{Deserialize, Serialize};
+
+/// Request to read records from an IndexedDB object store.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "database": "app-cache", "store": "todos", "key": "42" }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct IndexedDbRequest {
+    /// Name of the database.
+    pub database: String,
+    /// Name of the object store.
+    pub store: String,
+    /// Read only the record with this key. Numeric strings also match
+    /// number keys. Reads the whole store when omitted.
+    #[serde(default)]
+    pub key: Option<String>,
+}
+
+/// Records read from an object store.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IndexedDbResponse {
+    /// Record values in key order, serialized with `JSON.stringify`.
+    pub records: Vec<serde_json::Value>,
+    /// Why the read failed, e.g. a missing database or object store.
+    pub error: Option<String>,
+}
+
+/// Default for [`StorageWatchQuery::interval_ms`].
+pub const DEFAULT_STORAGE_POLL_MS: u64 = 250;
+
+/// Query parameters for `GET /storage/watch`.
+#[derive(Debug, Default, Deserialize)]
+pub struct StorageWatchQuery {
+    /// How often to collect new events from the page, in milliseconds
+    /// (default: [`DEFAULT_STORAGE_POLL_MS`]).
+    pub interval_ms: Option<u64>,
+}
+
+/// A change to `localStorage`, sent as a `storage` server-sent event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageEvent {
+    /// Changed key, or `None` when the storage was cleared.
+    pub key: Option<String>,
+    /// Value before the change, `None` for new keys.
+    pub old_value: Option<String>,
+    /// Value after the change, `None` for removed keys.
+    pub new_value: Option<String>,
+    /// URL of the document that made the change.
+    pub url: String,
+    /// When the change was recorded, in milliseconds since the Unix epoch.
+    pub timestamp_ms: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_indexeddb_request_defaults() {
+        let req: IndexedDbRequest =
+            serde_json::from_str(r#"{"database": "app", "store": "todos"}"#).unwrap();
+        assert_eq!(req.database, "app");
+        assert!(req.key.is_none());
+    }
+
+    #[test]
+    fn test_storage_event_clear() {
+        let json = r#"{"key": null, "old_value": null, "new_value": null, "url": "app://index.html", "timestamp_ms": 1}"#;
+        let event: StorageEvent = serde_json::from_str(json).unwrap();
+        assert!(event.key.is_none());
+    }
+}
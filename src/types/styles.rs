@@ -0,0 +1,43 @@
+//! Request and response types for injecting CSS at runtime.
+
+use serde::{Deserialize, Serialize};
+
+/// Request to add a `<style>` element to the document head.
+///
+/// The element gets the id `dioxus-inspector-{id}`. Injecting again with the
+/// same `id` replaces its CSS.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "id": "wider-sidebar", "css": ".sidebar { width: 320px; }" }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct InjectCssRequest {
+    /// Name of the style, made of letters, digits, `-` and `_`.
+    pub id: String,
+    /// CSS rules to add.
+    pub css: String,
+}
+
+/// Request to remove a style added by `POST /inject-css`.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "id": "wider-sidebar" }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct RemoveCssRequest {
+    /// Name the style was injected with.
+    pub id: String,
+}
+
+/// Response from injecting or removing CSS.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InjectCssResponse {
+    /// Whether the style was added, or for removal, whether it existed.
+    pub success: bool,
+    /// Id of the `<style>` element, e.g. `dioxus-inspector-wider-sidebar`.
+    pub style_id: String,
+}
@@ -0,0 +1,76 @@
+//! Request and response types for inspecting and animating SVG.
+
+use serde::{Deserialize, Serialize};
+
+/// Response from `GET /svg`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SvgResponse {
+    /// Every `<svg>` and the elements inside it, in document order.
+    pub elements: Vec<SvgElement>,
+}
+
+/// Geometry attributes of an `<svg>` or one of its descendants.
+///
+/// Attributes are read as written, so they are `None` when the element
+/// doesn't set them, even if the browser has a default.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SvgElement {
+    /// CSS selector path to the element, from the nearest ancestor with an id.
+    pub selector_path: String,
+    /// Lowercase tag name, e.g. `path`. Namespaced tags keep their case, e.g.
+    /// `linearGradient`.
+    pub tag: String,
+    /// `viewBox`, e.g. `0 0 100 100`.
+    pub view_box: Option<String>,
+    /// `width` attribute, e.g. `100%` or `240`.
+    pub width: Option<String>,
+    /// `height` attribute.
+    pub height: Option<String>,
+    /// `preserveAspectRatio`, e.g. `xMidYMid meet`.
+    pub preserve_aspect_ratio: Option<String>,
+    /// `transform`, e.g. `translate(10 20) rotate(45)`.
+    pub transform: Option<String>,
+    /// Path data, for `<path>`.
+    pub d: Option<String>,
+    /// Center x, for `<circle>`.
+    pub cx: Option<String>,
+    /// Center y, for `<circle>`.
+    pub cy: Option<String>,
+    /// Radius, for `<circle>`.
+    pub r: Option<String>,
+    /// Vertex list, for `<polygon>`.
+    pub points: Option<String>,
+}
+
+/// Request to animate an SVG attribute with an `<animate>` element.
+///
+/// The animation runs once and keeps its end value (`fill="freeze"`).
+///
+/// # JSON Format
+///
+/// ```json
+/// { "selector": "circle#dot", "attribute": "r", "from": "5", "to": "20", "duration_ms": 500 }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct SvgAnimateRequest {
+    /// CSS selector of the SVG element to animate.
+    pub selector: String,
+    /// Attribute to animate, e.g. `r` or `fill`.
+    pub attribute: String,
+    /// Value at the start of the animation.
+    pub from: String,
+    /// Value at the end of the animation.
+    pub to: String,
+    /// How long the animation runs.
+    pub duration_ms: u64,
+}
+
+/// Response from `POST /svg/animate`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SvgAnimateResponse {
+    /// Whether the `<animate>` element was added and started.
+    pub success: bool,
+    /// Error message if the element was missing or isn't SVG.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
@@ -0,0 +1,46 @@
+//! Request and response types for reading HTML tables.
+
+use serde::{Deserialize, Serialize};
+
+/// Request to extract the contents of a `<table>`.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "selector": "table.orders" }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct TableRequest {
+    /// CSS selector; the first matching `<table>` is read.
+    pub selector: String,
+}
+
+/// Cell text of a table, with merged cells spread over the slots they cover.
+///
+/// A cell with `colspan="2"` appears in both columns it spans, and one with
+/// `rowspan` in each row, so every row has one value per column.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TableResponse {
+    /// Text of the last `<thead>` row, or of the first row when it has only
+    /// `<th>` cells; empty when the table has no header.
+    pub headers: Vec<String>,
+    /// Text of each data row's cells, with whitespace collapsed.
+    pub rows: Vec<Vec<String>>,
+    /// Number of data rows.
+    pub row_count: u32,
+    /// Data cells with a `colspan` or `rowspan` above 1.
+    pub merged_cells: Vec<MergedCell>,
+}
+
+/// A data cell spanning several rows or columns.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MergedCell {
+    /// Index into `rows` of the row the cell starts in.
+    pub row: u32,
+    /// Column the cell starts in.
+    pub column: u32,
+    /// Number of rows the cell covers.
+    pub rowspan: u32,
+    /// Number of columns the cell covers.
+    pub colspan: u32,
+}
@@ -0,0 +1,54 @@
+//! Request and response types for simulating translated text.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Request to swap text in the page, e.g. to check that a layout survives
+/// longer German or wider Japanese strings.
+///
+/// Every occurrence of each key in the text nodes under `selector` is
+/// replaced by its value, longer keys first. Text in `<script>` and `<style>`
+/// is left alone.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "replacements": { "Save": "Speichern", "Cancel": "Abbrechen" }, "selector": "#toolbar" }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct TranslateRequest {
+    /// Text to find, mapped to its replacement.
+    pub replacements: HashMap<String, String>,
+    /// CSS selector of the element to translate; defaults to `document.body`.
+    #[serde(default)]
+    pub selector: Option<String>,
+}
+
+/// Response from translating the page.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TranslateResponse {
+    /// Number of occurrences replaced.
+    pub replaced_count: u32,
+}
+
+/// Response from `POST /translate/restore`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TranslateRestoreResponse {
+    /// Number of text nodes put back to their original text. Nodes the app
+    /// has re-rendered since keep their new text.
+    pub restored_count: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translate_request_without_selector() {
+        let req: TranslateRequest =
+            serde_json::from_str(r#"{"replacements": {"Save": "Speichern"}}"#).unwrap();
+        assert_eq!(req.replacements["Save"], "Speichern");
+        assert!(req.selector.is_none());
+    }
+}
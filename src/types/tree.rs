@@ -0,0 +1,38 @@
+//! Response types for the Dioxus component tree.
+
+use serde::{Deserialize, Serialize};
+
+/// An element Dioxus tracks, with the tracked elements nested inside it.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ComponentNode {
+    /// The element's `data-component` attribute, if the app sets one.
+    pub name: Option<String>,
+    /// The element's `data-dioxus-id`, or `root` for the tree's root.
+    pub id: String,
+    /// Nearest tracked descendants, in document order.
+    pub children: Vec<ComponentNode>,
+}
+
+/// Response from `GET /tree`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ComponentTreeResponse {
+    /// Synthetic node standing for `<body>`.
+    pub root: ComponentNode,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_component_tree_deserialize() {
+        let json = r#"{"root": {"name": null, "id": "root", "children": [
+            {"name": "App", "id": "1", "children": [{"name": null, "id": "2", "children": []}]}
+        ]}}"#;
+        let resp: ComponentTreeResponse = serde_json::from_str(json).unwrap();
+        let app = &resp.root.children[0];
+        assert_eq!(app.name.as_deref(), Some("App"));
+        assert_eq!(app.children[0].id, "2");
+        assert!(app.children[0].name.is_none());
+    }
+}
@@ -0,0 +1,309 @@
+//! Request and response types for the webview viewport.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Request to set the document zoom level.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "level": 1.5 }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct ZoomRequest {
+    /// CSS zoom factor applied to `document.body` (`1.0` is unzoomed).
+    pub level: f64,
+}
+
+/// Current zoom state of the document.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ZoomResponse {
+    /// Whether the zoom was read or applied.
+    pub success: bool,
+    /// CSS zoom factor of `document.body`.
+    pub level: f64,
+    /// The webview's `window.devicePixelRatio`.
+    pub device_pixel_ratio: f64,
+}
+
+/// Request to resize the viewport.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "width": 375, "height": 812 }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct SetViewportRequest {
+    /// Target width in pixels.
+    pub width: u32,
+    /// Target height in pixels.
+    pub height: u32,
+}
+
+/// Current viewport dimensions, from `GET /viewport` and `POST /viewport`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ViewportResponse {
+    /// `window.innerWidth` in CSS pixels.
+    pub inner_width: u32,
+    /// `window.innerHeight` in CSS pixels.
+    pub inner_height: u32,
+    /// `window.outerWidth` in CSS pixels.
+    pub outer_width: u32,
+    /// `window.outerHeight` in CSS pixels.
+    pub outer_height: u32,
+    /// The webview's `window.devicePixelRatio`.
+    pub device_pixel_ratio: f64,
+    /// `"landscape"` or `"portrait"`, as used by the `orientation` media query.
+    pub orientation: String,
+}
+
+/// Document and window metadata read in one eval, from `GET /window/state`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WindowStateResponse {
+    /// `document.title`.
+    pub title: String,
+    /// `window.location.href`.
+    pub url: String,
+    /// `document.readyState`: `"loading"`, `"interactive"` or `"complete"`.
+    pub ready_state: String,
+    /// `document.visibilityState`: `"visible"` or `"hidden"`.
+    pub visibility_state: String,
+    /// `document.hasFocus()`.
+    pub has_focus: bool,
+    /// `window.scrollX` in CSS pixels.
+    pub scroll_x: f64,
+    /// `window.scrollY` in CSS pixels.
+    pub scroll_y: f64,
+    /// `window.innerWidth` in CSS pixels.
+    pub inner_width: u32,
+    /// `window.innerHeight` in CSS pixels.
+    pub inner_height: u32,
+    /// `window.outerWidth` in CSS pixels.
+    pub outer_width: u32,
+    /// `window.outerHeight` in CSS pixels.
+    pub outer_height: u32,
+    /// `window.screenX`, negative on a screen left of the primary one.
+    pub screen_x: i32,
+    /// `window.screenY`, negative on a screen above the primary one.
+    pub screen_y: i32,
+    /// The webview's `window.devicePixelRatio`.
+    pub device_pixel_ratio: f64,
+}
+
+/// Request to switch the document's `data-theme` between dark and light.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "dark": true }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct SetDarkModeRequest {
+    /// Set `data-theme="dark"` when `true`, `data-theme="light"` otherwise.
+    pub dark: bool,
+}
+
+/// Color scheme preferred by the system and applied by the document.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DarkModeResponse {
+    /// Whether `(prefers-color-scheme: dark)` matches.
+    pub system_dark: bool,
+    /// Whether `<html>` has `data-theme="dark"`.
+    pub document_dark: bool,
+    /// The raw `data-theme` attribute of `<html>`, if set.
+    pub data_theme: Option<String>,
+}
+
+/// A named window size for [`ResponsiveTestRequest`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewportSize {
+    /// Label for the size, e.g. `"mobile"`. Also names the screenshot file.
+    pub name: String,
+    /// Window width in pixels.
+    pub width: u32,
+    /// Window height in pixels.
+    pub height: u32,
+}
+
+/// Request to render the app at several window sizes in turn.
+///
+/// # JSON Format
+///
+/// ```json
+/// {
+///   "sizes": [
+///     { "name": "mobile", "width": 375, "height": 812 },
+///     { "name": "desktop", "width": 1440, "height": 900 }
+///   ],
+///   "screenshot": true
+/// }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct ResponsiveTestRequest {
+    /// Sizes to try, in order.
+    pub sizes: Vec<ViewportSize>,
+    /// Also capture a screenshot at each size.
+    #[serde(default)]
+    pub screenshot: bool,
+}
+
+/// What the app looked like at one size.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResponsiveResult {
+    /// Name of the size, from the request.
+    pub name: String,
+    /// Requested size as `{width}x{height}`.
+    pub size: String,
+    /// Where the screenshot was saved, if one was requested and captured.
+    pub screenshot_path: Option<String>,
+    /// The `/dom` tree after the resize, or `null` if it couldn't be read.
+    pub dom: Option<serde_json::Value>,
+    /// Why the resize, screenshot or DOM read failed.
+    pub error: Option<String>,
+}
+
+/// Results of a responsive test, one per requested size.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResponsiveTestResponse {
+    /// One entry per requested size, in order.
+    pub results: Vec<ResponsiveResult>,
+}
+
+/// Default for [`BreakpointStep::delay_ms`].
+pub const DEFAULT_BREAKPOINT_DELAY_MS: u64 = 200;
+
+/// One window size to step through in [`BreakpointSimRequest`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BreakpointStep {
+    /// Label for the step, e.g. `"md"`. Also names the screenshot file.
+    pub name: String,
+    /// Window width in pixels.
+    pub width: u32,
+    /// Window height in pixels.
+    pub height: u32,
+    /// How long to wait after resizing, for layout and transitions to settle.
+    /// Defaults to [`DEFAULT_BREAKPOINT_DELAY_MS`].
+    #[serde(default = "default_breakpoint_delay_ms")]
+    pub delay_ms: u64,
+}
+
+fn default_breakpoint_delay_ms() -> u64 {
+    DEFAULT_BREAKPOINT_DELAY_MS
+}
+
+/// Request to step the window through breakpoints in order.
+///
+/// # JSON Format
+///
+/// ```json
+/// {
+///   "breakpoints": [
+///     { "name": "sm", "width": 640, "height": 900, "delay_ms": 300 },
+///     { "name": "md", "width": 768, "height": 900, "delay_ms": 300 }
+///   ],
+///   "screenshot": true
+/// }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct BreakpointSimRequest {
+    /// Steps to take, in order.
+    pub breakpoints: Vec<BreakpointStep>,
+    /// Also capture a screenshot at each step.
+    #[serde(default)]
+    pub screenshot: bool,
+}
+
+/// What one breakpoint step did to the page.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BreakpointResult {
+    /// Name of the step, from the request.
+    pub name: String,
+    /// Requested size as `{width}x{height}`.
+    pub size: String,
+    /// The viewport after the delay, or `null` if it couldn't be read.
+    pub viewport: Option<ViewportResponse>,
+    /// Whether the `/dom` tree differs from the last step that read one.
+    /// Always `false` for the first step.
+    pub dom_changed: bool,
+    /// Where the screenshot was saved, if one was requested and captured.
+    pub screenshot_path: Option<String>,
+    /// Why the resize, screenshot or reads failed.
+    pub error: Option<String>,
+}
+
+/// Results of a breakpoint simulation, one per step.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BreakpointSimResponse {
+    /// One entry per requested step, in order.
+    pub results: Vec<BreakpointResult>,
+}
+
+/// Query parameters for `GET /media-query`.
+#[derive(Debug, Deserialize)]
+pub struct MediaQueryParams {
+    /// Media query to evaluate, e.g. `(max-width: 768px)`.
+    pub query: String,
+}
+
+/// Whether a media query matches the webview.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MediaQueryResponse {
+    /// Result of `window.matchMedia(query).matches`.
+    pub matches: bool,
+    /// The query as given.
+    pub query: String,
+}
+
+/// Breakpoints checked by `GET /media-query/all`.
+pub const COMMON_BREAKPOINTS: &[&str] = &[
+    "(max-width: 640px)",
+    "(max-width: 768px)",
+    "(max-width: 1024px)",
+    "(max-width: 1280px)",
+];
+
+/// Which of the [`COMMON_BREAKPOINTS`] match.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MediaQueryAllResponse {
+    /// Match result keyed by media query.
+    pub matches: HashMap<String, bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zoom_request_deserialize() {
+        let req: ZoomRequest = serde_json::from_str(r#"{"level": 1.25}"#).unwrap();
+        assert!((req.level - 1.25).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_responsive_test_request_deserialize() {
+        let json = r#"{"sizes": [{"name": "mobile", "width": 375, "height": 812}]}"#;
+        let req: ResponsiveTestRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.sizes[0].name, "mobile");
+        assert_eq!(req.sizes[0].width, 375);
+        assert!(!req.screenshot);
+    }
+
+    #[test]
+    fn test_breakpoint_sim_request_deserialize() {
+        let json = r#"{"breakpoints": [{"name": "md", "width": 768, "height": 900}]}"#;
+        let req: BreakpointSimRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.breakpoints[0].delay_ms, DEFAULT_BREAKPOINT_DELAY_MS);
+        assert!(!req.screenshot);
+    }
+
+    #[test]
+    fn test_set_viewport_request_deserialize() {
+        let req: SetViewportRequest =
+            serde_json::from_str(r#"{"width": 375, "height": 812}"#).unwrap();
+        assert_eq!(req.width, 375);
+        assert_eq!(req.height, 812);
+    }
+}
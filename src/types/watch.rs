@@ -0,0 +1,102 @@
+//! Request and response types for waiting on elements.
+
+use serde::{Deserialize, Serialize};
+
+/// Default for [`WatchRequest::timeout_ms`].
+pub const DEFAULT_WATCH_TIMEOUT_MS: u64 = 5_000;
+
+/// What [`WatchRequest`] waits for.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "type": "text_equals", "value": "Saved" }
+/// ```
+///
+/// `{ "type": "exists" }`, `{ "type": "visible" }` and `{ "type": "hidden" }`
+/// take no options.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WatchCondition {
+    /// An element matches the selector.
+    Exists,
+    /// A matching element is rendered and not `visibility: hidden`.
+    Visible,
+    /// No matching element is visible, including when none exists.
+    Hidden,
+    /// The matching element's trimmed text content equals `value`.
+    TextEquals {
+        /// Expected text.
+        value: String,
+    },
+}
+
+impl WatchCondition {
+    /// Name used by the check script.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Exists => "exists",
+            Self::Visible => "visible",
+            Self::Hidden => "hidden",
+            Self::TextEquals { .. } => "text_equals",
+        }
+    }
+}
+
+/// Request to wait until an element meets a condition.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "selector": "#toast", "condition": { "type": "visible" }, "timeout_ms": 3000 }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct WatchRequest {
+    /// CSS selector of the element.
+    pub selector: String,
+    /// Condition to wait for.
+    pub condition: WatchCondition,
+    /// Give up after this many milliseconds. Defaults to
+    /// [`DEFAULT_WATCH_TIMEOUT_MS`].
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+fn default_timeout_ms() -> u64 {
+    DEFAULT_WATCH_TIMEOUT_MS
+}
+
+/// Outcome of a `/watch` request.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WatchResponse {
+    /// Whether the condition was met before the timeout.
+    pub matched: bool,
+    /// Time spent waiting.
+    pub elapsed_ms: u64,
+    /// Trimmed text content of the element at the last check, if it exists.
+    pub value: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_watch_request_deserialize() {
+        let json =
+            r##"{"selector": "#toast", "condition": {"type": "text_equals", "value": "Saved"}}"##;
+        let req: WatchRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            req.condition,
+            WatchCondition::TextEquals {
+                value: "Saved".to_string()
+            }
+        );
+        assert_eq!(req.timeout_ms, DEFAULT_WATCH_TIMEOUT_MS);
+
+        let json = r##"{"selector": "#toast", "condition": {"type": "hidden"}, "timeout_ms": 50}"##;
+        let req: WatchRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.condition, WatchCondition::Hidden);
+        assert_eq!(req.timeout_ms, 50);
+    }
+}
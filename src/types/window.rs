@@ -0,0 +1,243 @@
+//! Request and response types for capturing, resizing and moving the window.
+
+use serde::{Deserialize, Serialize};
+
+/// Screenshot request.
+///
+/// Captures a screenshot of the application window (macOS only).
+///
+/// # JSON Format
+///
+/// ```json
+/// { "path": "/tmp/screenshot.png" }
+/// ```
+#[derive(Debug, Deserialize, Default)]
+pub struct ScreenshotRequest {
+    /// Output path for the screenshot. Defaults to `/tmp/dioxus-screenshot.{ext}`,
+    /// with the extension matching the format.
+    #[serde(default)]
+    pub path: Option<String>,
+    /// Image format. Defaults to PNG.
+    #[serde(default)]
+    pub format: Option<ScreenshotFormat>,
+}
+
+/// Image format for screenshots.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "type": "jpeg", "quality": 80 }
+/// ```
+///
+/// `{ "type": "png" }` and `{ "type": "webp" }` take no options. WebP output
+/// is lossless.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum ScreenshotFormat {
+    /// Lossless PNG.
+    #[default]
+    Png,
+    /// Lossy JPEG with quality from 1 (smallest) to 100 (best).
+    Jpeg {
+        /// Encoder quality, clamped to `1..=100`.
+        quality: u8,
+    },
+    /// Lossless WebP.
+    WebP,
+}
+
+impl ScreenshotFormat {
+    /// MIME type of the encoded image.
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Jpeg { .. } => "image/jpeg",
+            Self::WebP => "image/webp",
+        }
+    }
+
+    /// File extension, without the dot.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg { .. } => "jpg",
+            Self::WebP => "webp",
+        }
+    }
+}
+
+/// Screenshot response.
+#[derive(Debug, Serialize)]
+pub struct ScreenshotResponse {
+    /// Whether the screenshot was captured successfully.
+    pub success: bool,
+    /// Path where the screenshot was saved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// MIME type of the saved image.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    /// Error message if capture failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Request to resize the window.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "width": 1024, "height": 768, "anchor": "center" }
+/// ```
+///
+/// # Note
+///
+/// The application must handle the resize command. The bridge sends an eval
+/// whose script contains one of these markers, which the app can intercept
+/// to apply the resize:
+///
+/// - `__DIOXUS_INSPECTOR_RESIZE__{width}x{height}__` without an anchor
+/// - `__DIOXUS_INSPECTOR_RESIZE__{width}x{height}@{anchor}__` with one,
+///   e.g. `__DIOXUS_INSPECTOR_RESIZE__800x600@center__`
+///
+/// The anchor is the point of the window that stays put: `top-left`,
+/// `top-right`, `bottom-left`, `bottom-right` or `center`.
+#[derive(Debug, Deserialize)]
+pub struct ResizeRequest {
+    /// Target width in pixels.
+    pub width: u32,
+    /// Target height in pixels.
+    pub height: u32,
+    /// Corner or center to resize from. Apps usually treat no anchor as `top-left`.
+    #[serde(default)]
+    pub anchor: Option<String>,
+}
+
+/// Response from resize operation.
+#[derive(Debug, Serialize)]
+pub struct ResizeResponse {
+    /// Whether the resize command was sent successfully.
+    pub success: bool,
+    /// The requested width.
+    pub width: u32,
+    /// The requested height.
+    pub height: u32,
+    /// The requested anchor, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anchor: Option<String>,
+    /// Error message if the resize failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Request to move the window.
+///
+/// # JSON Format
+///
+/// ```json
+/// { "x": 100, "y": 50, "monitor": 1 }
+/// ```
+///
+/// # Note
+///
+/// Like [`ResizeRequest`], the application must handle the move command. The
+/// bridge sends an eval whose script contains one of these markers:
+///
+/// - `__DIOXUS_INSPECTOR_MOVE__{x},{y}__` without a monitor
+/// - `__DIOXUS_INSPECTOR_MOVE__{x},{y}@monitor{n}__` with one,
+///   e.g. `__DIOXUS_INSPECTOR_MOVE__100,50@monitor1__`
+#[derive(Debug, Deserialize)]
+pub struct MoveRequest {
+    /// Target x position of the window's top-left corner, in pixels.
+    pub x: i32,
+    /// Target y position of the window's top-left corner, in pixels.
+    pub y: i32,
+    /// Index of the monitor the position is relative to. Apps usually treat
+    /// no monitor as the whole desktop.
+    #[serde(default)]
+    pub monitor: Option<u32>,
+}
+
+/// Response from move operation.
+#[derive(Debug, Serialize)]
+pub struct MoveResponse {
+    /// Whether the move command was sent successfully.
+    pub success: bool,
+    /// The requested x position.
+    pub x: i32,
+    /// The requested y position.
+    pub y: i32,
+    /// Error message if the move failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_screenshot_request_default() {
+        let req = ScreenshotRequest::default();
+        assert!(req.path.is_none());
+    }
+
+    #[test]
+    fn test_screenshot_request_with_format() {
+        let json = r#"{"format": {"type": "jpeg", "quality": 75}}"#;
+        let req: ScreenshotRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.format, Some(ScreenshotFormat::Jpeg { quality: 75 }));
+
+        let json = r#"{"format": {"type": "webp"}}"#;
+        let req: ScreenshotRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.format, Some(ScreenshotFormat::WebP));
+    }
+
+    #[test]
+    fn test_screenshot_format_mime_type() {
+        assert_eq!(ScreenshotFormat::default().mime_type(), "image/png");
+        assert_eq!(ScreenshotFormat::Jpeg { quality: 90 }.extension(), "jpg");
+        assert_eq!(ScreenshotFormat::WebP.mime_type(), "image/webp");
+    }
+
+    #[test]
+    fn test_resize_request_deserialize() {
+        let json = r#"{"width": 800, "height": 600}"#;
+        let req: ResizeRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.width, 800);
+        assert_eq!(req.height, 600);
+        assert!(req.anchor.is_none());
+    }
+
+    #[test]
+    fn test_move_request_deserialize() {
+        let json = r#"{"x": -1920, "y": 0, "monitor": 1}"#;
+        let req: MoveRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.x, -1920);
+        assert_eq!(req.y, 0);
+        assert_eq!(req.monitor, Some(1));
+    }
+
+    #[test]
+    fn test_resize_request_with_anchor() {
+        let json = r#"{"width": 800, "height": 600, "anchor": "bottom-right"}"#;
+        let req: ResizeRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.anchor.as_deref(), Some("bottom-right"));
+    }
+
+    #[test]
+    fn test_resize_response_serialize() {
+        let resp = ResizeResponse {
+            success: true,
+            width: 1024,
+            height: 768,
+            anchor: None,
+            error: None,
+        };
+        let json = serde_json::to_string(&resp).unwrap();
+        assert!(json.contains("\"width\":1024"));
+        assert!(json.contains("\"height\":768"));
+        assert!(!json.contains("error"));
+    }
+}
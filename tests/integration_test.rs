@@ -0,0 +1,113 @@
+//! End-to-end tests against a real bridge listening on a TCP port.
+
+// `start_bridge_with_config` is excluded from coverage builds
+#![cfg(not(tarpaulin_include))]
+
+use dioxus_inspector::{start_bridge_with_config, BridgeConfig, BridgeHandle, EvalResponse};
+use serde_json::{json, Value};
+
+/// Start a bridge on a free port with a fake app answering its evals.
+///
+/// The bridge ignores `DIOXUS_INSPECTOR_*` and advertises itself in its own
+/// lock file, so running the tests doesn't disturb an app already running.
+///
+/// The fake app recognizes the bridge's scripts by a marker string and
+/// answers the way a webview would. Returns the bridge's base URL.
+fn start_test_bridge() -> String {
+    let lock_file =
+        std::env::temp_dir().join(format!("dioxus-inspector-test-{}.lock", std::process::id()));
+    let handle = start_bridge_with_config(BridgeConfig::new(0, "test").with_lock_file(lock_file));
+    let port = handle.port().expect("bridge failed to bind");
+    tokio::spawn(respond(handle));
+    format!("http://127.0.0.1:{}", port)
+}
+
+async fn respond(mut handle: BridgeHandle) {
+    while let Some(cmd) = handle.recv().await {
+        let response = if cmd.script.contains("MAX_DEPTH") {
+            let tree = json!({"root": {"tag": "body"}, "stats": {"nodeCount": 1}});
+            EvalResponse::success(serde_json::to_string(&tree.to_string()).unwrap())
+        } else if cmd.script.contains("document.querySelector(\"h1\")") {
+            EvalResponse::success("\"Hello\"")
+        } else if cmd.script == "return 6 * 7" {
+            EvalResponse::success("42")
+        } else {
+            // Setup scripts and anything else
+            EvalResponse::success("null")
+        };
+        let _ = cmd.response_tx.send(response);
+    }
+}
+
+#[tokio::test]
+async fn test_status() {
+    let base = start_test_bridge();
+    let json: Value = reqwest::get(format!("{}/status", base))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(json["status"], "ok");
+    assert_eq!(json["app"], "test");
+    assert_eq!(json["pid"], std::process::id());
+}
+
+#[tokio::test]
+async fn test_eval() {
+    let base = start_test_bridge();
+    let response = reqwest::Client::new()
+        .post(format!("{}/eval", base))
+        .json(&json!({"script": "return 6 * 7"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+
+    let json: Value = response.json().await.unwrap();
+    assert_eq!(json["success"], true);
+    assert_eq!(json["result"], "42");
+}
+
+#[tokio::test]
+async fn test_query() {
+    let base = start_test_bridge();
+    let json: Value = reqwest::Client::new()
+        .post(format!("{}/query", base))
+        .json(&json!({"selector": "h1"}))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(json["success"], true);
+    assert_eq!(json["result"], "\"Hello\"");
+}
+
+#[tokio::test]
+async fn test_dom() {
+    let base = start_test_bridge();
+    let json: Value = reqwest::get(format!("{}/dom?depth=3", base))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(json["success"], true);
+    let tree: Value = serde_json::from_str(
+        &serde_json::from_str::<String>(json["result"].as_str().unwrap()).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(tree["root"]["tag"], "body");
+}
+
+#[tokio::test]
+async fn test_unknown_route() {
+    let base = start_test_bridge();
+    let response = reqwest::get(format!("{}/nope", base)).await.unwrap();
+    assert_eq!(response.status(), 404);
+}